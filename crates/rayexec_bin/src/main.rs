@@ -9,6 +9,7 @@ use rayexec_error::Result;
 use rayexec_execution::datasource::{DataSourceBuilder, DataSourceRegistry, MemoryDataSource};
 use rayexec_execution::runtime::{PipelineExecutor, Runtime, TokioHandlerProvider};
 use rayexec_iceberg::IcebergDataSource;
+use rayexec_json::JsonDataSource;
 use rayexec_parquet::ParquetDataSource;
 use rayexec_postgres::PostgresDataSource;
 use rayexec_rt_native::runtime::{NativeRuntime, ThreadedNativeExecutor};
@@ -87,6 +88,7 @@ async fn inner(
         .with_datasource("unity", UnityCatalogDataSource::initialize(runtime.clone()))?
         .with_datasource("parquet", ParquetDataSource::initialize(runtime.clone()))?
         .with_datasource("csv", CsvDataSource::initialize(runtime.clone()))?
+        .with_datasource("json", JsonDataSource::initialize(runtime.clone()))?
         .with_datasource("iceberg", IcebergDataSource::initialize(runtime.clone()))?;
     let engine = SingleUserEngine::try_new(executor, runtime, registry)?;
 
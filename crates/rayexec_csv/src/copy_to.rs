@@ -1,16 +1,16 @@
 use futures::future::BoxFuture;
 use futures::FutureExt;
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Schema;
 use rayexec_execution::execution::operators::sink::PartitionSink;
-use rayexec_execution::functions::copy::CopyToFunction;
+use rayexec_execution::functions::copy::{CopyToArgs, CopyToFunction};
 use rayexec_execution::runtime::Runtime;
+use rayexec_io::compression::Compression;
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::{FileProvider, FileSink};
 
-use crate::reader::DialectOptions;
-use crate::writer::CsvEncoder;
+use crate::writer::{CsvEncoder, CsvWriteOptions};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CsvCopyToFunction<R: Runtime> {
@@ -22,24 +22,45 @@ impl<R: Runtime> CopyToFunction for CsvCopyToFunction<R> {
         "csv_copy_to"
     }
 
+    fn file_extension(&self) -> Option<&'static str> {
+        Some("csv")
+    }
+
     // TODO: Access config
     fn create_sinks(
         &self,
         schema: Schema,
         location: FileLocation,
+        mut args: CopyToArgs,
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>> {
         let provider = self.runtime.file_provider();
+        let compression = args.try_remove_compression(&location)?;
+        let append = args.try_remove_append()?;
+        let options = CsvWriteOptions::try_from_args(&args)?;
+
+        if append && compression != Compression::None {
+            return Err(RayexecError::new(
+                "APPEND is not supported when writing compressed csv files",
+            ));
+        }
 
         let mut sinks = Vec::with_capacity(num_partitions);
         for _ in 0..num_partitions {
-            let sink = provider.file_sink(location.clone(), &AccessConfig::None)?;
-            let dialect = DialectOptions::default();
-
-            sinks.push(Box::new(CsvCopyToSink {
-                encoder: CsvEncoder::new(schema.clone(), dialect),
-                sink,
-            }) as _)
+            let mut options = options.clone();
+            let sink = if append {
+                let (sink, existing_len) =
+                    provider.file_sink_append(location.clone(), &AccessConfig::None)?;
+                if existing_len > 0 {
+                    // Header was already written when the file was created.
+                    options.header = false;
+                }
+                sink
+            } else {
+                provider.file_sink(location.clone(), &AccessConfig::None)?
+            };
+            let sink = compression.wrap_sink(sink)?;
+            sinks.push(Box::new(CsvCopyToSink::new(sink, schema.clone(), options)) as _)
         }
 
         Ok(sinks)
@@ -50,21 +71,46 @@ impl<R: Runtime> CopyToFunction for CsvCopyToFunction<R> {
 pub struct CsvCopyToSink {
     encoder: CsvEncoder,
     sink: Box<dyn FileSink>,
+    /// Encoded bytes that haven't been written to the sink yet.
+    buf: Vec<u8>,
+    /// Size of `buf` at which we'll write it out to the sink.
+    flush_size: usize,
 }
 
 impl CsvCopyToSink {
+    pub fn new(sink: Box<dyn FileSink>, schema: Schema, options: CsvWriteOptions) -> Self {
+        let flush_size = options.flush_size;
+        CsvCopyToSink {
+            encoder: CsvEncoder::new(schema, options),
+            sink,
+            buf: Vec::with_capacity(flush_size),
+            flush_size,
+        }
+    }
+
     async fn push_inner(&mut self, batch: Batch) -> Result<()> {
-        let mut buf = Vec::with_capacity(1024);
-        self.encoder.encode(&batch, &mut buf)?;
-        self.sink.write_all(buf.into()).await?;
+        self.encoder.encode(&batch, &mut self.buf)?;
+        if self.buf.len() >= self.flush_size {
+            self.flush().await?;
+        }
 
         Ok(())
     }
 
     async fn finalize_inner(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.flush().await?;
+        }
         self.sink.finish().await?;
         Ok(())
     }
+
+    /// Write out the buffered bytes to the sink.
+    async fn flush(&mut self) -> Result<()> {
+        let buf = std::mem::replace(&mut self.buf, Vec::with_capacity(self.flush_size));
+        self.sink.write_all(buf.into()).await?;
+        Ok(())
+    }
 }
 
 impl PartitionSink for CsvCopyToSink {
@@ -76,3 +122,182 @@ impl PartitionSink for CsvCopyToSink {
         self.finalize_inner().boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    use rayexec_execution::arrays::array::Array;
+    use rayexec_execution::arrays::datatype::DataType;
+    use rayexec_execution::arrays::field::Field;
+    use rayexec_execution::arrays::scalar::OwnedScalarValue;
+    use rayexec_io::memory::MemoryFileSystem;
+    use rayexec_io::FileSource;
+
+    use super::*;
+
+    /// Write batches to an in-memory file, returning the bytes written.
+    fn write_to_memory(
+        schema: Schema,
+        batches: impl IntoIterator<Item = Batch>,
+        options: CsvWriteOptions,
+    ) -> String {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("out.csv");
+        let mut sink = CsvCopyToSink::new(fs.file_sink(path).unwrap(), schema, options);
+
+        futures::executor::block_on(async {
+            for batch in batches {
+                sink.push(batch).await.unwrap();
+            }
+            sink.finalize().await.unwrap();
+
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            let bytes = source.read_range(0, size).await.unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        })
+    }
+
+    /// Copy a small table to an in-memory file, returning the bytes written.
+    fn copy_to_memory(named: HashMap<String, OwnedScalarValue>) -> String {
+        let schema = Schema::new([
+            Field::new("id", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let batch = Batch::try_new([
+            Array::from_iter([Some(1), None, Some(3)]),
+            Array::from_iter([Some("a,b"), Some("c"), None]),
+        ])
+        .unwrap();
+
+        let options = CsvWriteOptions::try_from_args(&CopyToArgs { named }).unwrap();
+
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("out.csv");
+        let mut sink = CsvCopyToSink::new(fs.file_sink(path).unwrap(), schema, options);
+
+        futures::executor::block_on(async {
+            sink.push(batch).await.unwrap();
+            sink.finalize().await.unwrap();
+
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            let bytes = source.read_range(0, size).await.unwrap();
+            String::from_utf8(bytes.to_vec()).unwrap()
+        })
+    }
+
+    #[test]
+    fn copy_to_defaults() {
+        let out = copy_to_memory(HashMap::new());
+        assert_eq!("id,name\n1,\"a,b\"\n,c\n3,\n", out);
+    }
+
+    #[test]
+    fn copy_to_with_options() {
+        let out = copy_to_memory(HashMap::from([
+            ("header".to_string(), OwnedScalarValue::Boolean(false)),
+            ("delimiter".to_string(), OwnedScalarValue::from("|")),
+            ("quote".to_string(), OwnedScalarValue::from("'")),
+            ("null".to_string(), OwnedScalarValue::from("NA")),
+        ]));
+        assert_eq!("1|a,b\nNA|c\n3|NA\n", out);
+    }
+
+    #[test]
+    fn copy_to_many_rows_chunked_flushes() {
+        const NUM_ROWS: usize = 100_000;
+        const BATCH_SIZE: usize = 4096;
+
+        let schema = Schema::new([
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+
+        let name = |i: usize| -> Option<String> {
+            match i % 4 {
+                0 => None,
+                1 => Some(format!("name {i}")),
+                2 => Some(format!("with,comma {i}")),
+                _ => Some(format!("with \"quote\" {i}")),
+            }
+        };
+
+        let batches = (0..NUM_ROWS).step_by(BATCH_SIZE).map(|start| {
+            let end = (start + BATCH_SIZE).min(NUM_ROWS);
+            Batch::try_new([
+                Array::from_iter((start..end).map(|i| i as i64)),
+                Array::from_iter((start..end).map(name)),
+            ])
+            .unwrap()
+        });
+
+        let options = CsvWriteOptions {
+            flush_size: 16 * 1024,
+            ..Default::default()
+        };
+        let out = write_to_memory(schema, batches, options);
+
+        let mut expected = String::from("id,name\n");
+        for i in 0..NUM_ROWS {
+            let name = match name(i) {
+                None => String::new(),
+                Some(s) if s.contains(',') => format!("\"{s}\""),
+                Some(s) if s.contains('"') => format!("\"{}\"", s.replace('"', "\"\"")),
+                Some(s) => s,
+            };
+            expected.push_str(&format!("{i},{name}\n"));
+        }
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn copy_to_quoting_edge_cases() {
+        let schema = Schema::new([Field::new("a,b", DataType::Utf8, true)]);
+        let batch = Batch::try_new([Array::from_iter([
+            Some(""),
+            None,
+            Some("with \"quote\""),
+            Some("line\nbreak"),
+            Some("carriage\rreturn"),
+            Some(" padded "),
+            Some("a,b"),
+            Some("\""),
+            Some("#comment"),
+        ])])
+        .unwrap();
+
+        let out = write_to_memory(schema, [batch], CsvWriteOptions::default());
+        let expected = concat!(
+            "\"a,b\"\n",
+            "\"\"\n",
+            // A lone empty field is always quoted to distinguish it from an
+            // empty line.
+            "\"\"\n",
+            "\"with \"\"quote\"\"\"\n",
+            "\"line\nbreak\"\n",
+            "\"carriage\rreturn\"\n",
+            " padded \n",
+            "\"a,b\"\n",
+            "\"\"\"\"\n",
+            "#comment\n",
+        );
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn copy_to_invalid_options() {
+        let args = CopyToArgs {
+            named: HashMap::from([("delimiter".to_string(), OwnedScalarValue::from("||"))]),
+        };
+        CsvWriteOptions::try_from_args(&args).unwrap_err();
+
+        let args = CopyToArgs {
+            named: HashMap::from([("compression".to_string(), OwnedScalarValue::from("gzip"))]),
+        };
+        CsvWriteOptions::try_from_args(&args).unwrap_err();
+    }
+}
@@ -11,6 +11,7 @@ use rayexec_execution::storage::table_storage::{
     ProjectedScan,
     Projections,
 };
+use rayexec_io::compression::Compression;
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::FileProvider;
 
@@ -27,7 +28,9 @@ use crate::reader::{AsyncCsvReader, CsvSchema, DialectOptions};
 pub struct SingleFileCsvDataTable<R: Runtime> {
     pub options: DialectOptions,
     pub csv_schema: CsvSchema,
+    pub null_string: Option<String>,
     pub location: FileLocation,
+    pub compression: Compression,
     pub conf: AccessConfig,
     pub runtime: R,
 }
@@ -42,7 +45,13 @@ impl<R: Runtime> DataTable for SingleFileCsvDataTable<R> {
             .runtime
             .file_provider()
             .file_source(self.location.clone(), &self.conf)?;
-        let csv_reader = AsyncCsvReader::new(reader, self.csv_schema.clone(), self.options);
+        let reader = self.compression.wrap_source(reader);
+        let csv_reader = AsyncCsvReader::new(
+            reader,
+            self.csv_schema.clone(),
+            self.options,
+            self.null_string.clone(),
+        );
 
         let mut scans: Vec<Box<dyn DataTableScan>> = vec![Box::new(ProjectedScan::new(
             CsvFileScan { reader: csv_reader },
@@ -73,21 +73,18 @@ impl DecoderState {
 
     pub fn clear_completed(&mut self) {
         let num_completed = self.num_records();
+        if num_completed == 0 {
+            // No completed records to clear.
+            return;
+        }
         let num_fields = match self.num_fields {
             Some(n) => n,
-            None => return, // No completed records to clear.
+            None => return,
         };
 
-        if self.current_field == 0 {
-            // Fast path, we can just reset the buffers.
-            self.buffer.clear();
-            self.buffer_len = 0;
-            self.ends.clear();
-            self.ends_len = 0;
-            return;
-        }
-
-        // Get start index of data that's part of a partial record.
+        // Get start index of data that's part of a partial record. This
+        // includes data for a field we're still in the middle of decoding,
+        // which won't have an end offset yet.
         let start_data_idx = self.ends[num_completed * num_fields - 1];
 
         // Shift ends down.
@@ -95,13 +92,10 @@ impl DecoderState {
         self.ends
             .copy_within(ends_idx..(ends_idx + self.current_field), 0);
 
-        // Get end data index based on the most recent field we've read.
-        let end_data_idx = self.ends[self.current_field - 1];
-
         // Shift data down.
-        self.buffer.copy_within(start_data_idx..end_data_idx, 0);
+        self.buffer.copy_within(start_data_idx..self.buffer_len, 0);
 
-        self.buffer_len = end_data_idx - start_data_idx;
+        self.buffer_len -= start_data_idx;
         self.ends_len = self.current_field;
 
         // Adjust ends to account for shifted data.
@@ -444,4 +438,52 @@ mod tests {
 
         assert_eq!(expected, fields);
     }
+
+    #[test]
+    fn quoted_fields_with_delimiter_and_newline() {
+        let mut decoder = CsvDecoder::new(DialectOptions::default());
+        let mut state = DecoderState::default();
+
+        let input = "a,\"b,c\",d\n\"e\nf\",g,\"h \"\"i\"\"\"\n";
+
+        decoder.decode(input.as_bytes(), &mut state).unwrap();
+
+        assert_eq!(2, state.num_records());
+        assert_eq!(Some(3), state.num_fields());
+
+        let fields: Vec<Vec<_>> = state
+            .completed_records()
+            .iter()
+            .map(|r| r.iter().map(|s| s.unwrap().to_string()).collect())
+            .collect();
+
+        let expected = vec![vec!["a", "b,c", "d"], vec!["e\nf", "g", "h \"i\""]];
+
+        assert_eq!(expected, fields);
+    }
+
+    #[test]
+    fn clear_completed_keeps_partial_field() {
+        let mut decoder = CsvDecoder::new(DialectOptions::default());
+        let mut state = DecoderState::default();
+
+        // Second record ends in the middle of a field.
+        decoder
+            .decode("a,bb\nccc,dd".as_bytes(), &mut state)
+            .unwrap();
+        assert_eq!(1, state.num_records());
+
+        state.clear_completed();
+        assert_eq!(0, state.num_records());
+
+        decoder.decode("dd\n".as_bytes(), &mut state).unwrap();
+
+        let fields: Vec<Vec<_>> = state
+            .completed_records()
+            .iter()
+            .map(|r| r.iter().map(|s| s.unwrap().to_string()).collect())
+            .collect();
+
+        assert_eq!(vec![vec!["ccc", "dddd"]], fields);
+    }
 }
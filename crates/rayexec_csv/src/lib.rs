@@ -41,7 +41,7 @@ impl<R: Runtime> DataSource for CsvDataSource<R> {
     }
 
     fn file_handlers(&self) -> Vec<FileHandler> {
-        let regex = RegexBuilder::new(r"^.*\.(csv)$")
+        let regex = RegexBuilder::new(r"^.*\.(csv)(\.(gz|zst))?$")
             .case_insensitive(true)
             .build()
             .expect("regex to build");
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use bytes::BytesMut;
 use futures::future::BoxFuture;
 use futures::{FutureExt, StreamExt};
 use rayexec_error::{RayexecError, Result};
@@ -9,6 +10,7 @@ use rayexec_execution::arrays::scalar::OwnedScalarValue;
 use rayexec_execution::database::DatabaseContext;
 use rayexec_execution::expr;
 use rayexec_execution::functions::table::{
+    try_compression_from_args,
     try_location_and_access_config_from_args,
     PlannedTableFunction,
     ScanPlanner,
@@ -75,41 +77,64 @@ impl<R: Runtime> ReadCsv<R> {
     ) -> Result<PlannedTableFunction> {
         let (location, conf) =
             try_location_and_access_config_from_args(&self, &positional_inputs, &named_inputs)?;
+        let compression = try_compression_from_args(&location, &named_inputs)?;
 
-        let mut source = self
+        let source = self
             .runtime
             .file_provider()
             .file_source(location.clone(), &conf)?;
+        let mut source = compression.wrap_source(source);
 
-        let mut stream = source.read_stream();
-        // TODO: Actually make sure this is a sufficient size to infer from.
+        // Buffer up enough of the file to get a reasonable sample of records
+        // to infer from.
+        //
         // TODO: This throws away the buffer after inferring.
-        let infer_buf = match stream.next().await {
-            Some(result) => {
-                const INFER_SIZE: usize = 1024;
-                let buf = result?;
-                if buf.len() > INFER_SIZE {
-                    buf.slice(0..INFER_SIZE)
-                } else {
-                    buf
-                }
+        const INFER_SIZE: usize = 16 * 1024;
+        let mut stream = source.read_stream();
+        let mut infer_buf = BytesMut::new();
+        while infer_buf.len() < INFER_SIZE {
+            match stream.next().await {
+                Some(result) => infer_buf.extend_from_slice(&result?),
+                None => break,
+            }
+        }
+        if infer_buf.is_empty() {
+            return Err(RayexecError::new("Stream returned no data"));
+        }
+        infer_buf.truncate(INFER_SIZE);
+
+        let delimiter = try_get_single_byte_named(&self, "delimiter", &named_inputs)?;
+        let quote = try_get_single_byte_named(&self, "quote", &named_inputs)?;
+        let null_string = match named_inputs.get("null_string") {
+            Some(v) => Some(v.try_as_str()?.to_string()),
+            None => None,
+        };
+
+        // Only infer the dialect if the user didn't specify one.
+        let dialect = if delimiter.is_none() && quote.is_none() {
+            DialectOptions::infer_from_sample(&infer_buf)?
+        } else {
+            let default = DialectOptions::default();
+            DialectOptions {
+                delimiter: delimiter.unwrap_or(default.delimiter),
+                quote: quote.unwrap_or(default.quote),
             }
-            None => return Err(RayexecError::new("Stream returned no data")),
         };
 
-        let dialect = DialectOptions::infer_from_sample(&infer_buf)?;
         let mut decoder = CsvDecoder::new(dialect);
         let mut state = DecoderState::default();
         let _ = decoder.decode(&infer_buf, &mut state)?;
         let completed = state.completed_records();
-        let csv_schema = CsvSchema::infer_from_records(completed)?;
+        let csv_schema = CsvSchema::infer_from_records(completed, null_string.as_deref())?;
 
         let schema = csv_schema.schema.clone();
 
         let datatable = SingleFileCsvDataTable {
             options: dialect,
             csv_schema,
+            null_string,
             location,
+            compression,
             conf,
             runtime: self.runtime.clone(),
         };
@@ -124,3 +149,24 @@ impl<R: Runtime> ReadCsv<R> {
         })
     }
 }
+
+/// Try to get an optional named argument that should be a single ASCII
+/// character, e.g. a delimiter.
+fn try_get_single_byte_named(
+    func: &impl TableFunction,
+    name: &str,
+    named: &HashMap<String, OwnedScalarValue>,
+) -> Result<Option<u8>> {
+    let s = match named.get(name) {
+        Some(v) => v.try_as_str()?,
+        None => return Ok(None),
+    };
+
+    match s.as_bytes() {
+        [b] if b.is_ascii() => Ok(Some(*b)),
+        _ => Err(RayexecError::new(format!(
+            "Expected a single character for argument '{name}' to function {}, got '{s}'",
+            func.name()
+        ))),
+    }
+}
@@ -72,14 +72,17 @@ impl DialectOptions {
             let mut decoder = CsvDecoder::new(*dialect);
 
             match decoder.decode(sample_bytes, &mut state) {
-                Ok(DecoderResult::InputExhuasted) | Ok(DecoderResult::Finished) => {
+                Ok(DecoderResult::InputExhuasted)
+                | Ok(DecoderResult::Finished)
+                | Ok(DecoderResult::BufferFull { .. }) => {
                     let decoded_fields = state.num_fields().unwrap_or(0);
                     let completed_records = state.num_records();
 
                     // To be considered the best dialect:
                     //
-                    // - Should decode at least 2 records.
-                    // - Should read the entirety of the input (checked by match).
+                    // - Should decode at least 2 records. A full buffer with
+                    //   fewer records likely means we attempted to read the
+                    //   entirety of the input as one field.
                     // - Should have decoded more number of fields than previous best.
                     if completed_records >= 2 && decoded_fields > best.1 {
                         best = (Some(*dialect), decoded_fields)
@@ -87,10 +90,6 @@ impl DialectOptions {
 
                     // Don't have enough info, try next dialect.
                 }
-                Ok(DecoderResult::BufferFull { .. }) => {
-                    // Means we migh have attempted to read the entirety of the
-                    // input as once field.
-                }
                 Err(_e) => {
                     // Assume all errors indicate inconsistent number of fields
                     // in record.
@@ -252,7 +251,13 @@ impl CsvSchema {
 
     /// Try to infer the schema for a csv input based on some number of input
     /// records.
-    pub fn infer_from_records(records: CompletedRecords) -> Result<Self> {
+    ///
+    /// Fields considered NULL (empty, or matching `null_string`) don't
+    /// contribute to the inferred types.
+    pub fn infer_from_records(
+        records: CompletedRecords,
+        null_string: Option<&str>,
+    ) -> Result<Self> {
         if records.num_completed() == 0 {
             return Err(RayexecError::new(
                 "Unable to infer CSV schema with no records",
@@ -266,11 +271,22 @@ impl CsvSchema {
 
         // Start with most restrictive.
         let mut candidates = vec![CandidateType::Boolean; num_fields];
+        // Track which columns had at least one non-null value in the sample.
+        let mut has_values = vec![false; num_fields];
 
         // Skip first record since it may be a header.
         for record in records.iter().skip(1) {
-            for (candidate, field) in candidates.iter_mut().zip(record.iter()) {
-                candidate.update_from_input(field?);
+            for ((candidate, has_value), field) in candidates
+                .iter_mut()
+                .zip(has_values.iter_mut())
+                .zip(record.iter())
+            {
+                let field = field?;
+                if is_null_field(field, null_string) {
+                    continue;
+                }
+                candidate.update_from_input(field);
+                *has_value = true;
             }
         }
 
@@ -281,7 +297,18 @@ impl CsvSchema {
             .ok_or_else(|| RayexecError::new("missing record 0"))?
             .iter()
             .zip(candidates.iter())
-            .any(|(field, candidate)| !candidate.is_valid(field.unwrap_or_default()));
+            .any(|(field, candidate)| {
+                let field = field.unwrap_or_default();
+                !is_null_field(field, null_string) && !candidate.is_valid(field)
+            });
+
+        // Columns containing only nulls in the sample can't tell us anything
+        // about their type, fall back to the widest type.
+        for (candidate, has_value) in candidates.iter_mut().zip(has_values) {
+            if !has_value {
+                *candidate = CandidateType::Utf8;
+            }
+        }
 
         let fields: Vec<_> = if has_header {
             // Use the names from the header.
@@ -318,6 +345,14 @@ impl CsvSchema {
     }
 }
 
+/// Check if a field should be read as NULL.
+///
+/// Empty fields are always NULL. Fields exactly matching `null_string` (if
+/// provided) are NULL as well.
+fn is_null_field(field: &str, null_string: Option<&str>) -> bool {
+    field.is_empty() || null_string == Some(field)
+}
+
 pub struct AsyncCsvReader {
     stream: AsyncCsvStream,
 }
@@ -327,10 +362,12 @@ impl AsyncCsvReader {
         mut reader: impl FileSource,
         csv_schema: CsvSchema,
         dialect: DialectOptions,
+        null_string: Option<String>,
     ) -> Self {
         let stream = AsyncCsvStream {
             schema: csv_schema.schema,
             skip_header: csv_schema.has_header,
+            null_string,
             stream: reader.read_stream(),
             decoder_state: DecoderState::default(),
             decoder: CsvDecoder::new(dialect),
@@ -360,6 +397,9 @@ struct AsyncCsvStream {
     /// If we should skip the header record.
     skip_header: bool,
 
+    /// Additional string to treat as NULL.
+    null_string: Option<String>,
+
     /// Inner stream for getting bytes.
     stream: BoxStream<'static, Result<Bytes>>,
 
@@ -417,8 +457,9 @@ impl AsyncCsvStream {
                 DecoderResult::InputExhuasted => continue, // To next iteration of outer loop.
                 DecoderResult::BufferFull { input_offset } => {
                     // Need to flush out buffer. Store for later use.
+                    // Offset is relative to the slice we passed in.
                     self.buf = Some(buf);
-                    self.buf_offset = input_offset;
+                    self.buf_offset = offset + input_offset;
                 }
             }
 
@@ -442,7 +483,12 @@ impl AsyncCsvStream {
                 ));
             }
 
-            let batch = Self::build_batch(completed, &self.schema, self.skip_header)?;
+            let batch = Self::build_batch(
+                completed,
+                &self.schema,
+                self.skip_header,
+                self.null_string.as_deref(),
+            )?;
             self.skip_header = false;
 
             self.decoder_state.clear_completed();
@@ -455,18 +501,22 @@ impl AsyncCsvStream {
         completed: CompletedRecords,
         schema: &Schema,
         skip_header: bool,
+        null_string: Option<&str>,
     ) -> Result<Batch> {
         let skip_records = if skip_header { 1 } else { 0 };
 
         let mut arrs = Vec::with_capacity(schema.fields.len());
         for (idx, field) in schema.fields.iter().enumerate() {
             let arr = match &field.datatype {
-                DataType::Boolean => Self::build_boolean(&completed, idx, skip_records)?,
+                DataType::Boolean => {
+                    Self::build_boolean(&completed, idx, skip_records, null_string)?
+                }
                 DataType::Int64 => Self::build_primitive(
                     &field.datatype,
                     &completed,
                     idx,
                     skip_records,
+                    null_string,
                     Int64Parser::new(),
                 )?,
                 DataType::Float64 => Self::build_primitive(
@@ -474,9 +524,10 @@ impl AsyncCsvStream {
                     &completed,
                     idx,
                     skip_records,
+                    null_string,
                     Float64Parser::new(),
                 )?,
-                DataType::Utf8 => Self::build_utf8(&completed, idx, skip_records)?,
+                DataType::Utf8 => Self::build_utf8(&completed, idx, skip_records, null_string)?,
                 other => return Err(RayexecError::new(format!("Unhandled data type: {other}"))),
             };
 
@@ -490,13 +541,14 @@ impl AsyncCsvStream {
         completed: &CompletedRecords,
         field_idx: usize,
         skip_records: usize,
+        null_string: Option<&str>,
     ) -> Result<Array> {
         let mut values = Bitmap::with_capacity(completed.num_completed());
         let mut validity = Bitmap::with_capacity(completed.num_completed());
 
         for record in completed.iter().skip(skip_records) {
             let field = record.get_field(field_idx)?;
-            if field.is_empty() {
+            if is_null_field(field, null_string) {
                 values.push(false);
                 validity.push(false);
             } else {
@@ -519,6 +571,7 @@ impl AsyncCsvStream {
         completed: &CompletedRecords,
         field_idx: usize,
         skip_records: usize,
+        null_string: Option<&str>,
         mut parser: P,
     ) -> Result<Array>
     where
@@ -531,7 +584,7 @@ impl AsyncCsvStream {
 
         for record in completed.iter().skip(skip_records) {
             let field = record.get_field(field_idx)?;
-            if field.is_empty() {
+            if is_null_field(field, null_string) {
                 values.push(T::default());
                 validity.push(false);
             } else {
@@ -555,13 +608,14 @@ impl AsyncCsvStream {
         completed: &CompletedRecords,
         field_idx: usize,
         skip_records: usize,
+        null_string: Option<&str>,
     ) -> Result<Array> {
         let mut values = GermanVarlenBuffer::with_len(completed.num_completed() - skip_records);
         let mut validity = Bitmap::with_capacity(completed.num_completed());
 
         for (idx, record) in completed.iter().skip(skip_records).enumerate() {
             let field = record.get_field(field_idx)?;
-            if field.is_empty() {
+            if is_null_field(field, null_string) {
                 validity.push(false);
             } else {
                 values.put(idx, field);
@@ -576,3 +630,44 @@ impl AsyncCsvStream {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn infer_schema(input: &str, null_string: Option<&str>) -> CsvSchema {
+        let mut decoder = CsvDecoder::new(DialectOptions::default());
+        let mut state = DecoderState::default();
+        decoder.decode(input.as_bytes(), &mut state).unwrap();
+
+        CsvSchema::infer_from_records(state.completed_records(), null_string).unwrap()
+    }
+
+    #[test]
+    fn infer_ignores_null_fields() {
+        let schema = infer_schema("a,b,c\n1,NA,\nNA,2.5,\n3,4.5,\n", Some("NA"));
+
+        assert!(schema.has_header);
+        let types: Vec<_> = schema
+            .schema
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.datatype.clone()))
+            .collect();
+        let expected = vec![
+            ("a", DataType::Int64),
+            ("b", DataType::Float64),
+            ("c", DataType::Utf8),
+        ];
+        assert_eq!(expected, types);
+    }
+
+    #[test]
+    fn infer_null_string_without_option() {
+        let schema = infer_schema("a,b\n1,NA\n2,3\n", None);
+
+        assert!(schema.has_header);
+        assert_eq!(DataType::Int64, schema.schema.fields[0].datatype);
+        assert_eq!(DataType::Utf8, schema.schema.fields[1].datatype);
+    }
+}
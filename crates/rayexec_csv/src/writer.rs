@@ -1,52 +1,153 @@
 use std::io::Write as _;
 
 use csv::ByteRecord;
-use rayexec_error::{Result, ResultExt};
+use rayexec_error::{RayexecError, Result, ResultExt};
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Schema;
 use rayexec_execution::arrays::format::{FormatOptions, Formatter};
+use rayexec_execution::arrays::scalar::OwnedScalarValue;
+use rayexec_execution::functions::copy::CopyToArgs;
 
 use crate::reader::DialectOptions;
 
+/// Default number of bytes to buffer before flushing.
+pub const DEFAULT_FLUSH_SIZE: usize = 1024 * 1024;
+
+/// Options for writing out csv files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvWriteOptions {
+    /// Dialect of csv we're writing.
+    pub dialect: DialectOptions,
+
+    /// If we should write the column names as the first record.
+    pub header: bool,
+
+    /// String to write for NULL values.
+    pub null_string: String,
+
+    /// Number of encoded bytes to buffer before flushing to the underlying
+    /// sink.
+    pub flush_size: usize,
+}
+
+impl Default for CsvWriteOptions {
+    fn default() -> Self {
+        CsvWriteOptions {
+            dialect: DialectOptions::default(),
+            header: true,
+            null_string: String::new(),
+            flush_size: DEFAULT_FLUSH_SIZE,
+        }
+    }
+}
+
+impl CsvWriteOptions {
+    /// Create write options from the arguments provided to COPY TO.
+    ///
+    /// Accepts `header`, `delimiter`, `quote`, and `null`. Errors on any other
+    /// argument.
+    pub fn try_from_args(args: &CopyToArgs) -> Result<Self> {
+        let mut opts = CsvWriteOptions::default();
+
+        for (key, val) in &args.named {
+            match key.as_str() {
+                "header" => opts.header = val.try_as_bool()?,
+                "delimiter" => opts.dialect.delimiter = try_single_byte(key, val)?,
+                "quote" => opts.dialect.quote = try_single_byte(key, val)?,
+                "null" => opts.null_string = val.try_as_str()?.to_string(),
+                other => {
+                    return Err(RayexecError::new(format!(
+                        "Unsupported COPY TO option for csv: '{other}'"
+                    )))
+                }
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+fn try_single_byte(key: &str, val: &OwnedScalarValue) -> Result<u8> {
+    let s = val.try_as_str()?;
+    match s.as_bytes() {
+        [b] if b.is_ascii() => Ok(*b),
+        _ => Err(RayexecError::new(format!(
+            "Expected a single character for COPY TO option '{key}', got '{s}'"
+        ))),
+    }
+}
+
+/// Formatted values for a single column in a batch.
+#[derive(Debug, Default)]
+struct ColumnBuffer {
+    /// Formatted values, concatenated.
+    values: Vec<u8>,
+    /// Offsets into `values` for each row, with an additional trailing offset.
+    offsets: Vec<usize>,
+}
+
+impl ColumnBuffer {
+    fn clear(&mut self) {
+        self.values.clear();
+        self.offsets.clear();
+        self.offsets.push(0);
+    }
+
+    fn value(&self, row: usize) -> &[u8] {
+        &self.values[self.offsets[row]..self.offsets[row + 1]]
+    }
+}
+
+/// Encodes batches as csv.
+///
+/// Each column in a batch is formatted in one pass into a reusable buffer, with
+/// records then being assembled from the formatted columns.
 #[derive(Debug)]
 pub struct CsvEncoder {
     /// Schema of the batches we're writing. Used to write the header out.
     schema: Schema,
 
-    /// If we've already written the header.
+    /// If we've already written the header, or don't need to write one.
     did_write_header: bool,
 
-    /// Dialect of csv we're writing.
-    dialect: DialectOptions,
+    /// Options for the csv we're writing.
+    options: CsvWriteOptions,
 
-    /// Buffer used for formatting the batch.
-    format_buf: Vec<u8>,
+    /// Buffers for the formatted values of each column.
+    columns: Vec<ColumnBuffer>,
 
     /// Buffer for current record.
     record: ByteRecord,
 }
 
 impl CsvEncoder {
-    pub fn new(schema: Schema, dialect: DialectOptions) -> Self {
+    pub fn new(schema: Schema, options: CsvWriteOptions) -> Self {
         let record = ByteRecord::with_capacity(1024, schema.fields.len());
+        let columns = (0..schema.fields.len())
+            .map(|_| ColumnBuffer::default())
+            .collect();
         CsvEncoder {
             schema,
-            dialect,
-            did_write_header: false,
-            format_buf: Vec::with_capacity(1024),
+            did_write_header: !options.header,
+            options,
+            columns,
             record,
         }
     }
 
     pub fn encode(&mut self, batch: &Batch, output_buf: &mut Vec<u8>) -> Result<()> {
-        const FORMATTER: Formatter = Formatter::new(FormatOptions::new());
+        let formatter = Formatter::new(FormatOptions {
+            null: &self.options.null_string,
+            empty_string: "",
+        });
 
         let mut csv_writer = csv::WriterBuilder::new()
-            .delimiter(self.dialect.delimiter)
-            .quote(self.dialect.quote)
+            .delimiter(self.options.dialect.delimiter)
+            .quote(self.options.dialect.quote)
             .from_writer(output_buf);
 
         if !self.did_write_header {
+            self.record.clear();
             for col_name in self.schema.fields.iter().map(|f| &f.name) {
                 self.record.push_field(col_name.as_bytes());
             }
@@ -57,17 +158,21 @@ impl CsvEncoder {
             self.did_write_header = true;
         }
 
-        for row in 0..batch.num_rows() {
-            self.record.clear();
-
-            for col in batch.columns() {
-                let scalar = FORMATTER
+        for (col, buf) in batch.columns().iter().zip(&mut self.columns) {
+            buf.clear();
+            for row in 0..batch.num_rows() {
+                let scalar = formatter
                     .format_array_value(col, row)
                     .expect("row to exist");
-                self.format_buf.clear();
-                write!(&mut self.format_buf, "{}", scalar).expect("write to succeed");
+                write!(&mut buf.values, "{}", scalar).expect("write to succeed");
+                buf.offsets.push(buf.values.len());
+            }
+        }
 
-                self.record.push_field(&self.format_buf);
+        for row in 0..batch.num_rows() {
+            self.record.clear();
+            for buf in &self.columns {
+                self.record.push_field(buf.value(row));
             }
 
             csv_writer
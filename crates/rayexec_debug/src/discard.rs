@@ -3,7 +3,7 @@ use rayexec_error::Result;
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Schema;
 use rayexec_execution::execution::operators::sink::PartitionSink;
-use rayexec_execution::functions::copy::CopyToFunction;
+use rayexec_execution::functions::copy::{CopyToArgs, CopyToFunction};
 use rayexec_io::location::FileLocation;
 
 /// COPY TO function implementation that discards all input.
@@ -19,6 +19,7 @@ impl CopyToFunction for DiscardCopyToFunction {
         &self,
         _schema: Schema,
         _location: FileLocation,
+        _args: CopyToArgs,
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>> {
         let sinks = (0..num_partitions)
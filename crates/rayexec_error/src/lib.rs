@@ -33,6 +33,10 @@ struct RayexecErrorInner {
     pub backtrace: Backtrace,
     /// Extra error fields to display.
     pub extra_fields: Vec<ErrorField>,
+    /// Explicitly set if this error is retriable.
+    ///
+    /// If None, retriability is determined by inspecting the source chain.
+    pub retriable: Option<bool>,
 }
 
 pub trait ErrorFieldValue: fmt::Debug + fmt::Display + Sync + Send {}
@@ -53,6 +57,7 @@ impl RayexecError {
                 source: None,
                 backtrace: Backtrace::capture(),
                 extra_fields: Vec::new(),
+                retriable: None,
             }),
         }
     }
@@ -64,6 +69,7 @@ impl RayexecError {
                 source: Some(source),
                 backtrace: Backtrace::capture(),
                 extra_fields: Vec::new(),
+                retriable: None,
             }),
         }
     }
@@ -96,6 +102,44 @@ impl RayexecError {
         self
     }
 
+    /// Explicitly mark this error as retriable or not, overriding the
+    /// classification based on the error's source.
+    ///
+    /// Data sources that know a failure is transient (e.g. a request timing
+    /// out) should set this.
+    pub fn with_retriable(mut self, retriable: bool) -> Self {
+        self.inner.retriable = Some(retriable);
+        self
+    }
+
+    /// Check if the operation that produced this error may succeed if retried.
+    ///
+    /// Errors explicitly marked with `with_retriable` use that value.
+    /// Otherwise the source chain is checked for IO errors that are typically
+    /// transient, like timeouts and connection resets.
+    pub fn is_retriable(&self) -> bool {
+        if let Some(retriable) = self.inner.retriable {
+            return retriable;
+        }
+
+        let mut source = self.source();
+        while let Some(err) = source {
+            if let Some(err) = err.downcast_ref::<RayexecError>() {
+                // Nested error, defer to it since it'll inspect its own
+                // sources.
+                return err.is_retriable();
+            }
+            if let Some(err) = err.downcast_ref::<std::io::Error>() {
+                if is_transient_io_error_kind(err.kind()) {
+                    return true;
+                }
+            }
+            source = err.source();
+        }
+
+        false
+    }
+
     pub fn get_msg(&self) -> &str {
         self.inner.msg.as_str()
     }
@@ -123,14 +167,26 @@ impl From<erased_serde::Error> for RayexecError {
     }
 }
 
-// TODO: This loses a bit of context surrounding the source of the error. What
-// was the value? What were we converting to?
-//
-// Likely this should be removed once we're in the polishing phase.
-impl From<std::num::TryFromIntError> for RayexecError {
-    fn from(value: std::num::TryFromIntError) -> Self {
-        Self::with_source("Int convert error", Box::new(value))
-    }
+fn is_transient_io_error_kind(kind: std::io::ErrorKind) -> bool {
+    use std::io::ErrorKind;
+
+    matches!(
+        kind,
+        ErrorKind::TimedOut
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::BrokenPipe
+            | ErrorKind::Interrupted
+    )
+}
+
+/// Create an error for an integer that couldn't be converted to the target
+/// type without overflowing.
+///
+/// Should be used in place of propagating `TryFromIntError` directly since that
+/// error doesn't include the value or type being converted to.
+pub fn int_conversion_error(value: impl fmt::Display, target: &'static str) -> RayexecError {
+    RayexecError::new(format!("Cannot convert {value} to {target}"))
 }
 
 impl fmt::Display for RayexecError {
@@ -197,3 +253,59 @@ impl<T> OptionExt<T> for Option<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retriable_io_timeout() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out");
+        let err = RayexecError::with_source("Failed to read range", Box::new(io_err));
+        assert!(err.is_retriable());
+
+        // Nested in another error.
+        let err = RayexecError::with_source("Failed to scan", Box::new(err));
+        assert!(err.is_retriable());
+
+        // Conversion through `From`.
+        let io_err = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        assert!(RayexecError::from(io_err).is_retriable());
+    }
+
+    #[test]
+    fn not_retriable() {
+        let err = RayexecError::new("Missing column for reference: a");
+        assert!(!err.is_retriable());
+
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
+        let err = RayexecError::with_source("Failed to open file", Box::new(io_err));
+        assert!(!err.is_retriable());
+    }
+
+    #[test]
+    fn retriable_explicit() {
+        let err = RayexecError::new("Request timed out").with_retriable(true);
+        assert!(err.is_retriable());
+
+        let io_err = std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out");
+        let err = RayexecError::with_source("Failed to read range", Box::new(io_err))
+            .with_retriable(false);
+        assert!(!err.is_retriable());
+    }
+
+    #[test]
+    fn int_conversion_error_message() {
+        let err = i8::try_from(300_i32)
+            .map_err(|_| int_conversion_error(300, "i8"))
+            .unwrap_err();
+        assert_eq!("Cannot convert 300 to i8", err.get_msg());
+
+        let value = u128::MAX;
+        let err = u64::try_from(value)
+            .map_err(|_| int_conversion_error(value, "u64"))
+            .unwrap_err();
+        assert!(err.get_msg().contains(&value.to_string()));
+        assert!(err.get_msg().contains("u64"));
+    }
+}
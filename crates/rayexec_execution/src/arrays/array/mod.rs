@@ -47,8 +47,10 @@ use crate::arrays::storage::{
     BooleanStorage,
     ContiguousVarlenStorage,
     GermanVarlenStorage,
+    ListItemMetadata,
     ListStorage,
     PrimitiveStorage,
+    StructStorage,
     UntypedNullStorage,
 };
 
@@ -99,7 +101,7 @@ impl Array {
     pub fn new_typed_null_array(datatype: DataType, len: usize) -> Result<Self> {
         // Create physical array data of length 1, and use a selection vector to
         // extend it out to the desired size.
-        let data = datatype.physical_type()?.zeroed_array_data(1);
+        let data = ArrayData::zeroed(&datatype, 1)?;
         let validity = Bitmap::new_with_all_false(1);
         let selection = SelectionVector::repeated(len, 0);
 
@@ -451,6 +453,7 @@ impl Array {
                 }
             }
             ArrayData::List(_) => Err(RayexecError::new("Cannot yet unselect list arrays")),
+            ArrayData::Struct(_) => Err(RayexecError::new("Cannot yet unselect struct arrays")),
         }
     }
 
@@ -585,7 +588,22 @@ impl Array {
                 };
                 v.into()
             }
-            DataType::Struct(_) => not_implemented!("get value: struct"),
+            DataType::Struct(_) => match &self.data {
+                ArrayData::Struct(storage) => {
+                    if idx >= storage.len() {
+                        return Err(RayexecError::new("Out of bounds"));
+                    }
+
+                    let vals = storage
+                        .arrays
+                        .iter()
+                        .map(|arr| arr.logical_value(idx))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    ScalarValue::Struct(vals)
+                }
+                _other => return Err(array_not_valid_for_type_err(&self.datatype)),
+            },
             DataType::List(_) => match &self.data {
                 ArrayData::List(list) => {
                     let meta = list
@@ -595,13 +613,38 @@ impl Array {
                         .ok_or_else(|| RayexecError::new("Out of bounds"))?;
 
                     let vals = (meta.offset..meta.offset + meta.len)
-                        .map(|idx| list.array.physical_scalar(idx as usize))
+                        .map(|idx| list.array.logical_value(idx as usize))
                         .collect::<Result<Vec<_>>>()?;
 
                     ScalarValue::List(vals)
                 }
                 _other => return Err(array_not_valid_for_type_err(&self.datatype)),
             },
+            DataType::Map(_) => match &self.data {
+                ArrayData::List(list) => {
+                    let meta = list
+                        .metadata
+                        .as_slice()
+                        .get(idx)
+                        .ok_or_else(|| RayexecError::new("Out of bounds"))?;
+
+                    let entries = (meta.offset..meta.offset + meta.len)
+                        .map(|idx| match list.array.logical_value(idx as usize)? {
+                            ScalarValue::Struct(mut entry) if entry.len() == 2 => {
+                                let value = entry.pop().unwrap();
+                                let key = entry.pop().unwrap();
+                                Ok((key, value))
+                            }
+                            other => Err(RayexecError::new(format!(
+                                "Expected key/value struct for map entry, got {other}"
+                            ))),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                    ScalarValue::Map(entries)
+                }
+                _other => return Err(array_not_valid_for_type_err(&self.datatype)),
+            },
         })
     }
 
@@ -910,9 +953,31 @@ pub enum ArrayData {
     Interval(Arc<PrimitiveStorage<Interval>>),
     Binary(BinaryData),
     List(Arc<ListStorage>),
+    Struct(Arc<StructStorage>),
 }
 
 impl ArrayData {
+    /// Create zeroed array data for a data type.
+    ///
+    /// Unlike `PhysicalType::zeroed_array_data`, list storage gets an empty
+    /// child array of the list's element type, allowing it to be concatenated
+    /// with other lists of the same type.
+    pub fn zeroed(datatype: &DataType, len: usize) -> Result<Self> {
+        let child_datatype = match datatype {
+            DataType::List(meta) => meta.datatype.as_ref().clone(),
+            DataType::Map(meta) => meta.entries_datatype(),
+            other => return Ok(other.physical_type()?.zeroed_array_data(len)),
+        };
+
+        let child_data = ArrayData::zeroed(&child_datatype, 0)?;
+
+        Ok(ListStorage {
+            metadata: vec![ListItemMetadata::default(); len].into(),
+            array: Array::new_with_array_data(child_datatype, child_data),
+        }
+        .into())
+    }
+
     pub fn physical_type(&self) -> PhysicalType {
         match self {
             Self::UntypedNull(_) => PhysicalType::UntypedNull,
@@ -933,6 +998,7 @@ impl ArrayData {
             Self::Interval(_) => PhysicalType::Interval,
             Self::Binary(_) => PhysicalType::Binary,
             Self::List(_) => PhysicalType::List,
+            Self::Struct(_) => PhysicalType::Struct,
         }
     }
 
@@ -960,6 +1026,7 @@ impl ArrayData {
                 BinaryData::German(s) => s.len(),
             },
             ArrayData::List(s) => s.len(),
+            ArrayData::Struct(s) => s.len(),
         }
     }
 
@@ -1096,6 +1163,12 @@ impl From<ListStorage> for ArrayData {
     }
 }
 
+impl From<StructStorage> for ArrayData {
+    fn from(value: StructStorage) -> Self {
+        ArrayData::Struct(Arc::new(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1,7 +1,8 @@
 use std::ops::Mul;
 
 use half::f16;
-use num::{CheckedDiv, CheckedMul, Float, NumCast, PrimInt, ToPrimitive};
+use num::traits::AsPrimitive;
+use num::{CheckedDiv, CheckedMul, Float, NumCast, One, PrimInt, ToPrimitive, Zero};
 use rayexec_error::{RayexecError, Result};
 
 use super::behavior::CastFailBehavior;
@@ -42,6 +43,7 @@ use super::parse::{
     Int8Parser,
     IntervalParser,
     Parser,
+    TimestampParser,
     UInt128Parser,
     UInt16Parser,
     UInt32Parser,
@@ -50,6 +52,7 @@ use super::parse::{
 };
 use crate::arrays::array::{Array, ArrayData};
 use crate::arrays::bitmap::Bitmap;
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::datatype::{DataType, TimeUnit};
 use crate::arrays::executor::builder::{
     ArrayBuilder,
@@ -80,6 +83,17 @@ use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType}
 use crate::arrays::storage::{AddressableStorage, PrimitiveStorage};
 
 pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Result<Array> {
+    cast_array_with_rounding(arr, to, behavior, RoundingMode::default())
+}
+
+/// Cast an array, using `rounding` for casts that lose precision (float to
+/// integer, float to decimal, or reducing the scale of a decimal).
+pub fn cast_array_with_rounding(
+    arr: &Array,
+    to: DataType,
+    behavior: CastFailBehavior,
+    rounding: RoundingMode,
+) -> Result<Array> {
     if arr.datatype() == &to {
         // TODO: Cow?
         return Ok(arr.clone());
@@ -88,7 +102,7 @@ pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Resu
     let arr = match arr.datatype() {
         DataType::Null => {
             // Can cast NULL to anything else.
-            let data = to.physical_type()?.zeroed_array_data(arr.logical_len());
+            let data = ArrayData::zeroed(&to, arr.logical_len())?;
             let validity = Bitmap::new_with_all_false(arr.logical_len());
             Array::new_with_validity_and_array_data(to, validity, data)
         }
@@ -96,6 +110,17 @@ pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Resu
         // String to anything else.
         DataType::Utf8 => cast_from_utf8(arr, to, behavior)?,
 
+        // Float to integer, rounding away the fractional part.
+        DataType::Float16 if to.is_integer() => {
+            cast_float_to_integer_helper::<PhysicalF16>(arr, to, behavior, rounding)?
+        }
+        DataType::Float32 if to.is_integer() => {
+            cast_float_to_integer_helper::<PhysicalF32>(arr, to, behavior, rounding)?
+        }
+        DataType::Float64 if to.is_integer() => {
+            cast_float_to_integer_helper::<PhysicalF64>(arr, to, behavior, rounding)?
+        }
+
         // Primitive numerics to other primitive numerics.
         DataType::Int8 if to.is_primitive_numeric() => {
             cast_primitive_numeric_helper::<PhysicalI8>(arr, to, behavior)?
@@ -188,18 +213,18 @@ pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Resu
 
         // Float to decimal.
         DataType::Float32 if to.is_decimal() => {
-            cast_float_to_decimal_helper::<PhysicalF32>(arr, to, behavior)?
+            cast_float_to_decimal_helper::<PhysicalF32>(arr, to, behavior, rounding)?
         }
         DataType::Float64 if to.is_decimal() => {
-            cast_float_to_decimal_helper::<PhysicalF64>(arr, to, behavior)?
+            cast_float_to_decimal_helper::<PhysicalF64>(arr, to, behavior, rounding)?
         }
 
         // Decimal to decimal
         DataType::Decimal64(_) if to.is_decimal() => {
-            decimal_rescale_helper::<PhysicalI64>(arr, to, behavior)?
+            decimal_rescale_helper::<PhysicalI64>(arr, to, behavior, rounding)?
         }
         DataType::Decimal128(_) if to.is_decimal() => {
-            decimal_rescale_helper::<PhysicalI128>(arr, to, behavior)?
+            decimal_rescale_helper::<PhysicalI128>(arr, to, behavior, rounding)?
         }
 
         // Decimal to float.
@@ -214,6 +239,26 @@ pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Resu
             other => return Err(RayexecError::new(format!("Unhandled data type: {other}"))),
         },
 
+        // Primitive numerics to boolean.
+        DataType::Int8 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalI8>(arr)?,
+        DataType::Int16 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalI16>(arr)?,
+        DataType::Int32 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalI32>(arr)?,
+        DataType::Int64 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalI64>(arr)?,
+        DataType::Int128 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalI128>(arr)?,
+        DataType::UInt8 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalU8>(arr)?,
+        DataType::UInt16 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalU16>(arr)?,
+        DataType::UInt32 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalU32>(arr)?,
+        DataType::UInt64 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalU64>(arr)?,
+        DataType::UInt128 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalU128>(arr)?,
+        DataType::Float16 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalF16>(arr)?,
+        DataType::Float32 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalF32>(arr)?,
+        DataType::Float64 if to == DataType::Boolean => cast_numeric_to_bool::<PhysicalF64>(arr)?,
+
+        // Boolean to primitive numerics.
+        DataType::Boolean if to.is_primitive_numeric() => {
+            cast_bool_to_primitive_numeric_helper(arr, to)?
+        }
+
         // Anything to string.
         _ if to.is_utf8() => cast_to_utf8(arr, behavior)?,
 
@@ -231,14 +276,17 @@ fn decimal_rescale_helper<'a, S>(
     arr: &'a Array,
     to: DataType,
     behavior: CastFailBehavior,
+    rounding: RoundingMode,
 ) -> Result<Array>
 where
     S: PhysicalStorage,
     S::Type<'a>: PrimInt,
 {
     match to {
-        DataType::Decimal64(_) => decimal_rescale::<S, Decimal64Type>(arr, to, behavior),
-        DataType::Decimal128(_) => decimal_rescale::<S, Decimal128Type>(arr, to, behavior),
+        DataType::Decimal64(_) => decimal_rescale::<S, Decimal64Type>(arr, to, behavior, rounding),
+        DataType::Decimal128(_) => {
+            decimal_rescale::<S, Decimal128Type>(arr, to, behavior, rounding)
+        }
         other => Err(RayexecError::new(format!("Unhandled data type: {other}"))),
     }
 }
@@ -247,6 +295,7 @@ pub fn decimal_rescale<'a, S, D>(
     arr: &'a Array,
     to: DataType,
     behavior: CastFailBehavior,
+    rounding: RoundingMode,
 ) -> Result<Array>
 where
     S: PhysicalStorage,
@@ -285,10 +334,7 @@ where
                     None => fail_state.set_did_fail(buf.idx),
                 }
             } else {
-                match v.checked_div(&scale_amount) {
-                    Some(v) => buf.put(&v),
-                    None => fail_state.set_did_fail(buf.idx),
-                }
+                buf.put(&rounding.round_div(v, scale_amount))
             }
         },
     )?;
@@ -300,14 +346,19 @@ fn cast_float_to_decimal_helper<'a, S>(
     arr: &'a Array,
     to: DataType,
     behavior: CastFailBehavior,
+    rounding: RoundingMode,
 ) -> Result<Array>
 where
     S: PhysicalStorage,
     S::Type<'a>: Float,
 {
     match to {
-        DataType::Decimal64(_) => cast_float_to_decimal::<S, Decimal64Type>(arr, to, behavior),
-        DataType::Decimal128(_) => cast_float_to_decimal::<S, Decimal128Type>(arr, to, behavior),
+        DataType::Decimal64(_) => {
+            cast_float_to_decimal::<S, Decimal64Type>(arr, to, behavior, rounding)
+        }
+        DataType::Decimal128(_) => {
+            cast_float_to_decimal::<S, Decimal128Type>(arr, to, behavior, rounding)
+        }
         other => Err(RayexecError::new(format!("Unhandled data type: {other}"))),
     }
 }
@@ -316,6 +367,7 @@ fn cast_float_to_decimal<'a, S, D>(
     arr: &'a Array,
     to: DataType,
     behavior: CastFailBehavior,
+    rounding: RoundingMode,
 ) -> Result<Array>
 where
     S: PhysicalStorage,
@@ -341,7 +393,7 @@ where
         },
         |v, buf| {
             // TODO: Properly handle negative scale.
-            let scaled_value = v.mul(scale).round();
+            let scaled_value = rounding.round_float(v.mul(scale));
 
             match <D::Primitive as NumCast>::from(scaled_value) {
                 Some(v) => {
@@ -534,6 +586,288 @@ where
     fail_state.check_and_apply(arr, output)
 }
 
+fn cast_float_to_integer_helper<'a, S>(
+    arr: &'a Array,
+    to: DataType,
+    behavior: CastFailBehavior,
+    rounding: RoundingMode,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Float,
+{
+    match to {
+        DataType::Int8 => cast_float_to_integer::<S, i8>(arr, to, behavior, rounding),
+        DataType::Int16 => cast_float_to_integer::<S, i16>(arr, to, behavior, rounding),
+        DataType::Int32 => cast_float_to_integer::<S, i32>(arr, to, behavior, rounding),
+        DataType::Int64 => cast_float_to_integer::<S, i64>(arr, to, behavior, rounding),
+        DataType::Int128 => cast_float_to_integer::<S, i128>(arr, to, behavior, rounding),
+        DataType::UInt8 => cast_float_to_integer::<S, u8>(arr, to, behavior, rounding),
+        DataType::UInt16 => cast_float_to_integer::<S, u16>(arr, to, behavior, rounding),
+        DataType::UInt32 => cast_float_to_integer::<S, u32>(arr, to, behavior, rounding),
+        DataType::UInt64 => cast_float_to_integer::<S, u64>(arr, to, behavior, rounding),
+        DataType::UInt128 => cast_float_to_integer::<S, u128>(arr, to, behavior, rounding),
+        other => Err(RayexecError::new(format!("Unhandled data type: {other}"))),
+    }
+}
+
+/// Cast a float array to an integer type, rounding each value to an integral
+/// value using `rounding` before checking if it fits in the target type.
+fn cast_float_to_integer<'a, S, T>(
+    arr: &'a Array,
+    datatype: DataType,
+    behavior: CastFailBehavior,
+    rounding: RoundingMode,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Float,
+    T: NumCast + Default + Copy,
+    ArrayData: From<PrimitiveStorage<T>>,
+{
+    let mut fail_state = behavior.new_state_for_array(arr);
+    let output = UnaryExecutor::execute::<S, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype,
+            buffer: PrimitiveBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| match T::from(rounding.round_float(v)) {
+            Some(v) => buf.put(&v),
+            None => fail_state.set_did_fail(buf.idx),
+        },
+    )?;
+
+    fail_state.check_and_apply(arr, output)
+}
+
+/// How to produce a value when casting to an integer type that can't hold the
+/// input value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerCastOverflow {
+    /// Keep the low bits of the value (two's complement wrapping).
+    ///
+    /// Only applies to integer inputs, floats that don't fit are treated as a
+    /// failed cast.
+    Wrap,
+    /// Clamp to the minimum or maximum value of the target type.
+    Saturate,
+}
+
+/// Cast a primitive numeric array to an integer type, using `overflow` to
+/// produce values that don't fit in the target type.
+///
+/// Float inputs are rounded to an integral value using `rounding` before
+/// checking if they fit. Values that can't be produced (e.g. NaN) are handled
+/// according to `behavior`.
+pub fn cast_to_integer_with_overflow(
+    arr: &Array,
+    to: DataType,
+    overflow: IntegerCastOverflow,
+    behavior: CastFailBehavior,
+    rounding: RoundingMode,
+) -> Result<Array> {
+    match arr.datatype() {
+        DataType::Int8 => cast_to_integer_with_overflow_helper::<PhysicalI8>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::Int16 => cast_to_integer_with_overflow_helper::<PhysicalI16>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::Int32 => cast_to_integer_with_overflow_helper::<PhysicalI32>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::Int64 => cast_to_integer_with_overflow_helper::<PhysicalI64>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::Int128 => cast_to_integer_with_overflow_helper::<PhysicalI128>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::UInt8 => cast_to_integer_with_overflow_helper::<PhysicalU8>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::UInt16 => cast_to_integer_with_overflow_helper::<PhysicalU16>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::UInt32 => cast_to_integer_with_overflow_helper::<PhysicalU32>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::UInt64 => cast_to_integer_with_overflow_helper::<PhysicalU64>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::UInt128 => cast_to_integer_with_overflow_helper::<PhysicalU128>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::Float16 => cast_to_integer_with_overflow_helper::<PhysicalF16>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::Float32 => cast_to_integer_with_overflow_helper::<PhysicalF32>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        DataType::Float64 => cast_to_integer_with_overflow_helper::<PhysicalF64>(
+            arr, to, overflow, behavior, rounding,
+        ),
+        other => Err(RayexecError::new(format!("Unhandled data type: {other}"))),
+    }
+}
+
+fn cast_to_integer_with_overflow_helper<'a, S>(
+    arr: &'a Array,
+    to: DataType,
+    overflow: IntegerCastOverflow,
+    behavior: CastFailBehavior,
+    rounding: RoundingMode,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: ToPrimitive + Copy,
+{
+    match to {
+        DataType::Int8 => {
+            cast_to_integer_overflowing::<S, i8>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::Int16 => {
+            cast_to_integer_overflowing::<S, i16>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::Int32 => {
+            cast_to_integer_overflowing::<S, i32>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::Int64 => {
+            cast_to_integer_overflowing::<S, i64>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::Int128 => {
+            cast_to_integer_overflowing::<S, i128>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::UInt8 => {
+            cast_to_integer_overflowing::<S, u8>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::UInt16 => {
+            cast_to_integer_overflowing::<S, u16>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::UInt32 => {
+            cast_to_integer_overflowing::<S, u32>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::UInt64 => {
+            cast_to_integer_overflowing::<S, u64>(arr, to, overflow, behavior, rounding)
+        }
+        DataType::UInt128 => {
+            cast_to_integer_overflowing::<S, u128>(arr, to, overflow, behavior, rounding)
+        }
+        other => Err(RayexecError::new(format!("Unhandled data type: {other}"))),
+    }
+}
+
+fn cast_to_integer_overflowing<'a, S, T>(
+    arr: &'a Array,
+    datatype: DataType,
+    overflow: IntegerCastOverflow,
+    behavior: CastFailBehavior,
+    rounding: RoundingMode,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: ToPrimitive + Copy,
+    T: PrimInt + Default + 'static,
+    i128: AsPrimitive<T>,
+    u128: AsPrimitive<T>,
+    ArrayData: From<PrimitiveStorage<T>>,
+{
+    let is_integer_input = arr.datatype().is_integer();
+
+    let mut fail_state = behavior.new_state_for_array(arr);
+    let output = UnaryExecutor::execute::<S, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype,
+            buffer: PrimitiveBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| {
+            let in_range = if is_integer_input {
+                T::from(v)
+            } else {
+                v.to_f64().and_then(|v| T::from(rounding.round_float(v)))
+            };
+
+            let casted = match in_range {
+                Some(v) => Some(v),
+                None => match overflow {
+                    IntegerCastOverflow::Wrap if is_integer_input => v
+                        .to_i128()
+                        .map(|v| v.as_())
+                        .or_else(|| v.to_u128().map(|v| v.as_())),
+                    IntegerCastOverflow::Wrap => None,
+                    IntegerCastOverflow::Saturate => match v.to_f64() {
+                        Some(f) if f.is_nan() => None,
+                        Some(f) if f < 0.0 => Some(T::min_value()),
+                        Some(_) => Some(T::max_value()),
+                        None => None,
+                    },
+                },
+            };
+
+            match casted {
+                Some(v) => buf.put(&v),
+                None => fail_state.set_did_fail(buf.idx),
+            }
+        },
+    )?;
+
+    fail_state.check_and_apply(arr, output)
+}
+/// Cast a numeric array to booleans, with zero being false and everything else
+/// being true.
+fn cast_numeric_to_bool<'a, S>(arr: &'a Array) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Zero,
+{
+    UnaryExecutor::execute::<S, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype: DataType::Boolean,
+            buffer: BooleanBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| buf.put(&!v.is_zero()),
+    )
+}
+
+fn cast_bool_to_primitive_numeric_helper(arr: &Array, to: DataType) -> Result<Array> {
+    match to {
+        DataType::Int8 => cast_bool_to_primitive_numeric::<i8>(arr, to),
+        DataType::Int16 => cast_bool_to_primitive_numeric::<i16>(arr, to),
+        DataType::Int32 => cast_bool_to_primitive_numeric::<i32>(arr, to),
+        DataType::Int64 => cast_bool_to_primitive_numeric::<i64>(arr, to),
+        DataType::Int128 => cast_bool_to_primitive_numeric::<i128>(arr, to),
+        DataType::UInt8 => cast_bool_to_primitive_numeric::<u8>(arr, to),
+        DataType::UInt16 => cast_bool_to_primitive_numeric::<u16>(arr, to),
+        DataType::UInt32 => cast_bool_to_primitive_numeric::<u32>(arr, to),
+        DataType::UInt64 => cast_bool_to_primitive_numeric::<u64>(arr, to),
+        DataType::UInt128 => cast_bool_to_primitive_numeric::<u128>(arr, to),
+        DataType::Float16 => cast_bool_to_primitive_numeric::<f16>(arr, to),
+        DataType::Float32 => cast_bool_to_primitive_numeric::<f32>(arr, to),
+        DataType::Float64 => cast_bool_to_primitive_numeric::<f64>(arr, to),
+        other => Err(RayexecError::new(format!("Unhandled data type: {other}"))),
+    }
+}
+
+/// Cast a boolean array to a numeric type, with true being 1 and false being
+/// 0.
+fn cast_bool_to_primitive_numeric<T>(arr: &Array, datatype: DataType) -> Result<Array>
+where
+    T: Zero + One + Default + Copy,
+    ArrayData: From<PrimitiveStorage<T>>,
+{
+    let (zero, one) = (T::zero(), T::one());
+    UnaryExecutor::execute::<PhysicalBool, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype,
+            buffer: PrimitiveBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| buf.put(if v { &one } else { &zero }),
+    )
+}
+
 pub fn cast_from_utf8(
     arr: &Array,
     datatype: DataType,
@@ -575,6 +909,10 @@ pub fn cast_from_utf8(
             Decimal128Parser::new(m.precision, m.scale),
         ),
         DataType::Date32 => cast_parse_primitive(arr, datatype, behavior, Date32Parser),
+        DataType::Timestamp(ref m) => {
+            let unit = m.unit;
+            cast_parse_primitive(arr, datatype, behavior, TimestampParser { unit })
+        }
         DataType::Interval => {
             cast_parse_primitive(arr, datatype, behavior, IntervalParser::default())
         }
@@ -728,7 +1066,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::arrays::datatype::DecimalTypeMeta;
+    use crate::arrays::datatype::{DecimalTypeMeta, TimeUnit, TimestampTypeMeta};
+    use crate::arrays::scalar::timestamp::TimestampScalar;
     use crate::arrays::scalar::ScalarValue;
 
     #[test]
@@ -759,6 +1098,92 @@ mod tests {
         assert_eq!(ScalarValue::Null, got.logical_value(2).unwrap());
     }
 
+    #[test]
+    fn array_cast_utf8_to_date32() {
+        let arr = Array::from_iter(["2023-01-02", "1970-01-01"]);
+
+        let got = cast_array(&arr, DataType::Date32, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(ScalarValue::Date32(19359), got.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::Date32(0), got.logical_value(1).unwrap());
+    }
+
+    #[test]
+    fn array_cast_utf8_to_timestamp() {
+        let arr = Array::from_iter(["2023-01-02T03:04:05", "2023-01-02"]);
+        let datatype = DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond));
+
+        let got = cast_array(&arr, datatype, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(
+            ScalarValue::Timestamp(TimestampScalar {
+                unit: TimeUnit::Microsecond,
+                value: 1_672_628_645_000_000,
+            }),
+            got.logical_value(0).unwrap()
+        );
+        assert_eq!(
+            ScalarValue::Timestamp(TimestampScalar {
+                unit: TimeUnit::Microsecond,
+                value: 1_672_617_600_000_000,
+            }),
+            got.logical_value(1).unwrap()
+        );
+    }
+
+    #[test]
+    fn array_cast_utf8_to_timestamp_malformed() {
+        let arr = Array::from_iter(["2023-01-02", "2023-13-45"]);
+        let datatype = DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond));
+
+        cast_array(&arr, datatype.clone(), CastFailBehavior::Error).unwrap_err();
+
+        let got = cast_array(&arr, datatype, CastFailBehavior::Null).unwrap();
+        assert_eq!(ScalarValue::Null, got.logical_value(1).unwrap());
+    }
+
+    #[test]
+    fn array_cast_numeric_to_bool() {
+        let arr = Array::from_iter([Some(0), Some(1), Some(5), Some(-3), None]);
+
+        let got = cast_array(&arr, DataType::Boolean, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(ScalarValue::from(false), got.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::from(true), got.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::from(true), got.logical_value(2).unwrap());
+        assert_eq!(ScalarValue::from(true), got.logical_value(3).unwrap());
+        assert_eq!(ScalarValue::Null, got.logical_value(4).unwrap());
+    }
+
+    #[test]
+    fn array_cast_float_to_bool() {
+        let arr = Array::from_iter([0.0_f64, 0.5, -0.0]);
+
+        let got = cast_array(&arr, DataType::Boolean, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(ScalarValue::from(false), got.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::from(true), got.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::from(false), got.logical_value(2).unwrap());
+    }
+
+    #[test]
+    fn array_cast_bool_to_numeric() {
+        let arr = Array::from_iter([Some(true), Some(false), None]);
+
+        let got = cast_array(&arr, DataType::Int32, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(&DataType::Int32, got.datatype());
+        assert_eq!(ScalarValue::from(1), got.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::from(0), got.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::Null, got.logical_value(2).unwrap());
+
+        let got = cast_array(&arr, DataType::Float64, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(ScalarValue::Float64(1.0), got.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::Float64(0.0), got.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::Null, got.logical_value(2).unwrap());
+    }
+
     #[test]
     fn array_cast_null_to_f32() {
         let arr = Array::new_untyped_null_array(3);
@@ -788,4 +1213,115 @@ mod tests {
         assert_eq!(ScalarValue::Float64(2.0), got.logical_value(1).unwrap());
         assert_eq!(ScalarValue::Float64(2.5), got.logical_value(2).unwrap());
     }
+
+    #[test]
+    fn array_cast_f64_to_i32_rounding() {
+        let arr = Array::from_iter([1.5_f64, 2.5, -2.5, 1.4]);
+
+        let got = cast_array_with_rounding(
+            &arr,
+            DataType::Int32,
+            CastFailBehavior::Error,
+            RoundingMode::HalfEven,
+        )
+        .unwrap();
+        assert_eq!(Array::from_iter([2, 2, -2, 1]), got);
+
+        let got = cast_array_with_rounding(
+            &arr,
+            DataType::Int32,
+            CastFailBehavior::Error,
+            RoundingMode::HalfUp,
+        )
+        .unwrap();
+        assert_eq!(Array::from_iter([2, 3, -3, 1]), got);
+
+        let got = cast_array_with_rounding(
+            &arr,
+            DataType::Int32,
+            CastFailBehavior::Error,
+            RoundingMode::TowardZero,
+        )
+        .unwrap();
+        assert_eq!(Array::from_iter([1, 2, -2, 1]), got);
+    }
+
+    #[test]
+    fn array_cast_i32_to_i8_overflow_error() {
+        let arr = Array::from_iter([1_i32, 300, -5]);
+        cast_array(&arr, DataType::Int8, CastFailBehavior::Error).unwrap_err();
+    }
+
+    #[test]
+    fn array_cast_i32_to_i8_wrap() {
+        let arr = Array::from_iter([1_i32, 300, -129]);
+
+        let got = cast_to_integer_with_overflow(
+            &arr,
+            DataType::Int8,
+            IntegerCastOverflow::Wrap,
+            CastFailBehavior::Error,
+            RoundingMode::default(),
+        )
+        .unwrap();
+
+        assert_eq!(Array::from_iter([1_i8, 44, 127]), got);
+    }
+
+    #[test]
+    fn array_cast_to_u8_saturate() {
+        let arr = Array::from_iter([1_i64, 300, -5]);
+        let got = cast_to_integer_with_overflow(
+            &arr,
+            DataType::UInt8,
+            IntegerCastOverflow::Saturate,
+            CastFailBehavior::Error,
+            RoundingMode::default(),
+        )
+        .unwrap();
+        assert_eq!(Array::from_iter([1_u8, 255, 0]), got);
+
+        let arr = Array::from_iter([1.5_f64, 1e10, -1e10]);
+        let got = cast_to_integer_with_overflow(
+            &arr,
+            DataType::UInt8,
+            IntegerCastOverflow::Saturate,
+            CastFailBehavior::Error,
+            RoundingMode::HalfEven,
+        )
+        .unwrap();
+        assert_eq!(Array::from_iter([2_u8, 255, 0]), got);
+
+        let got = cast_to_integer_with_overflow(
+            &arr,
+            DataType::UInt8,
+            IntegerCastOverflow::Saturate,
+            CastFailBehavior::Error,
+            RoundingMode::TowardZero,
+        )
+        .unwrap();
+        assert_eq!(Array::from_iter([1_u8, 255, 0]), got);
+    }
+
+    #[test]
+    fn array_cast_float_wrap_fails() {
+        let arr = Array::from_iter([1e10_f64]);
+        cast_to_integer_with_overflow(
+            &arr,
+            DataType::Int32,
+            IntegerCastOverflow::Wrap,
+            CastFailBehavior::Error,
+            RoundingMode::default(),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn array_cast_i32_to_utf8() {
+        let arr = Array::from_iter([13_i32, -18, 0]);
+
+        let got = cast_array(&arr, DataType::Utf8, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(Array::from_iter(["13", "-18", "0"]), got);
+    }
 }
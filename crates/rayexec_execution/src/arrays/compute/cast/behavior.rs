@@ -70,12 +70,15 @@ impl CastFailState {
                 let scalar = original.logical_value(error_idx.idx)?;
                 match error_idx.error {
                     Some(error) => Err(RayexecError::with_source(
-                        format!("Failed to cast '{scalar}' to {}", output.datatype()),
+                        format!(
+                            "Failed to cast '{scalar}' to {}",
+                            output.datatype().sql_name()
+                        ),
                         Box::new(error),
                     )),
                     None => Err(RayexecError::new(format!(
                         "Failed to cast '{scalar}' to {}",
-                        output.datatype()
+                        output.datatype().sql_name()
                     ))),
                 }
             }
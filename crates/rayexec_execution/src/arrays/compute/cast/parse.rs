@@ -3,11 +3,12 @@ use std::fmt::Write;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
-use chrono::{Datelike, NaiveDate};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime};
 use half::f16;
 use num::PrimInt;
 
 use crate::arrays::compute::date::EPOCH_DAYS_FROM_CE;
+use crate::arrays::datatype::TimeUnit;
 use crate::arrays::scalar::interval::Interval;
 
 /// Logic for parsing a string into some type.
@@ -82,6 +83,53 @@ impl Parser for Date32Parser {
     }
 }
 
+/// Parse an ISO-8601 string timestamp into the number of units since epoch.
+///
+/// Strings containing only a date are parsed as midnight on that date. Strings
+/// with a UTC offset are converted to UTC.
+///
+/// Example formats:
+///
+/// '1992-10-11'
+/// '1992-10-11 04:05:06'
+/// '1992-10-11T04:05:06.789'
+/// '1992-10-11T04:05:06+02:00'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampParser {
+    pub unit: TimeUnit,
+}
+
+impl Parser for TimestampParser {
+    type Type = i64;
+    fn parse(&mut self, s: &str) -> Option<Self::Type> {
+        const FORMATS: &[&str] = &[
+            "%Y-%m-%dT%H:%M:%S%.f",
+            "%Y-%m-%d %H:%M:%S%.f",
+            "%Y-%m-%dT%H:%M",
+            "%Y-%m-%d %H:%M",
+        ];
+
+        let datetime = match FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+        {
+            Some(datetime) => datetime,
+            None => match DateTime::parse_from_rfc3339(s) {
+                Ok(datetime) => datetime.naive_utc(),
+                Err(_) => NaiveDate::from_str(s).ok()?.and_hms_opt(0, 0, 0)?,
+            },
+        };
+
+        let datetime = datetime.and_utc();
+        match self.unit {
+            TimeUnit::Second => Some(datetime.timestamp()),
+            TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+            TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+            TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DecimalParser<T: PrimInt> {
     precision: u8,
@@ -365,6 +413,36 @@ mod tests {
         assert_eq!(-1, Date32Parser.parse("1969-12-31").unwrap());
     }
 
+    #[test]
+    fn parse_timestamp() {
+        let mut parser = TimestampParser {
+            unit: TimeUnit::Microsecond,
+        };
+
+        // 2023-01-02 03:04:05 UTC
+        let expected = 1_672_628_645_000_000;
+        assert_eq!(expected, parser.parse("2023-01-02T03:04:05").unwrap());
+        assert_eq!(expected, parser.parse("2023-01-02 03:04:05").unwrap());
+        assert_eq!(expected, parser.parse("2023-01-02T05:04:05+02:00").unwrap());
+        assert_eq!(
+            expected + 500_000,
+            parser.parse("2023-01-02T03:04:05.5").unwrap()
+        );
+
+        // Date only, midnight.
+        assert_eq!(1_672_617_600_000_000, parser.parse("2023-01-02").unwrap());
+
+        let mut parser = TimestampParser {
+            unit: TimeUnit::Second,
+        };
+        assert_eq!(1_672_628_640, parser.parse("2023-01-02 03:04").unwrap());
+
+        // Can't parse
+        assert_eq!(None, parser.parse("2023-01-02T"));
+        assert_eq!(None, parser.parse("2023-13-02"));
+        assert_eq!(None, parser.parse("not a timestamp"));
+    }
+
     #[test]
     fn parse_decimal() {
         // Can parse
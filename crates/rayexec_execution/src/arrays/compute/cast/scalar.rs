@@ -18,9 +18,15 @@ use super::parse::{
     UInt64Parser,
     UInt8Parser,
 };
-use crate::arrays::compute::cast::parse::{BoolParser, Date32Parser, IntervalParser};
+use crate::arrays::compute::cast::parse::{
+    BoolParser,
+    Date32Parser,
+    IntervalParser,
+    TimestampParser,
+};
 use crate::arrays::datatype::DataType;
 use crate::arrays::scalar::decimal::{Decimal128Scalar, Decimal64Scalar};
+use crate::arrays::scalar::timestamp::TimestampScalar;
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 
 // TODO: Try to remove this.
@@ -303,6 +309,10 @@ fn cast_from_utf8_scalar(v: &str, datatype: &DataType) -> Result<OwnedScalarValu
             )?,
         }),
         DataType::Date32 => ScalarValue::Date32(parse(Date32Parser, v, datatype)?),
+        DataType::Timestamp(meta) => ScalarValue::Timestamp(TimestampScalar {
+            unit: meta.unit,
+            value: parse(TimestampParser { unit: meta.unit }, v, datatype)?,
+        }),
         DataType::Interval => ScalarValue::Interval(parse(IntervalParser::default(), v, datatype)?),
         other => {
             return Err(RayexecError::new(format!(
@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, TimeDelta, Timelike, Utc};
 use rayexec_error::{not_implemented, RayexecError, Result};
 
 use crate::arrays::array::Array;
@@ -7,6 +7,7 @@ use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::physical_type::{PhysicalI32, PhysicalI64};
 use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::arrays::scalar::decimal::{Decimal64Type, DecimalType};
+use crate::arrays::scalar::interval::Interval;
 
 pub const EPOCH_NAIVE_DATE: NaiveDate = match NaiveDate::from_ymd_opt(1970, 1, 1) {
     Some(date) => date,
@@ -17,6 +18,85 @@ pub const EPOCH_DAYS_FROM_CE: i32 = 719_163;
 
 pub const SECONDS_IN_DAY: i64 = 86_400;
 
+/// Convert a timestamp value with the given unit to a datetime.
+pub fn timestamp_to_datetime(unit: TimeUnit, val: i64) -> Option<DateTime<Utc>> {
+    match unit {
+        TimeUnit::Second => DateTime::from_timestamp(val, 0),
+        TimeUnit::Millisecond => DateTime::from_timestamp_millis(val),
+        TimeUnit::Microsecond => DateTime::from_timestamp_micros(val),
+        TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(val)),
+    }
+}
+
+/// Convert a datetime to a timestamp value with the given unit.
+///
+/// Precision finer than `unit` is truncated.
+pub fn datetime_to_timestamp(unit: TimeUnit, datetime: DateTime<Utc>) -> Option<i64> {
+    match unit {
+        TimeUnit::Second => Some(datetime.timestamp()),
+        TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+        TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+    }
+}
+
+/// Add an interval to a timestamp value with the given unit.
+///
+/// Months are applied first, then days, then the sub-day component. Adding
+/// months clamps the day of month to the last day of the resulting month, e.g.
+/// '2024-01-31' + 1 month = '2024-02-29'.
+///
+/// Returns None if the result is out of range.
+pub fn timestamp_add_interval(unit: TimeUnit, val: i64, interval: Interval) -> Option<i64> {
+    let mut datetime = timestamp_to_datetime(unit, val)?;
+
+    let months = Months::new(interval.months.unsigned_abs());
+    datetime = if interval.months >= 0 {
+        datetime.checked_add_months(months)?
+    } else {
+        datetime.checked_sub_months(months)?
+    };
+
+    let days = Days::new(interval.days.unsigned_abs() as u64);
+    datetime = if interval.days >= 0 {
+        datetime.checked_add_days(days)?
+    } else {
+        datetime.checked_sub_days(days)?
+    };
+
+    datetime = datetime.checked_add_signed(TimeDelta::nanoseconds(interval.nanos))?;
+
+    datetime_to_timestamp(unit, datetime)
+}
+
+/// Compute the interval between two timestamp values (`a - b`).
+///
+/// Like Postgres, the resulting interval never contains months since month
+/// lengths vary. Whole days are placed in `days` with the remainder in `nanos`.
+///
+/// Returns None if the number of days doesn't fit in the interval.
+pub fn timestamp_diff(a_unit: TimeUnit, a: i64, b_unit: TimeUnit, b: i64) -> Option<Interval> {
+    const NANOSECONDS_IN_DAY: i128 =
+        SECONDS_IN_DAY as i128 * Interval::NANOSECONDS_IN_SECOND as i128;
+
+    let diff = timestamp_nanos(a_unit, a) - timestamp_nanos(b_unit, b);
+    let days = i32::try_from(diff / NANOSECONDS_IN_DAY).ok()?;
+    let nanos = (diff % NANOSECONDS_IN_DAY) as i64;
+
+    Some(Interval::new(0, days, nanos))
+}
+
+/// Widen a timestamp value to nanoseconds since epoch.
+fn timestamp_nanos(unit: TimeUnit, val: i64) -> i128 {
+    let scale = match unit {
+        TimeUnit::Second => Interval::NANOSECONDS_IN_SECOND,
+        TimeUnit::Millisecond => Interval::NANOSECONDS_IN_MILLISECOND,
+        TimeUnit::Microsecond => Interval::NANOSECONDS_IN_MICROSECOND,
+        TimeUnit::Nanosecond => 1,
+    };
+    val as i128 * scale as i128
+}
+
 /// Date parts that can be extracted for date and time values.
 ///
 /// Follows Postgres conventions: <https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-EXTRACT>
@@ -72,53 +152,45 @@ pub enum DatePart {
 pub fn extract_date_part(part: DatePart, arr: &Array) -> Result<Array> {
     let datatype = arr.datatype();
     match datatype {
-        DataType::Date32 => match part {
-            DatePart::Microseconds => date32_extract_with_fn(arr, extract_microseconds),
-            DatePart::Milliseconds => date32_extract_with_fn(arr, extract_milliseconds),
-            DatePart::Second => date32_extract_with_fn(arr, extract_seconds),
-            DatePart::Minute => date32_extract_with_fn(arr, extract_minute),
-            DatePart::DayOfWeek => date32_extract_with_fn(arr, extract_day_of_week),
-            DatePart::IsoDayOfWeek => date32_extract_with_fn(arr, extract_iso_day_of_week),
-            DatePart::Day => date32_extract_with_fn(arr, extract_day),
-            DatePart::Month => date32_extract_with_fn(arr, extract_month),
-            DatePart::Quarter => date32_extract_with_fn(arr, extract_quarter),
-            DatePart::Year => date32_extract_with_fn(arr, extract_year),
-            other => not_implemented!("Extract {other:?} from {datatype}"),
-        },
-        DataType::Date64 => match part {
-            DatePart::Microseconds => date64_extract_with_fn(arr, extract_microseconds),
-            DatePart::Milliseconds => date64_extract_with_fn(arr, extract_milliseconds),
-            DatePart::Second => date64_extract_with_fn(arr, extract_seconds),
-            DatePart::Minute => date64_extract_with_fn(arr, extract_minute),
-            DatePart::DayOfWeek => date64_extract_with_fn(arr, extract_day_of_week),
-            DatePart::IsoDayOfWeek => date64_extract_with_fn(arr, extract_iso_day_of_week),
-            DatePart::Day => date64_extract_with_fn(arr, extract_day),
-            DatePart::Month => date64_extract_with_fn(arr, extract_month),
-            DatePart::Quarter => date64_extract_with_fn(arr, extract_quarter),
-            DatePart::Year => date64_extract_with_fn(arr, extract_year),
-            other => not_implemented!("Extract {other:?} from {datatype}"),
-        },
-        DataType::Timestamp(m) => match part {
-            DatePart::Microseconds => timestamp_extract_with_fn(m.unit, arr, extract_microseconds),
-            DatePart::Milliseconds => timestamp_extract_with_fn(m.unit, arr, extract_milliseconds),
-            DatePart::Second => timestamp_extract_with_fn(m.unit, arr, extract_seconds),
-            DatePart::Minute => timestamp_extract_with_fn(m.unit, arr, extract_minute),
-            DatePart::DayOfWeek => timestamp_extract_with_fn(m.unit, arr, extract_day_of_week),
-            DatePart::IsoDayOfWeek => {
-                timestamp_extract_with_fn(m.unit, arr, extract_iso_day_of_week)
-            }
-            DatePart::Day => timestamp_extract_with_fn(m.unit, arr, extract_day),
-            DatePart::Month => timestamp_extract_with_fn(m.unit, arr, extract_month),
-            DatePart::Quarter => timestamp_extract_with_fn(m.unit, arr, extract_quarter),
-            DatePart::Year => timestamp_extract_with_fn(m.unit, arr, extract_year),
-            other => not_implemented!("Extract {other:?} from {datatype}"),
-        },
+        DataType::Date32 => date32_extract_with_fn(arr, date_part_extract_fn(part, datatype)?),
+        DataType::Date64 => date64_extract_with_fn(arr, date_part_extract_fn(part, datatype)?),
+        DataType::Timestamp(m) => {
+            timestamp_extract_with_fn(m.unit, arr, date_part_extract_fn(part, datatype)?)
+        }
         other => Err(RayexecError::new(format!(
             "Unable to extract date part for array with data type {other}"
         ))),
     }
 }
 
+/// Check that we're able to extract `part` from values of the given data type.
+///
+/// Allows erroring during planning instead of execution.
+pub fn check_date_part_supported(part: DatePart, datatype: &DataType) -> Result<()> {
+    date_part_extract_fn(part, datatype).map(|_| ())
+}
+
+fn date_part_extract_fn(part: DatePart, datatype: &DataType) -> Result<fn(DateTime<Utc>) -> i64> {
+    Ok(match part {
+        DatePart::Microseconds => extract_microseconds,
+        DatePart::Milliseconds => extract_milliseconds,
+        DatePart::Second => extract_seconds,
+        DatePart::Minute => extract_minute,
+        DatePart::Hour => extract_hour,
+        DatePart::DayOfWeek => extract_day_of_week,
+        DatePart::IsoDayOfWeek => extract_iso_day_of_week,
+        DatePart::Day => extract_day,
+        DatePart::DayOfYear => extract_day_of_year,
+        DatePart::Week => extract_week,
+        DatePart::Month => extract_month,
+        DatePart::Quarter => extract_quarter,
+        DatePart::Year => extract_year,
+        DatePart::IsoYear => extract_iso_year,
+        DatePart::Decade => extract_decade,
+        other => not_implemented!("Extract {other:?} from {datatype}"),
+    })
+}
+
 fn timestamp_extract_with_fn<F>(unit: TimeUnit, arr: &Array, f: F) -> Result<Array>
 where
     F: Fn(DateTime<Utc>) -> i64,
@@ -221,6 +293,14 @@ fn extract_year<T: Datelike + Timelike>(val: T) -> i64 {
     (val.year() as i64) * WHOLE_INT_SCALE
 }
 
+fn extract_iso_year<T: Datelike + Timelike>(val: T) -> i64 {
+    (val.iso_week().year() as i64) * WHOLE_INT_SCALE
+}
+
+fn extract_decade<T: Datelike + Timelike>(val: T) -> i64 {
+    (val.year().div_euclid(10) as i64) * WHOLE_INT_SCALE
+}
+
 fn extract_month<T: Datelike + Timelike>(val: T) -> i64 {
     (val.month() as i64) * WHOLE_INT_SCALE
 }
@@ -229,6 +309,14 @@ fn extract_day<T: Datelike + Timelike>(val: T) -> i64 {
     (val.day() as i64) * WHOLE_INT_SCALE
 }
 
+fn extract_day_of_year<T: Datelike + Timelike>(val: T) -> i64 {
+    (val.ordinal() as i64) * WHOLE_INT_SCALE
+}
+
+fn extract_week<T: Datelike + Timelike>(val: T) -> i64 {
+    (val.iso_week().week() as i64) * WHOLE_INT_SCALE
+}
+
 fn extract_day_of_week<T: Datelike + Timelike>(val: T) -> i64 {
     ((val.weekday().number_from_sunday() as i64) - 1) * WHOLE_INT_SCALE
 }
@@ -237,6 +325,10 @@ fn extract_iso_day_of_week<T: Datelike + Timelike>(val: T) -> i64 {
     (val.weekday().number_from_monday() as i64) * WHOLE_INT_SCALE
 }
 
+fn extract_hour<T: Datelike + Timelike>(val: T) -> i64 {
+    (val.hour() as i64) * WHOLE_INT_SCALE
+}
+
 fn extract_minute<T: Datelike + Timelike>(val: T) -> i64 {
     (val.minute() as i64) * WHOLE_INT_SCALE
 }
@@ -1,5 +1,6 @@
 //! Compute kernels.
 pub mod cast;
 pub mod date;
+pub mod rounding;
 
 pub mod util;
@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use num_traits::Float;
+use rayexec_error::{RayexecError, Result};
+use rayexec_proto::ProtoConv;
+
+use crate::arrays::scalar::decimal::DecimalPrimitive;
+
+/// How to round values that can't be represented exactly, e.g. when rounding
+/// to some number of decimal places or reducing the scale of a decimal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest value, with halfway values rounded to the nearest
+    /// even value (banker's rounding).
+    ///
+    /// `round(0.5) = 0`, `round(1.5) = 2`
+    #[default]
+    HalfEven,
+    /// Round to the nearest value, with halfway values rounded away from zero.
+    ///
+    /// `round(0.5) = 1`, `round(-0.5) = -1`
+    HalfUp,
+    /// Truncate towards zero.
+    ///
+    /// `round(1.9) = 1`, `round(-1.9) = -1`
+    TowardZero,
+}
+
+impl RoundingMode {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            RoundingMode::HalfEven => "half_even",
+            RoundingMode::HalfUp => "half_up",
+            RoundingMode::TowardZero => "toward_zero",
+        }
+    }
+
+    /// Round a float to an integral value.
+    pub fn round_float<F: Float>(&self, v: F) -> F {
+        match self {
+            RoundingMode::HalfUp => v.round(),
+            RoundingMode::TowardZero => v.trunc(),
+            RoundingMode::HalfEven => {
+                let rounded = v.round();
+                let trunc = v.trunc();
+                let half = F::from(0.5).expect("0.5 to fit in float");
+
+                if (v - trunc).abs() == half && (trunc * half).fract() == F::zero() {
+                    // Halfway and the truncated value is already even.
+                    trunc
+                } else {
+                    rounded
+                }
+            }
+        }
+    }
+
+    /// Divide `v` by `factor`, rounding the result to an integer.
+    ///
+    /// `factor` must be positive.
+    pub fn round_div<T: DecimalPrimitive>(&self, v: T, factor: T) -> T {
+        let quotient = v / factor;
+        let remainder = (v % factor).abs();
+        let rest = factor - remainder;
+
+        let round_away = match self {
+            RoundingMode::TowardZero => false,
+            RoundingMode::HalfUp => remainder >= rest,
+            RoundingMode::HalfEven => {
+                remainder > rest
+                    || (remainder == rest && quotient % (T::one() + T::one()) != T::zero())
+            }
+        };
+
+        if round_away {
+            quotient + v.signum()
+        } else {
+            quotient
+        }
+    }
+}
+
+impl FromStr for RoundingMode {
+    type Err = RayexecError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "half_even" => RoundingMode::HalfEven,
+            "half_up" => RoundingMode::HalfUp,
+            "toward_zero" => RoundingMode::TowardZero,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Invalid rounding mode: '{other}', expected one of 'half_even', 'half_up', or 'toward_zero'"
+                )))
+            }
+        })
+    }
+}
+
+impl ProtoConv for RoundingMode {
+    type ProtoType = rayexec_proto::generated::expr::RoundingMode;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::HalfEven => Self::ProtoType::HalfEven,
+            Self::HalfUp => Self::ProtoType::HalfUp,
+            Self::TowardZero => Self::ProtoType::TowardZero,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidRoundingMode => return Err(RayexecError::new("invalid")),
+            Self::ProtoType::HalfEven => Self::HalfEven,
+            Self::ProtoType::HalfUp => Self::HalfUp,
+            Self::ProtoType::TowardZero => Self::TowardZero,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_float_modes() {
+        let inputs = [0.5_f64, 1.5, 2.5, -0.5, -1.5, 1.4, -1.6];
+
+        let cases = [
+            (
+                RoundingMode::HalfEven,
+                [0.0, 2.0, 2.0, -0.0, -2.0, 1.0, -2.0],
+            ),
+            (RoundingMode::HalfUp, [1.0, 2.0, 3.0, -1.0, -2.0, 1.0, -2.0]),
+            (
+                RoundingMode::TowardZero,
+                [0.0, 1.0, 2.0, -0.0, -1.0, 1.0, -1.0],
+            ),
+        ];
+
+        for (mode, expected) in cases {
+            let out: Vec<_> = inputs.iter().map(|v| mode.round_float(*v)).collect();
+            assert_eq!(expected.to_vec(), out, "mode: {mode:?}");
+        }
+    }
+
+    #[test]
+    fn round_div_modes() {
+        // Dividing by 10, so 5 -> 0.5, 15 -> 1.5, etc.
+        let inputs = [5_i64, 15, 25, -5, -15, 14, -16];
+
+        let cases = [
+            (RoundingMode::HalfEven, [0, 2, 2, 0, -2, 1, -2]),
+            (RoundingMode::HalfUp, [1, 2, 3, -1, -2, 1, -2]),
+            (RoundingMode::TowardZero, [0, 1, 2, 0, -1, 1, -1]),
+        ];
+
+        for (mode, expected) in cases {
+            let out: Vec<_> = inputs.iter().map(|v| mode.round_div(*v, 10)).collect();
+            assert_eq!(expected.to_vec(), out, "mode: {mode:?}");
+        }
+    }
+
+    #[test]
+    fn from_str_roundtrip() {
+        for mode in [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfUp,
+            RoundingMode::TowardZero,
+        ] {
+            assert_eq!(mode, mode.as_str().parse::<RoundingMode>().unwrap());
+        }
+        "half_down".parse::<RoundingMode>().unwrap_err();
+    }
+}
@@ -1,12 +1,13 @@
 use std::fmt;
 
-use rayexec_error::{not_implemented, OptionExt, RayexecError, Result, ResultExt};
+use rayexec_error::{OptionExt, RayexecError, Result, ResultExt};
 use rayexec_proto::ProtoConv;
 use serde::{Deserialize, Serialize};
 
 use crate::arrays::executor::physical_type::PhysicalType;
 use crate::arrays::field::Field;
 use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::logical::resolver::Resolver;
 
 /// The 'type' of the dataype.
 ///
@@ -48,6 +49,7 @@ pub enum DataTypeId {
     Binary,
     Struct,
     List,
+    Map,
 }
 
 impl DataTypeId {
@@ -79,6 +81,7 @@ impl DataTypeId {
             Self::Binary => "Binary",
             Self::Struct => "Struct",
             Self::List => "List",
+            Self::Map => "Map",
         }
     }
 }
@@ -114,6 +117,7 @@ impl ProtoConv for DataTypeId {
             Self::Binary => Self::ProtoType::Binary,
             Self::Struct => Self::ProtoType::Struct,
             Self::List => Self::ProtoType::List,
+            Self::Map => Self::ProtoType::Map,
         })
     }
 
@@ -146,6 +150,7 @@ impl ProtoConv for DataTypeId {
             Self::ProtoType::Binary => Self::Binary,
             Self::ProtoType::Struct => Self::Struct,
             Self::ProtoType::List => Self::List,
+            Self::ProtoType::Map => Self::Map,
         })
     }
 }
@@ -319,6 +324,53 @@ impl ProtoConv for ListTypeMeta {
     }
 }
 
+/// Metadata associated with maps.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MapTypeMeta {
+    pub key: Box<DataType>,
+    pub value: Box<DataType>,
+}
+
+impl MapTypeMeta {
+    pub fn new(key: DataType, value: DataType) -> Self {
+        MapTypeMeta {
+            key: Box::new(key),
+            value: Box::new(value),
+        }
+    }
+
+    /// Get the data type of the entries in the map.
+    ///
+    /// Maps are physically stored as lists of key/value structs, with this
+    /// being the data type of the struct.
+    pub fn entries_datatype(&self) -> DataType {
+        DataType::Struct(StructTypeMeta {
+            fields: vec![
+                Field::new("key", self.key.as_ref().clone(), false),
+                Field::new("value", self.value.as_ref().clone(), true),
+            ],
+        })
+    }
+}
+
+impl ProtoConv for MapTypeMeta {
+    type ProtoType = rayexec_proto::generated::schema::MapTypeMeta;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            key: Some(Box::new(self.key.to_proto()?)),
+            value: Some(Box::new(self.value.to_proto()?)),
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(Self {
+            key: Box::new(DataType::from_proto(*proto.key.required("key")?)?),
+            value: Box::new(DataType::from_proto(*proto.value.required("value")?)?),
+        })
+    }
+}
+
 /// Supported data types.
 ///
 /// This generally follows Arrow's type system, but is not restricted to it.
@@ -361,13 +413,17 @@ pub enum DataType {
     Struct(StructTypeMeta),
     /// A list of values all of the same type.
     List(ListTypeMeta),
+    /// A mapping of keys to values.
+    ///
+    /// Physically represented as a list of key/value structs.
+    Map(MapTypeMeta),
 }
 
 impl DataType {
     /// Try to create a default data type from the the data type id.
     ///
     /// Errors on attempts to create a data type from an id that we either don't
-    /// have enough information about (struct, list) or can never be represented
+    /// have enough information about (struct, list, map) or can never be represented
     /// as a concrete data type (any).
     pub fn try_default_datatype(id: DataTypeId) -> Result<Self> {
         Ok(match id {
@@ -411,6 +467,9 @@ impl DataType {
             DataTypeId::List => {
                 return Err(RayexecError::new("Cannot create a default List datatype"))
             }
+            DataTypeId::Map => {
+                return Err(RayexecError::new("Cannot create a default Map datatype"))
+            }
         })
     }
 
@@ -442,6 +501,7 @@ impl DataType {
             DataType::Binary => DataTypeId::Binary,
             DataType::Struct(_) => DataTypeId::Struct,
             DataType::List(_) => DataTypeId::List,
+            DataType::Map(_) => DataTypeId::Map,
         }
     }
 
@@ -470,8 +530,9 @@ impl DataType {
             DataType::Interval => PhysicalType::Interval,
             DataType::Utf8 => PhysicalType::Utf8,
             DataType::Binary => PhysicalType::Binary,
-            DataType::Struct(_) => not_implemented!("struct data type to physical type"),
+            DataType::Struct(_) => PhysicalType::Struct,
             DataType::List(_) => PhysicalType::List,
+            DataType::Map(_) => PhysicalType::List,
         })
     }
 
@@ -485,6 +546,11 @@ impl DataType {
         matches!(self, DataType::List(_))
     }
 
+    /// Return if this datatype is a map.
+    pub const fn is_map(&self) -> bool {
+        matches!(self, DataType::Map(_))
+    }
+
     pub const fn is_utf8(&self) -> bool {
         matches!(self, DataType::Utf8)
     }
@@ -528,6 +594,22 @@ impl DataType {
         )
     }
 
+    pub const fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::Int128
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::UInt128
+        )
+    }
+
     pub const fn is_float(&self) -> bool {
         matches!(
             self,
@@ -548,6 +630,58 @@ impl DataType {
             ))),
         }
     }
+
+    /// Parse a SQL type name into a datatype, e.g. `DECIMAL(10,2)` or
+    /// `VARCHAR`.
+    ///
+    /// Uses the same rules as resolving types for CAST and CREATE TABLE.
+    pub fn from_sql_name(name: &str) -> Result<Self> {
+        let datatype = rayexec_parser::parser::parse_datatype(name)?;
+        Resolver::ast_datatype_to_exec_datatype(datatype)
+    }
+
+    /// Get the SQL name for this datatype.
+    ///
+    /// This should be used for any user-facing output where the type is
+    /// displayed, e.g. DESCRIBE or error messages. The `Display` impl shows
+    /// the internal type name (`Int32`) instead of the SQL name (`INTEGER`).
+    pub fn sql_name(&self) -> String {
+        match self {
+            Self::Null => "NULL".to_string(),
+            Self::Boolean => "BOOLEAN".to_string(),
+            Self::Int8 => "TINYINT".to_string(),
+            Self::Int16 => "SMALLINT".to_string(),
+            Self::Int32 => "INTEGER".to_string(),
+            Self::Int64 => "BIGINT".to_string(),
+            Self::Int128 => "HUGEINT".to_string(),
+            Self::UInt8 => "UTINYINT".to_string(),
+            Self::UInt16 => "USMALLINT".to_string(),
+            Self::UInt32 => "UINTEGER".to_string(),
+            Self::UInt64 => "UBIGINT".to_string(),
+            Self::UInt128 => "UHUGEINT".to_string(),
+            Self::Float16 => "HALF".to_string(),
+            Self::Float32 => "REAL".to_string(),
+            Self::Float64 => "DOUBLE".to_string(),
+            Self::Decimal64(meta) | Self::Decimal128(meta) => {
+                format!("DECIMAL({},{})", meta.precision, meta.scale)
+            }
+            Self::Timestamp(_) => "TIMESTAMP".to_string(),
+            Self::Date32 | Self::Date64 => "DATE".to_string(),
+            Self::Interval => "INTERVAL".to_string(),
+            Self::Utf8 => "VARCHAR".to_string(),
+            Self::Binary => "BLOB".to_string(),
+            Self::Struct(meta) => format!(
+                "STRUCT({})",
+                meta.fields
+                    .iter()
+                    .map(|field| format!("{} {}", field.name, field.datatype.sql_name()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::List(meta) => format!("{}[]", meta.datatype.sql_name()),
+            Self::Map(meta) => format!("MAP({}, {})", meta.key.sql_name(), meta.value.sql_name()),
+        }
+    }
 }
 
 impl ProtoConv for DataType {
@@ -583,6 +717,7 @@ impl ProtoConv for DataType {
             DataType::Binary => Value::TypeBinary(EmptyMeta {}),
             DataType::Struct(m) => Value::TypeStruct(m.to_proto()?),
             DataType::List(m) => Value::TypeList(Box::new(m.to_proto()?)),
+            DataType::Map(m) => Value::TypeMap(Box::new(m.to_proto()?)),
         };
         Ok(Self::ProtoType { value: Some(value) })
     }
@@ -616,6 +751,7 @@ impl ProtoConv for DataType {
             Value::TypeBinary(_) => DataType::Binary,
             Value::TypeStruct(m) => DataType::Struct(StructTypeMeta::from_proto(m)?),
             Value::TypeList(m) => DataType::List(ListTypeMeta::from_proto(*m)?),
+            Value::TypeMap(m) => DataType::Map(MapTypeMeta::from_proto(*m)?),
         })
     }
 }
@@ -658,6 +794,103 @@ impl fmt::Display for DataType {
                 )
             }
             DataType::List(meta) => write!(f, "List[{}]", meta.datatype),
+            DataType::Map(meta) => write!(f, "Map[{}, {}]", meta.key, meta.value),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sql_names() {
+        assert_eq!("INTEGER", DataType::Int32.sql_name());
+        assert_eq!("BIGINT", DataType::Int64.sql_name());
+        assert_eq!("DOUBLE", DataType::Float64.sql_name());
+        assert_eq!("VARCHAR", DataType::Utf8.sql_name());
+        assert_eq!("BOOLEAN", DataType::Boolean.sql_name());
+        assert_eq!(
+            "TIMESTAMP",
+            DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)).sql_name()
+        );
+    }
+
+    #[test]
+    fn from_sql_names() {
+        assert_eq!(DataType::Utf8, DataType::from_sql_name("VARCHAR(255)").unwrap());
+        assert_eq!(DataType::Utf8, DataType::from_sql_name("text").unwrap());
+        assert_eq!(DataType::Int32, DataType::from_sql_name("INT").unwrap());
+        assert_eq!(DataType::Int32, DataType::from_sql_name("integer").unwrap());
+        assert_eq!(
+            DataType::Decimal64(DecimalTypeMeta::new(10, 2)),
+            DataType::from_sql_name("DECIMAL(10,2)").unwrap()
+        );
+        assert_eq!(
+            DataType::Decimal128(DecimalTypeMeta::new(38, 4)),
+            DataType::from_sql_name("numeric(38, 4)").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_sql_name_roundtrip() {
+        let datatypes = [
+            DataType::Boolean,
+            DataType::Int8,
+            DataType::Int16,
+            DataType::Int32,
+            DataType::Int64,
+            DataType::Float32,
+            DataType::Float64,
+            DataType::Decimal64(DecimalTypeMeta::new(12, 3)),
+            DataType::Date32,
+            DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            DataType::Interval,
+            DataType::Utf8,
+        ];
+        for datatype in datatypes {
+            assert_eq!(
+                datatype,
+                DataType::from_sql_name(&datatype.sql_name()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn from_sql_name_unknown() {
+        let err = DataType::from_sql_name("NOTATYPE").unwrap_err();
+        assert!(err.get_msg().contains("NOTATYPE"), "{err}");
+
+        DataType::from_sql_name("INT INT").unwrap_err();
+    }
+
+    #[test]
+    fn sql_names_parameterized() {
+        assert_eq!(
+            "DECIMAL(18,3)",
+            DataType::Decimal64(DecimalTypeMeta::new(18, 3)).sql_name()
+        );
+        assert_eq!(
+            "DECIMAL(38,10)",
+            DataType::Decimal128(DecimalTypeMeta::new(38, 10)).sql_name()
+        );
+        assert_eq!(
+            "INTEGER[]",
+            DataType::List(ListTypeMeta::new(DataType::Int32)).sql_name()
+        );
+        assert_eq!(
+            "MAP(VARCHAR, DOUBLE)",
+            DataType::Map(MapTypeMeta::new(DataType::Utf8, DataType::Float64)).sql_name()
+        );
+        assert_eq!(
+            "STRUCT(a INTEGER, b VARCHAR)",
+            DataType::Struct(StructTypeMeta {
+                fields: vec![
+                    Field::new("a", DataType::Int32, true),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+            })
+            .sql_name()
+        );
+    }
+}
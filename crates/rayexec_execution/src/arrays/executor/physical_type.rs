@@ -15,6 +15,7 @@ use crate::arrays::storage::{
     ListItemMetadata,
     ListStorage,
     PrimitiveStorageSlice,
+    StructStorage,
     UntypedNull,
     UntypedNullStorage,
 };
@@ -40,6 +41,7 @@ pub enum PhysicalType {
     Binary,
     Utf8,
     List,
+    Struct,
 }
 
 impl PhysicalType {
@@ -68,6 +70,7 @@ impl PhysicalType {
                 array: Array::new_untyped_null_array(0),
             }
             .into(),
+            Self::Struct => StructStorage::empty(len).into(),
         }
     }
 }
@@ -96,6 +99,7 @@ impl ProtoConv for PhysicalType {
             Self::Utf8 => Self::ProtoType::Utf8,
             Self::Binary => Self::ProtoType::Binary,
             Self::List => Self::ProtoType::List,
+            Self::Struct => Self::ProtoType::Struct,
         })
     }
 
@@ -121,6 +125,7 @@ impl ProtoConv for PhysicalType {
             Self::ProtoType::Utf8 => Self::Utf8,
             Self::ProtoType::Binary => Self::Binary,
             Self::ProtoType::List => Self::List,
+            Self::ProtoType::Struct => Self::Struct,
         })
     }
 }
@@ -41,6 +41,7 @@ use crate::arrays::storage::{
     ListItemMetadata,
     ListStorage,
     PrimitiveStorage,
+    StructStorage,
     UntypedNullStorage,
 };
 
@@ -279,6 +280,17 @@ pub(crate) fn concat_with_exact_total_len(arrays: &[&Array], total_len: usize) -
             concat_with_fill_state::<PhysicalBinary, _>(arrays, state)
         }
         PhysicalType::List => concat_lists(datatype.clone(), arrays, total_len),
+        PhysicalType::Struct => {
+            let indices: Vec<_> = arrays
+                .iter()
+                .enumerate()
+                .flat_map(|(array_idx, arr)| {
+                    (0..arr.logical_len()).map(move |row_idx| (array_idx, row_idx))
+                })
+                .collect();
+            debug_assert_eq!(total_len, indices.len());
+            interleave_structs(datatype.clone(), arrays, &indices)
+        }
     }
 }
 
@@ -502,13 +514,147 @@ pub fn interleave(arrays: &[&Array], indices: &[(usize, usize)]) -> Result<Array
             });
             interleave_with_fill_state::<PhysicalBinary, _>(arrays, indices, state)
         }
-        PhysicalType::List => {
-            // TODO: Also doable
-            Err(RayexecError::new(
-                "interleaving list arrays not yet supported",
-            ))
+        PhysicalType::List => interleave_lists(datatype.clone(), arrays, indices),
+        PhysicalType::Struct => interleave_structs(datatype.clone(), arrays, indices),
+    }
+}
+
+fn interleave_structs(
+    datatype: DataType,
+    arrays: &[&Array],
+    indices: &[(usize, usize)],
+) -> Result<Array> {
+    let fields = match &datatype {
+        DataType::Struct(meta) => &meta.fields,
+        other => {
+            return Err(RayexecError::new(format!(
+                "Expected struct datatype, got {other}"
+            )))
+        }
+    };
+
+    let storages = arrays
+        .iter()
+        .map(|arr| match arr.array_data() {
+            ArrayData::Struct(storage) => Ok(storage.as_ref()),
+            other => Err(RayexecError::new(format!(
+                "Invalid inner array data for interleaving structs, got {:?}",
+                other.physical_type()
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Rows in the struct map directly to rows in the child arrays, so
+    // translate the logical row index for each struct into its physical index.
+    let mut validity = Bitmap::new_with_all_true(indices.len());
+    let mut child_indices = Vec::with_capacity(indices.len());
+
+    for (row_idx, &(array_idx, array_row_idx)) in indices.iter().enumerate() {
+        let array = arrays[array_idx];
+        if !array.is_valid(array_row_idx).unwrap_or(false) {
+            validity.set_unchecked(row_idx, false);
+        }
+        let physical_idx = selection::get(array.selection_vector(), array_row_idx);
+        child_indices.push((array_idx, physical_idx));
+    }
+
+    let mut children = Vec::with_capacity(fields.len());
+    for (field_idx, field) in fields.iter().enumerate() {
+        // Storage for all-null structs won't have child arrays, use typed
+        // nulls in their place.
+        let placeholders = storages
+            .iter()
+            .map(|storage| match storage.field_array(field_idx) {
+                Some(_) => Ok(None),
+                None => {
+                    Array::new_typed_null_array(field.datatype.clone(), storage.len()).map(Some)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let field_arrays: Vec<_> = storages
+            .iter()
+            .zip(&placeholders)
+            .map(|(storage, placeholder)| match placeholder {
+                Some(placeholder) => placeholder,
+                None => storage
+                    .field_array(field_idx)
+                    .expect("child array to exist"),
+            })
+            .collect();
+
+        children.push(interleave(&field_arrays, &child_indices)?);
+    }
+
+    let data = StructStorage::try_new(indices.len(), children)?;
+
+    Ok(Array {
+        datatype,
+        selection: None,
+        validity: Some(validity.into()),
+        data: data.into(),
+    })
+}
+
+fn interleave_lists(
+    datatype: DataType,
+    arrays: &[&Array],
+    indices: &[(usize, usize)],
+) -> Result<Array> {
+    let inner_arrays = arrays
+        .iter()
+        .map(|arr| match arr.array_data() {
+            ArrayData::List(list) => {
+                if list.array.has_selection() {
+                    return Err(RayexecError::new("List child array has selection"));
+                }
+                Ok(&list.array)
+            }
+            other => Err(RayexecError::new(format!(
+                "Invalid inner array data for interleaving lists, got {:?}",
+                other.physical_type()
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Child arrays are concatenated as-is, metadata for each output row points
+    // into the section belonging to its source array.
+    let concatenated = concat(&inner_arrays)?;
+
+    let mut base_offsets = Vec::with_capacity(inner_arrays.len());
+    let mut acc_rows = 0;
+    for child_array in &inner_arrays {
+        base_offsets.push(acc_rows);
+        acc_rows += child_array.logical_len() as i32;
+    }
+
+    let mut metadatas = Vec::with_capacity(indices.len());
+    let mut validity = Bitmap::new_with_all_true(indices.len());
+
+    for (row_idx, &(array_idx, array_row_idx)) in indices.iter().enumerate() {
+        match UnaryExecutor::value_at::<PhysicalList>(arrays[array_idx], array_row_idx)? {
+            Some(metadata) => metadatas.push(ListItemMetadata {
+                offset: metadata.offset + base_offsets[array_idx],
+                len: metadata.len,
+            }),
+            None => {
+                metadatas.push(ListItemMetadata::default());
+                validity.set_unchecked(row_idx, false);
+            }
         }
     }
+
+    let data = ListStorage {
+        metadata: PrimitiveStorage::from(metadatas),
+        array: concatenated,
+    };
+
+    Ok(Array {
+        datatype,
+        selection: None,
+        validity: Some(validity.into()),
+        data: data.into(),
+    })
 }
 
 fn interleave_with_fill_state<'a, S, B>(
@@ -721,4 +867,29 @@ mod tests {
             got.logical_value(1).unwrap()
         );
     }
+
+    #[test]
+    fn interleave_lists() {
+        let arr1 = ScalarValue::List(vec![1.into(), 2.into()])
+            .as_array(2)
+            .unwrap();
+        let arr2 = ScalarValue::List(vec![3.into()]).as_array(2).unwrap();
+
+        let indices = [(1, 0), (0, 1), (1, 1)];
+
+        let got = interleave(&[&arr1, &arr2], &indices).unwrap();
+
+        assert_eq!(
+            ScalarValue::List(vec![3.into()]),
+            got.logical_value(0).unwrap()
+        );
+        assert_eq!(
+            ScalarValue::List(vec![1.into(), 2.into()]),
+            got.logical_value(1).unwrap()
+        );
+        assert_eq!(
+            ScalarValue::List(vec![3.into()]),
+            got.logical_value(2).unwrap()
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use ahash::RandomState;
 use half::f16;
-use rayexec_error::{RayexecError, Result};
+use rayexec_error::{not_implemented, RayexecError, Result};
 
 use crate::arrays::array::{Array, ArrayData};
 use crate::arrays::executor::physical_type::{
@@ -32,6 +32,12 @@ use crate::arrays::storage::{AddressableStorage, UntypedNull};
 /// State used for all hashing operations during physical execution.
 pub const HASH_RANDOM_STATE: RandomState = RandomState::with_seeds(0, 0, 0, 0);
 
+/// Seed used for row hashes during physical execution.
+///
+/// Every operator that hashes rows (joins, aggregates, distinct) needs to use
+/// the same seed so that hashes for the same keys match across operators.
+pub const DEFAULT_HASH_SEED: u64 = 0;
+
 #[derive(Debug, Clone)]
 pub struct HashExecutor;
 
@@ -95,6 +101,7 @@ impl HashExecutor {
                 Self::hash_one_inner::<PhysicalInterval, CombineSetHash>(array, hashes)?
             }
             PhysicalType::List => Self::hash_list::<CombineSetHash>(array, hashes)?,
+            PhysicalType::Struct => not_implemented!("Hash struct arrays"),
         }
 
         Ok(())
@@ -159,22 +166,60 @@ impl HashExecutor {
                 Self::hash_one_inner::<PhysicalInterval, OverwriteSetHash>(array, hashes)?
             }
             PhysicalType::List => Self::hash_list::<OverwriteSetHash>(array, hashes)?,
+            PhysicalType::Struct => not_implemented!("Hash struct arrays"),
         }
 
         Ok(())
     }
 
-    pub fn hash_many<'b>(arrays: &[Array], hashes: &'b mut [u64]) -> Result<&'b mut [u64]> {
-        for (idx, array) in arrays.iter().enumerate() {
-            let combine_hash = idx > 0;
+    /// Hash rows across multiple columns, returning a hash per row.
+    ///
+    /// Each row's hash starts with `seed`, and the hash of each column is
+    /// combined in column order. Nulls always hash to the same value regardless
+    /// of the column's type.
+    ///
+    /// All arrays must have the same logical length.
+    pub fn hash_columns(arrays: &[&Array], seed: u64) -> Result<Vec<u64>> {
+        let num_rows = match arrays.first() {
+            Some(array) => array.logical_len(),
+            None => return Ok(Vec::new()),
+        };
+
+        let mut hashes = vec![0; num_rows];
+        Self::hash_columns_into(arrays.iter().copied(), seed, &mut hashes)?;
 
-            if combine_hash {
-                Self::hash_combine(array, hashes)?;
-            } else {
-                Self::hash_no_combine(array, hashes)?;
+        Ok(hashes)
+    }
+
+    /// Hash rows across multiple columns, writing the hashes to `hashes`.
+    ///
+    /// Produces the same hashes as `hash_columns`, but allows for reusing an
+    /// existing buffer. The length of `hashes` must match the logical length of
+    /// the arrays.
+    pub fn hash_columns_into<'a>(
+        arrays: impl IntoIterator<Item = &'a Array>,
+        seed: u64,
+        hashes: &mut [u64],
+    ) -> Result<()> {
+        hashes.fill(seed);
+
+        for array in arrays {
+            if array.logical_len() != hashes.len() {
+                return Err(RayexecError::new(format!(
+                    "Array length {} does not match hash buffer length {}",
+                    array.logical_len(),
+                    hashes.len()
+                )));
             }
+            Self::hash_combine(array, hashes)?;
         }
 
+        Ok(())
+    }
+
+    /// Hash rows across multiple columns using the default seed.
+    pub fn hash_many<'b>(arrays: &[Array], hashes: &'b mut [u64]) -> Result<&'b mut [u64]> {
+        Self::hash_columns_into(arrays, DEFAULT_HASH_SEED, hashes)?;
         Ok(hashes)
     }
 
@@ -300,15 +345,17 @@ struct CombineSetHash;
 
 impl SetHash for CombineSetHash {
     fn set_hash(new_hash_value: u64, existing: &mut u64) {
-        *existing = combine_hashes(new_hash_value, *existing)
+        *existing = combine_hashes(*existing, new_hash_value)
     }
 }
 
 /// All nulls should hash to the same value.
 ///
-/// _What_ that value is is arbitrary, but it needs to be consistent.
-fn null_hash_value() -> u64 {
-    HASH_RANDOM_STATE.hash_one(1)
+/// _What_ that value is is arbitrary, but it needs to be consistent. This
+/// shouldn't be derived from hashing a value since nulls would then collide
+/// with that value (e.g. hashing `1` would collide with the integer 1).
+const fn null_hash_value() -> u64 {
+    0x9e37_79b9_7f4a_7c15
 }
 
 /// Combines two hashes into one hash
@@ -374,3 +421,84 @@ impl HashValue for UntypedNull {
         null_hash_value()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_columns_identical_keys_equal() {
+        let a1 = Array::from_iter([1, 2, 3]);
+        let b1 = Array::from_iter(["a", "b", "c"]);
+
+        // Same keys, but shuffled.
+        let a2 = Array::from_iter([3, 1, 2]);
+        let b2 = Array::from_iter(["c", "a", "b"]);
+
+        let h1 = HashExecutor::hash_columns(&[&a1, &b1], DEFAULT_HASH_SEED).unwrap();
+        let h2 = HashExecutor::hash_columns(&[&a2, &b2], DEFAULT_HASH_SEED).unwrap();
+
+        assert_eq!(h1[0], h2[1]);
+        assert_eq!(h1[1], h2[2]);
+        assert_eq!(h1[2], h2[0]);
+        assert_ne!(h1[0], h1[1]);
+    }
+
+    #[test]
+    fn hash_columns_combine_order_is_column_stable() {
+        let a = Array::from_iter([1, 2]);
+        let b = Array::from_iter([2, 1]);
+
+        let ab = HashExecutor::hash_columns(&[&a, &b], DEFAULT_HASH_SEED).unwrap();
+        let ba = HashExecutor::hash_columns(&[&b, &a], DEFAULT_HASH_SEED).unwrap();
+
+        // Row 0 of (a, b) is (1, 2), row 1 of (b, a) is (1, 2).
+        assert_eq!(ab[0], ba[1]);
+        assert_eq!(ab[1], ba[0]);
+        // (1, 2) and (2, 1) should not collide.
+        assert_ne!(ab[0], ab[1]);
+    }
+
+    #[test]
+    fn hash_columns_nulls_consistent() {
+        let a = Array::from_iter([Some(1), None]);
+        let b = Array::from_iter([Some("a"), None]);
+
+        let ha = HashExecutor::hash_columns(&[&a], DEFAULT_HASH_SEED).unwrap();
+        let hb = HashExecutor::hash_columns(&[&b], DEFAULT_HASH_SEED).unwrap();
+
+        assert_eq!(ha[1], hb[1]);
+        assert_ne!(ha[0], ha[1]);
+    }
+
+    #[test]
+    fn hash_columns_seed() {
+        let a = Array::from_iter([1, 2, 3]);
+
+        let h1 = HashExecutor::hash_columns(&[&a], 0).unwrap();
+        let h2 = HashExecutor::hash_columns(&[&a], 42).unwrap();
+
+        assert_ne!(h1, h2);
+    }
+
+    #[test]
+    fn hash_columns_matches_hash_many() {
+        let a = Array::from_iter([1, 2, 3]);
+        let b = Array::from_iter(["a", "b", "c"]);
+
+        let expected = HashExecutor::hash_columns(&[&a, &b], DEFAULT_HASH_SEED).unwrap();
+
+        let mut buf = vec![0; 3];
+        let got = HashExecutor::hash_many(&[a, b], &mut buf).unwrap();
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn hash_columns_length_mismatch() {
+        let a = Array::from_iter([1, 2, 3]);
+        let b = Array::from_iter([1, 2]);
+
+        HashExecutor::hash_columns(&[&a, &b], DEFAULT_HASH_SEED).unwrap_err();
+    }
+}
@@ -244,6 +244,7 @@ impl ComparableRowEncoder {
                         cmp_col, arr, row_idx, data, row_offset,
                     )?,
                     ArrayData::List(_) => not_implemented!("Row encode list"),
+                    ArrayData::Struct(_) => not_implemented!("Row encode struct"),
                 };
             }
 
@@ -281,6 +282,7 @@ impl ComparableRowEncoder {
                     BinaryData::German(d) => d.data_size_bytes(),
                 },
                 ArrayData::List(_) => not_implemented!("Row encode list"),
+                ArrayData::Struct(_) => not_implemented!("Row encode struct"),
             };
 
             // Account for validities.
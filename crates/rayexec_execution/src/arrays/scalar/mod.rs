@@ -9,7 +9,7 @@ use std::hash::Hash;
 use decimal::{Decimal128Scalar, Decimal64Scalar};
 use half::f16;
 use interval::Interval;
-use rayexec_error::{not_implemented, OptionExt, RayexecError, Result};
+use rayexec_error::{int_conversion_error, not_implemented, OptionExt, RayexecError, Result};
 use rayexec_proto::ProtoConv;
 use serde::{Deserialize, Serialize};
 use timestamp::TimestampScalar;
@@ -46,6 +46,7 @@ use crate::arrays::datatype::{
     DataType,
     DecimalTypeMeta,
     ListTypeMeta,
+    MapTypeMeta,
     TimeUnit,
     TimestampTypeMeta,
 };
@@ -87,6 +88,8 @@ pub enum ScalarValue<'a> {
     Binary(Cow<'a, [u8]>),
     Struct(Vec<ScalarValue<'a>>),
     List(Vec<ScalarValue<'a>>),
+    /// Key/value pairs in a map.
+    Map(Vec<(ScalarValue<'a>, ScalarValue<'a>)>),
 }
 
 // TODO: TBD if we want this. We may need to implement PartialEq to exact
@@ -121,6 +124,7 @@ impl Hash for ScalarValue<'_> {
             Self::Binary(v) => v.hash(state),
             Self::Struct(v) => v.hash(state),
             Self::List(v) => v.hash(state),
+            Self::Map(v) => v.hash(state),
         }
     }
 }
@@ -166,6 +170,12 @@ impl ScalarValue<'_> {
                     datatype: Box::new(DataType::Null),
                 }),
             },
+            ScalarValue::Map(entries) => match entries.first() {
+                Some((key, value)) => {
+                    DataType::Map(MapTypeMeta::new(key.datatype(), value.datatype()))
+                }
+                None => DataType::Map(MapTypeMeta::new(DataType::Null, DataType::Null)),
+            },
         }
     }
 
@@ -200,6 +210,11 @@ impl ScalarValue<'_> {
             Self::List(v) => {
                 OwnedScalarValue::List(v.into_iter().map(|v| v.into_owned()).collect())
             }
+            Self::Map(v) => OwnedScalarValue::Map(
+                v.into_iter()
+                    .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                    .collect(),
+            ),
         }
     }
 
@@ -258,7 +273,7 @@ impl ScalarValue<'_> {
                     .into()
                 }
             }
-            other => not_implemented!("{other:?} to array"), // Struct, List
+            other => not_implemented!("{other:?} to array"), // Struct, Map
         };
 
         let mut array = Array::new_with_array_data(self.datatype(), data);
@@ -276,14 +291,16 @@ impl ScalarValue<'_> {
 
     pub fn try_as_usize(&self) -> Result<usize> {
         match self {
-            Self::Int8(i) => Ok((*i).try_into()?),
-            Self::Int16(i) => Ok((*i).try_into()?),
-            Self::Int32(i) => Ok((*i).try_into()?),
-            Self::Int64(i) => Ok((*i).try_into()?),
+            Self::Int8(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
+            Self::Int16(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
+            Self::Int32(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
+            Self::Int64(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
+            Self::Int128(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
             Self::UInt8(i) => Ok(*i as usize),
             Self::UInt16(i) => Ok(*i as usize),
-            Self::UInt32(i) => Ok(*i as usize),
-            Self::UInt64(i) => Ok(*i as usize),
+            Self::UInt32(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
+            Self::UInt64(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
+            Self::UInt128(i) => usize::try_from(*i).map_err(|_| int_conversion_error(i, "usize")),
             other => Err(RayexecError::new(format!("Not an integer: {other}"))),
         }
     }
@@ -409,6 +426,15 @@ impl fmt::Display for ScalarValue<'_> {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
+            Self::Map(entries) => write!(
+                f,
+                "{{{}}}",
+                entries
+                    .iter()
+                    .map(|(k, v)| format!("{k}={v}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
 }
@@ -523,7 +549,7 @@ impl ProtoConv for OwnedScalarValue {
 
     fn to_proto(&self) -> Result<Self::ProtoType> {
         use rayexec_proto::generated::expr::owned_scalar_value::Value;
-        use rayexec_proto::generated::expr::{EmptyScalar, ListScalar, StructScalar};
+        use rayexec_proto::generated::expr::{EmptyScalar, ListScalar, MapScalar, StructScalar};
 
         let value = match self {
             Self::Null => Value::ScalarNull(EmptyScalar {}),
@@ -557,6 +583,17 @@ impl ProtoConv for OwnedScalarValue {
                 let values = v.iter().map(|v| v.to_proto()).collect::<Result<Vec<_>>>()?;
                 Value::ScalarList(ListScalar { values })
             }
+            Self::Map(v) => {
+                let keys = v
+                    .iter()
+                    .map(|(k, _)| k.to_proto())
+                    .collect::<Result<Vec<_>>>()?;
+                let values = v
+                    .iter()
+                    .map(|(_, v)| v.to_proto())
+                    .collect::<Result<Vec<_>>>()?;
+                Value::ScalarMap(MapScalar { keys, values })
+            }
         };
         Ok(Self::ProtoType { value: Some(value) })
     }
@@ -567,16 +604,24 @@ impl ProtoConv for OwnedScalarValue {
         Ok(match proto.value.required("owned scalar value enum")? {
             Value::ScalarNull(_) => Self::Null,
             Value::ScalarBoolean(v) => Self::Boolean(v),
-            Value::ScalarInt8(v) => Self::Int8(v.try_into()?),
-            Value::ScalarInt16(v) => Self::Int16(v.try_into()?),
+            Value::ScalarInt8(v) => Self::Int8(
+                i8::try_from(v).map_err(|_| int_conversion_error(v, "i8"))?,
+            ),
+            Value::ScalarInt16(v) => Self::Int16(
+                i16::try_from(v).map_err(|_| int_conversion_error(v, "i16"))?,
+            ),
             Value::ScalarInt32(v) => Self::Int32(v),
             Value::ScalarInt64(v) => Self::Int64(v),
             Value::ScalarInt128(v) => Self::Int128(i128::from_le_bytes(
                 v.try_into()
                     .map_err(|_| RayexecError::new("byte buffer not 16 bytes"))?,
             )),
-            Value::ScalarUint8(v) => Self::UInt8(v.try_into()?),
-            Value::ScalarUint16(v) => Self::UInt16(v.try_into()?),
+            Value::ScalarUint8(v) => Self::UInt8(
+                u8::try_from(v).map_err(|_| int_conversion_error(v, "u8"))?,
+            ),
+            Value::ScalarUint16(v) => Self::UInt16(
+                u16::try_from(v).map_err(|_| int_conversion_error(v, "u16"))?,
+            ),
             Value::ScalarUint32(v) => Self::UInt32(v),
             Value::ScalarUint64(v) => Self::UInt64(v),
             Value::ScalarUint128(v) => Self::UInt128(u128::from_le_bytes(
@@ -610,6 +655,49 @@ impl ProtoConv for OwnedScalarValue {
                     .collect::<Result<Vec<_>>>()?;
                 Self::List(values)
             }
+            Value::ScalarMap(v) => {
+                if v.keys.len() != v.values.len() {
+                    return Err(RayexecError::new(
+                        "Map scalar has mismatched number of keys and values",
+                    ));
+                }
+                let entries = v
+                    .keys
+                    .into_iter()
+                    .zip(v.values)
+                    .map(|(k, v)| {
+                        Ok((
+                            OwnedScalarValue::from_proto(k)?,
+                            OwnedScalarValue::from_proto(v)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Self::Map(entries)
+            }
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_as_usize_out_of_range() {
+        assert_eq!(4, OwnedScalarValue::Int128(4).try_as_usize().unwrap());
+
+        let err = OwnedScalarValue::Int32(-5).try_as_usize().unwrap_err();
+        assert_eq!("Cannot convert -5 to usize", err.get_msg());
+    }
+
+    #[test]
+    fn from_proto_out_of_range() {
+        use rayexec_proto::generated::expr::owned_scalar_value::Value;
+
+        let proto = rayexec_proto::generated::expr::OwnedScalarValue {
+            value: Some(Value::ScalarInt8(300)),
+        };
+        let err = OwnedScalarValue::from_proto(proto).unwrap_err();
+        assert_eq!("Cannot convert 300 to i8", err.get_msg());
+    }
+}
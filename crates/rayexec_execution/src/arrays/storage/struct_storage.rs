@@ -0,0 +1,58 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::Array;
+
+/// Storage for struct arrays.
+///
+/// Each field is stored as a separate child array. The physical index of a row
+/// in the struct maps to the logical index in each of the child arrays.
+#[derive(Debug, PartialEq)]
+pub struct StructStorage {
+    pub(crate) len: usize,
+    pub(crate) arrays: Vec<Array>,
+}
+
+impl StructStorage {
+    pub fn try_new(len: usize, arrays: Vec<Array>) -> Result<Self> {
+        for array in &arrays {
+            if array.logical_len() != len {
+                return Err(RayexecError::new("Struct child array has incorrect length")
+                    .with_field("expected", len)
+                    .with_field("got", array.logical_len()));
+            }
+        }
+
+        Ok(StructStorage { len, arrays })
+    }
+
+    /// Create struct storage without any child arrays.
+    ///
+    /// Used for representing all-null struct arrays where the child arrays
+    /// will never be read.
+    pub fn empty(len: usize) -> Self {
+        StructStorage {
+            len,
+            arrays: Vec::new(),
+        }
+    }
+
+    /// Get the child array for a field.
+    ///
+    /// Returns None if the storage doesn't contain child arrays (all-null
+    /// struct array), or if the field index is out of bounds.
+    pub fn field_array(&self, idx: usize) -> Option<&Array> {
+        self.arrays.get(idx)
+    }
+
+    pub fn field_arrays(&self) -> &[Array] {
+        &self.arrays
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
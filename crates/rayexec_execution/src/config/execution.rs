@@ -5,12 +5,15 @@ use rayexec_error::{RayexecError, Result};
 pub struct IntermediatePlanConfig {
     /// If we should allow nested loop join.
     pub allow_nested_loop_join: bool,
+    /// Maximum number of iterations for recursive CTEs.
+    pub max_recursive_cte_iterations: usize,
 }
 
 impl Default for IntermediatePlanConfig {
     fn default() -> Self {
         IntermediatePlanConfig {
             allow_nested_loop_join: true,
+            max_recursive_cte_iterations: 10_000,
         }
     }
 }
@@ -3,7 +3,10 @@ use std::sync::LazyLock;
 
 use rayexec_error::{RayexecError, Result};
 
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
+use crate::logical::resolver::search_path::SearchPath;
 use crate::runtime::{PipelineExecutor, Runtime};
 
 /// Configuration for the session.
@@ -16,6 +19,19 @@ pub struct SessionConfig {
     pub batch_size: u64,
     pub verify_optimized_plan: bool,
     pub enable_function_chaining: bool,
+    pub query_seed: Option<u64>,
+    pub search_path: SearchPath,
+    pub sort_group_by_output: bool,
+    pub max_result_rows: u64,
+    pub integer_division: bool,
+    pub rounding_mode: RoundingMode,
+    pub arithmetic_overflow: OverflowBehavior,
+    pub max_recursive_cte_iterations: u64,
+    /// Overlay for settings changed with `SET LOCAL` in the current
+    /// transaction, holding the values from before the change.
+    ///
+    /// None if not in a transaction. Restored when the transaction ends.
+    transaction_locals: Option<HashMap<String, OwnedScalarValue>>,
 }
 
 impl SessionConfig {
@@ -32,6 +48,15 @@ impl SessionConfig {
             batch_size: 4096,
             verify_optimized_plan: false,
             enable_function_chaining: true,
+            query_seed: None,
+            search_path: SearchPath::default(),
+            sort_group_by_output: false,
+            max_result_rows: 0,
+            integer_division: true,
+            rounding_mode: RoundingMode::default(),
+            arithmetic_overflow: OverflowBehavior::default(),
+            max_recursive_cte_iterations: 10_000,
+            transaction_locals: None,
         }
     }
 
@@ -40,9 +65,71 @@ impl SessionConfig {
             .get(name)
             .ok_or_else(|| RayexecError::new("Missing setting for '{name}'"))?;
 
+        (func.set)(value, self)?;
+
+        // A session level SET takes precedence over an earlier SET LOCAL in the
+        // same transaction.
+        if let Some(locals) = self.transaction_locals.as_mut() {
+            locals.remove(name);
+        }
+
+        Ok(())
+    }
+
+    /// Set a value for only the duration of the current transaction.
+    ///
+    /// Errors if not in a transaction.
+    pub fn set_local_from_scalar(&mut self, name: &str, value: ScalarValue) -> Result<()> {
+        let func = GET_SET_FUNCTIONS
+            .get(name)
+            .ok_or_else(|| RayexecError::new("Missing setting for '{name}'"))?;
+
+        let original = (func.get)(self);
+        let locals = self
+            .transaction_locals
+            .as_mut()
+            .ok_or_else(|| RayexecError::new("SET LOCAL can only be used in transaction blocks"))?;
+        // Only keep the value from before the first SET LOCAL.
+        locals.entry(name.to_string()).or_insert(original);
+
         (func.set)(value, self)
     }
 
+    pub fn in_transaction(&self) -> bool {
+        self.transaction_locals.is_some()
+    }
+
+    /// Begin a transaction, allowing for SET LOCAL.
+    pub fn begin_transaction(&mut self) -> Result<()> {
+        if self.transaction_locals.is_some() {
+            return Err(RayexecError::new(
+                "There is already a transaction in progress",
+            ));
+        }
+        self.transaction_locals = Some(HashMap::new());
+        Ok(())
+    }
+
+    /// End the current transaction, reverting any settings changed with SET
+    /// LOCAL.
+    ///
+    /// This happens on both commit and rollback.
+    pub fn end_transaction(&mut self) -> Result<()> {
+        let locals = self
+            .transaction_locals
+            .take()
+            .ok_or_else(|| RayexecError::new("There is no transaction in progress"))?;
+
+        for (name, original) in locals {
+            let func = GET_SET_FUNCTIONS
+                .get(name.as_str())
+                .ok_or_else(|| RayexecError::new("Missing setting for '{name}'"))?;
+            (func.set)(original, self)?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_as_scalar(&self, name: &str) -> Result<OwnedScalarValue> {
         let func = GET_SET_FUNCTIONS
             .get(name)
@@ -73,7 +160,10 @@ impl SessionConfig {
         P: PipelineExecutor,
         R: Runtime,
     {
+        // Keep transaction state, RESET ALL doesn't end the transaction.
+        let transaction_locals = self.transaction_locals.take();
         *self = Self::new(executor, runtime);
+        self.transaction_locals = transaction_locals;
     }
 }
 
@@ -106,6 +196,14 @@ static GET_SET_FUNCTIONS: LazyLock<HashMap<&'static str, SettingFunctions>> = La
     insert_setting::<Partitions>(&mut map);
     insert_setting::<BatchSize>(&mut map);
     insert_setting::<EnableFunctionChaining>(&mut map);
+    insert_setting::<QuerySeed>(&mut map);
+    insert_setting::<SearchPathSetting>(&mut map);
+    insert_setting::<SortGroupByOutput>(&mut map);
+    insert_setting::<MaxResultRows>(&mut map);
+    insert_setting::<IntegerDivision>(&mut map);
+    insert_setting::<RoundingModeSetting>(&mut map);
+    insert_setting::<ArithmeticOverflowSetting>(&mut map);
+    insert_setting::<MaxRecursiveCteIterations>(&mut map);
 
     map
 });
@@ -238,6 +336,165 @@ impl SessionSetting for EnableFunctionChaining {
     }
 }
 
+pub struct QuerySeed;
+
+impl SessionSetting for QuerySeed {
+    const NAME: &'static str = "query_seed";
+    const DESCRIPTION: &'static str =
+        "Seed for nondeterministic functions in a query. NULL generates a new seed for every query";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        conf.query_seed = match scalar {
+            ScalarValue::Null => None,
+            other => Some(other.try_as_i64()? as u64),
+        };
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        match conf.query_seed {
+            Some(seed) => seed.into(),
+            None => ScalarValue::Null,
+        }
+    }
+}
+
+pub struct SearchPathSetting;
+
+impl SessionSetting for SearchPathSetting {
+    const NAME: &'static str = "search_path";
+    const DESCRIPTION: &'static str =
+        "Comma separated list of schemas to search for unqualified table references";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_into_string()?;
+        conf.search_path = SearchPath::parse(&val)?;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.search_path.to_string().into()
+    }
+}
+
+pub struct SortGroupByOutput;
+
+impl SessionSetting for SortGroupByOutput {
+    const NAME: &'static str = "sort_group_by_output";
+    const DESCRIPTION: &'static str =
+        "Sort the output of GROUP BY queries on the group keys when there's no ORDER BY";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_bool()?;
+        conf.sort_group_by_output = val;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.sort_group_by_output.into()
+    }
+}
+
+pub struct MaxResultRows;
+
+impl SessionSetting for MaxResultRows {
+    const NAME: &'static str = "max_result_rows";
+    const DESCRIPTION: &'static str =
+        "Maximum number of rows a query may return before erroring. Zero means unlimited";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_i64()?;
+        if val < 0 {
+            return Err(RayexecError::new(format!(
+                "max_result_rows cannot be negative, got {val}"
+            )));
+        }
+        conf.max_result_rows = val as u64;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.max_result_rows.into()
+    }
+}
+
+pub struct IntegerDivision;
+
+impl SessionSetting for IntegerDivision {
+    const NAME: &'static str = "integer_division";
+    const DESCRIPTION: &'static str =
+        "If dividing two integers truncates to an integer. If false, produces a float";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_bool()?;
+        conf.integer_division = val;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.integer_division.into()
+    }
+}
+
+pub struct RoundingModeSetting;
+
+impl SessionSetting for RoundingModeSetting {
+    const NAME: &'static str = "rounding_mode";
+    const DESCRIPTION: &'static str =
+        "Rounding mode used by ROUND and casts that need to round. One of 'half_even', 'half_up', or 'toward_zero'";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_str()?.parse()?;
+        conf.rounding_mode = val;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.rounding_mode.as_str().into()
+    }
+}
+
+pub struct ArithmeticOverflowSetting;
+
+impl SessionSetting for ArithmeticOverflowSetting {
+    const NAME: &'static str = "arithmetic_overflow";
+    const DESCRIPTION: &'static str =
+        "Behavior when integer or decimal arithmetic overflows. One of 'error', 'wrap', 'saturate', or 'null'";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_str()?.parse()?;
+        conf.arithmetic_overflow = val;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.arithmetic_overflow.as_str().into()
+    }
+}
+
+pub struct MaxRecursiveCteIterations;
+
+impl SessionSetting for MaxRecursiveCteIterations {
+    const NAME: &'static str = "max_recursive_cte_iterations";
+    const DESCRIPTION: &'static str =
+        "Maximum number of times the recursive term of a recursive CTE may be evaluated before erroring";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_i64()?;
+        if val < 0 {
+            return Err(RayexecError::new(format!(
+                "max_recursive_cte_iterations cannot be negative, got {val}"
+            )));
+        }
+        conf.max_recursive_cte_iterations = val as u64;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.max_recursive_cte_iterations.into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,6 +508,15 @@ mod tests {
             batch_size: 4096,
             verify_optimized_plan: false,
             enable_function_chaining: true,
+            query_seed: None,
+            search_path: SearchPath::default(),
+            sort_group_by_output: false,
+            max_result_rows: 0,
+            integer_division: true,
+            rounding_mode: RoundingMode::default(),
+            arithmetic_overflow: OverflowBehavior::default(),
+            max_recursive_cte_iterations: 10_000,
+            transaction_locals: None,
         }
     }
 
@@ -279,4 +545,102 @@ mod tests {
         let val = conf.get_as_scalar("partitions").unwrap();
         assert_eq!(ScalarValue::UInt64(13), val);
     }
+
+    #[test]
+    fn set_query_seed_null_unsets() {
+        let mut conf = new_test_config();
+        conf.set_from_scalar("query_seed", ScalarValue::Int32(42))
+            .unwrap();
+        assert_eq!(Some(42), conf.query_seed);
+        assert_eq!(
+            ScalarValue::UInt64(42),
+            conf.get_as_scalar("query_seed").unwrap()
+        );
+
+        conf.set_from_scalar("query_seed", ScalarValue::Null)
+            .unwrap();
+        assert_eq!(None, conf.query_seed);
+        assert_eq!(ScalarValue::Null, conf.get_as_scalar("query_seed").unwrap());
+    }
+
+    #[test]
+    fn set_rounding_mode() {
+        let mut conf = new_test_config();
+        assert_eq!(
+            ScalarValue::from("half_even"),
+            conf.get_as_scalar("rounding_mode").unwrap()
+        );
+
+        conf.set_from_scalar("rounding_mode", "half_up".into())
+            .unwrap();
+        assert_eq!(RoundingMode::HalfUp, conf.rounding_mode);
+
+        conf.set_from_scalar("rounding_mode", "half_down".into())
+            .unwrap_err();
+        assert_eq!(RoundingMode::HalfUp, conf.rounding_mode);
+    }
+
+    #[test]
+    fn set_arithmetic_overflow() {
+        let mut conf = new_test_config();
+        assert_eq!(
+            ScalarValue::from("error"),
+            conf.get_as_scalar("arithmetic_overflow").unwrap()
+        );
+
+        conf.set_from_scalar("arithmetic_overflow", "wrap".into())
+            .unwrap();
+        assert_eq!(OverflowBehavior::Wrap, conf.arithmetic_overflow);
+
+        conf.set_from_scalar("arithmetic_overflow", "saturate".into())
+            .unwrap();
+        assert_eq!(OverflowBehavior::Saturate, conf.arithmetic_overflow);
+        assert_eq!(
+            ScalarValue::from("saturate"),
+            conf.get_as_scalar("arithmetic_overflow").unwrap()
+        );
+
+        conf.set_from_scalar("arithmetic_overflow", "clamp".into())
+            .unwrap_err();
+        assert_eq!(OverflowBehavior::Saturate, conf.arithmetic_overflow);
+    }
+
+    #[test]
+    fn set_local_reverts_on_end_transaction() {
+        let mut conf = new_test_config();
+        conf.set_from_scalar("batch_size", 1024.into()).unwrap();
+
+        conf.begin_transaction().unwrap();
+        conf.set_local_from_scalar("batch_size", 16.into()).unwrap();
+        conf.set_local_from_scalar("batch_size", 32.into()).unwrap();
+        assert_eq!(32, conf.batch_size);
+
+        conf.end_transaction().unwrap();
+        assert_eq!(1024, conf.batch_size);
+        assert!(!conf.in_transaction());
+    }
+
+    #[test]
+    fn set_in_transaction_overrides_set_local() {
+        let mut conf = new_test_config();
+
+        conf.begin_transaction().unwrap();
+        conf.set_local_from_scalar("batch_size", 16.into()).unwrap();
+        conf.set_from_scalar("batch_size", 32.into()).unwrap();
+        conf.end_transaction().unwrap();
+
+        assert_eq!(32, conf.batch_size);
+    }
+
+    #[test]
+    fn set_local_requires_transaction() {
+        let mut conf = new_test_config();
+        conf.set_local_from_scalar("batch_size", 16.into())
+            .unwrap_err();
+        assert_eq!(4096, conf.batch_size);
+
+        conf.end_transaction().unwrap_err();
+        conf.begin_transaction().unwrap();
+        conf.begin_transaction().unwrap_err();
+    }
 }
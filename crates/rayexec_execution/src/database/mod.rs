@@ -8,6 +8,7 @@ pub mod system;
 
 mod catalog_map;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -77,6 +78,21 @@ pub struct Database {
     pub attach_info: Option<AttachInfo>,
 }
 
+/// Names of databases that are created for every context and can't be
+/// detached.
+const BUILTIN_DATABASES: &[&str] = &["system", "temp"];
+
+/// How database names are resolved when looking up, attaching, or detaching
+/// databases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameResolution {
+    /// Names must match exactly.
+    #[default]
+    CaseSensitive,
+    /// Names are folded to lowercase before being compared.
+    CaseInsensitive,
+}
+
 /// Root of all accessible catalogs.
 ///
 /// Attaching external databases falls outside the normal catalog flow, and so
@@ -84,6 +100,7 @@ pub struct Database {
 #[derive(Debug)]
 pub struct DatabaseContext {
     databases: HashMap<String, Database>,
+    name_resolution: NameResolution,
 }
 
 impl DatabaseContext {
@@ -93,6 +110,15 @@ impl DatabaseContext {
     /// By itself, this context cannot be used to persist data. Additional
     /// catalogs need to be attached via `attach_catalog`.
     pub fn new(system_catalog: Arc<MemoryCatalog>) -> Result<Self> {
+        Self::new_with_name_resolution(system_catalog, NameResolution::default())
+    }
+
+    /// Creates a new database context that resolves database names according
+    /// to `name_resolution`.
+    pub fn new_with_name_resolution(
+        system_catalog: Arc<MemoryCatalog>,
+        name_resolution: NameResolution,
+    ) -> Result<Self> {
         // TODO: Make system catalog actually read-only.
         let mut databases = HashMap::new();
 
@@ -125,7 +151,22 @@ impl DatabaseContext {
             },
         );
 
-        Ok(DatabaseContext { databases })
+        Ok(DatabaseContext {
+            databases,
+            name_resolution,
+        })
+    }
+
+    pub fn name_resolution(&self) -> NameResolution {
+        self.name_resolution
+    }
+
+    /// Normalize a database name according to the configured name resolution.
+    fn normalize_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match self.name_resolution {
+            NameResolution::CaseSensitive => Cow::Borrowed(name),
+            NameResolution::CaseInsensitive => Cow::Owned(name.to_lowercase()),
+        }
     }
 
     pub fn system_catalog(&self) -> Result<&MemoryCatalog> {
@@ -137,6 +178,7 @@ impl DatabaseContext {
 
     pub fn attach_database(&mut self, name: impl Into<String>, database: Database) -> Result<()> {
         let name = name.into();
+        let name = self.normalize_name(&name).into_owned();
         if self.databases.contains_key(&name) {
             return Err(RayexecError::new(format!(
                 "Catalog with name '{name}' already attached"
@@ -148,6 +190,14 @@ impl DatabaseContext {
     }
 
     pub fn detach_database(&mut self, name: &str) -> Result<()> {
+        let name = self.normalize_name(name);
+        let name = name.as_ref();
+        if BUILTIN_DATABASES.contains(&name) {
+            return Err(RayexecError::new(format!(
+                "Cannot detach builtin database '{name}'"
+            )));
+        }
+
         if self.databases.remove(name).is_none() {
             return Err(RayexecError::new(format!(
                 "Database with name '{name}' doesn't exist"
@@ -157,16 +207,160 @@ impl DatabaseContext {
     }
 
     pub fn database_exists(&self, name: &str) -> bool {
-        self.databases.contains_key(name)
+        self.databases
+            .contains_key(self.normalize_name(name).as_ref())
     }
 
     pub fn get_database(&self, name: &str) -> Result<&Database> {
         self.databases
-            .get(name)
+            .get(self.normalize_name(name).as_ref())
             .ok_or_else(|| RayexecError::new(format!("Missing catalog '{name}'")))
     }
 
+    /// Get the names of all attached databases, sorted by name.
+    pub fn database_names(&self) -> Vec<&str> {
+        let mut names: Vec<_> = self.databases.keys().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Iterate all attached databases, sorted by name.
     pub fn iter_databases(&self) -> impl Iterator<Item = (&String, &Database)> {
-        self.databases.iter()
+        let mut databases: Vec<_> = self.databases.iter().collect();
+        databases.sort_unstable_by_key(|(name, _)| *name);
+        databases.into_iter()
+    }
+
+    /// Iterate the catalogs for all attached databases, sorted by name.
+    pub fn iter_catalogs(&self) -> impl Iterator<Item = (&str, &MemoryCatalog)> {
+        self.iter_databases()
+            .map(|(name, database)| (name.as_str(), database.catalog.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_memory_database() -> Database {
+        Database {
+            catalog: Arc::new(MemoryCatalog::default()),
+            catalog_storage: None,
+            table_storage: Some(Arc::new(MemoryTableStorage::default())),
+            attach_info: None,
+        }
+    }
+
+    #[test]
+    fn attach_detach_user_database() {
+        let mut context = DatabaseContext::new(Arc::new(MemoryCatalog::default())).unwrap();
+
+        context
+            .attach_database("my_db", new_memory_database())
+            .unwrap();
+        assert!(context.database_exists("my_db"));
+
+        context.detach_database("my_db").unwrap();
+        assert!(!context.database_exists("my_db"));
+    }
+
+    #[test]
+    fn list_databases_sorted() {
+        let mut context = DatabaseContext::new(Arc::new(MemoryCatalog::default())).unwrap();
+
+        context
+            .attach_database("zoo", new_memory_database())
+            .unwrap();
+        context
+            .attach_database("aardvark", new_memory_database())
+            .unwrap();
+
+        let expected = vec!["aardvark", "system", "temp", "zoo"];
+        assert_eq!(expected, context.database_names());
+
+        let names: Vec<_> = context.iter_catalogs().map(|(name, _)| name).collect();
+        assert_eq!(expected, names);
+    }
+
+    #[test]
+    fn detach_builtin_database_errors() {
+        let mut context = DatabaseContext::new(Arc::new(MemoryCatalog::default())).unwrap();
+
+        context.detach_database("system").unwrap_err();
+        context.detach_database("temp").unwrap_err();
+
+        assert!(context.database_exists("system"));
+        assert!(context.database_exists("temp"));
+        context.system_catalog().unwrap();
+    }
+
+    #[test]
+    fn case_sensitive_resolution() {
+        let mut context = DatabaseContext::new(Arc::new(MemoryCatalog::default())).unwrap();
+        assert_eq!(NameResolution::CaseSensitive, context.name_resolution());
+
+        context
+            .attach_database("Foo", new_memory_database())
+            .unwrap();
+        assert!(context.database_exists("Foo"));
+        assert!(!context.database_exists("foo"));
+        context.get_database("foo").unwrap_err();
+        context.get_database("System").unwrap_err();
+
+        // Different case is a different database.
+        context
+            .attach_database("foo", new_memory_database())
+            .unwrap();
+        assert_eq!(
+            vec!["Foo", "foo", "system", "temp"],
+            context.database_names()
+        );
+
+        context.detach_database("FOO").unwrap_err();
+        context.detach_database("Foo").unwrap();
+        assert!(context.database_exists("foo"));
+    }
+
+    #[test]
+    fn case_insensitive_resolution() {
+        let mut context = DatabaseContext::new_with_name_resolution(
+            Arc::new(MemoryCatalog::default()),
+            NameResolution::CaseInsensitive,
+        )
+        .unwrap();
+
+        context.get_database("System").unwrap();
+        assert!(context.database_exists("TEMP"));
+
+        context
+            .attach_database("Foo", new_memory_database())
+            .unwrap();
+        assert!(context.database_exists("foo"));
+        assert!(context.database_exists("FOO"));
+        context.get_database("fOo").unwrap();
+        assert_eq!(vec!["foo", "system", "temp"], context.database_names());
+
+        // Builtins still can't be detached.
+        context.detach_database("SYSTEM").unwrap_err();
+
+        context.detach_database("FOO").unwrap();
+        assert!(!context.database_exists("foo"));
+    }
+
+    #[test]
+    fn case_insensitive_attach_conflict() {
+        let mut context = DatabaseContext::new_with_name_resolution(
+            Arc::new(MemoryCatalog::default()),
+            NameResolution::CaseInsensitive,
+        )
+        .unwrap();
+
+        context
+            .attach_database("Foo", new_memory_database())
+            .unwrap();
+        let err = context
+            .attach_database("foo", new_memory_database())
+            .unwrap_err();
+        assert_eq!("Catalog with name 'foo' already attached", err.get_msg());
     }
 }
@@ -7,7 +7,9 @@ mod verifier;
 
 use std::sync::Arc;
 
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
+use rayexec_parser::parser;
+use result::ExecutionResult;
 use server_state::ServerState;
 use session::Session;
 
@@ -69,6 +71,35 @@ where
         ))
     }
 
+    /// Execute a single sql statement in a new session, returning the
+    /// execution result containing a stream of the statement's output.
+    ///
+    /// Batches are yielded as they're produced instead of after the query
+    /// completes, so a client can begin reading rows from a long running
+    /// query early. Queries containing pipeline breaking operators (e.g. sorts
+    /// or aggregates) will only begin producing batches once that operator has
+    /// received all of its input.
+    ///
+    /// The query can be canceled through the handle on the returned result.
+    pub async fn execute_stream(&self, sql: &str) -> Result<ExecutionResult> {
+        const UNNAMED: &str = "";
+
+        let mut statements = parser::parse(sql)?;
+        let statement = match statements.len() {
+            1 => statements.pop().unwrap(),
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Expected 1 statement, got {other}"
+                )))
+            }
+        };
+
+        let mut session = self.new_session()?;
+        session.prepare(UNNAMED, statement)?;
+        session.bind(UNNAMED, UNNAMED).await?;
+        session.execute(UNNAMED).await
+    }
+
     pub fn new_server_state(&self) -> Result<ServerState<P, R>> {
         Ok(ServerState::new(
             self.executor.clone(),
@@ -18,11 +18,16 @@ use crate::runtime::handle::QueryHandle;
 use crate::runtime::ErrorSink;
 
 /// Create sinks and streams for sending query output to a client.
-pub fn new_results_sinks() -> (ResultStream, ResultSink, ResultErrorSink) {
+///
+/// If `max_rows` is provided, the sink will error once the query produces more
+/// than that many rows.
+pub fn new_results_sinks(max_rows: Option<usize>) -> (ResultStream, ResultSink, ResultErrorSink) {
     let inner = Arc::new(Mutex::new(InnerState {
         batch: None,
         error: None,
         finished: false,
+        num_rows: 0,
+        max_rows,
         push_waker: None,
         pull_waker: None,
     }));
@@ -160,6 +165,10 @@ struct InnerState {
     batch: Option<Batch>,
     error: Option<RayexecError>,
     finished: bool,
+    /// Total number of rows pushed to the sink.
+    num_rows: usize,
+    /// Max number of rows we're allowed to push before erroring.
+    max_rows: Option<usize>,
     push_waker: Option<Waker>,
     pull_waker: Option<Waker>,
 }
@@ -179,6 +188,17 @@ impl Future for PushFuture {
             return Poll::Pending;
         }
 
+        if let Some(batch) = &this.batch {
+            inner.num_rows += batch.num_rows();
+            if let Some(max_rows) = inner.max_rows {
+                if inner.num_rows > max_rows {
+                    return Poll::Ready(Err(RayexecError::new(format!(
+                        "Query result exceeded the max_result_rows limit of {max_rows}"
+                    ))));
+                }
+            }
+        }
+
         inner.batch = this.batch.take();
 
         if let Some(pull_waker) = inner.pull_waker.take() {
@@ -27,6 +27,7 @@ use crate::hybrid::client::HybridClient;
 use crate::logical::binder::bind_statement::StatementBinder;
 use crate::logical::logical_attach::LogicalAttachDatabase;
 use crate::logical::logical_set::VariableOrAll;
+use crate::logical::logical_transaction::LogicalTransaction;
 use crate::logical::operator::{LogicalOperator, Node};
 use crate::logical::planner::plan_statement::StatementPlanner;
 use crate::logical::resolver::resolve_context::ResolveContext;
@@ -245,6 +246,7 @@ where
             self.registry.get_file_handlers(),
             ResolveConfig {
                 enable_function_chaining: self.config.enable_function_chaining,
+                search_path: self.config.search_path.clone(),
             },
         )
         .resolve_statement(stmt.statement.clone())
@@ -255,7 +257,11 @@ where
             .plan_intermediate(resolved_stmt, resolve_context, resolve_mode, &mut profile)
             .await?;
 
-        let (stream, sink, errors) = new_results_sinks();
+        let max_result_rows = match self.config.max_result_rows {
+            0 => None,
+            n => Some(n as usize),
+        };
+        let (stream, sink, errors) = new_results_sinks(max_result_rows);
 
         let mut planner = ExecutablePipelinePlanner::<R>::new(
             &self.context,
@@ -356,7 +362,10 @@ where
                             t.column_names
                                 .iter()
                                 .zip(&t.column_types)
-                                .map(|(name, datatype)| Field::new(name, datatype.clone(), true))
+                                .zip(&t.column_nullable)
+                                .map(|((name, datatype), nullable)| {
+                                    Field::new(name, datatype.clone(), *nullable)
+                                })
                         }),
                 );
 
@@ -364,6 +373,8 @@ where
                 let planner = IntermediatePipelinePlanner::new(
                     IntermediatePlanConfig {
                         allow_nested_loop_join: self.config.allow_nested_loop_join,
+                        max_recursive_cte_iterations: self.config.max_recursive_cte_iterations
+                            as usize,
                     },
                     query_id,
                 );
@@ -389,8 +400,13 @@ where
                         // We could have an implementation for the local session, and a
                         // separate implementation used for nodes taking part in
                         // distributed execution.
-                        self.config
-                            .set_from_scalar(&set_var.node.name, set_var.node.value)?;
+                        let set_var = set_var.into_inner();
+                        if set_var.local {
+                            self.config
+                                .set_local_from_scalar(&set_var.name, set_var.value)?;
+                        } else {
+                            self.config.set_from_scalar(&set_var.name, set_var.value)?;
+                        }
                         planner.plan_pipelines(LogicalOperator::EMPTY, bind_context)?
                     }
                     LogicalOperator::ResetVar(reset) => {
@@ -405,6 +421,15 @@ where
                         }
                         planner.plan_pipelines(LogicalOperator::EMPTY, bind_context)?
                     }
+                    LogicalOperator::Transaction(transaction) => {
+                        match transaction.node {
+                            LogicalTransaction::Begin => self.config.begin_transaction()?,
+                            LogicalTransaction::Commit | LogicalTransaction::Rollback => {
+                                self.config.end_transaction()?
+                            }
+                        }
+                        planner.plan_pipelines(LogicalOperator::EMPTY, bind_context)?
+                    }
                     root => {
                         let timer = Timer::<R::Instant>::start();
                         let pipelines = planner.plan_pipelines(root, bind_context)?;
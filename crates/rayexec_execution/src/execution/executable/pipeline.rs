@@ -264,6 +264,17 @@ impl OperatorWithState {
     pub fn profile_data(&self) -> &OperatorProfileData {
         &self.profile_data
     }
+
+    /// Merge this partition's profile data into the metrics for the physical
+    /// operator.
+    ///
+    /// Should be called once this partition will no longer be pulling from
+    /// the operator.
+    fn merge_metrics(&self) {
+        if let Some(metrics) = self.physical.metrics() {
+            metrics.merge(&self.profile_data);
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -389,6 +400,8 @@ impl ExecutablePartitionPipeline {
                             return Poll::Pending;
                         }
                         Ok(PollPull::Exhausted) => {
+                            operator.merge_metrics();
+
                             // Finalize the next operator to indicate that it
                             // will no longer be receiving batch inputs.
                             *state = PipelinePartitionState::FinalizePush {
@@ -511,6 +524,14 @@ impl ExecutablePartitionPipeline {
                             // An example use of the Break is the LIMIT
                             // operator. It needs a way to signal that it needs
                             // no more batches.
+                            //
+                            // Operators we're skipping won't be exhausted, so
+                            // merge their metrics now.
+                            for skipped in
+                                &self.operators[self.pull_start.pull_start..*operator_idx]
+                            {
+                                skipped.merge_metrics();
+                            }
                             self.pull_start = PullStart {
                                 pull_start: *operator_idx,
                                 pull_stack: Vec::new(),
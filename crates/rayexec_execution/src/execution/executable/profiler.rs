@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
 use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::Duration;
 
 use super::pipeline::{ExecutablePartitionPipeline, PipelineId};
@@ -107,3 +108,34 @@ pub struct OperatorProfileData {
     /// Elapsed time while activley executing this operator.
     pub elapsed: Duration,
 }
+
+/// Metrics for a single physical operator, combined across all partitions.
+///
+/// Partition pipelines merge their operator profile data into this once
+/// they're done executing the operator, letting EXPLAIN ANALYZE report actuals
+/// for the operator as a whole.
+#[derive(Debug, Default)]
+pub struct OperatorMetrics {
+    rows_emitted: AtomicUsize,
+    elapsed_nanos: AtomicU64,
+}
+
+impl OperatorMetrics {
+    /// Merge profile data for one partition into these metrics.
+    pub fn merge(&self, data: &OperatorProfileData) {
+        self.rows_emitted
+            .fetch_add(data.rows_emitted, Ordering::Relaxed);
+        self.elapsed_nanos
+            .fetch_add(data.elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    /// Total number of rows produced by the operator.
+    pub fn rows_emitted(&self) -> usize {
+        self.rows_emitted.load(Ordering::Relaxed)
+    }
+
+    /// Total time spent executing the operator, summed across partitions.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+}
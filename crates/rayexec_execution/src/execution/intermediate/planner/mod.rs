@@ -15,11 +15,14 @@ mod plan_join;
 mod plan_limit;
 mod plan_magic_scan;
 mod plan_materialize_scan;
+mod plan_no_rows;
+mod plan_ordinality;
 mod plan_project;
 mod plan_scan;
 mod plan_set_operation;
 mod plan_show_var;
 mod plan_sort;
+mod plan_top_n;
 mod plan_unnest;
 
 use std::sync::Arc;
@@ -237,9 +240,11 @@ impl<'a> IntermediatePipelineBuildState<'a> {
                 self.plan_magic_join(id_gen, materializations, join)
             }
             LogicalOperator::Empty(empty) => self.plan_empty(id_gen, empty),
+            LogicalOperator::NoRows(no_rows) => self.plan_no_rows(id_gen, no_rows),
             LogicalOperator::Aggregate(agg) => self.plan_aggregate(id_gen, materializations, agg),
             LogicalOperator::Limit(limit) => self.plan_limit(id_gen, materializations, limit),
             LogicalOperator::Order(order) => self.plan_sort(id_gen, materializations, order),
+            LogicalOperator::TopN(top_n) => self.plan_top_n(id_gen, materializations, top_n),
             LogicalOperator::ShowVar(show_var) => self.plan_show_var(id_gen, show_var),
             LogicalOperator::Explain(explain) => {
                 self.plan_explain(id_gen, materializations, explain)
@@ -266,12 +271,18 @@ impl<'a> IntermediatePipelineBuildState<'a> {
                 self.plan_set_operation(id_gen, materializations, setop)
             }
             LogicalOperator::InOut(inout) => self.plan_inout(id_gen, materializations, inout),
+            LogicalOperator::Ordinality(ordinality) => {
+                self.plan_ordinality(id_gen, materializations, ordinality)
+            }
             LogicalOperator::SetVar(_) => {
                 Err(RayexecError::new("SET should be handled in the session"))
             }
             LogicalOperator::ResetVar(_) => {
                 Err(RayexecError::new("RESET should be handled in the session"))
             }
+            LogicalOperator::Transaction(_) => Err(RayexecError::new(
+                "Transaction statements should be handled in the session",
+            )),
             LogicalOperator::DetachDatabase(_) | LogicalOperator::AttachDatabase(_) => Err(
                 RayexecError::new("ATTACH/DETACH should be handled in the session"),
             ),
@@ -450,6 +461,51 @@ impl<'a> IntermediatePipelineBuildState<'a> {
         )
     }
 
+    /// Finishes the in-progress pipeline, starting a new in-progress pipeline
+    /// reading a single partition from it.
+    ///
+    /// Used after operators that accept n-partitions but produce only a single
+    /// partition (e.g. merging sorted partitions).
+    fn finish_single_partition_pipeline(
+        &mut self,
+        location: LocationRequirement,
+        id_gen: &mut PipelineIdGen,
+    ) -> Result<()> {
+        let in_progress = self.take_in_progress_pipeline()?;
+        self.in_progress = Some(InProgressPipeline {
+            id: id_gen.next_pipeline_id(),
+            operators: Vec::new(),
+            location,
+            source: PipelineSource::OtherPipeline {
+                pipeline: in_progress.id,
+                partitioning_requirement: Some(1),
+            },
+        });
+
+        let pipeline = IntermediatePipeline {
+            id: in_progress.id,
+            sink: PipelineSink::InPipeline,
+            source: in_progress.source,
+            operators: in_progress.operators,
+        };
+        // TODO: This should not be happening here.
+        // https://github.com/GlareDB/glaredb/issues/3352
+        match location {
+            LocationRequirement::ClientLocal => {
+                self.local_group.pipelines.insert(pipeline.id, pipeline);
+            }
+            LocationRequirement::Remote => {
+                self.remote_group.pipelines.insert(pipeline.id, pipeline);
+            }
+            LocationRequirement::Any => {
+                // TODO
+                self.local_group.pipelines.insert(pipeline.id, pipeline);
+            }
+        }
+
+        Ok(())
+    }
+
     fn finish(&mut self, id_gen: &mut PipelineIdGen) -> Result<()> {
         let mut in_progress = self.take_in_progress_pipeline()?;
         if in_progress.location == LocationRequirement::Any {
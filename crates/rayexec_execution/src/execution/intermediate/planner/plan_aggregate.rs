@@ -3,16 +3,18 @@ use std::sync::Arc;
 use rayexec_error::{RayexecError, Result, ResultExt};
 
 use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
+use crate::arrays::datatype::DataType;
 use crate::execution::intermediate::pipeline::IntermediateOperator;
 use crate::execution::operators::hash_aggregate::PhysicalHashAggregate;
 use crate::execution::operators::project::{PhysicalProject, ProjectOperation};
+use crate::execution::operators::streaming_aggregate::PhysicalStreamingAggregate;
 use crate::execution::operators::ungrouped_aggregate::PhysicalUngroupedAggregate;
 use crate::execution::operators::PhysicalOperator;
 use crate::expr::physical::column_expr::PhysicalColumnExpr;
 use crate::expr::physical::PhysicalAggregateExpression;
 use crate::expr::Expression;
 use crate::logical::logical_aggregate::LogicalAggregate;
-use crate::logical::operator::{LogicalNode, Node};
+use crate::logical::operator::{LogicalNode, LogicalOperator, Node};
 
 impl IntermediatePipelineBuildState<'_> {
     pub fn plan_aggregate(
@@ -25,6 +27,7 @@ impl IntermediatePipelineBuildState<'_> {
 
         let input = agg.take_one_child_exact()?;
         let input_refs = input.get_output_table_refs(self.bind_context);
+        let streaming = self.input_sorted_on_group_exprs(&agg.node, &input)?;
         self.walk(materializations, id_gen, input)?;
 
         let mut phys_aggs = Vec::new();
@@ -51,18 +54,34 @@ impl IntermediatePipelineBuildState<'_> {
             }
             let end_col_index = preproject_exprs.len();
 
+            // Filter is placed in the pre-projection right after the inputs.
+            let filter = match &agg.filter {
+                Some(filter) => {
+                    let scalar = self
+                        .expr_planner
+                        .plan_scalar(&input_refs, filter)
+                        .context("Failed to plan aggregate filter for pre-projection")?;
+                    preproject_exprs.push(scalar);
+                    Some(PhysicalColumnExpr { idx: end_col_index })
+                }
+                None => None,
+            };
+
             let phys_agg = PhysicalAggregateExpression {
                 function: agg.agg,
                 columns: (start_col_index..end_col_index)
                     .map(|idx| PhysicalColumnExpr { idx })
                     .collect(),
                 is_distinct: agg.distinct,
+                filter,
             };
 
             phys_aggs.push(phys_agg);
         }
 
         // Place group by expressions in pre-projection as well.
+        let group_columns: Vec<_> =
+            (preproject_exprs.len()..preproject_exprs.len() + agg.node.group_exprs.len()).collect();
         for group_expr in agg.node.group_exprs {
             let scalar = self
                 .expr_planner
@@ -77,9 +96,9 @@ impl IntermediatePipelineBuildState<'_> {
 
         self.push_intermediate_operator(
             IntermediateOperator {
-                operator: Arc::new(PhysicalOperator::Project(PhysicalProject {
-                    operation: ProjectOperation::new(preproject_exprs),
-                })),
+                operator: Arc::new(PhysicalOperator::Project(PhysicalProject::new(
+                    ProjectOperation::new(preproject_exprs),
+                ))),
                 partitioning_requirement: None,
             },
             location,
@@ -87,6 +106,19 @@ impl IntermediatePipelineBuildState<'_> {
         )?;
 
         match agg.node.grouping_sets {
+            Some(_) if streaming => {
+                // Input is sorted on the group keys, we can emit groups as
+                // they complete instead of building a hash table.
+                //
+                // Input ordering is only preserved within a single partition.
+                let operator = IntermediateOperator {
+                    operator: Arc::new(PhysicalOperator::StreamingAggregate(
+                        PhysicalStreamingAggregate::new(phys_aggs, group_columns),
+                    )),
+                    partitioning_requirement: Some(1),
+                };
+                self.push_intermediate_operator(operator, location, id_gen)?;
+            }
             Some(grouping_sets) => {
                 // If we're working with groups, push a hash aggregate operator.
                 let operator = IntermediateOperator {
@@ -116,4 +148,72 @@ impl IntermediatePipelineBuildState<'_> {
 
         Ok(())
     }
+
+    /// Check if the input to an aggregate is sorted such that all rows for a
+    /// group are next to each other.
+    ///
+    /// This is the case when the input is an ORDER BY whose leading sort
+    /// expressions are exactly the group expressions (in any order). Only a
+    /// single grouping set containing all group expressions is supported.
+    fn input_sorted_on_group_exprs(
+        &self,
+        agg: &LogicalAggregate,
+        input: &LogicalOperator,
+    ) -> Result<bool> {
+        let order = match input {
+            LogicalOperator::Order(order) => order,
+            _ => return Ok(false),
+        };
+
+        let num_groups = agg.group_exprs.len();
+        match &agg.grouping_sets {
+            Some(sets) if sets.len() == 1 && sets[0].len() == num_groups => (),
+            _ => return Ok(false),
+        }
+        if num_groups == 0 || !agg.grouping_functions.is_empty() {
+            return Ok(false);
+        }
+
+        if order.node.exprs.len() < num_groups {
+            return Ok(false);
+        }
+
+        // Look through a projection below the sort so that different column
+        // references to the same expression compare equal.
+        let resolve = |expr: &Expression| -> Expression {
+            if let (Expression::Column(col), [LogicalOperator::Project(project)]) =
+                (expr, order.children.as_slice())
+            {
+                if col.table_scope == project.node.projection_table {
+                    if let Some(projected) = project.node.projections.get(col.column) {
+                        return projected.clone();
+                    }
+                }
+            }
+            expr.clone()
+        };
+
+        let group_exprs: Vec<_> = agg.group_exprs.iter().map(resolve).collect();
+        let sort_exprs: Vec<_> = order.node.exprs[..num_groups]
+            .iter()
+            .map(|sort| resolve(&sort.expr))
+            .collect();
+
+        let all_sorted = group_exprs.iter().all(|expr| sort_exprs.contains(expr));
+        let all_grouped = sort_exprs.iter().all(|expr| group_exprs.contains(expr));
+        if !all_sorted || !all_grouped {
+            return Ok(false);
+        }
+
+        // Group keys are compared using the row encoding, which doesn't support
+        // nested types.
+        for expr in &agg.group_exprs {
+            let datatype = expr.datatype(self.bind_context.get_table_list())?;
+            if matches!(datatype, DataType::List(_) | DataType::Struct(_)) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
 }
@@ -28,6 +28,8 @@ impl IntermediatePipelineBuildState<'_> {
                     copy_to: copy_to.node.copy_to,
                     location: copy_to.node.location,
                     schema: copy_to.node.source_schema,
+                    args: copy_to.node.args,
+                    partition_by: copy_to.node.partition_by,
                 },
             ))),
             // This should be temporary until there's a better understanding of
@@ -25,7 +25,7 @@ impl IntermediatePipelineBuildState<'_> {
 
         let names = Array::from_iter(describe.node.schema.iter().map(|f| f.name.as_str()));
         let datatypes =
-            Array::from_iter(describe.node.schema.iter().map(|f| f.datatype.to_string()));
+            Array::from_iter(describe.node.schema.iter().map(|f| f.datatype.sql_name()));
         let batch = Batch::try_new(vec![names, datatypes])?;
 
         let operator = IntermediateOperator {
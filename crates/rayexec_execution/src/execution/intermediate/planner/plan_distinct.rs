@@ -1,11 +1,10 @@
-use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use rayexec_error::Result;
 
 use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
 use crate::execution::intermediate::pipeline::IntermediateOperator;
-use crate::execution::operators::hash_aggregate::PhysicalHashAggregate;
+use crate::execution::operators::distinct::PhysicalDistinct;
 use crate::execution::operators::project::{PhysicalProject, ProjectOperation};
 use crate::execution::operators::PhysicalOperator;
 use crate::logical::logical_distinct::LogicalDistinct;
@@ -22,37 +21,25 @@ impl IntermediatePipelineBuildState<'_> {
         let input_refs = input.get_output_table_refs(self.bind_context);
         self.walk(materializations, id_gen, input)?;
 
-        // Create group expressions from the distinct.
-        let group_types = distinct
-            .node
-            .on
-            .iter()
-            .map(|expr| expr.datatype(self.bind_context.get_table_list()))
-            .collect::<Result<Vec<_>>>()?;
-        let group_exprs = self
+        let exprs = self
             .expr_planner
             .plan_scalars(&input_refs, &distinct.node.on)?;
 
         self.push_intermediate_operator(
             IntermediateOperator {
-                operator: Arc::new(PhysicalOperator::Project(PhysicalProject {
-                    operation: ProjectOperation::new(group_exprs),
-                })),
+                operator: Arc::new(PhysicalOperator::Project(PhysicalProject::new(
+                    ProjectOperation::new(exprs),
+                ))),
                 partitioning_requirement: None,
             },
             distinct.location,
             id_gen,
         )?;
 
-        let grouping_sets: Vec<BTreeSet<usize>> = vec![(0..group_types.len()).collect()];
-
+        // Rows seen are shared across partitions, no need to repartition.
         self.push_intermediate_operator(
             IntermediateOperator {
-                operator: Arc::new(PhysicalOperator::HashAggregate(PhysicalHashAggregate::new(
-                    Vec::new(),
-                    grouping_sets,
-                    Vec::new(),
-                ))),
+                operator: Arc::new(PhysicalOperator::Distinct(PhysicalDistinct)),
                 partitioning_requirement: None,
             },
             distinct.location,
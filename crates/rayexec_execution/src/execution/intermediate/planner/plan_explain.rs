@@ -1,17 +1,22 @@
 use std::sync::Arc;
 
-use rayexec_error::{not_implemented, RayexecError, Result};
+use rayexec_error::{not_implemented, OptionExt, RayexecError, Result};
 use tracing::error;
 
 use super::{InProgressPipeline, IntermediatePipelineBuildState, Materializations, PipelineIdGen};
 use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
-use crate::execution::intermediate::pipeline::{IntermediateOperator, PipelineSource};
+use crate::execution::intermediate::pipeline::{
+    IntermediateOperator,
+    PipelineSink,
+    PipelineSource,
+};
+use crate::execution::operators::analyze::{AnalyzedPipeline, PhysicalAnalyze};
 use crate::execution::operators::values::PhysicalValues;
 use crate::execution::operators::PhysicalOperator;
 use crate::explain::context_display::ContextDisplayMode;
-use crate::explain::explainable::ExplainConfig;
-use crate::explain::formatter::ExplainFormatter;
+use crate::explain::explainable::{ExplainConfig, Explainable};
+use crate::explain::formatter::{intermediate_pipeline_entry, ExplainFormatter};
 use crate::logical::logical_explain::LogicalExplain;
 use crate::logical::operator::Node;
 
@@ -29,7 +34,7 @@ impl IntermediatePipelineBuildState<'_> {
         }
 
         if explain.node.analyze {
-            not_implemented!("explain analyze")
+            return self.plan_explain_analyze(id_gen, materializations, explain);
         }
 
         // Plan in seperate planner to avoid conmingling pipelines we will be
@@ -101,4 +106,87 @@ impl IntermediatePipelineBuildState<'_> {
 
         Ok(())
     }
+
+    /// Plan EXPLAIN ANALYZE.
+    ///
+    /// Unlike EXPLAIN, the input is planned as part of the pipelines we'll be
+    /// executing, with the analyze operator discarding the query's output. The
+    /// operators planned for the input are captured prior to execution so that
+    /// the metrics they record can be reported.
+    fn plan_explain_analyze(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut explain: Node<LogicalExplain>,
+    ) -> Result<()> {
+        let location = explain.location;
+
+        let config = ExplainConfig {
+            context_mode: ContextDisplayMode::Enriched(self.bind_context),
+            verbose: explain.node.verbose,
+        };
+        let formatter = ExplainFormatter::new(self.bind_context, config, explain.node.format);
+
+        let mut plans = vec![(
+            "unoptimized".to_string(),
+            formatter.format_logical_plan(&explain.node.logical_unoptimized)?,
+        )];
+        if let Some(optimized) = &explain.node.logical_optimized {
+            plans.push((
+                "optimized".to_string(),
+                formatter.format_logical_plan(optimized)?,
+            ));
+        }
+
+        let input = explain.take_one_child_exact()?;
+        self.walk(materializations, id_gen, input)?;
+
+        if !self.remote_group.is_empty() {
+            not_implemented!("explain analyze with remote pipelines")
+        }
+
+        let analyzed_operators = |operators: &[IntermediateOperator]| {
+            operators
+                .iter()
+                .map(|op| (op.operator.explain_entry(config), op.operator.clone()))
+                .collect()
+        };
+
+        let mut local_pipelines: Vec<_> = self.local_group.pipelines.values().collect();
+        local_pipelines.sort_by_key(|pipeline| pipeline.id);
+
+        let mut pipelines: Vec<_> = local_pipelines
+            .into_iter()
+            .map(|pipeline| AnalyzedPipeline {
+                entry: intermediate_pipeline_entry(pipeline.id, &pipeline.sink, &pipeline.source),
+                operators: analyzed_operators(&pipeline.operators),
+            })
+            .collect();
+
+        // The in-progress pipeline will be finished with the analyze operator
+        // as the query output.
+        let in_progress = self.in_progress.as_ref().required("in-progress pipeline")?;
+        pipelines.push(AnalyzedPipeline {
+            entry: intermediate_pipeline_entry(
+                in_progress.id,
+                &PipelineSink::QueryOutput,
+                &in_progress.source,
+            ),
+            operators: analyzed_operators(&in_progress.operators),
+        });
+
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::Analyze(PhysicalAnalyze::new(
+                explain.node.format,
+                plans,
+                pipelines,
+            ))),
+            // Analyze needs all input before producing its output.
+            partitioning_requirement: Some(1),
+        };
+
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        Ok(())
+    }
 }
@@ -153,7 +153,10 @@ impl IntermediatePipelineBuildState<'_> {
         } else {
             // Need to fall back to nested loop join.
 
-            if join.node.join_type != JoinType::Inner {
+            if !matches!(
+                join.node.join_type,
+                JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full
+            ) {
                 not_implemented!("join type with nl join: {}", join.node.join_type);
             }
 
@@ -204,16 +207,15 @@ impl IntermediatePipelineBuildState<'_> {
             )
             .context("Failed to plan expressions arbitrary join filter")?;
 
-        // Modify the filter as to match the join type.
-        let filter = match join.node.join_type {
-            JoinType::Inner => filter,
+        match join.node.join_type {
+            JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full => (),
             other => {
                 // TODO: Other join types.
                 return Err(RayexecError::new(format!(
                     "Unhandled join type for arbitrary join: {other:?}"
                 )));
             }
-        };
+        }
 
         let [left, right] = join.take_two_children_exact()?;
 
@@ -266,6 +268,19 @@ impl IntermediatePipelineBuildState<'_> {
     ) -> Result<()> {
         self.config.check_nested_loop_join_allowed()?;
 
+        // Types needed for producing null columns during outer joins.
+        let mut left_types = Vec::new();
+        for table_ref in left.get_output_table_refs(self.bind_context) {
+            let table = self.bind_context.get_table(table_ref)?;
+            left_types.extend(table.column_types.iter().cloned());
+        }
+
+        let mut right_types = Vec::new();
+        for table_ref in right.get_output_table_refs(self.bind_context) {
+            let table = self.bind_context.get_table(table_ref)?;
+            right_types.extend(table.column_types.iter().cloned());
+        }
+
         // Continue to build up all the inputs into the right side.
         self.walk(materializations, id_gen, right)?;
 
@@ -289,7 +304,7 @@ impl IntermediatePipelineBuildState<'_> {
 
         let operator = IntermediateOperator {
             operator: Arc::new(PhysicalOperator::NestedLoopJoin(
-                PhysicalNestedLoopJoin::new(filter, join_type),
+                PhysicalNestedLoopJoin::new(filter, join_type, left_types, right_types),
             )),
             partitioning_requirement: None,
         };
@@ -1,13 +1,14 @@
 use std::sync::Arc;
 
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
 use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
 use crate::execution::intermediate::pipeline::IntermediateOperator;
 use crate::execution::operators::limit::PhysicalLimit;
 use crate::execution::operators::PhysicalOperator;
+use crate::expr::physical::PhysicalScalarExpression;
 use crate::logical::logical_limit::LogicalLimit;
-use crate::logical::operator::Node;
+use crate::logical::operator::{LogicalNode, Node};
 
 impl IntermediatePipelineBuildState<'_> {
     pub fn plan_limit(
@@ -18,16 +19,38 @@ impl IntermediatePipelineBuildState<'_> {
     ) -> Result<()> {
         let location = limit.location;
         let input = limit.take_one_child_exact()?;
+        let input_refs = input.get_output_table_refs(self.bind_context);
 
         self.walk(materializations, id_gen, input)?;
 
+        let physical_limit = match &limit.node.with_ties {
+            Some(exprs) => {
+                let tie_columns = exprs
+                    .iter()
+                    .map(
+                        |expr| match self.expr_planner.plan_scalar(&input_refs, expr)? {
+                            PhysicalScalarExpression::Column(col) => Ok(col),
+                            other => Err(RayexecError::new(format!(
+                                "Expected column expression for WITH TIES, got: {other}"
+                            ))),
+                        },
+                    )
+                    .collect::<Result<Vec<_>>>()?;
+
+                let count = limit
+                    .node
+                    .limit
+                    .ok_or_else(|| RayexecError::new("WITH TIES requires a limit"))?;
+
+                PhysicalLimit::new_with_ties(count, limit.node.offset, tie_columns)
+            }
+            None => PhysicalLimit::new(limit.node.limit, limit.node.offset),
+        };
+
         // This is a global limit, ensure this operator is only receiving a
         // single input partition.
         let operator = IntermediateOperator {
-            operator: Arc::new(PhysicalOperator::Limit(PhysicalLimit::new(
-                limit.node.limit,
-                limit.node.offset,
-            ))),
+            operator: Arc::new(PhysicalOperator::Limit(physical_limit)),
             partitioning_requirement: Some(1),
         };
 
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use rayexec_error::{RayexecError, Result};
+
+use super::{InProgressPipeline, IntermediatePipelineBuildState, PipelineIdGen};
+use crate::execution::intermediate::pipeline::{IntermediateOperator, PipelineSource};
+use crate::execution::operators::values::PhysicalValues;
+use crate::execution::operators::PhysicalOperator;
+use crate::logical::logical_no_rows::LogicalNoRows;
+use crate::logical::operator::Node;
+
+impl IntermediatePipelineBuildState<'_> {
+    pub fn plan_no_rows(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        no_rows: Node<LogicalNoRows>,
+    ) -> Result<()> {
+        if self.in_progress.is_some() {
+            return Err(RayexecError::new("Expected in progress to be None"));
+        }
+
+        // Values operator with no batches, immediately exhausted for every
+        // partition. Since no batches are ever produced, we don't need to
+        // worry about the output columns matching what operators higher up in
+        // the pipeline expect.
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::Values(PhysicalValues::new(Vec::new()))),
+            partitioning_requirement: None,
+        };
+
+        self.in_progress = Some(InProgressPipeline {
+            id: id_gen.next_pipeline_id(),
+            operators: vec![operator],
+            location: no_rows.location,
+            source: PipelineSource::InPipeline,
+        });
+
+        Ok(())
+    }
+}
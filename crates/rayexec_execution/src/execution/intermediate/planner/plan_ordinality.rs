@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use rayexec_error::Result;
+
+use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
+use crate::execution::intermediate::pipeline::IntermediateOperator;
+use crate::execution::operators::ordinality::PhysicalOrdinality;
+use crate::execution::operators::PhysicalOperator;
+use crate::logical::logical_ordinality::LogicalOrdinality;
+use crate::logical::operator::Node;
+
+impl IntermediatePipelineBuildState<'_> {
+    pub fn plan_ordinality(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut ordinality: Node<LogicalOrdinality>,
+    ) -> Result<()> {
+        let location = ordinality.location;
+        let input = ordinality.take_one_child_exact()?;
+        self.walk(materializations, id_gen, input)?;
+
+        // Row numbers need to be unique across all rows, ensure this operator
+        // is only receiving a single input partition.
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::Ordinality(PhysicalOrdinality)),
+            partitioning_requirement: Some(1),
+        };
+
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        Ok(())
+    }
+}
@@ -6,10 +6,14 @@ use super::{InProgressPipeline, IntermediatePipelineBuildState, PipelineIdGen};
 use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
 use crate::execution::intermediate::pipeline::{IntermediateOperator, PipelineSource};
+use crate::execution::operators::project::ProjectOperation;
 use crate::execution::operators::scan::PhysicalScan;
+use crate::execution::operators::simple::SimpleOperator;
 use crate::execution::operators::table_function::PhysicalTableFunction;
 use crate::execution::operators::values::PhysicalValues;
 use crate::execution::operators::PhysicalOperator;
+use crate::expr::physical::column_expr::PhysicalColumnExpr;
+use crate::expr::physical::PhysicalScalarExpression;
 use crate::expr::Expression;
 use crate::logical::logical_scan::{LogicalScan, ScanSource};
 use crate::logical::operator::Node;
@@ -32,6 +36,34 @@ impl IntermediatePipelineBuildState<'_> {
             Projections::all()
         };
 
+        if scan.node.source == ScanSource::WorkingTable {
+            // Batches from the working table are fed directly into the
+            // recursive term by the recursive CTE operator, so there's no scan
+            // operator. We just need to handle pruned columns.
+            let mut operators = Vec::new();
+            if let Some(indices) = projections.column_indices {
+                let exprs = indices
+                    .into_iter()
+                    .map(|idx| PhysicalScalarExpression::Column(PhysicalColumnExpr { idx }))
+                    .collect();
+                operators.push(IntermediateOperator {
+                    operator: Arc::new(PhysicalOperator::Project(SimpleOperator::new(
+                        ProjectOperation::new(exprs),
+                    ))),
+                    partitioning_requirement: None,
+                });
+            }
+
+            self.in_progress = Some(InProgressPipeline {
+                id: id_gen.next_pipeline_id(),
+                operators,
+                location,
+                source: PipelineSource::InPipeline,
+            });
+
+            return Ok(());
+        }
+
         let operator = match scan.node.source {
             ScanSource::Table {
                 catalog,
@@ -47,10 +79,10 @@ impl IntermediatePipelineBuildState<'_> {
                 partitioning_requirement: None,
             },
             ScanSource::TableFunction { function } => IntermediateOperator {
-                operator: Arc::new(PhysicalOperator::TableFunction(PhysicalTableFunction::new(
-                    function,
-                    projections,
-                ))),
+                operator: Arc::new(PhysicalOperator::TableFunction(
+                    PhysicalTableFunction::new(function, projections)
+                        .with_filters(scan.node.scan_filters),
+                )),
                 partitioning_requirement: None,
             },
             ScanSource::ExpressionList { rows } => {
@@ -61,6 +93,7 @@ impl IntermediatePipelineBuildState<'_> {
                 }
             }
             ScanSource::View { .. } => not_implemented!("view physical planning"),
+            ScanSource::WorkingTable => unreachable!("working table scan handled above"),
         };
 
         self.in_progress = Some(InProgressPipeline {
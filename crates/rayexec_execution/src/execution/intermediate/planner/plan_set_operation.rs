@@ -3,8 +3,10 @@ use std::sync::Arc;
 use rayexec_error::{not_implemented, Result};
 
 use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
-use crate::execution::intermediate::pipeline::IntermediateOperator;
-use crate::execution::operators::hash_aggregate::PhysicalHashAggregate;
+use crate::execution::intermediate::pipeline::{IntermediateOperator, PipelineSource};
+use crate::execution::operators::distinct::PhysicalDistinct;
+use crate::execution::operators::hash_setop::PhysicalHashSetOp;
+use crate::execution::operators::recursive_cte::PhysicalRecursiveCte;
 use crate::execution::operators::union::PhysicalUnion;
 use crate::execution::operators::PhysicalOperator;
 use crate::logical::logical_setop::{LogicalSetop, SetOpKind};
@@ -17,6 +19,10 @@ impl IntermediatePipelineBuildState<'_> {
         materializations: &mut Materializations,
         mut setop: Node<LogicalSetop>,
     ) -> Result<()> {
+        if setop.node.recursive {
+            return self.plan_recursive_cte(id_gen, materializations, setop);
+        }
+
         let location = setop.location;
 
         let [left, right] = setop.take_two_children_exact()?;
@@ -48,32 +54,86 @@ impl IntermediatePipelineBuildState<'_> {
 
                 // The union operator is the "sink" for the bottom pipeline.
                 self.push_as_child_pipeline(bottom_in_progress, 1)?;
-            }
-            other => not_implemented!("set op {other}"),
-        }
 
-        // Make output distinct by grouping on all columns. No output
-        // aggregates, so the output schema remains the same.
-        if !setop.node.all {
-            let output_types = self
-                .bind_context
-                .get_table(setop.node.table_ref)?
-                .column_types
-                .clone();
-
-            let grouping_sets = vec![(0..output_types.len()).collect()];
-
-            let operator =
-                IntermediateOperator {
-                    operator: Arc::new(PhysicalOperator::HashAggregate(
-                        PhysicalHashAggregate::new(Vec::new(), grouping_sets, Vec::new()),
-                    )),
+                // UNION without ALL removes duplicate rows from the combined
+                // output. Both sides have already been cast to the same types.
+                if !setop.node.all {
+                    let operator = IntermediateOperator {
+                        operator: Arc::new(PhysicalOperator::Distinct(PhysicalDistinct)),
+                        partitioning_requirement: None,
+                    };
+
+                    self.push_intermediate_operator(operator, location, id_gen)?;
+                }
+            }
+            SetOpKind::Intersect | SetOpKind::Except => {
+                // Handles both ALL and distinct semantics itself.
+                let operator = IntermediateOperator {
+                    operator: Arc::new(PhysicalOperator::HashSetOp(PhysicalHashSetOp::try_new(
+                        setop.node.kind,
+                        setop.node.all,
+                    )?)),
                     partitioning_requirement: None,
                 };
 
-            self.push_intermediate_operator(operator, location, id_gen)?;
+                self.push_intermediate_operator(operator, location, id_gen)?;
+
+                // Bottom builds the hash table.
+                self.push_as_child_pipeline(
+                    bottom_in_progress,
+                    PhysicalHashSetOp::BUILD_SIDE_INPUT_INDEX,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Plans a recursive CTE.
+    ///
+    /// The anchor is planned as normal and feeds into the recursive CTE
+    /// operator. The recursive term is planned separately, and its operators
+    /// are executed by the recursive CTE operator itself for each iteration.
+    fn plan_recursive_cte(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut setop: Node<LogicalSetop>,
+    ) -> Result<()> {
+        let location = setop.location;
+
+        let [anchor, recursive] = setop.take_two_children_exact()?;
+
+        self.walk(materializations, id_gen, anchor)?;
+
+        let mut term_builder = IntermediatePipelineBuildState::new(self.config, self.bind_context);
+        term_builder.walk(materializations, id_gen, recursive)?;
+        let term = term_builder.take_in_progress_pipeline()?;
+
+        // The working table scan doesn't produce an operator, so the
+        // recursive term needs to be a single pipeline starting with it.
+        if !term_builder.local_group.is_empty()
+            || !term_builder.remote_group.is_empty()
+            || term.source != PipelineSource::InPipeline
+        {
+            not_implemented!("Recursive CTE with a recursive term requiring multiple pipelines");
         }
 
+        let recursive_term = term.operators.into_iter().map(|op| op.operator).collect();
+
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::RecursiveCte(
+                PhysicalRecursiveCte::try_new(
+                    recursive_term,
+                    setop.node.all,
+                    self.config.max_recursive_cte_iterations,
+                )?,
+            )),
+            partitioning_requirement: None,
+        };
+
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
         Ok(())
     }
 }
@@ -2,18 +2,13 @@ use std::sync::Arc;
 
 use rayexec_error::Result;
 
-use super::{InProgressPipeline, IntermediatePipelineBuildState, Materializations, PipelineIdGen};
-use crate::execution::intermediate::pipeline::{
-    IntermediateOperator,
-    IntermediatePipeline,
-    PipelineSink,
-    PipelineSource,
-};
+use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
+use crate::execution::intermediate::pipeline::IntermediateOperator;
 use crate::execution::operators::sort::gather_sort::PhysicalGatherSort;
 use crate::execution::operators::sort::scatter_sort::PhysicalScatterSort;
 use crate::execution::operators::PhysicalOperator;
 use crate::logical::logical_order::LogicalOrder;
-use crate::logical::operator::{LocationRequirement, LogicalNode, Node};
+use crate::logical::operator::{LogicalNode, Node};
 
 impl IntermediatePipelineBuildState<'_> {
     pub fn plan_sort(
@@ -58,38 +53,7 @@ impl IntermediatePipelineBuildState<'_> {
 
         // Global sorting accepts n-partitions, but produces only a single
         // partition. We finish the current pipeline
-
-        let in_progress = self.take_in_progress_pipeline()?;
-        self.in_progress = Some(InProgressPipeline {
-            id: id_gen.next_pipeline_id(),
-            operators: Vec::new(),
-            location,
-            source: PipelineSource::OtherPipeline {
-                pipeline: in_progress.id,
-                partitioning_requirement: Some(1),
-            },
-        });
-
-        let pipeline = IntermediatePipeline {
-            id: in_progress.id,
-            sink: PipelineSink::InPipeline,
-            source: in_progress.source,
-            operators: in_progress.operators,
-        };
-        // TODO: This should not be happening here.
-        // https://github.com/GlareDB/glaredb/issues/3352
-        match location {
-            LocationRequirement::ClientLocal => {
-                self.local_group.pipelines.insert(pipeline.id, pipeline);
-            }
-            LocationRequirement::Remote => {
-                self.remote_group.pipelines.insert(pipeline.id, pipeline);
-            }
-            LocationRequirement::Any => {
-                // TODO
-                self.local_group.pipelines.insert(pipeline.id, pipeline);
-            }
-        }
+        self.finish_single_partition_pipeline(location, id_gen)?;
 
         Ok(())
     }
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use rayexec_error::Result;
+
+use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
+use crate::execution::intermediate::pipeline::IntermediateOperator;
+use crate::execution::operators::limit::PhysicalLimit;
+use crate::execution::operators::sort::gather_sort::PhysicalGatherSort;
+use crate::execution::operators::sort::top_n::PhysicalTopN;
+use crate::execution::operators::PhysicalOperator;
+use crate::logical::logical_top_n::LogicalTopN;
+use crate::logical::operator::{LogicalNode, Node};
+
+impl IntermediatePipelineBuildState<'_> {
+    pub fn plan_top_n(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut top_n: Node<LogicalTopN>,
+    ) -> Result<()> {
+        let location = top_n.location;
+
+        let input = top_n.take_one_child_exact()?;
+        let input_refs = input.get_output_table_refs(self.bind_context);
+        self.walk(materializations, id_gen, input)?;
+
+        let exprs = self
+            .expr_planner
+            .plan_sorts(&input_refs, &top_n.node.exprs)?;
+
+        // Each partition needs to keep enough rows to satisfy the offset as
+        // well.
+        let local_limit = top_n
+            .node
+            .limit
+            .saturating_add(top_n.node.offset.unwrap_or(0));
+
+        // Partition-local top N.
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::LocalTopN(PhysicalTopN::new(
+                exprs.clone(),
+                local_limit,
+            ))),
+            partitioning_requirement: None,
+        };
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        // Merge the partition-local results.
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::MergeSorted(PhysicalGatherSort::new(
+                exprs,
+            ))),
+            partitioning_requirement: None,
+        };
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        // Same as sorting, the merge produces a single partition, finish the
+        // current pipeline.
+        self.finish_single_partition_pipeline(location, id_gen)?;
+
+        // Apply the final limit and offset to the merged output.
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::Limit(PhysicalLimit::new(
+                Some(top_n.node.limit),
+                top_n.node.offset,
+            ))),
+            partitioning_requirement: Some(1),
+        };
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        Ok(())
+    }
+}
@@ -1,31 +1,96 @@
-use std::task::Context;
+use std::sync::Arc;
+use std::task::{Context, Waker};
 
 use rayexec_error::Result;
 
 use super::{
     ExecutableOperator,
     ExecutionStates,
+    InputOutputStates,
     OperatorState,
     PartitionState,
+    PhysicalOperator,
     PollFinalize,
     PollPull,
     PollPush,
 };
+use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::explain::formatter::format_analyzed_pipelines;
+use crate::logical::logical_explain::ExplainFormat;
+
+/// A pipeline being analyzed along with the operators that make it up.
+#[derive(Debug)]
+pub struct AnalyzedPipeline {
+    /// Entry describing the pipeline itself.
+    pub entry: ExplainEntry,
+    /// Explain entries for each operator, along with the operator so we can
+    /// get its metrics once execution completes.
+    pub operators: Vec<(ExplainEntry, Arc<PhysicalOperator>)>,
+}
+
+#[derive(Debug)]
+pub struct AnalyzePartitionState {
+    /// If all input has been received.
+    finished: bool,
+    /// If we've already produced the output batch.
+    emitted: bool,
+    /// Waker on the pull side, woken once we've received all input.
+    pull_waker: Option<Waker>,
+}
 
 /// Physical operator for EXPLAIN ANALYZE.
+///
+/// Discards all of its input, then produces a single batch containing the
+/// explained plans, with the physical plan annotated with metrics recorded
+/// during execution.
+///
+/// This should receive a single input partition.
 #[derive(Debug)]
-pub struct PhysicalAnalyze {}
+pub struct PhysicalAnalyze {
+    format: ExplainFormat,
+    /// Plans that were formatted during planning, as (type, plan) pairs.
+    plans: Vec<(String, String)>,
+    /// Pipelines to annotate with metrics.
+    pipelines: Vec<AnalyzedPipeline>,
+}
+
+impl PhysicalAnalyze {
+    pub fn new(
+        format: ExplainFormat,
+        plans: Vec<(String, String)>,
+        pipelines: Vec<AnalyzedPipeline>,
+    ) -> Self {
+        PhysicalAnalyze {
+            format,
+            plans,
+            pipelines,
+        }
+    }
+}
 
 impl ExecutableOperator for PhysicalAnalyze {
     fn create_states(
         &self,
         _context: &DatabaseContext,
-        _partitions: Vec<usize>,
+        partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
-        unimplemented!()
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::None),
+            partition_states: InputOutputStates::OneToOne {
+                partition_states: (0..partitions[0])
+                    .map(|_| {
+                        PartitionState::Analyze(AnalyzePartitionState {
+                            finished: false,
+                            emitted: false,
+                            pull_waker: None,
+                        })
+                    })
+                    .collect(),
+            },
+        })
     }
 
     fn poll_push(
@@ -35,25 +100,69 @@ impl ExecutableOperator for PhysicalAnalyze {
         _operator_state: &OperatorState,
         _batch: Batch,
     ) -> Result<PollPush> {
-        unimplemented!()
+        // Output of the query is discarded, we only care about the metrics.
+        Ok(PollPush::NeedsMore)
     }
 
     fn poll_finalize_push(
         &self,
         _cx: &mut Context,
-        _partition_state: &mut PartitionState,
+        partition_state: &mut PartitionState,
         _operator_state: &OperatorState,
     ) -> Result<PollFinalize> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::Analyze(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        state.finished = true;
+
+        if let Some(waker) = state.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollFinalize::Finalized)
     }
 
     fn poll_pull(
         &self,
-        _cx: &mut Context,
-        _partition_state: &mut PartitionState,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
         _operator_state: &OperatorState,
     ) -> Result<PollPull> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::Analyze(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        if state.emitted {
+            return Ok(PollPull::Exhausted);
+        }
+
+        if !state.finished {
+            state.pull_waker = Some(cx.waker().clone());
+            return Ok(PollPull::Pending);
+        }
+
+        let mut type_strings: Vec<String> = Vec::with_capacity(self.plans.len() + 1);
+        let mut plan_strings: Vec<String> = Vec::with_capacity(self.plans.len() + 1);
+
+        for (typ, plan) in &self.plans {
+            type_strings.push(typ.clone());
+            plan_strings.push(plan.clone());
+        }
+
+        type_strings.push("physical_analyzed".to_string());
+        plan_strings.push(format_analyzed_pipelines(self.format, &self.pipelines)?);
+
+        state.emitted = true;
+
+        let batch = Batch::try_new([
+            Array::from_iter(type_strings),
+            Array::from_iter(plan_strings),
+        ])?;
+
+        Ok(PollPull::Computed(batch.into()))
     }
 }
 
@@ -1,12 +1,21 @@
-use rayexec_error::{OptionExt, Result};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use rayexec_error::{OptionExt, RayexecError, Result};
 use rayexec_io::location::FileLocation;
 use rayexec_proto::ProtoConv;
 
 use super::sink::{PartitionSink, SinkOperation, SinkOperator};
+use crate::arrays::batch::Batch;
 use crate::arrays::field::Schema;
+use crate::arrays::scalar::ScalarValue;
+use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
-use crate::functions::copy::CopyToFunction;
+use crate::functions::copy::{CopyToArgs, CopyToFunction};
 use crate::proto::DatabaseProtoConv;
 
 pub type PhysicalCopyTo = SinkOperator<CopyToOperation>;
@@ -16,6 +25,9 @@ pub struct CopyToOperation {
     pub copy_to: Box<dyn CopyToFunction>,
     pub location: FileLocation,
     pub schema: Schema,
+    pub args: CopyToArgs,
+    /// Indices of columns to partition the output by.
+    pub partition_by: Vec<usize>,
 }
 
 impl SinkOperation for CopyToOperation {
@@ -24,8 +36,21 @@ impl SinkOperation for CopyToOperation {
         _context: &DatabaseContext,
         num_sinks: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>> {
-        self.copy_to
-            .create_sinks(self.schema.clone(), self.location.clone(), num_sinks)
+        if self.partition_by.is_empty() {
+            return self.copy_to.create_sinks(
+                self.schema.clone(),
+                self.location.clone(),
+                self.args.clone(),
+                num_sinks,
+            );
+        }
+
+        (0..num_sinks)
+            .map(|idx| {
+                let sink = PartitionedCopyToSink::try_new(self, idx)?;
+                Ok(Box::new(sink) as _)
+            })
+            .collect()
     }
 
     fn partition_requirement(&self) -> Option<usize> {
@@ -36,10 +61,176 @@ impl SinkOperation for CopyToOperation {
 
 impl Explainable for CopyToOperation {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("CopyTo").with_value("location", &self.location)
+        let mut ent = ExplainEntry::new("CopyTo").with_value("location", &self.location);
+        if !self.partition_by.is_empty() {
+            ent = ent.with_values("partition_by", &self.partition_by);
+        }
+        ent
+    }
+}
+
+/// Directory name used for NULL partition values, matching hive.
+const HIVE_NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Sink for writing hive-partitioned output.
+///
+/// Each row is routed to a file in a `col1=val1/col2=val2/` directory under the
+/// COPY TO location based on its partition key. Sinks for each partition are
+/// created from the COPY TO function on first write. Partition columns are
+/// omitted from the written data.
+#[derive(Debug)]
+pub struct PartitionedCopyToSink {
+    copy_to: Box<dyn CopyToFunction>,
+    location: FileLocation,
+    args: CopyToArgs,
+    /// Partition column indices and names.
+    partition_columns: Vec<(usize, String)>,
+    /// Indices of columns that are written out.
+    data_columns: Vec<usize>,
+    /// Schema of written data, excluding partition columns.
+    data_schema: Schema,
+    /// Name of the file written to in each partition directory.
+    file_name: String,
+    /// Sinks keyed by partition directory.
+    sinks: HashMap<String, Box<dyn PartitionSink>>,
+}
+
+impl PartitionedCopyToSink {
+    fn try_new(operation: &CopyToOperation, partition_idx: usize) -> Result<Self> {
+        let partition_columns = operation
+            .partition_by
+            .iter()
+            .map(|&idx| {
+                let field = operation.schema.fields.get(idx).ok_or_else(|| {
+                    RayexecError::new(format!("Missing partition column at index {idx}"))
+                })?;
+                Ok((idx, field.name.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let data_columns: Vec<_> = (0..operation.schema.fields.len())
+            .filter(|idx| !operation.partition_by.contains(idx))
+            .collect();
+        let data_schema = Schema::new(
+            data_columns
+                .iter()
+                .map(|&idx| operation.schema.fields[idx].clone()),
+        );
+
+        let file_name = match operation.copy_to.file_extension() {
+            Some(ext) => format!("data_{partition_idx}.{ext}"),
+            None => format!("data_{partition_idx}"),
+        };
+
+        Ok(PartitionedCopyToSink {
+            copy_to: operation.copy_to.clone(),
+            location: operation.location.clone(),
+            args: operation.args.clone(),
+            partition_columns,
+            data_columns,
+            data_schema,
+            file_name,
+            sinks: HashMap::new(),
+        })
+    }
+
+    /// Split the batch by partition key, returning the partition directory
+    /// and selection for each key in order of first appearance.
+    fn split_partitions(&self, batch: &Batch) -> Result<Vec<(String, SelectionVector)>> {
+        let mut partitions: Vec<(String, Vec<usize>)> = Vec::new();
+        let mut dir_indices: HashMap<String, usize> = HashMap::new();
+
+        for row_idx in 0..batch.num_rows() {
+            let mut dir = String::new();
+            for (col_idx, (idx, name)) in self.partition_columns.iter().enumerate() {
+                if col_idx > 0 {
+                    dir.push('/');
+                }
+                let val = batch
+                    .column(*idx)
+                    .required("partition column")?
+                    .logical_value(row_idx)?;
+                write!(dir, "{}={}", escape_partition_value(name), hive_value(&val))?;
+            }
+
+            match dir_indices.get(&dir) {
+                Some(&idx) => partitions[idx].1.push(row_idx),
+                None => {
+                    dir_indices.insert(dir.clone(), partitions.len());
+                    partitions.push((dir, vec![row_idx]));
+                }
+            }
+        }
+
+        Ok(partitions
+            .into_iter()
+            .map(|(dir, rows)| (dir, SelectionVector::from(rows)))
+            .collect())
+    }
+}
+
+impl PartitionSink for PartitionedCopyToSink {
+    fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+        async move {
+            let data = batch.project(&self.data_columns);
+
+            for (dir, selection) in self.split_partitions(&batch)? {
+                let sink = match self.sinks.get_mut(&dir) {
+                    Some(sink) => sink,
+                    None => {
+                        let location = self
+                            .location
+                            .join(dir.split('/').chain([self.file_name.as_str()]))?;
+                        let sink = self
+                            .copy_to
+                            .create_sinks(self.data_schema.clone(), location, self.args.clone(), 1)?
+                            .pop()
+                            .required("partition sink")?;
+                        self.sinks.entry(dir).or_insert(sink)
+                    }
+                };
+
+                sink.push(data.select(Arc::new(selection))).await?;
+            }
+
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+        async move {
+            for sink in self.sinks.values_mut() {
+                sink.finalize().await?;
+            }
+            Ok(())
+        }
+        .boxed()
+    }
+}
+
+/// Format a partition value for use in a directory name.
+fn hive_value(val: &ScalarValue) -> String {
+    match val {
+        ScalarValue::Null => HIVE_NULL_PARTITION.to_string(),
+        val => escape_partition_value(&val.to_string()),
     }
 }
 
+/// Percent-encode characters that can't appear in a partition directory name.
+fn escape_partition_value(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '/' | '\\' | '=' | '%' | ':' | '#' | '?' | '*' | '"' | '\'' | '\n' | '\r' => {
+                escaped.push_str(&format!("%{:02X}", c as u32))
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 impl DatabaseProtoConv for PhysicalCopyTo {
     type ProtoType = rayexec_proto::generated::execution::PhysicalCopyTo;
 
@@ -48,6 +239,8 @@ impl DatabaseProtoConv for PhysicalCopyTo {
             copy_to: Some(self.sink.copy_to.to_proto_ctx(context)?),
             location: Some(self.sink.location.to_proto()?),
             schema: Some(self.sink.schema.to_proto()?),
+            args: Some(self.sink.args.to_proto()?),
+            partition_by: self.sink.partition_by.iter().map(|&idx| idx as u64).collect(),
         })
     }
 
@@ -59,6 +252,190 @@ impl DatabaseProtoConv for PhysicalCopyTo {
             )?,
             location: ProtoConv::from_proto(proto.location.required("location")?)?,
             schema: ProtoConv::from_proto(proto.schema.required("schema")?)?,
+            args: ProtoConv::from_proto(proto.args.required("args")?)?,
+            partition_by: proto.partition_by.into_iter().map(|idx| idx as usize).collect(),
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use parking_lot::Mutex;
+
+    use super::*;
+    use crate::arrays::array::Array;
+    use crate::arrays::datatype::DataType;
+    use crate::arrays::field::Field;
+    use crate::execution::operators::test_util::test_database_context;
+
+    type Written = Arc<Mutex<Vec<(FileLocation, Schema, Vec<Batch>)>>>;
+
+    /// COPY TO function that records the batches written to each location.
+    #[derive(Debug, Clone, Default)]
+    struct RecordingCopyTo {
+        written: Written,
+    }
+
+    impl CopyToFunction for RecordingCopyTo {
+        fn name(&self) -> &'static str {
+            "recording_copy_to"
+        }
+
+        fn file_extension(&self) -> Option<&'static str> {
+            Some("rec")
+        }
+
+        fn create_sinks(
+            &self,
+            schema: Schema,
+            location: FileLocation,
+            _args: CopyToArgs,
+            num_partitions: usize,
+        ) -> Result<Vec<Box<dyn PartitionSink>>> {
+            Ok((0..num_partitions)
+                .map(|_| {
+                    Box::new(RecordingSink {
+                        location: location.clone(),
+                        schema: schema.clone(),
+                        batches: Vec::new(),
+                        written: self.written.clone(),
+                    }) as _
+                })
+                .collect())
+        }
+    }
+
+    #[derive(Debug)]
+    struct RecordingSink {
+        location: FileLocation,
+        schema: Schema,
+        batches: Vec<Batch>,
+        written: Written,
+    }
+
+    impl PartitionSink for RecordingSink {
+        fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+            self.batches.push(batch);
+            async { Ok(()) }.boxed()
+        }
+
+        fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+            self.written.lock().push((
+                self.location.clone(),
+                self.schema.clone(),
+                std::mem::take(&mut self.batches),
+            ));
+            async { Ok(()) }.boxed()
+        }
+    }
+
+    #[test]
+    fn partitioned_round_trip() {
+        let copy_to = RecordingCopyTo::default();
+        let operation = CopyToOperation {
+            copy_to: Box::new(copy_to.clone()),
+            location: FileLocation::Path(PathBuf::from("out")),
+            schema: Schema::new([
+                Field::new("year", DataType::Int32, true),
+                Field::new("amount", DataType::Int64, true),
+                Field::new("region", DataType::Utf8, true),
+            ]),
+            args: CopyToArgs {
+                named: HashMap::new(),
+            },
+            partition_by: vec![0, 2],
+        };
+
+        let input = [
+            (Some(2023), 1, Some("us/east")),
+            (Some(2024), 2, Some("eu")),
+            (Some(2023), 3, Some("us/east")),
+            (None, 4, Some("eu")),
+            (Some(2024), 5, None),
+        ];
+
+        let mut sinks = operation
+            .create_partition_sinks(&test_database_context(), 1)
+            .unwrap();
+        let sink = &mut sinks[0];
+        futures::executor::block_on(async {
+            // Split across two batches so partitions are written to more than
+            // once.
+            for rows in [&input[..2], &input[2..]] {
+                let batch = Batch::try_new([
+                    Array::from_iter(rows.iter().map(|r| r.0)),
+                    Array::from_iter(rows.iter().map(|r| r.1 as i64)),
+                    Array::from_iter(rows.iter().map(|r| r.2)),
+                ])
+                .unwrap();
+                sink.push(batch).await.unwrap();
+            }
+            sink.finalize().await.unwrap();
+        });
+
+        // Read back, recovering partition columns from the hive directories.
+        let mut dirs = Vec::new();
+        let mut output = Vec::new();
+        for (location, schema, batches) in copy_to.written.lock().iter() {
+            let path = match location {
+                FileLocation::Path(path) => path.strip_prefix("out").unwrap(),
+                other => panic!("unexpected location: {other}"),
+            };
+            assert_eq!(Some("data_0.rec"), path.file_name().unwrap().to_str());
+            assert_eq!(
+                vec!["amount"],
+                schema.fields.iter().map(|f| &f.name).collect::<Vec<_>>()
+            );
+
+            let dir = path.parent().unwrap();
+            dirs.push(dir.to_path_buf());
+            let keys: HashMap<_, _> = dir
+                .iter()
+                .map(|seg| {
+                    let (key, val) = seg.to_str().unwrap().split_once('=').unwrap();
+                    (key.to_string(), val.to_string())
+                })
+                .collect();
+
+            for batch in batches {
+                assert_eq!(1, batch.num_columns());
+                for row_idx in 0..batch.num_rows() {
+                    let amount = batch.column(0).unwrap().logical_value(row_idx).unwrap();
+                    output.push((
+                        keys["year"].clone(),
+                        amount.try_as_i64().unwrap(),
+                        keys["region"].clone(),
+                    ));
+                }
+            }
+        }
+
+        dirs.sort();
+        assert_eq!(
+            vec![
+                Path::new("year=2023/region=us%2Feast"),
+                Path::new("year=2024/region=__HIVE_DEFAULT_PARTITION__"),
+                Path::new("year=2024/region=eu"),
+                Path::new("year=__HIVE_DEFAULT_PARTITION__/region=eu"),
+            ],
+            dirs
+        );
+
+        output.sort_by_key(|row| row.1);
+        let expected: Vec<_> = input
+            .iter()
+            .map(|(year, amount, region)| {
+                let year = year.map(|y| y.to_string());
+                let region = region.map(|r| r.replace('/', "%2F"));
+                (
+                    year.unwrap_or_else(|| HIVE_NULL_PARTITION.to_string()),
+                    *amount as i64,
+                    region.unwrap_or_else(|| HIVE_NULL_PARTITION.to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(expected, output);
+    }
+}
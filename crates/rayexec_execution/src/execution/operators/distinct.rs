@@ -0,0 +1,393 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::task::{Context, Waker};
+
+use parking_lot::Mutex;
+use rayexec_error::Result;
+
+use super::hash_setop::batch_rows;
+use super::util::hash::partition_for_hash;
+use super::{
+    ExecutableOperator,
+    ExecutionStates,
+    InputOutputStates,
+    OperatorState,
+    PartitionState,
+    PollFinalize,
+    PollPull,
+    PollPush,
+};
+use crate::arrays::batch::Batch;
+use crate::arrays::executor::scalar::HashExecutor;
+use crate::arrays::row::OwnedScalarRow;
+use crate::arrays::selection::SelectionVector;
+use crate::database::DatabaseContext;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::proto::DatabaseProtoConv;
+
+#[derive(Debug)]
+pub struct DistinctPartitionState {
+    /// Rows from the last pushed batch that haven't been seen before, waiting
+    /// to be pulled.
+    buffered: Option<Batch>,
+    /// Waker on the pull side if no batch is ready.
+    pull_waker: Option<Waker>,
+    /// Waker on the push side if this partition is already buffering a batch.
+    push_waker: Option<Waker>,
+    /// If the input is finished.
+    exhausted: bool,
+    /// Reusable buffer for row hashes.
+    hash_buf: Vec<u64>,
+}
+
+#[derive(Debug)]
+pub struct DistinctOperatorState {
+    /// Rows seen so far across all partitions, one table per partition.
+    ///
+    /// Rows are assigned to a table by their hash, so each table holds a
+    /// disjoint set of rows and pushes only contend on the tables they touch.
+    seen: Vec<Mutex<HashSet<OwnedScalarRow>>>,
+}
+
+/// Removes duplicate rows from the input, emitting each unique row once.
+///
+/// All columns of the input make up the row being compared. NULLs are treated
+/// as equal to each other. Rows are streamed through as they're first seen,
+/// with the set of seen rows being hash partitioned across per-partition
+/// tables.
+#[derive(Debug)]
+pub struct PhysicalDistinct;
+
+impl ExecutableOperator for PhysicalDistinct {
+    fn create_states(
+        &self,
+        _context: &DatabaseContext,
+        partitions: Vec<usize>,
+    ) -> Result<ExecutionStates> {
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::Distinct(DistinctOperatorState {
+                seen: (0..partitions[0])
+                    .map(|_| Mutex::new(HashSet::new()))
+                    .collect(),
+            })),
+            partition_states: InputOutputStates::OneToOne {
+                partition_states: (0..partitions[0])
+                    .map(|_| {
+                        PartitionState::Distinct(DistinctPartitionState {
+                            buffered: None,
+                            pull_waker: None,
+                            push_waker: None,
+                            exhausted: false,
+                            hash_buf: Vec::new(),
+                        })
+                    })
+                    .collect(),
+            },
+        })
+    }
+
+    fn poll_push(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+        batch: Batch,
+    ) -> Result<PollPush> {
+        let state = match partition_state {
+            PartitionState::Distinct(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+        let operator_state = match operator_state {
+            OperatorState::Distinct(state) => state,
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        if state.buffered.is_some() {
+            state.push_waker = Some(cx.waker().clone());
+            if let Some(waker) = state.pull_waker.take() {
+                waker.wake();
+            }
+            return Ok(PollPush::Pending(batch));
+        }
+
+        // Split rows by the table they belong to so each table is only locked
+        // once per batch.
+        let num_tables = operator_state.seen.len();
+        state.hash_buf.clear();
+        state.hash_buf.resize(batch.num_rows(), 0);
+        let hashes = HashExecutor::hash_many(batch.columns(), &mut state.hash_buf)?;
+
+        let mut table_rows: Vec<Vec<(usize, OwnedScalarRow)>> = vec![Vec::new(); num_tables];
+        for (idx, (row, hash)) in batch_rows(&batch)?.into_iter().zip(hashes).enumerate() {
+            table_rows[partition_for_hash(*hash, num_tables)].push((idx, row));
+        }
+
+        let mut unseen = Vec::new();
+        for (table_idx, rows) in table_rows.into_iter().enumerate() {
+            if rows.is_empty() {
+                continue;
+            }
+            let mut seen = operator_state.seen[table_idx].lock();
+            unseen.extend(
+                rows.into_iter()
+                    .filter_map(|(idx, row)| seen.insert(row).then_some(idx)),
+            );
+        }
+
+        // Keep rows in input order.
+        unseen.sort_unstable();
+        let selection = SelectionVector::from(unseen);
+
+        if selection.is_empty() {
+            // Every row was a duplicate, nothing to output.
+            return Ok(PollPush::NeedsMore);
+        }
+
+        let batch = if selection.len() == batch.num_rows() {
+            batch
+        } else {
+            batch.select(Arc::new(selection))
+        };
+
+        state.buffered = Some(batch);
+        if let Some(waker) = state.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollPush::Pushed)
+    }
+
+    fn poll_finalize_push(
+        &self,
+        _cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollFinalize> {
+        let state = match partition_state {
+            PartitionState::Distinct(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        state.exhausted = true;
+        if let Some(waker) = state.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollFinalize::Finalized)
+    }
+
+    fn poll_pull(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let state = match partition_state {
+            PartitionState::Distinct(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        match state.buffered.take() {
+            Some(batch) => {
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Computed(batch.into()))
+            }
+            None => {
+                if state.exhausted {
+                    return Ok(PollPull::Exhausted);
+                }
+
+                state.pull_waker = Some(cx.waker().clone());
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Pending)
+            }
+        }
+    }
+}
+
+impl Explainable for PhysicalDistinct {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("Distinct")
+    }
+}
+
+impl DatabaseProtoConv for PhysicalDistinct {
+    type ProtoType = rayexec_proto::generated::execution::PhysicalDistinct;
+
+    fn to_proto_ctx(&self, _context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {})
+    }
+
+    fn from_proto_ctx(_proto: Self::ProtoType, _context: &DatabaseContext) -> Result<Self> {
+        Ok(PhysicalDistinct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::array::Array;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::execution::operators::test_util::{
+        logical_value,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+
+    /// Create a two column batch, `None` values are NULL.
+    fn make_batch(rows: &[(Option<i32>, Option<&str>)]) -> Batch {
+        let mut a = Array::from_iter(rows.iter().map(|row| row.0.unwrap_or_default()));
+        let mut b = Array::from_iter(rows.iter().map(|row| row.1.unwrap_or_default()));
+        for (idx, row) in rows.iter().enumerate() {
+            a.set_physical_validity(idx, row.0.is_some());
+            b.set_physical_validity(idx, row.1.is_some());
+        }
+        Batch::try_new([a, b]).unwrap()
+    }
+
+    fn create_states(
+        operator: &PhysicalDistinct,
+        partitions: usize,
+    ) -> (Arc<OperatorState>, Vec<PartitionState>) {
+        let states = operator
+            .create_states(&test_database_context(), vec![partitions])
+            .unwrap();
+        match states.partition_states {
+            InputOutputStates::OneToOne { partition_states } => {
+                (states.operator_state, partition_states)
+            }
+            other => panic!("unexpected states: {other:?}"),
+        }
+    }
+
+    /// Push a batch and pull the output, returning the output rows.
+    fn push_and_pull(
+        operator: &Arc<PhysicalDistinct>,
+        operator_state: &OperatorState,
+        partition_state: &mut PartitionState,
+        batch: Batch,
+    ) -> Vec<(ScalarValue<'static>, ScalarValue<'static>)> {
+        let cx = TestWakerContext::new();
+        let poll_push = cx
+            .poll_push(operator, partition_state, operator_state, batch)
+            .unwrap();
+        if poll_push == PollPush::NeedsMore {
+            // All rows were duplicates.
+            return Vec::new();
+        }
+        assert_eq!(PollPush::Pushed, poll_push);
+
+        let poll_pull = cx
+            .poll_pull(operator, partition_state, operator_state)
+            .unwrap();
+        let out = unwrap_poll_pull_batch(poll_pull);
+        (0..out.num_rows())
+            .map(|row| {
+                (
+                    logical_value(&out, 0, row).into_owned(),
+                    logical_value(&out, 1, row).into_owned(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn distinct_nulls_equal() {
+        let operator = Arc::new(PhysicalDistinct);
+        let (operator_state, mut partition_states) = create_states(&operator, 1);
+
+        let out = push_and_pull(
+            &operator,
+            &operator_state,
+            &mut partition_states[0],
+            make_batch(&[
+                (Some(1), Some("a")),
+                (Some(1), Some("a")),
+                (Some(1), None),
+                (None, None),
+                (Some(1), None),
+                (None, None),
+                (None, Some("a")),
+            ]),
+        );
+        let expected = vec![
+            (ScalarValue::Int32(1), ScalarValue::from("a")),
+            (ScalarValue::Int32(1), ScalarValue::Null),
+            (ScalarValue::Null, ScalarValue::Null),
+            (ScalarValue::Null, ScalarValue::from("a")),
+        ];
+        assert_eq!(expected, out);
+
+        // Rows seen in a previous batch aren't emitted again.
+        let out = push_and_pull(
+            &operator,
+            &operator_state,
+            &mut partition_states[0],
+            make_batch(&[(None, None), (Some(2), None), (Some(1), Some("a"))]),
+        );
+        assert_eq!(vec![(ScalarValue::Int32(2), ScalarValue::Null)], out);
+    }
+
+    #[test]
+    fn distinct_preserves_input_order_across_tables() {
+        let operator = Arc::new(PhysicalDistinct);
+        let (operator_state, mut partition_states) = create_states(&operator, 4);
+
+        let input: Vec<_> = (0..64).map(|v| (Some(v % 16), Some("a"))).collect();
+        let out = push_and_pull(
+            &operator,
+            &operator_state,
+            &mut partition_states[0],
+            make_batch(&input),
+        );
+        let expected: Vec<_> = (0..16)
+            .map(|v| (ScalarValue::Int32(v), ScalarValue::from("a")))
+            .collect();
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn distinct_all_duplicates_across_partitions() {
+        let operator = Arc::new(PhysicalDistinct);
+        let (operator_state, mut partition_states) = create_states(&operator, 2);
+
+        let input = [
+            (Some(4), Some("b")),
+            (None, Some("b")),
+            (Some(4), Some("b")),
+        ];
+
+        let out = push_and_pull(
+            &operator,
+            &operator_state,
+            &mut partition_states[0],
+            make_batch(&input),
+        );
+        assert_eq!(2, out.len());
+
+        // Same rows on the other partition are all duplicates.
+        let out = push_and_pull(
+            &operator,
+            &operator_state,
+            &mut partition_states[1],
+            make_batch(&input),
+        );
+        assert!(out.is_empty());
+
+        for partition_state in &mut partition_states {
+            let cx = TestWakerContext::new();
+            operator
+                .poll_finalize_push(&mut cx.context(), partition_state, &operator_state)
+                .unwrap();
+            let poll_pull = cx
+                .poll_pull(&operator, partition_state, &operator_state)
+                .unwrap();
+            assert_eq!(PollPull::Exhausted, poll_pull);
+        }
+    }
+}
@@ -11,7 +11,7 @@ use crate::proto::DatabaseProtoConv;
 
 pub type PhysicalFilter = SimpleOperator<FilterOperation>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FilterOperation {
     predicate: PhysicalScalarExpression,
 }
@@ -29,6 +29,15 @@ impl StatelessOperation for FilterOperation {
 
         Ok(batch)
     }
+
+    fn for_partition(&self, partition: usize) -> Option<Box<dyn StatelessOperation>> {
+        let mut local = self.clone();
+        if local.predicate.make_partition_local(partition) {
+            Some(Box::new(local))
+        } else {
+            None
+        }
+    }
 }
 
 impl Explainable for FilterOperation {
@@ -47,13 +56,11 @@ impl DatabaseProtoConv for PhysicalFilter {
     }
 
     fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
-        Ok(Self {
-            operation: FilterOperation {
-                predicate: PhysicalScalarExpression::from_proto_ctx(
-                    proto.predicate.required("predicate")?,
-                    context,
-                )?,
-            },
-        })
+        Ok(Self::new(FilterOperation {
+            predicate: PhysicalScalarExpression::from_proto_ctx(
+                proto.predicate.required("predicate")?,
+                context,
+            )?,
+        }))
     }
 }
@@ -1,10 +1,13 @@
+use std::sync::Arc;
+
 use rayexec_error::Result;
 
 use super::hash_table::GroupAddress;
 use super::AggregateStates;
 use crate::arrays::array::Array;
 use crate::arrays::executor::physical_type::PhysicalType;
-use crate::arrays::executor::scalar::concat;
+use crate::arrays::executor::scalar::{concat, SelectExecutor};
+use crate::arrays::selection::SelectionVector;
 use crate::execution::operators::util::resizer::DEFAULT_TARGET_BATCH_SIZE;
 use crate::functions::aggregate::ChunkGroupAddressIter;
 
@@ -91,10 +94,37 @@ impl GroupChunk {
                 .filter_map(|(selected, arr)| if selected { Some(arr) } else { None })
                 .collect();
 
-            agg_states.states.update_states(
-                &input_cols,
-                ChunkGroupAddressIter::new(self.chunk_idx, addrs),
-            )?;
+            match agg_states.filter_col {
+                Some(filter_col) => {
+                    // Only update states using rows that pass the filter.
+                    let mut selection = SelectionVector::with_capacity(addrs.len());
+                    SelectExecutor::select(&inputs[filter_col], &mut selection)?;
+                    let selection = Arc::new(selection);
+
+                    let input_cols: Vec<_> = input_cols
+                        .into_iter()
+                        .map(|arr| {
+                            let mut arr = arr.clone();
+                            arr.select_mut(selection.clone());
+                            arr
+                        })
+                        .collect();
+                    let input_cols: Vec<_> = input_cols.iter().collect();
+
+                    let addrs: Vec<_> = selection.iter_locations().map(|idx| addrs[idx]).collect();
+
+                    agg_states.states.update_states(
+                        &input_cols,
+                        ChunkGroupAddressIter::new(self.chunk_idx, &addrs),
+                    )?;
+                }
+                None => {
+                    agg_states.states.update_states(
+                        &input_cols,
+                        ChunkGroupAddressIter::new(self.chunk_idx, addrs),
+                    )?;
+                }
+            }
         }
 
         Ok(())
@@ -161,6 +161,9 @@ where
             PhysicalType::List => {
                 not_implemented!("Row compare list")
             }
+            PhysicalType::Struct => {
+                not_implemented!("Row compare struct")
+            }
         }
     }
 
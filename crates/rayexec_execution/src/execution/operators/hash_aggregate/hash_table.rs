@@ -482,6 +482,7 @@ mod tests {
     use super::*;
     use crate::arrays::bitmap::Bitmap;
     use crate::arrays::datatype::DataType;
+    use crate::arrays::scalar::ScalarValue;
     use crate::expr;
     use crate::functions::aggregate::builtin::sum::Sum;
     use crate::functions::aggregate::{AggregateFunction, PlannedAggregateFunction};
@@ -492,6 +493,7 @@ mod tests {
             function: function.function_impl,
             col_selection: Bitmap::from_iter([true]),
             is_distinct: false,
+            filter_col: None,
         };
 
         HashTable::new(16, vec![aggregate])
@@ -531,6 +533,46 @@ mod tests {
         assert_eq!(2, table.num_occupied);
     }
 
+    #[test]
+    fn insert_with_filters() {
+        // SUM(i) FILTER (WHERE f1), SUM(i) FILTER (WHERE f2) GROUP BY g
+        let groups = [Array::from_iter(["g1", "g2", "g1"])];
+        let inputs = [
+            Array::from_iter::<[i64; 3]>([1, 2, 3]),
+            Array::from_iter([true, true, false]),
+            Array::from_iter([false, true, true]),
+        ];
+
+        let hashes = [4, 5, 4];
+
+        let aggregates = [1, 2]
+            .into_iter()
+            .map(|filter_col| {
+                let agg =
+                    make_planned_aggregate([("g", DataType::Utf8), ("i", DataType::Int32)], 1);
+                Aggregate {
+                    function: agg.function_impl,
+                    col_selection: Bitmap::from_iter([true, false, false]),
+                    is_distinct: false,
+                    filter_col: Some(filter_col),
+                }
+            })
+            .collect();
+
+        let mut table = HashTable::new(16, aggregates);
+        table.insert(&groups, &hashes, &inputs).unwrap();
+
+        let batch = table.into_drain().next().unwrap().unwrap();
+
+        // Columns are [agg1, agg2, group]
+        let agg1 = batch.column(0).unwrap();
+        let agg2 = batch.column(1).unwrap();
+        assert_eq!(ScalarValue::Int64(1), agg1.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::Int64(2), agg1.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::Int64(3), agg2.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::Int64(2), agg2.logical_value(1).unwrap());
+    }
+
     #[test]
     fn insert_chunk_append() {
         // Assumes knowledge of internals.
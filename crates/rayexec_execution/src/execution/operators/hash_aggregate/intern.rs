@@ -0,0 +1,210 @@
+use hashbrown::HashMap;
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::executor::scalar::{concat, interleave, HashExecutor, DEFAULT_HASH_SEED};
+
+/// Interns group keys into a dictionary, assigning each distinct key a dense
+/// integer group id.
+///
+/// Unique keys are stored once in compacted chunks, so repeated keys (e.g. a
+/// low cardinality string column) only cost a single `u32` per row. Group ids
+/// are assigned in the order keys are first seen, starting at 0.
+#[derive(Debug, Default)]
+pub struct GroupKeyInterner {
+    /// Chunks of unique keys, one array per group column.
+    chunks: Vec<Vec<Array>>,
+    /// Location (chunk_idx, row_idx) of each group's key, indexed by group id.
+    locations: Vec<(usize, usize)>,
+    /// Group ids keyed by the hash of the group's key.
+    ids_by_hash: HashMap<u64, Vec<u32>>,
+    /// Reusable hash buffer.
+    hash_buf: Vec<u64>,
+}
+
+impl GroupKeyInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of distinct groups interned so far.
+    pub fn num_groups(&self) -> usize {
+        self.locations.len()
+    }
+
+    /// Intern rows from the group-key arrays, writing the group id for each row
+    /// to `ids`.
+    ///
+    /// All arrays must have the same logical length, and the same types as
+    /// arrays from previous calls.
+    pub fn intern(&mut self, keys: &[&Array], ids: &mut Vec<u32>) -> Result<()> {
+        let num_rows = match keys.first() {
+            Some(arr) => arr.logical_len(),
+            None => return Err(RayexecError::new("Cannot intern zero group columns")),
+        };
+
+        if let Some(chunk) = self.chunks.first() {
+            if chunk.len() != keys.len() {
+                return Err(RayexecError::new(format!(
+                    "Expected {} group columns, got {}",
+                    chunk.len(),
+                    keys.len()
+                )));
+            }
+        }
+
+        self.hash_buf.clear();
+        self.hash_buf.resize(num_rows, 0);
+        HashExecutor::hash_columns_into(
+            keys.iter().copied(),
+            DEFAULT_HASH_SEED,
+            &mut self.hash_buf,
+        )?;
+
+        ids.clear();
+        ids.reserve(num_rows);
+
+        // Chunk that new keys from this input will be written to.
+        let new_chunk_idx = self.chunks.len();
+        // Input rows containing keys not seen before.
+        let mut new_rows = Vec::new();
+
+        for (row_idx, &hash) in self.hash_buf.iter().enumerate() {
+            let candidates = self.ids_by_hash.entry(hash).or_default();
+
+            let mut found = None;
+            for &candidate in candidates.iter() {
+                let (chunk_idx, key_row) = self.locations[candidate as usize];
+                let eq = if chunk_idx == new_chunk_idx {
+                    // Key was first seen in this input.
+                    rows_eq(keys.iter().copied(), new_rows[key_row], keys, row_idx)?
+                } else {
+                    rows_eq(&self.chunks[chunk_idx], key_row, keys, row_idx)?
+                };
+
+                if eq {
+                    found = Some(candidate);
+                    break;
+                }
+            }
+
+            let id = match found {
+                Some(id) => id,
+                None => {
+                    let id = u32::try_from(self.locations.len()).map_err(|_| {
+                        RayexecError::new("Exceeded maximum number of interned groups")
+                    })?;
+                    self.locations.push((new_chunk_idx, new_rows.len()));
+                    new_rows.push(row_idx);
+                    candidates.push(id);
+                    id
+                }
+            };
+
+            ids.push(id);
+        }
+
+        if !new_rows.is_empty() {
+            // Materialize only the new keys so we don't hold onto the full
+            // input arrays.
+            let indices: Vec<_> = new_rows.iter().map(|&row| (0, row)).collect();
+            let chunk = keys
+                .iter()
+                .map(|&arr| interleave(&[arr], &indices))
+                .collect::<Result<Vec<_>>>()?;
+            self.chunks.push(chunk);
+        }
+
+        Ok(())
+    }
+
+    /// Reconstruct the key values for all groups.
+    ///
+    /// Returns one array per group column, where the row at index `i`
+    /// contains the key for group id `i`.
+    pub fn keys(&self) -> Result<Vec<Array>> {
+        let num_cols = match self.chunks.first() {
+            Some(chunk) => chunk.len(),
+            None => return Ok(Vec::new()),
+        };
+
+        (0..num_cols)
+            .map(|col_idx| {
+                let arrays: Vec<_> = self.chunks.iter().map(|chunk| &chunk[col_idx]).collect();
+                concat(&arrays)
+            })
+            .collect()
+    }
+}
+
+/// Check if the key at `row1` in `arrays1` equals the key at `row2` in
+/// `arrays2`.
+///
+/// Nulls compare equal to each other for grouping.
+fn rows_eq<'a>(
+    arrays1: impl IntoIterator<Item = &'a Array>,
+    row1: usize,
+    arrays2: &[&Array],
+    row2: usize,
+) -> Result<bool> {
+    for (arr1, arr2) in arrays1.into_iter().zip(arrays2) {
+        if arr1.logical_value(row1)? != arr2.logical_value(row2)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern_low_cardinality_strings() {
+        let mut interner = GroupKeyInterner::new();
+        let mut ids = Vec::new();
+
+        let keys = Array::from_iter(["red", "green", "red", "blue", "green", "red"]);
+        interner.intern(&[&keys], &mut ids).unwrap();
+        assert_eq!(vec![0, 1, 0, 2, 1, 0], ids);
+
+        // Existing keys keep their ids across inputs.
+        let keys = Array::from_iter(["blue", "yellow", "red", "yellow"]);
+        interner.intern(&[&keys], &mut ids).unwrap();
+        assert_eq!(vec![2, 3, 0, 3], ids);
+
+        assert_eq!(4, interner.num_groups());
+
+        let reconstructed = interner.keys().unwrap();
+        let expected = Array::from_iter(["red", "green", "blue", "yellow"]);
+        assert_eq!(vec![expected], reconstructed);
+    }
+
+    #[test]
+    fn intern_multiple_columns_with_nulls() {
+        let mut interner = GroupKeyInterner::new();
+        let mut ids = Vec::new();
+
+        let col1 = Array::from_iter([Some("a"), None, Some("a"), None, Some("a")]);
+        let col2 = Array::from_iter([1_i32, 1, 2, 1, 1]);
+        interner.intern(&[&col1, &col2], &mut ids).unwrap();
+        assert_eq!(vec![0, 1, 2, 1, 0], ids);
+
+        let reconstructed = interner.keys().unwrap();
+        let expected = vec![
+            Array::from_iter([Some("a"), None, Some("a")]),
+            Array::from_iter([1_i32, 1, 2]),
+        ];
+        assert_eq!(expected, reconstructed);
+    }
+
+    #[test]
+    fn intern_column_count_mismatch() {
+        let mut interner = GroupKeyInterner::new();
+        let mut ids = Vec::new();
+
+        let col = Array::from_iter([1_i32, 2]);
+        interner.intern(&[&col], &mut ids).unwrap();
+        interner.intern(&[&col, &col], &mut ids).unwrap_err();
+    }
+}
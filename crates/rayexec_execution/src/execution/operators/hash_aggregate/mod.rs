@@ -4,6 +4,7 @@ pub mod distinct;
 pub mod drain;
 pub mod entry;
 pub mod hash_table;
+pub mod intern;
 
 use std::collections::BTreeSet;
 use std::sync::Arc;
@@ -49,6 +50,9 @@ pub struct Aggregate {
     pub col_selection: Bitmap,
     /// If inputs are distinct.
     pub is_distinct: bool,
+    /// Index of the input column containing the result of the FILTER
+    /// predicate for this aggregate.
+    pub filter_col: Option<usize>,
 }
 
 impl Aggregate {
@@ -58,11 +62,13 @@ impl Aggregate {
             Ok(AggregateStates {
                 states,
                 col_selection: self.col_selection.clone(),
+                filter_col: self.filter_col,
             })
         } else {
             Ok(AggregateStates {
                 states: self.function.new_states(),
                 col_selection: self.col_selection.clone(),
+                filter_col: self.filter_col,
             })
         }
     }
@@ -89,6 +95,12 @@ pub struct AggregateStates {
     /// work, we pass both 'a' and 'b' to the hash table in one pass. Then this
     /// bitmap is used to further refine the inputs specific to the aggregate.
     pub col_selection: Bitmap,
+
+    /// Index of the input column containing the result of the FILTER
+    /// predicate for this aggregate.
+    ///
+    /// Only rows where the predicate is true are used to update the states.
+    pub filter_col: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -185,6 +197,7 @@ impl PhysicalHashAggregate {
         let mut agg_input_cols = BTreeSet::new();
         for expr in &exprs {
             agg_input_cols.extend(expr.columns.iter().map(|expr| expr.idx));
+            agg_input_cols.extend(expr.filter.as_ref().map(|expr| expr.idx));
         }
 
         // Used to generate intial null masks. This doesn't take into account
@@ -266,6 +279,12 @@ impl ExecutableOperator for PhysicalHashAggregate {
                             function: expr.function.function_impl.clone(),
                             col_selection: col_selection.clone(),
                             is_distinct: expr.is_distinct,
+                            filter_col: expr.filter.as_ref().map(|filter| {
+                                self.aggregate_columns
+                                    .iter()
+                                    .position(|&idx| idx == filter.idx)
+                                    .expect("filter column to be an aggregate input")
+                            }),
                         })
                         .collect();
                     HashTable::new(16, aggregates)
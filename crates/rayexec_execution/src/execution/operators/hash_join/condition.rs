@@ -25,6 +25,26 @@ pub struct HashJoinCondition {
     pub function: PlannedScalarFunction,
 }
 
+impl HashJoinCondition {
+    /// Create copies of `conditions` local to `partition`.
+    ///
+    /// Returns None if none of the conditions hold execution state, in which
+    /// case the original conditions can be shared across partitions.
+    pub fn partition_local_conditions(
+        conditions: &[HashJoinCondition],
+        partition: usize,
+    ) -> Option<Vec<HashJoinCondition>> {
+        let mut local = conditions.to_vec();
+        let mut replaced = false;
+        for condition in &mut local {
+            replaced |= condition.left.make_partition_local(partition);
+            replaced |= condition.right.make_partition_local(partition);
+        }
+
+        replaced.then_some(local)
+    }
+}
+
 impl fmt::Display for HashJoinCondition {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -91,12 +111,16 @@ impl LeftPrecomputedJoinConditions {
     ///
     /// The output is the (left, right) selection vectors to use for the final
     /// output batch.
+    ///
+    /// If provided, the right side of each condition is evaluated using the
+    /// corresponding condition in `local_conditions` instead.
     pub fn compute_selection_for_probe(
         &self,
         left_batch_idx: usize,
         left_row_sel: SelectionVector,
         right_row_sel: SelectionVector,
         right: &Batch,
+        local_conditions: Option<&[HashJoinCondition]>,
     ) -> Result<(SelectionVector, SelectionVector)> {
         assert_eq!(left_row_sel.num_rows(), right_row_sel.num_rows());
 
@@ -108,7 +132,7 @@ impl LeftPrecomputedJoinConditions {
         // Select rows from the right batch.
         let selected_right = right.select(right_row_sel.clone());
 
-        for condition in &self.conditions {
+        for (idx, condition) in self.conditions.iter().enumerate() {
             let mut left_precomputed = condition
                 .left_precomputed
                 .get(left_batch_idx)
@@ -121,7 +145,11 @@ impl LeftPrecomputedJoinConditions {
             left_precomputed.select_mut(left_row_sel.clone());
 
             // Eval the right side.
-            let right_arr = condition.right.eval(&selected_right)?;
+            let right_expr = match local_conditions {
+                Some(local) => &local[idx].right,
+                None => &condition.right,
+            };
+            let right_arr = right_expr.eval(&selected_right)?;
 
             // Compute join condition result.
             let result = condition
@@ -124,11 +124,15 @@ impl GlobalHashTable {
     }
 
     /// Probe the table.
+    ///
+    /// `local_conditions` are partition local copies of the join conditions
+    /// to use when evaluating the right side of each condition.
     pub fn probe(
         &self,
         right: &Batch,
         hashes: &[u64],
         mut left_outer_tracker: Option<&mut LeftOuterJoinTracker>,
+        local_conditions: Option<&[HashJoinCondition]>,
     ) -> Result<Vec<Batch>> {
         // Track per-batch row indices that match the input columns.
         //
@@ -193,6 +197,7 @@ impl GlobalHashTable {
                 left_row_sel,
                 right_row_sel,
                 right,
+                local_conditions,
             )?;
 
             // Update right unvisited bitmap. May be None if we're not doing a
@@ -26,7 +26,7 @@ use super::{
 };
 use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
-use crate::arrays::executor::scalar::HashExecutor;
+use crate::arrays::executor::scalar::{HashExecutor, DEFAULT_HASH_SEED};
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::logical::logical_join::JoinType;
@@ -40,6 +40,8 @@ pub struct HashJoinBuildPartitionState {
     local_hashtable: Option<PartitionHashTable>,
     /// Reusable hashes buffer.
     hash_buf: Vec<u64>,
+    /// Partition local copies of the equalities if they hold execution state.
+    local_equalities: Option<Vec<HashJoinCondition>>,
 }
 
 #[derive(Debug)]
@@ -67,6 +69,10 @@ pub struct HashJoinProbePartitionState {
     ///
     /// This is currently populated for one partition at the end of probing.
     outer_join_drain_state: Option<LeftOuterJoinDrainState>,
+    /// Partition local copies of the equalities if they hold execution state.
+    local_equalities: Option<Vec<HashJoinCondition>>,
+    /// Partition local copies of the conditions if they hold execution state.
+    local_conditions: Option<Vec<HashJoinCondition>>,
 }
 
 #[derive(Debug)]
@@ -213,10 +219,18 @@ impl ExecutableOperator for PhysicalHashJoin {
         };
 
         let build_states: Vec<_> = (0..build_partitions)
-            .map(|_| {
+            .map(|partition| {
+                let local_conditions =
+                    HashJoinCondition::partition_local_conditions(&self.conditions, partition);
                 PartitionState::HashJoinBuild(HashJoinBuildPartitionState {
-                    local_hashtable: Some(PartitionHashTable::new(&self.conditions)),
+                    local_hashtable: Some(PartitionHashTable::new(
+                        local_conditions.as_deref().unwrap_or(&self.conditions),
+                    )),
                     hash_buf: Vec::new(),
+                    local_equalities: HashJoinCondition::partition_local_conditions(
+                        &self.equalities,
+                        partition,
+                    ),
                 })
             })
             .collect();
@@ -233,6 +247,14 @@ impl ExecutableOperator for PhysicalHashJoin {
                     input_finished: false,
                     partition_outer_join_tracker: None,
                     outer_join_drain_state: None,
+                    local_equalities: HashJoinCondition::partition_local_conditions(
+                        &self.equalities,
+                        idx,
+                    ),
+                    local_conditions: HashJoinCondition::partition_local_conditions(
+                        &self.conditions,
+                        idx,
+                    ),
                 })
             })
             .collect();
@@ -313,15 +335,18 @@ impl ExecutableOperator for PhysicalHashJoin {
                 state.hash_buf.clear();
                 state.hash_buf.resize(batch.num_rows(), 0);
 
-                for (idx, equality) in self.equalities.iter().enumerate() {
-                    let result = equality.right.eval(&batch)?;
-
-                    if idx == 0 {
-                        HashExecutor::hash_no_combine(&result, &mut state.hash_buf)?;
-                    } else {
-                        HashExecutor::hash_combine(&result, &mut state.hash_buf)?;
-                    }
-                }
+                let results = state
+                    .local_equalities
+                    .as_deref()
+                    .unwrap_or(&self.equalities)
+                    .iter()
+                    .map(|equality| equality.right.eval(&batch))
+                    .collect::<Result<Vec<_>>>()?;
+                HashExecutor::hash_columns_into(
+                    results.iter().map(|result| result.as_ref()),
+                    DEFAULT_HASH_SEED,
+                    &mut state.hash_buf,
+                )?;
 
                 let hashtable = state.global.as_ref().expect("hash table to exist");
 
@@ -329,6 +354,7 @@ impl ExecutableOperator for PhysicalHashJoin {
                     &batch,
                     &state.hash_buf,
                     state.partition_outer_join_tracker.as_mut(),
+                    state.local_conditions.as_deref(),
                 )?;
 
                 state.buffered_output = ComputedBatches::new(batches);
@@ -612,15 +638,18 @@ impl PhysicalHashJoin {
         state.hash_buf.clear();
         state.hash_buf.resize(batch.num_rows(), 0);
 
-        for (idx, equality) in self.equalities.iter().enumerate() {
-            let result = equality.left.eval(&batch)?;
-
-            if idx == 0 {
-                HashExecutor::hash_no_combine(&result, &mut state.hash_buf)?;
-            } else {
-                HashExecutor::hash_combine(&result, &mut state.hash_buf)?;
-            }
-        }
+        let results = state
+            .local_equalities
+            .as_deref()
+            .unwrap_or(&self.equalities)
+            .iter()
+            .map(|equality| equality.left.eval(&batch))
+            .collect::<Result<Vec<_>>>()?;
+        HashExecutor::hash_columns_into(
+            results.iter().map(|result| result.as_ref()),
+            DEFAULT_HASH_SEED,
+            &mut state.hash_buf,
+        )?;
 
         state
             .local_hashtable
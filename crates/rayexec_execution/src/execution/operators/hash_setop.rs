@@ -0,0 +1,497 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::task::{Context, Waker};
+
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result};
+use rayexec_proto::ProtoConv;
+
+use super::{
+    ExecutableOperator,
+    ExecutionStates,
+    InputOutputStates,
+    OperatorState,
+    PartitionState,
+    PollFinalize,
+    PollPull,
+    PollPush,
+};
+use crate::arrays::batch::Batch;
+use crate::arrays::row::{OwnedScalarRow, ScalarRow};
+use crate::arrays::selection::SelectionVector;
+use crate::database::DatabaseContext;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::logical::logical_setop::SetOpKind;
+use crate::proto::DatabaseProtoConv;
+
+#[derive(Debug)]
+pub struct HashSetOpBuildPartitionState {
+    /// Row counts for the rows this partition has seen.
+    ///
+    /// Merged into the global table once this partition's input is finished.
+    local_counts: HashMap<OwnedScalarRow, usize>,
+}
+
+#[derive(Debug)]
+pub struct HashSetOpProbePartitionState {
+    partition_idx: usize,
+    /// Output rows from the last pushed batch waiting to be pulled.
+    buffered: Option<Batch>,
+    /// If the probe input is finished.
+    finished: bool,
+    push_waker: Option<Waker>,
+    pull_waker: Option<Waker>,
+}
+
+#[derive(Debug)]
+pub struct HashSetOpOperatorState {
+    inner: Mutex<SharedState>,
+}
+
+#[derive(Debug)]
+struct SharedState {
+    /// Rows from the build side, along with how they've been used by the probe
+    /// side so far.
+    table: HashMap<OwnedScalarRow, SetOpEntry>,
+    /// Number of build partitions still pushing.
+    build_inputs_remaining: usize,
+    /// Wakers for probe partitions waiting on the build side to complete.
+    probe_push_wakers: Vec<Option<Waker>>,
+}
+
+#[derive(Debug, Default)]
+struct SetOpEntry {
+    /// Remaining number of build side rows that haven't been matched to a
+    /// probe row.
+    remaining: usize,
+    /// If we've already emitted this row. Only used for the non-ALL variants.
+    emitted: bool,
+}
+
+/// Hash based INTERSECT and EXCEPT.
+///
+/// The right side of the set operation is used to build a hash table keyed on
+/// the full row which tracks the number of times each row was seen. The left
+/// side is then streamed through, with each row checked against the table.
+///
+/// For the ALL variants, every left row that matches a right row consumes that
+/// row, producing `min(l, r)` copies for INTERSECT ALL and `max(l - r, 0)`
+/// copies for EXCEPT ALL. Otherwise each row is emitted at most once.
+///
+/// NULLs are treated as equal to each other.
+#[derive(Debug)]
+pub struct PhysicalHashSetOp {
+    kind: SetOpKind,
+    all: bool,
+}
+
+impl PhysicalHashSetOp {
+    /// Index of the input for the left side of the set operation. Batches from
+    /// this side are checked against the hash table.
+    pub const PROBE_SIDE_INPUT_INDEX: usize = 0;
+    /// Index of the input for the right side of the set operation. This side
+    /// builds the hash table.
+    pub const BUILD_SIDE_INPUT_INDEX: usize = 1;
+
+    pub fn try_new(kind: SetOpKind, all: bool) -> Result<Self> {
+        if kind == SetOpKind::Union {
+            return Err(RayexecError::new(
+                "Hash set operation only supports INTERSECT and EXCEPT",
+            ));
+        }
+        Ok(PhysicalHashSetOp { kind, all })
+    }
+
+    /// Check if a left row should be emitted, updating its entry as needed.
+    fn should_emit(
+        &self,
+        table: &mut HashMap<OwnedScalarRow, SetOpEntry>,
+        row: OwnedScalarRow,
+    ) -> bool {
+        match (self.kind, self.all) {
+            (SetOpKind::Intersect, false) => match table.get_mut(&row) {
+                Some(entry) if !entry.emitted => {
+                    entry.emitted = true;
+                    true
+                }
+                _ => false,
+            },
+            (SetOpKind::Intersect, true) => match table.get_mut(&row) {
+                Some(entry) if entry.remaining > 0 => {
+                    entry.remaining -= 1;
+                    true
+                }
+                _ => false,
+            },
+            (SetOpKind::Except, false) => {
+                let entry = table.entry(row).or_default();
+                if entry.remaining > 0 || entry.emitted {
+                    false
+                } else {
+                    entry.emitted = true;
+                    true
+                }
+            }
+            (SetOpKind::Except, true) => match table.get_mut(&row) {
+                Some(entry) if entry.remaining > 0 => {
+                    entry.remaining -= 1;
+                    false
+                }
+                _ => true,
+            },
+            (SetOpKind::Union, _) => unreachable!("union checked on create"),
+        }
+    }
+}
+
+/// Build owned rows for every row in the batch.
+pub(super) fn batch_rows(batch: &Batch) -> Result<Vec<OwnedScalarRow>> {
+    let arrays: Vec<_> = batch.columns().iter().collect();
+    (0..batch.num_rows())
+        .map(|idx| Ok(ScalarRow::try_new_from_arrays(&arrays, idx)?.into_owned()))
+        .collect()
+}
+
+impl ExecutableOperator for PhysicalHashSetOp {
+    fn create_states(
+        &self,
+        _context: &DatabaseContext,
+        partitions: Vec<usize>,
+    ) -> Result<ExecutionStates> {
+        let num_partitions = partitions[0];
+
+        let probe_states = (0..num_partitions)
+            .map(|idx| {
+                PartitionState::HashSetOpProbe(HashSetOpProbePartitionState {
+                    partition_idx: idx,
+                    buffered: None,
+                    finished: false,
+                    push_waker: None,
+                    pull_waker: None,
+                })
+            })
+            .collect();
+
+        let build_states = (0..num_partitions)
+            .map(|_| {
+                PartitionState::HashSetOpBuild(HashSetOpBuildPartitionState {
+                    local_counts: HashMap::new(),
+                })
+            })
+            .collect();
+
+        let operator_state = HashSetOpOperatorState {
+            inner: Mutex::new(SharedState {
+                table: HashMap::new(),
+                build_inputs_remaining: num_partitions,
+                probe_push_wakers: vec![None; num_partitions],
+            }),
+        };
+
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::HashSetOp(operator_state)),
+            partition_states: InputOutputStates::NaryInputSingleOutput {
+                partition_states: vec![probe_states, build_states],
+                pull_states: Self::PROBE_SIDE_INPUT_INDEX,
+            },
+        })
+    }
+
+    fn poll_push(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+        batch: Batch,
+    ) -> Result<PollPush> {
+        match partition_state {
+            PartitionState::HashSetOpBuild(state) => {
+                for row in batch_rows(&batch)? {
+                    *state.local_counts.entry(row).or_default() += 1;
+                }
+                Ok(PollPush::NeedsMore)
+            }
+            PartitionState::HashSetOpProbe(state) => {
+                if state.buffered.is_some() {
+                    state.push_waker = Some(cx.waker().clone());
+                    if let Some(waker) = state.pull_waker.take() {
+                        waker.wake();
+                    }
+                    return Ok(PollPush::Pending(batch));
+                }
+
+                let mut shared = match operator_state {
+                    OperatorState::HashSetOp(state) => state.inner.lock(),
+                    other => panic!("invalid operator state: {other:?}"),
+                };
+
+                // Need the complete build side before we can check any rows.
+                if shared.build_inputs_remaining != 0 {
+                    shared.probe_push_wakers[state.partition_idx] = Some(cx.waker().clone());
+                    return Ok(PollPush::Pending(batch));
+                }
+
+                let selection: SelectionVector = batch_rows(&batch)?
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(idx, row)| {
+                        self.should_emit(&mut shared.table, row).then_some(idx)
+                    })
+                    .collect();
+                std::mem::drop(shared);
+
+                if selection.is_empty() {
+                    // Nothing from this batch in the output.
+                    return Ok(PollPush::NeedsMore);
+                }
+
+                let batch = if selection.len() == batch.num_rows() {
+                    batch
+                } else {
+                    batch.select(Arc::new(selection))
+                };
+
+                state.buffered = Some(batch);
+                if let Some(waker) = state.pull_waker.take() {
+                    waker.wake();
+                }
+
+                Ok(PollPush::Pushed)
+            }
+            other => panic!("invalid partition state: {other:?}"),
+        }
+    }
+
+    fn poll_finalize_push(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+    ) -> Result<PollFinalize> {
+        let mut shared = match operator_state {
+            OperatorState::HashSetOp(state) => state.inner.lock(),
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        match partition_state {
+            PartitionState::HashSetOpBuild(state) => {
+                for (row, count) in state.local_counts.drain() {
+                    shared.table.entry(row).or_default().remaining += count;
+                }
+
+                shared.build_inputs_remaining -= 1;
+
+                if shared.build_inputs_remaining == 0 {
+                    // Table complete, probers can make progress now.
+                    for waker in shared.probe_push_wakers.iter_mut() {
+                        if let Some(waker) = waker.take() {
+                            waker.wake();
+                        }
+                    }
+                }
+
+                Ok(PollFinalize::Finalized)
+            }
+            PartitionState::HashSetOpProbe(state) => {
+                // Ensure the build side completes before we report being done.
+                if shared.build_inputs_remaining != 0 {
+                    shared.probe_push_wakers[state.partition_idx] = Some(cx.waker().clone());
+                    return Ok(PollFinalize::Pending);
+                }
+
+                state.finished = true;
+                if let Some(waker) = state.pull_waker.take() {
+                    waker.wake();
+                }
+
+                Ok(PollFinalize::Finalized)
+            }
+            other => panic!("invalid partition state: {other:?}"),
+        }
+    }
+
+    fn poll_pull(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let state = match partition_state {
+            PartitionState::HashSetOpProbe(state) => state,
+            PartitionState::HashSetOpBuild(_) => {
+                panic!("should not pull with a build state")
+            }
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        match state.buffered.take() {
+            Some(batch) => {
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Computed(batch.into()))
+            }
+            None => {
+                if state.finished {
+                    return Ok(PollPull::Exhausted);
+                }
+
+                state.pull_waker = Some(cx.waker().clone());
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Pending)
+            }
+        }
+    }
+}
+
+impl Explainable for PhysicalHashSetOp {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        let kind = format!("{}{}", self.kind, if self.all { " ALL" } else { "" });
+        ExplainEntry::new("HashSetOp").with_value("kind", kind)
+    }
+}
+
+impl DatabaseProtoConv for PhysicalHashSetOp {
+    type ProtoType = rayexec_proto::generated::execution::PhysicalHashSetOp;
+
+    fn to_proto_ctx(&self, _context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            kind: self.kind.to_proto()? as i32,
+            all: self.all,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, _context: &DatabaseContext) -> Result<Self> {
+        Self::try_new(SetOpKind::from_proto(proto.kind())?, proto.all)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::array::Array;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::execution::operators::test_util::{
+        logical_value,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+
+    /// Run a set operation over a single partition, returning the output
+    /// values sorted.
+    fn run_setop(kind: SetOpKind, all: bool, left: &[i32], right: &[i32]) -> Vec<i32> {
+        let operator = Arc::new(PhysicalHashSetOp::try_new(kind, all).unwrap());
+        let states = operator
+            .create_states(&test_database_context(), vec![1])
+            .unwrap();
+        let operator_state = states.operator_state;
+        let mut partition_states = match states.partition_states {
+            InputOutputStates::NaryInputSingleOutput {
+                partition_states, ..
+            } => partition_states,
+            other => panic!("unexpected states: {other:?}"),
+        };
+        let mut build_state = partition_states.pop().unwrap().pop().unwrap();
+        let mut probe_state = partition_states.pop().unwrap().pop().unwrap();
+
+        let build_cx = TestWakerContext::new();
+        let poll_push = build_cx
+            .poll_push(
+                &operator,
+                &mut build_state,
+                &operator_state,
+                Batch::try_new([Array::from_iter(right.iter().copied())]).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(PollPush::NeedsMore, poll_push);
+        operator
+            .poll_finalize_push(&mut build_cx.context(), &mut build_state, &operator_state)
+            .unwrap();
+
+        let probe_cx = TestWakerContext::new();
+        let poll_push = probe_cx
+            .poll_push(
+                &operator,
+                &mut probe_state,
+                &operator_state,
+                Batch::try_new([Array::from_iter(left.iter().copied())]).unwrap(),
+            )
+            .unwrap();
+
+        let mut out = Vec::new();
+        if poll_push == PollPush::Pushed {
+            let poll_pull = probe_cx
+                .poll_pull(&operator, &mut probe_state, &operator_state)
+                .unwrap();
+            let batch = unwrap_poll_pull_batch(poll_pull);
+            for row in 0..batch.num_rows() {
+                match logical_value(&batch, 0, row) {
+                    ScalarValue::Int32(v) => out.push(v),
+                    other => panic!("unexpected value: {other}"),
+                }
+            }
+        } else {
+            assert_eq!(PollPush::NeedsMore, poll_push);
+        }
+
+        operator
+            .poll_finalize_push(&mut probe_cx.context(), &mut probe_state, &operator_state)
+            .unwrap();
+        let poll_pull = probe_cx
+            .poll_pull(&operator, &mut probe_state, &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn intersect_and_except_multiplicity() {
+        let left = [1, 1, 1, 2, 2, 3];
+        let right = [1, 1, 2, 4, 4];
+
+        assert_eq!(
+            vec![1, 2],
+            run_setop(SetOpKind::Intersect, false, &left, &right)
+        );
+        assert_eq!(
+            vec![1, 1, 2],
+            run_setop(SetOpKind::Intersect, true, &left, &right)
+        );
+        assert_eq!(vec![3], run_setop(SetOpKind::Except, false, &left, &right));
+        assert_eq!(
+            vec![1, 2, 3],
+            run_setop(SetOpKind::Except, true, &left, &right)
+        );
+    }
+
+    #[test]
+    fn probe_waits_for_build() {
+        let operator = Arc::new(PhysicalHashSetOp::try_new(SetOpKind::Except, false).unwrap());
+        let states = operator
+            .create_states(&test_database_context(), vec![1])
+            .unwrap();
+        let mut probe_state = match states.partition_states {
+            InputOutputStates::NaryInputSingleOutput {
+                mut partition_states,
+                ..
+            } => partition_states.swap_remove(0).pop().unwrap(),
+            other => panic!("unexpected states: {other:?}"),
+        };
+
+        let cx = TestWakerContext::new();
+        let poll_push = cx
+            .poll_push(
+                &operator,
+                &mut probe_state,
+                &states.operator_state,
+                Batch::try_new([Array::from_iter([1, 2])]).unwrap(),
+            )
+            .unwrap();
+        assert!(matches!(poll_push, PollPush::Pending(_)));
+    }
+}
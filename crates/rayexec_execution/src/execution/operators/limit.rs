@@ -14,8 +14,10 @@ use super::{
     PollPush,
 };
 use crate::arrays::batch::Batch;
+use crate::arrays::row::OwnedScalarRow;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::physical::column_expr::PhysicalColumnExpr;
 use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug)]
@@ -25,7 +27,8 @@ pub struct LimitPartitionState {
 
     /// Remaining number of rows before we stop sending batches.
     ///
-    /// Initialized to the operator `limit`.
+    /// Initialized to the operator `limit`, or `usize::MAX` if there's no
+    /// limit in which case all rows after the offset are streamed through.
     remaining_count: usize,
 
     /// A buffered batch.
@@ -40,6 +43,12 @@ pub struct LimitPartitionState {
 
     /// If inputs are finished.
     finished: bool,
+
+    /// Sort key values of the last row within the limit.
+    ///
+    /// Only set when using WITH TIES and the limit has been reached, but we
+    /// may still receive rows that tie with the last row.
+    tie_row: Option<OwnedScalarRow>,
 }
 
 /// Operator for LIMIT and OFFSET clauses.
@@ -49,15 +58,78 @@ pub struct LimitPartitionState {
 #[derive(Debug)]
 pub struct PhysicalLimit {
     /// Number of rows to limit to.
-    limit: usize,
+    ///
+    /// None if we're only skipping rows with an offset.
+    limit: Option<usize>,
 
     /// Offset to start limiting from.
     offset: Option<usize>,
+
+    /// Sort key columns to use for determining ties for WITH TIES.
+    ///
+    /// Input is expected to be sorted on these columns.
+    with_ties: Option<Vec<PhysicalColumnExpr>>,
 }
 
 impl PhysicalLimit {
-    pub fn new(limit: usize, offset: Option<usize>) -> Self {
-        PhysicalLimit { limit, offset }
+    pub fn new(limit: Option<usize>, offset: Option<usize>) -> Self {
+        PhysicalLimit {
+            limit,
+            offset,
+            with_ties: None,
+        }
+    }
+
+    /// Create a new limit that also includes rows tying with the last row
+    /// according to `tie_columns`.
+    pub fn new_with_ties(
+        limit: usize,
+        offset: Option<usize>,
+        tie_columns: Vec<PhysicalColumnExpr>,
+    ) -> Self {
+        PhysicalLimit {
+            limit: Some(limit),
+            offset,
+            with_ties: Some(tie_columns),
+        }
+    }
+
+    /// Get the values of the tie columns for a row.
+    fn tie_row(
+        tie_columns: &[PhysicalColumnExpr],
+        batch: &Batch,
+        row: usize,
+    ) -> Result<OwnedScalarRow> {
+        tie_columns
+            .iter()
+            .map(|col| Ok(col.eval(batch)?.logical_value(row)?.into_owned()))
+            .collect()
+    }
+
+    /// Count the number of consecutive rows starting at `start` that tie with
+    /// `tie_row`.
+    fn count_ties(
+        tie_columns: &[PhysicalColumnExpr],
+        batch: &Batch,
+        start: usize,
+        tie_row: &OwnedScalarRow,
+    ) -> Result<usize> {
+        let arrays = tie_columns
+            .iter()
+            .map(|col| col.eval(batch))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut count = 0;
+        'rows: for row in start..batch.num_rows() {
+            for (array, tie_val) in arrays.iter().zip(tie_row.iter()) {
+                if &array.logical_value(row)? != tie_val {
+                    break 'rows;
+                }
+            }
+            count += 1;
+        }
+
+        Ok(count)
     }
 }
 
@@ -75,12 +147,13 @@ impl ExecutableOperator for PhysicalLimit {
                 partition_states: (0..partitions)
                     .map(|_| {
                         PartitionState::Limit(LimitPartitionState {
-                            remaining_count: self.limit,
+                            remaining_count: self.limit.unwrap_or(usize::MAX),
                             remaining_offset: self.offset.unwrap_or(0),
                             buffer: None,
                             pull_waker: None,
                             push_waker: None,
                             finished: false,
+                            tie_row: None,
                         })
                     })
                     .collect(),
@@ -105,7 +178,29 @@ impl ExecutableOperator for PhysicalLimit {
             return Ok(PollPush::Pending(batch));
         }
 
-        let batch = if state.remaining_offset > 0 {
+        if let Some(tie_row) = &state.tie_row {
+            // Limit already reached, only include rows that tie with the last
+            // row.
+            let tie_columns = self.with_ties.as_deref().unwrap_or_default();
+            let ties = Self::count_ties(tie_columns, &batch, 0, tie_row)?;
+
+            if ties > 0 {
+                state.buffer = Some(batch.slice(0, ties));
+            }
+            if let Some(waker) = state.pull_waker.take() {
+                waker.wake();
+            }
+
+            if ties < batch.num_rows() {
+                // Input is sorted, nothing after this can tie.
+                state.finished = true;
+                return Ok(PollPush::Break);
+            }
+            return Ok(PollPush::Pushed);
+        }
+
+        // Compute the range of rows in this batch that's part of the output.
+        let (start, mut count) = if state.remaining_offset > 0 {
             // Offset greater than the number of rows in this batch. Discard the
             // batch, and keep asking for more input.
             if state.remaining_offset >= batch.num_rows() {
@@ -114,27 +209,39 @@ impl ExecutableOperator for PhysicalLimit {
             }
 
             // Otherwise we have to slice the batch at the offset point.
-            let count = std::cmp::min(
-                batch.num_rows() - state.remaining_offset,
-                state.remaining_count,
-            );
+            let start = state.remaining_offset;
+            state.remaining_offset = 0;
+            (
+                start,
+                std::cmp::min(batch.num_rows() - start, state.remaining_count),
+            )
+        } else {
+            (0, std::cmp::min(batch.num_rows(), state.remaining_count))
+        };
+        state.remaining_count -= count;
 
-            let batch = batch.slice(state.remaining_offset, count);
+        let mut finished = state.remaining_count == 0;
 
-            state.remaining_offset = 0;
-            state.remaining_count -= batch.num_rows();
-            batch
-        } else if state.remaining_count < batch.num_rows() {
-            // Remaining offset is 0, and input batch is has more rows than we
-            // need, just slice to the right size.
-            let batch = batch.slice(0, state.remaining_count);
-            state.remaining_count = 0;
+        if let Some(tie_columns) = &self.with_ties {
+            if finished && count > 0 {
+                // Extend the output with any rows in this batch that tie with
+                // the last row.
+                let tie_row = Self::tie_row(tie_columns, &batch, start + count - 1)?;
+                count += Self::count_ties(tie_columns, &batch, start + count, &tie_row)?;
+
+                if start + count == batch.num_rows() {
+                    // Ties may continue into the next batch.
+                    state.tie_row = Some(tie_row);
+                    finished = false;
+                }
+            }
+        }
+
+        let batch = if start == 0 && count == batch.num_rows() {
+            // Entire batch is part of the output, use the batch as-is.
             batch
         } else {
-            // Remaing offset is 0, and input batch has more rows than our
-            // limit, so just use the batch as-is.
-            state.remaining_count -= batch.num_rows();
-            batch
+            batch.slice(start, count)
         };
 
         state.buffer = Some(batch);
@@ -143,7 +250,7 @@ impl ExecutableOperator for PhysicalLimit {
         }
 
         // We're done, no more inputs should arrive.
-        if state.remaining_count == 0 {
+        if finished {
             // When returning `Break`, we do not call `finalize_push`, and
             // instead the partition pipeline will immediately start to pull
             // from this operator.
@@ -202,10 +309,16 @@ impl ExecutableOperator for PhysicalLimit {
 
 impl Explainable for PhysicalLimit {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        let mut ent = ExplainEntry::new("Limit").with_value("limit", self.limit);
+        let mut ent = ExplainEntry::new("Limit");
+        if let Some(limit) = self.limit {
+            ent = ent.with_value("limit", limit);
+        }
         if let Some(offset) = self.offset {
             ent = ent.with_value("offset", offset);
         }
+        if let Some(with_ties) = &self.with_ties {
+            ent = ent.with_values("with_ties", with_ties);
+        }
         ent
     }
 }
@@ -213,17 +326,38 @@ impl Explainable for PhysicalLimit {
 impl DatabaseProtoConv for PhysicalLimit {
     type ProtoType = rayexec_proto::generated::execution::PhysicalLimit;
 
-    fn to_proto_ctx(&self, _context: &DatabaseContext) -> Result<Self::ProtoType> {
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
         Ok(Self::ProtoType {
-            limit: self.limit as u64,
+            limit: self.limit.map(|l| l as u64),
             offset: self.offset.map(|o| o as u64),
+            tie_columns: self
+                .with_ties
+                .iter()
+                .flatten()
+                .map(|col| col.to_proto_ctx(context))
+                .collect::<Result<Vec<_>>>()?,
         })
     }
 
-    fn from_proto_ctx(proto: Self::ProtoType, _context: &DatabaseContext) -> Result<Self> {
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        // WITH TIES always has at least one sort key, so no columns means a
+        // normal limit.
+        let with_ties = if proto.tie_columns.is_empty() {
+            None
+        } else {
+            Some(
+                proto
+                    .tie_columns
+                    .into_iter()
+                    .map(|col| PhysicalColumnExpr::from_proto_ctx(col, context))
+                    .collect::<Result<Vec<_>>>()?,
+            )
+        };
+
         Ok(Self {
-            limit: proto.limit as usize,
+            limit: proto.limit.map(|l| l as usize),
             offset: proto.offset.map(|o| o as usize),
+            with_ties,
         })
     }
 }
@@ -259,7 +393,7 @@ mod tests {
             make_i32_batch([5, 6, 7, 8, 9, 10]),
         ];
 
-        let operator = Arc::new(PhysicalLimit::new(5, None));
+        let operator = Arc::new(PhysicalLimit::new(Some(5), None));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
 
@@ -326,7 +460,7 @@ mod tests {
             make_i32_batch([5, 6, 7, 8, 9, 10]),
         ];
 
-        let operator = Arc::new(PhysicalLimit::new(5, Some(2)));
+        let operator = Arc::new(PhysicalLimit::new(Some(5), Some(2)));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
 
@@ -380,7 +514,7 @@ mod tests {
             make_i32_batch([5, 6, 7, 8, 9, 10]),
         ];
 
-        let operator = Arc::new(PhysicalLimit::new(2, Some(5)));
+        let operator = Arc::new(PhysicalLimit::new(Some(2), Some(5)));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
 
@@ -422,7 +556,7 @@ mod tests {
     fn limit_break_exhaust() {
         let mut inputs = vec![make_i32_batch([1, 2, 3, 4]), make_i32_batch([5, 6, 7, 8])];
 
-        let operator = Arc::new(PhysicalLimit::new(2, None));
+        let operator = Arc::new(PhysicalLimit::new(Some(2), None));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
 
@@ -448,4 +582,341 @@ mod tests {
             .unwrap();
         assert_eq!(PollPull::Exhausted, poll_pull);
     }
+
+    #[test]
+    fn offset_without_limit_streams_remaining() {
+        // 500 rows across 5 batches.
+        let mut inputs: Vec<_> = (0..5)
+            .map(|batch_idx| make_i32_batch((0..100).map(|v| batch_idx * 100 + v)))
+            .collect();
+
+        let operator = Arc::new(PhysicalLimit::new(None, Some(490)));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        let pull_cx = TestWakerContext::new();
+
+        // First four batches are entirely skipped.
+        for _ in 0..4 {
+            let poll_push = push_cx
+                .poll_push(
+                    &operator,
+                    &mut partition_states[0],
+                    &operator_state,
+                    inputs.remove(0),
+                )
+                .unwrap();
+            assert_eq!(PollPush::NeedsMore, poll_push);
+        }
+
+        // Last batch is partially skipped, with the remaining rows passed
+        // through without waiting for the end of input.
+        let poll_push = push_cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                inputs.remove(0),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Pushed, poll_push);
+
+        let output = unwrap_poll_pull_batch(
+            pull_cx
+                .poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!((490..500).collect::<Vec<_>>(), i32_values(&output));
+
+        let poll_finalize = operator
+            .poll_finalize_push(
+                &mut push_cx.context(),
+                &mut partition_states[0],
+                &operator_state,
+            )
+            .unwrap();
+        assert_eq!(PollFinalize::Finalized, poll_finalize);
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn offset_without_limit_beyond_input() {
+        let operator = Arc::new(PhysicalLimit::new(None, Some(10)));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        let poll_push = push_cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                make_i32_batch([1, 2, 3]),
+            )
+            .unwrap();
+        assert_eq!(PollPush::NeedsMore, poll_push);
+
+        operator
+            .poll_finalize_push(
+                &mut push_cx.context(),
+                &mut partition_states[0],
+                &operator_state,
+            )
+            .unwrap();
+
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn limit_zero() {
+        let operator = Arc::new(PhysicalLimit::new(Some(0), None));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        // First push should immediately break.
+        let push_cx = TestWakerContext::new();
+        let poll_push = push_cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                make_i32_batch([1, 2, 3]),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Break, poll_push);
+
+        // Only an empty batch is produced.
+        let pull_cx = TestWakerContext::new();
+        let output = unwrap_poll_pull_batch(
+            pull_cx
+                .poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(0, output.num_rows());
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn limit_offset_past_end() {
+        let mut inputs = vec![make_i32_batch([1, 2, 3]), make_i32_batch([4, 5])];
+
+        let operator = Arc::new(PhysicalLimit::new(Some(2), Some(8)));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        for _ in 0..2 {
+            let poll_push = push_cx
+                .poll_push(
+                    &operator,
+                    &mut partition_states[0],
+                    &operator_state,
+                    inputs.remove(0),
+                )
+                .unwrap();
+            assert_eq!(PollPush::NeedsMore, poll_push);
+        }
+
+        operator
+            .poll_finalize_push(
+                &mut push_cx.context(),
+                &mut partition_states[0],
+                &operator_state,
+            )
+            .unwrap();
+
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn limit_breaks_before_consuming_large_input() {
+        // Effectively unbounded input, limit should stop pulling from it once
+        // satisfied.
+        let mut inputs =
+            (0..).map(|batch_idx| make_i32_batch((0..100).map(|v| batch_idx * 100 + v)));
+
+        let operator = Arc::new(PhysicalLimit::new(Some(250), Some(10)));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        let pull_cx = TestWakerContext::new();
+
+        let mut pushed = 0;
+        let mut output = Vec::new();
+        loop {
+            let poll_push = push_cx
+                .poll_push(
+                    &operator,
+                    &mut partition_states[0],
+                    &operator_state,
+                    inputs.next().unwrap(),
+                )
+                .unwrap();
+            pushed += 1;
+
+            if poll_push != PollPush::NeedsMore {
+                let batch = unwrap_poll_pull_batch(
+                    pull_cx
+                        .poll_pull(&operator, &mut partition_states[0], &operator_state)
+                        .unwrap(),
+                );
+                output.extend(i32_values(&batch));
+            }
+
+            if poll_push == PollPush::Break {
+                break;
+            }
+            assert!(pushed < 10, "limit never signaled break");
+        }
+
+        // 260 rows needed, spread across 3 batches.
+        assert_eq!(3, pushed);
+        assert_eq!((10..260).collect::<Vec<_>>(), output);
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    fn i32_values(batch: &Batch) -> Vec<i32> {
+        (0..batch.num_rows())
+            .map(|row| match logical_value(batch, 0, row) {
+                ScalarValue::Int32(v) => v,
+                other => panic!("unexpected value: {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn limit_with_ties_includes_tying_row() {
+        // Rows 3 and 4 (1-indexed) tie.
+        let mut inputs = vec![make_i32_batch([1, 2, 3, 3, 4])];
+
+        let operator = Arc::new(PhysicalLimit::new_with_ties(
+            3,
+            None,
+            vec![PhysicalColumnExpr { idx: 0 }],
+        ));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        let poll_push = push_cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                inputs.remove(0),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Break, poll_push);
+
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        let output = unwrap_poll_pull_batch(poll_pull);
+        assert_eq!(vec![1, 2, 3, 3], i32_values(&output));
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn limit_with_ties_across_batches() {
+        let mut inputs = vec![
+            make_i32_batch([1, 2, 2]),
+            make_i32_batch([2, 2]),
+            make_i32_batch([2, 3, 4]),
+        ];
+
+        let operator = Arc::new(PhysicalLimit::new_with_ties(
+            2,
+            None,
+            vec![PhysicalColumnExpr { idx: 0 }],
+        ));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        let pull_cx = TestWakerContext::new();
+
+        // Limit reached at the end of the first batch, but the next batch may
+        // contain ties.
+        let poll_push = push_cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                inputs.remove(0),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Pushed, poll_push);
+        let output = unwrap_poll_pull_batch(
+            pull_cx
+                .poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(vec![1, 2, 2], i32_values(&output));
+
+        // Entire batch ties.
+        let poll_push = push_cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                inputs.remove(0),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Pushed, poll_push);
+        let output = unwrap_poll_pull_batch(
+            pull_cx
+                .poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(vec![2, 2], i32_values(&output));
+
+        // Only the first row ties.
+        let poll_push = push_cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                inputs.remove(0),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Break, poll_push);
+        let output = unwrap_poll_pull_batch(
+            pull_cx
+                .poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(vec![2], i32_values(&output));
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
 }
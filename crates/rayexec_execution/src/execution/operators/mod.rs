@@ -6,22 +6,27 @@ pub mod copy_to;
 pub mod create_schema;
 pub mod create_table;
 pub mod create_view;
+pub mod distinct;
 pub mod drop;
 pub mod empty;
 pub mod filter;
 pub mod hash_aggregate;
 pub mod hash_join;
+pub mod hash_setop;
 pub mod insert;
 pub mod limit;
 pub mod materialize;
 pub mod nl_join;
+pub mod ordinality;
 pub mod project;
+pub mod recursive_cte;
 pub mod round_robin;
 pub mod scan;
 pub mod simple;
 pub mod sink;
 pub mod sort;
 pub mod source;
+pub mod streaming_aggregate;
 pub mod table_function;
 pub mod table_inout;
 pub mod ungrouped_aggregate;
@@ -39,11 +44,13 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use std::task::Context;
 
+use analyze::{AnalyzePartitionState, PhysicalAnalyze};
 use batch_resizer::{BatchResizerPartitionState, PhysicalBatchResizer};
 use copy_to::PhysicalCopyTo;
 use create_schema::{CreateSchemaPartitionState, PhysicalCreateSchema};
 use create_table::PhysicalCreateTable;
 use create_view::{CreateViewPartitionState, PhysicalCreateView};
+use distinct::{DistinctOperatorState, DistinctPartitionState, PhysicalDistinct};
 use drop::{DropPartitionState, PhysicalDrop};
 use empty::PhysicalEmpty;
 use filter::{FilterOperation, PhysicalFilter};
@@ -54,19 +61,29 @@ use hash_join::{
     HashJoinProbePartitionState,
     PhysicalHashJoin,
 };
+use hash_setop::{
+    HashSetOpBuildPartitionState,
+    HashSetOpOperatorState,
+    HashSetOpProbePartitionState,
+    PhysicalHashSetOp,
+};
 use insert::PhysicalInsert;
 use limit::PhysicalLimit;
 use materialize::{MaterializeSourceOperation, MaterializedSinkOperation};
 use nl_join::PhysicalNestedLoopJoin;
+use ordinality::{OrdinalityPartitionState, PhysicalOrdinality};
 use project::{PhysicalProject, ProjectOperation};
 use rayexec_error::{not_implemented, OptionExt, Result};
+use recursive_cte::{PhysicalRecursiveCte, RecursiveCteOperatorState, RecursiveCtePartitionState};
 use round_robin::PhysicalRoundRobinRepartition;
 use scan::{PhysicalScan, ScanPartitionState};
 use simple::SimpleOperator;
 use sink::{SinkOperation, SinkOperator, SinkOperatorState, SinkPartitionState};
 use sort::gather_sort::PhysicalGatherSort;
 use sort::scatter_sort::PhysicalScatterSort;
+use sort::top_n::PhysicalTopN;
 use source::{SourceOperation, SourceOperator, SourcePartitionState};
+use streaming_aggregate::{PhysicalStreamingAggregate, StreamingAggregatePartitionState};
 use table_function::{PhysicalTableFunction, TableFunctionPartitionState};
 use table_inout::{PhysicalTableInOut, TableInOutPartitionState};
 use ungrouped_aggregate::{
@@ -99,11 +116,13 @@ use self::sort::gather_sort::{
     GatherSortPushPartitionState,
 };
 use self::sort::scatter_sort::ScatterSortPartitionState;
+use self::sort::top_n::TopNPartitionState;
 use self::values::ValuesPartitionState;
 use super::computed_batch::ComputedBatches;
 use crate::arrays::batch::Batch;
 use crate::database::DatabaseContext;
 use crate::engine::result::ResultSink;
+use crate::execution::executable::profiler::OperatorMetrics;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::proto::DatabaseProtoConv;
 
@@ -113,6 +132,8 @@ use crate::proto::DatabaseProtoConv;
 pub enum PartitionState {
     HashAggregate(HashAggregatePartitionState),
     UngroupedAggregate(UngroupedAggregatePartitionState),
+    StreamingAggregate(StreamingAggregatePartitionState),
+    Ordinality(OrdinalityPartitionState),
     NestedLoopJoinBuild(NestedLoopJoinBuildPartitionState),
     NestedLoopJoinProbe(NestedLoopJoinProbePartitionState),
     HashJoinBuild(HashJoinBuildPartitionState),
@@ -125,7 +146,12 @@ pub enum PartitionState {
     GatherSortPush(GatherSortPushPartitionState),
     GatherSortPull(GatherSortPullPartitionState),
     ScatterSort(ScatterSortPartitionState),
+    TopN(TopNPartitionState),
     Limit(LimitPartitionState),
+    Distinct(DistinctPartitionState),
+    HashSetOpBuild(HashSetOpBuildPartitionState),
+    HashSetOpProbe(HashSetOpProbePartitionState),
+    RecursiveCte(RecursiveCtePartitionState),
     Unnest(UnnestPartitionState),
     UnionTop(UnionTopPartitionState),
     UnionBottom(UnionBottomPartitionState),
@@ -138,6 +164,7 @@ pub enum PartitionState {
     Drop(DropPartitionState),
     Empty(EmptyPartitionState),
     BatchResizer(BatchResizerPartitionState),
+    Analyze(AnalyzePartitionState),
     None,
 }
 
@@ -151,6 +178,9 @@ pub enum OperatorState {
     HashJoin(HashJoinOperatorState),
     RoundRobin(RoundRobinOperatorState),
     GatherSort(GatherSortOperatorState),
+    Distinct(DistinctOperatorState),
+    HashSetOp(HashSetOpOperatorState),
+    RecursiveCte(RecursiveCteOperatorState),
     Union(UnionOperatorState),
     Sink(SinkOperatorState),
     None,
@@ -317,6 +347,8 @@ pub trait ExecutableOperator: Sync + Send + Debug + Explainable {
 pub enum PhysicalOperator {
     HashAggregate(PhysicalHashAggregate),
     UngroupedAggregate(PhysicalUngroupedAggregate),
+    StreamingAggregate(PhysicalStreamingAggregate),
+    Ordinality(PhysicalOrdinality),
     Window(PhysicalWindow),
     NestedLoopJoin(PhysicalNestedLoopJoin),
     HashJoin(PhysicalHashJoin),
@@ -329,7 +361,11 @@ pub enum PhysicalOperator {
     RoundRobin(PhysicalRoundRobinRepartition),
     MergeSorted(PhysicalGatherSort),
     LocalSort(PhysicalScatterSort),
+    LocalTopN(PhysicalTopN),
     Limit(PhysicalLimit),
+    Distinct(PhysicalDistinct),
+    HashSetOp(PhysicalHashSetOp),
+    RecursiveCte(PhysicalRecursiveCte),
     Union(PhysicalUnion),
     Filter(SimpleOperator<FilterOperation>),
     Project(SimpleOperator<ProjectOperation>),
@@ -345,6 +381,22 @@ pub enum PhysicalOperator {
     Drop(PhysicalDrop),
     Empty(PhysicalEmpty),
     BatchResizer(PhysicalBatchResizer),
+    Analyze(PhysicalAnalyze),
+}
+
+impl PhysicalOperator {
+    /// Get the metrics for this operator if it records them.
+    ///
+    /// Only a subset of operators currently record metrics for EXPLAIN
+    /// ANALYZE.
+    pub fn metrics(&self) -> Option<&OperatorMetrics> {
+        match self {
+            Self::Filter(op) => Some(&op.metrics),
+            Self::Project(op) => Some(&op.metrics),
+            Self::Scan(op) => Some(&op.metrics),
+            _ => None,
+        }
+    }
 }
 
 impl ExecutableOperator for PhysicalOperator {
@@ -356,6 +408,8 @@ impl ExecutableOperator for PhysicalOperator {
         match self {
             Self::HashAggregate(op) => op.create_states(context, partitions),
             Self::UngroupedAggregate(op) => op.create_states(context, partitions),
+            Self::StreamingAggregate(op) => op.create_states(context, partitions),
+            Self::Ordinality(op) => op.create_states(context, partitions),
             Self::Window(op) => op.create_states(context, partitions),
             Self::NestedLoopJoin(op) => op.create_states(context, partitions),
             Self::HashJoin(op) => op.create_states(context, partitions),
@@ -368,7 +422,11 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.create_states(context, partitions),
             Self::MergeSorted(op) => op.create_states(context, partitions),
             Self::LocalSort(op) => op.create_states(context, partitions),
+            Self::LocalTopN(op) => op.create_states(context, partitions),
             Self::Limit(op) => op.create_states(context, partitions),
+            Self::Distinct(op) => op.create_states(context, partitions),
+            Self::HashSetOp(op) => op.create_states(context, partitions),
+            Self::RecursiveCte(op) => op.create_states(context, partitions),
             Self::Union(op) => op.create_states(context, partitions),
             Self::Filter(op) => op.create_states(context, partitions),
             Self::Project(op) => op.create_states(context, partitions),
@@ -384,6 +442,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.create_states(context, partitions),
             Self::Empty(op) => op.create_states(context, partitions),
             Self::BatchResizer(op) => op.create_states(context, partitions),
+            Self::Analyze(op) => op.create_states(context, partitions),
         }
     }
 
@@ -399,6 +458,10 @@ impl ExecutableOperator for PhysicalOperator {
             Self::UngroupedAggregate(op) => {
                 op.poll_push(cx, partition_state, operator_state, batch)
             }
+            Self::StreamingAggregate(op) => {
+                op.poll_push(cx, partition_state, operator_state, batch)
+            }
+            Self::Ordinality(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Window(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::NestedLoopJoin(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::HashJoin(op) => op.poll_push(cx, partition_state, operator_state, batch),
@@ -413,7 +476,11 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::MergeSorted(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::LocalSort(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::LocalTopN(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Limit(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::Distinct(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::HashSetOp(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::RecursiveCte(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Union(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Filter(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Project(op) => op.poll_push(cx, partition_state, operator_state, batch),
@@ -429,6 +496,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Empty(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::BatchResizer(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::Analyze(op) => op.poll_push(cx, partition_state, operator_state, batch),
         }
     }
 
@@ -443,6 +511,10 @@ impl ExecutableOperator for PhysicalOperator {
             Self::UngroupedAggregate(op) => {
                 op.poll_finalize_push(cx, partition_state, operator_state)
             }
+            Self::StreamingAggregate(op) => {
+                op.poll_finalize_push(cx, partition_state, operator_state)
+            }
+            Self::Ordinality(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Window(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::NestedLoopJoin(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::HashJoin(op) => op.poll_finalize_push(cx, partition_state, operator_state),
@@ -459,7 +531,11 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::MergeSorted(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::LocalSort(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::LocalTopN(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Limit(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::Distinct(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::HashSetOp(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::RecursiveCte(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Union(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Filter(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Project(op) => op.poll_finalize_push(cx, partition_state, operator_state),
@@ -475,6 +551,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Empty(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::BatchResizer(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::Analyze(op) => op.poll_finalize_push(cx, partition_state, operator_state),
         }
     }
 
@@ -487,6 +564,8 @@ impl ExecutableOperator for PhysicalOperator {
         match self {
             Self::HashAggregate(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::UngroupedAggregate(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::StreamingAggregate(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::Ordinality(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Window(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::NestedLoopJoin(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::HashJoin(op) => op.poll_pull(cx, partition_state, operator_state),
@@ -499,7 +578,11 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::MergeSorted(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::LocalSort(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::LocalTopN(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Limit(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::Distinct(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::HashSetOp(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::RecursiveCte(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Union(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Filter(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Project(op) => op.poll_pull(cx, partition_state, operator_state),
@@ -515,6 +598,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Empty(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::BatchResizer(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::Analyze(op) => op.poll_pull(cx, partition_state, operator_state),
         }
     }
 }
@@ -524,6 +608,8 @@ impl Explainable for PhysicalOperator {
         match self {
             Self::HashAggregate(op) => op.explain_entry(conf),
             Self::UngroupedAggregate(op) => op.explain_entry(conf),
+            Self::StreamingAggregate(op) => op.explain_entry(conf),
+            Self::Ordinality(op) => op.explain_entry(conf),
             Self::Window(op) => op.explain_entry(conf),
             Self::NestedLoopJoin(op) => op.explain_entry(conf),
             Self::HashJoin(op) => op.explain_entry(conf),
@@ -536,7 +622,11 @@ impl Explainable for PhysicalOperator {
             Self::RoundRobin(op) => op.explain_entry(conf),
             Self::MergeSorted(op) => op.explain_entry(conf),
             Self::LocalSort(op) => op.explain_entry(conf),
+            Self::LocalTopN(op) => op.explain_entry(conf),
             Self::Limit(op) => op.explain_entry(conf),
+            Self::Distinct(op) => op.explain_entry(conf),
+            Self::HashSetOp(op) => op.explain_entry(conf),
+            Self::RecursiveCte(op) => op.explain_entry(conf),
             Self::Union(op) => op.explain_entry(conf),
             Self::Filter(op) => op.explain_entry(conf),
             Self::Project(op) => op.explain_entry(conf),
@@ -552,6 +642,7 @@ impl Explainable for PhysicalOperator {
             Self::Drop(op) => op.explain_entry(conf),
             Self::Empty(op) => op.explain_entry(conf),
             Self::BatchResizer(op) => op.explain_entry(conf),
+            Self::Analyze(op) => op.explain_entry(conf),
         }
     }
 }
@@ -580,6 +671,10 @@ impl DatabaseProtoConv for PhysicalOperator {
             Self::CopyTo(op) => Value::CopyTo(op.to_proto_ctx(context)?),
             Self::LocalSort(op) => Value::LocalSort(op.to_proto_ctx(context)?),
             Self::MergeSorted(op) => Value::MergeSorted(op.to_proto_ctx(context)?),
+            Self::LocalTopN(op) => Value::TopN(op.to_proto_ctx(context)?),
+            Self::Distinct(op) => Value::Distinct(op.to_proto_ctx(context)?),
+            Self::HashSetOp(op) => Value::HashSetOp(op.to_proto_ctx(context)?),
+            Self::RecursiveCte(op) => Value::RecursiveCte(op.to_proto_ctx(context)?),
             other => not_implemented!("to proto: {other:?}"),
         };
 
@@ -637,6 +732,18 @@ impl DatabaseProtoConv for PhysicalOperator {
             Value::MergeSorted(op) => {
                 PhysicalOperator::MergeSorted(PhysicalGatherSort::from_proto_ctx(op, context)?)
             }
+            Value::TopN(op) => {
+                PhysicalOperator::LocalTopN(PhysicalTopN::from_proto_ctx(op, context)?)
+            }
+            Value::Distinct(op) => {
+                PhysicalOperator::Distinct(PhysicalDistinct::from_proto_ctx(op, context)?)
+            }
+            Value::HashSetOp(op) => {
+                PhysicalOperator::HashSetOp(PhysicalHashSetOp::from_proto_ctx(op, context)?)
+            }
+            Value::RecursiveCte(op) => {
+                PhysicalOperator::RecursiveCte(PhysicalRecursiveCte::from_proto_ctx(op, context)?)
+            }
         })
     }
 }
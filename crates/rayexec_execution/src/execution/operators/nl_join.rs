@@ -2,11 +2,16 @@ use std::sync::Arc;
 use std::task::{Context, Waker};
 
 use parking_lot::Mutex;
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
-use super::util::outer_join_tracker::LeftOuterJoinTracker;
+use super::util::outer_join_tracker::{
+    LeftOuterJoinDrainState,
+    LeftOuterJoinTracker,
+    RightOuterJoinTracker,
+};
 use super::ComputedBatches;
 use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
 use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
 use crate::execution::operators::{
@@ -80,6 +85,14 @@ pub struct NestedLoopJoinProbePartitionState {
 
     /// Track rows visited on the left side for this partition.
     partition_outer_join_tracker: Option<LeftOuterJoinTracker>,
+
+    /// State for tracking rows on the left side that we still need to emit.
+    ///
+    /// Populated once all probe partitions have finished.
+    outer_join_drain_state: Option<LeftOuterJoinDrainState>,
+
+    /// Partition local copy of the join filter if it holds execution state.
+    local_filter: Option<PhysicalScalarExpression>,
 }
 
 impl NestedLoopJoinProbePartitionState {
@@ -93,6 +106,8 @@ impl NestedLoopJoinProbePartitionState {
             pull_waker: None,
             input_finished: false,
             partition_outer_join_tracker: None,
+            outer_join_drain_state: None,
+            local_filter: None,
         }
     }
 }
@@ -149,6 +164,20 @@ enum SharedOperatorState {
         ///
         /// Referenced with draining unvisited rows in the case of a LEFT join.
         global_outer_join_tracker: Option<LeftOuterJoinTracker>,
+
+        /// Number of partitions on the probe side.
+        probe_partition_count: usize,
+
+        /// Number of probe partitions that haven't finished yet.
+        ///
+        /// Unvisited rows from the left can only be drained once this reaches
+        /// zero.
+        probe_partitions_remaining: usize,
+
+        /// Wakers for probe partitions waiting to drain unvisited left rows.
+        ///
+        /// Indexed by partition index on the probe side.
+        probe_drain_wakers: Vec<Option<Waker>>,
     },
 }
 
@@ -167,11 +196,12 @@ impl SharedOperatorState {
                 assert_eq!(0, *build_partitions_remaining);
 
                 // Wake any pending probers.
-                for waker in probe_side_wakers {
+                for waker in probe_side_wakers.iter_mut() {
                     if let Some(waker) = waker.take() {
                         waker.wake();
                     }
                 }
+                let probe_partition_count = probe_side_wakers.len();
 
                 let global_outer_join_tracker = match join_type {
                     JoinType::Left | JoinType::Full => {
@@ -184,6 +214,9 @@ impl SharedOperatorState {
                 *self = Self::Probing {
                     batches: Arc::new(batches),
                     global_outer_join_tracker,
+                    probe_partition_count,
+                    probe_partitions_remaining: probe_partition_count,
+                    probe_drain_wakers: vec![None; probe_partition_count],
                 }
             }
             Self::Probing { .. } => panic!("inner state is already probing"),
@@ -197,14 +230,38 @@ pub struct PhysicalNestedLoopJoin {
     /// Filter to apply after cross joining batches.
     filter: Option<PhysicalScalarExpression>,
     join_type: JoinType,
+    /// Types for the batches we'll be receiving from the left side. Used during
+    /// RIGHT joins to produce null columns on the left side.
+    left_types: Vec<DataType>,
+    /// Types for the batches we'll be receiving from the right side. Used
+    /// during LEFT joins to produce null columns on the right side.
+    right_types: Vec<DataType>,
 }
 
 impl PhysicalNestedLoopJoin {
     pub const BUILD_SIDE_INPUT_INDEX: usize = 0;
     pub const PROBE_SIDE_INPUT_INDEX: usize = 1;
 
-    pub fn new(filter: Option<PhysicalScalarExpression>, join_type: JoinType) -> Self {
-        PhysicalNestedLoopJoin { filter, join_type }
+    pub fn new(
+        filter: Option<PhysicalScalarExpression>,
+        join_type: JoinType,
+        left_types: Vec<DataType>,
+        right_types: Vec<DataType>,
+    ) -> Self {
+        PhysicalNestedLoopJoin {
+            filter,
+            join_type,
+            left_types,
+            right_types,
+        }
+    }
+
+    const fn join_requires_drain(&self) -> bool {
+        matches!(self.join_type, JoinType::Left | JoinType::Full)
+    }
+
+    const fn is_right_join(&self) -> bool {
+        matches!(self.join_type, JoinType::Right | JoinType::Full)
     }
 }
 
@@ -225,9 +282,14 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
 
         let right_states = (0..num_partitions)
             .map(|partition| {
-                PartitionState::NestedLoopJoinProbe(
-                    NestedLoopJoinProbePartitionState::new_for_partition(partition),
-                )
+                let mut state = NestedLoopJoinProbePartitionState::new_for_partition(partition);
+                if let Some(filter) = &self.filter {
+                    let mut local = filter.clone();
+                    if local.make_partition_local(partition) {
+                        state.local_filter = Some(local);
+                    }
+                }
+                PartitionState::NestedLoopJoinProbe(state)
             })
             .collect();
 
@@ -278,6 +340,7 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                         SharedOperatorState::Probing {
                             batches,
                             global_outer_join_tracker,
+                            ..
                         } => {
                             // Otherwise the batches are ready for us. Clone the
                             // reference into our local state.
@@ -302,19 +365,37 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                 }
 
                 // Do the join.
+                let mut right_tracker = if self.is_right_join() {
+                    Some(RightOuterJoinTracker::new_for_batch(&batch))
+                } else {
+                    None
+                };
+
+                let filter = state.local_filter.as_ref().or(self.filter.as_ref());
+
                 let mut batches = Vec::new();
                 for (left_idx, left) in state.all_batches.iter().enumerate() {
                     let mut out = cross_join(
                         left_idx,
                         left,
                         &batch,
-                        self.filter.as_ref(),
+                        filter,
                         state.partition_outer_join_tracker.as_mut(),
-                        false,
+                        right_tracker.as_mut(),
                     )?;
                     batches.append(&mut out);
                 }
 
+                // Emit rows on the right that didn't match anything on the
+                // left.
+                if let Some(right_tracker) = right_tracker {
+                    if let Some(unvisited) =
+                        right_tracker.into_unvisited(&self.left_types, &batch)?
+                    {
+                        batches.push(unvisited);
+                    }
+                }
+
                 state.buffered = ComputedBatches::new(batches);
                 if state.buffered.is_empty() {
                     // Nothing produces, signal to push more.
@@ -334,7 +415,7 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
 
     fn poll_finalize_push(
         &self,
-        _cx: &mut Context,
+        cx: &mut Context,
         partition_state: &mut PartitionState,
         operator_state: &OperatorState,
     ) -> Result<PollFinalize> {
@@ -371,6 +452,48 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                 }
             }
             PartitionState::NestedLoopJoinProbe(state) => {
+                let operator_state = match operator_state {
+                    OperatorState::NestedLoopJoin(operater_state) => operater_state,
+                    other => panic!("invalid operator state: {other:?}"),
+                };
+
+                let mut inner = operator_state.inner.lock();
+                match &mut *inner {
+                    SharedOperatorState::Building {
+                        probe_side_wakers, ..
+                    } => {
+                        // Left side needs to be complete before we can
+                        // finalize as we may need to drain unvisited rows.
+                        probe_side_wakers[state.partition_idx] = Some(cx.waker().clone());
+                        return Ok(PollFinalize::Pending);
+                    }
+                    SharedOperatorState::Probing {
+                        global_outer_join_tracker,
+                        probe_partitions_remaining,
+                        probe_drain_wakers,
+                        ..
+                    } => {
+                        // Local tracker may be None if this partition never
+                        // received any batches.
+                        if let (Some(global), Some(local)) = (
+                            global_outer_join_tracker.as_mut(),
+                            &state.partition_outer_join_tracker,
+                        ) {
+                            global.merge_from(local);
+                        }
+
+                        *probe_partitions_remaining -= 1;
+                        if *probe_partitions_remaining == 0 {
+                            // Wake up partitions waiting to drain.
+                            for waker in probe_drain_wakers.iter_mut() {
+                                if let Some(waker) = waker.take() {
+                                    waker.wake();
+                                }
+                            }
+                        }
+                    }
+                }
+
                 state.input_finished = true;
                 if let Some(waker) = state.pull_waker.take() {
                     waker.wake();
@@ -385,7 +508,7 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
         &self,
         cx: &mut Context,
         partition_state: &mut PartitionState,
-        _operator_state: &OperatorState,
+        operator_state: &OperatorState,
     ) -> Result<PollPull> {
         match partition_state {
             PartitionState::NestedLoopJoinProbe(state) => {
@@ -393,7 +516,60 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                 if computed.has_batches() {
                     Ok(PollPull::Computed(computed))
                 } else if state.input_finished {
-                    Ok(PollPull::Exhausted)
+                    if !self.join_requires_drain() {
+                        return Ok(PollPull::Exhausted);
+                    }
+
+                    if state.outer_join_drain_state.is_none() {
+                        // Check if all probe partitions have merged their
+                        // visited rows so we can start draining.
+                        let operator_state = match operator_state {
+                            OperatorState::NestedLoopJoin(operater_state) => operater_state,
+                            other => panic!("invalid operator state: {other:?}"),
+                        };
+
+                        let mut inner = operator_state.inner.lock();
+                        match &mut *inner {
+                            SharedOperatorState::Probing {
+                                batches,
+                                global_outer_join_tracker,
+                                probe_partition_count,
+                                probe_partitions_remaining,
+                                probe_drain_wakers,
+                            } => {
+                                if *probe_partitions_remaining != 0 {
+                                    probe_drain_wakers[state.partition_idx] =
+                                        Some(cx.waker().clone());
+                                    return Ok(PollPull::Pending);
+                                }
+
+                                let global =
+                                    global_outer_join_tracker.as_ref().ok_or_else(|| {
+                                        RayexecError::new(
+                                            "Global left outer tracker unexpectedly None",
+                                        )
+                                    })?;
+
+                                state.outer_join_drain_state = Some(LeftOuterJoinDrainState::new(
+                                    state.partition_idx,
+                                    *probe_partition_count,
+                                    global.clone(),
+                                    batches.as_ref().clone(),
+                                    self.right_types.clone(),
+                                ));
+                            }
+                            other => panic!("inner join state is not probing: {other:?}"),
+                        }
+                    }
+
+                    let drain_state = state
+                        .outer_join_drain_state
+                        .as_mut()
+                        .expect("drain state to be initialized");
+                    match drain_state.drain_next()? {
+                        Some(batch) => Ok(PollPull::Computed(batch.into())),
+                        None => Ok(PollPull::Exhausted),
+                    }
                 } else {
                     // We just gotta wait for more input.
                     if let Some(waker) = state.push_waker.take() {
@@ -419,13 +595,16 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
 
 /// Generate a cross product of two batches, applying an optional filter to the
 /// result.
+///
+/// Rows that produce output are marked as visited in the provided outer join
+/// trackers.
 fn cross_join(
     left_batch_idx: usize,
     left: &Batch,
     right: &Batch,
     filter_expr: Option<&PhysicalScalarExpression>,
     mut left_outer_tracker: Option<&mut LeftOuterJoinTracker>,
-    _right_join: bool,
+    mut right_outer_tracker: Option<&mut RightOuterJoinTracker>,
 ) -> Result<Vec<Batch>> {
     let mut batches = Vec::with_capacity(left.num_rows() * right.num_rows());
 
@@ -446,11 +625,19 @@ fn cross_join(
             let selection = Arc::new(filter_expr.select(&output)?);
             output = output.select(selection.clone());
 
-            // If we're left joining, compute indices in the left batch that we
-            // visited.
+            // Output rows line up with rows on the right, so the selection
+            // indicates which right rows were visited.
+            if let Some(right_outer_tracker) = &mut right_outer_tracker {
+                right_outer_tracker.mark_rows_visited(selection.iter_locations());
+            }
+        } else if let Some(right_outer_tracker) = &mut right_outer_tracker {
+            right_outer_tracker.mark_rows_visited(0..right.num_rows());
+        }
+
+        // The left row was visited if it joined with anything on the right.
+        if output.num_rows() > 0 {
             if let Some(left_outer_tracker) = &mut left_outer_tracker {
-                left_outer_tracker
-                    .mark_rows_visited_for_batch(left_batch_idx, selection.iter_locations());
+                left_outer_tracker.mark_rows_visited_for_batch(left_batch_idx, [left_idx]);
             }
         }
 
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+use std::task::{Context, Waker};
+
+use rayexec_error::Result;
+
+use super::{
+    ExecutableOperator,
+    ExecutionStates,
+    InputOutputStates,
+    OperatorState,
+    PartitionState,
+    PollFinalize,
+    PollPull,
+    PollPush,
+};
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::database::DatabaseContext;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+
+#[derive(Debug)]
+pub struct OrdinalityPartitionState {
+    /// Ordinal to assign to the next row we see.
+    next_ordinal: i64,
+
+    /// A batch that's waiting to be pulled.
+    buffered: Option<Batch>,
+
+    /// Waker on the pull side.
+    pull_waker: Option<Waker>,
+
+    /// Waker on the push side.
+    push_waker: Option<Waker>,
+
+    /// Mark if this partition is exhausted.
+    exhausted: bool,
+}
+
+/// Appends an Int64 column containing a 1-based row number to every batch.
+///
+/// Row numbers are tracked per partition, so this should receive a single
+/// input partition to produce numbers unique across the whole input.
+#[derive(Debug)]
+pub struct PhysicalOrdinality;
+
+impl ExecutableOperator for PhysicalOrdinality {
+    fn create_states(
+        &self,
+        _context: &DatabaseContext,
+        partitions: Vec<usize>,
+    ) -> Result<ExecutionStates> {
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::None),
+            partition_states: InputOutputStates::OneToOne {
+                partition_states: (0..partitions[0])
+                    .map(|_| {
+                        PartitionState::Ordinality(OrdinalityPartitionState {
+                            next_ordinal: 1,
+                            buffered: None,
+                            pull_waker: None,
+                            push_waker: None,
+                            exhausted: false,
+                        })
+                    })
+                    .collect(),
+            },
+        })
+    }
+
+    fn poll_push(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+        batch: Batch,
+    ) -> Result<PollPush> {
+        let state = match partition_state {
+            PartitionState::Ordinality(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        // Need to wait for buffered batch to be pulled.
+        if state.buffered.is_some() {
+            state.push_waker = Some(cx.waker().clone());
+            if let Some(waker) = state.pull_waker.take() {
+                waker.wake();
+            }
+            return Ok(PollPush::Pending(batch));
+        }
+
+        let start = state.next_ordinal;
+        state.next_ordinal += batch.num_rows() as i64;
+
+        let mut arrays = batch.into_arrays();
+        arrays.push(Array::from_iter(start..state.next_ordinal));
+
+        state.buffered = Some(Batch::try_new(arrays)?);
+        if let Some(waker) = state.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollPush::Pushed)
+    }
+
+    fn poll_finalize_push(
+        &self,
+        _cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollFinalize> {
+        let state = match partition_state {
+            PartitionState::Ordinality(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        state.exhausted = true;
+
+        if let Some(waker) = state.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollFinalize::Finalized)
+    }
+
+    fn poll_pull(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let state = match partition_state {
+            PartitionState::Ordinality(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        match state.buffered.take() {
+            Some(out) => {
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Computed(out.into()))
+            }
+            None => {
+                if state.exhausted {
+                    return Ok(PollPull::Exhausted);
+                }
+
+                state.pull_waker = Some(cx.waker().clone());
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Pending)
+            }
+        }
+    }
+}
+
+impl Explainable for PhysicalOrdinality {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("Ordinality")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::testutil::assert_batches_eq;
+    use crate::execution::operators::test_util::{
+        make_i32_batch,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+
+    #[test]
+    fn ordinals_continue_across_batches() {
+        let operator = Arc::new(PhysicalOrdinality);
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = match operator
+            .create_states(&test_database_context(), vec![1])
+            .unwrap()
+            .partition_states
+        {
+            InputOutputStates::OneToOne { partition_states } => partition_states,
+            other => panic!("invalid states: {other:?}"),
+        };
+
+        let cx = TestWakerContext::new();
+
+        let inputs = [make_i32_batch([4, 5, 6]), make_i32_batch([7, 8])];
+        let expected = [
+            Batch::try_new([Array::from_iter([4, 5, 6]), Array::from_iter([1_i64, 2, 3])]).unwrap(),
+            Batch::try_new([Array::from_iter([7, 8]), Array::from_iter([4_i64, 5])]).unwrap(),
+        ];
+
+        for (input, expected) in inputs.into_iter().zip(expected) {
+            let poll_push = cx
+                .poll_push(&operator, &mut partition_states[0], &operator_state, input)
+                .unwrap();
+            assert_eq!(PollPush::Pushed, poll_push);
+
+            let poll_pull = cx
+                .poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap();
+            assert_batches_eq(&expected, &unwrap_poll_pull_batch(poll_pull));
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::proto::DatabaseProtoConv;
 
 pub type PhysicalProject = SimpleOperator<ProjectOperation>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ProjectOperation {
     exprs: Vec<PhysicalScalarExpression>,
 }
@@ -33,6 +33,11 @@ impl StatelessOperation for ProjectOperation {
 
         Batch::try_new(arrs)
     }
+
+    fn for_partition(&self, partition: usize) -> Option<Box<dyn StatelessOperation>> {
+        let exprs = PhysicalScalarExpression::partition_local_exprs(&self.exprs, partition)?;
+        Some(Box::new(ProjectOperation { exprs }))
+    }
 }
 
 impl Explainable for ProjectOperation {
@@ -56,14 +61,89 @@ impl DatabaseProtoConv for PhysicalProject {
     }
 
     fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
-        Ok(Self {
-            operation: ProjectOperation {
-                exprs: proto
-                    .exprs
-                    .into_iter()
-                    .map(|e| PhysicalScalarExpression::from_proto_ctx(e, context))
-                    .collect::<Result<Vec<_>>>()?,
-            },
-        })
+        Ok(Self::new(ProjectOperation {
+            exprs: proto
+                .exprs
+                .into_iter()
+                .map(|e| PhysicalScalarExpression::from_proto_ctx(e, context))
+                .collect::<Result<Vec<_>>>()?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::execution::operators::test_util::{
+        make_i32_batch,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+    use crate::execution::operators::{
+        ExecutableOperator,
+        InputOutputStates,
+        OperatorState,
+        PartitionState,
+        PollPush,
+    };
+    use crate::expr::physical::scalar_function_expr::PhysicalScalarFunctionExpr;
+    use crate::functions::scalar::builtin::random::Random;
+    use crate::functions::scalar::ScalarFunction;
+    use crate::logical::binder::table_list::TableList;
+
+    /// Project `random()` seeded with `seed`, pushing batches to partitions in
+    /// the given order. Returns the output batches for each partition.
+    fn project_random(seed: u64, partitions: usize, order: &[usize]) -> Vec<Vec<Batch>> {
+        let function = Random
+            .plan_with_seed(&TableList::empty(), Vec::new(), seed)
+            .unwrap();
+        let operator = Arc::new(PhysicalProject::new(ProjectOperation::new(vec![
+            PhysicalScalarExpression::ScalarFunction(PhysicalScalarFunctionExpr {
+                function,
+                inputs: Vec::new(),
+            }),
+        ])));
+        let operator_state = Arc::new(OperatorState::None);
+
+        let context = test_database_context();
+        let states = operator.create_states(&context, vec![partitions]).unwrap();
+        let mut partition_states: Vec<PartitionState> = match states.partition_states {
+            InputOutputStates::OneToOne { partition_states } => partition_states,
+            other => panic!("invalid states: {other:?}"),
+        };
+
+        let cx = TestWakerContext::new();
+        let mut outputs = vec![Vec::new(); partitions];
+        for &partition in order {
+            let poll_push = cx
+                .poll_push(
+                    &operator,
+                    &mut partition_states[partition],
+                    &operator_state,
+                    make_i32_batch([1, 2]),
+                )
+                .unwrap();
+            assert_eq!(PollPush::Pushed, poll_push);
+
+            let poll_pull = cx
+                .poll_pull(&operator, &mut partition_states[partition], &operator_state)
+                .unwrap();
+            outputs[partition].push(unwrap_poll_pull_batch(poll_pull));
+        }
+
+        outputs
+    }
+
+    #[test]
+    fn random_same_per_partition_regardless_of_schedule() {
+        let a = project_random(42, 2, &[0, 0, 1, 1]);
+        let b = project_random(42, 2, &[1, 0, 1, 0]);
+
+        assert_eq!(a, b);
+        // Partitions draw from different sequences.
+        assert_ne!(a[0], a[1]);
     }
 }
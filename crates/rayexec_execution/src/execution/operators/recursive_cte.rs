@@ -0,0 +1,420 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use std::task::{Context, Waker};
+
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result};
+
+use super::hash_setop::batch_rows;
+use super::{
+    ExecutableOperator,
+    ExecutionStates,
+    InputOutputStates,
+    OperatorState,
+    PartitionState,
+    PhysicalOperator,
+    PollFinalize,
+    PollPull,
+    PollPush,
+};
+use crate::arrays::batch::Batch;
+use crate::arrays::row::OwnedScalarRow;
+use crate::arrays::selection::SelectionVector;
+use crate::database::DatabaseContext;
+use crate::execution::operators::simple::StatelessOperation;
+use crate::explain::context_display::ContextDisplayMode;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::proto::DatabaseProtoConv;
+
+#[derive(Debug)]
+pub struct RecursiveCtePartitionState {
+    partition_idx: usize,
+}
+
+#[derive(Debug)]
+pub struct RecursiveCteOperatorState {
+    inner: Mutex<SharedState>,
+}
+
+#[derive(Debug)]
+struct SharedState {
+    /// Batches from the anchor.
+    anchor: Vec<Batch>,
+    /// Number of anchor partitions still pushing.
+    inputs_remaining: usize,
+    /// Output batches for each partition.
+    ///
+    /// Only set once all rows for the CTE have been computed.
+    output: Option<Vec<VecDeque<Batch>>>,
+    /// Wakers for partitions waiting on the output to be computed.
+    pull_wakers: Vec<Option<Waker>>,
+}
+
+/// Computes a recursive CTE.
+///
+/// Rows from the anchor are pushed into this operator. Once the anchor is
+/// complete, the recursive term is repeatedly applied to the rows produced by
+/// the previous iteration (the working table) until an iteration produces no
+/// new rows.
+///
+/// The recursive term is a sequence of stateless operators (projections and
+/// filters) executed directly by this operator.
+///
+/// For UNION (without ALL), rows that have already been produced are discarded
+/// and not fed back into the recursive term.
+#[derive(Debug)]
+pub struct PhysicalRecursiveCte {
+    /// Operators making up the recursive term, in execution order.
+    recursive_term: Vec<Arc<PhysicalOperator>>,
+    /// If duplicate rows are kept.
+    all: bool,
+    /// Maximum number of times the recursive term is evaluated before erroring.
+    max_iterations: usize,
+}
+
+impl PhysicalRecursiveCte {
+    pub fn try_new(
+        recursive_term: Vec<Arc<PhysicalOperator>>,
+        all: bool,
+        max_iterations: usize,
+    ) -> Result<Self> {
+        for operator in &recursive_term {
+            if !matches!(
+                operator.as_ref(),
+                PhysicalOperator::Project(_) | PhysicalOperator::Filter(_)
+            ) {
+                return Err(RayexecError::new(format!(
+                    "Unsupported operator in the recursive term of a recursive CTE: {}",
+                    operator
+                        .explain_entry(ExplainConfig {
+                            context_mode: ContextDisplayMode::Raw,
+                            verbose: false,
+                        })
+                        .name
+                )));
+            }
+        }
+
+        Ok(PhysicalRecursiveCte {
+            recursive_term,
+            all,
+            max_iterations,
+        })
+    }
+
+    /// Apply the recursive term to a batch from the working table.
+    fn execute_recursive_term(&self, mut batch: Batch) -> Result<Batch> {
+        for operator in &self.recursive_term {
+            batch = match operator.as_ref() {
+                PhysicalOperator::Project(op) => op.operation.execute(batch)?,
+                PhysicalOperator::Filter(op) => op.operation.execute(batch)?,
+                other => panic!("invalid recursive term operator: {other:?}"),
+            };
+        }
+        Ok(batch)
+    }
+
+    /// Remove rows from the batch that have already been seen.
+    ///
+    /// Returns None if all rows have been seen.
+    fn retain_unseen(seen: &mut HashSet<OwnedScalarRow>, batch: Batch) -> Result<Option<Batch>> {
+        let selection: SelectionVector = batch_rows(&batch)?
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, row)| seen.insert(row).then_some(idx))
+            .collect();
+
+        if selection.is_empty() {
+            return Ok(None);
+        }
+
+        if selection.len() == batch.num_rows() {
+            Ok(Some(batch))
+        } else {
+            Ok(Some(batch.select(Arc::new(selection))))
+        }
+    }
+
+    /// Compute all rows for the CTE starting from the anchor.
+    fn compute_fixpoint(&self, anchor: Vec<Batch>) -> Result<Vec<Batch>> {
+        let mut seen = HashSet::new();
+        let mut working = Vec::with_capacity(anchor.len());
+        for batch in anchor {
+            let batch = if self.all {
+                Some(batch)
+            } else {
+                Self::retain_unseen(&mut seen, batch)?
+            };
+            if let Some(batch) = batch.filter(|b| b.num_rows() > 0) {
+                working.push(batch);
+            }
+        }
+
+        let mut output = Vec::new();
+        let mut iterations = 0;
+
+        while !working.is_empty() {
+            if iterations == self.max_iterations {
+                return Err(RayexecError::new(format!(
+                    "Recursive CTE exceeded the maximum number of iterations ({}). The limit can be changed with 'max_recursive_cte_iterations'",
+                    self.max_iterations
+                )));
+            }
+            iterations += 1;
+
+            let mut next = Vec::new();
+            for batch in &working {
+                let batch = self.execute_recursive_term(batch.clone())?;
+                let batch = if self.all {
+                    Some(batch)
+                } else {
+                    Self::retain_unseen(&mut seen, batch)?
+                };
+                if let Some(batch) = batch.filter(|b| b.num_rows() > 0) {
+                    next.push(batch);
+                }
+            }
+
+            output.append(&mut working);
+            working = next;
+        }
+
+        Ok(output)
+    }
+}
+
+impl ExecutableOperator for PhysicalRecursiveCte {
+    fn create_states(
+        &self,
+        _context: &DatabaseContext,
+        partitions: Vec<usize>,
+    ) -> Result<ExecutionStates> {
+        let num_partitions = partitions[0];
+
+        let partition_states = (0..num_partitions)
+            .map(|idx| {
+                PartitionState::RecursiveCte(RecursiveCtePartitionState { partition_idx: idx })
+            })
+            .collect();
+
+        let operator_state = RecursiveCteOperatorState {
+            inner: Mutex::new(SharedState {
+                anchor: Vec::new(),
+                inputs_remaining: num_partitions,
+                output: None,
+                pull_wakers: vec![None; num_partitions],
+            }),
+        };
+
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::RecursiveCte(operator_state)),
+            partition_states: InputOutputStates::OneToOne { partition_states },
+        })
+    }
+
+    fn poll_push(
+        &self,
+        _cx: &mut Context,
+        _partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+        batch: Batch,
+    ) -> Result<PollPush> {
+        let mut shared = match operator_state {
+            OperatorState::RecursiveCte(state) => state.inner.lock(),
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        if batch.num_rows() > 0 {
+            shared.anchor.push(batch);
+        }
+
+        Ok(PollPush::NeedsMore)
+    }
+
+    fn poll_finalize_push(
+        &self,
+        _cx: &mut Context,
+        _partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+    ) -> Result<PollFinalize> {
+        let mut shared = match operator_state {
+            OperatorState::RecursiveCte(state) => state.inner.lock(),
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        shared.inputs_remaining -= 1;
+
+        if shared.inputs_remaining == 0 {
+            // Anchor complete, compute the CTE and distribute the output
+            // across partitions.
+            let anchor = std::mem::take(&mut shared.anchor);
+            let batches = self.compute_fixpoint(anchor)?;
+
+            let num_partitions = shared.pull_wakers.len();
+            let mut output = vec![VecDeque::new(); num_partitions];
+            for (idx, batch) in batches.into_iter().enumerate() {
+                output[idx % num_partitions].push_back(batch);
+            }
+            shared.output = Some(output);
+
+            for waker in shared.pull_wakers.iter_mut() {
+                if let Some(waker) = waker.take() {
+                    waker.wake();
+                }
+            }
+        }
+
+        Ok(PollFinalize::Finalized)
+    }
+
+    fn poll_pull(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let state = match partition_state {
+            PartitionState::RecursiveCte(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        let mut shared = match operator_state {
+            OperatorState::RecursiveCte(state) => state.inner.lock(),
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        match shared.output.as_mut() {
+            Some(output) => match output[state.partition_idx].pop_front() {
+                Some(batch) => Ok(PollPull::Computed(batch.into())),
+                None => Ok(PollPull::Exhausted),
+            },
+            None => {
+                shared.pull_wakers[state.partition_idx] = Some(cx.waker().clone());
+                Ok(PollPull::Pending)
+            }
+        }
+    }
+}
+
+impl Explainable for PhysicalRecursiveCte {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("RecursiveCte")
+            .with_value("all", self.all)
+            .with_value("max_iterations", self.max_iterations)
+    }
+}
+
+impl DatabaseProtoConv for PhysicalRecursiveCte {
+    type ProtoType = rayexec_proto::generated::execution::PhysicalRecursiveCte;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            recursive_term: self
+                .recursive_term
+                .iter()
+                .map(|op| op.to_proto_ctx(context))
+                .collect::<Result<Vec<_>>>()?,
+            all: self.all,
+            max_iterations: self.max_iterations as u64,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        let recursive_term = proto
+            .recursive_term
+            .into_iter()
+            .map(|op| Ok(Arc::new(PhysicalOperator::from_proto_ctx(op, context)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::try_new(recursive_term, proto.all, proto.max_iterations as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::array::Array;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::execution::operators::test_util::{
+        logical_value,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+
+    /// Push the anchor through a single partition and finalize, returning the
+    /// result of the finalize.
+    fn run_anchor(
+        operator: &Arc<PhysicalRecursiveCte>,
+        anchor: &[i32],
+    ) -> (Result<PollFinalize>, PartitionState, Arc<OperatorState>) {
+        let states = operator
+            .create_states(&test_database_context(), vec![1])
+            .unwrap();
+        let operator_state = states.operator_state;
+        let mut partition_state = match states.partition_states {
+            InputOutputStates::OneToOne { partition_states } => {
+                partition_states.into_iter().next().unwrap()
+            }
+            other => panic!("unexpected states: {other:?}"),
+        };
+
+        let cx = TestWakerContext::new();
+        let poll_push = cx
+            .poll_push(
+                operator,
+                &mut partition_state,
+                &operator_state,
+                Batch::try_new([Array::from_iter(anchor.iter().copied())]).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(PollPush::NeedsMore, poll_push);
+
+        let finalize =
+            operator.poll_finalize_push(&mut cx.context(), &mut partition_state, &operator_state);
+
+        (finalize, partition_state, operator_state)
+    }
+
+    #[test]
+    fn union_discards_seen_rows() {
+        // Recursive term with no operators produces the same rows it's given,
+        // which should be discarded without ALL.
+        let operator = Arc::new(PhysicalRecursiveCte::try_new(Vec::new(), false, 10).unwrap());
+        let (finalize, mut partition_state, operator_state) = run_anchor(&operator, &[1, 2, 2, 3]);
+        assert_eq!(PollFinalize::Finalized, finalize.unwrap());
+
+        let cx = TestWakerContext::new();
+        let batch = unwrap_poll_pull_batch(
+            cx.poll_pull(&operator, &mut partition_state, &operator_state)
+                .unwrap(),
+        );
+        let mut values: Vec<_> = (0..batch.num_rows())
+            .map(|idx| logical_value(&batch, 0, idx))
+            .collect();
+        values.sort_by_key(|v| v.try_as_i64().unwrap());
+        assert_eq!(
+            vec![
+                ScalarValue::Int32(1),
+                ScalarValue::Int32(2),
+                ScalarValue::Int32(3)
+            ],
+            values
+        );
+
+        let poll_pull = cx
+            .poll_pull(&operator, &mut partition_state, &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn union_all_hits_iteration_cap() {
+        let operator = Arc::new(PhysicalRecursiveCte::try_new(Vec::new(), true, 5).unwrap());
+        let (finalize, _, _) = run_anchor(&operator, &[1]);
+        let err = finalize.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("exceeded the maximum number of iterations (5)"));
+    }
+}
@@ -21,6 +21,7 @@ use crate::arrays::batch::Batch;
 use crate::database::catalog::CatalogTx;
 use crate::database::catalog_entry::CatalogEntry;
 use crate::database::DatabaseContext;
+use crate::execution::executable::profiler::OperatorMetrics;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::proto::DatabaseProtoConv;
 use crate::storage::table_storage::{DataTableScan, Projections};
@@ -43,6 +44,7 @@ pub struct PhysicalScan {
     schema: String,
     table: Arc<CatalogEntry>,
     projections: Projections,
+    pub(crate) metrics: OperatorMetrics,
 }
 
 impl PhysicalScan {
@@ -57,6 +59,7 @@ impl PhysicalScan {
             schema: schema.into(),
             table,
             projections,
+            metrics: OperatorMetrics::default(),
         }
     }
 }
@@ -16,6 +16,7 @@ use super::{
 };
 use crate::arrays::batch::Batch;
 use crate::database::DatabaseContext;
+use crate::execution::executable::profiler::OperatorMetrics;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 
 #[derive(Debug)]
@@ -37,6 +38,12 @@ pub struct SimplePartitionState {
     ///
     /// Needed to propagate batch exhaustion through the pipelines.
     exhausted: bool,
+
+    /// Copy of the operation local to this partition.
+    ///
+    /// Only set if the operation holds state that's advanced during
+    /// execution, otherwise the operator's operation is used.
+    local_operation: Option<Box<dyn StatelessOperation>>,
 }
 
 impl Default for SimplePartitionState {
@@ -52,6 +59,7 @@ impl SimplePartitionState {
             pull_waker: None,
             push_waker: None,
             exhausted: false,
+            local_operation: None,
         }
     }
 }
@@ -59,6 +67,14 @@ impl SimplePartitionState {
 /// A stateless operation on a batch.
 pub trait StatelessOperation: Sync + Send + Debug + Explainable {
     fn execute(&self, batch: Batch) -> Result<Batch>;
+
+    /// Create a copy of this operation for executing within a single
+    /// partition.
+    ///
+    /// Returns None if the operation can be shared across all partitions.
+    fn for_partition(&self, _partition: usize) -> Option<Box<dyn StatelessOperation>> {
+        None
+    }
 }
 
 /// A simple operator is an operator that wraps a function that requires no
@@ -68,11 +84,15 @@ pub trait StatelessOperation: Sync + Send + Debug + Explainable {
 #[derive(Debug)]
 pub struct SimpleOperator<S> {
     pub(crate) operation: S,
+    pub(crate) metrics: OperatorMetrics,
 }
 
 impl<S: StatelessOperation> SimpleOperator<S> {
     pub fn new(operation: S) -> Self {
-        SimpleOperator { operation }
+        SimpleOperator {
+            operation,
+            metrics: OperatorMetrics::default(),
+        }
     }
 }
 
@@ -86,7 +106,12 @@ impl<S: StatelessOperation> ExecutableOperator for SimpleOperator<S> {
             operator_state: Arc::new(OperatorState::None),
             partition_states: InputOutputStates::OneToOne {
                 partition_states: (0..partitions[0])
-                    .map(|_| PartitionState::Simple(SimplePartitionState::new()))
+                    .map(|partition| {
+                        PartitionState::Simple(SimplePartitionState {
+                            local_operation: self.operation.for_partition(partition),
+                            ..SimplePartitionState::new()
+                        })
+                    })
                     .collect(),
             },
         })
@@ -114,7 +139,10 @@ impl<S: StatelessOperation> ExecutableOperator for SimpleOperator<S> {
         }
 
         // Otherwise we're good to go.
-        let out = self.operation.execute(batch)?;
+        let out = match &state.local_operation {
+            Some(operation) => operation.execute(batch)?,
+            None => self.operation.execute(batch)?,
+        };
 
         state.buffered = Some(out);
         if let Some(waker) = state.pull_waker.take() {
@@ -542,7 +542,14 @@ impl PhysicalGatherSort {
                     // global input_buffers.
                     for (idx, local_buf) in input_buffers.buffered.iter_mut().enumerate() {
                         if local_buf.is_none() {
-                            *local_buf = shared.batches[idx].take();
+                            if let Some(batch) = shared.batches[idx].take() {
+                                *local_buf = Some(batch);
+                                // Global state has room for another batch,
+                                // wake a pending waker to try to get more.
+                                if let Some(waker) = shared.push_wakers[idx].take() {
+                                    waker.wake();
+                                }
+                            }
                         }
                         input_buffers.finished[idx] = shared.finished[idx];
                     }
@@ -1,5 +1,5 @@
 pub mod gather_sort;
 pub mod scatter_sort;
-pub mod top_k;
+pub mod top_n;
 
 mod util;
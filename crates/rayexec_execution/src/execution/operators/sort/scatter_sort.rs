@@ -263,7 +263,10 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
+    use crate::arrays::array::Array;
+    use crate::arrays::scalar::ScalarValue;
     use crate::execution::operators::test_util::{
+        logical_value,
         make_i32_batch,
         test_database_context,
         unwrap_poll_pull_batch,
@@ -365,6 +368,91 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    /// Create a batch with columns (a, b, id) where None is NULL.
+    fn make_keyed_batch(rows: &[(Option<i32>, Option<&str>, i32)]) -> Batch {
+        let mut a = Array::from_iter(rows.iter().map(|row| row.0.unwrap_or_default()));
+        let mut b = Array::from_iter(rows.iter().map(|row| row.1.unwrap_or_default()));
+        for (idx, row) in rows.iter().enumerate() {
+            a.set_physical_validity(idx, row.0.is_some());
+            b.set_physical_validity(idx, row.1.is_some());
+        }
+        let id = Array::from_iter(rows.iter().map(|row| row.2));
+
+        Batch::try_new([a, b, id]).unwrap()
+    }
+
+    #[test]
+    fn sort_multiple_keys_mixed_directions_stable() {
+        let inputs = vec![
+            make_keyed_batch(&[
+                (Some(1), Some("x"), 0),
+                (None, Some("y"), 1),
+                (Some(2), None, 2),
+                (Some(1), Some("y"), 3),
+            ]),
+            make_keyed_batch(&[
+                (Some(1), Some("x"), 4),
+                (Some(2), Some("z"), 5),
+                (None, Some("y"), 6),
+                (Some(1), None, 7),
+            ]),
+            make_keyed_batch(&[(Some(2), None, 8), (Some(1), Some("x"), 9)]),
+        ];
+
+        // ORDER BY a ASC NULLS LAST, b DESC NULLS FIRST
+        let operator = Arc::new(PhysicalScatterSort::new(vec![
+            PhysicalSortExpression {
+                column: PhysicalColumnExpr { idx: 0 },
+                desc: false,
+                nulls_first: false,
+            },
+            PhysicalSortExpression {
+                column: PhysicalColumnExpr { idx: 1 },
+                desc: true,
+                nulls_first: true,
+            },
+        ]));
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        for input in inputs {
+            let poll_push = push_cx
+                .poll_push(&operator, &mut partition_states[0], &operator_state, input)
+                .unwrap();
+            assert_eq!(PollPush::NeedsMore, poll_push);
+        }
+        operator
+            .poll_finalize_push(
+                &mut push_cx.context(),
+                &mut partition_states[0],
+                &operator_state,
+            )
+            .unwrap();
+
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        let output = unwrap_poll_pull_batch(poll_pull);
+
+        // Rows with equal keys keep their input order (e.g. ids 0, 4, 9 for
+        // (1, 'x')).
+        let ids: Vec<_> = (0..output.num_rows())
+            .map(|row| logical_value(&output, 2, row))
+            .collect();
+        let expected: Vec<_> = [7, 3, 0, 4, 9, 2, 8, 5, 1, 6]
+            .into_iter()
+            .map(ScalarValue::Int32)
+            .collect();
+        assert_eq!(expected, ids);
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
     #[test]
     fn sort_single_partition_multiple_outputs() {
         let inputs = vec![
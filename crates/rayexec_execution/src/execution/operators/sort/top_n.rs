@@ -0,0 +1,558 @@
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+use std::task::{Context, Waker};
+
+use rayexec_error::Result;
+
+use super::util::sort_keys::SortKeysExtractor;
+use crate::arrays::batch::Batch;
+use crate::arrays::executor::scalar::interleave;
+use crate::database::DatabaseContext;
+use crate::execution::operators::util::resizer::DEFAULT_TARGET_BATCH_SIZE;
+use crate::execution::operators::{
+    ExecutableOperator,
+    ExecutionStates,
+    InputOutputStates,
+    OperatorState,
+    PartitionState,
+    PollFinalize,
+    PollPull,
+    PollPush,
+};
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::physical::PhysicalSortExpression;
+use crate::proto::DatabaseProtoConv;
+
+#[derive(Debug)]
+pub enum TopNPartitionState {
+    /// Partition is accepting data, keeping only the top N rows.
+    Consuming(TopNConsumingState),
+    /// Partition is producing its top N rows in sorted order.
+    Producing(TopNProducingState),
+}
+
+#[derive(Debug)]
+pub struct TopNConsumingState {
+    /// Extract the sort keys from a batch.
+    extractor: SortKeysExtractor,
+    /// Max heap of the rows we're currently holding on to. The root is the
+    /// "worst" row, and is the first to be evicted when a better row comes in.
+    heap: BinaryHeap<HeapRow>,
+    /// Batches referenced by rows in the heap.
+    batches: Vec<Batch>,
+    /// Total number of rows across `batches`.
+    ///
+    /// Once this grows past the compaction threshold, the retained rows are
+    /// copied into a single batch so that memory stays proportional to N.
+    buffered_rows: usize,
+    /// Number of rows seen by this partition so far. Used to break ties
+    /// between rows with equal keys in favor of the row that came first.
+    rows_seen: u64,
+    /// Waker on the pull side that tried to get a batch before we were done
+    /// consuming this partition.
+    pull_waker: Option<Waker>,
+}
+
+#[derive(Debug)]
+pub struct TopNProducingState {
+    /// Sorted output batches, in reverse order so that the next batch to
+    /// return can be popped off the end.
+    batches: Vec<Batch>,
+}
+
+/// A row being tracked in the heap.
+///
+/// Ordered by the encoded sort key, then by arrival order.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct HeapRow {
+    key: Vec<u8>,
+    seq: u64,
+    batch_idx: usize,
+    row_idx: usize,
+}
+
+/// Physical operator for producing the first N rows of a partition according
+/// to some sort expressions.
+///
+/// Only N rows (plus a bounded amount of buffered input) are held in memory at
+/// any time, regardless of the size of the input. Like the scatter sort,
+/// output is sorted within a partition, and needs to be merged to produce a
+/// total order.
+#[derive(Debug)]
+pub struct PhysicalTopN {
+    exprs: Vec<PhysicalSortExpression>,
+    limit: usize,
+}
+
+impl PhysicalTopN {
+    pub fn new(exprs: Vec<PhysicalSortExpression>, limit: usize) -> Self {
+        PhysicalTopN { exprs, limit }
+    }
+
+    /// Number of buffered rows that will trigger a compaction of the retained
+    /// rows.
+    fn compaction_threshold(&self) -> usize {
+        self.limit.saturating_mul(2).max(DEFAULT_TARGET_BATCH_SIZE)
+    }
+}
+
+impl ExecutableOperator for PhysicalTopN {
+    fn create_states(
+        &self,
+        _context: &DatabaseContext,
+        partitions: Vec<usize>,
+    ) -> Result<ExecutionStates> {
+        let partitions = partitions[0];
+
+        let extractor = SortKeysExtractor::new(&self.exprs);
+        let states = (0..partitions)
+            .map(|_| {
+                PartitionState::TopN(TopNPartitionState::Consuming(TopNConsumingState {
+                    extractor: extractor.clone(),
+                    // Limit may be arbitrarily large, only reserve up to a
+                    // batch worth of rows up front.
+                    heap: BinaryHeap::with_capacity(self.limit.min(DEFAULT_TARGET_BATCH_SIZE)),
+                    batches: Vec::new(),
+                    buffered_rows: 0,
+                    rows_seen: 0,
+                    pull_waker: None,
+                }))
+            })
+            .collect();
+
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::None),
+            partition_states: InputOutputStates::OneToOne {
+                partition_states: states,
+            },
+        })
+    }
+
+    fn poll_push(
+        &self,
+        _cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+        batch: Batch,
+    ) -> Result<PollPush> {
+        let state = match partition_state {
+            PartitionState::TopN(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        match state {
+            TopNPartitionState::Consuming(state) => {
+                self.insert_batch(state, batch)?;
+                if state.buffered_rows >= self.compaction_threshold() {
+                    Self::compact(state)?;
+                }
+
+                Ok(PollPush::NeedsMore)
+            }
+            TopNPartitionState::Producing(_) => {
+                panic!("attempted to push to partition that's already producing data")
+            }
+        }
+    }
+
+    fn poll_finalize_push(
+        &self,
+        _cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollFinalize> {
+        let state = match partition_state {
+            PartitionState::TopN(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        match state {
+            TopNPartitionState::Consuming(consuming_state) => {
+                let pull_waker = consuming_state.pull_waker.take();
+
+                let heap = std::mem::take(&mut consuming_state.heap);
+                let rows = heap.into_sorted_vec();
+                // TODO: Configurable batch size.
+                let mut batches = rows
+                    .chunks(DEFAULT_TARGET_BATCH_SIZE)
+                    .map(|rows| build_batch(&consuming_state.batches, rows))
+                    .collect::<Result<Option<Vec<_>>>>()?
+                    .unwrap_or_default();
+                batches.reverse();
+
+                if let Some(waker) = pull_waker {
+                    waker.wake()
+                }
+
+                *state = TopNPartitionState::Producing(TopNProducingState { batches });
+
+                Ok(PollFinalize::Finalized)
+            }
+            TopNPartitionState::Producing(_) => {
+                panic!("attempted to finalize partition that's already producing data")
+            }
+        }
+    }
+
+    fn poll_pull(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let state = match partition_state {
+            PartitionState::TopN(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        match state {
+            TopNPartitionState::Consuming(state) => {
+                state.pull_waker = Some(cx.waker().clone());
+                Ok(PollPull::Pending)
+            }
+            TopNPartitionState::Producing(state) => {
+                let output = match state.batches.pop() {
+                    Some(batch) => batch,
+                    None => return Ok(PollPull::Exhausted),
+                };
+
+                Ok(PollPull::Computed(output.into()))
+            }
+        }
+    }
+}
+
+impl PhysicalTopN {
+    fn insert_batch(&self, state: &mut TopNConsumingState, batch: Batch) -> Result<()> {
+        if self.limit == 0 || batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let keys = state.extractor.sort_keys(&batch)?;
+        let batch_idx = state.batches.len();
+        let mut used = false;
+
+        for (row_idx, key) in keys.iter().enumerate() {
+            let seq = state.rows_seen;
+            state.rows_seen += 1;
+
+            if state.heap.len() < self.limit {
+                state.heap.push(HeapRow {
+                    key: key.data().to_vec(),
+                    seq,
+                    batch_idx,
+                    row_idx,
+                });
+                used = true;
+                continue;
+            }
+
+            // Heap is full, only replace the root if this row sorts before it.
+            // Rows with equal keys keep the earlier row since `seq` is always
+            // increasing.
+            let mut top = state.heap.peek_mut().expect("heap to not be empty");
+            if key.data() < top.key.as_slice() {
+                *top = HeapRow {
+                    key: key.data().to_vec(),
+                    seq,
+                    batch_idx,
+                    row_idx,
+                };
+                used = true;
+            }
+        }
+
+        if used {
+            state.buffered_rows += batch.num_rows();
+            state.batches.push(batch);
+        }
+
+        Ok(())
+    }
+
+    /// Copy the rows retained in the heap into a single batch, dropping all
+    /// other buffered batches.
+    fn compact(state: &mut TopNConsumingState) -> Result<()> {
+        let rows = std::mem::take(&mut state.heap).into_vec();
+        let batch = match build_batch(&state.batches, &rows)? {
+            Some(batch) => batch,
+            None => {
+                state.batches.clear();
+                state.buffered_rows = 0;
+                return Ok(());
+            }
+        };
+
+        state.heap = rows
+            .into_iter()
+            .enumerate()
+            .map(|(idx, row)| HeapRow {
+                batch_idx: 0,
+                row_idx: idx,
+                ..row
+            })
+            .collect();
+        state.buffered_rows = batch.num_rows();
+        state.batches = vec![batch];
+
+        Ok(())
+    }
+}
+
+/// Build a batch by taking `rows` in order from `batches`.
+fn build_batch(batches: &[Batch], rows: &[HeapRow]) -> Result<Option<Batch>> {
+    let num_cols = match batches.first() {
+        Some(batch) if !rows.is_empty() => batch.num_columns(),
+        _ => return Ok(None),
+    };
+
+    let indices: Vec<_> = rows
+        .iter()
+        .map(|row| (row.batch_idx, row.row_idx))
+        .collect();
+    let cols = (0..num_cols)
+        .map(|col_idx| {
+            let arrays: Vec<_> = batches
+                .iter()
+                .map(|batch| batch.column(col_idx).expect("column to exist"))
+                .collect();
+            interleave(&arrays, &indices)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(Batch::try_new(cols)?))
+}
+
+impl Explainable for PhysicalTopN {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("TopN").with_value("limit", self.limit)
+    }
+}
+
+impl DatabaseProtoConv for PhysicalTopN {
+    type ProtoType = rayexec_proto::generated::execution::PhysicalTopN;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            exprs: self
+                .exprs
+                .iter()
+                .map(|expr| expr.to_proto_ctx(context))
+                .collect::<Result<Vec<_>>>()?,
+            limit: self.limit as u64,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            exprs: proto
+                .exprs
+                .into_iter()
+                .map(|expr| DatabaseProtoConv::from_proto_ctx(expr, context))
+                .collect::<Result<Vec<_>>>()?,
+            limit: proto.limit as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+    use crate::arrays::array::Array;
+    use crate::arrays::scalar::OwnedScalarValue;
+    use crate::execution::operators::sort::scatter_sort::PhysicalScatterSort;
+    use crate::execution::operators::test_util::{
+        logical_value,
+        make_i32_batch,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+    use crate::expr::physical::column_expr::PhysicalColumnExpr;
+
+    /// Push all inputs through an operator with a single partition and collect
+    /// the output into a single batch.
+    fn run_single_partition<O: ExecutableOperator>(operator: Arc<O>, inputs: Vec<Batch>) -> Batch {
+        let context = test_database_context();
+        let states = operator.create_states(&context, vec![1]).unwrap();
+        let operator_state = states.operator_state;
+        let mut partition_state = match states.partition_states {
+            InputOutputStates::OneToOne { partition_states } => {
+                partition_states.into_iter().next().unwrap()
+            }
+            other => panic!("unexpected states: {other:?}"),
+        };
+
+        let push_cx = TestWakerContext::new();
+        for input in inputs {
+            let poll_push = push_cx
+                .poll_push(&operator, &mut partition_state, &operator_state, input)
+                .unwrap();
+            assert_eq!(PollPush::NeedsMore, poll_push);
+        }
+        operator
+            .poll_finalize_push(
+                &mut push_cx.context(),
+                &mut partition_state,
+                &operator_state,
+            )
+            .unwrap();
+
+        let pull_cx = TestWakerContext::new();
+        let mut outputs = Vec::new();
+        loop {
+            let poll_pull = pull_cx
+                .poll_pull(&operator, &mut partition_state, &operator_state)
+                .unwrap();
+            if poll_pull == PollPull::Exhausted {
+                break;
+            }
+            outputs.push(unwrap_poll_pull_batch(poll_pull));
+        }
+
+        if outputs.is_empty() {
+            return Batch::empty_with_num_rows(0);
+        }
+        Batch::concat(&outputs).unwrap()
+    }
+
+    fn collect_rows(batch: &Batch) -> Vec<Vec<OwnedScalarValue>> {
+        (0..batch.num_rows())
+            .map(|row| {
+                (0..batch.num_columns())
+                    .map(|col| logical_value(batch, col, row).into_owned())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Generate a batch with two low-cardinality key columns containing nulls,
+    /// and a unique id column.
+    fn make_random_batch(rng: &mut StdRng, num_rows: usize, id_start: i32) -> Batch {
+        let a_vals: Vec<Option<i32>> = (0..num_rows)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..20)))
+            .collect();
+        let b_vals: Vec<Option<i32>> = (0..num_rows)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..5)))
+            .collect();
+
+        let mut a = Array::from_iter(a_vals.iter().map(|v| v.unwrap_or_default()));
+        let mut b = Array::from_iter(b_vals.iter().map(|v| v.unwrap_or_default()));
+        for idx in 0..num_rows {
+            a.set_physical_validity(idx, a_vals[idx].is_some());
+            b.set_physical_validity(idx, b_vals[idx].is_some());
+        }
+        let id = Array::from_iter(id_start..(id_start + num_rows as i32));
+
+        Batch::try_new([a, b, id]).unwrap()
+    }
+
+    #[test]
+    fn top_n_matches_full_sort_random() {
+        // ORDER BY a ASC NULLS LAST, b DESC NULLS FIRST
+        let exprs = vec![
+            PhysicalSortExpression {
+                column: PhysicalColumnExpr { idx: 0 },
+                desc: false,
+                nulls_first: false,
+            },
+            PhysicalSortExpression {
+                column: PhysicalColumnExpr { idx: 1 },
+                desc: true,
+                nulls_first: true,
+            },
+        ];
+
+        let mut rng = StdRng::seed_from_u64(0xdecade);
+        for limit in [0, 1, 7, 100, 2500, 10_000, 100_000_000, usize::MAX] {
+            let mut inputs = Vec::new();
+            let mut id = 0;
+            for _ in 0..12 {
+                let num_rows = rng.gen_range(0..600);
+                inputs.push(make_random_batch(&mut rng, num_rows, id));
+                id += num_rows as i32;
+            }
+
+            let sorted = run_single_partition(
+                Arc::new(PhysicalScatterSort::new(exprs.clone())),
+                inputs.clone(),
+            );
+            let mut expected = collect_rows(&sorted);
+            expected.truncate(limit);
+
+            let got = collect_rows(&run_single_partition(
+                Arc::new(PhysicalTopN::new(exprs.clone(), limit)),
+                inputs,
+            ));
+
+            assert_eq!(expected, got, "limit: {limit}");
+        }
+    }
+
+    #[test]
+    fn top_n_memory_bounded() {
+        let limit = 10;
+        let operator = Arc::new(PhysicalTopN::new(
+            vec![PhysicalSortExpression {
+                column: PhysicalColumnExpr { idx: 0 },
+                desc: true,
+                nulls_first: false,
+            }],
+            limit,
+        ));
+        let context = test_database_context();
+        let states = operator.create_states(&context, vec![1]).unwrap();
+        let operator_state = states.operator_state;
+        let mut partition_state = match states.partition_states {
+            InputOutputStates::OneToOne { partition_states } => {
+                partition_states.into_iter().next().unwrap()
+            }
+            other => panic!("unexpected states: {other:?}"),
+        };
+
+        // Ascending input means every batch contains new top rows, so every
+        // batch would be retained without compaction.
+        let batch_size = 1024;
+        let num_batches = 1000;
+        let bound = operator.compaction_threshold() + batch_size;
+
+        let push_cx = TestWakerContext::new();
+        for batch_idx in 0..num_batches {
+            let start = (batch_idx * batch_size) as i32;
+            let input = make_i32_batch(start..(start + batch_size as i32));
+            push_cx
+                .poll_push(&operator, &mut partition_state, &operator_state, input)
+                .unwrap();
+
+            let state = match &partition_state {
+                PartitionState::TopN(TopNPartitionState::Consuming(state)) => state,
+                other => panic!("unexpected state: {other:?}"),
+            };
+            assert!(state.heap.len() <= limit);
+            assert!(
+                state.buffered_rows < bound,
+                "buffered rows: {}, bound: {bound}",
+                state.buffered_rows
+            );
+        }
+
+        operator
+            .poll_finalize_push(
+                &mut push_cx.context(),
+                &mut partition_state,
+                &operator_state,
+            )
+            .unwrap();
+
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_state, &operator_state)
+            .unwrap();
+        let output = unwrap_poll_pull_batch(poll_pull);
+        let max = (num_batches * batch_size) as i32;
+        assert_eq!(make_i32_batch((max - limit as i32..max).rev()), output);
+    }
+}
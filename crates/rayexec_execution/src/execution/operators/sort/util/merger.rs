@@ -208,7 +208,9 @@ where
 /// A row reference with an additional input index which allows us to map the
 /// row reference to some batch.
 ///
-/// `Eq` and `Ord` delegate to the row reference.
+/// `Eq` and `Ord` delegate to the row reference, with ties broken by the input
+/// index. This keeps the merge stable, rows from earlier inputs that compare
+/// equal are emitted first.
 #[derive(Debug)]
 struct InputRowReference {
     input_idx: usize,
@@ -217,7 +219,7 @@ struct InputRowReference {
 
 impl PartialEq for InputRowReference {
     fn eq(&self, other: &Self) -> bool {
-        self.row_reference == other.row_reference
+        self.row_reference == other.row_reference && self.input_idx == other.input_idx
     }
 }
 
@@ -231,6 +233,8 @@ impl PartialOrd for InputRowReference {
 
 impl Ord for InputRowReference {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.row_reference.cmp(&other.row_reference)
+        self.row_reference
+            .cmp(&other.row_reference)
+            .then_with(|| self.input_idx.cmp(&other.input_idx))
     }
 }
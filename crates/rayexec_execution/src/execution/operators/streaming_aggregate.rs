@@ -0,0 +1,641 @@
+use std::sync::Arc;
+use std::task::{Context, Waker};
+
+use rayexec_error::Result;
+
+use super::hash_aggregate::distinct::DistinctGroupedStates;
+use super::hash_aggregate::hash_table::GroupAddress;
+use super::{
+    ExecutableOperator,
+    ExecutionStates,
+    InputOutputStates,
+    OperatorState,
+    PartitionState,
+    PollFinalize,
+    PollPull,
+    PollPush,
+};
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::executor::scalar::{interleave, SelectExecutor};
+use crate::arrays::row::encoding::{ComparableColumn, ComparableRowEncoder};
+use crate::arrays::selection::SelectionVector;
+use crate::database::DatabaseContext;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::physical::PhysicalAggregateExpression;
+use crate::functions::aggregate::states::AggregateGroupStates;
+use crate::functions::aggregate::ChunkGroupAddressIter;
+
+#[derive(Debug)]
+pub struct StreamingAggregatePartitionState {
+    /// States for the group that's still receiving input, one per aggregate.
+    ///
+    /// Each contains a single state, or no states if we haven't received any
+    /// input yet.
+    agg_states: Vec<Box<dyn AggregateGroupStates>>,
+
+    /// Encoded group key of the open group.
+    open_key: Option<Vec<u8>>,
+
+    /// Group values of the open group, each array containing a single row.
+    open_group_values: Vec<Array>,
+
+    /// Batch of completed groups waiting to be pulled.
+    buffer: Option<Batch>,
+
+    /// Waker on pull side if no batch is ready.
+    pull_waker: Option<Waker>,
+
+    /// Waker on push side if this partition is already buffering an output
+    /// batch.
+    push_waker: Option<Waker>,
+
+    /// If inputs are finished.
+    finished: bool,
+}
+
+/// Compute aggregates over input that's sorted on the group columns.
+///
+/// Since all rows for a group are next to each other, a group is complete
+/// once we see a different group key. Completed groups are emitted right away,
+/// and only the states for the current group are kept between batches.
+///
+/// Output batch columns will include the computed aggregates, followed by the
+/// group by columns, matching the output of the hash aggregate.
+///
+/// Input order is only preserved within a partition, so this should be used
+/// with a single partition.
+#[derive(Debug)]
+pub struct PhysicalStreamingAggregate {
+    /// Aggregates we're computing.
+    aggregates: Vec<PhysicalAggregateExpression>,
+    /// Indices of the group by columns in the input.
+    group_columns: Vec<usize>,
+}
+
+impl PhysicalStreamingAggregate {
+    pub fn new(aggregates: Vec<PhysicalAggregateExpression>, group_columns: Vec<usize>) -> Self {
+        PhysicalStreamingAggregate {
+            aggregates,
+            group_columns,
+        }
+    }
+
+    fn create_agg_states(&self, num_groups: usize) -> Vec<Box<dyn AggregateGroupStates>> {
+        self.aggregates
+            .iter()
+            .map(|agg| {
+                let mut states = if agg.is_distinct {
+                    Box::new(DistinctGroupedStates::new(
+                        agg.function.function_impl.new_states(),
+                    ))
+                } else {
+                    agg.function.function_impl.new_states()
+                };
+                states.new_states(num_groups);
+                states
+            })
+            .collect()
+    }
+
+    /// Aggregate a batch, returning a batch containing the groups that were
+    /// completed by this batch.
+    fn aggregate_batch(
+        &self,
+        state: &mut StreamingAggregatePartitionState,
+        batch: &Batch,
+    ) -> Result<Option<Batch>> {
+        let num_rows = batch.num_rows();
+        if num_rows == 0 {
+            return Ok(None);
+        }
+
+        let group_cols: Vec<_> = self
+            .group_columns
+            .iter()
+            .map(|idx| batch.column(*idx).expect("column to exist"))
+            .collect();
+
+        // Only used for checking equality, direction doesn't matter.
+        let encoder = ComparableRowEncoder {
+            columns: self
+                .group_columns
+                .iter()
+                .map(|_| ComparableColumn {
+                    desc: false,
+                    nulls_first: false,
+                })
+                .collect(),
+        };
+        let keys = encoder.encode(&group_cols)?;
+
+        // Map each row to a group. If we have an open group from a previous
+        // batch, that's group 0.
+        let has_open = state.open_key.is_some();
+        let mut num_groups = usize::from(has_open);
+        let mut group_starts = Vec::new();
+        let mut addrs = Vec::with_capacity(num_rows);
+
+        let mut prev_key = state.open_key.as_deref();
+        for (row_idx, key) in keys.iter().enumerate() {
+            let key = key.data();
+            if prev_key != Some(key) {
+                group_starts.push(row_idx);
+                num_groups += 1;
+            }
+            prev_key = Some(key);
+
+            addrs.push(GroupAddress {
+                chunk_idx: 0,
+                row_idx: (num_groups - 1) as u16,
+            });
+        }
+
+        let mut batch_states = self.create_agg_states(num_groups);
+
+        if has_open {
+            let mapping = [GroupAddress {
+                chunk_idx: 0,
+                row_idx: 0,
+            }];
+            for (batch_state, open_state) in
+                batch_states.iter_mut().zip(state.agg_states.iter_mut())
+            {
+                batch_state.combine(open_state, ChunkGroupAddressIter::new(0, &mapping))?;
+            }
+        }
+
+        for (agg, states) in self.aggregates.iter().zip(batch_states.iter_mut()) {
+            let cols: Vec<_> = agg
+                .columns
+                .iter()
+                .map(|expr| batch.column(expr.idx).expect("column to exist"))
+                .collect();
+
+            match &agg.filter {
+                Some(filter) => {
+                    // Only update the states using rows that pass the filter.
+                    let filter = batch.column(filter.idx).expect("column to exist");
+                    let mut selection = SelectionVector::with_capacity(num_rows);
+                    SelectExecutor::select(filter, &mut selection)?;
+
+                    let selected_addrs: Vec<_> =
+                        selection.iter_locations().map(|idx| addrs[idx]).collect();
+
+                    let selection = Arc::new(selection);
+                    let cols: Vec<_> = cols
+                        .into_iter()
+                        .map(|arr| {
+                            let mut arr = arr.clone();
+                            arr.select_mut(selection.clone());
+                            arr
+                        })
+                        .collect();
+                    let cols: Vec<_> = cols.iter().collect();
+
+                    states.update_states(&cols, ChunkGroupAddressIter::new(0, &selected_addrs))?;
+                }
+                None => {
+                    states.update_states(&cols, ChunkGroupAddressIter::new(0, &addrs))?;
+                }
+            }
+        }
+
+        // The last group may continue into the next batch. Move its states
+        // out so that everything else can be finalized.
+        //
+        // Only the last state maps to chunk 0, all other states are skipped.
+        let last_group = num_groups - 1;
+        let mapping: Vec<_> = (0..num_groups)
+            .map(|group_idx| GroupAddress {
+                chunk_idx: if group_idx == last_group { 0 } else { 1 },
+                row_idx: 0,
+            })
+            .collect();
+
+        let mut open_states = self.create_agg_states(1);
+        for (open_state, batch_state) in open_states.iter_mut().zip(batch_states.iter_mut()) {
+            open_state.combine(batch_state, ChunkGroupAddressIter::new(0, &mapping))?;
+        }
+        state.agg_states = open_states;
+
+        let completed = if last_group > 0 {
+            // Group values for the completed groups, either from the
+            // previously open group or from the first row of a group in this
+            // batch.
+            let mut group_values = Vec::with_capacity(group_cols.len());
+            for (col_idx, col) in group_cols.iter().enumerate() {
+                let values = if has_open {
+                    let indices: Vec<_> = [(0, 0)]
+                        .into_iter()
+                        .chain(group_starts.iter().map(|&row| (1, row)))
+                        .take(last_group)
+                        .collect();
+                    interleave(&[&state.open_group_values[col_idx], col], &indices)?
+                } else {
+                    let indices: Vec<_> = group_starts
+                        .iter()
+                        .map(|&row| (0, row))
+                        .take(last_group)
+                        .collect();
+                    interleave(&[col], &indices)?
+                };
+                group_values.push(values);
+            }
+
+            let mut arrays = Vec::with_capacity(self.aggregates.len() + group_values.len());
+            for states in batch_states.iter_mut() {
+                // Last state was moved into the open states above, omit it
+                // from the output.
+                let arr = states.finalize()?;
+                arrays.push(arr.slice(0, last_group));
+            }
+            arrays.extend(group_values);
+
+            Some(Batch::try_new(arrays)?)
+        } else {
+            None
+        };
+
+        // Every row in a group has the same group values, just take them from
+        // the last row.
+        state.open_key = keys.row(num_rows - 1).map(|row| row.data().to_vec());
+        state.open_group_values = group_cols
+            .iter()
+            .map(|col| interleave(&[col], &[(0, num_rows - 1)]))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(completed)
+    }
+
+    /// Finalize the open group, returning a batch containing its output.
+    fn finalize_open_group(
+        &self,
+        state: &mut StreamingAggregatePartitionState,
+    ) -> Result<Option<Batch>> {
+        if state.open_key.take().is_none() {
+            return Ok(None);
+        }
+
+        let mut arrays = state
+            .agg_states
+            .iter_mut()
+            .map(|s| s.finalize())
+            .collect::<Result<Vec<_>>>()?;
+        arrays.append(&mut state.open_group_values);
+        state.agg_states.clear();
+
+        Ok(Some(Batch::try_new(arrays)?))
+    }
+}
+
+impl ExecutableOperator for PhysicalStreamingAggregate {
+    fn create_states(
+        &self,
+        _context: &DatabaseContext,
+        partitions: Vec<usize>,
+    ) -> Result<ExecutionStates> {
+        let partitions = partitions[0];
+
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::None),
+            partition_states: InputOutputStates::OneToOne {
+                partition_states: (0..partitions)
+                    .map(|_| {
+                        PartitionState::StreamingAggregate(StreamingAggregatePartitionState {
+                            agg_states: Vec::new(),
+                            open_key: None,
+                            open_group_values: Vec::new(),
+                            buffer: None,
+                            pull_waker: None,
+                            push_waker: None,
+                            finished: false,
+                        })
+                    })
+                    .collect(),
+            },
+        })
+    }
+
+    fn poll_push(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+        batch: Batch,
+    ) -> Result<PollPush> {
+        let state = match partition_state {
+            PartitionState::StreamingAggregate(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        if state.buffer.is_some() {
+            state.push_waker = Some(cx.waker().clone());
+
+            // Trigger pull to make room.
+            if let Some(waker) = state.pull_waker.take() {
+                waker.wake();
+            }
+
+            return Ok(PollPush::Pending(batch));
+        }
+
+        match self.aggregate_batch(state, &batch)? {
+            Some(completed) => {
+                state.buffer = Some(completed);
+                if let Some(waker) = state.pull_waker.take() {
+                    waker.wake();
+                }
+
+                Ok(PollPush::Pushed)
+            }
+            None => Ok(PollPush::NeedsMore),
+        }
+    }
+
+    fn poll_finalize_push(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollFinalize> {
+        let state = match partition_state {
+            PartitionState::StreamingAggregate(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        if state.buffer.is_some() {
+            state.push_waker = Some(cx.waker().clone());
+
+            // Trigger pull.
+            if let Some(waker) = state.pull_waker.take() {
+                waker.wake();
+            }
+
+            return Ok(PollFinalize::Pending);
+        }
+
+        state.finished = true;
+        state.buffer = self.finalize_open_group(state)?;
+
+        if let Some(waker) = state.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollFinalize::Finalized)
+    }
+
+    fn poll_pull(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let state = match partition_state {
+            PartitionState::StreamingAggregate(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        match state.buffer.take() {
+            Some(batch) => {
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Computed(batch.into()))
+            }
+            None => {
+                if state.finished {
+                    return Ok(PollPull::Exhausted);
+                }
+                state.pull_waker = Some(cx.waker().clone());
+                if let Some(waker) = state.push_waker.take() {
+                    waker.wake();
+                }
+                Ok(PollPull::Pending)
+            }
+        }
+    }
+}
+
+impl Explainable for PhysicalStreamingAggregate {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("StreamingAggregate").with_values("group_columns", &self.group_columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::execution::operators::test_util::{
+        logical_value,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+    use crate::expr;
+    use crate::expr::physical::column_expr::PhysicalColumnExpr;
+    use crate::functions::aggregate::builtin::sum::Sum;
+    use crate::functions::aggregate::AggregateFunction;
+    use crate::logical::binder::table_list::TableList;
+
+    /// Create an operator computing SUM(v) GROUP BY g, with input batches
+    /// containing columns (v, g).
+    fn make_sum_operator() -> PhysicalStreamingAggregate {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int64, DataType::Utf8],
+                vec!["v".to_string(), "g".to_string()],
+            )
+            .unwrap();
+        let function = Sum
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        PhysicalStreamingAggregate::new(
+            vec![PhysicalAggregateExpression {
+                function,
+                columns: vec![PhysicalColumnExpr { idx: 0 }],
+                is_distinct: false,
+                filter: None,
+            }],
+            vec![1],
+        )
+    }
+
+    fn make_batch(values: Vec<i64>, groups: Vec<&str>) -> Batch {
+        Batch::try_new([Array::from_iter(values), Array::from_iter(groups)]).unwrap()
+    }
+
+    fn num_open_states(partition_state: &PartitionState) -> usize {
+        match partition_state {
+            PartitionState::StreamingAggregate(state) => {
+                state.agg_states.iter().map(|s| s.num_states()).sum()
+            }
+            other => panic!("invalid partition state: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn groups_spanning_batches() {
+        let operator = Arc::new(make_sum_operator());
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = match operator
+            .create_states(&test_database_context(), vec![1])
+            .unwrap()
+            .partition_states
+        {
+            InputOutputStates::OneToOne { partition_states } => partition_states,
+            other => panic!("invalid states: {other:?}"),
+        };
+
+        let cx = TestWakerContext::new();
+
+        // 'a' is the only group seen so far, nothing to emit.
+        let poll_push = cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                make_batch(vec![1, 2], vec!["a", "a"]),
+            )
+            .unwrap();
+        assert_eq!(PollPush::NeedsMore, poll_push);
+        assert_eq!(1, num_open_states(&partition_states[0]));
+
+        // Completes 'a' (continued from the previous batch) and 'b'.
+        let poll_push = cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                make_batch(vec![3, 4, 5, 6], vec!["a", "b", "b", "c"]),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Pushed, poll_push);
+        assert_eq!(1, num_open_states(&partition_states[0]));
+
+        let output = unwrap_poll_pull_batch(
+            cx.poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(2, output.num_rows());
+        assert_eq!(ScalarValue::Int64(6), logical_value(&output, 0, 0));
+        assert_eq!(ScalarValue::from("a"), logical_value(&output, 1, 0));
+        assert_eq!(ScalarValue::Int64(9), logical_value(&output, 0, 1));
+        assert_eq!(ScalarValue::from("b"), logical_value(&output, 1, 1));
+
+        // Open group 'c' completes on a boundary of the next batch.
+        let poll_push = cx
+            .poll_push(
+                &operator,
+                &mut partition_states[0],
+                &operator_state,
+                make_batch(vec![7], vec!["d"]),
+            )
+            .unwrap();
+        assert_eq!(PollPush::Pushed, poll_push);
+
+        let output = unwrap_poll_pull_batch(
+            cx.poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(1, output.num_rows());
+        assert_eq!(ScalarValue::Int64(6), logical_value(&output, 0, 0));
+        assert_eq!(ScalarValue::from("c"), logical_value(&output, 1, 0));
+
+        // Finalizing emits the last open group.
+        let poll_finalize = operator
+            .poll_finalize_push(&mut cx.context(), &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollFinalize::Finalized, poll_finalize);
+
+        let output = unwrap_poll_pull_batch(
+            cx.poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(1, output.num_rows());
+        assert_eq!(ScalarValue::Int64(7), logical_value(&output, 0, 0));
+        assert_eq!(ScalarValue::from("d"), logical_value(&output, 1, 0));
+
+        let poll_pull = cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn constant_state_for_many_groups() {
+        let operator = Arc::new(make_sum_operator());
+        let operator_state = Arc::new(OperatorState::None);
+        let mut partition_states = match operator
+            .create_states(&test_database_context(), vec![1])
+            .unwrap()
+            .partition_states
+        {
+            InputOutputStates::OneToOne { partition_states } => partition_states,
+            other => panic!("invalid states: {other:?}"),
+        };
+
+        let cx = TestWakerContext::new();
+
+        // 100 batches with 4 groups each, each group having 2 rows.
+        let mut total_groups = 0;
+        let mut total_sum = 0;
+        for batch_idx in 0..100 {
+            let keys: Vec<_> = (0..4)
+                .map(|g| format!("{:05}", batch_idx * 4 + g))
+                .collect();
+            let groups: Vec<_> = keys.iter().flat_map(|k| [k.as_str(), k.as_str()]).collect();
+            let values = vec![1; groups.len()];
+
+            let poll_push = cx
+                .poll_push(
+                    &operator,
+                    &mut partition_states[0],
+                    &operator_state,
+                    make_batch(values, groups),
+                )
+                .unwrap();
+            assert_eq!(PollPush::Pushed, poll_push);
+
+            // Only the last group in the batch is kept around.
+            assert_eq!(1, num_open_states(&partition_states[0]));
+
+            let output = unwrap_poll_pull_batch(
+                cx.poll_pull(&operator, &mut partition_states[0], &operator_state)
+                    .unwrap(),
+            );
+            // Groups before the last in the batch, plus the open group from
+            // the previous batch.
+            let expected = if batch_idx == 0 { 3 } else { 4 };
+            assert_eq!(expected, output.num_rows());
+
+            total_groups += output.num_rows();
+            for row in 0..output.num_rows() {
+                assert_eq!(ScalarValue::Int64(2), logical_value(&output, 0, row));
+                total_sum += 2;
+            }
+        }
+
+        operator
+            .poll_finalize_push(&mut cx.context(), &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(0, num_open_states(&partition_states[0]));
+
+        let output = unwrap_poll_pull_batch(
+            cx.poll_pull(&operator, &mut partition_states[0], &operator_state)
+                .unwrap(),
+        );
+        assert_eq!(1, output.num_rows());
+        assert_eq!(ScalarValue::from("00399"), logical_value(&output, 1, 0));
+        total_groups += 1;
+        total_sum += 2;
+
+        assert_eq!(400, total_groups);
+        assert_eq!(800, total_sum);
+    }
+}
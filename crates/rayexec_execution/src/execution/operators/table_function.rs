@@ -21,6 +21,7 @@ use crate::arrays::batch::Batch;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::functions::table::{PlannedTableFunction, TableFunctionImpl};
+use crate::logical::scan_filter::ScanFilter;
 use crate::proto::DatabaseProtoConv;
 use crate::storage::table_storage::{DataTableScan, Projections};
 
@@ -41,6 +42,8 @@ impl fmt::Debug for TableFunctionPartitionState {
 pub struct PhysicalTableFunction {
     function: PlannedTableFunction,
     projections: Projections,
+    /// Filters that the scan may use to skip reading data.
+    filters: Vec<ScanFilter>,
 }
 
 impl PhysicalTableFunction {
@@ -48,8 +51,14 @@ impl PhysicalTableFunction {
         PhysicalTableFunction {
             function,
             projections,
+            filters: Vec::new(),
         }
     }
+
+    pub fn with_filters(mut self, filters: Vec<ScanFilter>) -> Self {
+        self.filters = filters;
+        self
+    }
 }
 
 impl ExecutableOperator for PhysicalTableFunction {
@@ -68,8 +77,8 @@ impl ExecutableOperator for PhysicalTableFunction {
             }
         };
 
-        // TODO: Pushdown  filters
-        let scans = scan_func.scan(self.projections.clone(), partitions[0])?;
+        let scans =
+            scan_func.scan_with_filters(self.projections.clone(), &self.filters, partitions[0])?;
 
         let states = scans
             .into_iter()
@@ -26,6 +26,12 @@ pub struct TableInOutPartitionState {
     function_state: Box<dyn inout::TableInOutPartitionState>,
     /// Additional outputs that will be included on the output batch.
     additional_outputs: Vec<Array>,
+    /// Partition local copies of the function inputs if they hold execution
+    /// state.
+    local_function_inputs: Option<Vec<PhysicalScalarExpression>>,
+    /// Partition local copies of the output projections if they hold
+    /// execution state.
+    local_projected_outputs: Option<Vec<PhysicalScalarExpression>>,
 }
 
 #[derive(Debug)]
@@ -58,10 +64,19 @@ impl ExecutableOperator for PhysicalTableInOut {
 
         let states: Vec<_> = states
             .into_iter()
-            .map(|state| {
+            .enumerate()
+            .map(|(partition, state)| {
                 PartitionState::TableInOut(TableInOutPartitionState {
                     function_state: state,
                     additional_outputs: Vec::new(),
+                    local_function_inputs: PhysicalScalarExpression::partition_local_exprs(
+                        &self.function_inputs,
+                        partition,
+                    ),
+                    local_projected_outputs: PhysicalScalarExpression::partition_local_exprs(
+                        &self.projected_outputs,
+                        partition,
+                    ),
                 })
             })
             .collect();
@@ -89,8 +104,10 @@ impl ExecutableOperator for PhysicalTableInOut {
         // TODO: Don't do this.
         let orig = batch.clone();
 
-        let inputs = self
-            .function_inputs
+        let inputs = state
+            .local_function_inputs
+            .as_deref()
+            .unwrap_or(&self.function_inputs)
             .iter()
             .map(|expr| {
                 let arr = expr.eval(&batch)?;
@@ -112,8 +129,10 @@ impl ExecutableOperator for PhysicalTableInOut {
             other => {
                 // Batch was pushed to the function state, compute additional
                 // outputs.
-                let additional_outputs = self
-                    .projected_outputs
+                let additional_outputs = state
+                    .local_projected_outputs
+                    .as_deref()
+                    .unwrap_or(&self.projected_outputs)
                     .iter()
                     .map(|expr| {
                         let arr = expr.eval(&batch)?;
@@ -17,6 +17,8 @@ use super::{
     PollPush,
 };
 use crate::arrays::batch::Batch;
+use crate::arrays::executor::scalar::SelectExecutor;
+use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
 use crate::execution::operators::InputOutputStates;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
@@ -164,8 +166,35 @@ impl ExecutableOperator for PhysicalUngroupedAggregate {
                         .map(|expr| batch.column(expr.idx).expect("column to exist"))
                         .collect();
 
-                    agg_states[agg_idx]
-                        .update_states(&cols, ChunkGroupAddressIter::new(0, &addrs))?;
+                    match &agg.filter {
+                        Some(filter) => {
+                            // Only update the state using rows that pass the
+                            // filter.
+                            let filter = batch.column(filter.idx).expect("column to exist");
+                            let mut selection = SelectionVector::with_capacity(addrs.len());
+                            SelectExecutor::select(filter, &mut selection)?;
+                            let selection = Arc::new(selection);
+
+                            let cols: Vec<_> = cols
+                                .into_iter()
+                                .map(|arr| {
+                                    let mut arr = arr.clone();
+                                    arr.select_mut(selection.clone());
+                                    arr
+                                })
+                                .collect();
+                            let cols: Vec<_> = cols.iter().collect();
+
+                            agg_states[agg_idx].update_states(
+                                &cols,
+                                ChunkGroupAddressIter::new(0, &addrs[..selection.num_rows()]),
+                            )?;
+                        }
+                        None => {
+                            agg_states[agg_idx]
+                                .update_states(&cols, ChunkGroupAddressIter::new(0, &addrs))?;
+                        }
+                    }
                 }
 
                 // Keep pushing.
@@ -73,6 +73,12 @@ pub struct UnnestPartitionState {
     ///
     /// Set if we've processed all rows and need more input.
     pull_waker: Option<Waker>,
+    /// Partition local copies of the projection expressions if they hold
+    /// execution state.
+    local_project_expressions: Option<Vec<PhysicalScalarExpression>>,
+    /// Partition local copies of the unnest expressions if they hold execution
+    /// state.
+    local_unnest_expressions: Option<Vec<PhysicalScalarExpression>>,
 }
 
 #[derive(Debug)]
@@ -90,7 +96,7 @@ impl ExecutableOperator for PhysicalUnnest {
         let partitions = partitions[0];
 
         let states: Vec<_> = (0..partitions)
-            .map(|_| {
+            .map(|partition| {
                 PartitionState::Unnest(UnnestPartitionState {
                     project_inputs: vec![
                         Array::new_untyped_null_array(0);
@@ -105,6 +111,14 @@ impl ExecutableOperator for PhysicalUnnest {
                     finished: false,
                     push_waker: None,
                     pull_waker: None,
+                    local_project_expressions: PhysicalScalarExpression::partition_local_exprs(
+                        &self.project_expressions,
+                        partition,
+                    ),
+                    local_unnest_expressions: PhysicalScalarExpression::partition_local_exprs(
+                        &self.unnest_expressions,
+                        partition,
+                    ),
                 })
             })
             .collect();
@@ -139,12 +153,21 @@ impl ExecutableOperator for PhysicalUnnest {
             return Ok(PollPush::Pending(batch));
         }
 
+        let project_expressions = state
+            .local_project_expressions
+            .as_deref()
+            .unwrap_or(&self.project_expressions);
+        let unnest_expressions = state
+            .local_unnest_expressions
+            .as_deref()
+            .unwrap_or(&self.unnest_expressions);
+
         // Compute inputs. These will be stored until we've processed all rows.
-        for (col_idx, expr) in self.project_expressions.iter().enumerate() {
+        for (col_idx, expr) in project_expressions.iter().enumerate() {
             state.project_inputs[col_idx] = expr.eval(&batch)?.into_owned();
         }
 
-        for (col_idx, expr) in self.unnest_expressions.iter().enumerate() {
+        for (col_idx, expr) in unnest_expressions.iter().enumerate() {
             state.unnest_inputs[col_idx] = expr.eval(&batch)?.into_owned();
         }
 
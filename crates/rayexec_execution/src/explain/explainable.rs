@@ -136,7 +136,11 @@ impl fmt::Display for ExplainEntry {
 }
 
 // TODO: `AsExplainValue` trait.
+///
+/// Serialized without a tag so that JSON output contains plain strings and
+/// arrays.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum ExplainValue {
     Value(String),
     Values(Vec<String>),
@@ -6,9 +6,11 @@ use super::explainable::{ExplainConfig, ExplainEntry};
 use crate::execution::intermediate::pipeline::{
     IntermediatePipeline,
     IntermediatePipelineGroup,
+    IntermediatePipelineId,
     PipelineSink,
     PipelineSource,
 };
+use crate::execution::operators::analyze::AnalyzedPipeline;
 use crate::explain::explainable::Explainable;
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::logical_explain::ExplainFormat;
@@ -60,51 +62,172 @@ impl<'a> ExplainFormatter<'a> {
     }
 
     fn format(&self, node: &ExplainNode) -> Result<String> {
-        match self.format {
-            ExplainFormat::Text => {
-                fn fmt(node: &ExplainNode, indent: usize, buf: &mut String) -> Result<()> {
-                    use std::fmt::Write as _;
-
-                    writeln!(buf, "{}{}", " ".repeat(indent), node.entry.name)?;
-
-                    for (idx, (item_name, item)) in node.entry.items.iter().enumerate() {
-                        let border = if idx == node.entry.items.len() - 1 {
-                            DEFAULT_EXPLAIN_CHARS.item_left_border_last
-                        } else {
-                            DEFAULT_EXPLAIN_CHARS.item_left_border
-                        };
-
-                        writeln!(
-                            buf,
-                            "{}  {} {}: {}",
-                            " ".repeat(indent),
-                            border,
-                            item_name,
-                            item
-                        )?;
-                    }
+        format_node(self.format, node)
+    }
+}
 
-                    for child in &node.children {
-                        fmt(child, indent + 2, buf)?;
+/// Format pipelines that have finished executing, annotating operators with
+/// the metrics they recorded.
+///
+/// Unlike the other plan stages, this doesn't require a bind context since the
+/// explain entries have already been generated for the pipelines.
+pub fn format_analyzed_pipelines(
+    format: ExplainFormat,
+    pipelines: &[AnalyzedPipeline],
+) -> Result<String> {
+    let children = pipelines
+        .iter()
+        .map(|pipeline| {
+            let children = pipeline
+                .operators
+                .iter()
+                .map(|(entry, operator)| {
+                    let mut entry = entry.clone();
+                    if let Some(metrics) = operator.metrics() {
+                        entry = entry
+                            .with_value("actual_rows", metrics.rows_emitted())
+                            .with_value("elapsed", format!("{:?}", metrics.elapsed()));
+                    }
+                    ExplainNode {
+                        entry,
+                        children: Vec::new(),
                     }
+                })
+                .collect();
 
-                    Ok(())
+            ExplainNode {
+                entry: pipeline.entry.clone(),
+                children,
+            }
+        })
+        .collect();
+
+    let node = ExplainNode {
+        entry: ExplainEntry::new("AnalyzedPipelines"),
+        children,
+    };
+
+    format_node(format, &node)
+}
+
+fn format_node(format: ExplainFormat, node: &ExplainNode) -> Result<String> {
+    match format {
+        ExplainFormat::Text => {
+            fn fmt(node: &ExplainNode, indent: usize, buf: &mut String) -> Result<()> {
+                use std::fmt::Write as _;
+
+                writeln!(buf, "{}{}", " ".repeat(indent), node.entry.name)?;
+
+                for (idx, (item_name, item)) in node.entry.items.iter().enumerate() {
+                    let border = if idx == node.entry.items.len() - 1 {
+                        DEFAULT_EXPLAIN_CHARS.item_left_border_last
+                    } else {
+                        DEFAULT_EXPLAIN_CHARS.item_left_border
+                    };
+
+                    writeln!(
+                        buf,
+                        "{}  {} {}: {}",
+                        " ".repeat(indent),
+                        border,
+                        item_name,
+                        item
+                    )?;
                 }
 
-                let mut buf = String::new();
-                fmt(node, 0, &mut buf)?;
+                for child in &node.children {
+                    fmt(child, indent + 2, buf)?;
+                }
 
-                Ok(buf)
-            }
-            ExplainFormat::Json => {
-                serde_json::to_string(&node).context("failed to serialize to json")
+                Ok(())
             }
+
+            let mut buf = String::new();
+            fmt(node, 0, &mut buf)?;
+
+            Ok(buf)
         }
+        ExplainFormat::Json => serde_json::to_string(&node).context("failed to serialize to json"),
     }
 }
 
+/// Create the explain entry for an intermediate pipeline, not including its
+/// operators.
+pub(crate) fn intermediate_pipeline_entry(
+    id: IntermediatePipelineId,
+    sink: &PipelineSink,
+    source: &PipelineSource,
+) -> ExplainEntry {
+    let mut entry = ExplainEntry::new(format!("IntermediatePipeline {}", id.0));
+    entry = match *sink {
+        PipelineSink::QueryOutput => entry.with_value("Sink", "QueryOutput"),
+        PipelineSink::InPipeline => entry.with_value("Sink", "InPipeline"),
+        PipelineSink::InGroup {
+            pipeline_id,
+            operator_idx,
+            input_idx,
+        } => entry.with_named_map(
+            "Sink",
+            "InGroup",
+            [
+                ("pipeline_id", pipeline_id.0),
+                ("operator_idx", operator_idx),
+                ("input_idx", input_idx),
+            ],
+        ),
+        PipelineSink::OtherGroup {
+            stream_id,
+            partitions,
+        } => entry.with_named_map(
+            "Sink",
+            "OtherGroup",
+            [
+                ("query_id", stream_id.query_id.to_string()),
+                ("stream_id", stream_id.stream_id.to_string()),
+                ("partitions", partitions.to_string()),
+            ],
+        ),
+        PipelineSink::Materialization { mat_ref } => entry.with_named_map(
+            "Sink",
+            "Materialization",
+            [("materialization_ref", mat_ref)],
+        ),
+    };
+
+    entry = match *source {
+        PipelineSource::InPipeline => entry.with_value("Source", "InPipeline"),
+        PipelineSource::OtherGroup {
+            stream_id,
+            partitions,
+        } => entry.with_named_map(
+            "Source",
+            "OtherGroup",
+            [
+                ("query_id", stream_id.query_id.to_string()),
+                ("stream_id", stream_id.stream_id.to_string()),
+                ("partitions", partitions.to_string()),
+            ],
+        ),
+        PipelineSource::OtherPipeline { pipeline, .. } => {
+            entry.with_named_map("Source", "OtherPipeline", [("pipeline_id", pipeline.0)])
+        }
+        PipelineSource::Materialization { mat_ref } => entry.with_named_map(
+            "Source",
+            "Materialization",
+            [("materialization_ref", mat_ref)],
+        ),
+    };
+
+    entry
+}
+
+/// A node in the explain tree.
+///
+/// When serialized, the entry's name and items are placed directly on the
+/// node alongside its children.
 #[derive(Debug, Serialize, Deserialize)]
 struct ExplainNode {
+    #[serde(flatten)]
     entry: ExplainEntry,
     children: Vec<ExplainNode>,
 }
@@ -148,65 +271,7 @@ impl ExplainNode {
     ) -> ExplainNode {
         let _ = bind_context;
 
-        let mut entry = ExplainEntry::new(format!("IntermediatePipeline {}", pipeline.id.0));
-        entry = match pipeline.sink {
-            PipelineSink::QueryOutput => entry.with_value("Sink", "QueryOutput"),
-            PipelineSink::InPipeline => entry.with_value("Sink", "InPipeline"),
-            PipelineSink::InGroup {
-                pipeline_id,
-                operator_idx,
-                input_idx,
-            } => entry.with_named_map(
-                "Sink",
-                "InGroup",
-                [
-                    ("pipeline_id", pipeline_id.0),
-                    ("operator_idx", operator_idx),
-                    ("input_idx", input_idx),
-                ],
-            ),
-            PipelineSink::OtherGroup {
-                stream_id,
-                partitions,
-            } => entry.with_named_map(
-                "Sink",
-                "OtherGroup",
-                [
-                    ("query_id", stream_id.query_id.to_string()),
-                    ("stream_id", stream_id.stream_id.to_string()),
-                    ("partitions", partitions.to_string()),
-                ],
-            ),
-            PipelineSink::Materialization { mat_ref } => entry.with_named_map(
-                "Sink",
-                "Materialization",
-                [("materialization_ref", mat_ref)],
-            ),
-        };
-
-        entry = match pipeline.source {
-            PipelineSource::InPipeline => entry.with_value("Source", "InPipeline"),
-            PipelineSource::OtherGroup {
-                stream_id,
-                partitions,
-            } => entry.with_named_map(
-                "Source",
-                "OtherGroup",
-                [
-                    ("query_id", stream_id.query_id.to_string()),
-                    ("stream_id", stream_id.stream_id.to_string()),
-                    ("partitions", partitions.to_string()),
-                ],
-            ),
-            PipelineSource::OtherPipeline { pipeline, .. } => {
-                entry.with_named_map("Source", "OtherPipeline", [("pipeline_id", pipeline.0)])
-            }
-            PipelineSource::Materialization { mat_ref } => entry.with_named_map(
-                "Source",
-                "Materialization",
-                [("materialization_ref", mat_ref)],
-            ),
-        };
+        let entry = intermediate_pipeline_entry(pipeline.id, &pipeline.sink, &pipeline.source);
 
         let children = pipeline
             .operators
@@ -234,10 +299,13 @@ impl ExplainNode {
             LogicalOperator::Aggregate(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::SetOp(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Empty(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::NoRows(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Limit(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::TopN(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Order(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::SetVar(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::ResetVar(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Transaction(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::ShowVar(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::AttachDatabase(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::DetachDatabase(n) => (n.explain_entry(config), &n.children),
@@ -256,6 +324,7 @@ impl ExplainNode {
             LogicalOperator::Unnest(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Window(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::InOut(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Ordinality(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::MaterializationScan(n) => {
                 // Materialization special case, walk children by get
                 // materialization from bind context.
@@ -295,3 +364,123 @@ impl ExplainNode {
         ExplainNode { entry, children }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::explain::context_display::ContextDisplayMode;
+    use crate::expr;
+    use crate::expr::aggregate_expr::AggregateExpr;
+    use crate::expr::comparison_expr::ComparisonOperator;
+    use crate::expr::Expression;
+    use crate::functions::aggregate::builtin::count::Count;
+    use crate::functions::aggregate::AggregateFunction;
+    use crate::logical::binder::table_list::{TableList, TableRef};
+    use crate::logical::logical_aggregate::LogicalAggregate;
+    use crate::logical::logical_empty::LogicalEmpty;
+    use crate::logical::logical_join::{ComparisonCondition, JoinType, LogicalComparisonJoin};
+    use crate::logical::operator::{LocationRequirement, Node};
+    use crate::logical::statistics::StatisticsValue;
+
+    fn node<N>(node: N, children: Vec<LogicalOperator>) -> Node<N> {
+        Node {
+            node,
+            location: LocationRequirement::Any,
+            children,
+            estimated_cardinality: StatisticsValue::Unknown,
+        }
+    }
+
+    /// Plan for an aggregate on top of a join.
+    fn join_aggregate_plan() -> LogicalOperator {
+        let join = LogicalOperator::ComparisonJoin(node(
+            LogicalComparisonJoin {
+                join_type: JoinType::Inner,
+                conditions: vec![ComparisonCondition {
+                    left: expr::lit(1),
+                    right: expr::lit(2),
+                    op: ComparisonOperator::Eq,
+                }],
+            },
+            vec![
+                LogicalOperator::Empty(node(LogicalEmpty, Vec::new())),
+                LogicalOperator::Empty(node(LogicalEmpty, Vec::new())),
+            ],
+        ));
+
+        let count = Count.plan(&TableList::empty(), vec![expr::lit(1)]).unwrap();
+        LogicalOperator::Aggregate(node(
+            LogicalAggregate {
+                aggregates_table: TableRef::from(0),
+                aggregates: vec![Expression::Aggregate(AggregateExpr {
+                    agg: count,
+                    filter: None,
+                    distinct: false,
+                    ordering: None,
+                })],
+                group_table: None,
+                group_exprs: Vec::new(),
+                grouping_sets: None,
+                grouping_functions_table: None,
+                grouping_functions: Vec::new(),
+            },
+            vec![join],
+        ))
+    }
+
+    fn format_json(plan: &LogicalOperator, verbose: bool) -> serde_json::Value {
+        let bind_context = BindContext::new();
+        let formatter = ExplainFormatter::new(
+            &bind_context,
+            ExplainConfig {
+                context_mode: ContextDisplayMode::Raw,
+                verbose,
+            },
+            ExplainFormat::Json,
+        );
+        let out = formatter.format_logical_plan(plan).unwrap();
+        serde_json::from_str(&out).unwrap()
+    }
+
+    #[test]
+    fn json_join_aggregate() {
+        let out = format_json(&join_aggregate_plan(), false);
+
+        let empty = json!({
+            "name": "Empty",
+            "items": {"location": "Any"},
+            "children": [],
+        });
+        let expected = json!({
+            "name": "Aggregate",
+            "items": {
+                "aggregates": ["count(1)"],
+                "location": "Any",
+            },
+            "children": [{
+                "name": "ComparisonJoin",
+                "items": {
+                    "conditions": ["1 = 2"],
+                    "join_type": "INNER",
+                    "location": "Any",
+                },
+                "children": [empty.clone(), empty],
+            }],
+        });
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn json_verbose() {
+        let out = format_json(&join_aggregate_plan(), true);
+
+        assert_eq!("[unknown]", out["items"]["cardinality"]);
+        let join = &out["children"][0];
+        assert_eq!("ComparisonJoin", join["name"]);
+        assert_eq!("[unknown]", join["items"]["cardinality"]);
+        assert_eq!("[unknown]", join["children"][1]["items"]["cardinality"]);
+    }
+}
@@ -16,6 +16,18 @@ pub struct AggregateExpr {
     pub filter: Option<Box<Expression>>,
     /// If the inputs should be deduplicated.
     pub distinct: bool,
+    /// Ordering from `WITHIN GROUP (ORDER BY ...)` for ordered-set aggregates.
+    pub ordering: Option<AggregateOrdering>,
+}
+
+/// Ordering specification for an ordered-set aggregate.
+///
+/// The expression being ordered is the first input to the aggregate, the
+/// remaining inputs are the direct arguments to the function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AggregateOrdering {
+    pub desc: bool,
+    pub nulls_first: bool,
 }
 
 impl AggregateExpr {
@@ -31,9 +43,15 @@ impl ContextDisplay for AggregateExpr {
         f: &mut fmt::Formatter<'_>,
     ) -> fmt::Result {
         write!(f, "{}", self.agg.function.name())?;
-        let inputs = self
-            .agg
-            .inputs
+
+        // Ordered-set aggregates only show the direct arguments in the call,
+        // the first input is shown in the WITHIN GROUP.
+        let direct_inputs = match self.ordering {
+            Some(_) => &self.agg.inputs[1..],
+            None => &self.agg.inputs[..],
+        };
+
+        let inputs = direct_inputs
             .iter()
             .map(|e| ContextDisplayWrapper::with_mode(e, mode).to_string())
             .collect::<Vec<_>>()
@@ -44,10 +62,24 @@ impl ContextDisplay for AggregateExpr {
             write!(f, "({})", inputs)?;
         }
 
+        if let Some(ordering) = self.ordering {
+            write!(
+                f,
+                " WITHIN GROUP (ORDER BY {} {} {})",
+                ContextDisplayWrapper::with_mode(&self.agg.inputs[0], mode),
+                if ordering.desc { "desc" } else { "asc" },
+                if ordering.nulls_first {
+                    "nulls_first"
+                } else {
+                    "nulls_last"
+                }
+            )?;
+        }
+
         if let Some(filter) = self.filter.as_ref() {
             write!(
                 f,
-                "FILTER (WHERE {})",
+                " FILTER (WHERE {})",
                 ContextDisplayWrapper::with_mode(filter.as_ref(), mode)
             )?;
         }
@@ -1,13 +1,16 @@
 use std::fmt;
 
-use rayexec_error::Result;
+use rayexec_error::{OptionExt, RayexecError, Result};
+use rayexec_proto::ProtoConv;
 
 use super::{AsScalarFunction, Expression};
 use crate::arrays::datatype::DataType;
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
-use crate::functions::scalar::builtin::arith;
+use crate::functions::scalar::builtin::arith::{self, OverflowBehavior};
 use crate::functions::scalar::ScalarFunction;
 use crate::logical::binder::table_list::TableList;
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ArithOperator {
@@ -16,6 +19,11 @@ pub enum ArithOperator {
     Div,
     Mul,
     Mod,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
 }
 
 impl AsScalarFunction for ArithOperator {
@@ -26,6 +34,11 @@ impl AsScalarFunction for ArithOperator {
             Self::Div => &arith::Div,
             Self::Mul => &arith::Mul,
             Self::Mod => &arith::Rem,
+            Self::BitAnd => &arith::BitAnd,
+            Self::BitOr => &arith::BitOr,
+            Self::BitXor => &arith::BitXor,
+            Self::ShiftLeft => &arith::ShiftLeft,
+            Self::ShiftRight => &arith::ShiftRight,
         }
     }
 }
@@ -38,15 +51,57 @@ impl fmt::Display for ArithOperator {
             Self::Div => write!(f, "/"),
             Self::Mul => write!(f, "*"),
             Self::Mod => write!(f, "%"),
+            Self::BitAnd => write!(f, "&"),
+            Self::BitOr => write!(f, "|"),
+            Self::BitXor => write!(f, "#"),
+            Self::ShiftLeft => write!(f, "<<"),
+            Self::ShiftRight => write!(f, ">>"),
         }
     }
 }
 
+impl ProtoConv for ArithOperator {
+    type ProtoType = rayexec_proto::generated::logical::ArithOperator;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::Add => Self::ProtoType::ArithAdd,
+            Self::Sub => Self::ProtoType::ArithSub,
+            Self::Div => Self::ProtoType::ArithDiv,
+            Self::Mul => Self::ProtoType::ArithMul,
+            Self::Mod => Self::ProtoType::ArithMod,
+            Self::BitAnd => Self::ProtoType::ArithBitAnd,
+            Self::BitOr => Self::ProtoType::ArithBitOr,
+            Self::BitXor => Self::ProtoType::ArithBitXor,
+            Self::ShiftLeft => Self::ProtoType::ArithShiftLeft,
+            Self::ShiftRight => Self::ProtoType::ArithShiftRight,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidArithOperator => return Err(RayexecError::new("invalid")),
+            Self::ProtoType::ArithAdd => Self::Add,
+            Self::ProtoType::ArithSub => Self::Sub,
+            Self::ProtoType::ArithDiv => Self::Div,
+            Self::ProtoType::ArithMul => Self::Mul,
+            Self::ProtoType::ArithMod => Self::Mod,
+            Self::ProtoType::ArithBitAnd => Self::BitAnd,
+            Self::ProtoType::ArithBitOr => Self::BitOr,
+            Self::ProtoType::ArithBitXor => Self::BitXor,
+            Self::ProtoType::ArithShiftLeft => Self::ShiftLeft,
+            Self::ProtoType::ArithShiftRight => Self::ShiftRight,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ArithExpr {
     pub left: Box<Expression>,
     pub right: Box<Expression>,
     pub op: ArithOperator,
+    /// Behavior when the result doesn't fit in the output type.
+    pub overflow: OverflowBehavior,
 }
 
 impl ArithExpr {
@@ -78,3 +133,33 @@ impl ContextDisplay for ArithExpr {
         )
     }
 }
+
+impl DatabaseProtoConv for ArithExpr {
+    type ProtoType = rayexec_proto::generated::logical::ArithExpr;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            left: Some(Box::new(self.left.to_proto_ctx(context)?)),
+            right: Some(Box::new(self.right.to_proto_ctx(context)?)),
+            op: self.op.to_proto()? as i32,
+            overflow: self.overflow.to_proto()? as i32,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        let op = ArithOperator::from_proto(proto.op())?;
+        let overflow = OverflowBehavior::from_proto(proto.overflow())?;
+        Ok(Self {
+            left: Box::new(DatabaseProtoConv::from_proto_ctx(
+                *proto.left.required("left")?,
+                context,
+            )?),
+            right: Box::new(DatabaseProtoConv::from_proto_ctx(
+                *proto.right.required("right")?,
+                context,
+            )?),
+            op,
+            overflow,
+        })
+    }
+}
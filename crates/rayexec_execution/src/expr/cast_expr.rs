@@ -1,15 +1,54 @@
 use std::fmt;
 
+use rayexec_error::{OptionExt, Result};
+use rayexec_proto::ProtoConv;
+
 use super::Expression;
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::datatype::DataType;
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
+use crate::logical::binder::bind_context::BindContext;
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CastExpr {
     pub to: DataType,
+    /// Rounding mode to use if the cast loses precision, e.g. when reducing
+    /// the scale of a decimal.
+    pub rounding: RoundingMode,
+    /// Behavior when a numeric value doesn't fit in the target type.
+    pub overflow: OverflowBehavior,
     pub expr: Box<Expression>,
 }
 
+impl CastExpr {
+    /// Create a new cast using the default rounding and overflow behavior.
+    ///
+    /// Only suitable for casts that can't lose precision or overflow, e.g.
+    /// widening an integer. Use `new_using_session` otherwise.
+    pub fn new(expr: Expression, to: DataType) -> Self {
+        CastExpr {
+            to,
+            rounding: RoundingMode::default(),
+            overflow: OverflowBehavior::default(),
+            expr: Box::new(expr),
+        }
+    }
+
+    /// Create a new cast using the rounding and overflow behavior configured
+    /// for the session.
+    pub fn new_using_session(bind_context: &BindContext, expr: Expression, to: DataType) -> Self {
+        CastExpr {
+            to,
+            rounding: bind_context.rounding_mode(),
+            overflow: bind_context.arithmetic_overflow(),
+            expr: Box::new(expr),
+        }
+    }
+}
+
 impl ContextDisplay for CastExpr {
     fn fmt_using_context(
         &self,
@@ -24,3 +63,28 @@ impl ContextDisplay for CastExpr {
         )
     }
 }
+
+impl DatabaseProtoConv for CastExpr {
+    type ProtoType = rayexec_proto::generated::logical::CastExpr;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            to: Some(self.to.to_proto()?),
+            rounding: self.rounding.to_proto()? as i32,
+            overflow: self.overflow.to_proto()? as i32,
+            expr: Some(Box::new(self.expr.to_proto_ctx(context)?)),
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            rounding: RoundingMode::from_proto(proto.rounding())?,
+            overflow: OverflowBehavior::from_proto(proto.overflow())?,
+            to: ProtoConv::from_proto(proto.to.required("to")?)?,
+            expr: Box::new(DatabaseProtoConv::from_proto_ctx(
+                *proto.expr.required("expr")?,
+                context,
+            )?),
+        })
+    }
+}
@@ -1,9 +1,11 @@
 use std::fmt;
 
-use rayexec_error::Result;
+use rayexec_error::{OptionExt, RayexecError, Result};
+use rayexec_proto::ProtoConv;
 
 use crate::arrays::datatype::DataType;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode};
+use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::table_list::{TableList, TableRef};
 
 /// Reference to a column in a query.
@@ -23,10 +25,57 @@ impl ColumnExpr {
         }
     }
 
-    pub fn datatype(&self, table_list: &TableList) -> Result<DataType> {
-        let (_, datatype) = table_list.get_column(self.table_scope, self.column)?;
+    /// Create a new column expression, checking that the column exists in the
+    /// referenced table.
+    ///
+    /// Prefer this over `new` when the column index comes from somewhere that
+    /// hasn't already been validated against the table.
+    pub fn try_new(
+        tables: &impl AsRef<TableList>,
+        table: impl Into<TableRef>,
+        column: usize,
+    ) -> Result<Self> {
+        let table_scope = table.into();
+        let table = tables.as_ref().get(table_scope)?;
+        if column >= table.num_columns() {
+            return Err(RayexecError::new(format!(
+                "Column index {column} out of range for table {table_scope} with {} columns",
+                table.num_columns()
+            )));
+        }
+
+        Ok(ColumnExpr {
+            table_scope,
+            column,
+        })
+    }
+
+    /// Get the datatype of the column.
+    ///
+    /// Accepts anything that can provide a table list, allowing the column to
+    /// be resolved using either a `TableList` or a `BindContext`.
+    pub fn datatype(&self, tables: &impl AsRef<TableList>) -> Result<DataType> {
+        let (_, datatype) = tables.as_ref().get_column(self.table_scope, self.column)?;
         Ok(datatype.clone())
     }
+
+    /// Get a human readable name for this column using the bind context.
+    ///
+    /// The name is qualified with the table alias if the table has one. Falls
+    /// back to the raw `table.column` index form if the column can't be found
+    /// in the context.
+    pub fn display_name(&self, bind_context: &BindContext) -> String {
+        let table = match bind_context.get_table(self.table_scope) {
+            Ok(table) if table.num_columns() > self.column => table,
+            _ => return self.to_string(),
+        };
+
+        let name = &table.column_names[self.column];
+        match &table.alias {
+            Some(alias) => format!("{}.{name}", alias.table),
+            None => name.clone(),
+        }
+    }
 }
 
 impl fmt::Display for ColumnExpr {
@@ -42,13 +91,151 @@ impl ContextDisplay for ColumnExpr {
         f: &mut fmt::Formatter<'_>,
     ) -> fmt::Result {
         match mode {
-            ContextDisplayMode::Enriched(context) => match context.get_table(self.table_scope) {
-                Ok(table) if table.num_columns() > self.column => {
-                    write!(f, "{}", &table.column_names[self.column])
-                }
-                _ => write!(f, "<missing! {self}>"),
-            },
+            ContextDisplayMode::Enriched(context) => write!(f, "{}", self.display_name(context)),
             ContextDisplayMode::Raw => write!(f, "{self}"),
         }
     }
 }
+
+impl ProtoConv for ColumnExpr {
+    type ProtoType = rayexec_proto::generated::logical::ColumnExpr;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            table_ref: Some(self.table_scope.to_proto()?),
+            column: self.column as u32,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(Self {
+            table_scope: ProtoConv::from_proto(proto.table_ref.required("table_ref")?)?,
+            column: proto.column as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logical::binder::table_list::TableAlias;
+
+    #[test]
+    fn datatype_from_bind_context_and_table_list() {
+        let mut bind_context = BindContext::new();
+        let table_ref = bind_context
+            .push_table(
+                bind_context.root_scope_ref(),
+                None,
+                vec![DataType::Int32, DataType::Utf8],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let col = ColumnExpr::new(table_ref, 1);
+
+        assert_eq!(DataType::Utf8, col.datatype(&bind_context).unwrap());
+        assert_eq!(
+            DataType::Utf8,
+            col.datatype(bind_context.get_table_list()).unwrap()
+        );
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int32, DataType::Utf8],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let col = ColumnExpr::new(table_ref, 1);
+        assert_eq!(DataType::Utf8, col.datatype(&table_list).unwrap());
+    }
+
+    #[test]
+    fn datatype_missing_column() {
+        let mut bind_context = BindContext::new();
+        let table_ref = bind_context
+            .push_table(
+                bind_context.root_scope_ref(),
+                None,
+                vec![DataType::Int32],
+                vec!["a".to_string()],
+            )
+            .unwrap();
+
+        let col = ColumnExpr::new(table_ref, 1);
+        col.datatype(&bind_context).unwrap_err();
+        col.datatype(bind_context.get_table_list()).unwrap_err();
+    }
+
+    #[test]
+    fn try_new_valid_column() {
+        let mut bind_context = BindContext::new();
+        let table_ref = bind_context
+            .push_table(
+                bind_context.root_scope_ref(),
+                None,
+                vec![DataType::Int32, DataType::Utf8],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let col = ColumnExpr::try_new(&bind_context, table_ref, 1).unwrap();
+        assert_eq!(ColumnExpr::new(table_ref, 1), col);
+    }
+
+    #[test]
+    fn try_new_out_of_range() {
+        let mut bind_context = BindContext::new();
+        let table_ref = bind_context
+            .push_table(
+                bind_context.root_scope_ref(),
+                None,
+                vec![DataType::Int32],
+                vec!["a".to_string()],
+            )
+            .unwrap();
+
+        ColumnExpr::try_new(&bind_context, table_ref, 1).unwrap_err();
+        // Missing table.
+        ColumnExpr::try_new(&bind_context, TableRef::from(table_ref.table_idx + 1), 0)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn display_name_resolves_bound_name() {
+        let mut bind_context = BindContext::new();
+        let unaliased = bind_context
+            .push_table(
+                bind_context.root_scope_ref(),
+                None,
+                vec![DataType::Int32, DataType::Utf8],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+        let aliased = bind_context
+            .push_table(
+                bind_context.root_scope_ref(),
+                Some(TableAlias {
+                    database: None,
+                    schema: None,
+                    table: "t1".to_string(),
+                }),
+                vec![DataType::Int32],
+                vec!["c".to_string()],
+            )
+            .unwrap();
+
+        assert_eq!("b", ColumnExpr::new(unaliased, 1).display_name(&bind_context));
+        assert_eq!(
+            "t1.c",
+            ColumnExpr::new(aliased, 0).display_name(&bind_context)
+        );
+
+        // Falls back to the raw form.
+        let missing = ColumnExpr::new(aliased, 4);
+        assert_eq!(missing.to_string(), missing.display_name(&bind_context));
+    }
+}
@@ -1,9 +1,14 @@
 use std::fmt;
 
+use rayexec_error::{OptionExt, RayexecError, Result};
+use rayexec_proto::ProtoConv;
+
 use super::{AsScalarFunction, Expression};
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
 use crate::functions::scalar::builtin::comparison;
 use crate::functions::scalar::ScalarFunction;
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ComparisonOperator {
@@ -65,6 +70,33 @@ impl fmt::Display for ComparisonOperator {
     }
 }
 
+impl ProtoConv for ComparisonOperator {
+    type ProtoType = rayexec_proto::generated::logical::ComparisonOperator;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::Eq => Self::ProtoType::ComparisonEq,
+            Self::NotEq => Self::ProtoType::ComparisonNotEq,
+            Self::Lt => Self::ProtoType::ComparisonLt,
+            Self::LtEq => Self::ProtoType::ComparisonLtEq,
+            Self::Gt => Self::ProtoType::ComparisonGt,
+            Self::GtEq => Self::ProtoType::ComparisonGtEq,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidComparisonOperator => return Err(RayexecError::new("invalid")),
+            Self::ProtoType::ComparisonEq => Self::Eq,
+            Self::ProtoType::ComparisonNotEq => Self::NotEq,
+            Self::ProtoType::ComparisonLt => Self::Lt,
+            Self::ProtoType::ComparisonLtEq => Self::LtEq,
+            Self::ProtoType::ComparisonGt => Self::Gt,
+            Self::ProtoType::ComparisonGtEq => Self::GtEq,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ComparisonExpr {
     pub left: Box<Expression>,
@@ -87,3 +119,30 @@ impl ContextDisplay for ComparisonExpr {
         )
     }
 }
+
+impl DatabaseProtoConv for ComparisonExpr {
+    type ProtoType = rayexec_proto::generated::logical::ComparisonExpr;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            left: Some(Box::new(self.left.to_proto_ctx(context)?)),
+            right: Some(Box::new(self.right.to_proto_ctx(context)?)),
+            op: self.op.to_proto()? as i32,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        let op = ComparisonOperator::from_proto(proto.op())?;
+        Ok(Self {
+            left: Box::new(DatabaseProtoConv::from_proto_ctx(
+                *proto.left.required("left")?,
+                context,
+            )?),
+            right: Box::new(DatabaseProtoConv::from_proto_ctx(
+                *proto.right.required("right")?,
+                context,
+            )?),
+            op,
+        })
+    }
+}
@@ -1,9 +1,14 @@
 use std::fmt;
 
+use rayexec_error::{RayexecError, Result};
+use rayexec_proto::ProtoConv;
+
 use super::{AsScalarFunction, Expression};
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
 use crate::functions::scalar::builtin::boolean;
 use crate::functions::scalar::ScalarFunction;
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ConjunctionOperator {
@@ -29,6 +34,27 @@ impl fmt::Display for ConjunctionOperator {
     }
 }
 
+impl ProtoConv for ConjunctionOperator {
+    type ProtoType = rayexec_proto::generated::logical::ConjunctionOperator;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::And => Self::ProtoType::ConjunctionAnd,
+            Self::Or => Self::ProtoType::ConjunctionOr,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidConjunctionOperator => {
+                return Err(RayexecError::new("invalid"))
+            }
+            Self::ProtoType::ConjunctionAnd => Self::And,
+            Self::ProtoType::ConjunctionOr => Self::Or,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ConjunctionExpr {
     pub op: ConjunctionOperator,
@@ -62,3 +88,29 @@ impl ContextDisplay for ConjunctionExpr {
         Ok(())
     }
 }
+
+impl DatabaseProtoConv for ConjunctionExpr {
+    type ProtoType = rayexec_proto::generated::logical::ConjunctionExpr;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            op: self.op.to_proto()? as i32,
+            expressions: self
+                .expressions
+                .iter()
+                .map(|expr| expr.to_proto_ctx(context))
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            op: ConjunctionOperator::from_proto(proto.op())?,
+            expressions: proto
+                .expressions
+                .into_iter()
+                .map(|expr| DatabaseProtoConv::from_proto_ctx(expr, context))
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
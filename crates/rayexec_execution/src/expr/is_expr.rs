@@ -1,7 +1,12 @@
 use std::fmt;
 
+use rayexec_error::{OptionExt, RayexecError, Result};
+use rayexec_proto::ProtoConv;
+
 use super::Expression;
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IsOperator {
@@ -22,6 +27,29 @@ impl fmt::Display for IsOperator {
     }
 }
 
+impl ProtoConv for IsOperator {
+    type ProtoType = rayexec_proto::generated::logical::IsOperator;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::IsTrue => Self::ProtoType::IsTrue,
+            Self::IsFalse => Self::ProtoType::IsFalse,
+            Self::IsNull => Self::ProtoType::IsNull,
+            Self::IsNotNull => Self::ProtoType::IsNotNull,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidIsOperator => return Err(RayexecError::new("invalid")),
+            Self::ProtoType::IsTrue => Self::IsTrue,
+            Self::ProtoType::IsFalse => Self::IsFalse,
+            Self::ProtoType::IsNull => Self::IsNull,
+            Self::ProtoType::IsNotNull => Self::IsNotNull,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IsExpr {
     pub op: IsOperator,
@@ -42,3 +70,24 @@ impl ContextDisplay for IsExpr {
         )
     }
 }
+
+impl DatabaseProtoConv for IsExpr {
+    type ProtoType = rayexec_proto::generated::logical::IsExpr;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            op: self.op.to_proto()? as i32,
+            input: Some(Box::new(self.input.to_proto_ctx(context)?)),
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            op: IsOperator::from_proto(proto.op())?,
+            input: Box::new(DatabaseProtoConv::from_proto_ctx(
+                *proto.input.required("input")?,
+                context,
+            )?),
+        })
+    }
+}
@@ -1,5 +1,8 @@
 use std::fmt;
 
+use rayexec_error::{OptionExt, Result};
+use rayexec_proto::ProtoConv;
+
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode};
 
@@ -29,3 +32,19 @@ impl ContextDisplay for LiteralExpr {
         }
     }
 }
+
+impl ProtoConv for LiteralExpr {
+    type ProtoType = rayexec_proto::generated::logical::LiteralExpr;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            literal: Some(self.literal.to_proto()?),
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(Self {
+            literal: ProtoConv::from_proto(proto.literal.required("literal")?)?,
+        })
+    }
+}
@@ -17,7 +17,7 @@ pub mod window_expr;
 
 pub mod physical;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Debug};
 
 use aggregate_expr::AggregateExpr;
@@ -32,17 +32,21 @@ use grouping_set_expr::GroupingSetExpr;
 use is_expr::IsExpr;
 use literal_expr::LiteralExpr;
 use negate_expr::NegateExpr;
-use rayexec_error::{RayexecError, Result};
+use rayexec_error::{not_implemented, OptionExt, RayexecError, Result};
+use rayexec_proto::ProtoConv;
 use scalar_function_expr::ScalarFunctionExpr;
-use subquery_expr::SubqueryExpr;
+use subquery_expr::{SubqueryExpr, SubqueryType};
 use unnest_expr::UnnestExpr;
 use window_expr::WindowExpr;
 
 use crate::arrays::datatype::DataType;
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode};
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
 use crate::functions::scalar::{FunctionVolatility, ScalarFunction};
 use crate::logical::binder::table_list::{TableList, TableRef};
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Expression {
@@ -90,6 +94,57 @@ impl Expression {
         })
     }
 
+    /// Check if the expression may produce NULL values.
+    ///
+    /// This is conservative, an expression is assumed to be nullable unless we
+    /// can prove otherwise. Column nullability is read from the table list.
+    pub fn nullable(&self, table_list: &TableList) -> Result<bool> {
+        Ok(match self {
+            Self::Aggregate(expr) => expr.agg.function.output_nullable(),
+            Self::Arith(expr) => {
+                // Overflowing produces NULL instead of erroring.
+                expr.overflow == OverflowBehavior::Null
+                    || any_nullable(table_list, [&*expr.left, &*expr.right])?
+            }
+            Self::Between(expr) => {
+                any_nullable(table_list, [&*expr.input, &*expr.lower, &*expr.upper])?
+            }
+            Self::Case(expr) => match &expr.else_expr {
+                Some(else_expr) => {
+                    else_expr.nullable(table_list)?
+                        || any_nullable(table_list, expr.cases.iter().map(|c| &c.then))?
+                }
+                // Falls through to NULL if no cases match.
+                None => true,
+            },
+            Self::Cast(expr) => {
+                // Narrowing casts produce NULL instead of erroring.
+                expr.overflow == OverflowBehavior::Null || expr.expr.nullable(table_list)?
+            }
+            Self::Column(expr) => table_list.column_nullable(expr.table_scope, expr.column)?,
+            Self::Comparison(expr) => any_nullable(table_list, [&*expr.left, &*expr.right])?,
+            Self::Conjunction(expr) => any_nullable(table_list, &expr.expressions)?,
+            Self::Is(_) => false,
+            Self::Literal(expr) => matches!(expr.literal, ScalarValue::Null),
+            Self::Negate(expr) => {
+                expr.overflow == OverflowBehavior::Null || expr.expr.nullable(table_list)?
+            }
+            Self::ScalarFunction(expr) => {
+                let inputs = expr
+                    .function
+                    .inputs
+                    .iter()
+                    .map(|input| input.nullable(table_list))
+                    .collect::<Result<Vec<_>>>()?;
+                expr.function.output_nullable(&inputs)
+            }
+            Self::Subquery(expr) => !matches!(expr.subquery_type, SubqueryType::Exists { .. }),
+            Self::Window(window) => window.agg.function.output_nullable(),
+            Self::Unnest(_) => true,
+            Self::GroupingSet(_) => false,
+        })
+    }
+
     pub fn for_each_child_mut<F>(&mut self, func: &mut F) -> Result<()>
     where
         F: FnMut(&mut Expression) -> Result<()>,
@@ -393,6 +448,24 @@ impl Expression {
         self
     }
 
+    /// Replace all column references found in `mapping` with their associated
+    /// expression.
+    pub fn replace_column_references(&mut self, mapping: &HashMap<ColumnExpr, Expression>) {
+        match self {
+            Expression::Column(col) => {
+                if let Some(replace) = mapping.get(col) {
+                    *self = replace.clone()
+                }
+            }
+            other => other
+                .for_each_child_mut(&mut |child| {
+                    child.replace_column_references(mapping);
+                    Ok(())
+                })
+                .expect("replace to not fail"),
+        }
+    }
+
     /// Get all column references in the expression.
     pub fn get_column_references(&self) -> Vec<ColumnExpr> {
         fn inner(expr: &Expression, cols: &mut Vec<ColumnExpr>) {
@@ -446,6 +519,59 @@ impl Expression {
             other => Err(RayexecError::new(format!("Not a literal: {other}"))),
         }
     }
+
+    /// Create an AND conjunction from the given expressions.
+    ///
+    /// Nested AND conjunctions are flattened into a single conjunction. A
+    /// single expression is returned as-is, and no expressions produces a
+    /// literal `true`.
+    pub fn and(exprs: impl IntoIterator<Item = Expression>) -> Expression {
+        Self::conjunction(ConjunctionOperator::And, exprs)
+    }
+
+    /// Create an OR conjunction from the given expressions.
+    ///
+    /// Nested OR conjunctions are flattened into a single conjunction. A single
+    /// expression is returned as-is, and no expressions produces a literal
+    /// `false`.
+    pub fn or(exprs: impl IntoIterator<Item = Expression>) -> Expression {
+        Self::conjunction(ConjunctionOperator::Or, exprs)
+    }
+
+    fn conjunction(
+        op: ConjunctionOperator,
+        exprs: impl IntoIterator<Item = Expression>,
+    ) -> Expression {
+        let mut expressions = Vec::new();
+        for expr in exprs {
+            match expr {
+                Expression::Conjunction(conj) if conj.op == op => {
+                    expressions.extend(conj.expressions)
+                }
+                other => expressions.push(other),
+            }
+        }
+
+        match expressions.len() {
+            // TRUE is the identity for AND, FALSE is the identity for OR.
+            0 => lit(op == ConjunctionOperator::And),
+            1 => expressions.pop().unwrap(),
+            _ => Expression::Conjunction(ConjunctionExpr { op, expressions }),
+        }
+    }
+}
+
+/// Check if any of the expressions may produce NULL values.
+fn any_nullable<'a>(
+    table_list: &TableList,
+    exprs: impl IntoIterator<Item = &'a Expression>,
+) -> Result<bool> {
+    for expr in exprs {
+        if expr.nullable(table_list)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
 }
 
 pub fn add(left: Expression, right: Expression) -> Expression {
@@ -453,6 +579,16 @@ pub fn add(left: Expression, right: Expression) -> Expression {
         left: Box::new(left),
         right: Box::new(right),
         op: ArithOperator::Add,
+        overflow: OverflowBehavior::default(),
+    })
+}
+
+pub fn mul(left: Expression, right: Expression) -> Expression {
+    Expression::Arith(ArithExpr {
+        left: Box::new(left),
+        right: Box::new(right),
+        op: ArithOperator::Mul,
+        overflow: OverflowBehavior::default(),
     })
 }
 
@@ -544,10 +680,7 @@ pub fn lit(scalar: impl Into<OwnedScalarValue>) -> Expression {
 }
 
 pub fn cast(expr: Expression, to: DataType) -> Expression {
-    Expression::Cast(CastExpr {
-        to,
-        expr: Box::new(expr),
-    })
+    Expression::Cast(CastExpr::new(expr, to))
 }
 
 impl fmt::Display for Expression {
@@ -583,6 +716,47 @@ impl ContextDisplay for Expression {
     }
 }
 
+impl DatabaseProtoConv for Expression {
+    type ProtoType = rayexec_proto::generated::logical::Expression;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        use rayexec_proto::generated::logical::expression::Value;
+
+        let value = match self {
+            Self::Arith(expr) => Value::Arith(Box::new(expr.to_proto_ctx(context)?)),
+            Self::Cast(expr) => Value::Cast(Box::new(expr.to_proto_ctx(context)?)),
+            Self::Column(expr) => Value::Column(expr.to_proto()?),
+            Self::Comparison(expr) => Value::Comparison(Box::new(expr.to_proto_ctx(context)?)),
+            Self::Conjunction(expr) => Value::Conjunction(expr.to_proto_ctx(context)?),
+            Self::Is(expr) => Value::Is(Box::new(expr.to_proto_ctx(context)?)),
+            Self::Literal(expr) => Value::Literal(expr.to_proto()?),
+            Self::Negate(expr) => Value::Negate(Box::new(expr.to_proto_ctx(context)?)),
+            other => not_implemented!("proto encode expression: {other}"),
+        };
+
+        Ok(Self::ProtoType { value: Some(value) })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        use rayexec_proto::generated::logical::expression::Value;
+
+        Ok(match proto.value.required("value")? {
+            Value::Arith(expr) => Self::Arith(DatabaseProtoConv::from_proto_ctx(*expr, context)?),
+            Value::Cast(expr) => Self::Cast(DatabaseProtoConv::from_proto_ctx(*expr, context)?),
+            Value::Column(expr) => Self::Column(ProtoConv::from_proto(expr)?),
+            Value::Comparison(expr) => {
+                Self::Comparison(DatabaseProtoConv::from_proto_ctx(*expr, context)?)
+            }
+            Value::Conjunction(expr) => {
+                Self::Conjunction(DatabaseProtoConv::from_proto_ctx(expr, context)?)
+            }
+            Value::Is(expr) => Self::Is(DatabaseProtoConv::from_proto_ctx(*expr, context)?),
+            Value::Literal(expr) => Self::Literal(ProtoConv::from_proto(expr)?),
+            Value::Negate(expr) => Self::Negate(DatabaseProtoConv::from_proto_ctx(*expr, context)?),
+        })
+    }
+}
+
 pub trait AsScalarFunction {
     /// Returns the scalar function that implements the expression.
     fn as_scalar_function(&self) -> &dyn ScalarFunction;
@@ -630,6 +804,64 @@ mod tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn and_empty() {
+        assert_eq!(lit(true), Expression::and([]));
+    }
+
+    #[test]
+    fn or_empty() {
+        assert_eq!(lit(false), Expression::or([]));
+    }
+
+    #[test]
+    fn and_or_single() {
+        assert_eq!(col_ref(0, 1), Expression::and([col_ref(0, 1)]));
+        assert_eq!(col_ref(0, 1), Expression::or([col_ref(0, 1)]));
+    }
+
+    #[test]
+    fn and_multiple() {
+        let expr = Expression::and([col_ref(0, 0), col_ref(0, 1), col_ref(0, 2)]);
+        let expected = Expression::Conjunction(ConjunctionExpr {
+            op: ConjunctionOperator::And,
+            expressions: vec![col_ref(0, 0), col_ref(0, 1), col_ref(0, 2)],
+        });
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn and_flattens_nested_and() {
+        let expr = Expression::and([
+            col_ref(0, 0),
+            Expression::and([col_ref(0, 1), col_ref(0, 2)]),
+        ]);
+        let expected = Expression::Conjunction(ConjunctionExpr {
+            op: ConjunctionOperator::And,
+            expressions: vec![col_ref(0, 0), col_ref(0, 1), col_ref(0, 2)],
+        });
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn and_keeps_nested_or() {
+        let expr = Expression::and([
+            col_ref(0, 0),
+            Expression::or([col_ref(0, 1), col_ref(0, 2)]),
+        ]);
+        let expected = Expression::Conjunction(ConjunctionExpr {
+            op: ConjunctionOperator::And,
+            expressions: vec![
+                col_ref(0, 0),
+                Expression::Conjunction(ConjunctionExpr {
+                    op: ConjunctionOperator::Or,
+                    expressions: vec![col_ref(0, 1), col_ref(0, 2)],
+                }),
+            ],
+        });
+        assert_eq!(expected, expr);
+    }
+
     #[test]
     fn is_const_foldable() {
         let expr = and([
@@ -1,13 +1,17 @@
 use std::fmt;
 
-use rayexec_error::Result;
+use rayexec_error::{OptionExt, RayexecError, Result};
+use rayexec_proto::ProtoConv;
 
 use super::{AsScalarFunction, Expression};
 use crate::arrays::datatype::DataType;
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
 use crate::functions::scalar::builtin::negate;
 use crate::functions::scalar::ScalarFunction;
 use crate::logical::binder::table_list::TableList;
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum NegateOperator {
@@ -24,10 +28,31 @@ impl AsScalarFunction for NegateOperator {
     }
 }
 
+impl ProtoConv for NegateOperator {
+    type ProtoType = rayexec_proto::generated::logical::NegateOperator;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::Not => Self::ProtoType::NegateNot,
+            Self::Negate => Self::ProtoType::NegateNegate,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidNegateOperator => return Err(RayexecError::new("invalid")),
+            Self::ProtoType::NegateNot => Self::Not,
+            Self::ProtoType::NegateNegate => Self::Negate,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NegateExpr {
     pub op: NegateOperator,
     pub expr: Box<Expression>,
+    /// Behavior when negating a numeric overflows (e.g. `-i8::MIN`).
+    pub overflow: OverflowBehavior,
 }
 
 impl NegateExpr {
@@ -65,3 +90,26 @@ impl ContextDisplay for NegateExpr {
         }
     }
 }
+
+impl DatabaseProtoConv for NegateExpr {
+    type ProtoType = rayexec_proto::generated::logical::NegateExpr;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            op: self.op.to_proto()? as i32,
+            expr: Some(Box::new(self.expr.to_proto_ctx(context)?)),
+            overflow: self.overflow.to_proto()? as i32,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            op: NegateOperator::from_proto(proto.op())?,
+            overflow: OverflowBehavior::from_proto(proto.overflow())?,
+            expr: Box::new(DatabaseProtoConv::from_proto_ctx(
+                *proto.expr.required("expr")?,
+                context,
+            )?),
+        })
+    }
+}
@@ -7,22 +7,70 @@ use rayexec_proto::ProtoConv;
 use super::PhysicalScalarExpression;
 use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
-use crate::arrays::compute::cast::array::cast_array;
+use crate::arrays::compute::cast::array::{
+    cast_array_with_rounding,
+    cast_to_integer_with_overflow,
+    IntegerCastOverflow,
+};
 use crate::arrays::compute::cast::behavior::CastFailBehavior;
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::datatype::DataType;
 use crate::database::DatabaseContext;
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
 use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone)]
 pub struct PhysicalCastExpr {
     pub to: DataType,
+    pub rounding: RoundingMode,
+    pub overflow: OverflowBehavior,
     pub expr: Box<PhysicalScalarExpression>,
 }
 
 impl PhysicalCastExpr {
     pub fn eval<'a>(&self, batch: &'a Batch) -> Result<Cow<'a, Array>> {
         let input = self.expr.eval(batch)?;
-        let out = cast_array(input.as_ref(), self.to.clone(), CastFailBehavior::Error)?;
+        let input = input.as_ref();
+
+        // Overflow behavior only applies to numeric to numeric casts, anything
+        // else (e.g. parsing a string) always errors on failure.
+        let is_numeric =
+            |datatype: &DataType| datatype.is_primitive_numeric() || datatype.is_decimal();
+        if !is_numeric(input.datatype()) || !is_numeric(&self.to) {
+            let out = cast_array_with_rounding(
+                input,
+                self.to.clone(),
+                CastFailBehavior::Error,
+                self.rounding,
+            )?;
+            return Ok(Cow::Owned(out));
+        }
+
+        let integer_overflow = match self.overflow {
+            OverflowBehavior::Wrap => Some(IntegerCastOverflow::Wrap),
+            OverflowBehavior::Saturate => Some(IntegerCastOverflow::Saturate),
+            OverflowBehavior::Error | OverflowBehavior::Null => None,
+        };
+
+        let out = match integer_overflow {
+            Some(overflow) if input.datatype().is_primitive_numeric() && self.to.is_integer() => {
+                cast_to_integer_with_overflow(
+                    input,
+                    self.to.clone(),
+                    overflow,
+                    CastFailBehavior::Error,
+                    self.rounding,
+                )?
+            }
+            _ => {
+                let behavior = match self.overflow {
+                    OverflowBehavior::Null => CastFailBehavior::Null,
+                    _ => CastFailBehavior::Error,
+                };
+                cast_array_with_rounding(input, self.to.clone(), behavior, self.rounding)?
+            }
+        };
+
         Ok(Cow::Owned(out))
     }
 }
@@ -39,12 +87,16 @@ impl DatabaseProtoConv for PhysicalCastExpr {
     fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
         Ok(Self::ProtoType {
             cast_to: Some(self.to.to_proto()?),
+            rounding: self.rounding.to_proto()? as i32,
+            overflow: self.overflow.to_proto()? as i32,
             expr: Some(Box::new(self.expr.to_proto_ctx(context)?)),
         })
     }
 
     fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
         Ok(Self {
+            rounding: RoundingMode::from_proto(proto.rounding())?,
+            overflow: OverflowBehavior::from_proto(proto.overflow())?,
             to: ProtoConv::from_proto(proto.cast_to.required("to")?)?,
             expr: Box::new(DatabaseProtoConv::from_proto_ctx(
                 *proto.expr.required("expr")?,
@@ -44,6 +44,54 @@ impl PhysicalScalarExpression {
         }
     }
 
+    /// Replace functions holding execution state with copies local to
+    /// `partition`.
+    ///
+    /// Returns true if any function in the expression was replaced.
+    pub fn make_partition_local(&mut self, partition: usize) -> bool {
+        match self {
+            Self::Case(e) => {
+                let mut replaced = false;
+                for case in &mut e.cases {
+                    replaced |= case.when.make_partition_local(partition);
+                    replaced |= case.then.make_partition_local(partition);
+                }
+                replaced |= e.else_expr.make_partition_local(partition);
+                replaced
+            }
+            Self::Cast(e) => e.expr.make_partition_local(partition),
+            Self::Column(_) | Self::Literal(_) => false,
+            Self::ScalarFunction(e) => {
+                let mut replaced = false;
+                for input in &mut e.inputs {
+                    replaced |= input.make_partition_local(partition);
+                }
+                if let Some(function_impl) = e.function.function_impl.for_partition(partition) {
+                    e.function.function_impl = function_impl;
+                    replaced = true;
+                }
+                replaced
+            }
+        }
+    }
+
+    /// Create copies of `exprs` local to `partition`.
+    ///
+    /// Returns None if none of the expressions hold execution state, in which
+    /// case the original expressions can be shared across partitions.
+    pub fn partition_local_exprs(
+        exprs: &[PhysicalScalarExpression],
+        partition: usize,
+    ) -> Option<Vec<PhysicalScalarExpression>> {
+        let mut local = exprs.to_vec();
+        let mut replaced = false;
+        for expr in &mut local {
+            replaced |= expr.make_partition_local(partition);
+        }
+
+        replaced.then_some(local)
+    }
+
     /// Produce a selection vector for the batch using this expression.
     ///
     /// The selection vector will include row indices where the expression
@@ -113,7 +161,11 @@ pub struct PhysicalAggregateExpression {
     pub columns: Vec<PhysicalColumnExpr>,
     /// If inputs are distinct.
     pub is_distinct: bool,
-    // TODO: Filter
+    /// Optional column containing the result of the aggregate's FILTER
+    /// predicate.
+    ///
+    /// Only rows where this column is true will be aggregated.
+    pub filter: Option<PhysicalColumnExpr>,
 }
 
 impl PhysicalAggregateExpression {
@@ -7,11 +7,14 @@ use super::column_expr::PhysicalColumnExpr;
 use super::literal_expr::PhysicalLiteralExpr;
 use super::scalar_function_expr::PhysicalScalarFunctionExpr;
 use super::PhysicalSortExpression;
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::scalar::ScalarValue;
 use crate::execution::operators::hash_join::condition::HashJoinCondition;
 use crate::expr::physical::case_expr::PhysicalWhenThen;
 use crate::expr::physical::PhysicalScalarExpression;
 use crate::expr::{AsScalarFunction, Expression};
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
+use crate::functions::scalar::ScalarPlanOptions;
 use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
 use crate::logical::binder::table_list::{TableList, TableRef};
 use crate::logical::logical_join::ComparisonCondition;
@@ -100,6 +103,8 @@ impl<'a> PhysicalExpressionPlanner<'a> {
             }
             Expression::Cast(expr) => Ok(PhysicalScalarExpression::Cast(PhysicalCastExpr {
                 to: expr.to.clone(),
+                rounding: expr.rounding,
+                overflow: expr.overflow,
                 expr: Box::new(self.plan_scalar(table_refs, &expr.expr)?),
             })),
             Expression::Comparison(expr) => {
@@ -133,9 +138,13 @@ impl<'a> PhysicalExpressionPlanner<'a> {
             }
             Expression::Arith(expr) => {
                 let scalar = expr.op.as_scalar_function();
-                let function = scalar.plan(
+                let function = scalar.plan_with_options(
                     self.table_list,
                     vec![expr.left.as_ref().clone(), expr.right.as_ref().clone()],
+                    ScalarPlanOptions {
+                        overflow: expr.overflow,
+                        ..Default::default()
+                    },
                 )?;
 
                 let physical_inputs = self.plan_scalars(table_refs, &function.inputs)?;
@@ -149,7 +158,14 @@ impl<'a> PhysicalExpressionPlanner<'a> {
             }
             Expression::Negate(expr) => {
                 let scalar = expr.op.as_scalar_function();
-                let function = scalar.plan(self.table_list, vec![expr.expr.as_ref().clone()])?;
+                let function = scalar.plan_with_options(
+                    self.table_list,
+                    vec![expr.expr.as_ref().clone()],
+                    ScalarPlanOptions {
+                        overflow: expr.overflow,
+                        ..Default::default()
+                    },
+                )?;
 
                 let physical_inputs = self.plan_scalars(table_refs, &function.inputs)?;
 
@@ -177,6 +193,8 @@ impl<'a> PhysicalExpressionPlanner<'a> {
                     Some(else_expr) => self.plan_scalar(table_refs, else_expr)?,
                     None => PhysicalScalarExpression::Cast(PhysicalCastExpr {
                         to: datatype,
+                        rounding: RoundingMode::default(),
+                        overflow: OverflowBehavior::default(),
                         expr: Box::new(PhysicalScalarExpression::Literal(PhysicalLiteralExpr {
                             literal: ScalarValue::Null,
                         })),
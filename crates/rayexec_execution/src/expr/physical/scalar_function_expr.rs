@@ -7,6 +7,7 @@ use rayexec_error::Result;
 use super::PhysicalScalarExpression;
 use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
+use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
 use crate::functions::scalar::PlannedScalarFunction;
 use crate::proto::DatabaseProtoConv;
@@ -19,23 +20,16 @@ pub struct PhysicalScalarFunctionExpr {
 
 impl PhysicalScalarFunctionExpr {
     pub fn eval<'a>(&self, batch: &'a Batch) -> Result<Cow<'a, Array>> {
-        let inputs = self
-            .inputs
-            .iter()
-            .map(|input| input.eval(batch))
-            .collect::<Result<Vec<_>>>()?;
-
-        let refs: Vec<_> = inputs.iter().map(|a| a.as_ref()).collect(); // Can I not?
-        let mut out = self.function.function_impl.execute(&refs)?;
+        let mut out = self
+            .function
+            .function_impl
+            .execute_lazy(batch, &self.inputs)?;
 
         // If function is provided no input, it's expected to return an
         // array of length 1. We extend the array here so that it's the
         // same size as the rest.
-        //
-        // TODO: Could just extend the selection vector too.
-        if refs.is_empty() {
-            let scalar = out.logical_value(0)?;
-            out = scalar.as_array(batch.num_rows())?;
+        if self.inputs.is_empty() {
+            out.select_mut(SelectionVector::repeated(batch.num_rows(), 0));
         }
 
         Ok(Cow::Owned(out))
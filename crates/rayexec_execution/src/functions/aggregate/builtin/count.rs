@@ -68,6 +68,11 @@ impl AggregateFunction for Count {
             function_impl: Box::new(CountNonNullImpl),
         })
     }
+
+    fn output_nullable(&self) -> bool {
+        // Produces 0 on empty input.
+        false
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -136,6 +136,9 @@ impl AggregateFunction for First {
                 // TODO: Easy, clone underlying array and select.
                 not_implemented!("FIRST for list arrays")
             }
+            PhysicalType::Struct => {
+                not_implemented!("FIRST for struct arrays")
+            }
         };
 
         Ok(PlannedAggregateFunction {
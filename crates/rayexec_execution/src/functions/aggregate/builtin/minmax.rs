@@ -131,6 +131,9 @@ impl AggregateFunction for Min {
             PhysicalType::List => {
                 not_implemented!("MIN for list arrays")
             }
+            PhysicalType::Struct => {
+                not_implemented!("MIN for struct arrays")
+            }
         };
 
         Ok(PlannedAggregateFunction {
@@ -225,6 +228,9 @@ impl AggregateFunction for Max {
             PhysicalType::List => {
                 not_implemented!("MAX for list arrays")
             }
+            PhysicalType::Struct => {
+                not_implemented!("MAX for struct arrays")
+            }
         };
 
         Ok(PlannedAggregateFunction {
@@ -4,6 +4,7 @@ pub mod count;
 pub mod covar;
 pub mod first;
 pub mod minmax;
+pub mod percentile;
 pub mod regr_avg;
 pub mod regr_count;
 pub mod regr_r2;
@@ -38,5 +39,7 @@ pub static BUILTIN_AGGREGATE_FUNCTIONS: LazyLock<Vec<Box<dyn AggregateFunction>>
             Box::new(regr_r2::RegrR2),
             Box::new(regr_slope::RegrSlope),
             Box::new(string_agg::StringAgg),
+            Box::new(percentile::PercentileCont),
+            Box::new(percentile::PercentileDisc),
         ]
     });
@@ -0,0 +1,456 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use half::f16;
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::ArrayData;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::aggregate::AggregateState;
+use crate::arrays::executor::physical_type::{
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI128,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI8,
+    PhysicalStorage,
+    PhysicalType,
+    PhysicalU128,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU8,
+};
+use crate::arrays::scalar::ScalarValue;
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::aggregate_expr::AggregateOrdering;
+use crate::expr::cast_expr::CastExpr;
+use crate::expr::Expression;
+use crate::functions::aggregate::states::{
+    new_unary_aggregate_states,
+    primitive_finalize,
+    AggregateGroupStates,
+};
+use crate::functions::aggregate::{
+    AggregateFunction,
+    AggregateFunctionImpl,
+    PlannedAggregateFunction,
+};
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentileCont;
+
+impl FunctionInfo for PercentileCont {
+    fn name(&self) -> &'static str {
+        "percentile_cont"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Float64, DataTypeId::Float64],
+            variadic_arg: None,
+            return_type: DataTypeId::Float64,
+            doc: Some(&Documentation {
+                category: Category::Aggregate,
+                description: "Compute a continuous percentile of the values ordered by `WITHIN GROUP (ORDER BY value)`, interpolating between adjacent values if needed.",
+                arguments: &["value", "fraction"],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl AggregateFunction for PercentileCont {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        _inputs: Vec<Expression>,
+    ) -> Result<PlannedAggregateFunction> {
+        Err(missing_within_group_error(self))
+    }
+
+    fn plan_ordered_set(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        ordering: AggregateOrdering,
+    ) -> Result<PlannedAggregateFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        if !is_numeric(&datatypes[0]) || !is_numeric(&datatypes[1]) {
+            return Err(invalid_input_types_error(self, &datatypes));
+        }
+
+        let inputs: Vec<_> = inputs
+            .into_iter()
+            .zip(datatypes)
+            .map(|(input, datatype)| cast_to_float64(input, datatype))
+            .collect();
+
+        let fraction = plan_fraction(self, table_list, &inputs[1])?;
+
+        Ok(PlannedAggregateFunction {
+            function: Box::new(*self),
+            return_type: DataType::Float64,
+            inputs,
+            function_impl: Box::new(PercentileContImpl {
+                fraction,
+                desc: ordering.desc,
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PercentileContImpl {
+    fraction: f64,
+    desc: bool,
+}
+
+impl AggregateFunctionImpl for PercentileContImpl {
+    fn new_states(&self) -> Box<dyn AggregateGroupStates> {
+        let fraction = self.fraction;
+        let desc = self.desc;
+
+        new_unary_aggregate_states::<PhysicalF64, _, _, _, _>(
+            move || PercentileContState {
+                values: Vec::new(),
+                fraction,
+                desc,
+            },
+            move |states| primitive_finalize(DataType::Float64, states),
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PercentileContState {
+    /// All non-NULL values seen for the group.
+    values: Vec<f64>,
+    fraction: f64,
+    desc: bool,
+}
+
+impl AggregateState<f64, f64> for PercentileContState {
+    fn merge(&mut self, other: &mut Self) -> Result<()> {
+        self.values.append(&mut other.values);
+        Ok(())
+    }
+
+    fn update(&mut self, input: f64) -> Result<()> {
+        self.values.push(input);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Result<(f64, bool)> {
+        if self.values.is_empty() {
+            return Ok((0.0, false));
+        }
+
+        sort_values(&mut self.values, self.desc);
+
+        let pos = self.fraction * (self.values.len() - 1) as f64;
+        let lower = pos.floor();
+        let upper = pos.ceil();
+
+        let lower_val = self.values[lower as usize];
+        let upper_val = self.values[upper as usize];
+
+        Ok((lower_val + (pos - lower) * (upper_val - lower_val), true))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentileDisc;
+
+impl FunctionInfo for PercentileDisc {
+    fn name(&self) -> &'static str {
+        "percentile_disc"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Any, DataTypeId::Float64],
+            variadic_arg: None,
+            return_type: DataTypeId::Any,
+            doc: Some(&Documentation {
+                category: Category::Aggregate,
+                description: "Compute a discrete percentile of the values ordered by `WITHIN GROUP (ORDER BY value)`, returning the first value whose position in the ordering equals or exceeds the fraction.",
+                arguments: &["value", "fraction"],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl AggregateFunction for PercentileDisc {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        _inputs: Vec<Expression>,
+    ) -> Result<PlannedAggregateFunction> {
+        Err(missing_within_group_error(self))
+    }
+
+    fn plan_ordered_set(
+        &self,
+        table_list: &TableList,
+        mut inputs: Vec<Expression>,
+        ordering: AggregateOrdering,
+    ) -> Result<PlannedAggregateFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        if !is_numeric(&datatypes[1]) {
+            return Err(invalid_input_types_error(self, &datatypes));
+        }
+
+        let fraction_input = cast_to_float64(inputs.pop().unwrap(), datatypes[1].clone());
+        inputs.push(fraction_input);
+
+        let fraction = plan_fraction(self, table_list, &inputs[1])?;
+        let datatype = datatypes[0].clone();
+        let desc = ordering.desc;
+
+        let function_impl: Box<dyn AggregateFunctionImpl> = match datatype.physical_type()? {
+            PhysicalType::Float16 => Box::new(PercentileDiscImpl::<PhysicalF16, f16>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::Float32 => Box::new(PercentileDiscImpl::<PhysicalF32, f32>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::Float64 => Box::new(PercentileDiscImpl::<PhysicalF64, f64>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::Int8 => Box::new(PercentileDiscImpl::<PhysicalI8, i8>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::Int16 => Box::new(PercentileDiscImpl::<PhysicalI16, i16>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::Int32 => Box::new(PercentileDiscImpl::<PhysicalI32, i32>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::Int64 => Box::new(PercentileDiscImpl::<PhysicalI64, i64>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::Int128 => Box::new(PercentileDiscImpl::<PhysicalI128, i128>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::UInt8 => Box::new(PercentileDiscImpl::<PhysicalU8, u8>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::UInt16 => Box::new(PercentileDiscImpl::<PhysicalU16, u16>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::UInt32 => Box::new(PercentileDiscImpl::<PhysicalU32, u32>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::UInt64 => Box::new(PercentileDiscImpl::<PhysicalU64, u64>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            PhysicalType::UInt128 => Box::new(PercentileDiscImpl::<PhysicalU128, u128>::new(
+                datatype.clone(),
+                fraction,
+                desc,
+            )),
+            _ => return Err(invalid_input_types_error(self, &datatypes)),
+        };
+
+        Ok(PlannedAggregateFunction {
+            function: Box::new(*self),
+            return_type: datatype,
+            inputs,
+            function_impl,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct PercentileDiscImpl<S, T> {
+    datatype: DataType,
+    fraction: f64,
+    desc: bool,
+    _s: PhantomData<S>,
+    _t: PhantomData<T>,
+}
+
+impl<S, T> PercentileDiscImpl<S, T> {
+    fn new(datatype: DataType, fraction: f64, desc: bool) -> Self {
+        PercentileDiscImpl {
+            datatype,
+            fraction,
+            desc,
+            _s: PhantomData,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<S, T> AggregateFunctionImpl for PercentileDiscImpl<S, T>
+where
+    for<'a> S: PhysicalStorage<Type<'a> = T>,
+    T: PartialOrd + Debug + Default + Sync + Send + Copy + 'static,
+    ArrayData: From<PrimitiveStorage<T>>,
+{
+    fn new_states(&self) -> Box<dyn AggregateGroupStates> {
+        let datatype = self.datatype.clone();
+        let fraction = self.fraction;
+        let desc = self.desc;
+
+        new_unary_aggregate_states::<S, _, _, _, _>(
+            move || PercentileDiscState {
+                values: Vec::new(),
+                fraction,
+                desc,
+            },
+            move |states| primitive_finalize(datatype.clone(), states),
+        )
+    }
+}
+
+impl<S, T> Clone for PercentileDiscImpl<S, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.datatype.clone(), self.fraction, self.desc)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PercentileDiscState<T> {
+    /// All non-NULL values seen for the group.
+    values: Vec<T>,
+    fraction: f64,
+    desc: bool,
+}
+
+impl<T> AggregateState<T, T> for PercentileDiscState<T>
+where
+    T: PartialOrd + Debug + Default + Copy,
+{
+    fn merge(&mut self, other: &mut Self) -> Result<()> {
+        self.values.append(&mut other.values);
+        Ok(())
+    }
+
+    fn update(&mut self, input: T) -> Result<()> {
+        self.values.push(input);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Result<(T, bool)> {
+        if self.values.is_empty() {
+            return Ok((T::default(), false));
+        }
+
+        sort_values(&mut self.values, self.desc);
+
+        // First value whose position (1-based) is at least `fraction * n`.
+        let pos = (self.fraction * self.values.len() as f64).ceil() as usize;
+        let idx = pos.saturating_sub(1).min(self.values.len() - 1);
+
+        Ok((self.values[idx], true))
+    }
+}
+
+fn sort_values<T: PartialOrd>(values: &mut [T], desc: bool) {
+    if desc {
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+    } else {
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    }
+}
+
+/// Get the constant fraction argument for a percentile function, erroring if
+/// it's not between 0 and 1.
+fn plan_fraction(
+    func: &impl FunctionInfo,
+    table_list: &TableList,
+    input: &Expression,
+) -> Result<f64> {
+    if !input.is_const_foldable() {
+        return Err(RayexecError::new(format!(
+            "Fraction for {} must be constant",
+            func.name()
+        )));
+    }
+
+    let fraction = match ConstFold::rewrite(table_list, input.clone())?.try_into_scalar()? {
+        ScalarValue::Float64(v) => v,
+        other => {
+            return Err(RayexecError::new(format!(
+                "Unexpected fraction for {}: {other}",
+                func.name()
+            )))
+        }
+    };
+
+    if !(0.0..=1.0).contains(&fraction) {
+        return Err(RayexecError::new(format!(
+            "Fraction for {} must be between 0 and 1, got {fraction}",
+            func.name()
+        )));
+    }
+
+    Ok(fraction)
+}
+
+fn is_numeric(datatype: &DataType) -> bool {
+    datatype.is_numeric() || matches!(datatype, DataType::Float16 | DataType::Null)
+}
+
+fn cast_to_float64(input: Expression, datatype: DataType) -> Expression {
+    if datatype == DataType::Float64 {
+        input
+    } else {
+        Expression::Cast(CastExpr::new(input, DataType::Float64))
+    }
+}
+
+fn missing_within_group_error(func: &impl FunctionInfo) -> RayexecError {
+    RayexecError::new(format!(
+        "{} is an ordered-set aggregate and requires WITHIN GROUP (ORDER BY ...)",
+        func.name()
+    ))
+}
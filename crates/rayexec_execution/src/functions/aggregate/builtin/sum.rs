@@ -3,14 +3,12 @@ use std::marker::PhantomData;
 use std::ops::AddAssign;
 
 use num_traits::CheckedAdd;
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
-use crate::arrays::array::ArrayData;
-use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
 use crate::arrays::executor::aggregate::AggregateState;
 use crate::arrays::executor::physical_type::{PhysicalF64, PhysicalI64};
 use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
-use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
 use crate::functions::aggregate::states::{
     new_unary_aggregate_states,
@@ -58,7 +56,7 @@ impl FunctionInfo for Sum {
             Signature {
                 positional_args: &[DataTypeId::Decimal64],
                 variadic_arg: None,
-                return_type: DataTypeId::Decimal64,
+                return_type: DataTypeId::Decimal128,
                 doc: Some(DOC),
             },
             Signature {
@@ -84,14 +82,14 @@ impl AggregateFunction for Sum {
                 DataType::Int64 => (Box::new(SumInt64Impl), DataType::Int64),
                 DataType::Float64 => (Box::new(SumFloat64Impl), DataType::Float64),
                 DataType::Decimal64(m) => {
-                    let datatype = DataType::Decimal64(m);
+                    let datatype = SumDecimalImpl::<Decimal64Type>::return_type(m);
                     (
                         Box::new(SumDecimalImpl::<Decimal64Type>::new(datatype.clone())),
                         datatype,
                     )
                 }
                 DataType::Decimal128(m) => {
-                    let datatype = DataType::Decimal128(m);
+                    let datatype = SumDecimalImpl::<Decimal128Type>::return_type(m);
                     (
                         Box::new(SumDecimalImpl::<Decimal128Type>::new(datatype.clone())),
                         datatype,
@@ -133,6 +131,10 @@ impl AggregateFunctionImpl for SumFloat64Impl {
     }
 }
 
+/// Sum for decimals.
+///
+/// Values are always accumulated into an i128, with the output being a
+/// Decimal128 at max precision with the same scale as the input.
 #[derive(Debug, Clone)]
 pub struct SumDecimalImpl<D> {
     datatype: DataType,
@@ -146,23 +148,79 @@ impl<D> SumDecimalImpl<D> {
             _d: PhantomData,
         }
     }
+
+    /// Get the widened return type for an input decimal.
+    fn return_type(meta: DecimalTypeMeta) -> DataType {
+        DataType::Decimal128(DecimalTypeMeta::new(
+            Decimal128Type::MAX_PRECISION,
+            meta.scale,
+        ))
+    }
 }
 
 impl<D> AggregateFunctionImpl for SumDecimalImpl<D>
 where
     D: DecimalType,
-    ArrayData: From<PrimitiveStorage<D::Primitive>>,
+    D::Primitive: Into<i128>,
 {
     fn new_states(&self) -> Box<dyn AggregateGroupStates> {
         let datatype = self.datatype.clone();
 
         new_unary_aggregate_states::<D::Storage, _, _, _, _>(
-            SumStateCheckedAdd::<D::Primitive>::default,
-            move |states| primitive_finalize(datatype.clone(), states),
+            SumDecimalState::default,
+            move |states| primitive_finalize::<_, D::Primitive, _>(datatype.clone(), states),
         )
     }
 }
 
+/// State for summing decimals, erroring if the sum can't fit in a Decimal128
+/// at max precision.
+#[derive(Debug, Default)]
+pub struct SumDecimalState {
+    sum: i128,
+    set: bool,
+}
+
+impl SumDecimalState {
+    fn add(&mut self, value: i128) -> Result<()> {
+        // Largest (exclusive) absolute value that fits in the max precision.
+        const LIMIT: u128 = 10_u128.pow(Decimal128Type::MAX_PRECISION as u32);
+
+        match self.sum.checked_add(value) {
+            Some(sum) if sum.unsigned_abs() < LIMIT => {
+                self.sum = sum;
+                Ok(())
+            }
+            _ => Err(RayexecError::new(format!(
+                "Overflow computing sum for decimal with precision {}",
+                Decimal128Type::MAX_PRECISION
+            ))),
+        }
+    }
+}
+
+impl<I: Into<i128>> AggregateState<I, i128> for SumDecimalState {
+    fn merge(&mut self, other: &mut Self) -> Result<()> {
+        self.add(other.sum)?;
+        self.set = self.set || other.set;
+        Ok(())
+    }
+
+    fn update(&mut self, input: I) -> Result<()> {
+        self.add(input.into())?;
+        self.set = true;
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> Result<(i128, bool)> {
+        if self.set {
+            Ok((self.sum, true))
+        } else {
+            Ok((0, false))
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct SumStateCheckedAdd<T> {
     sum: T,
@@ -223,7 +281,9 @@ impl<T: AddAssign + Default + Debug + Copy> AggregateState<T, T> for SumStateAdd
 mod tests {
     use super::*;
     use crate::arrays::array::Array;
+    use crate::arrays::scalar::decimal::Decimal128Scalar;
     use crate::arrays::scalar::ScalarValue;
+    use crate::arrays::storage::PrimitiveStorage;
     use crate::execution::operators::hash_aggregate::hash_table::GroupAddress;
     use crate::expr;
     use crate::functions::aggregate::ChunkGroupAddressIter;
@@ -536,6 +596,64 @@ mod tests {
         assert_eq!(ScalarValue::Int64(25), out.logical_value(2).unwrap());
     }
 
+    /// Sum all values in the array into a single group.
+    fn sum_single_group(datatype: DataType, vals: &Array) -> Result<(DataType, Array)> {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![datatype], vec!["c0".to_string()])
+            .unwrap();
+
+        let specialized = Sum
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let mut states = specialized.function_impl.new_states();
+        states.new_states(1);
+
+        let addrs: Vec<_> = (0..vals.logical_len())
+            .map(|_| GroupAddress {
+                chunk_idx: 0,
+                row_idx: 0,
+            })
+            .collect();
+        states.update_states(&[vals], ChunkGroupAddressIter::new(0, &addrs))?;
+
+        Ok((specialized.return_type, states.finalize()?))
+    }
+
+    #[test]
+    fn sum_decimal64_widens_and_keeps_scale() {
+        let datatype = DataType::Decimal64(DecimalTypeMeta::new(18, 2));
+        // 9999999999999999.99 * 3
+        let vals = Array::new_with_array_data(
+            datatype.clone(),
+            PrimitiveStorage::from(vec![999_999_999_999_999_999_i64; 3]),
+        );
+
+        let (return_type, out) = sum_single_group(datatype, &vals).unwrap();
+        assert_eq!(
+            DataType::Decimal128(DecimalTypeMeta::new(38, 2)),
+            return_type
+        );
+
+        let expected = ScalarValue::Decimal128(Decimal128Scalar {
+            precision: 38,
+            scale: 2,
+            value: 2_999_999_999_999_999_997,
+        });
+        assert_eq!(expected, out.logical_value(0).unwrap());
+    }
+
+    #[test]
+    fn sum_decimal128_overflow() {
+        let datatype = DataType::Decimal128(DecimalTypeMeta::new(38, 4));
+        let max = 10_i128.pow(38) - 1;
+        let vals =
+            Array::new_with_array_data(datatype.clone(), PrimitiveStorage::from(vec![max, 1]));
+
+        sum_single_group(datatype, &vals).unwrap_err();
+    }
+
     // #[test]
     // fn sum_i64_drain_multiple() {
     //     // Three groups, single partition, test that drain can be called
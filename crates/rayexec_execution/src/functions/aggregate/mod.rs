@@ -5,13 +5,14 @@ use std::fmt::Debug;
 use std::hash::Hash;
 
 use dyn_clone::DynClone;
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 use states::AggregateGroupStates;
 
 use super::FunctionInfo;
 use crate::arrays::datatype::DataType;
 use crate::arrays::executor::aggregate::RowToStateMapping;
 use crate::execution::operators::hash_aggregate::hash_table::GroupAddress;
+use crate::expr::aggregate_expr::AggregateOrdering;
 use crate::expr::Expression;
 use crate::logical::binder::table_list::TableList;
 
@@ -23,6 +24,34 @@ pub trait AggregateFunction: FunctionInfo + Debug + Sync + Send + DynClone {
         table_list: &TableList,
         inputs: Vec<Expression>,
     ) -> Result<PlannedAggregateFunction>;
+
+    /// Plan an ordered-set aggregate, e.g. `percentile_cont(0.5) WITHIN GROUP
+    /// (ORDER BY col)`.
+    ///
+    /// The first input is the expression being ordered, followed by the direct
+    /// arguments to the function. Inputs are not cast to match a signature
+    /// before calling this.
+    ///
+    /// Errors by default, only ordered-set aggregates should implement this.
+    fn plan_ordered_set(
+        &self,
+        _table_list: &TableList,
+        _inputs: Vec<Expression>,
+        _ordering: AggregateOrdering,
+    ) -> Result<PlannedAggregateFunction> {
+        Err(RayexecError::new(format!(
+            "WITHIN GROUP is only supported for ordered-set aggregates, '{}' is not one",
+            self.name()
+        )))
+    }
+
+    /// If the output of this aggregate may be NULL.
+    ///
+    /// Most aggregates produce NULL when there's no input (or only NULL
+    /// inputs), so this defaults to true.
+    fn output_nullable(&self) -> bool {
+        true
+    }
 }
 
 impl Clone for Box<dyn AggregateFunction> {
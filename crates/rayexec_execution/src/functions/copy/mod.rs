@@ -3,16 +3,21 @@ use std::fmt::Debug;
 
 use dyn_clone::DynClone;
 use rayexec_error::{RayexecError, Result};
+use rayexec_io::compression::Compression;
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::s3::credentials::AwsCredentials;
 use rayexec_io::s3::S3Location;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::arrays::field::Schema;
-use crate::arrays::scalar::OwnedScalarValue;
+use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::execution::operators::sink::PartitionSink;
 
 pub const FORMAT_OPT_KEY: &str = "format";
+pub const COMPRESSION_OPT_KEY: &str = "compression";
+pub const PARTITION_BY_OPT_KEY: &str = "partition_by";
+pub const APPEND_OPT_KEY: &str = "append";
 
 /// Arguments provided via a COPY TO statement.
 ///
@@ -60,6 +65,44 @@ impl CopyToArgs {
         self.named.remove(FORMAT_OPT_KEY)
     }
 
+    /// Try to remove the COMPRESSION option from the arguments, returning the
+    /// compression to use when writing to `loc`.
+    ///
+    /// If the option isn't provided, compression is inferred from the file
+    /// extension of the location.
+    pub fn try_remove_compression(&mut self, loc: &FileLocation) -> Result<Compression> {
+        match self.named.remove(COMPRESSION_OPT_KEY) {
+            Some(val) => Compression::try_from_name(val.try_as_str()?),
+            None => Ok(Compression::from_location(loc)),
+        }
+    }
+
+    /// Try to remove the PARTITION_BY option from the arguments, returning the
+    /// names of the columns to partition by.
+    ///
+    /// Accepts either a single column (`PARTITION_BY year`) or a list of
+    /// columns (`PARTITION_BY (year, month)`). Returns an empty vec if the
+    /// option isn't provided.
+    pub fn try_remove_partition_by(&mut self) -> Result<Vec<String>> {
+        match self.named.remove(PARTITION_BY_OPT_KEY) {
+            Some(ScalarValue::List(cols)) => cols
+                .iter()
+                .map(|col| Ok(col.try_as_str()?.to_string()))
+                .collect(),
+            Some(col) => Ok(vec![col.try_as_str()?.to_string()]),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Try to remove the APPEND option from the arguments, returning if the
+    /// COPY TO should append to the target instead of overwriting it.
+    pub fn try_remove_append(&mut self) -> Result<bool> {
+        match self.named.remove(APPEND_OPT_KEY) {
+            Some(val) => val.try_as_bool(),
+            None => Ok(false),
+        }
+    }
+
     pub fn try_get_named(&self, name: &str) -> Result<&OwnedScalarValue> {
         self.named
             .get(name)
@@ -71,16 +114,41 @@ pub trait CopyToFunction: Debug + Sync + Send + DynClone {
     /// Name of the copy to function.
     fn name(&self) -> &'static str;
 
+    /// File extension (without the leading dot) to use when generating file
+    /// names, e.g. for partitioned writes.
+    fn file_extension(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Validate the options provided in the COPY TO statement.
+    ///
+    /// Called during planning so that invalid options error before anything
+    /// gets executed. `args` has the FORMAT option already removed.
+    fn validate_args(&self, _args: &CopyToArgs) -> Result<()> {
+        Ok(())
+    }
+
     /// Create a COPY TO destination that will write to the given location.
-    // TODO: Additional COPY TO args once we have them.
+    ///
+    /// `args` contains the options provided in the COPY TO statement, with the
+    /// FORMAT option already removed.
     fn create_sinks(
         &self,
         schema: Schema,
         location: FileLocation,
+        args: CopyToArgs,
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>>;
 }
 
+/// Generate a unique file name with the given extension.
+///
+/// Used when writing new files into a directory that may already contain
+/// files from previous writes.
+pub fn unique_file_name(extension: &str) -> String {
+    format!("data_{}.{extension}", Uuid::new_v4().simple())
+}
+
 impl Clone for Box<dyn CopyToFunction> {
     fn clone(&self) -> Self {
         dyn_clone::clone_box(&**self)
@@ -8,6 +8,8 @@ pub enum Category {
     Time,
     Interval,
     List,
+    Struct,
+    Map,
     String,
     Regexp,
     Binary,
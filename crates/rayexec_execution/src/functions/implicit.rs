@@ -1,4 +1,5 @@
-use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta, ListTypeMeta};
+use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
 
 /// Score that should be used if no cast is needed.
 pub const NO_CAST_SCORE: u32 = 400;
@@ -33,6 +34,13 @@ pub const fn implicit_cast_score(have: &DataType, want: DataTypeId) -> Option<u3
         DataType::Float32 => return float32_cast_score(want),
         DataType::Float64 => return float64_cast_score(want),
 
+        // Decimals can always widen.
+        DataType::Decimal64(_) => {
+            if let DataTypeId::Decimal128 = want {
+                return Some(target_score(want));
+            }
+        }
+
         // String casts
         DataType::Utf8 => match want {
             DataTypeId::Int8
@@ -60,6 +68,13 @@ pub const fn implicit_cast_score(have: &DataType, want: DataTypeId) -> Option<u3
     None
 }
 
+/// Check if `have` can be implicitly cast to `want`.
+///
+/// A type can always be used in place of itself.
+pub fn can_implicitly_cast(have: &DataType, want: DataTypeId) -> bool {
+    have.datatype_id() == want || implicit_cast_score(have, want).is_some()
+}
+
 /// Determine the score for the target type we can cast to.
 ///
 /// More "specific" types will have a higher target score.
@@ -222,6 +237,163 @@ const fn float64_cast_score(want: DataTypeId) -> Option<u32> {
     })
 }
 
+/// Find the common type that both `a` and `b` can be implicitly cast to.
+///
+/// This should be used anywhere we need values of two types to produce a
+/// single type (e.g. the output of a UNION or the branches of a CASE).
+///
+/// The promotion rules are:
+///
+/// - NULL can be cast to any type.
+/// - Integers widen to the smallest integer that can hold both. Mixing signed
+///   and unsigned produces a signed integer wide enough for the unsigned
+///   input.
+/// - Integers mixed with floats produce a float that can exactly represent the
+///   integer where possible.
+/// - Decimals unify their scales, keeping enough precision for the integral
+///   digits of both inputs. Integers are treated as decimals with a scale of
+///   0.
+/// - Decimals mixed with floats produce a Float64.
+/// - Strings can be cast to integers, decimals, intervals, and timestamps.
+/// - Lists use the common type of their children.
+///
+/// Returns None if there's no common type.
+pub fn implicit_common_type(a: &DataType, b: &DataType) -> Option<DataType> {
+    if a == b {
+        return Some(a.clone());
+    }
+
+    match (a, b) {
+        (DataType::Null, other) | (other, DataType::Null) => Some(other.clone()),
+
+        (DataType::List(a), DataType::List(b)) => {
+            let child = implicit_common_type(&a.datatype, &b.datatype)?;
+            Some(DataType::List(ListTypeMeta::new(child)))
+        }
+
+        // Strings can be implicitly cast to a handful of types, use that type.
+        (DataType::Utf8, other) | (other, DataType::Utf8) => {
+            implicit_cast_score(&DataType::Utf8, other.datatype_id()).map(|_| other.clone())
+        }
+
+        (DataType::Decimal64(_) | DataType::Decimal128(_), _)
+        | (_, DataType::Decimal64(_) | DataType::Decimal128(_)) => decimal_common_type(a, b),
+
+        (a, b) if a.is_float() || b.is_float() => {
+            let a_width = float_width_for(a)?;
+            let b_width = float_width_for(b)?;
+            Some(match a_width.max(b_width) {
+                16 => DataType::Float16,
+                32 => DataType::Float32,
+                _ => DataType::Float64,
+            })
+        }
+
+        (a, b) => {
+            let (a_signed, a_width) = int_width(a)?;
+            let (b_signed, b_width) = int_width(b)?;
+
+            let (signed, width) = match (a_signed, b_signed) {
+                (true, true) | (false, false) => (a_signed, a_width.max(b_width)),
+                // Signed type needs to be wider than the unsigned type.
+                (true, false) => (true, a_width.max(b_width * 2)),
+                (false, true) => (true, b_width.max(a_width * 2)),
+            };
+
+            Some(match (signed, width) {
+                (true, 8) => DataType::Int8,
+                (true, 16) => DataType::Int16,
+                (true, 32) => DataType::Int32,
+                (true, 64) => DataType::Int64,
+                (true, 128) => DataType::Int128,
+                (false, 8) => DataType::UInt8,
+                (false, 16) => DataType::UInt16,
+                (false, 32) => DataType::UInt32,
+                (false, 64) => DataType::UInt64,
+                (false, 128) => DataType::UInt128,
+                _ => return None,
+            })
+        }
+    }
+}
+
+/// Returns if the integer type is signed along with its bit width.
+const fn int_width(datatype: &DataType) -> Option<(bool, u32)> {
+    Some(match datatype {
+        DataType::Int8 => (true, 8),
+        DataType::Int16 => (true, 16),
+        DataType::Int32 => (true, 32),
+        DataType::Int64 => (true, 64),
+        DataType::Int128 => (true, 128),
+        DataType::UInt8 => (false, 8),
+        DataType::UInt16 => (false, 16),
+        DataType::UInt32 => (false, 32),
+        DataType::UInt64 => (false, 64),
+        DataType::UInt128 => (false, 128),
+        _ => return None,
+    })
+}
+
+/// Width of the smallest float that can hold values of the given type.
+///
+/// Integers map to a float with enough mantissa bits to represent them
+/// exactly, except for 64 and 128 bit integers which map to Float64.
+const fn float_width_for(datatype: &DataType) -> Option<u32> {
+    Some(match datatype {
+        DataType::Float16 => 16,
+        DataType::Float32 => 32,
+        DataType::Float64 => 64,
+        other => match int_width(other) {
+            Some((_, 8)) => 16,
+            Some((_, 16)) => 32,
+            Some(_) => 64,
+            None => return None,
+        },
+    })
+}
+
+/// Common type for when at least one input is a decimal.
+fn decimal_common_type(a: &DataType, b: &DataType) -> Option<DataType> {
+    if a.is_float() || b.is_float() {
+        return Some(DataType::Float64);
+    }
+
+    let a_meta = decimal_meta_for(a)?;
+    let b_meta = decimal_meta_for(b)?;
+
+    let scale = a_meta.scale.max(b_meta.scale);
+    let a_digits = a_meta.precision as i16 - a_meta.scale as i16;
+    let b_digits = b_meta.precision as i16 - b_meta.scale as i16;
+    let precision = a_digits.max(b_digits) + scale.max(0) as i16;
+    let precision = u8::try_from(precision.max(1)).ok()?;
+
+    let use_decimal128 = matches!(a, DataType::Decimal128(_))
+        || matches!(b, DataType::Decimal128(_))
+        || precision > Decimal64Type::MAX_PRECISION;
+
+    let meta = DecimalTypeMeta::new(precision, scale);
+    if !use_decimal128 {
+        Some(DataType::Decimal64(meta))
+    } else if precision <= Decimal128Type::MAX_PRECISION {
+        Some(DataType::Decimal128(meta))
+    } else {
+        None
+    }
+}
+
+/// Get the decimal meta for a decimal or integer type.
+const fn decimal_meta_for(datatype: &DataType) -> Option<DecimalTypeMeta> {
+    Some(match datatype {
+        DataType::Decimal64(meta) | DataType::Decimal128(meta) => *meta,
+        DataType::Int8 | DataType::UInt8 => DecimalTypeMeta::new(3, 0),
+        DataType::Int16 | DataType::UInt16 => DecimalTypeMeta::new(5, 0),
+        DataType::Int32 | DataType::UInt32 => DecimalTypeMeta::new(10, 0),
+        DataType::Int64 => DecimalTypeMeta::new(19, 0),
+        DataType::UInt64 => DecimalTypeMeta::new(20, 0),
+        _ => return None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +417,14 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn can_implicitly_cast_same_type() {
+        assert!(can_implicitly_cast(&DataType::Utf8, DataTypeId::Utf8));
+        assert!(can_implicitly_cast(&DataType::Boolean, DataTypeId::Boolean));
+        assert!(!can_implicitly_cast(&DataType::Boolean, DataTypeId::Int32));
+        assert!(!can_implicitly_cast(&DataType::Int64, DataTypeId::Int32));
+    }
+
     #[test]
     fn integer_casts() {
         // Valid
@@ -277,4 +457,134 @@ mod tests {
             "int64: {to_int64_score}, float32: {to_float32_score}"
         );
     }
+
+    #[test]
+    fn common_type_int_widening() {
+        assert_eq!(
+            Some(DataType::Int64),
+            implicit_common_type(&DataType::Int32, &DataType::Int64)
+        );
+        assert_eq!(
+            Some(DataType::Int64),
+            implicit_common_type(&DataType::Int64, &DataType::Int32)
+        );
+        assert_eq!(
+            Some(DataType::UInt32),
+            implicit_common_type(&DataType::UInt8, &DataType::UInt32)
+        );
+        // Mixed signedness.
+        assert_eq!(
+            Some(DataType::Int64),
+            implicit_common_type(&DataType::Int32, &DataType::UInt32)
+        );
+        assert_eq!(
+            Some(DataType::Int16),
+            implicit_common_type(&DataType::UInt8, &DataType::Int16)
+        );
+        assert_eq!(
+            Some(DataType::Int128),
+            implicit_common_type(&DataType::UInt64, &DataType::Int8)
+        );
+        assert_eq!(
+            None,
+            implicit_common_type(&DataType::UInt128, &DataType::Int8)
+        );
+    }
+
+    #[test]
+    fn common_type_int_float() {
+        assert_eq!(
+            Some(DataType::Float64),
+            implicit_common_type(&DataType::Int32, &DataType::Float64)
+        );
+        assert_eq!(
+            Some(DataType::Float64),
+            implicit_common_type(&DataType::Float32, &DataType::Int32)
+        );
+        assert_eq!(
+            Some(DataType::Float32),
+            implicit_common_type(&DataType::Int16, &DataType::Float32)
+        );
+        assert_eq!(
+            Some(DataType::Float64),
+            implicit_common_type(&DataType::Float16, &DataType::Float64)
+        );
+    }
+
+    #[test]
+    fn common_type_decimals() {
+        // Scale unification.
+        assert_eq!(
+            Some(DataType::Decimal64(DecimalTypeMeta::new(6, 3))),
+            implicit_common_type(
+                &DataType::Decimal64(DecimalTypeMeta::new(5, 2)),
+                &DataType::Decimal64(DecimalTypeMeta::new(4, 3)),
+            )
+        );
+        // Int as decimal with scale 0.
+        assert_eq!(
+            Some(DataType::Decimal64(DecimalTypeMeta::new(12, 2))),
+            implicit_common_type(
+                &DataType::Int32,
+                &DataType::Decimal64(DecimalTypeMeta::new(5, 2)),
+            )
+        );
+        // Precision beyond decimal64.
+        assert_eq!(
+            Some(DataType::Decimal128(DecimalTypeMeta::new(22, 3))),
+            implicit_common_type(
+                &DataType::Int64,
+                &DataType::Decimal64(DecimalTypeMeta::new(18, 3)),
+            )
+        );
+        assert_eq!(
+            Some(DataType::Float64),
+            implicit_common_type(
+                &DataType::Decimal64(DecimalTypeMeta::new(18, 3)),
+                &DataType::Float32,
+            )
+        );
+    }
+
+    #[test]
+    fn common_type_other() {
+        assert_eq!(
+            Some(DataType::Int32),
+            implicit_common_type(&DataType::Null, &DataType::Int32)
+        );
+        assert_eq!(
+            Some(DataType::Int64),
+            implicit_common_type(&DataType::Utf8, &DataType::Int64)
+        );
+        assert_eq!(
+            Some(DataType::List(ListTypeMeta::new(DataType::Int64))),
+            implicit_common_type(
+                &DataType::List(ListTypeMeta::new(DataType::Int32)),
+                &DataType::List(ListTypeMeta::new(DataType::Int64)),
+            )
+        );
+    }
+
+    #[test]
+    fn common_type_incompatible() {
+        assert_eq!(
+            None,
+            implicit_common_type(&DataType::Boolean, &DataType::Int32)
+        );
+        assert_eq!(
+            None,
+            implicit_common_type(&DataType::Utf8, &DataType::Float64)
+        );
+        assert_eq!(
+            None,
+            implicit_common_type(&DataType::Date32, &DataType::Int64)
+        );
+        assert_eq!(
+            None,
+            implicit_common_type(
+                &DataType::List(ListTypeMeta::new(DataType::Int32)),
+                &DataType::Int32
+            )
+        );
+    }
 }
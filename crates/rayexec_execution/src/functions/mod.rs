@@ -11,10 +11,13 @@ use std::fmt::Display;
 
 use documentation::Documentation;
 use fmtutil::IntoDisplayableSlice;
-use implicit::{implicit_cast_score, NO_CAST_SCORE};
+use implicit::{can_implicitly_cast, implicit_cast_score, implicit_common_type, NO_CAST_SCORE};
 use rayexec_error::{RayexecError, Result};
 
 use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::cast_expr::CastExpr;
+use crate::expr::Expression;
+use crate::logical::binder::table_list::TableList;
 
 /// Function signature.
 // TODO: Include named args. Also make sure to update PartialEq too.
@@ -167,6 +170,16 @@ pub enum CastType {
 }
 
 impl CastType {
+    /// Get the cast type for using `have` in place of `want`.
+    ///
+    /// Assumes `have` can be implicitly cast to `want`.
+    fn implicit(have: &DataType, want: DataTypeId) -> Self {
+        match implicit_cast_score(have, want) {
+            Some(score) if have.datatype_id() != want => Self::Cast { to: want, score },
+            _ => Self::NoCastNeeded,
+        }
+    }
+
     fn score(&self) -> u32 {
         match self {
             Self::Cast { score, .. } => *score,
@@ -235,18 +248,10 @@ impl CandidateSignature {
         buf.clear();
 
         for (have, &want) in have.iter().zip(want.iter()) {
-            if have.datatype_id() == want {
-                buf.push(CastType::NoCastNeeded);
-                continue;
-            }
-
-            let score = implicit_cast_score(have, want);
-            if let Some(score) = score {
-                buf.push(CastType::Cast { to: want, score });
-                continue;
+            if !can_implicitly_cast(have, want) {
+                return false;
             }
-
-            return false;
+            buf.push(CastType::implicit(have, want));
         }
 
         // Check variadic.
@@ -264,21 +269,10 @@ impl CandidateSignature {
                 };
 
                 for have in remaining {
-                    if have.datatype_id() == expected {
-                        buf.push(CastType::NoCastNeeded);
-                        continue;
-                    }
-
-                    let score = implicit_cast_score(have, expected);
-                    if let Some(score) = score {
-                        buf.push(CastType::Cast {
-                            to: expected,
-                            score,
-                        });
-                        continue;
+                    if !can_implicitly_cast(have, expected) {
+                        return false;
                     }
-
-                    return false;
+                    buf.push(CastType::implicit(have, expected));
                 }
 
                 // Everything's valid, casts have been pushed to the buffer.
@@ -365,6 +359,132 @@ pub fn plan_check_num_args_one_of<T, const N: usize>(
     Ok(())
 }
 
+/// Combine positional and named arguments into a single list of positional
+/// arguments.
+///
+/// Named arguments are matched against the argument names in a signature's
+/// documentation. The first signature taking exactly the number of provided
+/// arguments, and whose remaining argument names (after the positional
+/// arguments) cover all the named arguments, determines the final order.
+pub fn plan_named_args<T>(
+    func: &(impl FunctionInfo + ?Sized),
+    positional: Vec<T>,
+    named: Vec<(String, T)>,
+) -> Result<Vec<T>> {
+    if named.is_empty() {
+        return Ok(positional);
+    }
+
+    for (idx, (name, _)) in named.iter().enumerate() {
+        if named[..idx].iter().any(|(prev, _)| prev == name) {
+            return Err(RayexecError::new(format!(
+                "Duplicate argument '{name}' for '{}'",
+                func.name()
+            )));
+        }
+    }
+
+    let arg_names = func
+        .signatures()
+        .iter()
+        .filter(|sig| sig.variadic_arg.is_none())
+        .filter_map(|sig| sig.doc.map(|doc| doc.arguments))
+        .filter(|names| names.len() == positional.len() + named.len());
+
+    for names in arg_names {
+        let remaining = &names[positional.len()..];
+        if !named
+            .iter()
+            .all(|(name, _)| remaining.contains(&name.as_str()))
+        {
+            continue;
+        }
+
+        let mut named = named;
+        let mut args = positional;
+        for arg_name in remaining {
+            let idx = named
+                .iter()
+                .position(|(name, _)| name == arg_name)
+                .expect("named arg to exist");
+            args.push(named.swap_remove(idx).1);
+        }
+
+        return Ok(args);
+    }
+
+    // No matching signature, figure out a useful error.
+    for (name, _) in &named {
+        let positions: Vec<_> = func
+            .signatures()
+            .iter()
+            .filter_map(|sig| sig.doc)
+            .filter_map(|doc| doc.arguments.iter().position(|arg| arg == name))
+            .collect();
+
+        if positions.is_empty() {
+            return Err(RayexecError::new(format!(
+                "Unknown argument '{name}' for '{}'",
+                func.name()
+            )));
+        }
+
+        if positions.iter().all(|pos| *pos < positional.len()) {
+            return Err(RayexecError::new(format!(
+                "Argument '{name}' for '{}' already provided as a positional argument",
+                func.name()
+            )));
+        }
+    }
+
+    Err(RayexecError::new(format!(
+        "No signature for '{}' matches the provided named arguments",
+        func.name()
+    )))
+}
+
+/// Find a common type for all inputs, casting any inputs that aren't already
+/// that type.
+///
+/// Used by functions accepting `DataTypeId::Any` where all inputs need to be
+/// the same type (e.g. `coalesce`). Returns `DataType::Null` if all inputs are
+/// NULL or there are no inputs.
+pub fn plan_cast_to_common_type(
+    func: &impl FunctionInfo,
+    table_list: &TableList,
+    inputs: Vec<Expression>,
+) -> Result<(DataType, Vec<Expression>)> {
+    let datatypes = inputs
+        .iter()
+        .map(|input| input.datatype(table_list))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut common = DataType::Null;
+    for datatype in &datatypes {
+        common = implicit_common_type(&common, datatype).ok_or_else(|| {
+            RayexecError::new(format!(
+                "Cannot find a common type for inputs {} to '{}'",
+                datatypes.display_with_brackets(),
+                func.name(),
+            ))
+        })?;
+    }
+
+    let inputs = inputs
+        .into_iter()
+        .zip(datatypes)
+        .map(|(input, datatype)| {
+            if datatype == common {
+                input
+            } else {
+                Expression::Cast(CastExpr::new(input, common.clone()))
+            }
+        })
+        .collect();
+
+    Ok((common, inputs))
+}
+
 /// Return an error indicating the input types we got are not ones we can
 /// handle.
 // TODO: Include valid signatures in the error
@@ -384,6 +504,94 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::functions::documentation::Category;
+
+    #[derive(Debug)]
+    struct NamedArgsFunc;
+
+    impl FunctionInfo for NamedArgsFunc {
+        fn name(&self) -> &'static str {
+            "named_args_func"
+        }
+
+        fn signatures(&self) -> &[Signature] {
+            &[
+                Signature {
+                    positional_args: &[DataTypeId::Utf8, DataTypeId::Int64],
+                    variadic_arg: None,
+                    return_type: DataTypeId::Utf8,
+                    doc: Some(&Documentation {
+                        category: Category::String,
+                        description: "",
+                        arguments: &["string", "count"],
+                        example: None,
+                    }),
+                },
+                Signature {
+                    positional_args: &[DataTypeId::Utf8, DataTypeId::Int64, DataTypeId::Utf8],
+                    variadic_arg: None,
+                    return_type: DataTypeId::Utf8,
+                    doc: Some(&Documentation {
+                        category: Category::String,
+                        description: "",
+                        arguments: &["string", "count", "pad"],
+                        example: None,
+                    }),
+                },
+            ]
+        }
+    }
+
+    fn named(args: &[(&str, i32)]) -> Vec<(String, i32)> {
+        args.iter().map(|(n, v)| (n.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn named_args_reordered() {
+        let args = plan_named_args(&NamedArgsFunc, vec![1], named(&[("count", 2)])).unwrap();
+        assert_eq!(vec![1, 2], args);
+
+        let args = plan_named_args(
+            &NamedArgsFunc,
+            vec![],
+            named(&[("pad", 3), ("string", 1), ("count", 2)]),
+        )
+        .unwrap();
+        assert_eq!(vec![1, 2, 3], args);
+    }
+
+    #[test]
+    fn named_args_only_positional() {
+        let args = plan_named_args(&NamedArgsFunc, vec![1, 2], Vec::new()).unwrap();
+        assert_eq!(vec![1, 2], args);
+    }
+
+    #[test]
+    fn named_args_unknown_name() {
+        let err = plan_named_args(&NamedArgsFunc, vec![1], named(&[("cnt", 2)])).unwrap_err();
+        assert!(err.to_string().contains("Unknown argument 'cnt'"), "{err}");
+    }
+
+    #[test]
+    fn named_args_duplicate() {
+        let err = plan_named_args(
+            &NamedArgsFunc,
+            vec![1],
+            named(&[("count", 2), ("count", 3)]),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("Duplicate argument 'count'"),
+            "{err}"
+        );
+
+        let err = plan_named_args(&NamedArgsFunc, vec![1, 2], named(&[("string", 3)])).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("already provided as a positional argument"),
+            "{err}"
+        );
+    }
 
     #[test]
     fn find_candidate_no_match() {
@@ -1,10 +1,12 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
+use super::{execute_checked_binary, CheckedArith, OverflowBehavior};
 use crate::arrays::array::{Array, ArrayData};
-use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::compute::date::timestamp_add_interval;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::physical_type::{
     PhysicalF16,
@@ -15,6 +17,7 @@ use crate::arrays::executor::physical_type::{
     PhysicalI32,
     PhysicalI64,
     PhysicalI8,
+    PhysicalInterval,
     PhysicalStorage,
     PhysicalU128,
     PhysicalU16,
@@ -25,7 +28,12 @@ use crate::arrays::executor::physical_type::{
 use crate::arrays::executor::scalar::BinaryExecutor;
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
-use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::scalar::{
+    PlannedScalarFunction,
+    ScalarFunction,
+    ScalarFunctionImpl,
+    ScalarPlanOptions,
+};
 use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
 
@@ -78,10 +86,22 @@ impl FunctionInfo for Add {
                 &[DataTypeId::Interval, DataTypeId::Int64],
                 DataTypeId::Interval,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Timestamp, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Interval, DataTypeId::Timestamp],
+                DataTypeId::Timestamp,
+            ),
             Signature::new_positional(
                 &[DataTypeId::Decimal64, DataTypeId::Decimal64],
                 DataTypeId::Decimal64,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal128, DataTypeId::Decimal128],
+                DataTypeId::Decimal128,
+            ),
         ];
         SIGS
     }
@@ -92,6 +112,15 @@ impl ScalarFunction for Add {
         &self,
         table_list: &TableList,
         inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_options(table_list, inputs, ScalarPlanOptions::default())
+    }
+
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        options: ScalarPlanOptions,
     ) -> Result<PlannedScalarFunction> {
         plan_check_num_args(self, &inputs, 2)?;
 
@@ -100,81 +129,139 @@ impl ScalarFunction for Add {
             inputs[1].datatype(table_list)?,
         ) {
             (DataType::Float16, DataType::Float16) => (
-                Box::new(AddImpl::<PhysicalF16>::new(DataType::Float16)),
+                Box::new(AddImpl::<PhysicalF16>::new(
+                    DataType::Float16,
+                    options.overflow,
+                )),
                 DataType::Float16,
             ),
             (DataType::Float32, DataType::Float32) => (
-                Box::new(AddImpl::<PhysicalF32>::new(DataType::Float32)),
+                Box::new(AddImpl::<PhysicalF32>::new(
+                    DataType::Float32,
+                    options.overflow,
+                )),
                 DataType::Float32,
             ),
             (DataType::Float64, DataType::Float64) => (
-                Box::new(AddImpl::<PhysicalF64>::new(DataType::Float64)),
+                Box::new(AddImpl::<PhysicalF64>::new(
+                    DataType::Float64,
+                    options.overflow,
+                )),
                 DataType::Float64,
             ),
             (DataType::Int8, DataType::Int8) => (
-                Box::new(AddImpl::<PhysicalI8>::new(DataType::Int8)),
+                Box::new(AddImpl::<PhysicalI8>::new(DataType::Int8, options.overflow)),
                 DataType::Int8,
             ),
             (DataType::Int16, DataType::Int16) => (
-                Box::new(AddImpl::<PhysicalI16>::new(DataType::Int16)),
+                Box::new(AddImpl::<PhysicalI16>::new(
+                    DataType::Int16,
+                    options.overflow,
+                )),
                 DataType::Int16,
             ),
             (DataType::Int32, DataType::Int32) => (
-                Box::new(AddImpl::<PhysicalI32>::new(DataType::Int32)),
+                Box::new(AddImpl::<PhysicalI32>::new(
+                    DataType::Int32,
+                    options.overflow,
+                )),
                 DataType::Int32,
             ),
             (DataType::Int64, DataType::Int64) => (
-                Box::new(AddImpl::<PhysicalI64>::new(DataType::Int64)),
+                Box::new(AddImpl::<PhysicalI64>::new(
+                    DataType::Int64,
+                    options.overflow,
+                )),
                 DataType::Int64,
             ),
             (DataType::Int128, DataType::Int128) => (
-                Box::new(AddImpl::<PhysicalI128>::new(DataType::Int128)),
+                Box::new(AddImpl::<PhysicalI128>::new(
+                    DataType::Int128,
+                    options.overflow,
+                )),
                 DataType::Int128,
             ),
             (DataType::UInt8, DataType::UInt8) => (
-                Box::new(AddImpl::<PhysicalU8>::new(DataType::UInt8)),
+                Box::new(AddImpl::<PhysicalU8>::new(
+                    DataType::UInt8,
+                    options.overflow,
+                )),
                 DataType::UInt8,
             ),
             (DataType::UInt16, DataType::UInt16) => (
-                Box::new(AddImpl::<PhysicalU16>::new(DataType::UInt16)),
+                Box::new(AddImpl::<PhysicalU16>::new(
+                    DataType::UInt16,
+                    options.overflow,
+                )),
                 DataType::UInt16,
             ),
             (DataType::UInt32, DataType::UInt32) => (
-                Box::new(AddImpl::<PhysicalU32>::new(DataType::UInt32)),
+                Box::new(AddImpl::<PhysicalU32>::new(
+                    DataType::UInt32,
+                    options.overflow,
+                )),
                 DataType::UInt32,
             ),
             (DataType::UInt64, DataType::UInt64) => (
-                Box::new(AddImpl::<PhysicalU64>::new(DataType::UInt64)),
+                Box::new(AddImpl::<PhysicalU64>::new(
+                    DataType::UInt64,
+                    options.overflow,
+                )),
                 DataType::UInt64,
             ),
             (DataType::UInt128, DataType::UInt128) => (
-                Box::new(AddImpl::<PhysicalU128>::new(DataType::UInt128)),
+                Box::new(AddImpl::<PhysicalU128>::new(
+                    DataType::UInt128,
+                    options.overflow,
+                )),
                 DataType::UInt128,
             ),
 
             // TODO: Split out decimal (for scaling)
             datatypes @ (DataType::Decimal64(_), DataType::Decimal64(_)) => (
-                Box::new(AddImpl::<PhysicalI64>::new(datatypes.0.clone())),
+                Box::new(AddImpl::<PhysicalI64>::new(
+                    datatypes.0.clone(),
+                    options.overflow,
+                )),
                 datatypes.0,
             ),
             datatypes @ (DataType::Decimal128(_), DataType::Decimal128(_)) => (
-                Box::new(AddImpl::<PhysicalI128>::new(datatypes.0.clone())),
+                Box::new(AddImpl::<PhysicalI128>::new(
+                    datatypes.0.clone(),
+                    options.overflow,
+                )),
                 datatypes.0,
             ),
 
             // Date + days
             (DataType::Date32, DataType::Int32) => (
-                Box::new(AddImpl::<PhysicalI32>::new(DataType::Date32)),
+                Box::new(AddImpl::<PhysicalI32>::new(
+                    DataType::Date32,
+                    options.overflow,
+                )),
                 DataType::Date32,
             ),
             // Days + date
             // Note both are represented as i32 physical type, we don't need to worry about flipping the sides.
             (DataType::Int32, DataType::Date32) => (
-                Box::new(AddImpl::<PhysicalI32>::new(DataType::Date32)),
+                Box::new(AddImpl::<PhysicalI32>::new(
+                    DataType::Date32,
+                    options.overflow,
+                )),
                 DataType::Date32,
             ),
 
-            // TODO: Interval
+            // Timestamp + interval
+            (DataType::Timestamp(m), DataType::Interval) => (
+                Box::new(TimestampIntervalAddImpl::<false>::new(m.unit)),
+                DataType::Timestamp(m),
+            ),
+            // Interval + timestamp
+            (DataType::Interval, DataType::Timestamp(m)) => (
+                Box::new(TimestampIntervalAddImpl::<true>::new(m.unit)),
+                DataType::Timestamp(m),
+            ),
+
             (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
         };
 
@@ -190,13 +277,15 @@ impl ScalarFunction for Add {
 #[derive(Debug, Clone)]
 pub struct AddImpl<S> {
     datatype: DataType,
+    overflow: OverflowBehavior,
     _s: PhantomData<S>,
 }
 
 impl<S> AddImpl<S> {
-    fn new(datatype: DataType) -> Self {
+    fn new(datatype: DataType, overflow: OverflowBehavior) -> Self {
         AddImpl {
             datatype,
+            overflow,
             _s: PhantomData,
         }
     }
@@ -205,26 +294,77 @@ impl<S> AddImpl<S> {
 impl<S> ScalarFunctionImpl for AddImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Add<Output = S::Type<'static>> + Default + Copy,
-    ArrayData: From<PrimitiveStorage<S::Type<'static>>>,
+    for<'a> S::Type<'a>: CheckedArith,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        let a = inputs[0];
-        let b = inputs[1];
+        execute_checked_binary::<S>(
+            "+",
+            inputs[0],
+            inputs[1],
+            self.datatype.clone(),
+            self.overflow,
+            CheckedArith::overflowing_add,
+            CheckedArith::saturating_add,
+        )
+    }
+
+    fn output_nullable(&self, inputs: &[bool]) -> Option<bool> {
+        // Overflowing may produce NULLs from non-NULL inputs.
+        Some(self.overflow == OverflowBehavior::Null || inputs.iter().any(|n| *n))
+    }
+}
+
+/// Adds an interval to a timestamp, clamping the day of month when adding
+/// months.
+#[derive(Debug, Clone)]
+pub struct TimestampIntervalAddImpl<const LHS_RHS_FLIPPED: bool> {
+    unit: TimeUnit,
+}
+
+impl<const LHS_RHS_FLIPPED: bool> TimestampIntervalAddImpl<LHS_RHS_FLIPPED> {
+    fn new(unit: TimeUnit) -> Self {
+        TimestampIntervalAddImpl { unit }
+    }
+}
+
+impl<const LHS_RHS_FLIPPED: bool> ScalarFunctionImpl for TimestampIntervalAddImpl<LHS_RHS_FLIPPED> {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let (timestamps, intervals) = if LHS_RHS_FLIPPED {
+            (inputs[1], inputs[0])
+        } else {
+            (inputs[0], inputs[1])
+        };
 
         let builder = ArrayBuilder {
-            datatype: self.datatype.clone(),
-            buffer: PrimitiveBuffer::with_len(a.logical_len()),
+            datatype: timestamps.datatype().clone(),
+            buffer: PrimitiveBuffer::<i64>::with_len(timestamps.logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a + b)))
+        let mut out_of_range = false;
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalInterval, _, _>(
+            timestamps,
+            intervals,
+            builder,
+            |ts, interval, buf| match timestamp_add_interval(self.unit, ts, interval) {
+                Some(v) => buf.put(&v),
+                None => out_of_range = true,
+            },
+        )?;
+
+        if out_of_range {
+            return Err(RayexecError::new("Timestamp out of range"));
+        }
+
+        Ok(out)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::arrays::datatype::DataType;
+    use crate::arrays::datatype::{DataType, TimestampTypeMeta};
+    use crate::arrays::scalar::interval::Interval;
     use crate::expr;
     use crate::functions::scalar::ScalarFunction;
 
@@ -254,4 +394,89 @@ mod tests {
 
         assert_eq!(expected, out);
     }
+
+    fn add_i8_max_plus_one(overflow: OverflowBehavior) -> Result<Array> {
+        let a = Array::from_iter([i8::MAX, 1]);
+        let b = Array::from_iter([1_i8, 1]);
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int8, DataType::Int8],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let planned = Add
+            .plan_with_options(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+                ScalarPlanOptions {
+                    overflow,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        planned.function_impl.execute(&[&a, &b])
+    }
+
+    #[test]
+    fn add_i8_overflow_error() {
+        add_i8_max_plus_one(OverflowBehavior::Error).unwrap_err();
+    }
+
+    #[test]
+    fn add_i8_overflow_wrap() {
+        let out = add_i8_max_plus_one(OverflowBehavior::Wrap).unwrap();
+        assert_eq!(Array::from_iter([-128_i8, 2]), out);
+    }
+
+    #[test]
+    fn add_i8_overflow_saturate() {
+        let out = add_i8_max_plus_one(OverflowBehavior::Saturate).unwrap();
+        assert_eq!(Array::from_iter([127_i8, 2]), out);
+    }
+
+    #[test]
+    fn add_month_to_month_end_timestamp() {
+        let datatype = DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond));
+
+        // 2024-01-31 12:00:00 and 2023-01-31 00:00:00
+        let a = Array::new_with_array_data(
+            datatype.clone(),
+            PrimitiveStorage::from(vec![1_706_702_400_000_000_i64, 1_675_123_200_000_000]),
+        );
+        let b = Array::new_with_array_data(
+            DataType::Interval,
+            PrimitiveStorage::from(vec![Interval::new(1, 0, 0), Interval::new(1, 0, 0)]),
+        );
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![datatype.clone(), DataType::Interval],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let planned = Add
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+            )
+            .unwrap();
+        assert_eq!(datatype, planned.return_type);
+
+        let out = planned.function_impl.execute(&[&a, &b]).unwrap();
+        // 2024-02-29 12:00:00 (leap year) and 2023-02-28 00:00:00
+        let expected = Array::new_with_array_data(
+            datatype,
+            PrimitiveStorage::from(vec![1_709_208_000_000_000_i64, 1_677_542_400_000_000]),
+        );
+
+        assert_eq!(expected, out);
+    }
 }
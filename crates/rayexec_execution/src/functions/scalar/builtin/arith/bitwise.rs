@@ -0,0 +1,586 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use rayexec_error::Result;
+
+use super::gcd::int_signatures;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::DataType;
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalI128,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI8,
+    PhysicalStorage,
+    PhysicalU128,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU8,
+};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+/// Integer types that support bitwise operations.
+///
+/// Shift amounts are taken modulo the bit width of the type, so `1 << 33` on
+/// a 32 bit integer is the same as `1 << 1`. This matches what Postgres
+/// produces on common hardware. Negative shift amounts are interpreted as
+/// their two's complement bit pattern before masking. Shifting right on
+/// signed integers is arithmetic (the sign bit is extended).
+pub trait BitwiseInteger: Copy + Default {
+    fn bit_and(self, other: Self) -> Self;
+    fn bit_or(self, other: Self) -> Self;
+    fn bit_xor(self, other: Self) -> Self;
+    fn shift_left(self, amount: Self) -> Self;
+    fn shift_right(self, amount: Self) -> Self;
+}
+
+macro_rules! impl_bitwise_integer {
+    ($type:ty) => {
+        impl BitwiseInteger for $type {
+            fn bit_and(self, other: Self) -> Self {
+                self & other
+            }
+
+            fn bit_or(self, other: Self) -> Self {
+                self | other
+            }
+
+            fn bit_xor(self, other: Self) -> Self {
+                self ^ other
+            }
+
+            fn shift_left(self, amount: Self) -> Self {
+                // Wrapping shifts mask the amount by the bit width. Truncating
+                // to u32 first doesn't change the masked value since all
+                // widths divide 2^32.
+                self.wrapping_shl(amount as u32)
+            }
+
+            fn shift_right(self, amount: Self) -> Self {
+                self.wrapping_shr(amount as u32)
+            }
+        }
+    };
+}
+
+impl_bitwise_integer!(i8);
+impl_bitwise_integer!(i16);
+impl_bitwise_integer!(i32);
+impl_bitwise_integer!(i64);
+impl_bitwise_integer!(i128);
+impl_bitwise_integer!(u8);
+impl_bitwise_integer!(u16);
+impl_bitwise_integer!(u32);
+impl_bitwise_integer!(u64);
+impl_bitwise_integer!(u128);
+
+/// Plan a bitwise operation for the matched integer input types.
+fn plan_bitwise_function<F>(
+    func: &F,
+    table_list: &TableList,
+    inputs: Vec<Expression>,
+    op: BitwiseOp,
+) -> Result<PlannedScalarFunction>
+where
+    F: ScalarFunction + Clone + 'static,
+{
+    plan_check_num_args(func, &inputs, 2)?;
+
+    let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) = match (
+        inputs[0].datatype(table_list)?,
+        inputs[1].datatype(table_list)?,
+    ) {
+        (DataType::Int8, DataType::Int8) => (
+            Box::new(BitwiseImpl::<PhysicalI8>::new(DataType::Int8, op)),
+            DataType::Int8,
+        ),
+        (DataType::Int16, DataType::Int16) => (
+            Box::new(BitwiseImpl::<PhysicalI16>::new(DataType::Int16, op)),
+            DataType::Int16,
+        ),
+        (DataType::Int32, DataType::Int32) => (
+            Box::new(BitwiseImpl::<PhysicalI32>::new(DataType::Int32, op)),
+            DataType::Int32,
+        ),
+        (DataType::Int64, DataType::Int64) => (
+            Box::new(BitwiseImpl::<PhysicalI64>::new(DataType::Int64, op)),
+            DataType::Int64,
+        ),
+        (DataType::Int128, DataType::Int128) => (
+            Box::new(BitwiseImpl::<PhysicalI128>::new(DataType::Int128, op)),
+            DataType::Int128,
+        ),
+        (DataType::UInt8, DataType::UInt8) => (
+            Box::new(BitwiseImpl::<PhysicalU8>::new(DataType::UInt8, op)),
+            DataType::UInt8,
+        ),
+        (DataType::UInt16, DataType::UInt16) => (
+            Box::new(BitwiseImpl::<PhysicalU16>::new(DataType::UInt16, op)),
+            DataType::UInt16,
+        ),
+        (DataType::UInt32, DataType::UInt32) => (
+            Box::new(BitwiseImpl::<PhysicalU32>::new(DataType::UInt32, op)),
+            DataType::UInt32,
+        ),
+        (DataType::UInt64, DataType::UInt64) => (
+            Box::new(BitwiseImpl::<PhysicalU64>::new(DataType::UInt64, op)),
+            DataType::UInt64,
+        ),
+        (DataType::UInt128, DataType::UInt128) => (
+            Box::new(BitwiseImpl::<PhysicalU128>::new(DataType::UInt128, op)),
+            DataType::UInt128,
+        ),
+        (a, b) => return Err(invalid_input_types_error(func, &[a, b])),
+    };
+
+    Ok(PlannedScalarFunction {
+        function: Box::new(func.clone()),
+        return_type,
+        inputs,
+        function_impl,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitAnd;
+
+impl FunctionInfo for BitAnd {
+    fn name(&self) -> &'static str {
+        "&"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["bitwise_and"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &int_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Compute the bitwise AND of two integers.",
+            arguments: &["a", "b"],
+            example: Some(Example {
+                example: "12 & 10",
+                output: "8",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for BitAnd {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_bitwise_function(self, table_list, inputs, BitwiseOp::And)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitOr;
+
+impl FunctionInfo for BitOr {
+    fn name(&self) -> &'static str {
+        "|"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["bitwise_or"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &int_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Compute the bitwise OR of two integers.",
+            arguments: &["a", "b"],
+            example: Some(Example {
+                example: "12 | 10",
+                output: "14",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for BitOr {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_bitwise_function(self, table_list, inputs, BitwiseOp::Or)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitXor;
+
+impl FunctionInfo for BitXor {
+    fn name(&self) -> &'static str {
+        "#"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["bitwise_xor", "xor"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &int_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Compute the bitwise exclusive OR of two integers.",
+            arguments: &["a", "b"],
+            example: Some(Example {
+                example: "12 # 10",
+                output: "6",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for BitXor {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_bitwise_function(self, table_list, inputs, BitwiseOp::Xor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShiftLeft;
+
+impl FunctionInfo for ShiftLeft {
+    fn name(&self) -> &'static str {
+        "<<"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["shift_left"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &int_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Shift an integer left by some number of bits. The shift amount is taken modulo the bit width of the type.",
+            arguments: &["value", "amount"],
+            example: Some(Example {
+                example: "1 << 4",
+                output: "16",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for ShiftLeft {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_bitwise_function(self, table_list, inputs, BitwiseOp::ShiftLeft)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShiftRight;
+
+impl FunctionInfo for ShiftRight {
+    fn name(&self) -> &'static str {
+        ">>"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["shift_right"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &int_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Shift an integer right by some number of bits. The shift amount is taken modulo the bit width of the type.",
+            arguments: &["value", "amount"],
+            example: Some(Example {
+                example: "16 >> 2",
+                output: "4",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for ShiftRight {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_bitwise_function(self, table_list, inputs, BitwiseOp::ShiftRight)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BitwiseOp {
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+#[derive(Debug, Clone)]
+pub struct BitwiseImpl<S> {
+    datatype: DataType,
+    op: BitwiseOp,
+    _s: PhantomData<S>,
+}
+
+impl<S> BitwiseImpl<S> {
+    fn new(datatype: DataType, op: BitwiseOp) -> Self {
+        BitwiseImpl {
+            datatype,
+            op,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunctionImpl for BitwiseImpl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: BitwiseInteger,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        bitwise::<S>(inputs[0], inputs[1], self.datatype.clone(), self.op)
+    }
+}
+
+fn bitwise<'a, S>(a: &'a Array, b: &'a Array, datatype: DataType, op: BitwiseOp) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: BitwiseInteger,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(a.logical_len()),
+    };
+
+    BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+        let v = match op {
+            BitwiseOp::And => a.bit_and(b),
+            BitwiseOp::Or => a.bit_or(b),
+            BitwiseOp::Xor => a.bit_xor(b),
+            BitwiseOp::ShiftLeft => a.shift_left(b),
+            BitwiseOp::ShiftRight => a.shift_right(b),
+        };
+        buf.put(&v)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn plan(func: &impl ScalarFunction, datatype: DataType) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![datatype.clone(), datatype],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        func.plan(
+            &table_list,
+            vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn bit_and_per_type() {
+        let out = plan(&BitAnd, DataType::Int8)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([12_i8, -1, 0]),
+                &Array::from_iter([10_i8, 5, 7]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([8_i8, 5, 0]), out);
+
+        let out = plan(&BitAnd, DataType::Int64)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([0xFF00_i64, -2]),
+                &Array::from_iter([0x0FF0_i64, 3]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([0x0F00_i64, 2]), out);
+
+        let out = plan(&BitAnd, DataType::UInt16)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([u16::MAX, 6]),
+                &Array::from_iter([0x00FF_u16, 3]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([0x00FF_u16, 2]), out);
+    }
+
+    #[test]
+    fn bit_or_per_type() {
+        let out = plan(&BitOr, DataType::Int32)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([12_i32, -8, 0]),
+                &Array::from_iter([10_i32, 3, 0]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([14_i32, -5, 0]), out);
+
+        let out = plan(&BitOr, DataType::UInt8)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([0xF0_u8, 1]),
+                &Array::from_iter([0x0F_u8, 2]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([0xFF_u8, 3]), out);
+
+        let out = plan(&BitOr, DataType::Int128)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([1_i128 << 100]),
+                &Array::from_iter([1_i128]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([(1_i128 << 100) | 1]), out);
+    }
+
+    #[test]
+    fn bit_xor_per_type() {
+        let out = plan(&BitXor, DataType::Int16)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([12_i16, -1, 5]),
+                &Array::from_iter([10_i16, 0, 5]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([6_i16, -1, 0]), out);
+
+        let out = plan(&BitXor, DataType::UInt64)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([u64::MAX, 12]),
+                &Array::from_iter([1_u64, 10]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([u64::MAX - 1, 6]), out);
+    }
+
+    #[test]
+    fn shift_left_per_type() {
+        let out = plan(&ShiftLeft, DataType::Int32)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([1_i32, 1, 1, -1]),
+                &Array::from_iter([4_i32, 31, 33, 1]),
+            ])
+            .unwrap();
+        // Shift amounts are masked modulo 32.
+        assert_eq!(Array::from_iter([16_i32, i32::MIN, 2, -2]), out);
+
+        let out = plan(&ShiftLeft, DataType::UInt8)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([1_u8, 0x81, 3]),
+                &Array::from_iter([7_u8, 1, 9]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([0x80_u8, 0x02, 6]), out);
+
+        let out = plan(&ShiftLeft, DataType::Int64)
+            .function_impl
+            .execute(&[&Array::from_iter([1_i64]), &Array::from_iter([-1_i64])])
+            .unwrap();
+        // -1 masked modulo 64 is 63.
+        assert_eq!(Array::from_iter([i64::MIN]), out);
+    }
+
+    #[test]
+    fn shift_right_per_type() {
+        let out = plan(&ShiftRight, DataType::Int32)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([16_i32, -16, 16]),
+                &Array::from_iter([2_i32, 2, 34]),
+            ])
+            .unwrap();
+        // Arithmetic shift for signed types, amount masked modulo 32.
+        assert_eq!(Array::from_iter([4_i32, -4, 4]), out);
+
+        let out = plan(&ShiftRight, DataType::UInt32)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([u32::MAX, 16]),
+                &Array::from_iter([31_u32, 32]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([1_u32, 16]), out);
+
+        let out = plan(&ShiftRight, DataType::UInt128)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([1_u128 << 127]),
+                &Array::from_iter([127_u128]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([1_u128]), out);
+    }
+
+    #[test]
+    fn bitwise_nulls() {
+        let out = plan(&BitAnd, DataType::Int32)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([Some(3_i32), None, Some(6)]),
+                &Array::from_iter([Some(1_i32), Some(1), None]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter([Some(1_i32), None, None]), out);
+    }
+
+    #[test]
+    fn bitwise_rejects_floats() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Float64, DataType::Float64],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        BitAnd
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+            )
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,297 @@
+use half::f16;
+use num_traits::Float;
+use rayexec_error::Result;
+
+use super::OverflowBehavior;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::DataType;
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::PhysicalStorage;
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::arrays::storage::PrimitiveStorage;
+
+/// Arithmetic that reports overflow instead of panicking.
+///
+/// The `overflowing_*` operations return the wrapped (two's complement) result
+/// along with a bool indicating if the operation overflowed, and the
+/// `saturating_*` operations clamp the result to the bounds of the type,
+/// mirroring the methods on the integer primitives. Floats never overflow.
+pub trait CheckedArith: Copy + Default {
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+    fn overflowing_neg(self) -> (Self, bool);
+    fn overflowing_abs(self) -> (Self, bool);
+
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn saturating_neg(self) -> Self;
+    fn saturating_abs(self) -> Self;
+}
+
+macro_rules! impl_checked_signed {
+    ($type:ty) => {
+        impl CheckedArith for $type {
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_add(self, rhs)
+            }
+
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_sub(self, rhs)
+            }
+
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_mul(self, rhs)
+            }
+
+            fn overflowing_neg(self) -> (Self, bool) {
+                <$type>::overflowing_neg(self)
+            }
+
+            fn overflowing_abs(self) -> (Self, bool) {
+                <$type>::overflowing_abs(self)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$type>::saturating_add(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$type>::saturating_sub(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$type>::saturating_mul(self, rhs)
+            }
+
+            fn saturating_neg(self) -> Self {
+                <$type>::saturating_neg(self)
+            }
+
+            fn saturating_abs(self) -> Self {
+                <$type>::saturating_abs(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_unsigned {
+    ($type:ty) => {
+        impl CheckedArith for $type {
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_add(self, rhs)
+            }
+
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_sub(self, rhs)
+            }
+
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                <$type>::overflowing_mul(self, rhs)
+            }
+
+            fn overflowing_neg(self) -> (Self, bool) {
+                <$type>::overflowing_neg(self)
+            }
+
+            fn overflowing_abs(self) -> (Self, bool) {
+                (self, false)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$type>::saturating_add(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$type>::saturating_sub(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$type>::saturating_mul(self, rhs)
+            }
+
+            fn saturating_neg(self) -> Self {
+                // Negating any unsigned value clamps to zero.
+                0
+            }
+
+            fn saturating_abs(self) -> Self {
+                self
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_float {
+    ($type:ty) => {
+        impl CheckedArith for $type {
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                (self + rhs, false)
+            }
+
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                (self - rhs, false)
+            }
+
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                (self * rhs, false)
+            }
+
+            fn overflowing_neg(self) -> (Self, bool) {
+                (-self, false)
+            }
+
+            fn overflowing_abs(self) -> (Self, bool) {
+                (Float::abs(self), false)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                self - rhs
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+
+            fn saturating_neg(self) -> Self {
+                -self
+            }
+
+            fn saturating_abs(self) -> Self {
+                Float::abs(self)
+            }
+        }
+    };
+}
+
+impl_checked_signed!(i8);
+impl_checked_signed!(i16);
+impl_checked_signed!(i32);
+impl_checked_signed!(i64);
+impl_checked_signed!(i128);
+impl_checked_unsigned!(u8);
+impl_checked_unsigned!(u16);
+impl_checked_unsigned!(u32);
+impl_checked_unsigned!(u64);
+impl_checked_unsigned!(u128);
+impl_checked_float!(f16);
+impl_checked_float!(f32);
+impl_checked_float!(f64);
+
+/// Execute a unary arithmetic operation, handling rows that overflow according
+/// to `overflow`.
+///
+/// `saturating` is used in place of `op` when saturating on overflow. `name` is
+/// only used for the error message.
+pub(crate) fn execute_checked_unary<'a, S>(
+    name: &str,
+    input: &'a Array,
+    datatype: DataType,
+    overflow: OverflowBehavior,
+    op: impl Fn(S::Type<'a>) -> (S::Type<'a>, bool),
+    saturating: impl Fn(S::Type<'a>) -> S::Type<'a>,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: CheckedArith,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(input.logical_len()),
+    };
+
+    if overflow == OverflowBehavior::Saturate {
+        return UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| buf.put(&saturating(v)));
+    }
+
+    let mut overflowed = Vec::new();
+    let out = UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| {
+        let (result, did_overflow) = op(v);
+        if did_overflow {
+            overflowed.push(buf.idx);
+        }
+        buf.put(&result)
+    })?;
+
+    overflow.apply(name, &[input], &overflowed, out)
+}
+
+/// Execute a binary arithmetic operation, handling rows that overflow
+/// according to `overflow`.
+///
+/// `saturating` is used in place of `op` when saturating on overflow. `name` is
+/// only used for the error message.
+pub(crate) fn execute_checked_binary<'a, S>(
+    name: &str,
+    a: &'a Array,
+    b: &'a Array,
+    datatype: DataType,
+    overflow: OverflowBehavior,
+    op: impl Fn(S::Type<'a>, S::Type<'a>) -> (S::Type<'a>, bool),
+    saturating: impl Fn(S::Type<'a>, S::Type<'a>) -> S::Type<'a>,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: CheckedArith,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(a.logical_len()),
+    };
+
+    if overflow == OverflowBehavior::Saturate {
+        return BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+            buf.put(&saturating(a, b))
+        });
+    }
+
+    let mut overflowed = Vec::new();
+    let out = BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+        let (result, did_overflow) = op(a, b);
+        if did_overflow {
+            overflowed.push(buf.idx);
+        }
+        buf.put(&result)
+    })?;
+
+    overflow.apply(name, &[a, b], &overflowed, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflowing_signed_min() {
+        assert_eq!((i8::MIN, true), CheckedArith::overflowing_neg(i8::MIN));
+        assert_eq!((i8::MIN, true), CheckedArith::overflowing_abs(i8::MIN));
+        assert_eq!((i8::MAX, false), CheckedArith::overflowing_neg(-i8::MAX));
+        assert_eq!((i8::MAX, true), CheckedArith::overflowing_sub(i8::MIN, 1));
+        assert_eq!((0_i8, true), CheckedArith::overflowing_mul(i8::MIN, 2));
+    }
+
+    #[test]
+    fn saturating_signed_min() {
+        assert_eq!(i8::MAX, CheckedArith::saturating_neg(i8::MIN));
+        assert_eq!(i8::MAX, CheckedArith::saturating_abs(i8::MIN));
+        assert_eq!(i8::MIN, CheckedArith::saturating_sub(i8::MIN, 1));
+        assert_eq!(i8::MIN, CheckedArith::saturating_mul(i8::MIN, 2));
+        assert_eq!(i8::MAX, CheckedArith::saturating_mul(i8::MIN, -2));
+    }
+
+    #[test]
+    fn overflowing_float_never_overflows() {
+        assert_eq!(
+            (f64::INFINITY, false),
+            CheckedArith::overflowing_mul(f64::MAX, 2.0)
+        );
+        assert_eq!((1.5_f32, false), CheckedArith::overflowing_abs(-1.5_f32));
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
 use crate::arrays::array::{Array, ArrayData};
 use crate::arrays::compute::cast::array::cast_decimal_to_float;
@@ -40,10 +40,6 @@ impl FunctionInfo for Div {
         "/"
     }
 
-    fn aliases(&self) -> &'static [&'static str] {
-        &["div"]
-    }
-
     fn signatures(&self) -> &[Signature] {
         const SIGS: &[Signature] = &[
             Signature::new_positional(
@@ -218,9 +214,25 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        BinaryExecutor::execute::<PhysicalF64, PhysicalF64, _, _>(&a, &b, builder, |a, b, buf| {
-            buf.put(&(a / b))
-        })
+        let mut div_by_zero = false;
+        let out = BinaryExecutor::execute::<PhysicalF64, PhysicalF64, _, _>(
+            &a,
+            &b,
+            builder,
+            |a, b, buf| {
+                if b == 0.0 {
+                    div_by_zero = true;
+                } else {
+                    buf.put(&(a / b))
+                }
+            },
+        )?;
+
+        if div_by_zero {
+            return Err(division_by_zero_error());
+        }
+
+        Ok(out)
     }
 }
 
@@ -242,7 +254,7 @@ impl<S> DivImpl<S> {
 impl<S> ScalarFunctionImpl for DivImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Div<Output = S::Type<'static>> + Default + Copy,
+    for<'a> S::Type<'a>: std::ops::Div<Output = S::Type<'static>> + Default + Copy + PartialEq,
     ArrayData: From<PrimitiveStorage<S::Type<'static>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
@@ -254,10 +266,27 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a / b)))
+        let mut div_by_zero = false;
+        let out = BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+            if b == Default::default() {
+                div_by_zero = true;
+            } else {
+                buf.put(&(a / b))
+            }
+        })?;
+
+        if div_by_zero {
+            return Err(division_by_zero_error());
+        }
+
+        Ok(out)
     }
 }
 
+pub(super) fn division_by_zero_error() -> RayexecError {
+    RayexecError::new("Division by zero")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,4 +320,33 @@ mod tests {
 
         assert_eq!(expected, out);
     }
+
+    #[test]
+    fn div_by_zero_errors() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int32, DataType::Int32],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let planned = Div
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+            )
+            .unwrap();
+
+        let a = Array::from_iter([4, 5]);
+        let b = Array::from_iter([1, 0]);
+        planned.function_impl.execute(&[&a, &b]).unwrap_err();
+
+        // Zero divisor for a NULL row is fine.
+        let a = Array::from_iter([Some(4), None]);
+        let b = Array::from_iter([1, 0]);
+        let out = planned.function_impl.execute(&[&a, &b]).unwrap();
+        assert_eq!(Array::from_iter([Some(4), None]), out);
+    }
 }
@@ -0,0 +1,444 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use rayexec_error::Result;
+
+use super::div::division_by_zero_error;
+use super::gcd::int_signatures;
+use super::OverflowBehavior;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalF64,
+    PhysicalI128,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI8,
+    PhysicalStorage,
+    PhysicalU128,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU8,
+};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+/// Numeric types supporting division rounded toward negative infinity.
+///
+/// `div` and `mod` satisfy `a = div(a, b) * b + mod(a, b)`, with the result of
+/// `mod` taking the sign of the divisor.
+///
+/// Both return None if the result doesn't fit in the type (e.g. `div(i32::MIN,
+/// -1)`). Division by zero is checked by the caller.
+pub trait FloorDivNumeric: Copy + Default + PartialEq {
+    fn checked_floor_div(self, other: Self) -> Option<Self>;
+    fn checked_floor_mod(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_floor_div_signed {
+    ($type:ty) => {
+        impl FloorDivNumeric for $type {
+            fn checked_floor_div(self, other: Self) -> Option<Self> {
+                let q = self.checked_div(other)?;
+                if self % other != 0 && ((self < 0) != (other < 0)) {
+                    Some(q - 1)
+                } else {
+                    Some(q)
+                }
+            }
+
+            fn checked_floor_mod(self, other: Self) -> Option<Self> {
+                // Wrapping to handle `MIN % -1`, which is always 0.
+                let r = self.wrapping_rem(other);
+                if r != 0 && ((r < 0) != (other < 0)) {
+                    Some(r + other)
+                } else {
+                    Some(r)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_floor_div_unsigned {
+    ($type:ty) => {
+        impl FloorDivNumeric for $type {
+            fn checked_floor_div(self, other: Self) -> Option<Self> {
+                self.checked_div(other)
+            }
+
+            fn checked_floor_mod(self, other: Self) -> Option<Self> {
+                self.checked_rem(other)
+            }
+        }
+    };
+}
+
+impl_floor_div_signed!(i8);
+impl_floor_div_signed!(i16);
+impl_floor_div_signed!(i32);
+impl_floor_div_signed!(i64);
+impl_floor_div_signed!(i128);
+impl_floor_div_unsigned!(u8);
+impl_floor_div_unsigned!(u16);
+impl_floor_div_unsigned!(u32);
+impl_floor_div_unsigned!(u64);
+impl_floor_div_unsigned!(u128);
+
+impl FloorDivNumeric for f64 {
+    fn checked_floor_div(self, other: Self) -> Option<Self> {
+        Some((self / other).floor())
+    }
+
+    fn checked_floor_mod(self, other: Self) -> Option<Self> {
+        Some(self - other * (self / other).floor())
+    }
+}
+
+/// Signatures for all integer types, as well as Float64.
+const fn floor_signatures(doc: &'static Documentation) -> [Signature; 11] {
+    let [i8, i16, i32, i64, i128, u8, u16, u32, u64, u128] = int_signatures(doc);
+    [
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        Signature {
+            positional_args: &[DataTypeId::Float64, DataTypeId::Float64],
+            variadic_arg: None,
+            return_type: DataTypeId::Float64,
+            doc: Some(doc),
+        },
+    ]
+}
+
+fn plan_floor_function<F>(
+    func: &F,
+    table_list: &TableList,
+    inputs: Vec<Expression>,
+    op: FloorOp,
+) -> Result<PlannedScalarFunction>
+where
+    F: ScalarFunction + Clone + 'static,
+{
+    plan_check_num_args(func, &inputs, 2)?;
+
+    let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) = match (
+        inputs[0].datatype(table_list)?,
+        inputs[1].datatype(table_list)?,
+    ) {
+        (DataType::Int8, DataType::Int8) => (
+            Box::new(FloorDivImpl::<PhysicalI8>::new(DataType::Int8, op)),
+            DataType::Int8,
+        ),
+        (DataType::Int16, DataType::Int16) => (
+            Box::new(FloorDivImpl::<PhysicalI16>::new(DataType::Int16, op)),
+            DataType::Int16,
+        ),
+        (DataType::Int32, DataType::Int32) => (
+            Box::new(FloorDivImpl::<PhysicalI32>::new(DataType::Int32, op)),
+            DataType::Int32,
+        ),
+        (DataType::Int64, DataType::Int64) => (
+            Box::new(FloorDivImpl::<PhysicalI64>::new(DataType::Int64, op)),
+            DataType::Int64,
+        ),
+        (DataType::Int128, DataType::Int128) => (
+            Box::new(FloorDivImpl::<PhysicalI128>::new(DataType::Int128, op)),
+            DataType::Int128,
+        ),
+        (DataType::UInt8, DataType::UInt8) => (
+            Box::new(FloorDivImpl::<PhysicalU8>::new(DataType::UInt8, op)),
+            DataType::UInt8,
+        ),
+        (DataType::UInt16, DataType::UInt16) => (
+            Box::new(FloorDivImpl::<PhysicalU16>::new(DataType::UInt16, op)),
+            DataType::UInt16,
+        ),
+        (DataType::UInt32, DataType::UInt32) => (
+            Box::new(FloorDivImpl::<PhysicalU32>::new(DataType::UInt32, op)),
+            DataType::UInt32,
+        ),
+        (DataType::UInt64, DataType::UInt64) => (
+            Box::new(FloorDivImpl::<PhysicalU64>::new(DataType::UInt64, op)),
+            DataType::UInt64,
+        ),
+        (DataType::UInt128, DataType::UInt128) => (
+            Box::new(FloorDivImpl::<PhysicalU128>::new(DataType::UInt128, op)),
+            DataType::UInt128,
+        ),
+        (DataType::Float64, DataType::Float64) => (
+            Box::new(FloorDivImpl::<PhysicalF64>::new(DataType::Float64, op)),
+            DataType::Float64,
+        ),
+        (a, b) => return Err(invalid_input_types_error(func, &[a, b])),
+    };
+
+    Ok(PlannedScalarFunction {
+        function: Box::new(func.clone()),
+        return_type,
+        inputs,
+        function_impl,
+    })
+}
+
+/// Division rounding toward negative infinity.
+///
+/// Also used for the `//` operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloorDiv;
+
+impl FunctionInfo for FloorDiv {
+    fn name(&self) -> &'static str {
+        "div"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &floor_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Divide two numbers, rounding the result toward negative infinity.",
+            arguments: &["a", "b"],
+            example: Some(Example {
+                example: "div(-7, 2)",
+                output: "-4",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for FloorDiv {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_floor_function(self, table_list, inputs, FloorOp::Div)
+    }
+}
+
+/// Modulo matching `div`, with the result taking the sign of the divisor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FloorMod;
+
+impl FunctionInfo for FloorMod {
+    fn name(&self) -> &'static str {
+        "mod"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &floor_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Compute the remainder of `div(a, b)`, taking the sign of the divisor.",
+            arguments: &["a", "b"],
+            example: Some(Example {
+                example: "mod(-7, 2)",
+                output: "1",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for FloorMod {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_floor_function(self, table_list, inputs, FloorOp::Mod)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FloorOp {
+    Div,
+    Mod,
+}
+
+impl FloorOp {
+    const fn name(&self) -> &'static str {
+        match self {
+            FloorOp::Div => "div",
+            FloorOp::Mod => "mod",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FloorDivImpl<S> {
+    datatype: DataType,
+    op: FloorOp,
+    overflow: OverflowBehavior,
+    _s: PhantomData<S>,
+}
+
+impl<S> FloorDivImpl<S> {
+    fn new(datatype: DataType, op: FloorOp) -> Self {
+        FloorDivImpl {
+            datatype,
+            op,
+            overflow: OverflowBehavior::default(),
+            _s: PhantomData,
+        }
+    }
+
+    /// Set the behavior for results that don't fit in the output type.
+    pub fn with_overflow_behavior(mut self, overflow: OverflowBehavior) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+impl<S> ScalarFunctionImpl for FloorDivImpl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: FloorDivNumeric,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: self.datatype.clone(),
+            buffer: PrimitiveBuffer::with_len(inputs[0].logical_len()),
+        };
+
+        let mut div_by_zero = false;
+        let mut overflowed = Vec::new();
+        let out =
+            BinaryExecutor::execute::<S, S, _, _>(inputs[0], inputs[1], builder, |a, b, buf| {
+                if b == Default::default() {
+                    div_by_zero = true;
+                    return;
+                }
+                let result = match self.op {
+                    FloorOp::Div => a.checked_floor_div(b),
+                    FloorOp::Mod => a.checked_floor_mod(b),
+                };
+                match result {
+                    Some(v) => buf.put(&v),
+                    None => overflowed.push(buf.idx),
+                }
+            })?;
+
+        if div_by_zero {
+            return Err(division_by_zero_error());
+        }
+
+        self.overflow
+            .apply(self.op.name(), inputs, &overflowed, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn plan_i32(func: &impl ScalarFunction) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int32, DataType::Int32],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        func.plan(
+            &table_list,
+            vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn floor_div_negative_operands() {
+        let a = Array::from_iter([7, -7, 7, -7, -6]);
+        let b = Array::from_iter([2, 2, -2, -2, 3]);
+
+        let out = plan_i32(&FloorDiv)
+            .function_impl
+            .execute(&[&a, &b])
+            .unwrap();
+        let expected = Array::from_iter([3, -4, -4, 3, -2]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn floor_mod_negative_operands() {
+        let a = Array::from_iter([7, -7, 7, -7, -6]);
+        let b = Array::from_iter([2, 2, -2, -2, 3]);
+
+        let out = plan_i32(&FloorMod)
+            .function_impl
+            .execute(&[&a, &b])
+            .unwrap();
+        let expected = Array::from_iter([1, 1, -1, -1, 0]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn division_identity() {
+        for a in -20_i64..=20 {
+            for b in [-7_i64, -3, -1, 1, 2, 5] {
+                let q = a.checked_floor_div(b).unwrap();
+                let r = a.checked_floor_mod(b).unwrap();
+                assert_eq!(a, q * b + r, "a: {a}, b: {b}");
+            }
+        }
+
+        for (a, b) in [(-7.5_f64, 2.0), (7.5, -2.0), (-7.0, -2.5)] {
+            let q = a.checked_floor_div(b).unwrap();
+            let r = a.checked_floor_mod(b).unwrap();
+            assert_eq!(a, q * b + r, "a: {a}, b: {b}");
+        }
+    }
+
+    #[test]
+    fn floor_div_by_zero_errors() {
+        let a = Array::from_iter([4, 5]);
+        let b = Array::from_iter([1, 0]);
+
+        plan_i32(&FloorDiv)
+            .function_impl
+            .execute(&[&a, &b])
+            .unwrap_err();
+        plan_i32(&FloorMod)
+            .function_impl
+            .execute(&[&a, &b])
+            .unwrap_err();
+    }
+
+    #[test]
+    fn floor_div_overflow() {
+        let a = Array::from_iter([i32::MIN]);
+        let b = Array::from_iter([-1]);
+
+        plan_i32(&FloorDiv)
+            .function_impl
+            .execute(&[&a, &b])
+            .unwrap_err();
+
+        let out = plan_i32(&FloorMod)
+            .function_impl
+            .execute(&[&a, &b])
+            .unwrap();
+        assert_eq!(Array::from_iter([0]), out);
+    }
+}
@@ -0,0 +1,533 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use rayexec_error::Result;
+
+use super::OverflowBehavior;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalI128,
+    PhysicalI16,
+    PhysicalI32,
+    PhysicalI64,
+    PhysicalI8,
+    PhysicalStorage,
+    PhysicalU128,
+    PhysicalU16,
+    PhysicalU32,
+    PhysicalU64,
+    PhysicalU8,
+};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{
+    PlannedScalarFunction,
+    ScalarFunction,
+    ScalarFunctionImpl,
+    ScalarPlanOptions,
+};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+/// Integer types that we can compute the greatest common divisor and least
+/// common multiple for.
+///
+/// Both operations use the absolute values of the inputs, and return None if
+/// the result doesn't fit in the type (e.g. `gcd(i32::MIN, 0)`).
+pub trait GcdInteger: Copy + Default {
+    fn checked_gcd(self, other: Self) -> Option<Self>;
+    fn checked_lcm(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_gcd_integer {
+    ($type:ty, $unsigned:ty, $abs:expr) => {
+        impl GcdInteger for $type {
+            fn checked_gcd(self, other: Self) -> Option<Self> {
+                let abs: fn($type) -> $unsigned = $abs;
+                let (mut a, mut b) = (abs(self), abs(other));
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                <$type>::try_from(a).ok()
+            }
+
+            fn checked_lcm(self, other: Self) -> Option<Self> {
+                let abs: fn($type) -> $unsigned = $abs;
+                let (a, b) = (abs(self), abs(other));
+                if a == 0 || b == 0 {
+                    return Some(0);
+                }
+                let (mut x, mut y) = (a, b);
+                while y != 0 {
+                    (x, y) = (y, x % y);
+                }
+                let lcm = (a / x).checked_mul(b)?;
+                <$type>::try_from(lcm).ok()
+            }
+        }
+    };
+}
+
+impl_gcd_integer!(i8, u8, i8::unsigned_abs);
+impl_gcd_integer!(i16, u16, i16::unsigned_abs);
+impl_gcd_integer!(i32, u32, i32::unsigned_abs);
+impl_gcd_integer!(i64, u64, i64::unsigned_abs);
+impl_gcd_integer!(i128, u128, i128::unsigned_abs);
+impl_gcd_integer!(u8, u8, |v| v);
+impl_gcd_integer!(u16, u16, |v| v);
+impl_gcd_integer!(u32, u32, |v| v);
+impl_gcd_integer!(u64, u64, |v| v);
+impl_gcd_integer!(u128, u128, |v| v);
+
+/// Signatures for a binary function accepting two integers of the same type.
+pub(super) const fn int_signatures(doc: &'static Documentation) -> [Signature; 10] {
+    const fn sig(
+        positional_args: &'static [DataTypeId],
+        return_type: DataTypeId,
+        doc: &'static Documentation,
+    ) -> Signature {
+        Signature {
+            positional_args,
+            variadic_arg: None,
+            return_type,
+            doc: Some(doc),
+        }
+    }
+
+    [
+        sig(&[DataTypeId::Int8, DataTypeId::Int8], DataTypeId::Int8, doc),
+        sig(
+            &[DataTypeId::Int16, DataTypeId::Int16],
+            DataTypeId::Int16,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::Int32, DataTypeId::Int32],
+            DataTypeId::Int32,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::Int64, DataTypeId::Int64],
+            DataTypeId::Int64,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::Int128, DataTypeId::Int128],
+            DataTypeId::Int128,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::UInt8, DataTypeId::UInt8],
+            DataTypeId::UInt8,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::UInt16, DataTypeId::UInt16],
+            DataTypeId::UInt16,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::UInt32, DataTypeId::UInt32],
+            DataTypeId::UInt32,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::UInt64, DataTypeId::UInt64],
+            DataTypeId::UInt64,
+            doc,
+        ),
+        sig(
+            &[DataTypeId::UInt128, DataTypeId::UInt128],
+            DataTypeId::UInt128,
+            doc,
+        ),
+    ]
+}
+
+/// Plan either gcd or lcm for the matched integer input types.
+fn plan_integer_function<F>(
+    func: &F,
+    table_list: &TableList,
+    inputs: Vec<Expression>,
+    op: IntegerOp,
+    overflow: OverflowBehavior,
+) -> Result<PlannedScalarFunction>
+where
+    F: ScalarFunction + Clone + 'static,
+{
+    plan_check_num_args(func, &inputs, 2)?;
+
+    let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) = match (
+        inputs[0].datatype(table_list)?,
+        inputs[1].datatype(table_list)?,
+    ) {
+        (DataType::Int8, DataType::Int8) => (
+            Box::new(GcdLcmImpl::<PhysicalI8>::new(DataType::Int8, op, overflow)),
+            DataType::Int8,
+        ),
+        (DataType::Int16, DataType::Int16) => (
+            Box::new(GcdLcmImpl::<PhysicalI16>::new(
+                DataType::Int16,
+                op,
+                overflow,
+            )),
+            DataType::Int16,
+        ),
+        (DataType::Int32, DataType::Int32) => (
+            Box::new(GcdLcmImpl::<PhysicalI32>::new(
+                DataType::Int32,
+                op,
+                overflow,
+            )),
+            DataType::Int32,
+        ),
+        (DataType::Int64, DataType::Int64) => (
+            Box::new(GcdLcmImpl::<PhysicalI64>::new(
+                DataType::Int64,
+                op,
+                overflow,
+            )),
+            DataType::Int64,
+        ),
+        (DataType::Int128, DataType::Int128) => (
+            Box::new(GcdLcmImpl::<PhysicalI128>::new(
+                DataType::Int128,
+                op,
+                overflow,
+            )),
+            DataType::Int128,
+        ),
+        (DataType::UInt8, DataType::UInt8) => (
+            Box::new(GcdLcmImpl::<PhysicalU8>::new(DataType::UInt8, op, overflow)),
+            DataType::UInt8,
+        ),
+        (DataType::UInt16, DataType::UInt16) => (
+            Box::new(GcdLcmImpl::<PhysicalU16>::new(
+                DataType::UInt16,
+                op,
+                overflow,
+            )),
+            DataType::UInt16,
+        ),
+        (DataType::UInt32, DataType::UInt32) => (
+            Box::new(GcdLcmImpl::<PhysicalU32>::new(
+                DataType::UInt32,
+                op,
+                overflow,
+            )),
+            DataType::UInt32,
+        ),
+        (DataType::UInt64, DataType::UInt64) => (
+            Box::new(GcdLcmImpl::<PhysicalU64>::new(
+                DataType::UInt64,
+                op,
+                overflow,
+            )),
+            DataType::UInt64,
+        ),
+        (DataType::UInt128, DataType::UInt128) => (
+            Box::new(GcdLcmImpl::<PhysicalU128>::new(
+                DataType::UInt128,
+                op,
+                overflow,
+            )),
+            DataType::UInt128,
+        ),
+        (a, b) => return Err(invalid_input_types_error(func, &[a, b])),
+    };
+
+    Ok(PlannedScalarFunction {
+        function: Box::new(func.clone()),
+        return_type,
+        inputs,
+        function_impl,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gcd;
+
+impl FunctionInfo for Gcd {
+    fn name(&self) -> &'static str {
+        "gcd"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["greatest_common_divisor"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &int_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Compute the greatest common divisor of two integers.",
+            arguments: &["a", "b"],
+            example: Some(Example {
+                example: "gcd(12, 18)",
+                output: "6",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for Gcd {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_options(table_list, inputs, ScalarPlanOptions::default())
+    }
+
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        options: ScalarPlanOptions,
+    ) -> Result<PlannedScalarFunction> {
+        plan_integer_function(self, table_list, inputs, IntegerOp::Gcd, options.overflow)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lcm;
+
+impl FunctionInfo for Lcm {
+    fn name(&self) -> &'static str {
+        "lcm"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["least_common_multiple"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const SIGS: &[Signature] = &int_signatures(&Documentation {
+            category: Category::Numeric,
+            description: "Compute the least common multiple of two integers.",
+            arguments: &["a", "b"],
+            example: Some(Example {
+                example: "lcm(4, 6)",
+                output: "12",
+            }),
+        });
+        SIGS
+    }
+}
+
+impl ScalarFunction for Lcm {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_options(table_list, inputs, ScalarPlanOptions::default())
+    }
+
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        options: ScalarPlanOptions,
+    ) -> Result<PlannedScalarFunction> {
+        plan_integer_function(self, table_list, inputs, IntegerOp::Lcm, options.overflow)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntegerOp {
+    Gcd,
+    Lcm,
+}
+
+impl IntegerOp {
+    const fn name(&self) -> &'static str {
+        match self {
+            IntegerOp::Gcd => "gcd",
+            IntegerOp::Lcm => "lcm",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GcdLcmImpl<S> {
+    datatype: DataType,
+    op: IntegerOp,
+    overflow: OverflowBehavior,
+    _s: PhantomData<S>,
+}
+
+impl<S> GcdLcmImpl<S> {
+    fn new(datatype: DataType, op: IntegerOp, overflow: OverflowBehavior) -> Self {
+        GcdLcmImpl {
+            datatype,
+            op,
+            overflow,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunctionImpl for GcdLcmImpl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: GcdInteger,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let mut overflowed = Vec::new();
+        let out = gcd_lcm::<S>(
+            inputs[0],
+            inputs[1],
+            self.datatype.clone(),
+            self.op,
+            &mut overflowed,
+        )?;
+
+        self.overflow
+            .apply(self.op.name(), inputs, &overflowed, out)
+    }
+
+    fn output_nullable(&self, inputs: &[bool]) -> Option<bool> {
+        // Overflowing may produce NULLs from non-NULL inputs.
+        Some(self.overflow == OverflowBehavior::Null || inputs.iter().any(|n| *n))
+    }
+}
+
+fn gcd_lcm<'a, S>(
+    a: &'a Array,
+    b: &'a Array,
+    datatype: DataType,
+    op: IntegerOp,
+    overflowed: &mut Vec<usize>,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: GcdInteger,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(a.logical_len()),
+    };
+
+    BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+        let result = match op {
+            IntegerOp::Gcd => a.checked_gcd(b),
+            IntegerOp::Lcm => a.checked_lcm(b),
+        };
+        match result {
+            Some(v) => buf.put(&v),
+            None => overflowed.push(buf.idx),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn plan_i32(func: &impl ScalarFunction) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int32, DataType::Int32],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        func.plan(
+            &table_list,
+            vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn gcd_i32() {
+        // Coprime, shared factor, zero operands, negatives.
+        let a = Array::from_iter([7, 12, 0, 0, -12]);
+        let b = Array::from_iter([9, 18, 5, 0, 18]);
+
+        let planned = plan_i32(&Gcd);
+        let out = planned.function_impl.execute(&[&a, &b]).unwrap();
+        let expected = Array::from_iter([1, 6, 5, 0, 6]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn lcm_i32() {
+        // Coprime, shared factor, zero operands, negatives.
+        let a = Array::from_iter([7, 4, 0, 0, -4]);
+        let b = Array::from_iter([9, 6, 5, 0, 6]);
+
+        let planned = plan_i32(&Lcm);
+        let out = planned.function_impl.execute(&[&a, &b]).unwrap();
+        let expected = Array::from_iter([63, 12, 0, 0, 12]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn lcm_i32_overflow_error() {
+        let a = Array::from_iter([4, 2_147_483_647]);
+        let b = Array::from_iter([6, 2_147_483_646]);
+
+        let planned = plan_i32(&Lcm);
+        planned.function_impl.execute(&[&a, &b]).unwrap_err();
+    }
+
+    #[test]
+    fn lcm_i32_overflow_null() {
+        let a = Array::from_iter([4, 2_147_483_647]);
+        let b = Array::from_iter([6, 2_147_483_646]);
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int32, DataType::Int32],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let planned = Lcm
+            .plan_with_options(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+                ScalarPlanOptions {
+                    overflow: OverflowBehavior::Null,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let out = planned.function_impl.execute(&[&a, &b]).unwrap();
+        let expected = Array::from_iter([Some(12), None]);
+
+        assert_eq!(expected, out);
+        assert!(planned.output_nullable(&[false, false]));
+    }
+
+    #[test]
+    fn lcm_i32_overflow_error_not_nullable() {
+        let planned = plan_i32(&Lcm);
+        assert!(!planned.output_nullable(&[false, false]));
+        assert!(planned.output_nullable(&[true, false]));
+    }
+
+    #[test]
+    fn gcd_i32_min_overflow() {
+        let a = Array::from_iter([i32::MIN]);
+        let b = Array::from_iter([0]);
+
+        let planned = plan_i32(&Gcd);
+        planned.function_impl.execute(&[&a, &b]).unwrap_err();
+    }
+}
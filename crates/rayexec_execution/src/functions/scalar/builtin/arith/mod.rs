@@ -12,3 +12,18 @@ pub use mul::*;
 
 mod rem;
 pub use rem::*;
+
+mod floor_div;
+pub use floor_div::*;
+
+mod gcd;
+pub use gcd::*;
+
+mod bitwise;
+pub use bitwise::*;
+
+mod overflow;
+pub use overflow::*;
+
+mod checked;
+pub use checked::*;
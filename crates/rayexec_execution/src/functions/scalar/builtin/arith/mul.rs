@@ -4,6 +4,7 @@ use std::marker::PhantomData;
 use num_traits::{NumCast, PrimInt};
 use rayexec_error::Result;
 
+use super::{execute_checked_binary, CheckedArith, OverflowBehavior};
 use crate::arrays::array::{Array, ArrayData};
 use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
@@ -29,7 +30,12 @@ use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType}
 use crate::arrays::scalar::interval::Interval;
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
-use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::scalar::{
+    PlannedScalarFunction,
+    ScalarFunction,
+    ScalarFunctionImpl,
+    ScalarPlanOptions,
+};
 use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
 
@@ -99,6 +105,10 @@ impl FunctionInfo for Mul {
                 &[DataTypeId::Decimal64, DataTypeId::Decimal64],
                 DataTypeId::Decimal64,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal128, DataTypeId::Decimal128],
+                DataTypeId::Decimal128,
+            ),
         ];
         SIGS
     }
@@ -109,6 +119,15 @@ impl ScalarFunction for Mul {
         &self,
         table_list: &TableList,
         inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_options(table_list, inputs, ScalarPlanOptions::default())
+    }
+
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        options: ScalarPlanOptions,
     ) -> Result<PlannedScalarFunction> {
         plan_check_num_args(self, &inputs, 2)?;
 
@@ -117,55 +136,91 @@ impl ScalarFunction for Mul {
             inputs[1].datatype(table_list)?,
         ) {
             (DataType::Float16, DataType::Float16) => (
-                Box::new(MulImpl::<PhysicalF16>::new(DataType::Float16)),
+                Box::new(MulImpl::<PhysicalF16>::new(
+                    DataType::Float16,
+                    options.overflow,
+                )),
                 DataType::Float16,
             ),
             (DataType::Float32, DataType::Float32) => (
-                Box::new(MulImpl::<PhysicalF32>::new(DataType::Float32)),
+                Box::new(MulImpl::<PhysicalF32>::new(
+                    DataType::Float32,
+                    options.overflow,
+                )),
                 DataType::Float32,
             ),
             (DataType::Float64, DataType::Float64) => (
-                Box::new(MulImpl::<PhysicalF64>::new(DataType::Float64)),
+                Box::new(MulImpl::<PhysicalF64>::new(
+                    DataType::Float64,
+                    options.overflow,
+                )),
                 DataType::Float64,
             ),
             (DataType::Int8, DataType::Int8) => (
-                Box::new(MulImpl::<PhysicalI8>::new(DataType::Int8)),
+                Box::new(MulImpl::<PhysicalI8>::new(DataType::Int8, options.overflow)),
                 DataType::Int8,
             ),
             (DataType::Int16, DataType::Int16) => (
-                Box::new(MulImpl::<PhysicalI16>::new(DataType::Int16)),
+                Box::new(MulImpl::<PhysicalI16>::new(
+                    DataType::Int16,
+                    options.overflow,
+                )),
                 DataType::Int16,
             ),
             (DataType::Int32, DataType::Int32) => (
-                Box::new(MulImpl::<PhysicalI32>::new(DataType::Int32)),
+                Box::new(MulImpl::<PhysicalI32>::new(
+                    DataType::Int32,
+                    options.overflow,
+                )),
                 DataType::Int32,
             ),
             (DataType::Int64, DataType::Int64) => (
-                Box::new(MulImpl::<PhysicalI64>::new(DataType::Int64)),
+                Box::new(MulImpl::<PhysicalI64>::new(
+                    DataType::Int64,
+                    options.overflow,
+                )),
                 DataType::Int64,
             ),
             (DataType::Int128, DataType::Int128) => (
-                Box::new(MulImpl::<PhysicalI128>::new(DataType::Int128)),
+                Box::new(MulImpl::<PhysicalI128>::new(
+                    DataType::Int128,
+                    options.overflow,
+                )),
                 DataType::Int128,
             ),
             (DataType::UInt8, DataType::UInt8) => (
-                Box::new(MulImpl::<PhysicalU8>::new(DataType::UInt8)),
+                Box::new(MulImpl::<PhysicalU8>::new(
+                    DataType::UInt8,
+                    options.overflow,
+                )),
                 DataType::UInt8,
             ),
             (DataType::UInt16, DataType::UInt16) => (
-                Box::new(MulImpl::<PhysicalU16>::new(DataType::UInt16)),
+                Box::new(MulImpl::<PhysicalU16>::new(
+                    DataType::UInt16,
+                    options.overflow,
+                )),
                 DataType::UInt16,
             ),
             (DataType::UInt32, DataType::UInt32) => (
-                Box::new(MulImpl::<PhysicalU32>::new(DataType::UInt32)),
+                Box::new(MulImpl::<PhysicalU32>::new(
+                    DataType::UInt32,
+                    options.overflow,
+                )),
                 DataType::UInt32,
             ),
             (DataType::UInt64, DataType::UInt64) => (
-                Box::new(MulImpl::<PhysicalU64>::new(DataType::UInt64)),
+                Box::new(MulImpl::<PhysicalU64>::new(
+                    DataType::UInt64,
+                    options.overflow,
+                )),
                 DataType::UInt64,
             ),
             (DataType::UInt128, DataType::UInt128) => (
-                Box::new(MulImpl::<PhysicalU128>::new(DataType::UInt128)),
+                Box::new(MulImpl::<PhysicalU128>::new(
+                    DataType::UInt128,
+                    options.overflow,
+                )),
                 DataType::UInt128,
             ),
 
@@ -178,7 +233,10 @@ impl ScalarFunction for Mul {
                 let scale = a.scale + b.scale;
                 let return_type = DataType::Decimal64(DecimalTypeMeta { precision, scale });
                 (
-                    Box::new(DecimalMulImpl::<Decimal64Type>::new(return_type.clone())),
+                    Box::new(DecimalMulImpl::<Decimal64Type>::new(
+                        return_type.clone(),
+                        options.overflow,
+                    )),
                     return_type,
                 )
             }
@@ -187,7 +245,10 @@ impl ScalarFunction for Mul {
                 let scale = a.scale + b.scale;
                 let return_type = DataType::Decimal128(DecimalTypeMeta { precision, scale });
                 (
-                    Box::new(DecimalMulImpl::<Decimal128Type>::new(return_type.clone())),
+                    Box::new(DecimalMulImpl::<Decimal128Type>::new(
+                        return_type.clone(),
+                        options.overflow,
+                    )),
                     return_type,
                 )
             }
@@ -265,13 +326,15 @@ where
 #[derive(Debug, Clone)]
 pub struct DecimalMulImpl<D> {
     datatype: DataType,
+    overflow: OverflowBehavior,
     _d: PhantomData<D>,
 }
 
 impl<D> DecimalMulImpl<D> {
-    fn new(datatype: DataType) -> Self {
+    fn new(datatype: DataType, overflow: OverflowBehavior) -> Self {
         DecimalMulImpl {
             datatype,
+            overflow,
             _d: PhantomData,
         }
     }
@@ -280,33 +343,39 @@ impl<D> DecimalMulImpl<D> {
 impl<D> ScalarFunctionImpl for DecimalMulImpl<D>
 where
     D: DecimalType,
+    D::Primitive: CheckedArith,
     ArrayData: From<PrimitiveStorage<D::Primitive>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        let a = inputs[0];
-        let b = inputs[1];
-
-        let builder = ArrayBuilder {
-            datatype: self.datatype.clone(),
-            buffer: PrimitiveBuffer::<D::Primitive>::with_len(a.logical_len()),
-        };
+        execute_checked_binary::<D::Storage>(
+            "*",
+            inputs[0],
+            inputs[1],
+            self.datatype.clone(),
+            self.overflow,
+            CheckedArith::overflowing_mul,
+            CheckedArith::saturating_mul,
+        )
+    }
 
-        BinaryExecutor::execute::<D::Storage, D::Storage, _, _>(a, b, builder, |a, b, buf| {
-            buf.put(&(a * b))
-        })
+    fn output_nullable(&self, inputs: &[bool]) -> Option<bool> {
+        // Overflowing may produce NULLs from non-NULL inputs.
+        Some(self.overflow == OverflowBehavior::Null || inputs.iter().any(|n| *n))
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct MulImpl<S> {
     datatype: DataType,
+    overflow: OverflowBehavior,
     _s: PhantomData<S>,
 }
 
 impl<S> MulImpl<S> {
-    fn new(datatype: DataType) -> Self {
+    fn new(datatype: DataType, overflow: OverflowBehavior) -> Self {
         MulImpl {
             datatype,
+            overflow,
             _s: PhantomData,
         }
     }
@@ -315,19 +384,24 @@ impl<S> MulImpl<S> {
 impl<S> ScalarFunctionImpl for MulImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Mul<Output = S::Type<'static>> + Default + Copy,
-    ArrayData: From<PrimitiveStorage<S::Type<'static>>>,
+    for<'a> S::Type<'a>: CheckedArith,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        let a = inputs[0];
-        let b = inputs[1];
-
-        let builder = ArrayBuilder {
-            datatype: self.datatype.clone(),
-            buffer: PrimitiveBuffer::with_len(a.logical_len()),
-        };
+        execute_checked_binary::<S>(
+            "*",
+            inputs[0],
+            inputs[1],
+            self.datatype.clone(),
+            self.overflow,
+            CheckedArith::overflowing_mul,
+            CheckedArith::saturating_mul,
+        )
+    }
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a * b)))
+    fn output_nullable(&self, inputs: &[bool]) -> Option<bool> {
+        // Overflowing may produce NULLs from non-NULL inputs.
+        Some(self.overflow == OverflowBehavior::Null || inputs.iter().any(|n| *n))
     }
 }
 
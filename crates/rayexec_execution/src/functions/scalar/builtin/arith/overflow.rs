@@ -0,0 +1,114 @@
+use std::str::FromStr;
+
+use rayexec_error::{RayexecError, Result};
+use rayexec_proto::ProtoConv;
+
+use crate::arrays::array::Array;
+
+/// Behavior when an arithmetic operation produces a value that doesn't fit in
+/// the output type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowBehavior {
+    /// Return an error.
+    #[default]
+    Error,
+    /// Keep the wrapped (two's complement) result.
+    ///
+    /// The operation is responsible for writing the wrapped value for rows
+    /// that overflowed.
+    Wrap,
+    /// Clamp the result to the minimum or maximum value of the type.
+    ///
+    /// The operation is responsible for writing the clamped value for rows
+    /// that overflowed.
+    Saturate,
+    /// Use a NULL value.
+    Null,
+}
+
+impl OverflowBehavior {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            OverflowBehavior::Error => "error",
+            OverflowBehavior::Wrap => "wrap",
+            OverflowBehavior::Saturate => "saturate",
+            OverflowBehavior::Null => "null",
+        }
+    }
+
+    /// Apply this behavior to an output array given the rows that overflowed.
+    ///
+    /// `func` and `inputs` are only used for producing the error message.
+    pub(crate) fn apply(
+        &self,
+        func: &str,
+        inputs: &[&Array],
+        overflowed: &[usize],
+        mut output: Array,
+    ) -> Result<Array> {
+        let first = match overflowed.first() {
+            Some(&idx) => idx,
+            None => return Ok(output),
+        };
+
+        match self {
+            OverflowBehavior::Error => {
+                let args = inputs
+                    .iter()
+                    .map(|arr| arr.logical_value(first).map(|v| v.to_string()))
+                    .collect::<Result<Vec<_>>>()?;
+
+                Err(RayexecError::new(format!(
+                    "Overflow computing {func}({}) for type {}",
+                    args.join(", "),
+                    output.datatype(),
+                )))
+            }
+            OverflowBehavior::Null => {
+                for &idx in overflowed {
+                    output.set_physical_validity(idx, false);
+                }
+                Ok(output)
+            }
+            OverflowBehavior::Wrap | OverflowBehavior::Saturate => Ok(output),
+        }
+    }
+}
+
+impl FromStr for OverflowBehavior {
+    type Err = RayexecError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => OverflowBehavior::Error,
+            "wrap" => OverflowBehavior::Wrap,
+            "saturate" => OverflowBehavior::Saturate,
+            "null" => OverflowBehavior::Null,
+            other => return Err(RayexecError::new(format!(
+                "Invalid overflow behavior: '{other}', expected one of 'error', 'wrap', 'saturate', or 'null'"
+            ))),
+        })
+    }
+}
+
+impl ProtoConv for OverflowBehavior {
+    type ProtoType = rayexec_proto::generated::expr::OverflowBehavior;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::Error => Self::ProtoType::OverflowError,
+            Self::Wrap => Self::ProtoType::OverflowWrap,
+            Self::Saturate => Self::ProtoType::OverflowSaturate,
+            Self::Null => Self::ProtoType::OverflowNull,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidOverflowBehavior => return Err(RayexecError::new("invalid")),
+            Self::ProtoType::OverflowError => Self::Error,
+            Self::ProtoType::OverflowWrap => Self::Wrap,
+            Self::ProtoType::OverflowSaturate => Self::Saturate,
+            Self::ProtoType::OverflowNull => Self::Null,
+        })
+    }
+}
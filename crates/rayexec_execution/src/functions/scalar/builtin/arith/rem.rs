@@ -38,7 +38,7 @@ impl FunctionInfo for Rem {
     }
 
     fn aliases(&self) -> &'static [&'static str] {
-        &["rem", "mod"]
+        &["rem"]
     }
 
     fn signatures(&self) -> &[Signature] {
@@ -1,10 +1,12 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
+use super::{execute_checked_binary, CheckedArith, OverflowBehavior};
 use crate::arrays::array::{Array, ArrayData};
-use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::compute::date::{timestamp_add_interval, timestamp_diff};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::physical_type::{
     PhysicalF16,
@@ -15,6 +17,7 @@ use crate::arrays::executor::physical_type::{
     PhysicalI32,
     PhysicalI64,
     PhysicalI8,
+    PhysicalInterval,
     PhysicalStorage,
     PhysicalU128,
     PhysicalU16,
@@ -23,9 +26,15 @@ use crate::arrays::executor::physical_type::{
     PhysicalU8,
 };
 use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::interval::Interval;
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
-use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::scalar::{
+    PlannedScalarFunction,
+    ScalarFunction,
+    ScalarFunctionImpl,
+    ScalarPlanOptions,
+};
 use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
 
@@ -81,6 +90,14 @@ impl FunctionInfo for Sub {
                 DataTypeId::UInt128,
             ),
             Signature::new_positional(&[DataTypeId::Date32, DataTypeId::Int32], DataTypeId::Date32),
+            Signature::new_positional(
+                &[DataTypeId::Timestamp, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Timestamp, DataTypeId::Timestamp],
+                DataTypeId::Interval,
+            ),
             // TODO
             // Signature {
             //     input: &[DataTypeId::Interval, DataTypeId::Int64],
@@ -105,6 +122,15 @@ impl ScalarFunction for Sub {
         &self,
         table_list: &TableList,
         inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_options(table_list, inputs, ScalarPlanOptions::default())
+    }
+
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        options: ScalarPlanOptions,
     ) -> Result<PlannedScalarFunction> {
         plan_check_num_args(self, &inputs, 2)?;
 
@@ -113,75 +139,133 @@ impl ScalarFunction for Sub {
             inputs[1].datatype(table_list)?,
         ) {
             (DataType::Float16, DataType::Float16) => (
-                Box::new(SubImpl::<PhysicalF16>::new(DataType::Float16)),
+                Box::new(SubImpl::<PhysicalF16>::new(
+                    DataType::Float16,
+                    options.overflow,
+                )),
                 DataType::Float16,
             ),
             (DataType::Float32, DataType::Float32) => (
-                Box::new(SubImpl::<PhysicalF32>::new(DataType::Float32)),
+                Box::new(SubImpl::<PhysicalF32>::new(
+                    DataType::Float32,
+                    options.overflow,
+                )),
                 DataType::Float32,
             ),
             (DataType::Float64, DataType::Float64) => (
-                Box::new(SubImpl::<PhysicalF64>::new(DataType::Float64)),
+                Box::new(SubImpl::<PhysicalF64>::new(
+                    DataType::Float64,
+                    options.overflow,
+                )),
                 DataType::Float64,
             ),
             (DataType::Int8, DataType::Int8) => (
-                Box::new(SubImpl::<PhysicalI8>::new(DataType::Int8)),
+                Box::new(SubImpl::<PhysicalI8>::new(DataType::Int8, options.overflow)),
                 DataType::Int8,
             ),
             (DataType::Int16, DataType::Int16) => (
-                Box::new(SubImpl::<PhysicalI16>::new(DataType::Int16)),
+                Box::new(SubImpl::<PhysicalI16>::new(
+                    DataType::Int16,
+                    options.overflow,
+                )),
                 DataType::Int16,
             ),
             (DataType::Int32, DataType::Int32) => (
-                Box::new(SubImpl::<PhysicalI32>::new(DataType::Int32)),
+                Box::new(SubImpl::<PhysicalI32>::new(
+                    DataType::Int32,
+                    options.overflow,
+                )),
                 DataType::Int32,
             ),
             (DataType::Int64, DataType::Int64) => (
-                Box::new(SubImpl::<PhysicalI64>::new(DataType::Int64)),
+                Box::new(SubImpl::<PhysicalI64>::new(
+                    DataType::Int64,
+                    options.overflow,
+                )),
                 DataType::Int64,
             ),
             (DataType::Int128, DataType::Int128) => (
-                Box::new(SubImpl::<PhysicalI128>::new(DataType::Int128)),
+                Box::new(SubImpl::<PhysicalI128>::new(
+                    DataType::Int128,
+                    options.overflow,
+                )),
                 DataType::Int128,
             ),
             (DataType::UInt8, DataType::UInt8) => (
-                Box::new(SubImpl::<PhysicalU8>::new(DataType::UInt8)),
+                Box::new(SubImpl::<PhysicalU8>::new(
+                    DataType::UInt8,
+                    options.overflow,
+                )),
                 DataType::UInt8,
             ),
             (DataType::UInt16, DataType::UInt16) => (
-                Box::new(SubImpl::<PhysicalU16>::new(DataType::UInt16)),
+                Box::new(SubImpl::<PhysicalU16>::new(
+                    DataType::UInt16,
+                    options.overflow,
+                )),
                 DataType::UInt16,
             ),
             (DataType::UInt32, DataType::UInt32) => (
-                Box::new(SubImpl::<PhysicalU32>::new(DataType::UInt32)),
+                Box::new(SubImpl::<PhysicalU32>::new(
+                    DataType::UInt32,
+                    options.overflow,
+                )),
                 DataType::UInt32,
             ),
             (DataType::UInt64, DataType::UInt64) => (
-                Box::new(SubImpl::<PhysicalU64>::new(DataType::UInt64)),
+                Box::new(SubImpl::<PhysicalU64>::new(
+                    DataType::UInt64,
+                    options.overflow,
+                )),
                 DataType::UInt64,
             ),
             (DataType::UInt128, DataType::UInt128) => (
-                Box::new(SubImpl::<PhysicalU128>::new(DataType::UInt128)),
+                Box::new(SubImpl::<PhysicalU128>::new(
+                    DataType::UInt128,
+                    options.overflow,
+                )),
                 DataType::UInt128,
             ),
 
             // TODO: Split out decimal (for scaling)
             datatypes @ (DataType::Decimal64(_), DataType::Decimal64(_)) => (
-                Box::new(SubImpl::<PhysicalI64>::new(datatypes.0.clone())),
+                Box::new(SubImpl::<PhysicalI64>::new(
+                    datatypes.0.clone(),
+                    options.overflow,
+                )),
                 datatypes.0,
             ),
             datatypes @ (DataType::Decimal128(_), DataType::Decimal128(_)) => (
-                Box::new(SubImpl::<PhysicalI128>::new(datatypes.0.clone())),
+                Box::new(SubImpl::<PhysicalI128>::new(
+                    datatypes.0.clone(),
+                    options.overflow,
+                )),
                 datatypes.0,
             ),
 
             // Date + days
             (DataType::Date32, DataType::Int32) => (
-                Box::new(SubImpl::<PhysicalI32>::new(DataType::Date32)),
+                Box::new(SubImpl::<PhysicalI32>::new(
+                    DataType::Date32,
+                    options.overflow,
+                )),
                 DataType::Date32,
             ),
 
-            // TODO: Interval
+            // Timestamp - interval
+            (DataType::Timestamp(m), DataType::Interval) => (
+                Box::new(TimestampIntervalSubImpl { unit: m.unit }),
+                DataType::Timestamp(m),
+            ),
+            // Timestamp - timestamp
+            (DataType::Timestamp(a), DataType::Timestamp(b)) => (
+                Box::new(TimestampSubImpl {
+                    lhs_unit: a.unit,
+                    rhs_unit: b.unit,
+                }),
+                DataType::Interval,
+            ),
+
             (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
         };
 
@@ -197,13 +281,15 @@ impl ScalarFunction for Sub {
 #[derive(Debug, Clone)]
 pub struct SubImpl<S> {
     datatype: DataType,
+    overflow: OverflowBehavior,
     _s: PhantomData<S>,
 }
 
 impl<S> SubImpl<S> {
-    fn new(datatype: DataType) -> Self {
+    fn new(datatype: DataType, overflow: OverflowBehavior) -> Self {
         SubImpl {
             datatype,
+            overflow,
             _s: PhantomData,
         }
     }
@@ -212,26 +298,109 @@ impl<S> SubImpl<S> {
 impl<S> ScalarFunctionImpl for SubImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Sub<Output = S::Type<'static>> + Default + Copy,
-    ArrayData: From<PrimitiveStorage<S::Type<'static>>>,
+    for<'a> S::Type<'a>: CheckedArith,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        let a = inputs[0];
-        let b = inputs[1];
+        execute_checked_binary::<S>(
+            "-",
+            inputs[0],
+            inputs[1],
+            self.datatype.clone(),
+            self.overflow,
+            CheckedArith::overflowing_sub,
+            CheckedArith::saturating_sub,
+        )
+    }
+
+    fn output_nullable(&self, inputs: &[bool]) -> Option<bool> {
+        // Overflowing may produce NULLs from non-NULL inputs.
+        Some(self.overflow == OverflowBehavior::Null || inputs.iter().any(|n| *n))
+    }
+}
+
+/// Subtracts an interval from a timestamp, clamping the day of month when
+/// subtracting months.
+#[derive(Debug, Clone)]
+pub struct TimestampIntervalSubImpl {
+    unit: TimeUnit,
+}
 
+impl ScalarFunctionImpl for TimestampIntervalSubImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
         let builder = ArrayBuilder {
-            datatype: self.datatype.clone(),
-            buffer: PrimitiveBuffer::with_len(a.logical_len()),
+            datatype: inputs[0].datatype().clone(),
+            buffer: PrimitiveBuffer::<i64>::with_len(inputs[0].logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a - b)))
+        let mut out_of_range = false;
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalInterval, _, _>(
+            inputs[0],
+            inputs[1],
+            builder,
+            |ts, interval, buf| {
+                let result = negate_interval(interval)
+                    .and_then(|interval| timestamp_add_interval(self.unit, ts, interval));
+                match result {
+                    Some(v) => buf.put(&v),
+                    None => out_of_range = true,
+                }
+            },
+        )?;
+
+        if out_of_range {
+            return Err(RayexecError::new("Timestamp out of range"));
+        }
+
+        Ok(out)
+    }
+}
+
+fn negate_interval(interval: Interval) -> Option<Interval> {
+    Some(Interval::new(
+        interval.months.checked_neg()?,
+        interval.days.checked_neg()?,
+        interval.nanos.checked_neg()?,
+    ))
+}
+
+/// Subtracts two timestamps, producing an interval of days and nanoseconds.
+#[derive(Debug, Clone)]
+pub struct TimestampSubImpl {
+    lhs_unit: TimeUnit,
+    rhs_unit: TimeUnit,
+}
+
+impl ScalarFunctionImpl for TimestampSubImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: DataType::Interval,
+            buffer: PrimitiveBuffer::<Interval>::with_len(inputs[0].logical_len()),
+        };
+
+        let mut out_of_range = false;
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalI64, _, _>(
+            inputs[0],
+            inputs[1],
+            builder,
+            |a, b, buf| match timestamp_diff(self.lhs_unit, a, self.rhs_unit, b) {
+                Some(v) => buf.put(&v),
+                None => out_of_range = true,
+            },
+        )?;
+
+        if out_of_range {
+            return Err(RayexecError::new("Interval out of range"));
+        }
+
+        Ok(out)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::arrays::datatype::DataType;
+    use crate::arrays::datatype::{DataType, TimestampTypeMeta};
     use crate::expr;
     use crate::functions::scalar::ScalarFunction;
 
@@ -261,4 +430,49 @@ mod tests {
 
         assert_eq!(expected, out);
     }
+
+    #[test]
+    fn sub_timestamps_to_interval() {
+        let datatype = DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond));
+
+        // 2024-03-02 13:30:00.5 and 2024-02-28 12:00:00
+        let a = Array::new_with_array_data(
+            datatype.clone(),
+            PrimitiveStorage::from(vec![1_709_386_200_500_000_i64]),
+        );
+        let b = Array::new_with_array_data(
+            datatype.clone(),
+            PrimitiveStorage::from(vec![1_709_121_600_000_000_i64]),
+        );
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![datatype.clone(), datatype],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let planned = Sub
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+            )
+            .unwrap();
+        assert_eq!(DataType::Interval, planned.return_type);
+
+        let out = planned.function_impl.execute(&[&a, &b]).unwrap();
+        // 3 days 01:30:00.5
+        let expected = Array::new_with_array_data(
+            DataType::Interval,
+            PrimitiveStorage::from(vec![Interval::new(
+                0,
+                3,
+                90 * Interval::NANOSECONDS_IN_MINUTE + 500 * Interval::NANOSECONDS_IN_MILLISECOND,
+            )]),
+        );
+
+        assert_eq!(expected, out);
+    }
 }
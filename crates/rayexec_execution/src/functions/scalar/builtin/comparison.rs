@@ -7,6 +7,7 @@ use rayexec_error::{RayexecError, Result};
 use crate::arrays::array::{Array, ArrayData};
 use crate::arrays::compute::cast::array::decimal_rescale;
 use crate::arrays::compute::cast::behavior::CastFailBehavior;
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
 use crate::arrays::executor::builder::{ArrayBuilder, BooleanBuffer};
 use crate::arrays::executor::physical_type::{
@@ -566,7 +567,7 @@ fn new_comparison_impl<O: ComparisonOperation>(
                 RescalingComparisionImpl::<O, Decimal128Type>::new(left, right),
             ),
             (DataType::Timestamp(_), DataType::Timestamp(_)) => {
-                Box::new(BaseComparisonImpl::<O, PhysicalBool>::new())
+                Box::new(BaseComparisonImpl::<O, PhysicalI64>::new())
             }
             (DataType::Interval, DataType::Interval) => {
                 Box::new(BaseComparisonImpl::<O, PhysicalInterval>::new())
@@ -765,6 +766,11 @@ where
                     "Comparison between nested lists not yet supported",
                 ))
             }
+            PhysicalType::Struct => {
+                return Err(RayexecError::new(
+                    "Comparison between lists of structs not yet supported",
+                ))
+            }
         };
 
         Ok(array)
@@ -860,6 +866,7 @@ where
                     right,
                     left.datatype().clone(),
                     CastFailBehavior::Error,
+                    RoundingMode::default(),
                 )?;
 
                 BinaryExecutor::execute::<T::Storage, T::Storage, _, _>(
@@ -874,6 +881,7 @@ where
                     left,
                     right.datatype().clone(),
                     CastFailBehavior::Error,
+                    RoundingMode::default(),
                 )?;
 
                 BinaryExecutor::execute::<T::Storage, T::Storage, _, _>(
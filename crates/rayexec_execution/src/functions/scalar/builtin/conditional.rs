@@ -0,0 +1,291 @@
+use std::sync::Arc;
+
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::bitmap::Bitmap;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::physical_type::PhysicalBool;
+use crate::arrays::executor::scalar::{interleave, UnaryExecutor};
+use crate::arrays::selection::SelectionVector;
+use crate::expr::physical::PhysicalScalarExpression;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::builtin::comparison::Eq;
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{plan_cast_to_common_type, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coalesce;
+
+impl FunctionInfo for Coalesce {
+    fn name(&self) -> &'static str {
+        "coalesce"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: Some(DataTypeId::Any),
+            return_type: DataTypeId::Any,
+            doc: Some(&Documentation {
+                category: Category::General,
+                description:
+                    "Return the first non-NULL argument. Returns NULL if all arguments are NULL.",
+                arguments: &["var_args"],
+                example: Some(Example {
+                    example: "coalesce(NULL, 2, 3)",
+                    output: "2",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for Coalesce {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        if inputs.is_empty() {
+            return Err(RayexecError::new("coalesce requires at least one argument"));
+        }
+
+        let (return_type, inputs) = plan_cast_to_common_type(self, table_list, inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type,
+            inputs,
+            function_impl: Box::new(CoalesceImpl),
+        })
+    }
+
+    fn output_nullable(&self, inputs: &[bool]) -> bool {
+        // Only NULL if every input can be NULL.
+        inputs.iter().all(|nullable| *nullable)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CoalesceImpl;
+
+impl ScalarFunctionImpl for CoalesceImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let len = inputs[0].logical_len();
+        let mut indices: Vec<(usize, usize)> = (0..len).map(|idx| (0, idx)).collect();
+
+        // Go in reverse so earlier inputs take precedence.
+        for (array_idx, input) in inputs.iter().enumerate().rev() {
+            for (row_idx, index) in indices.iter_mut().enumerate() {
+                if input.is_valid(row_idx).unwrap_or(false) {
+                    *index = (array_idx, row_idx);
+                }
+            }
+        }
+
+        interleave(inputs, &indices)
+    }
+
+    fn execute_lazy(&self, batch: &Batch, inputs: &[PhysicalScalarExpression]) -> Result<Array> {
+        let mut arrays = Vec::with_capacity(inputs.len());
+        let mut indices: Vec<(usize, usize)> = vec![(0, 0); batch.num_rows()];
+
+        // Rows that haven't had a non-NULL value yet.
+        let mut remaining = Bitmap::new_with_all_true(batch.num_rows());
+
+        for (input_idx, input) in inputs.iter().enumerate() {
+            let is_last = input_idx == inputs.len() - 1;
+
+            // Only evaluate the input for rows we still need a value for.
+            let selection = Arc::new(SelectionVector::from_iter(remaining.index_iter()));
+            let selected_batch = batch.select(selection.clone());
+            let output = input.eval(&selected_batch)?.into_owned();
+
+            let array_idx = arrays.len();
+            for (array_row_idx, output_row_idx) in selection.iter_locations().enumerate() {
+                // Rows still remaining after the last input are NULL, use the
+                // last input's (NULL) value for them.
+                if is_last || output.is_valid(array_row_idx).unwrap_or(false) {
+                    indices[output_row_idx] = (array_idx, array_row_idx);
+                    remaining.set_unchecked(output_row_idx, false);
+                }
+            }
+            arrays.push(output);
+
+            if remaining.count_trues() == 0 {
+                break;
+            }
+        }
+
+        let refs: Vec<_> = arrays.iter().collect();
+        interleave(&refs, &indices)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullIf;
+
+impl FunctionInfo for NullIf {
+    fn name(&self) -> &'static str {
+        "nullif"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Any, DataTypeId::Any],
+            variadic_arg: None,
+            return_type: DataTypeId::Any,
+            doc: Some(&Documentation {
+                category: Category::General,
+                description:
+                    "Return NULL if both arguments are equal, otherwise return the first argument.",
+                arguments: &["a", "b"],
+                example: Some(Example {
+                    example: "nullif(1, 1)",
+                    output: "NULL",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for NullIf {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        let (return_type, inputs) = plan_cast_to_common_type(self, table_list, inputs)?;
+        // Reuse equality for the comparison.
+        let eq = Eq.plan(table_list, inputs.clone())?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: return_type.clone(),
+            inputs,
+            function_impl: Box::new(NullIfImpl {
+                return_type,
+                eq_impl: eq.function_impl,
+            }),
+        })
+    }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NullIfImpl {
+    return_type: DataType,
+    eq_impl: Box<dyn ScalarFunctionImpl>,
+}
+
+impl ScalarFunctionImpl for NullIfImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let first = inputs[0];
+        let eq = self.eq_impl.execute(inputs)?;
+
+        let nulls = Array::new_typed_null_array(self.return_type.clone(), 1)?;
+
+        let mut indices: Vec<(usize, usize)> =
+            (0..first.logical_len()).map(|idx| (0, idx)).collect();
+        UnaryExecutor::for_each::<PhysicalBool, _>(&eq, |idx, is_eq| {
+            if is_eq == Some(true) {
+                indices[idx] = (1, 0);
+            }
+        })?;
+
+        interleave(&[first, &nulls], &indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn coalesce_mixed_types() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int32, DataType::Null, DataType::Int64],
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            )
+            .unwrap();
+
+        let planned = Coalesce
+            .plan(
+                &table_list,
+                vec![
+                    expr::col_ref(table_ref, 0),
+                    expr::col_ref(table_ref, 1),
+                    expr::col_ref(table_ref, 2),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(DataType::Int64, planned.return_type);
+        // Int32 input gets casted, others don't need it.
+        assert!(matches!(planned.inputs[0], Expression::Cast(_)));
+        assert!(matches!(planned.inputs[1], Expression::Cast(_)));
+        assert!(matches!(planned.inputs[2], Expression::Column(_)));
+    }
+
+    #[test]
+    fn coalesce_first_non_null() {
+        let out = CoalesceImpl
+            .execute(&[
+                &Array::from_iter([Some(1_i64), None, None]),
+                &Array::from_iter([Some(2_i64), Some(3), None]),
+            ])
+            .unwrap();
+        let expected = Array::from_iter([Some(1_i64), Some(3), None]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn coalesce_nullability() {
+        assert!(!Coalesce.output_nullable(&[true, false]));
+        assert!(Coalesce.output_nullable(&[true, true]));
+    }
+
+    #[test]
+    fn nullif_equal_and_unequal() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int64, DataType::Int64],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let planned = NullIf
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+            )
+            .unwrap();
+
+        let out = planned
+            .function_impl
+            .execute(&[
+                &Array::from_iter([Some(1_i64), Some(2), None, Some(4)]),
+                &Array::from_iter([Some(1_i64), Some(3), Some(3), None]),
+            ])
+            .unwrap();
+        let expected = Array::from_iter([None, Some(2_i64), None, Some(4)]);
+
+        assert_eq!(expected, out);
+    }
+}
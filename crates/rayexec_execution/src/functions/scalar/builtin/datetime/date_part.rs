@@ -2,7 +2,7 @@ use rayexec_error::Result;
 use rayexec_parser::ast;
 
 use crate::arrays::array::Array;
-use crate::arrays::compute::date::{self, extract_date_part};
+use crate::arrays::compute::date::{self, check_date_part_supported, extract_date_part};
 use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
 use crate::arrays::scalar::decimal::{Decimal64Type, DecimalType};
 use crate::expr::Expression;
@@ -79,7 +79,8 @@ impl ScalarFunction for DatePart {
         let part = convert_ast_date_part(part);
 
         match &datatypes[1] {
-            DataType::Date32 | DataType::Date64 | DataType::Timestamp(_) => {
+            datatype @ (DataType::Date32 | DataType::Date64 | DataType::Timestamp(_)) => {
+                check_date_part_supported(part, datatype)?;
                 Ok(PlannedScalarFunction {
                     function: Box::new(*self),
                     return_type: DataType::Decimal64(DecimalTypeMeta::new(
@@ -1,12 +1,15 @@
 use std::str::FromStr;
 
+use chrono::{Datelike, Days, NaiveDate, NaiveTime};
 use rayexec_error::{not_implemented, RayexecError, Result};
 
 use crate::arrays::array::Array;
+use crate::arrays::compute::date::{datetime_to_timestamp, timestamp_to_datetime};
 use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::physical_type::PhysicalI64;
 use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::interval::Interval;
 use crate::expr::Expression;
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
 use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
@@ -34,26 +37,12 @@ impl FunctionInfo for DateTrunc {
         //     }),
         // };
 
-        &[
-            Signature {
-                positional_args: &[DataTypeId::Utf8, DataTypeId::Date32],
-                variadic_arg: None,
-                return_type: DataTypeId::Decimal64,
-                doc: None,
-            },
-            Signature {
-                positional_args: &[DataTypeId::Utf8, DataTypeId::Date64],
-                variadic_arg: None,
-                return_type: DataTypeId::Decimal64,
-                doc: None,
-            },
-            Signature {
-                positional_args: &[DataTypeId::Utf8, DataTypeId::Timestamp],
-                variadic_arg: None,
-                return_type: DataTypeId::Decimal64,
-                doc: None,
-            },
-        ]
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Timestamp],
+            variadic_arg: None,
+            return_type: DataTypeId::Timestamp,
+            doc: None,
+        }]
     }
 }
 
@@ -78,6 +67,9 @@ impl ScalarFunction for DateTrunc {
             .to_lowercase();
 
         let field = field.parse::<TruncField>()?;
+        if matches!(field, TruncField::Century | TruncField::Millennium) {
+            not_implemented!("date_trunc field: {field:?}");
+        }
 
         match &datatypes[1] {
             DataType::Timestamp(m) => Ok(PlannedScalarFunction {
@@ -133,6 +125,38 @@ impl FromStr for TruncField {
     }
 }
 
+impl TruncField {
+    /// Returns the width of this field in nanoseconds if it has a fixed width.
+    ///
+    /// Returns None for fields that depend on the calendar (e.g. months).
+    const fn fixed_width_nanos(&self) -> Option<i64> {
+        Some(match self {
+            Self::Microseconds => Interval::NANOSECONDS_IN_MICROSECOND,
+            Self::Milliseconds => Interval::NANOSECONDS_IN_MILLISECOND,
+            Self::Second => Interval::NANOSECONDS_IN_SECOND,
+            Self::Minute => Interval::NANOSECONDS_IN_MINUTE,
+            Self::Hour => Interval::NANOSECONDS_IN_HOUR,
+            Self::Day => 24 * Interval::NANOSECONDS_IN_HOUR,
+            _ => return None,
+        })
+    }
+
+    /// Truncate a date to the start of this field.
+    fn trunc_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Self::Week => {
+                // ISO weeks start on Monday.
+                date.checked_sub_days(Days::new(date.weekday().num_days_from_monday() as u64))
+            }
+            Self::Month => date.with_day(1),
+            Self::Quarter => NaiveDate::from_ymd_opt(date.year(), (date.month0() / 3) * 3 + 1, 1),
+            Self::Year => NaiveDate::from_ymd_opt(date.year(), 1, 1),
+            Self::Decade => NaiveDate::from_ymd_opt(date.year() - date.year().rem_euclid(10), 1, 1),
+            _ => Some(date),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DateTruncImpl {
     input_unit: TimeUnit,
@@ -143,44 +167,6 @@ impl ScalarFunctionImpl for DateTruncImpl {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
         let input = &inputs[1];
 
-        let trunc = match self.input_unit {
-            TimeUnit::Second => match self.field {
-                TruncField::Microseconds | TruncField::Milliseconds | TruncField::Second => {
-                    return Ok((*input).clone())
-                }
-                TruncField::Minute => 60,
-                TruncField::Hour => 60 * 60,
-                TruncField::Day => 24 * 60 * 60,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-            TimeUnit::Millisecond => match self.field {
-                TruncField::Microseconds | TruncField::Milliseconds => return Ok((*input).clone()),
-                TruncField::Second => 1000,
-                TruncField::Minute => 60 * 1000,
-                TruncField::Hour => 60 * 60 * 1000,
-                TruncField::Day => 24 * 60 * 60 * 1000,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-            TimeUnit::Microsecond => match self.field {
-                TruncField::Microseconds => return Ok((*input).clone()),
-                TruncField::Milliseconds => 1000,
-                TruncField::Second => 1000 * 1000,
-                TruncField::Minute => 60 * 1000 * 1000,
-                TruncField::Hour => 60 * 60 * 1000 * 1000,
-                TruncField::Day => 24 * 60 * 60 * 1000 * 1000,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-            TimeUnit::Nanosecond => match self.field {
-                TruncField::Microseconds => 1000,
-                TruncField::Milliseconds => 1000 * 1000,
-                TruncField::Second => 1000 * 1000 * 1000,
-                TruncField::Minute => 60 * 1000 * 1000 * 1000,
-                TruncField::Hour => 60 * 60 * 1000 * 1000 * 1000,
-                TruncField::Day => 24 * 60 * 60 * 1000 * 1000 * 1000,
-                other => not_implemented!("trunc field: {other:?}"),
-            },
-        };
-
         let builder = ArrayBuilder {
             datatype: DataType::Timestamp(TimestampTypeMeta {
                 unit: self.input_unit,
@@ -188,9 +174,42 @@ impl ScalarFunctionImpl for DateTruncImpl {
             buffer: PrimitiveBuffer::with_len(input.logical_len()),
         };
 
-        UnaryExecutor::execute::<PhysicalI64, _, _>(input, builder, |v, buf| {
-            let v = (v / trunc) * trunc;
-            buf.put(&v)
-        })
+        if let Some(width) = self.field.fixed_width_nanos() {
+            let unit_nanos = match self.input_unit {
+                TimeUnit::Second => Interval::NANOSECONDS_IN_SECOND,
+                TimeUnit::Millisecond => Interval::NANOSECONDS_IN_MILLISECOND,
+                TimeUnit::Microsecond => Interval::NANOSECONDS_IN_MICROSECOND,
+                TimeUnit::Nanosecond => 1,
+            };
+            if width <= unit_nanos {
+                // Input is already at (or coarser than) the requested precision.
+                return Ok((*input).clone());
+            }
+            let trunc = width / unit_nanos;
+
+            return UnaryExecutor::execute::<PhysicalI64, _, _>(input, builder, |v, buf| {
+                let v = v.div_euclid(trunc) * trunc;
+                buf.put(&v)
+            });
+        }
+
+        let mut out_of_range = false;
+        let out = UnaryExecutor::execute::<PhysicalI64, _, _>(input, builder, |v, buf| {
+            let truncated = timestamp_to_datetime(self.input_unit, v)
+                .and_then(|datetime| self.field.trunc_date(datetime.date_naive()))
+                .and_then(|date| {
+                    datetime_to_timestamp(self.input_unit, date.and_time(NaiveTime::MIN).and_utc())
+                });
+            match truncated {
+                Some(v) => buf.put(&v),
+                None => out_of_range = true,
+            }
+        })?;
+
+        if out_of_range {
+            return Err(RayexecError::new("Timestamp out of range"));
+        }
+
+        Ok(out)
     }
 }
@@ -6,3 +6,11 @@ pub use epoch::*;
 
 mod date_trunc;
 pub use date_trunc::*;
+
+mod strftime;
+
+mod to_char;
+pub use to_char::*;
+
+mod to_timestamp;
+pub use to_timestamp::*;
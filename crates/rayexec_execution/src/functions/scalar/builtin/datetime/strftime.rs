@@ -0,0 +1,209 @@
+//! Strftime-style format strings used by `to_char` and `to_timestamp`.
+//!
+//! Only a subset of format codes are supported:
+//!
+//! | Code | Description                                  | Example   |
+//! |------|----------------------------------------------|-----------|
+//! | `%Y` | Year with century                            | 2024      |
+//! | `%y` | Year without century, zero padded            | 24        |
+//! | `%m` | Month number, zero padded                    | 03        |
+//! | `%b` | Abbreviated month name                       | Mar       |
+//! | `%B` | Full month name                              | March     |
+//! | `%d` | Day of month, zero padded                    | 05        |
+//! | `%e` | Day of month, space padded                   | ` 5`      |
+//! | `%j` | Day of year, zero padded                     | 065       |
+//! | `%a` | Abbreviated weekday name                     | Tue       |
+//! | `%A` | Full weekday name                            | Tuesday   |
+//! | `%H` | Hour (24-hour clock), zero padded            | 13        |
+//! | `%I` | Hour (12-hour clock), zero padded            | 01        |
+//! | `%p` | AM or PM                                     | PM        |
+//! | `%M` | Minute, zero padded                          | 45        |
+//! | `%S` | Second, zero padded                          | 07        |
+//! | `%f` | Fractional seconds in nanoseconds, 9 digits  | 250000000 |
+//! | `%%` | A literal '%'                                | %         |
+//!
+//! When parsing, the format needs to contain enough codes to determine the
+//! date. Time fields that aren't in the format default to midnight.
+
+use std::fmt::Write as _;
+
+use chrono::format::{self, Item, Parsed, StrftimeItems};
+use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::scalar::ScalarValue;
+use crate::expr::Expression;
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+/// Format codes we accept, see module docs.
+const SUPPORTED_CODES: &[char] = &[
+    'Y', 'y', 'm', 'b', 'B', 'd', 'e', 'j', 'a', 'A', 'H', 'I', 'p', 'M', 'S', 'f', '%',
+];
+
+/// A validated and compiled format string.
+#[derive(Debug, Clone)]
+pub struct TimestampFormat {
+    format: String,
+    items: Vec<Item<'static>>,
+}
+
+impl TimestampFormat {
+    pub fn try_new(format: &str) -> Result<Self> {
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                continue;
+            }
+            match chars.next() {
+                Some(code) if SUPPORTED_CODES.contains(&code) => (),
+                Some(code) => {
+                    return Err(RayexecError::new(format!(
+                        "Unsupported format code '%{code}' in format '{format}'"
+                    )))
+                }
+                None => {
+                    return Err(RayexecError::new(format!(
+                        "Format '{format}' ends with an incomplete format code"
+                    )))
+                }
+            }
+        }
+
+        let items = StrftimeItems::new(format).parse_to_owned().map_err(|e| {
+            RayexecError::with_source(format!("Invalid format '{format}'"), Box::new(e))
+        })?;
+
+        Ok(TimestampFormat {
+            format: format.to_string(),
+            items,
+        })
+    }
+
+    /// Get the original format string.
+    pub fn as_str(&self) -> &str {
+        &self.format
+    }
+
+    /// Format a datetime, writing the result to `buf`.
+    ///
+    /// `buf` is cleared before writing.
+    pub fn format(&self, datetime: &DateTime<Utc>, buf: &mut String) -> Result<()> {
+        buf.clear();
+        write!(buf, "{}", datetime.format_with_items(self.items.iter()))
+            .map_err(|_| RayexecError::new(format!("Failed to format timestamp '{datetime}'")))
+    }
+
+    /// Parse a string into a datetime.
+    ///
+    /// Returns None if the string doesn't match the format.
+    pub fn parse(&self, s: &str) -> Option<NaiveDateTime> {
+        let mut parsed = Parsed::new();
+        format::parse(&mut parsed, s, self.items.iter()).ok()?;
+
+        let date = parsed.to_naive_date().ok()?;
+        let time = match parsed.to_naive_time() {
+            Ok(time) => time,
+            Err(_) if parsed.hour_mod_12().is_none() => NaiveTime::MIN,
+            Err(_) => return None,
+        };
+
+        Some(date.and_time(time))
+    }
+}
+
+/// Compile the format argument if it's constant.
+///
+/// Returns None if the format isn't constant, or is a constant NULL, in which
+/// case formats need to be compiled per row. Errors if a constant format is
+/// invalid.
+pub(super) fn plan_constant_format(
+    table_list: &TableList,
+    format: &Expression,
+) -> Result<Option<TimestampFormat>> {
+    if !format.is_const_foldable() {
+        return Ok(None);
+    }
+
+    let format = ConstFold::rewrite(table_list, format.clone())?.try_into_scalar()?;
+    if matches!(format, ScalarValue::Null) {
+        return Ok(None);
+    }
+
+    Ok(Some(TimestampFormat::try_new(format.try_as_str()?)?))
+}
+
+/// Compiles per-row formats for functions with non-constant formats.
+///
+/// The most recently compiled format is kept around since the same format is
+/// commonly repeated across rows. The first invalid format is stored and
+/// returned as an error when calling `finish`.
+#[derive(Debug, Default)]
+pub(super) struct RowFormatCache {
+    last: Option<(String, TimestampFormat)>,
+    error: Option<RayexecError>,
+}
+
+impl RowFormatCache {
+    /// Get the compiled format.
+    ///
+    /// Returns None if the format is invalid, or if a previous format was
+    /// invalid.
+    pub(super) fn get(&mut self, format: &str) -> Option<&TimestampFormat> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        let is_cached = matches!(&self.last, Some((last, _)) if last == format);
+        if !is_cached {
+            match TimestampFormat::try_new(format) {
+                Ok(compiled) => self.last = Some((format.to_string(), compiled)),
+                Err(e) => {
+                    self.error = Some(e);
+                    return None;
+                }
+            }
+        }
+
+        self.last.as_ref().map(|(_, compiled)| compiled)
+    }
+
+    /// Return the error for the first invalid format, if any.
+    pub(super) fn finish(self) -> Result<()> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_code() {
+        let err = TimestampFormat::try_new("%Y-%m-%d %Z").unwrap_err();
+        assert!(err.to_string().contains("'%Z'"), "{err}");
+    }
+
+    #[test]
+    fn incomplete_code() {
+        TimestampFormat::try_new("%Y-%").unwrap_err();
+    }
+
+    #[test]
+    fn parse_date_only_defaults_to_midnight() {
+        let format = TimestampFormat::try_new("%d/%m/%Y").unwrap();
+        let parsed = format.parse("05/03/2024").unwrap();
+        assert_eq!("2024-03-05 00:00:00", parsed.to_string());
+    }
+
+    #[test]
+    fn parse_twelve_hour_clock() {
+        let format = TimestampFormat::try_new("%Y-%m-%d %I:%M %p").unwrap();
+        let parsed = format.parse("2024-03-05 01:45 PM").unwrap();
+        assert_eq!("2024-03-05 13:45:00", parsed.to_string());
+    }
+}
@@ -0,0 +1,133 @@
+use rayexec_error::{RayexecError, Result};
+
+use super::strftime::{plan_constant_format, RowFormatCache, TimestampFormat};
+use crate::arrays::array::Array;
+use crate::arrays::compute::date::timestamp_to_datetime;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::builder::{ArrayBuilder, GermanVarlenBuffer};
+use crate::arrays::executor::physical_type::{PhysicalI64, PhysicalUtf8};
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToChar;
+
+impl FunctionInfo for ToChar {
+    fn name(&self) -> &'static str {
+        "to_char"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Timestamp, DataTypeId::Utf8],
+            variadic_arg: None,
+            return_type: DataTypeId::Utf8,
+            doc: Some(&Documentation {
+                category: Category::Date,
+                description: "Format a timestamp as a string using strftime-style format codes.",
+                arguments: &["timestamp", "format"],
+                example: Some(Example {
+                    example: "to_char('2024-03-05 13:45:00'::timestamp, '%Y/%m/%d %H:%M')",
+                    output: "2024/03/05 13:45",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for ToChar {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+        let unit = match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Timestamp(m), DataType::Utf8) => m.unit,
+            (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
+        };
+
+        let format = plan_constant_format(table_list, &inputs[1])?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Utf8,
+            inputs,
+            function_impl: Box::new(ToCharImpl { unit, format }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ToCharImpl {
+    unit: TimeUnit,
+    /// Format if it was constant.
+    format: Option<TimestampFormat>,
+}
+
+impl ToCharImpl {
+    fn format_timestamp(&self, format: &TimestampFormat, val: i64, buf: &mut String) -> Result<()> {
+        let datetime = timestamp_to_datetime(self.unit, val)
+            .ok_or_else(|| RayexecError::new("Timestamp out of range"))?;
+        format.format(&datetime, buf)
+    }
+}
+
+impl ScalarFunctionImpl for ToCharImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: DataType::Utf8,
+            buffer: GermanVarlenBuffer::<str>::with_len(inputs[0].logical_len()),
+        };
+
+        let mut s = String::new();
+        let mut error = None;
+
+        let out = match self.format.as_ref() {
+            Some(format) => {
+                UnaryExecutor::execute::<PhysicalI64, _, _>(inputs[0], builder, |v, buf| {
+                    if error.is_some() {
+                        return;
+                    }
+                    match self.format_timestamp(format, v, &mut s) {
+                        Ok(()) => buf.put(s.as_str()),
+                        Err(e) => error = Some(e),
+                    }
+                })?
+            }
+            None => {
+                let mut cache = RowFormatCache::default();
+                let out = BinaryExecutor::execute::<PhysicalI64, PhysicalUtf8, _, _>(
+                    inputs[0],
+                    inputs[1],
+                    builder,
+                    |v, format, buf| {
+                        if error.is_some() {
+                            return;
+                        }
+                        if let Some(format) = cache.get(format) {
+                            match self.format_timestamp(format, v, &mut s) {
+                                Ok(()) => buf.put(s.as_str()),
+                                Err(e) => error = Some(e),
+                            }
+                        }
+                    },
+                )?;
+                cache.finish()?;
+                out
+            }
+        };
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(out),
+        }
+    }
+}
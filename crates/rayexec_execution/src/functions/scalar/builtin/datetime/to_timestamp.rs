@@ -0,0 +1,265 @@
+use rayexec_error::{RayexecError, Result};
+
+use super::strftime::{plan_constant_format, RowFormatCache, TimestampFormat};
+use crate::arrays::array::Array;
+use crate::arrays::compute::date::datetime_to_timestamp;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
+use crate::arrays::executor::builder::{ArrayBuilder, OutputBuffer, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::PhysicalUtf8;
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{
+    invalid_input_types_error,
+    plan_check_num_args_one_of,
+    FunctionInfo,
+    Signature,
+};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToTimestamp;
+
+impl FunctionInfo for ToTimestamp {
+    fn name(&self) -> &'static str {
+        "to_timestamp"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8],
+                variadic_arg: None,
+                return_type: DataTypeId::Timestamp,
+                doc: Some(&Documentation {
+                    category: Category::Date,
+                    description: "Parse a string into a timestamp using strftime-style format codes. Errors if the string doesn't match the format.",
+                    arguments: &["string", "format"],
+                    example: Some(Example {
+                        example: "to_timestamp('2024/03/05 13:45', '%Y/%m/%d %H:%M')",
+                        output: "2024-03-05 13:45:00 UTC",
+                    }),
+                }),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8, DataTypeId::Boolean],
+                variadic_arg: None,
+                return_type: DataTypeId::Timestamp,
+                doc: Some(&Documentation {
+                    category: Category::Date,
+                    description: "Parse a string into a timestamp using strftime-style format codes. If 'strict' is false, strings that don't match the format produce NULL instead of an error.",
+                    arguments: &["string", "format", "strict"],
+                    example: Some(Example {
+                        example: "to_timestamp('2024/03/05', '%Y-%m-%d', strict => false)",
+                        output: "NULL",
+                    }),
+                }),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for ToTimestamp {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args_one_of(self, &inputs, [2, 3])?;
+
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        let strict = match datatypes.as_slice() {
+            [DataType::Utf8, DataType::Utf8] => true,
+            [DataType::Utf8, DataType::Utf8, DataType::Boolean] => {
+                if !inputs[2].is_const_foldable() {
+                    return Err(RayexecError::new(
+                        "Third argument to 'to_timestamp' ('strict') must be constant",
+                    ));
+                }
+                ConstFold::rewrite(table_list, inputs[2].clone())?
+                    .try_into_scalar()?
+                    .try_as_bool()?
+            }
+            _ => return Err(invalid_input_types_error(self, &datatypes)),
+        };
+
+        let format = plan_constant_format(table_list, &inputs[1])?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            inputs,
+            function_impl: Box::new(ToTimestampImpl { format, strict }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ToTimestampImpl {
+    /// Format if it was constant.
+    format: Option<TimestampFormat>,
+    /// Error on strings that fail to parse instead of producing NULL.
+    strict: bool,
+}
+
+impl ScalarFunctionImpl for ToTimestampImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            buffer: PrimitiveBuffer::<i64>::with_len(inputs[0].logical_len()),
+        };
+
+        // Rows that failed to parse, and the error for the first failure.
+        let mut failed = Vec::new();
+        let mut first_error = None;
+
+        let mut parse = |format: &TimestampFormat, s: &str, buf: &mut OutputBuffer<_>| {
+            let parsed = format.parse(s).and_then(|datetime| {
+                datetime_to_timestamp(TimeUnit::Microsecond, datetime.and_utc())
+            });
+            match parsed {
+                Some(v) => buf.put(&v),
+                None => {
+                    if first_error.is_none() {
+                        first_error = Some(format!(
+                            "Failed to parse '{s}' as a timestamp using format '{}'",
+                            format.as_str()
+                        ));
+                    }
+                    failed.push(buf.idx);
+                }
+            }
+        };
+
+        let mut out = match self.format.as_ref() {
+            Some(format) => {
+                UnaryExecutor::execute::<PhysicalUtf8, _, _>(inputs[0], builder, |s, buf| {
+                    parse(format, s, buf)
+                })?
+            }
+            None => {
+                let mut cache = RowFormatCache::default();
+                let out = BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _, _>(
+                    inputs[0],
+                    inputs[1],
+                    builder,
+                    |s, format, buf| {
+                        if let Some(format) = cache.get(format) {
+                            parse(format, s, buf)
+                        }
+                    },
+                )?;
+                cache.finish()?;
+                out
+            }
+        };
+
+        if let Some(error) = first_error {
+            if self.strict {
+                return Err(RayexecError::new(error));
+            }
+        }
+
+        for idx in failed {
+            out.set_physical_validity(idx, false);
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::arrays::storage::PrimitiveStorage;
+    use crate::expr;
+    use crate::functions::scalar::builtin::datetime::ToChar;
+
+    fn plan_to_timestamp(strict: Option<bool>) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Utf8], vec!["a".to_string()])
+            .unwrap();
+
+        let mut inputs = vec![expr::col_ref(table_ref, 0), expr::lit("%Y-%m-%d %H:%M:%S")];
+        inputs.extend(strict.map(expr::lit));
+
+        ToTimestamp.plan(&table_list, inputs).unwrap()
+    }
+
+    #[test]
+    fn round_trip_to_char_to_timestamp() {
+        const FORMAT: &str = "%d %B %Y, %I:%M:%S.%f %p";
+
+        let datatype = DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond));
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![datatype.clone(), DataType::Utf8],
+                vec!["ts".to_string(), "s".to_string()],
+            )
+            .unwrap();
+
+        let to_char = ToChar
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::lit(FORMAT)],
+            )
+            .unwrap();
+        let to_timestamp = ToTimestamp
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 1), expr::lit(FORMAT)],
+            )
+            .unwrap();
+
+        // 2024-03-05 13:45:07.25 and 1969-07-20 20:17:00
+        let timestamps = Array::new_with_array_data(
+            datatype,
+            PrimitiveStorage::from(vec![1_709_646_307_250_000_i64, -14_182_980_000_000]),
+        );
+
+        let formatted = to_char.function_impl.execute(&[&timestamps]).unwrap();
+        assert_eq!(
+            Array::from_iter([
+                "05 March 2024, 01:45:07.250000000 PM",
+                "20 July 1969, 08:17:00.000000000 PM",
+            ]),
+            formatted
+        );
+
+        let parsed = to_timestamp.function_impl.execute(&[&formatted]).unwrap();
+        assert_eq!(timestamps, parsed);
+    }
+
+    #[test]
+    fn unparseable_strict_errors() {
+        let planned = plan_to_timestamp(None);
+        let input = Array::from_iter(["2024-03-05 13:45:07", "not a timestamp"]);
+
+        let err = planned.function_impl.execute(&[&input]).unwrap_err();
+        assert!(err.to_string().contains("not a timestamp"), "{err}");
+    }
+
+    #[test]
+    fn unparseable_non_strict_null() {
+        let planned = plan_to_timestamp(Some(false));
+        let input = Array::from_iter(["2024-03-05 13:45:07", "not a timestamp"]);
+
+        let out = planned.function_impl.execute(&[&input]).unwrap();
+        assert_eq!(
+            "2024-03-05 13:45:07 UTC",
+            out.logical_value(0).unwrap().to_string()
+        );
+        assert_eq!(ScalarValue::Null, out.logical_value(1).unwrap());
+    }
+}
@@ -52,6 +52,10 @@ impl ScalarFunction for IsNull {
             function_impl: Box::new(CheckNullImpl::<true>),
         })
     }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -95,6 +99,10 @@ impl ScalarFunction for IsNotNull {
             function_impl: Box::new(CheckNullImpl::<false>),
         })
     }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -168,6 +176,10 @@ impl ScalarFunction for IsTrue {
             function_impl: Box::new(CheckBoolImpl::<false, true>),
         })
     }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -211,6 +223,10 @@ impl ScalarFunction for IsNotTrue {
             function_impl: Box::new(CheckBoolImpl::<true, true>),
         })
     }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -254,6 +270,10 @@ impl ScalarFunction for IsFalse {
             function_impl: Box::new(CheckBoolImpl::<false, false>),
         })
     }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -297,6 +317,10 @@ impl ScalarFunction for IsNotFalse {
             function_impl: Box::new(CheckBoolImpl::<true, false>),
         })
     }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -87,10 +87,12 @@ impl ScalarFunction for ListExtract {
             .try_into_scalar()?
             .try_as_i64()?;
 
-        if index <= 0 {
-            return Err(RayexecError::new("Index cannot be less than 1"));
-        }
-        let index = (index - 1) as usize;
+        // Indexes less than 1 are always out of bounds and produce NULL.
+        let index = if index > 0 {
+            Some((index - 1) as usize)
+        } else {
+            None
+        };
 
         let inner_datatype = match &datatypes[0] {
             DataType::List(meta) => meta.datatype.as_ref().clone(),
@@ -111,29 +113,40 @@ impl ScalarFunction for ListExtract {
             }),
         })
     }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        // Out of bounds indexes produce NULL.
+        true
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ListExtractImpl {
     inner_datatype: DataType,
-    index: usize,
+    index: Option<usize>,
 }
 
 impl ScalarFunctionImpl for ListExtractImpl {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
         let input = inputs[0];
-        extract(input, self.index)
+        match self.index {
+            Some(index) => extract(input, &self.inner_datatype, index),
+            None => Array::new_typed_null_array(self.inner_datatype.clone(), input.logical_len()),
+        }
     }
 }
 
-fn extract(array: &Array, idx: usize) -> Result<Array> {
+fn extract(array: &Array, inner_datatype: &DataType, idx: usize) -> Result<Array> {
     let data = match array.array_data() {
         ArrayData::List(list) => list.as_ref(),
         _other => return Err(RayexecError::new("Unexpected storage type")),
     };
 
     match data.inner_array().physical_type() {
-        PhysicalType::UntypedNull => not_implemented!("NULL list extract"),
+        PhysicalType::UntypedNull => {
+            // Only NULL lists, every extracted value is NULL.
+            Array::new_typed_null_array(inner_datatype.clone(), array.logical_len())
+        }
         PhysicalType::Boolean => {
             let builder = ArrayBuilder {
                 datatype: DataType::Boolean,
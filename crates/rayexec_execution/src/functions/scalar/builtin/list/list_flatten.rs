@@ -0,0 +1,152 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::bitmap::Bitmap;
+use crate::arrays::datatype::{DataType, DataTypeId, ListTypeMeta};
+use crate::arrays::executor::physical_type::PhysicalList;
+use crate::arrays::executor::scalar::{interleave, UnaryExecutor};
+use crate::arrays::storage::{ListItemMetadata, ListStorage};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListFlatten;
+
+impl FunctionInfo for ListFlatten {
+    fn name(&self) -> &'static str {
+        "flatten"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::List],
+            variadic_arg: None,
+            return_type: DataTypeId::List,
+            doc: Some(&Documentation {
+                category: Category::List,
+                description: "Concatenate the sublists of a nested list into a single list. NULL sublists are skipped.",
+                arguments: &["list"],
+                example: Some(Example {
+                    example: "flatten([[1, 2], [3]])",
+                    output: "[1, 2, 3]",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for ListFlatten {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+
+        let datatype = inputs[0].datatype(table_list)?;
+        let element_datatype = match &datatype {
+            DataType::List(outer) => match outer.datatype.as_ref() {
+                DataType::List(inner) => inner.datatype.as_ref().clone(),
+                _ => {
+                    return Err(RayexecError::new(format!(
+                        "flatten expects a nested list, got {datatype}"
+                    )))
+                }
+            },
+            _ => {
+                return Err(RayexecError::new(format!(
+                    "flatten expects a nested list, got {datatype}"
+                )))
+            }
+        };
+
+        let return_type = DataType::List(ListTypeMeta::new(element_datatype));
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: return_type.clone(),
+            inputs,
+            function_impl: Box::new(ListFlattenImpl { return_type }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListFlattenImpl {
+    return_type: DataType,
+}
+
+impl ScalarFunctionImpl for ListFlattenImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+
+        let sublists = match input.array_data() {
+            ArrayData::List(list) => list.inner_array(),
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        // Sublists might not have list storage if there's no sublists at all
+        // (e.g. all outer lists are NULL).
+        let elements = match sublists.array_data() {
+            ArrayData::List(list) => Some(list.inner_array()),
+            _ if sublists.logical_len() == 0 => None,
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        let mut indices: Vec<(usize, usize)> = Vec::new();
+        let mut metadatas = Vec::with_capacity(input.logical_len());
+        let mut validity = Bitmap::new_with_all_true(input.logical_len());
+
+        for row in 0..input.logical_len() {
+            let offset = indices.len();
+
+            match UnaryExecutor::value_at::<PhysicalList>(input, row)? {
+                Some(outer) => {
+                    let start = outer.offset as usize;
+                    let end = start + outer.len as usize;
+
+                    for sublist_idx in start..end {
+                        // NULL sublists are skipped.
+                        if let Some(inner) =
+                            UnaryExecutor::value_at::<PhysicalList>(sublists, sublist_idx)?
+                        {
+                            let start = inner.offset as usize;
+                            let end = start + inner.len as usize;
+                            indices.extend((start..end).map(|idx| (0, idx)));
+                        }
+                    }
+                }
+                None => validity.set_unchecked(row, false),
+            }
+
+            metadatas.push(ListItemMetadata {
+                offset: offset as i32,
+                len: (indices.len() - offset) as i32,
+            });
+        }
+
+        let child = match elements {
+            Some(elements) => interleave(&[elements], &indices)?,
+            None => {
+                let element_datatype = match &self.return_type {
+                    DataType::List(meta) => meta.datatype.as_ref().clone(),
+                    other => {
+                        return Err(RayexecError::new(format!(
+                            "Unexpected return type: {other}"
+                        )))
+                    }
+                };
+                Array::new_typed_null_array(element_datatype, 0)?
+            }
+        };
+
+        Ok(Array::new_with_validity_and_array_data(
+            self.return_type.clone(),
+            validity,
+            ListStorage::try_new(metadatas, child)?,
+        ))
+    }
+}
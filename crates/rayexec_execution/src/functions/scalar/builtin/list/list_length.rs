@@ -0,0 +1,139 @@
+use rayexec_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::PhysicalList;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cardinality;
+
+impl FunctionInfo for Cardinality {
+    fn name(&self) -> &'static str {
+        "cardinality"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[
+            Signature {
+                positional_args: &[DataTypeId::List],
+                variadic_arg: None,
+                return_type: DataTypeId::Int64,
+                doc: Some(&Documentation {
+                    category: Category::List,
+                    description: "Get the number of elements in a list.",
+                    arguments: &["list"],
+                    example: Some(Example {
+                        example: "cardinality([4, 5, 6])",
+                        output: "3",
+                    }),
+                }),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Map],
+                variadic_arg: None,
+                return_type: DataTypeId::Int64,
+                doc: Some(&Documentation {
+                    category: Category::Map,
+                    description: "Get the number of entries in a map.",
+                    arguments: &["map"],
+                    example: Some(Example {
+                        example: "cardinality(MAP {'a': 1, 'b': 2})",
+                        output: "2",
+                    }),
+                }),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for Cardinality {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+        match inputs[0].datatype(table_list)? {
+            DataType::List(_) | DataType::Map(_) => Ok(PlannedScalarFunction {
+                function: Box::new(*self),
+                return_type: DataType::Int64,
+                inputs,
+                function_impl: Box::new(ListLengthImpl),
+            }),
+            a => Err(invalid_input_types_error(self, &[a])),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayLength;
+
+impl FunctionInfo for ArrayLength {
+    fn name(&self) -> &'static str {
+        "array_length"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::List],
+            variadic_arg: None,
+            return_type: DataTypeId::Int64,
+            doc: Some(&Documentation {
+                category: Category::List,
+                description: "Get the number of elements in the outermost dimension of a list.",
+                arguments: &["list"],
+                example: Some(Example {
+                    example: "array_length([[1, 2], [3]])",
+                    output: "2",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for ArrayLength {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+        match inputs[0].datatype(table_list)? {
+            DataType::List(_) => Ok(PlannedScalarFunction {
+                function: Box::new(*self),
+                return_type: DataType::Int64,
+                inputs,
+                function_impl: Box::new(ListLengthImpl),
+            }),
+            a => Err(invalid_input_types_error(self, &[a])),
+        }
+    }
+}
+
+/// Computes the number of elements in a list.
+///
+/// Also works on maps since they're physically lists of entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListLengthImpl;
+
+impl ScalarFunctionImpl for ListLengthImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Int64,
+            buffer: PrimitiveBuffer::with_len(input.logical_len()),
+        };
+
+        UnaryExecutor::execute::<PhysicalList, _, _>(input, builder, |metadata, buf| {
+            buf.put(&(metadata.len as i64))
+        })
+    }
+}
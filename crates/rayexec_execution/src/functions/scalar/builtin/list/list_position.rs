@@ -0,0 +1,245 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::bitmap::Bitmap;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{
+    ArrayBuilder,
+    ArrayDataBuffer,
+    BooleanBuffer,
+    PrimitiveBuffer,
+};
+use crate::arrays::executor::physical_type::{PhysicalList, PhysicalType};
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::ScalarValue;
+use crate::expr::{self, Expression};
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::implicit::can_implicitly_cast;
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListContains;
+
+impl FunctionInfo for ListContains {
+    fn name(&self) -> &'static str {
+        "array_contains"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["list_contains"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::List, DataTypeId::Any],
+            variadic_arg: None,
+            return_type: DataTypeId::Boolean,
+            doc: Some(&Documentation {
+                category: Category::List,
+                description:
+                    "Check if a list contains a value. NULL values are matched by NULL elements.",
+                arguments: &["list", "value"],
+                example: Some(Example {
+                    example: "array_contains([4, 5, 6], 5)",
+                    output: "true",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for ListContains {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let inputs = plan_list_search(self, table_list, inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Boolean,
+            inputs,
+            function_impl: Box::new(ListContainsImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListContainsImpl;
+
+impl ScalarFunctionImpl for ListContainsImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: DataType::Boolean,
+            buffer: BooleanBuffer::with_len(inputs[0].logical_len()),
+        };
+
+        search_lists(builder, inputs[0], inputs[1], |pos| pos.is_some())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListPosition;
+
+impl FunctionInfo for ListPosition {
+    fn name(&self) -> &'static str {
+        "list_position"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["array_position"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::List, DataTypeId::Any],
+            variadic_arg: None,
+            return_type: DataTypeId::Int64,
+            doc: Some(&Documentation {
+                category: Category::List,
+                description: "Get the 1-based position of the first occurrence of a value in a list, or 0 if the value isn't found. NULL values are matched by NULL elements.",
+                arguments: &["list", "value"],
+                example: Some(Example {
+                    example: "list_position([4, 5, 6], 5)",
+                    output: "2",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for ListPosition {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let inputs = plan_list_search(self, table_list, inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Int64,
+            inputs,
+            function_impl: Box::new(ListPositionImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListPositionImpl;
+
+impl ScalarFunctionImpl for ListPositionImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: DataType::Int64,
+            buffer: PrimitiveBuffer::<i64>::with_len(inputs[0].logical_len()),
+        };
+
+        search_lists(builder, inputs[0], inputs[1], |pos| match pos {
+            Some(pos) => (pos + 1) as i64,
+            None => 0,
+        })
+    }
+}
+
+/// Check the inputs for searching a list for a value, casting the value to the
+/// list's element type if needed.
+fn plan_list_search(
+    func: &impl FunctionInfo,
+    table_list: &TableList,
+    mut inputs: Vec<Expression>,
+) -> Result<Vec<Expression>> {
+    let datatypes = inputs
+        .iter()
+        .map(|expr| expr.datatype(table_list))
+        .collect::<Result<Vec<_>>>()?;
+
+    plan_check_num_args(func, &datatypes, 2)?;
+
+    let element_datatype = match &datatypes[0] {
+        DataType::List(meta) => meta.datatype.as_ref().clone(),
+        _ => return Err(invalid_input_types_error(func, &datatypes)),
+    };
+
+    // Lists with a NULL element type only contain NULLs, no need to cast the
+    // value we're searching for.
+    if datatypes[1] != element_datatype && !element_datatype.is_null() {
+        if !can_implicitly_cast(&datatypes[1], element_datatype.datatype_id()) {
+            return Err(RayexecError::new(format!(
+                "Cannot search for a value of type {} in a list with element type {}",
+                datatypes[1], element_datatype,
+            )));
+        }
+
+        let value = inputs.pop().unwrap();
+        inputs.push(expr::cast(value, element_datatype));
+    }
+
+    Ok(inputs)
+}
+
+/// Search each list for its corresponding value, writing the output of
+/// `output_fn` for the 0-based position of the first match (if any).
+///
+/// Values are compared with IS NOT DISTINCT FROM semantics, so a NULL value
+/// matches NULL elements. NULL lists produce NULL.
+fn search_lists<B, F>(
+    mut builder: ArrayBuilder<B>,
+    lists: &Array,
+    values: &Array,
+    output_fn: F,
+) -> Result<Array>
+where
+    B: ArrayDataBuffer,
+    B::Type: Sized,
+    F: Fn(Option<usize>) -> B::Type,
+{
+    let inner = match lists.array_data() {
+        ArrayData::List(list) => list.inner_array(),
+        _other => return Err(RayexecError::new("Unexpected storage type")),
+    };
+
+    let mut validity = Bitmap::new_with_all_true(builder.buffer.len());
+
+    for row in 0..lists.logical_len() {
+        let metadata = match UnaryExecutor::value_at::<PhysicalList>(lists, row)? {
+            Some(metadata) => metadata,
+            None => {
+                validity.set_unchecked(row, false);
+                continue;
+            }
+        };
+
+        let value = values.logical_value(row)?;
+
+        let found = if inner.physical_type() == PhysicalType::UntypedNull {
+            // Lists with only NULL elements might not have a typed child array,
+            // every element is NULL so only NULL values can match.
+            if value == ScalarValue::Null && metadata.len > 0 {
+                Some(0)
+            } else {
+                None
+            }
+        } else {
+            let mut found = None;
+            for pos in 0..(metadata.len as usize) {
+                if inner.scalar_value_logically_eq(&value, metadata.offset as usize + pos)? {
+                    found = Some(pos);
+                    break;
+                }
+            }
+            found
+        };
+
+        builder.buffer.put(row, &output_fn(found));
+    }
+
+    Ok(Array::new_with_validity_and_array_data(
+        builder.datatype,
+        validity,
+        builder.buffer.into_data(),
+    ))
+}
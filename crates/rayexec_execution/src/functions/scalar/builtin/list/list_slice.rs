@@ -0,0 +1,128 @@
+use rayexec_error::{RayexecError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::bitmap::Bitmap;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::physical_type::PhysicalList;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::storage::{ListItemMetadata, ListStorage};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListSlice;
+
+impl FunctionInfo for ListSlice {
+    fn name(&self) -> &'static str {
+        "list_slice"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::List, DataTypeId::Int64, DataTypeId::Int64],
+            variadic_arg: None,
+            return_type: DataTypeId::List,
+            doc: Some(&Documentation {
+                category: Category::List,
+                description: "Extract a sublist using 1-based inclusive bounds. Bounds are clamped to the list.",
+                arguments: &["list", "begin", "end"],
+                example: Some(Example {
+                    example: "list_slice([4,5,6,7], 2, 3)",
+                    output: "[5, 6]",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for ListSlice {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        plan_check_num_args(self, &datatypes, 3)?;
+
+        if !matches!(datatypes[0], DataType::List(_)) {
+            return Err(RayexecError::new(format!(
+                "Cannot slice non-list type, got {}",
+                datatypes[0]
+            )));
+        }
+
+        let begin = ConstFold::rewrite(table_list, inputs[1].clone())?
+            .try_into_scalar()?
+            .try_as_i64()?;
+        let end = ConstFold::rewrite(table_list, inputs[2].clone())?
+            .try_into_scalar()?
+            .try_as_i64()?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: datatypes[0].clone(),
+            inputs,
+            function_impl: Box::new(ListSliceImpl {
+                list_datatype: datatypes[0].clone(),
+                begin,
+                end,
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListSliceImpl {
+    list_datatype: DataType,
+    begin: i64,
+    end: i64,
+}
+
+impl ScalarFunctionImpl for ListSliceImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+        let data = match input.array_data() {
+            ArrayData::List(list) => list.as_ref(),
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        let len = input.logical_len();
+        let mut metadata = vec![ListItemMetadata::default(); len];
+        let mut validity = Bitmap::new_with_all_true(len);
+
+        // Slices reuse the child array, only the offsets and lengths of each
+        // list are adjusted.
+        UnaryExecutor::for_each::<PhysicalList, _>(input, |idx, list_meta| match list_meta {
+            Some(list_meta) => {
+                let list_len = list_meta.len as i64;
+                // Convert to 0-based, clamping to the list bounds.
+                let begin = self.begin.saturating_sub(1).clamp(0, list_len);
+                let end = self.end.clamp(begin, list_len);
+
+                metadata[idx] = ListItemMetadata {
+                    offset: list_meta.offset + begin as i32,
+                    len: (end - begin) as i32,
+                };
+            }
+            None => validity.set_unchecked(idx, false),
+        })?;
+
+        let storage = ListStorage::try_new(metadata, data.inner_array().clone())?;
+
+        Ok(Array::new_with_validity_and_array_data(
+            self.list_datatype.clone(),
+            validity,
+            storage,
+        ))
+    }
+}
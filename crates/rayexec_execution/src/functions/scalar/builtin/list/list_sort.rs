@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::bitmap::Bitmap;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::physical_type::PhysicalList;
+use crate::arrays::executor::scalar::{interleave, UnaryExecutor};
+use crate::arrays::row::encoding::{ComparableColumn, ComparableRowEncoder, ComparableRows};
+use crate::arrays::storage::{ListItemMetadata, ListStorage};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListSort;
+
+impl FunctionInfo for ListSort {
+    fn name(&self) -> &'static str {
+        "list_sort"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["array_sort"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[
+            Signature {
+                positional_args: &[DataTypeId::List],
+                variadic_arg: None,
+                return_type: DataTypeId::List,
+                doc: Some(&Documentation {
+                    category: Category::List,
+                    description: "Sort the elements of a list in ascending order. NULLs are placed last.",
+                    arguments: &["list"],
+                    example: Some(Example {
+                        example: "list_sort([3, 1, 2])",
+                        output: "[1, 2, 3]",
+                    }),
+                }),
+            },
+            Signature {
+                positional_args: &[DataTypeId::List, DataTypeId::Utf8],
+                variadic_arg: None,
+                return_type: DataTypeId::List,
+                doc: Some(&Documentation {
+                    category: Category::List,
+                    description: "Sort the elements of a list in the given order ('asc' or 'desc'). NULLs are placed last.",
+                    arguments: &["list", "order"],
+                    example: Some(Example {
+                        example: "list_sort([3, 1, 2], 'desc')",
+                        output: "[3, 2, 1]",
+                    }),
+                }),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for ListSort {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        mut inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let desc = match inputs.len() {
+            1 => false,
+            2 => {
+                let order = ConstFold::rewrite(table_list, inputs.pop().unwrap())?
+                    .try_into_scalar()?
+                    .try_into_string()?;
+                match order.to_lowercase().as_str() {
+                    "asc" => false,
+                    "desc" => true,
+                    _ => {
+                        return Err(RayexecError::new(format!(
+                            "Invalid sort order for list_sort: '{order}', expected 'asc' or 'desc'"
+                        )))
+                    }
+                }
+            }
+            _ => {
+                return Err(RayexecError::new(format!(
+                    "Expected 1 or 2 arguments for list_sort, got {}",
+                    inputs.len()
+                )))
+            }
+        };
+
+        let return_type = plan_list_input(self, table_list, &inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: return_type.clone(),
+            inputs,
+            function_impl: Box::new(ListSortImpl { return_type, desc }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListSortImpl {
+    return_type: DataType,
+    desc: bool,
+}
+
+impl ScalarFunctionImpl for ListSortImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let column = ComparableColumn {
+            desc: self.desc,
+            nulls_first: false,
+        };
+
+        select_list_elements(
+            self.return_type.clone(),
+            inputs[0],
+            column,
+            |rows, metadata, indices| {
+                let start = metadata.offset as usize;
+                let end = start + metadata.len as usize;
+
+                let mut elements: Vec<_> = (start..end).collect();
+                // Stable sort, equal elements keep their original order.
+                elements.sort_by_key(|&idx| rows.row(idx).expect("row to exist"));
+
+                indices.extend(elements);
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListDistinct;
+
+impl FunctionInfo for ListDistinct {
+    fn name(&self) -> &'static str {
+        "list_distinct"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["array_distinct"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::List],
+            variadic_arg: None,
+            return_type: DataTypeId::List,
+            doc: Some(&Documentation {
+                category: Category::List,
+                description: "Remove duplicate elements from a list, keeping the first occurrence of each. All NULL elements are considered equal.",
+                arguments: &["list"],
+                example: Some(Example {
+                    example: "list_distinct([1, 2, 1, 3])",
+                    output: "[1, 2, 3]",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for ListDistinct {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+        let return_type = plan_list_input(self, table_list, &inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: return_type.clone(),
+            inputs,
+            function_impl: Box::new(ListDistinctImpl { return_type }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListDistinctImpl {
+    return_type: DataType,
+}
+
+impl ScalarFunctionImpl for ListDistinctImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        // Order doesn't matter, we only compare the encoded elements for
+        // equality.
+        let column = ComparableColumn {
+            desc: false,
+            nulls_first: false,
+        };
+
+        select_list_elements(
+            self.return_type.clone(),
+            inputs[0],
+            column,
+            |rows, metadata, indices| {
+                let start = metadata.offset as usize;
+                let end = start + metadata.len as usize;
+
+                let mut seen = HashSet::with_capacity(metadata.len as usize);
+                for idx in start..end {
+                    let row = rows.row(idx).expect("row to exist");
+                    if seen.insert(row.data()) {
+                        indices.push(idx);
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// Get the return type for a function taking a single list as its first
+/// input, returning a list of the same type.
+fn plan_list_input(
+    func: &impl FunctionInfo,
+    table_list: &TableList,
+    inputs: &[Expression],
+) -> Result<DataType> {
+    match inputs[0].datatype(table_list)? {
+        datatype @ DataType::List(_) => Ok(datatype),
+        other => Err(invalid_input_types_error(func, &[other])),
+    }
+}
+
+/// Produce a new list array by selecting elements from each input list.
+///
+/// The elements of the input lists are encoded using the given column
+/// configuration, and `select_fn` is called for every non-NULL list with the
+/// encoded rows and the list's metadata. `select_fn` should push the (logical)
+/// indices of the child elements to include in the output list.
+fn select_list_elements<F>(
+    return_type: DataType,
+    input: &Array,
+    column: ComparableColumn,
+    mut select_fn: F,
+) -> Result<Array>
+where
+    F: FnMut(&ComparableRows, ListItemMetadata, &mut Vec<usize>),
+{
+    let child = match input.array_data() {
+        ArrayData::List(list) => list.inner_array(),
+        _other => return Err(RayexecError::new("Unexpected storage type")),
+    };
+
+    let rows = ComparableRowEncoder {
+        columns: vec![column],
+    }
+    .encode(&[child])?;
+
+    let mut elements: Vec<usize> = Vec::new();
+    let mut metadatas = Vec::with_capacity(input.logical_len());
+    let mut validity = Bitmap::new_with_all_true(input.logical_len());
+
+    for row in 0..input.logical_len() {
+        let offset = elements.len();
+
+        match UnaryExecutor::value_at::<PhysicalList>(input, row)? {
+            Some(metadata) => select_fn(&rows, metadata, &mut elements),
+            None => validity.set_unchecked(row, false),
+        }
+
+        metadatas.push(ListItemMetadata {
+            offset: offset as i32,
+            len: (elements.len() - offset) as i32,
+        });
+    }
+
+    let indices: Vec<_> = elements.into_iter().map(|idx| (0, idx)).collect();
+    let child = interleave(&[child], &indices)?;
+
+    Ok(Array::new_with_validity_and_array_data(
+        return_type,
+        validity,
+        ListStorage::try_new(metadatas, child)?,
+    ))
+}
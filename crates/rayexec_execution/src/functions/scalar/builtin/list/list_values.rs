@@ -1,13 +1,13 @@
-use rayexec_error::{RayexecError, Result};
+use rayexec_error::Result;
 
 use crate::arrays::array::Array;
 use crate::arrays::datatype::{DataType, DataTypeId, ListTypeMeta};
-use crate::arrays::executor::scalar::concat;
-use crate::arrays::storage::ListStorage;
+use crate::arrays::executor::scalar::interleave;
+use crate::arrays::storage::{ListItemMetadata, ListStorage};
 use crate::expr::Expression;
 use crate::functions::documentation::{Category, Documentation, Example};
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
-use crate::functions::{FunctionInfo, Signature};
+use crate::functions::{plan_cast_to_common_type, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,35 +42,12 @@ impl ScalarFunction for ListValues {
         table_list: &TableList,
         inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction> {
-        let first = match inputs.first() {
-            Some(expr) => expr.datatype(table_list)?,
-            None => {
-                let return_type = DataType::List(ListTypeMeta {
-                    datatype: Box::new(DataType::Null),
-                });
-                return Ok(PlannedScalarFunction {
-                    function: Box::new(*self),
-                    return_type: return_type.clone(),
-                    inputs,
-                    function_impl: Box::new(ListValuesImpl {
-                        list_datatype: return_type,
-                    }),
-                });
-            }
-        };
-
-        for input in &inputs {
-            let dt = input.datatype(table_list)?;
-            // TODO: We can add casts here.
-            if dt != first {
-                return Err(RayexecError::new(format!(
-                    "Not all inputs are the same type, got {dt}, expected {first}"
-                )));
-            }
-        }
+        // All elements need to be the same type. An empty list has an element
+        // type of NULL.
+        let (element_type, inputs) = plan_cast_to_common_type(self, table_list, inputs)?;
 
         let return_type = DataType::List(ListTypeMeta {
-            datatype: Box::new(first.clone()),
+            datatype: Box::new(element_type),
         });
 
         Ok(PlannedScalarFunction {
@@ -82,6 +59,11 @@ impl ScalarFunction for ListValues {
             }),
         })
     }
+
+    fn cast_variadic_args(&self) -> bool {
+        // Elements are cast to their common type during planning.
+        false
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -101,8 +83,23 @@ impl ScalarFunctionImpl for ListValuesImpl {
             return Ok(Array::new_with_array_data(self.list_datatype.clone(), data));
         }
 
-        let out = concat(inputs)?;
-        let data = ListStorage::single_list(out);
+        // Each row produces a list containing the values from every input at
+        // that row.
+        let num_rows = inputs[0].logical_len();
+        let num_inputs = inputs.len();
+
+        let indices: Vec<_> = (0..num_rows)
+            .flat_map(|row| (0..num_inputs).map(move |input| (input, row)))
+            .collect();
+        let child = interleave(inputs, &indices)?;
+
+        let metadata: Vec<_> = (0..num_rows)
+            .map(|row| ListItemMetadata {
+                offset: (row * num_inputs) as i32,
+                len: num_inputs as i32,
+            })
+            .collect();
+        let data = ListStorage::try_new(metadata, child)?;
 
         Ok(Array::new_with_array_data(self.list_datatype.clone(), data))
     }
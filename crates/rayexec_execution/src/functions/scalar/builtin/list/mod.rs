@@ -3,3 +3,18 @@ pub use list_values::*;
 
 mod list_extract;
 pub use list_extract::*;
+
+mod list_slice;
+pub use list_slice::*;
+
+mod list_length;
+pub use list_length::*;
+
+mod list_position;
+pub use list_position::*;
+
+mod list_flatten;
+pub use list_flatten::*;
+
+mod list_sort;
+pub use list_sort::*;
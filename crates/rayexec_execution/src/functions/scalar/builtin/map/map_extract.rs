@@ -0,0 +1,148 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::physical_type::PhysicalList;
+use crate::arrays::executor::scalar::{interleave, UnaryExecutor};
+use crate::arrays::selection;
+use crate::expr::{self, Expression};
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapExtract;
+
+impl FunctionInfo for MapExtract {
+    fn name(&self) -> &'static str {
+        "map_extract"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Map, DataTypeId::Any],
+            variadic_arg: None,
+            return_type: DataTypeId::Any,
+            doc: Some(&Documentation {
+                category: Category::Map,
+                description:
+                    "Get the value for a key in a map. Returns NULL if the key doesn't exist.",
+                arguments: &["map", "key"],
+                example: Some(Example {
+                    example: "map_extract(MAP {'a': 1, 'b': 2}, 'b')",
+                    output: "2",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for MapExtract {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        mut inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        plan_check_num_args(self, &datatypes, 2)?;
+
+        let meta = match &datatypes[0] {
+            DataType::Map(meta) => meta.clone(),
+            _ => return Err(invalid_input_types_error(self, &datatypes)),
+        };
+
+        // Cast the key we're looking up to the key type of the map. Maps with a
+        // NULL key type are empty, so the key is never compared.
+        if datatypes[1] != *meta.key && !meta.key.is_null() {
+            let key = inputs.pop().unwrap();
+            inputs.push(expr::cast(key, meta.key.as_ref().clone()));
+        }
+
+        let value_datatype = meta.value.as_ref().clone();
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: value_datatype.clone(),
+            inputs,
+            function_impl: Box::new(MapExtractImpl { value_datatype }),
+        })
+    }
+
+    fn output_nullable(&self, _inputs: &[bool]) -> bool {
+        // Missing keys produce NULL.
+        true
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MapExtractImpl {
+    value_datatype: DataType,
+}
+
+impl ScalarFunctionImpl for MapExtractImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let map = inputs[0];
+        let keys = inputs[1];
+
+        let entries = match map.array_data() {
+            ArrayData::List(list) => list.inner_array(),
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        let fields = match entries.array_data() {
+            ArrayData::Struct(storage) => storage.field_array(0).zip(storage.field_array(1)),
+            _ if entries.logical_len() == 0 => None,
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        let (entry_keys, entry_values) = match fields {
+            Some(fields) => fields,
+            None => {
+                // No entries (e.g. all maps are NULL or empty), every lookup is
+                // NULL.
+                return Array::new_typed_null_array(self.value_datatype.clone(), map.logical_len());
+            }
+        };
+
+        let nulls = Array::new_typed_null_array(self.value_datatype.clone(), 1)?;
+
+        let mut indices = Vec::with_capacity(map.logical_len());
+        for row in 0..map.logical_len() {
+            let metadata = match UnaryExecutor::value_at::<PhysicalList>(map, row)? {
+                Some(metadata) => metadata,
+                None => {
+                    indices.push((1, 0));
+                    continue;
+                }
+            };
+
+            let key = keys.logical_value(row)?;
+
+            // Keys are unique within a map, but search from the back such that
+            // the last value would win regardless.
+            let start = metadata.offset as usize;
+            let end = start + metadata.len as usize;
+
+            let mut found = None;
+            for entry_idx in (start..end).rev() {
+                let struct_idx = selection::get(entries.selection_vector(), entry_idx);
+                if entry_keys.scalar_value_logically_eq(&key, struct_idx)? {
+                    found = Some(struct_idx);
+                    break;
+                }
+            }
+
+            match found {
+                Some(struct_idx) => indices.push((0, struct_idx)),
+                None => indices.push((1, 0)),
+            }
+        }
+
+        interleave(&[entry_values, &nulls], &indices)
+    }
+}
@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+
+use rayexec_error::{RayexecError, Result};
+
+use super::{build_map, check_map_key_type};
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId, MapTypeMeta};
+use crate::arrays::executor::physical_type::PhysicalList;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::selection;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapFromEntries;
+
+impl FunctionInfo for MapFromEntries {
+    fn name(&self) -> &'static str {
+        "map_from_entries"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::List],
+            variadic_arg: None,
+            return_type: DataTypeId::Map,
+            doc: Some(&Documentation {
+                category: Category::Map,
+                description: "Create a map from a list of key/value structs. Duplicate keys take the last value.",
+                arguments: &["entries"],
+                example: Some(Example {
+                    example: "map_from_entries([{k: 'a', v: 1}, {k: 'b', v: 2}])",
+                    output: "{a=1, b=2}",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for MapFromEntries {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        plan_check_num_args(self, &datatypes, 1)?;
+
+        let meta = match &datatypes[0] {
+            DataType::List(list) => match list.datatype.as_ref() {
+                DataType::Struct(entry) if entry.fields.len() == 2 => MapTypeMeta::new(
+                    entry.fields[0].datatype.clone(),
+                    entry.fields[1].datatype.clone(),
+                ),
+                other => {
+                    return Err(RayexecError::new(format!(
+                        "Map entries must be structs with two fields, got {other}"
+                    )))
+                }
+            },
+            _ => return Err(invalid_input_types_error(self, &datatypes)),
+        };
+
+        check_map_key_type(&meta.key)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Map(meta.clone()),
+            inputs,
+            function_impl: Box::new(MapFromEntriesImpl { meta }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MapFromEntriesImpl {
+    meta: MapTypeMeta,
+}
+
+impl ScalarFunctionImpl for MapFromEntriesImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+        let entries = match input.array_data() {
+            ArrayData::List(list) => list.inner_array(),
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        let fields = match entries.array_data() {
+            ArrayData::Struct(storage) => storage.field_array(0).zip(storage.field_array(1)),
+            _ if entries.logical_len() == 0 => None,
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        let (keys, values) = match fields {
+            Some((keys, values)) => (Cow::Borrowed(keys), Cow::Borrowed(values)),
+            None => {
+                // All-null structs or no entries at all. Only valid if there's
+                // no entries to read.
+                if entries.logical_len() != 0 {
+                    return Err(RayexecError::new("Map entries cannot be NULL"));
+                }
+                (
+                    Cow::Owned(Array::new_typed_null_array(
+                        self.meta.key.as_ref().clone(),
+                        0,
+                    )?),
+                    Cow::Owned(Array::new_typed_null_array(
+                        self.meta.value.as_ref().clone(),
+                        0,
+                    )?),
+                )
+            }
+        };
+
+        let mut rows = Vec::with_capacity(input.logical_len());
+        for idx in 0..input.logical_len() {
+            match UnaryExecutor::value_at::<PhysicalList>(input, idx)? {
+                Some(metadata) => {
+                    let start = metadata.offset as usize;
+                    let end = start + metadata.len as usize;
+
+                    let mut row = Vec::with_capacity(metadata.len as usize);
+                    for entry_idx in start..end {
+                        if !entries.is_valid(entry_idx).unwrap_or(false) {
+                            return Err(RayexecError::new("Map entries cannot be NULL"));
+                        }
+                        // Physical rows in the struct map to logical rows in
+                        // the key and value arrays.
+                        let struct_idx = selection::get(entries.selection_vector(), entry_idx);
+                        row.push((struct_idx, struct_idx));
+                    }
+                    rows.push(Some(row));
+                }
+                None => rows.push(None),
+            }
+        }
+
+        build_map(&self.meta, &keys, &values, rows)
+    }
+}
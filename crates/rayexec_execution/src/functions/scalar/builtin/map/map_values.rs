@@ -0,0 +1,120 @@
+use rayexec_error::{RayexecError, Result};
+
+use super::{build_map, check_map_key_type};
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId, MapTypeMeta};
+use crate::arrays::executor::scalar::interleave;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{plan_cast_to_common_type, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapValues;
+
+impl FunctionInfo for MapValues {
+    fn name(&self) -> &'static str {
+        "map"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: Some(DataTypeId::Any),
+            return_type: DataTypeId::Map,
+            doc: Some(&Documentation {
+                category: Category::Map,
+                description: "Create a map from alternating keys and values. Duplicate keys take the last value.",
+                arguments: &["var_arg"],
+                example: Some(Example {
+                    example: "map('a', 1, 'b', 2)",
+                    output: "{a=1, b=2}",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for MapValues {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        if !inputs.len().is_multiple_of(2) {
+            return Err(RayexecError::new("map expects alternating keys and values"));
+        }
+
+        let mut keys = Vec::with_capacity(inputs.len() / 2);
+        let mut values = Vec::with_capacity(inputs.len() / 2);
+
+        let mut inputs = inputs.into_iter();
+        while let (Some(key), Some(value)) = (inputs.next(), inputs.next()) {
+            keys.push(key);
+            values.push(value);
+        }
+
+        // Keys and values are each cast to their own common type. An empty map
+        // has NULL key and value types.
+        let (key_type, keys) = plan_cast_to_common_type(self, table_list, keys)?;
+        let (value_type, values) = plan_cast_to_common_type(self, table_list, values)?;
+
+        check_map_key_type(&key_type)?;
+
+        let meta = MapTypeMeta::new(key_type, value_type);
+
+        let inputs = keys
+            .into_iter()
+            .zip(values)
+            .flat_map(|(key, value)| [key, value])
+            .collect();
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Map(meta.clone()),
+            inputs,
+            function_impl: Box::new(MapValuesImpl { meta }),
+        })
+    }
+
+    fn cast_variadic_args(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MapValuesImpl {
+    meta: MapTypeMeta,
+}
+
+impl ScalarFunctionImpl for MapValuesImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        if inputs.is_empty() {
+            let keys = Array::new_typed_null_array(self.meta.key.as_ref().clone(), 0)?;
+            let values = Array::new_typed_null_array(self.meta.value.as_ref().clone(), 0)?;
+            return build_map(&self.meta, &keys, &values, [Some([])]);
+        }
+
+        let num_rows = inputs[0].logical_len();
+        let num_entries = inputs.len() / 2;
+
+        let keys: Vec<_> = inputs.iter().step_by(2).copied().collect();
+        let values: Vec<_> = inputs.iter().skip(1).step_by(2).copied().collect();
+
+        // Flatten keys and values such that the entries for each row are
+        // contiguous.
+        let indices: Vec<_> = (0..num_rows)
+            .flat_map(|row| (0..num_entries).map(move |entry| (entry, row)))
+            .collect();
+        let keys = interleave(&keys, &indices)?;
+        let values = interleave(&values, &indices)?;
+
+        let rows = (0..num_rows).map(|row| {
+            let offset = row * num_entries;
+            Some((offset..offset + num_entries).map(|idx| (idx, idx)))
+        });
+
+        build_map(&self.meta, &keys, &values, rows)
+    }
+}
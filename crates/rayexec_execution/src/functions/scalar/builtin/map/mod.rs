@@ -0,0 +1,112 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::bitmap::Bitmap;
+use crate::arrays::datatype::{DataType, MapTypeMeta};
+use crate::arrays::executor::scalar::interleave;
+use crate::arrays::scalar::ScalarValue;
+use crate::arrays::storage::{ListItemMetadata, ListStorage, StructStorage};
+
+mod map_values;
+pub use map_values::*;
+
+mod map_from_entries;
+pub use map_from_entries::*;
+
+mod map_extract;
+pub use map_extract::*;
+
+/// Check that a data type can be used as the key of a map.
+///
+/// Keys need to be hashable scalars, so nested types are rejected.
+pub(crate) fn check_map_key_type(datatype: &DataType) -> Result<()> {
+    match datatype {
+        DataType::Struct(_) | DataType::List(_) | DataType::Map(_) => Err(RayexecError::new(
+            format!("Map keys must be a scalar type, got {datatype}"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Build a map array from arrays containing keys and values.
+///
+/// Each item in `rows` produces a row in the output map. A row is either NULL,
+/// or contains (key_idx, value_idx) pairs pointing to the logical rows in
+/// `keys` and `values` making up the entries.
+///
+/// If a key appears more than once in a row, the last value wins.
+pub(crate) fn build_map<R>(
+    meta: &MapTypeMeta,
+    keys: &Array,
+    values: &Array,
+    rows: impl IntoIterator<Item = Option<R>>,
+) -> Result<Array>
+where
+    R: IntoIterator<Item = (usize, usize)>,
+{
+    let mut key_indices: Vec<(usize, usize)> = Vec::new();
+    let mut value_indices: Vec<(usize, usize)> = Vec::new();
+    let mut metadatas = Vec::new();
+    let mut valid = Vec::new();
+
+    let mut slots: HashMap<ScalarValue, usize> = HashMap::new();
+
+    for row in rows {
+        let offset = key_indices.len();
+
+        match row {
+            Some(entries) => {
+                slots.clear();
+                for (key_idx, value_idx) in entries {
+                    let key = keys.logical_value(key_idx)?.into_owned();
+                    if key == ScalarValue::Null {
+                        return Err(RayexecError::new("Map keys cannot be NULL"));
+                    }
+
+                    match slots.entry(key) {
+                        Entry::Occupied(slot) => {
+                            // Duplicate key, last value wins.
+                            value_indices[*slot.get()] = (0, value_idx);
+                        }
+                        Entry::Vacant(slot) => {
+                            slot.insert(key_indices.len());
+                            key_indices.push((0, key_idx));
+                            value_indices.push((0, value_idx));
+                        }
+                    }
+                }
+                valid.push(true);
+            }
+            None => valid.push(false),
+        }
+
+        metadatas.push(ListItemMetadata {
+            offset: offset as i32,
+            len: (key_indices.len() - offset) as i32,
+        });
+    }
+
+    let key_child = interleave(&[keys], &key_indices)?;
+    let value_child = interleave(&[values], &value_indices)?;
+
+    let entries = Array::new_with_array_data(
+        meta.entries_datatype(),
+        StructStorage::try_new(key_indices.len(), vec![key_child, value_child])?,
+    );
+
+    let data = ListStorage::try_new(metadatas, entries)?;
+    let datatype = DataType::Map(meta.clone());
+
+    if valid.iter().all(|v| *v) {
+        Ok(Array::new_with_array_data(datatype, data))
+    } else {
+        Ok(Array::new_with_validity_and_array_data(
+            datatype,
+            Bitmap::from_iter(valid),
+            data,
+        ))
+    }
+}
@@ -1,9 +1,11 @@
 pub mod arith;
 pub mod boolean;
 pub mod comparison;
+pub mod conditional;
 pub mod datetime;
 pub mod is;
 pub mod list;
+pub mod map;
 pub mod negate;
 pub mod numeric;
 pub mod random;
@@ -24,6 +26,15 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         Box::new(arith::Mul),
         Box::new(arith::Div),
         Box::new(arith::Rem),
+        Box::new(arith::FloorDiv),
+        Box::new(arith::FloorMod),
+        Box::new(arith::Gcd),
+        Box::new(arith::Lcm),
+        Box::new(arith::BitAnd),
+        Box::new(arith::BitOr),
+        Box::new(arith::BitXor),
+        Box::new(arith::ShiftLeft),
+        Box::new(arith::ShiftRight),
         // Boolean
         Box::new(boolean::And),
         Box::new(boolean::Or),
@@ -34,6 +45,9 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         Box::new(comparison::LtEq),
         Box::new(comparison::Gt),
         Box::new(comparison::GtEq),
+        // Conditional
+        Box::new(conditional::Coalesce),
+        Box::new(conditional::NullIf),
         // Numeric
         Box::new(numeric::Ceil::new()),
         Box::new(numeric::Floor::new()),
@@ -41,23 +55,32 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         Box::new(numeric::Acos::new()),
         Box::new(numeric::Asin::new()),
         Box::new(numeric::Atan::new()),
+        Box::new(numeric::Atan2),
         Box::new(numeric::Cbrt::new()),
         Box::new(numeric::Cos::new()),
         Box::new(numeric::Exp::new()),
         Box::new(numeric::Ln::new()),
         Box::new(numeric::Log::new()),
+        Box::new(numeric::Log10::new()),
         Box::new(numeric::Log2::new()),
         Box::new(numeric::Sin::new()),
         Box::new(numeric::Sqrt::new()),
         Box::new(numeric::Tan::new()),
         Box::new(numeric::Degrees::new()),
         Box::new(numeric::Radians::new()),
+        Box::new(numeric::Pi),
         Box::new(numeric::IsNan),
+        Box::new(numeric::Sign),
+        Box::new(numeric::Trunc),
+        Box::new(numeric::Round),
+        Box::new(numeric::Power),
         // String
         Box::new(string::Lower),
         Box::new(string::Upper),
         Box::new(string::Repeat),
         Box::new(string::Substring),
+        Box::new(string::Left),
+        Box::new(string::Right),
         Box::new(string::StartsWith),
         Box::new(string::EndsWith),
         Box::new(string::Contains),
@@ -65,7 +88,10 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         Box::new(string::ByteLength),
         Box::new(string::BitLength),
         Box::new(string::Concat),
+        Box::new(string::ConcatOperator),
         Box::new(string::RegexpReplace),
+        Box::new(string::RegexpLike),
+        Box::new(string::RegexpExtract),
         Box::new(string::Ascii),
         Box::new(string::LeftPad),
         Box::new(string::RightPad),
@@ -75,6 +101,8 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         Box::new(string::Like),
         // Struct
         Box::new(struct_funcs::StructPack),
+        Box::new(struct_funcs::StructRow),
+        Box::new(struct_funcs::StructExtract),
         // Unary
         Box::new(negate::Negate),
         Box::new(negate::Not),
@@ -83,11 +111,25 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         // List
         Box::new(list::ListExtract),
         Box::new(list::ListValues),
+        Box::new(list::ListSlice),
+        Box::new(list::Cardinality),
+        Box::new(list::ArrayLength),
+        Box::new(list::ListContains),
+        Box::new(list::ListPosition),
+        Box::new(list::ListFlatten),
+        Box::new(list::ListSort),
+        Box::new(list::ListDistinct),
+        // Map
+        Box::new(map::MapValues),
+        Box::new(map::MapFromEntries),
+        Box::new(map::MapExtract),
         // Datetime
         Box::new(datetime::DatePart),
         Box::new(datetime::DateTrunc),
         Box::new(datetime::EpochMs),
         Box::new(datetime::Epoch),
+        Box::new(datetime::ToChar),
+        Box::new(datetime::ToTimestamp),
         // Is
         Box::new(is::IsNull),
         Box::new(is::IsNotNull),
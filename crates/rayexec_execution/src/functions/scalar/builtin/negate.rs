@@ -4,7 +4,7 @@ use rayexec_error::Result;
 
 use crate::arrays::array::{Array, ArrayData};
 use crate::arrays::datatype::{DataType, DataTypeId};
-use crate::arrays::executor::builder::{ArrayBuilder, BooleanBuffer, PrimitiveBuffer};
+use crate::arrays::executor::builder::{ArrayBuilder, BooleanBuffer};
 use crate::arrays::executor::physical_type::{
     PhysicalBool,
     PhysicalF16,
@@ -21,7 +21,17 @@ use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
 use crate::functions::documentation::{Category, Documentation, Example};
-use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::scalar::builtin::arith::{
+    execute_checked_unary,
+    CheckedArith,
+    OverflowBehavior,
+};
+use crate::functions::scalar::{
+    PlannedScalarFunction,
+    ScalarFunction,
+    ScalarFunctionImpl,
+    ScalarPlanOptions,
+};
 use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
 
@@ -54,6 +64,15 @@ impl ScalarFunction for Negate {
         &self,
         table_list: &TableList,
         inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_options(table_list, inputs, ScalarPlanOptions::default())
+    }
+
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        options: ScalarPlanOptions,
     ) -> Result<PlannedScalarFunction> {
         plan_check_num_args(self, &inputs, 1)?;
 
@@ -61,14 +80,22 @@ impl ScalarFunction for Negate {
 
         // TODO: Interval
         let function_impl: Box<dyn ScalarFunctionImpl> = match dt.clone() {
-            dt @ DataType::Int8 => Box::new(NegateImpl::<PhysicalI8>::new(dt)),
-            dt @ DataType::Int16 => Box::new(NegateImpl::<PhysicalI16>::new(dt)),
-            dt @ DataType::Int32 => Box::new(NegateImpl::<PhysicalI32>::new(dt)),
-            dt @ DataType::Int64 => Box::new(NegateImpl::<PhysicalI64>::new(dt)),
-            dt @ DataType::Int128 => Box::new(NegateImpl::<PhysicalI128>::new(dt)),
-            dt @ DataType::Float16 => Box::new(NegateImpl::<PhysicalF16>::new(dt)),
-            dt @ DataType::Float32 => Box::new(NegateImpl::<PhysicalF32>::new(dt)),
-            dt @ DataType::Float64 => Box::new(NegateImpl::<PhysicalF64>::new(dt)),
+            dt @ DataType::Int8 => Box::new(NegateImpl::<PhysicalI8>::new(dt, options.overflow)),
+            dt @ DataType::Int16 => Box::new(NegateImpl::<PhysicalI16>::new(dt, options.overflow)),
+            dt @ DataType::Int32 => Box::new(NegateImpl::<PhysicalI32>::new(dt, options.overflow)),
+            dt @ DataType::Int64 => Box::new(NegateImpl::<PhysicalI64>::new(dt, options.overflow)),
+            dt @ DataType::Int128 => {
+                Box::new(NegateImpl::<PhysicalI128>::new(dt, options.overflow))
+            }
+            dt @ DataType::Float16 => {
+                Box::new(NegateImpl::<PhysicalF16>::new(dt, options.overflow))
+            }
+            dt @ DataType::Float32 => {
+                Box::new(NegateImpl::<PhysicalF32>::new(dt, options.overflow))
+            }
+            dt @ DataType::Float64 => {
+                Box::new(NegateImpl::<PhysicalF64>::new(dt, options.overflow))
+            }
             other => return Err(invalid_input_types_error(self, &[other])),
         };
 
@@ -84,13 +111,15 @@ impl ScalarFunction for Negate {
 #[derive(Debug, Clone)]
 pub struct NegateImpl<S> {
     datatype: DataType, // TODO: Would be nice not needing to store this.
+    overflow: OverflowBehavior,
     _s: PhantomData<S>,
 }
 
 impl<S> NegateImpl<S> {
-    fn new(datatype: DataType) -> Self {
+    fn new(datatype: DataType, overflow: OverflowBehavior) -> Self {
         NegateImpl {
             datatype,
+            overflow,
             _s: PhantomData,
         }
     }
@@ -99,20 +128,23 @@ impl<S> NegateImpl<S> {
 impl<S> ScalarFunctionImpl for NegateImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Neg<Output = S::Type<'static>> + Default + Copy,
-    ArrayData: From<PrimitiveStorage<S::Type<'static>>>,
+    for<'a> S::Type<'a>: CheckedArith,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        use std::ops::Neg;
-
-        let a = inputs[0];
-        let datatype = self.datatype.clone();
-        let builder = ArrayBuilder {
-            datatype,
-            buffer: PrimitiveBuffer::with_len(a.logical_len()),
-        };
+        execute_checked_unary::<S>(
+            "negate",
+            inputs[0],
+            self.datatype.clone(),
+            self.overflow,
+            CheckedArith::overflowing_neg,
+            CheckedArith::saturating_neg,
+        )
+    }
 
-        UnaryExecutor::execute::<S, _, _>(a, builder, |a, buf| buf.put(&(a.neg())))
+    fn output_nullable(&self, inputs: &[bool]) -> Option<bool> {
+        // Overflowing may produce NULLs from non-NULL inputs.
+        Some(self.overflow == OverflowBehavior::Null || inputs.iter().any(|n| *n))
     }
 }
 
@@ -176,3 +208,76 @@ impl ScalarFunctionImpl for NotImpl {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::expr;
+
+    fn plan_negate_i8(overflow: OverflowBehavior) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Int8], vec!["a".to_string()])
+            .unwrap();
+
+        Negate
+            .plan_with_options(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0)],
+                ScalarPlanOptions {
+                    overflow,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn negate_i8_min_error() {
+        let planned = plan_negate_i8(OverflowBehavior::Error);
+        let a = Array::from_iter([1_i8, i8::MIN, -4]);
+        planned.function_impl.execute(&[&a]).unwrap_err();
+    }
+
+    #[test]
+    fn negate_i8_min_wrap() {
+        let planned = plan_negate_i8(OverflowBehavior::Wrap);
+        let a = Array::from_iter([1_i8, i8::MIN, -4]);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+
+        let expected = Array::from_iter([-1_i8, i8::MIN, 4]);
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn negate_i8_min_null() {
+        let planned = plan_negate_i8(OverflowBehavior::Null);
+        let a = Array::from_iter([1_i8, i8::MIN, -4]);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+
+        assert_eq!(ScalarValue::Int8(-1), out.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::Null, out.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::Int8(4), out.logical_value(2).unwrap());
+    }
+
+    #[test]
+    fn negate_i8_min_saturate() {
+        let planned = plan_negate_i8(OverflowBehavior::Saturate);
+        let a = Array::from_iter([1_i8, i8::MIN, -4]);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+
+        let expected = Array::from_iter([-1_i8, i8::MAX, 4]);
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn negate_i8_max_no_overflow() {
+        let planned = plan_negate_i8(OverflowBehavior::Error);
+        let a = Array::from_iter([i8::MAX]);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+
+        let expected = Array::from_iter([-i8::MAX]);
+        assert_eq!(expected, out);
+    }
+}
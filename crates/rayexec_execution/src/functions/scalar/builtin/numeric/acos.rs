@@ -16,7 +16,12 @@ pub struct AcosOp;
 
 impl UnaryInputNumericOperation for AcosOp {
     const NAME: &'static str = "acos";
-    const DESCRIPTION: &'static str = "Compute the arccosine of value";
+    const DESCRIPTION: &'static str =
+        "Compute the arccosine of value. Returns NaN for values outside [-1, 1]";
+
+    fn in_domain<F: Float>(v: F) -> bool {
+        v.is_nan() || v.abs() <= F::one()
+    }
 
     fn execute_float<'a, S>(input: &'a Array, ret: DataType) -> Result<Array>
     where
@@ -16,7 +16,12 @@ pub struct AsinOp;
 
 impl UnaryInputNumericOperation for AsinOp {
     const NAME: &'static str = "asin";
-    const DESCRIPTION: &'static str = "Compute the arcsine of value";
+    const DESCRIPTION: &'static str =
+        "Compute the arcsine of value. Returns NaN for values outside [-1, 1]";
+
+    fn in_domain<F: Float>(v: F) -> bool {
+        v.is_nan() || v.abs() <= F::one()
+    }
 
     fn execute_float<'a, S>(input: &'a Array, ret: DataType) -> Result<Array>
     where
@@ -0,0 +1,155 @@
+use std::marker::PhantomData;
+
+use num_traits::Float;
+use rayexec_error::Result;
+
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalStorage,
+};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::{self, Expression};
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Atan2;
+
+impl FunctionInfo for Atan2 {
+    fn name(&self) -> &'static str {
+        "atan2"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description: "Compute the arctangent of y/x, using the signs of both arguments to determine the quadrant.",
+            arguments: &["y", "x"],
+            example: Some(Example {
+                example: "atan2(1.0, 1.0)",
+                output: "0.7853981633974483",
+            }),
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Float16, DataTypeId::Float16],
+                variadic_arg: None,
+                return_type: DataTypeId::Float16,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float32, DataTypeId::Float32],
+                variadic_arg: None,
+                return_type: DataTypeId::Float32,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64, DataTypeId::Float64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+            // Integers are promoted to Float64.
+            Signature {
+                positional_args: &[DataTypeId::Int64, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for Atan2 {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        mut inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        if inputs[0].datatype(table_list)?.is_integer()
+            && inputs[1].datatype(table_list)?.is_integer()
+        {
+            inputs = inputs
+                .into_iter()
+                .map(|input| expr::cast(input, DataType::Float64))
+                .collect();
+        }
+
+        let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) = match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Float16, DataType::Float16) => (
+                Box::new(Atan2Impl::<PhysicalF16>::new(DataType::Float16)),
+                DataType::Float16,
+            ),
+            (DataType::Float32, DataType::Float32) => (
+                Box::new(Atan2Impl::<PhysicalF32>::new(DataType::Float32)),
+                DataType::Float32,
+            ),
+            (DataType::Float64, DataType::Float64) => (
+                Box::new(Atan2Impl::<PhysicalF64>::new(DataType::Float64)),
+                DataType::Float64,
+            ),
+            (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
+        };
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type,
+            inputs,
+            function_impl,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Atan2Impl<S> {
+    datatype: DataType,
+    _s: PhantomData<S>,
+}
+
+impl<S> Atan2Impl<S> {
+    fn new(datatype: DataType) -> Self {
+        Atan2Impl {
+            datatype,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunctionImpl for Atan2Impl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: Float + Default,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        atan2::<S>(inputs[0], inputs[1], self.datatype.clone())
+    }
+}
+
+fn atan2<'a, S>(y: &'a Array, x: &'a Array, datatype: DataType) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Float + Default,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(y.logical_len()),
+    };
+
+    BinaryExecutor::execute::<S, S, _, _>(y, x, builder, |y, x, buf| buf.put(&y.atan2(x)))
+}
@@ -31,3 +31,30 @@ impl UnaryInputNumericOperation for CbrtOp {
         UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| buf.put(&v.cbrt()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::functions::scalar::ScalarFunction;
+    use crate::logical::binder::table_list::TableList;
+
+    #[test]
+    fn cbrt_f64() {
+        let a = Array::from_iter([-27.0_f64, 8.0, 0.0]);
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Float64], vec!["a".to_string()])
+            .unwrap();
+
+        let planned = Cbrt::new()
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected = Array::from_iter([-3.0_f64, 2.0, 0.0]);
+
+        assert_eq!(expected, out);
+    }
+}
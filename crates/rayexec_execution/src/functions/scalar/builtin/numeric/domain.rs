@@ -0,0 +1,42 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::Array;
+
+/// Behavior when a math function receives an input outside of its domain,
+/// e.g. `sqrt(-1)` or `ln(0)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DomainErrorBehavior {
+    /// Produce NaN for the row.
+    #[default]
+    Nan,
+    /// Return an error.
+    Error,
+}
+
+impl DomainErrorBehavior {
+    /// Apply this behavior given the first row (if any) that was outside of
+    /// the function's domain.
+    ///
+    /// `func` and `inputs` are only used for producing the error message.
+    pub(crate) fn check(
+        &self,
+        func: &str,
+        inputs: &[&Array],
+        out_of_domain: Option<usize>,
+    ) -> Result<()> {
+        let idx = match (self, out_of_domain) {
+            (DomainErrorBehavior::Error, Some(idx)) => idx,
+            _ => return Ok(()),
+        };
+
+        let args = inputs
+            .iter()
+            .map(|arr| arr.logical_value(idx).map(|v| v.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+
+        Err(RayexecError::new(format!(
+            "Input out of domain for {func}({})",
+            args.join(", "),
+        )))
+    }
+}
@@ -18,6 +18,10 @@ impl UnaryInputNumericOperation for LnOp {
     const NAME: &'static str = "ln";
     const DESCRIPTION: &'static str = "Compute natural log of value";
 
+    fn in_domain<F: Float>(v: F) -> bool {
+        v.is_nan() || v > F::zero()
+    }
+
     fn execute_float<'a, S>(input: &'a Array, ret: DataType) -> Result<Array>
     where
         S: PhysicalStorage,
@@ -28,6 +32,13 @@ impl UnaryInputNumericOperation for LnOp {
             datatype: ret,
             buffer: PrimitiveBuffer::with_len(input.logical_len()),
         };
-        UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| buf.put(&v.ln()))
+        UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| {
+            let out = if Self::in_domain(v) {
+                v.ln()
+            } else {
+                Float::nan()
+            };
+            buf.put(&out)
+        })
     }
 }
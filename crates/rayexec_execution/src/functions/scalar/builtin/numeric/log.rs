@@ -1,22 +1,215 @@
 use num_traits::Float;
 use rayexec_error::Result;
 
-use super::{UnaryInputNumericOperation, UnaryInputNumericScalar};
+use super::{DomainErrorBehavior, UnaryInputNumericOperation, UnaryInputNumericScalar};
 use crate::arrays::array::{Array, ArrayData};
-use crate::arrays::datatype::DataType;
+use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
-use crate::arrays::executor::physical_type::PhysicalStorage;
-use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::executor::physical_type::{PhysicalF64, PhysicalStorage};
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
 use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{
+    invalid_input_types_error,
+    plan_check_num_args_one_of,
+    FunctionInfo,
+    Signature,
+};
+use crate::logical::binder::table_list::TableList;
 
-pub type Log = UnaryInputNumericScalar<LogOp>;
+/// Logarithm.
+///
+/// `log(x)` computes the base-10 log of `x`, and `log(b, x)` computes the log
+/// of `x` in base `b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Log {
+    domain: DomainErrorBehavior,
+}
+
+impl Log {
+    pub const fn new() -> Self {
+        Log {
+            domain: DomainErrorBehavior::Nan,
+        }
+    }
+
+    /// Set the behavior for inputs outside of the function's domain.
+    pub const fn with_domain_error_behavior(mut self, domain: DomainErrorBehavior) -> Self {
+        self.domain = domain;
+        self
+    }
+}
+
+impl FunctionInfo for Log {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description: "Compute the base-10 log of a value.",
+            arguments: &["value"],
+            example: Some(Example {
+                example: "log(100)",
+                output: "2",
+            }),
+        };
+
+        const DOC_BASE: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description: "Compute the log of a value in the given base.",
+            arguments: &["base", "value"],
+            example: Some(Example {
+                example: "log(2, 8)",
+                output: "3",
+            }),
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Float16],
+                variadic_arg: None,
+                return_type: DataTypeId::Float16,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float32],
+                variadic_arg: None,
+                return_type: DataTypeId::Float32,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64, DataTypeId::Float64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC_BASE),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for Log {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args_one_of(self, &inputs, [1, 2])?;
+
+        if inputs.len() == 1 {
+            let mut planned = Log10::new()
+                .with_domain_error_behavior(self.domain)
+                .plan(table_list, inputs)?;
+            planned.function = Box::new(*self);
+            return Ok(planned);
+        }
+
+        match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Float64, DataType::Float64) => (),
+            (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
+        }
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Float64,
+            inputs,
+            function_impl: Box::new(LogBaseImpl {
+                domain: self.domain,
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogBaseImpl {
+    domain: DomainErrorBehavior,
+}
+
+impl LogBaseImpl {
+    fn in_domain(base: f64, v: f64) -> bool {
+        if base.is_nan() || v.is_nan() {
+            return true;
+        }
+        base > 0.0 && base != 1.0 && v > 0.0
+    }
+}
+
+impl ScalarFunctionImpl for LogBaseImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let base = inputs[0];
+        let value = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Float64,
+            buffer: PrimitiveBuffer::with_len(base.logical_len()),
+        };
+
+        let mut saw_out_of_domain = false;
+        let out = BinaryExecutor::execute::<PhysicalF64, PhysicalF64, _, _>(
+            base,
+            value,
+            builder,
+            |b, v, buf| {
+                let out = if Self::in_domain(b, v) {
+                    v.log(b)
+                } else {
+                    saw_out_of_domain = true;
+                    f64::NAN
+                };
+                buf.put(&out)
+            },
+        )?;
+
+        if saw_out_of_domain && self.domain == DomainErrorBehavior::Error {
+            let mut out_of_domain = None;
+            for idx in 0..base.logical_len() {
+                let b = UnaryExecutor::value_at::<PhysicalF64>(base, idx)?;
+                let v = UnaryExecutor::value_at::<PhysicalF64>(value, idx)?;
+                if let (Some(b), Some(v)) = (b, v) {
+                    if !Self::in_domain(b, v) {
+                        out_of_domain = Some(idx);
+                        break;
+                    }
+                }
+            }
+            self.domain.check("log", inputs, out_of_domain)?;
+        }
+
+        Ok(out)
+    }
+}
+
+pub type Log10 = UnaryInputNumericScalar<Log10Op>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct LogOp;
+pub struct Log10Op;
+
+impl UnaryInputNumericOperation for Log10Op {
+    const NAME: &'static str = "log10";
+    const DESCRIPTION: &'static str =
+        "Compute base-10 log of value. Returns NaN for values less than or equal to zero";
 
-impl UnaryInputNumericOperation for LogOp {
-    const NAME: &'static str = "log";
-    const DESCRIPTION: &'static str = "Compute base-10 log of value";
+    fn in_domain<F: Float>(v: F) -> bool {
+        v.is_nan() || v > F::zero()
+    }
 
     fn execute_float<'a, S>(input: &'a Array, ret: DataType) -> Result<Array>
     where
@@ -28,7 +221,14 @@ impl UnaryInputNumericOperation for LogOp {
             datatype: ret,
             buffer: PrimitiveBuffer::with_len(input.logical_len()),
         };
-        UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| buf.put(&v.log10()))
+        UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| {
+            let out = if Self::in_domain(v) {
+                v.log10()
+            } else {
+                Float::nan()
+            };
+            buf.put(&out)
+        })
     }
 }
 
@@ -39,7 +239,12 @@ pub struct LogOp2;
 
 impl UnaryInputNumericOperation for LogOp2 {
     const NAME: &'static str = "log2";
-    const DESCRIPTION: &'static str = "Compute base-2 log of value";
+    const DESCRIPTION: &'static str =
+        "Compute base-2 log of value. Returns NaN for values less than or equal to zero";
+
+    fn in_domain<F: Float>(v: F) -> bool {
+        v.is_nan() || v > F::zero()
+    }
 
     fn execute_float<'a, S>(input: &'a Array, ret: DataType) -> Result<Array>
     where
@@ -51,6 +256,13 @@ impl UnaryInputNumericOperation for LogOp2 {
             datatype: ret,
             buffer: PrimitiveBuffer::with_len(input.logical_len()),
         };
-        UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| buf.put(&v.log2()))
+        UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| {
+            let out = if Self::in_domain(v) {
+                v.log2()
+            } else {
+                Float::nan()
+            };
+            buf.put(&out)
+        })
     }
 }
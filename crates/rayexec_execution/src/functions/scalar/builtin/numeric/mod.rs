@@ -2,19 +2,26 @@ mod abs;
 mod acos;
 mod asin;
 mod atan;
+mod atan2;
 mod cbrt;
 mod ceil;
 mod cos;
 mod degrees;
+mod domain;
 mod exp;
 mod floor;
 mod isnan;
 mod ln;
 mod log;
+mod pi;
+mod power;
 mod radians;
+mod round;
+mod sign;
 mod sin;
 mod sqrt;
 mod tan;
+mod trunc;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
@@ -22,21 +29,28 @@ pub use abs::*;
 pub use acos::*;
 pub use asin::*;
 pub use atan::*;
+pub use atan2::*;
 pub use cbrt::*;
 pub use ceil::*;
 pub use cos::*;
 pub use degrees::*;
+pub use domain::*;
 pub use exp::*;
 pub use floor::*;
 pub use isnan::*;
 pub use ln::*;
 pub use log::*;
 use num_traits::Float;
+pub use pi::*;
+pub use power::*;
 pub use radians::*;
 use rayexec_error::{RayexecError, Result};
+pub use round::*;
+pub use sign::*;
 pub use sin::*;
 pub use sqrt::*;
 pub use tan::*;
+pub use trunc::*;
 
 use crate::arrays::array::{Array, ArrayData};
 use crate::arrays::datatype::{DataType, DataTypeId};
@@ -47,8 +61,9 @@ use crate::arrays::executor::physical_type::{
     PhysicalStorage,
     PhysicalType,
 };
+use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::arrays::storage::PrimitiveStorage;
-use crate::expr::Expression;
+use crate::expr::{self, Expression};
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
 use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
@@ -74,6 +89,14 @@ const UNARY_NUMERIC_INPUT_OUTPUT_SIGS: &[Signature] = &[
         return_type: DataTypeId::Float64,
         doc: None,
     },
+    // Integers are promoted to Float64. Listed so that integer inputs don't
+    // get implicitly cast to Float16.
+    Signature {
+        positional_args: &[DataTypeId::Int64],
+        variadic_arg: None,
+        return_type: DataTypeId::Float64,
+        doc: None,
+    },
 ];
 
 /// Helper trait for defining math functions on floats.
@@ -81,6 +104,14 @@ pub trait UnaryInputNumericOperation: Debug + Clone + Copy + Sync + Send + 'stat
     const NAME: &'static str;
     const DESCRIPTION: &'static str;
 
+    /// If the value is within the domain of this function.
+    ///
+    /// Defaults to accepting all values. NaN inputs should always be
+    /// considered in domain.
+    fn in_domain<F: Float>(_v: F) -> bool {
+        true
+    }
+
     fn execute_float<'a, S>(input: &'a Array, ret: DataType) -> Result<Array>
     where
         S: PhysicalStorage,
@@ -92,12 +123,22 @@ pub trait UnaryInputNumericOperation: Debug + Clone + Copy + Sync + Send + 'stat
 /// numeric argument.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct UnaryInputNumericScalar<O: UnaryInputNumericOperation> {
+    domain: DomainErrorBehavior,
     _op: PhantomData<O>,
 }
 
 impl<O: UnaryInputNumericOperation> UnaryInputNumericScalar<O> {
     pub const fn new() -> Self {
-        UnaryInputNumericScalar { _op: PhantomData }
+        UnaryInputNumericScalar {
+            domain: DomainErrorBehavior::Nan,
+            _op: PhantomData,
+        }
+    }
+
+    /// Set the behavior for inputs outside of the function's domain.
+    pub const fn with_domain_error_behavior(mut self, domain: DomainErrorBehavior) -> Self {
+        self.domain = domain;
+        self
     }
 }
 
@@ -115,14 +156,19 @@ impl<O: UnaryInputNumericOperation> ScalarFunction for UnaryInputNumericScalar<O
     fn plan(
         &self,
         table_list: &TableList,
-        inputs: Vec<Expression>,
+        mut inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction> {
         plan_check_num_args(self, &inputs, 1)?;
-        let datatype = inputs[0].datatype(table_list)?;
+        let mut datatype = inputs[0].datatype(table_list)?;
 
         // TODO: Decimals too
         match &datatype {
             DataType::Float16 | DataType::Float32 | DataType::Float64 => (),
+            other if other.is_integer() => {
+                datatype = DataType::Float64;
+                let input = inputs.pop().expect("single input");
+                inputs.push(expr::cast(input, DataType::Float64));
+            }
             other => return Err(invalid_input_types_error(self, &[other])),
         }
 
@@ -132,6 +178,7 @@ impl<O: UnaryInputNumericOperation> ScalarFunction for UnaryInputNumericScalar<O
             inputs,
             function_impl: Box::new(UnaryInputNumericScalarImpl::<O> {
                 ret: datatype,
+                domain: self.domain,
                 _op: PhantomData,
             }),
         })
@@ -141,19 +188,210 @@ impl<O: UnaryInputNumericOperation> ScalarFunction for UnaryInputNumericScalar<O
 #[derive(Debug, Clone)]
 pub(crate) struct UnaryInputNumericScalarImpl<O: UnaryInputNumericOperation> {
     ret: DataType,
+    domain: DomainErrorBehavior,
     _op: PhantomData<O>,
 }
 
+impl<O: UnaryInputNumericOperation> UnaryInputNumericScalarImpl<O> {
+    fn execute_checked<'a, S>(&self, input: &'a Array) -> Result<Array>
+    where
+        S: PhysicalStorage,
+        S::Type<'a>: Float + Default,
+        ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+    {
+        if self.domain == DomainErrorBehavior::Error {
+            let mut out_of_domain = None;
+            UnaryExecutor::for_each::<S, _>(input, |idx, v| {
+                if let Some(v) = v {
+                    if out_of_domain.is_none() && !O::in_domain(v) {
+                        out_of_domain = Some(idx);
+                    }
+                }
+            })?;
+            self.domain.check(O::NAME, &[input], out_of_domain)?;
+        }
+
+        O::execute_float::<S>(input, self.ret.clone())
+    }
+}
+
 impl<O: UnaryInputNumericOperation> ScalarFunctionImpl for UnaryInputNumericScalarImpl<O> {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
         let input = inputs[0];
         match input.physical_type() {
-            PhysicalType::Float16 => O::execute_float::<PhysicalF16>(input, self.ret.clone()),
-            PhysicalType::Float32 => O::execute_float::<PhysicalF32>(input, self.ret.clone()),
-            PhysicalType::Float64 => O::execute_float::<PhysicalF64>(input, self.ret.clone()),
+            PhysicalType::Float16 => self.execute_checked::<PhysicalF16>(input),
+            PhysicalType::Float32 => self.execute_checked::<PhysicalF32>(input),
+            PhysicalType::Float64 => self.execute_checked::<PhysicalF64>(input),
             other => Err(RayexecError::new(format!(
                 "Invalid physical type: {other:?}"
             ))),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::expr;
+
+    /// Execute a float64 function on the given inputs, returning the output
+    /// values.
+    fn execute_f64(func: &dyn ScalarFunction, inputs: &[&[f64]]) -> Vec<f64> {
+        try_execute_f64(func, inputs).unwrap()
+    }
+
+    fn try_execute_f64(func: &dyn ScalarFunction, inputs: &[&[f64]]) -> Result<Vec<f64>> {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Float64; inputs.len()],
+                (0..inputs.len()).map(|idx| format!("c{idx}")).collect(),
+            )
+            .unwrap();
+
+        let planned = func
+            .plan(
+                &table_list,
+                (0..inputs.len())
+                    .map(|idx| expr::col_ref(table_ref, idx))
+                    .collect(),
+            )
+            .unwrap();
+
+        let arrays: Vec<_> = inputs
+            .iter()
+            .map(|vals| Array::from_iter(vals.iter().copied()))
+            .collect();
+        let refs: Vec<_> = arrays.iter().collect();
+        let out = planned.function_impl.execute(&refs)?;
+
+        Ok((0..out.logical_len())
+            .map(|idx| match out.logical_value(idx).unwrap() {
+                ScalarValue::Float64(v) => v,
+                other => panic!("unexpected value: {other:?}"),
+            })
+            .collect())
+    }
+
+    fn assert_approx_eq(expected: &[f64], got: &[f64]) {
+        assert_eq!(expected.len(), got.len());
+        for (expected, got) in expected.iter().zip(got) {
+            assert!(
+                (expected - got).abs() < 1e-12,
+                "expected: {expected}, got: {got}"
+            );
+        }
+    }
+
+    #[test]
+    fn trig_known_values() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, FRAC_PI_6, PI};
+
+        let got = execute_f64(&Sin::new(), &[&[0.0, FRAC_PI_6, FRAC_PI_2]]);
+        assert_approx_eq(&[0.0, 0.5, 1.0], &got);
+
+        let got = execute_f64(&Cos::new(), &[&[0.0, PI]]);
+        assert_approx_eq(&[1.0, -1.0], &got);
+
+        let got = execute_f64(&Tan::new(), &[&[0.0, FRAC_PI_4]]);
+        assert_approx_eq(&[0.0, 1.0], &got);
+
+        let got = execute_f64(&Asin::new(), &[&[0.0, 0.5, -1.0]]);
+        assert_approx_eq(&[0.0, FRAC_PI_6, -FRAC_PI_2], &got);
+
+        let got = execute_f64(&Acos::new(), &[&[1.0, 0.0, -1.0]]);
+        assert_approx_eq(&[0.0, FRAC_PI_2, PI], &got);
+
+        let got = execute_f64(&Atan::new(), &[&[0.0, 1.0]]);
+        assert_approx_eq(&[0.0, FRAC_PI_4], &got);
+    }
+
+    #[test]
+    fn atan2_quadrants() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        let got = execute_f64(
+            &Atan2,
+            &[&[1.0, 1.0, -1.0, 1.0, 0.0], &[1.0, -1.0, -1.0, 0.0, -1.0]],
+        );
+        assert_approx_eq(
+            &[FRAC_PI_4, 3.0 * FRAC_PI_4, -3.0 * FRAC_PI_4, FRAC_PI_2, PI],
+            &got,
+        );
+    }
+
+    #[test]
+    fn asin_acos_out_of_domain() {
+        let got = execute_f64(&Asin::new(), &[&[1.5, -2.0]]);
+        assert!(got.iter().all(|v| v.is_nan()), "got: {got:?}");
+
+        let got = execute_f64(&Acos::new(), &[&[1.5, -2.0]]);
+        assert!(got.iter().all(|v| v.is_nan()), "got: {got:?}");
+    }
+
+    #[test]
+    fn log_exp_known_values() {
+        use std::f64::consts::E;
+
+        let got = execute_f64(&Ln::new(), &[&[1.0, E]]);
+        assert_approx_eq(&[0.0, 1.0], &got);
+
+        let got = execute_f64(&Log::new(), &[&[1.0, 100.0]]);
+        assert_approx_eq(&[0.0, 2.0], &got);
+
+        let got = execute_f64(&Log10::new(), &[&[1000.0]]);
+        assert_approx_eq(&[3.0], &got);
+
+        let got = execute_f64(&Log::new(), &[&[2.0, 3.0], &[8.0, 81.0]]);
+        assert_approx_eq(&[3.0, 4.0], &got);
+
+        let got = execute_f64(&Exp::new(), &[&[0.0, 1.0]]);
+        assert_approx_eq(&[1.0, E], &got);
+
+        let got = execute_f64(&Sqrt::new(), &[&[9.0]]);
+        assert_approx_eq(&[3.0], &got);
+    }
+
+    #[test]
+    fn domain_error_nan() {
+        let got = execute_f64(&Ln::new(), &[&[-1.0, 0.0]]);
+        assert!(got.iter().all(|v| v.is_nan()), "got: {got:?}");
+
+        let got = execute_f64(&Log::new(), &[&[1.0, 2.0], &[8.0, 0.0]]);
+        assert!(got.iter().all(|v| v.is_nan()), "got: {got:?}");
+    }
+
+    #[test]
+    fn domain_error_error() {
+        let sqrt = Sqrt::new().with_domain_error_behavior(DomainErrorBehavior::Error);
+        try_execute_f64(&sqrt, &[&[4.0, -1.0]]).unwrap_err();
+        let got = execute_f64(&sqrt, &[&[4.0, f64::NAN]]);
+        assert_eq!(2.0, got[0]);
+
+        let ln = Ln::new().with_domain_error_behavior(DomainErrorBehavior::Error);
+        try_execute_f64(&ln, &[&[0.0]]).unwrap_err();
+
+        let log = Log::new().with_domain_error_behavior(DomainErrorBehavior::Error);
+        try_execute_f64(&log, &[&[0.0]]).unwrap_err();
+        try_execute_f64(&log, &[&[1.0], &[8.0]]).unwrap_err();
+        let got = execute_f64(&log, &[&[2.0], &[8.0]]);
+        assert_approx_eq(&[3.0], &got);
+    }
+
+    #[test]
+    fn pi_degrees_radians() {
+        use std::f64::consts::PI;
+
+        let planned = Pi.plan(&TableList::empty(), Vec::new()).unwrap();
+        let out = planned.function_impl.execute(&[]).unwrap();
+        assert_eq!(ScalarValue::Float64(PI), out.logical_value(0).unwrap());
+
+        let got = execute_f64(&Degrees::new(), &[&[PI, 0.0]]);
+        assert_approx_eq(&[180.0, 0.0], &got);
+
+        let got = execute_f64(&Radians::new(), &[&[180.0, 90.0]]);
+        assert_approx_eq(&[PI, PI / 2.0], &got);
+    }
+}
@@ -0,0 +1,64 @@
+use rayexec_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pi;
+
+impl FunctionInfo for Pi {
+    fn name(&self) -> &'static str {
+        "pi"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: None,
+            return_type: DataTypeId::Float64,
+            doc: Some(&Documentation {
+                category: Category::Numeric,
+                description: "Return the value of pi.",
+                arguments: &[],
+                example: Some(Example {
+                    example: "pi()",
+                    output: "3.141592653589793",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for Pi {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 0)?;
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Float64,
+            inputs,
+            function_impl: Box::new(PiImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PiImpl;
+
+impl ScalarFunctionImpl for PiImpl {
+    fn execute(&self, _inputs: &[&Array]) -> Result<Array> {
+        Ok(Array::new_with_array_data(
+            DataType::Float64,
+            PrimitiveStorage::from(vec![std::f64::consts::PI]),
+        ))
+    }
+}
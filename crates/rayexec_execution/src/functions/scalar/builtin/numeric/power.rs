@@ -0,0 +1,237 @@
+use std::marker::PhantomData;
+
+use num_traits::Float;
+use rayexec_error::Result;
+
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI64,
+    PhysicalStorage,
+};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Power;
+
+impl FunctionInfo for Power {
+    fn name(&self) -> &'static str {
+        "power"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["pow", "^"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description: "Raise a number to the given power. Integer inputs produce a float.",
+            arguments: &["base", "exponent"],
+            example: Some(Example {
+                example: "power(2, 10)",
+                output: "1024",
+            }),
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Float32, DataTypeId::Float32],
+                variadic_arg: None,
+                return_type: DataTypeId::Float32,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64, DataTypeId::Float64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+            // Integers are promoted to float since negative exponents produce
+            // fractional results.
+            Signature {
+                positional_args: &[DataTypeId::Int64, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for Power {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) = match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Float32, DataType::Float32) => (
+                Box::new(PowerImpl::<PhysicalF32>::new(DataType::Float32)),
+                DataType::Float32,
+            ),
+            (DataType::Float64, DataType::Float64) => (
+                Box::new(PowerImpl::<PhysicalF64>::new(DataType::Float64)),
+                DataType::Float64,
+            ),
+            (DataType::Int64, DataType::Int64) => (Box::new(PowerIntImpl), DataType::Float64),
+            (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
+        };
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type,
+            inputs,
+            function_impl,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PowerImpl<S> {
+    datatype: DataType,
+    _s: PhantomData<S>,
+}
+
+impl<S> PowerImpl<S> {
+    fn new(datatype: DataType) -> Self {
+        PowerImpl {
+            datatype,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunctionImpl for PowerImpl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: Float + Default,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        power_float::<S>(inputs[0], inputs[1], self.datatype.clone())
+    }
+}
+
+fn power_float<'a, S>(base: &'a Array, exp: &'a Array, datatype: DataType) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Float + Default,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(base.logical_len()),
+    };
+
+    BinaryExecutor::execute::<S, S, _, _>(base, exp, builder, |base, exp, buf| {
+        buf.put(&base.powf(exp))
+    })
+}
+
+/// Power for integer inputs, producing a Float64.
+#[derive(Debug, Clone)]
+pub struct PowerIntImpl;
+
+impl ScalarFunctionImpl for PowerIntImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let base = inputs[0];
+        let exp = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Float64,
+            buffer: PrimitiveBuffer::with_len(base.logical_len()),
+        };
+
+        BinaryExecutor::execute::<PhysicalI64, PhysicalI64, _, _>(
+            base,
+            exp,
+            builder,
+            |base, exp, buf| buf.put(&(base as f64).powf(exp as f64)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn plan(datatype: DataType) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![datatype.clone(), datatype],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        Power
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn power_int_promotes_to_float() {
+        let planned = plan(DataType::Int64);
+        assert_eq!(DataType::Float64, planned.return_type);
+
+        let out = planned
+            .function_impl
+            .execute(&[
+                &Array::from_iter([2_i64, 2, -3, 0]),
+                &Array::from_iter([10_i64, -1, 3, 0]),
+            ])
+            .unwrap();
+        let expected = Array::from_iter([1024.0_f64, 0.5, -27.0, 1.0]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn power_f64() {
+        let planned = plan(DataType::Float64);
+
+        let out = planned
+            .function_impl
+            .execute(&[
+                &Array::from_iter([2.0_f64, 9.0, 2.0]),
+                &Array::from_iter([10.0_f64, 0.5, -2.0]),
+            ])
+            .unwrap();
+        let expected = Array::from_iter([1024.0_f64, 3.0, 0.25]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn power_f32() {
+        let planned = plan(DataType::Float32);
+        assert_eq!(DataType::Float32, planned.return_type);
+
+        let out = planned
+            .function_impl
+            .execute(&[&Array::from_iter([2.0_f32]), &Array::from_iter([3.0_f32])])
+            .unwrap();
+
+        assert_eq!(Array::from_iter([8.0_f32]), out);
+    }
+}
@@ -0,0 +1,619 @@
+use std::marker::PhantomData;
+
+use num_traits::{Float, FromPrimitive, One, Zero};
+use rayexec_error::{RayexecError, Result};
+
+use super::ScalarFunction;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::compute::rounding::RoundingMode;
+use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalI64,
+    PhysicalStorage,
+};
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunctionImpl, ScalarPlanOptions};
+use crate::functions::{
+    invalid_input_types_error,
+    plan_check_num_args_one_of,
+    FunctionInfo,
+    Signature,
+};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+/// Round a number.
+///
+/// Halfway values are rounded according to the session's rounding mode. By
+/// default halfway values are rounded to the nearest even value (e.g.
+/// `round(2.5) = 2` and `round(3.5) = 4`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round;
+
+impl FunctionInfo for Round {
+    fn name(&self) -> &'static str {
+        "round"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description:
+                "Round a number to the nearest integer. Halfway values are rounded using the 'rounding_mode' setting (half even by default).",
+            arguments: &["number"],
+            example: Some(Example {
+                example: "round(2.5)",
+                output: "2",
+            }),
+        };
+
+        const DOC_DIGITS: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description: "Round a number to the given number of decimal places. Halfway values are rounded using the 'rounding_mode' setting (half even by default). Negative places round to the left of the decimal point.",
+            arguments: &["number", "places"],
+            example: Some(Example {
+                example: "round(1.23456, 2)",
+                output: "1.23",
+            }),
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Float16],
+                variadic_arg: None,
+                return_type: DataTypeId::Float16,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float32],
+                variadic_arg: None,
+                return_type: DataTypeId::Float32,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal64],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal128],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal128,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float16, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float16,
+                doc: Some(DOC_DIGITS),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float32, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float32,
+                doc: Some(DOC_DIGITS),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC_DIGITS),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal64, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal64,
+                doc: Some(DOC_DIGITS),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal128, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal128,
+                doc: Some(DOC_DIGITS),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for Round {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_options(table_list, inputs, ScalarPlanOptions::default())
+    }
+
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        options: ScalarPlanOptions,
+    ) -> Result<PlannedScalarFunction> {
+        let rounding = options.rounding;
+        plan_check_num_args_one_of(self, &inputs, [1, 2])?;
+
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        match datatypes.get(1) {
+            Some(DataType::Int64) | None => (),
+            Some(_) => return Err(invalid_input_types_error(self, &datatypes)),
+        }
+
+        let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) = match &datatypes[0] {
+            DataType::Float16 => (
+                Box::new(RoundFloatImpl::<PhysicalF16>::new(
+                    DataType::Float16,
+                    rounding,
+                )),
+                DataType::Float16,
+            ),
+            DataType::Float32 => (
+                Box::new(RoundFloatImpl::<PhysicalF32>::new(
+                    DataType::Float32,
+                    rounding,
+                )),
+                DataType::Float32,
+            ),
+            DataType::Float64 => (
+                Box::new(RoundFloatImpl::<PhysicalF64>::new(
+                    DataType::Float64,
+                    rounding,
+                )),
+                DataType::Float64,
+            ),
+            DataType::Decimal64(meta) => {
+                let digits = plan_decimal_digits(table_list, &inputs)?;
+                let function_impl = RoundDecimalImpl::<Decimal64Type>::try_new(
+                    *meta,
+                    digits,
+                    rounding,
+                    DataType::Decimal64,
+                )?;
+                let return_type = function_impl.datatype.clone();
+                (Box::new(function_impl), return_type)
+            }
+            DataType::Decimal128(meta) => {
+                let digits = plan_decimal_digits(table_list, &inputs)?;
+                let function_impl = RoundDecimalImpl::<Decimal128Type>::try_new(
+                    *meta,
+                    digits,
+                    rounding,
+                    DataType::Decimal128,
+                )?;
+                let return_type = function_impl.datatype.clone();
+                (Box::new(function_impl), return_type)
+            }
+            _ => return Err(invalid_input_types_error(self, &datatypes)),
+        };
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type,
+            inputs,
+            function_impl,
+        })
+    }
+}
+
+/// Get the number of digits to round a decimal to.
+///
+/// The output scale of a decimal depends on the number of digits, so it needs
+/// to be known during planning.
+fn plan_decimal_digits(table_list: &TableList, inputs: &[Expression]) -> Result<i64> {
+    match inputs.get(1) {
+        Some(digits) => {
+            if !digits.is_const_foldable() {
+                return Err(RayexecError::new(
+                    "Number of decimal places to round to must be constant when rounding decimals",
+                ));
+            }
+            ConstFold::rewrite(table_list, digits.clone())?
+                .try_into_scalar()?
+                .try_as_i64()
+        }
+        None => Ok(0),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RoundFloatImpl<S> {
+    datatype: DataType,
+    rounding: RoundingMode,
+    _s: PhantomData<S>,
+}
+
+impl<S> RoundFloatImpl<S> {
+    fn new(datatype: DataType, rounding: RoundingMode) -> Self {
+        RoundFloatImpl {
+            datatype,
+            rounding,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunctionImpl for RoundFloatImpl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: Float + Default,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        round_float::<S>(
+            inputs[0],
+            inputs.get(1).copied(),
+            self.datatype.clone(),
+            self.rounding,
+        )
+    }
+}
+
+fn round_float<'a, S>(
+    input: &'a Array,
+    digits: Option<&'a Array>,
+    datatype: DataType,
+    rounding: RoundingMode,
+) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Float + Default,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(input.logical_len()),
+    };
+
+    match digits {
+        Some(digits) => BinaryExecutor::execute::<S, PhysicalI64, _, _>(
+            input,
+            digits,
+            builder,
+            |v, digits, buf| buf.put(&round_float_to_digits(v, digits, rounding)),
+        ),
+        None => UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| {
+            buf.put(&rounding.round_float(v))
+        }),
+    }
+}
+
+/// Round a float to some number of decimal places.
+fn round_float_to_digits<F: Float>(v: F, digits: i64, rounding: RoundingMode) -> F {
+    let digits = digits.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+    let factor = match F::from(10.0) {
+        Some(ten) => ten.powi(digits),
+        None => return v,
+    };
+
+    if factor.is_zero() {
+        // Rounding far to the left of the decimal point.
+        return F::zero() * v.signum();
+    }
+
+    let scaled = v * factor;
+    if !scaled.is_finite() {
+        // Rounding to more places than the float can represent.
+        return v;
+    }
+
+    rounding.round_float(scaled) / factor
+}
+
+#[derive(Debug, Clone)]
+pub struct RoundDecimalImpl<D: DecimalType> {
+    datatype: DataType,
+    rounding: RoundingMode,
+    /// Factor to round the value to a multiple of.
+    ///
+    /// None if the factor overflows the primitive, in which case all values
+    /// round to zero.
+    factor: Option<D::Primitive>,
+    /// Multiplier to apply after rounding to get the value in the output
+    /// scale. Only not one when rounding to negative places.
+    rescale: D::Primitive,
+}
+
+impl<D: DecimalType> RoundDecimalImpl<D> {
+    /// Create a new impl for rounding decimals of the given type meta to
+    /// `digits` decimal places.
+    ///
+    /// The output scale will be `digits` (or 0 if `digits` is negative) if
+    /// that's less than the input scale. Precision is adjusted to account for
+    /// the reduced scale and carry from rounding up.
+    fn try_new(
+        meta: DecimalTypeMeta,
+        digits: i64,
+        rounding: RoundingMode,
+        datatype_fn: fn(DecimalTypeMeta) -> DataType,
+    ) -> Result<Self> {
+        let scale = meta.scale as i64;
+
+        if digits >= scale {
+            // Nothing to round.
+            return Ok(RoundDecimalImpl {
+                datatype: datatype_fn(meta),
+                rounding,
+                factor: Some(D::Primitive::one()),
+                rescale: D::Primitive::one(),
+            });
+        }
+
+        let out_scale = digits.max(0);
+        let precision =
+            (meta.precision as i64 - scale + out_scale + 1).clamp(1, D::MAX_PRECISION as i64) as u8;
+
+        let ten = D::Primitive::from_u8(10).expect("10 to fit in decimal primitive");
+        let pow10 = |exp: i64| -> Option<D::Primitive> {
+            usize::try_from(exp)
+                .ok()
+                .and_then(|exp| num_traits::checked_pow(ten, exp))
+        };
+
+        let factor = pow10(scale - digits);
+        let rescale = pow10(out_scale - digits)
+            .ok_or_else(|| RayexecError::new(format!("Cannot round decimal to {digits} places")))?;
+
+        Ok(RoundDecimalImpl {
+            datatype: datatype_fn(DecimalTypeMeta {
+                precision,
+                scale: out_scale as i8,
+            }),
+            rounding,
+            factor,
+            rescale,
+        })
+    }
+}
+
+impl<D> ScalarFunctionImpl for RoundDecimalImpl<D>
+where
+    D: DecimalType,
+    ArrayData: From<PrimitiveStorage<D::Primitive>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+        let builder = ArrayBuilder {
+            datatype: self.datatype.clone(),
+            buffer: PrimitiveBuffer::<D::Primitive>::with_len(input.logical_len()),
+        };
+
+        match self.factor {
+            Some(factor) => UnaryExecutor::execute::<D::Storage, _, _>(input, builder, |v, buf| {
+                buf.put(&(self.rounding.round_div(v, factor) * self.rescale))
+            }),
+            None => UnaryExecutor::execute::<D::Storage, _, _>(input, builder, |_, buf| {
+                buf.put(&D::Primitive::zero())
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn plan_round(
+        datatype: DataType,
+        digits: Option<i64>,
+        rounding: RoundingMode,
+    ) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![datatype], vec!["a".to_string()])
+            .unwrap();
+
+        let mut inputs = vec![expr::col_ref(table_ref, 0)];
+        if let Some(digits) = digits {
+            inputs.push(expr::lit(digits));
+        }
+
+        Round
+            .plan_with_options(
+                &table_list,
+                inputs,
+                ScalarPlanOptions {
+                    rounding,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn round_f64_half_up() {
+        let a = Array::from_iter([2.5_f64, -2.5, 1.4, -1.6, 0.5]);
+        let planned = plan_round(DataType::Float64, None, RoundingMode::HalfUp);
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected = Array::from_iter([3.0_f64, -3.0, 1.0, -2.0, 1.0]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn round_half_even_vs_half_up() {
+        let a = Array::from_iter([0.5_f64, 1.5, -0.5, -2.5]);
+
+        let planned = plan_round(DataType::Float64, None, RoundingMode::HalfEven);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        assert_eq!(Array::from_iter([0.0_f64, 2.0, -0.0, -2.0]), out);
+
+        let planned = plan_round(DataType::Float64, None, RoundingMode::HalfUp);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        assert_eq!(Array::from_iter([1.0_f64, 2.0, -1.0, -3.0]), out);
+
+        let planned = plan_round(DataType::Float64, None, RoundingMode::TowardZero);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        assert_eq!(Array::from_iter([0.0_f64, 1.0, -0.0, -2.0]), out);
+    }
+
+    #[test]
+    fn round_decimal_half_even_vs_half_up() {
+        let meta = DecimalTypeMeta {
+            precision: 4,
+            scale: 1,
+        };
+        // 0.5, 1.5, -0.5, -2.5
+        let a = Array::new_with_array_data(
+            DataType::Decimal64(meta),
+            PrimitiveStorage::from(vec![5_i64, 15, -5, -25]),
+        );
+        let return_type = DataType::Decimal64(DecimalTypeMeta {
+            precision: 4,
+            scale: 0,
+        });
+
+        let planned = plan_round(DataType::Decimal64(meta), None, RoundingMode::HalfEven);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected = Array::new_with_array_data(
+            return_type.clone(),
+            PrimitiveStorage::from(vec![0_i64, 2, 0, -2]),
+        );
+        assert_eq!(expected, out);
+
+        let planned = plan_round(DataType::Decimal64(meta), None, RoundingMode::HalfUp);
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected =
+            Array::new_with_array_data(return_type, PrimitiveStorage::from(vec![1_i64, 2, -1, -3]));
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn round_f32() {
+        let a = Array::from_iter([2.5_f32, -0.4]);
+        let planned = plan_round(DataType::Float32, None, RoundingMode::HalfUp);
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected = Array::from_iter([3.0_f32, -0.0]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn round_f64_digits() {
+        let a = Array::from_iter([1.23456_f64, -1.23456, 1234.5]);
+        let digits = Array::from_iter([2_i64, 3, -2]);
+        let planned = plan_round(DataType::Float64, Some(2), RoundingMode::HalfUp);
+
+        let out = planned.function_impl.execute(&[&a, &digits]).unwrap();
+        let expected = Array::from_iter([1.23_f64, -1.235, 1200.0]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn round_decimal64_to_integer() {
+        let meta = DecimalTypeMeta {
+            precision: 4,
+            scale: 1,
+        };
+        // 2.5, -2.5, 99.5, -1.4
+        let a = Array::new_with_array_data(
+            DataType::Decimal64(meta),
+            PrimitiveStorage::from(vec![25_i64, -25, 995, -14]),
+        );
+        let planned = plan_round(DataType::Decimal64(meta), None, RoundingMode::HalfUp);
+
+        // Scale reduced to 0, precision grows by one for the carry.
+        let return_type = DataType::Decimal64(DecimalTypeMeta {
+            precision: 4,
+            scale: 0,
+        });
+        assert_eq!(return_type, planned.return_type);
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected = Array::new_with_array_data(
+            return_type,
+            PrimitiveStorage::from(vec![3_i64, -3, 100, -1]),
+        );
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn round_decimal128_digits() {
+        let meta = DecimalTypeMeta {
+            precision: 8,
+            scale: 4,
+        };
+        // 1.2345, -1.2355, 0.0049
+        let a = Array::new_with_array_data(
+            DataType::Decimal128(meta),
+            PrimitiveStorage::from(vec![12345_i128, -12355, 49]),
+        );
+        let planned = plan_round(DataType::Decimal128(meta), Some(2), RoundingMode::HalfUp);
+
+        let return_type = DataType::Decimal128(DecimalTypeMeta {
+            precision: 7,
+            scale: 2,
+        });
+        assert_eq!(return_type, planned.return_type);
+
+        let inputs = [&a, &Array::from_iter([2_i64, 2, 2])];
+        let out = planned.function_impl.execute(&inputs).unwrap();
+        let expected = Array::new_with_array_data(
+            return_type,
+            PrimitiveStorage::from(vec![123_i128, -124, 0]),
+        );
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn round_decimal64_negative_digits() {
+        let meta = DecimalTypeMeta {
+            precision: 6,
+            scale: 2,
+        };
+        // 1250.00, -1249.99
+        let a = Array::new_with_array_data(
+            DataType::Decimal64(meta),
+            PrimitiveStorage::from(vec![125000_i64, -124999]),
+        );
+        let planned = plan_round(DataType::Decimal64(meta), Some(-2), RoundingMode::HalfUp);
+
+        let return_type = DataType::Decimal64(DecimalTypeMeta {
+            precision: 5,
+            scale: 0,
+        });
+        assert_eq!(return_type, planned.return_type);
+
+        let inputs = [&a, &Array::from_iter([-2_i64, -2])];
+        let out = planned.function_impl.execute(&inputs).unwrap();
+        let expected =
+            Array::new_with_array_data(return_type, PrimitiveStorage::from(vec![1300_i64, -1200]));
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn round_decimal_more_digits_than_scale() {
+        let meta = DecimalTypeMeta {
+            precision: 6,
+            scale: 2,
+        };
+        let planned = plan_round(DataType::Decimal64(meta), Some(4), RoundingMode::HalfUp);
+        assert_eq!(DataType::Decimal64(meta), planned.return_type);
+    }
+}
@@ -0,0 +1,268 @@
+use std::marker::PhantomData;
+
+use num_traits::{Float, Signed, Zero};
+use rayexec_error::Result;
+
+use super::ScalarFunction;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalStorage,
+};
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sign;
+
+impl FunctionInfo for Sign {
+    fn name(&self) -> &'static str {
+        "sign"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description: "Get the sign of a number, returning -1 for negative numbers, 0 for zero, and 1 for positive numbers.",
+            arguments: &["number"],
+            example: Some(Example {
+                example: "sign(-4.5)",
+                output: "-1",
+            }),
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Float16],
+                variadic_arg: None,
+                return_type: DataTypeId::Float16,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float32],
+                variadic_arg: None,
+                return_type: DataTypeId::Float32,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal64],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal128],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal128,
+                doc: Some(DOC),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for Sign {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+
+        // Decimal signs are always -1, 0, or 1, so the output doesn't need any
+        // fractional digits.
+        const DECIMAL_SIGN_META: DecimalTypeMeta = DecimalTypeMeta {
+            precision: 1,
+            scale: 0,
+        };
+
+        let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) =
+            match inputs[0].datatype(table_list)? {
+                DataType::Float16 => (
+                    Box::new(SignFloatImpl::<PhysicalF16>::new(DataType::Float16)),
+                    DataType::Float16,
+                ),
+                DataType::Float32 => (
+                    Box::new(SignFloatImpl::<PhysicalF32>::new(DataType::Float32)),
+                    DataType::Float32,
+                ),
+                DataType::Float64 => (
+                    Box::new(SignFloatImpl::<PhysicalF64>::new(DataType::Float64)),
+                    DataType::Float64,
+                ),
+                DataType::Decimal64(_) => {
+                    let return_type = DataType::Decimal64(DECIMAL_SIGN_META);
+                    (
+                        Box::new(SignDecimalImpl::<Decimal64Type>::new(return_type.clone())),
+                        return_type,
+                    )
+                }
+                DataType::Decimal128(_) => {
+                    let return_type = DataType::Decimal128(DECIMAL_SIGN_META);
+                    (
+                        Box::new(SignDecimalImpl::<Decimal128Type>::new(return_type.clone())),
+                        return_type,
+                    )
+                }
+                other => return Err(invalid_input_types_error(self, &[other])),
+            };
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type,
+            inputs,
+            function_impl,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SignFloatImpl<S> {
+    datatype: DataType,
+    _s: PhantomData<S>,
+}
+
+impl<S> SignFloatImpl<S> {
+    fn new(datatype: DataType) -> Self {
+        SignFloatImpl {
+            datatype,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunctionImpl for SignFloatImpl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: Float + Default,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        sign_float::<S>(inputs[0], self.datatype.clone())
+    }
+}
+
+fn sign_float<'a, S>(input: &'a Array, datatype: DataType) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Float + Default,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(input.logical_len()),
+    };
+
+    UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| {
+        // `Float::signum` returns 1 for positive zero, we want 0.
+        let sign = if v.is_zero() { v } else { v.signum() };
+        buf.put(&sign)
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SignDecimalImpl<D> {
+    datatype: DataType,
+    _d: PhantomData<D>,
+}
+
+impl<D> SignDecimalImpl<D> {
+    fn new(datatype: DataType) -> Self {
+        SignDecimalImpl {
+            datatype,
+            _d: PhantomData,
+        }
+    }
+}
+
+impl<D> ScalarFunctionImpl for SignDecimalImpl<D>
+where
+    D: DecimalType,
+    ArrayData: From<PrimitiveStorage<D::Primitive>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+        let builder = ArrayBuilder {
+            datatype: self.datatype.clone(),
+            buffer: PrimitiveBuffer::<D::Primitive>::with_len(input.logical_len()),
+        };
+
+        UnaryExecutor::execute::<D::Storage, _, _>(input, builder, |v, buf| {
+            buf.put(&Signed::signum(&v))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn sign_f64() {
+        let a = Array::from_iter([-2.5_f64, 0.0, 3.0]);
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Float64], vec!["a".to_string()])
+            .unwrap();
+
+        let planned = Sign
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected = Array::from_iter([-1.0_f64, 0.0, 1.0]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn sign_decimal64() {
+        let meta = DecimalTypeMeta {
+            precision: 6,
+            scale: 2,
+        };
+        // -12.34, 0.00, 0.01
+        let a = Array::new_with_array_data(
+            DataType::Decimal64(meta),
+            PrimitiveStorage::from(vec![-1234_i64, 0, 1]),
+        );
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Decimal64(meta)], vec!["a".to_string()])
+            .unwrap();
+
+        let planned = Sign
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let return_type = DataType::Decimal64(DecimalTypeMeta {
+            precision: 1,
+            scale: 0,
+        });
+        assert_eq!(return_type, planned.return_type);
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected =
+            Array::new_with_array_data(return_type, PrimitiveStorage::from(vec![-1_i64, 0, 1]));
+
+        assert_eq!(expected, out);
+    }
+}
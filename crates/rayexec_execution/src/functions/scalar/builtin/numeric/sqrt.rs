@@ -11,12 +11,20 @@ use crate::arrays::storage::PrimitiveStorage;
 
 pub type Sqrt = UnaryInputNumericScalar<SqrtOp>;
 
+/// Square root.
+///
+/// Negative inputs produce NaN instead of an error, following IEEE 754.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SqrtOp;
 
 impl UnaryInputNumericOperation for SqrtOp {
     const NAME: &'static str = "sqrt";
-    const DESCRIPTION: &'static str = "Compute the square root of value";
+    const DESCRIPTION: &'static str =
+        "Compute the square root of value. Returns NaN for negative values";
+
+    fn in_domain<F: Float>(v: F) -> bool {
+        v.is_nan() || v >= F::zero()
+    }
 
     fn execute_float<'a, S>(input: &'a Array, ret: DataType) -> Result<Array>
     where
@@ -31,3 +39,39 @@ impl UnaryInputNumericOperation for SqrtOp {
         UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| buf.put(&v.sqrt()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::expr;
+    use crate::functions::scalar::ScalarFunction;
+    use crate::logical::binder::table_list::TableList;
+
+    #[test]
+    fn sqrt_f64() {
+        let a = Array::from_iter([4.0_f64, 2.0, -1.0]);
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Float64], vec!["a".to_string()])
+            .unwrap();
+
+        let planned = Sqrt::new()
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+
+        assert_eq!(ScalarValue::Float64(2.0), out.logical_value(0).unwrap());
+        assert_eq!(
+            ScalarValue::Float64(std::f64::consts::SQRT_2),
+            out.logical_value(1).unwrap()
+        );
+        // Negative input is NaN, not an error.
+        assert!(matches!(
+            out.logical_value(2).unwrap(),
+            ScalarValue::Float64(v) if v.is_nan()
+        ));
+    }
+}
@@ -0,0 +1,274 @@
+use std::marker::PhantomData;
+
+use num_traits::{Float, FromPrimitive};
+use rayexec_error::{RayexecError, Result};
+
+use super::ScalarFunction;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::physical_type::{
+    PhysicalF16,
+    PhysicalF32,
+    PhysicalF64,
+    PhysicalStorage,
+};
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::arrays::storage::PrimitiveStorage;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trunc;
+
+impl FunctionInfo for Trunc {
+    fn name(&self) -> &'static str {
+        "trunc"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Numeric,
+            description: "Truncate a number towards zero, removing any fractional digits.",
+            arguments: &["number"],
+            example: Some(Example {
+                example: "trunc(-4.7)",
+                output: "-4",
+            }),
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Float16],
+                variadic_arg: None,
+                return_type: DataTypeId::Float16,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float32],
+                variadic_arg: None,
+                return_type: DataTypeId::Float32,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Float64],
+                variadic_arg: None,
+                return_type: DataTypeId::Float64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal64],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal64,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Decimal128],
+                variadic_arg: None,
+                return_type: DataTypeId::Decimal128,
+                doc: Some(DOC),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for Trunc {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+
+        let (function_impl, return_type): (Box<dyn ScalarFunctionImpl>, _) =
+            match inputs[0].datatype(table_list)? {
+                DataType::Float16 => (
+                    Box::new(TruncFloatImpl::<PhysicalF16>::new(DataType::Float16)),
+                    DataType::Float16,
+                ),
+                DataType::Float32 => (
+                    Box::new(TruncFloatImpl::<PhysicalF32>::new(DataType::Float32)),
+                    DataType::Float32,
+                ),
+                DataType::Float64 => (
+                    Box::new(TruncFloatImpl::<PhysicalF64>::new(DataType::Float64)),
+                    DataType::Float64,
+                ),
+                DataType::Decimal64(meta) => {
+                    let function_impl =
+                        TruncDecimalImpl::<Decimal64Type>::try_new(meta, DataType::Decimal64)?;
+                    let return_type = function_impl.datatype.clone();
+                    (Box::new(function_impl), return_type)
+                }
+                DataType::Decimal128(meta) => {
+                    let function_impl =
+                        TruncDecimalImpl::<Decimal128Type>::try_new(meta, DataType::Decimal128)?;
+                    let return_type = function_impl.datatype.clone();
+                    (Box::new(function_impl), return_type)
+                }
+                other => return Err(invalid_input_types_error(self, &[other])),
+            };
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type,
+            inputs,
+            function_impl,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TruncFloatImpl<S> {
+    datatype: DataType,
+    _s: PhantomData<S>,
+}
+
+impl<S> TruncFloatImpl<S> {
+    fn new(datatype: DataType) -> Self {
+        TruncFloatImpl {
+            datatype,
+            _s: PhantomData,
+        }
+    }
+}
+
+impl<S> ScalarFunctionImpl for TruncFloatImpl<S>
+where
+    S: PhysicalStorage,
+    for<'a> S::Type<'a>: Float + Default,
+    for<'a> ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        trunc_float::<S>(inputs[0], self.datatype.clone())
+    }
+}
+
+fn trunc_float<'a, S>(input: &'a Array, datatype: DataType) -> Result<Array>
+where
+    S: PhysicalStorage,
+    S::Type<'a>: Float + Default,
+    ArrayData: From<PrimitiveStorage<S::Type<'a>>>,
+{
+    let builder = ArrayBuilder {
+        datatype,
+        buffer: PrimitiveBuffer::with_len(input.logical_len()),
+    };
+
+    UnaryExecutor::execute::<S, _, _>(input, builder, |v, buf| buf.put(&v.trunc()))
+}
+
+#[derive(Debug, Clone)]
+pub struct TruncDecimalImpl<D: DecimalType> {
+    datatype: DataType,
+    /// Value to divide by to remove the fractional digits.
+    divisor: D::Primitive,
+}
+
+impl<D: DecimalType> TruncDecimalImpl<D> {
+    /// Create a new impl for truncating decimals with the given type meta.
+    ///
+    /// The output type is created with `datatype_fn` and always has a scale of
+    /// 0.
+    fn try_new(
+        meta: DecimalTypeMeta,
+        datatype_fn: fn(DecimalTypeMeta) -> DataType,
+    ) -> Result<Self> {
+        let scale = meta.scale.max(0) as u32;
+        let out_meta = DecimalTypeMeta {
+            precision: (meta.precision.saturating_sub(scale as u8)).max(1),
+            scale: 0,
+        };
+
+        let divisor = D::Primitive::from_u8(10)
+            .and_then(|ten| num_traits::checked_pow(ten, scale as usize))
+            .ok_or_else(|| RayexecError::new(format!("Decimal scale {scale} out of range")))?;
+
+        Ok(TruncDecimalImpl {
+            datatype: datatype_fn(out_meta),
+            divisor,
+        })
+    }
+}
+
+impl<D> ScalarFunctionImpl for TruncDecimalImpl<D>
+where
+    D: DecimalType,
+    ArrayData: From<PrimitiveStorage<D::Primitive>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+        let builder = ArrayBuilder {
+            datatype: self.datatype.clone(),
+            buffer: PrimitiveBuffer::<D::Primitive>::with_len(input.logical_len()),
+        };
+
+        // Integer division truncates towards zero.
+        UnaryExecutor::execute::<D::Storage, _, _>(input, builder, |v, buf| {
+            buf.put(&(v / self.divisor))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn trunc_f64() {
+        let a = Array::from_iter([-2.7_f64, -0.5, 0.0, 3.9]);
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Float64], vec!["a".to_string()])
+            .unwrap();
+
+        let planned = Trunc
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected = Array::from_iter([-2.0_f64, -0.0, 0.0, 3.0]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn trunc_decimal64() {
+        let meta = DecimalTypeMeta {
+            precision: 6,
+            scale: 2,
+        };
+        // -12.78, 0.99, 45.01
+        let a = Array::new_with_array_data(
+            DataType::Decimal64(meta),
+            PrimitiveStorage::from(vec![-1278_i64, 99, 4501]),
+        );
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Decimal64(meta)], vec!["a".to_string()])
+            .unwrap();
+
+        let planned = Trunc
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let return_type = DataType::Decimal64(DecimalTypeMeta {
+            precision: 4,
+            scale: 0,
+        });
+        assert_eq!(return_type, planned.return_type);
+
+        let out = planned.function_impl.execute(&[&a]).unwrap();
+        let expected =
+            Array::new_with_array_data(return_type, PrimitiveStorage::from(vec![-12_i64, 0, 45]));
+
+        assert_eq!(expected, out);
+    }
+}
@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rayexec_error::Result;
-use serde::{Deserialize, Serialize};
 
 use crate::arrays::array::Array;
 use crate::arrays::datatype::{DataType, DataTypeId};
@@ -44,30 +48,118 @@ impl ScalarFunction for Random {
     }
 
     fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan_with_seed(table_list, inputs, rand::random())
+    }
+
+    fn plan_with_seed(
         &self,
         _table_list: &TableList,
         inputs: Vec<Expression>,
+        seed: u64,
     ) -> Result<PlannedScalarFunction> {
         plan_check_num_args(self, &inputs, 0)?;
         Ok(PlannedScalarFunction {
             function: Box::new(*self),
             return_type: DataType::Float64,
             inputs,
-            function_impl: Box::new(RandomImpl),
+            function_impl: Box::new(RandomImpl::new(seed)),
         })
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct RandomImpl;
+/// Produces random floats from a seeded generator.
+///
+/// The generator is shared across clones of the impl. During execution, each
+/// partition gets its own generator seeded from this impl's seed and the
+/// partition index so that the values a partition produces don't depend on
+/// how partitions are scheduled.
+#[derive(Debug, Clone)]
+pub struct RandomImpl {
+    seed: u64,
+    rng: Arc<Mutex<StdRng>>,
+}
+
+impl RandomImpl {
+    pub fn new(seed: u64) -> Self {
+        RandomImpl {
+            seed,
+            rng: Arc::new(Mutex::new(StdRng::seed_from_u64(seed))),
+        }
+    }
+}
 
 impl ScalarFunctionImpl for RandomImpl {
     fn execute(&self, _inputs: &[&Array]) -> Result<Array> {
         // TODO: Need to pass in dummy input to produce all unique values.
-        let val = rand::random::<f64>();
+        let val = self.rng.lock().gen::<f64>();
         Ok(Array::new_with_array_data(
             DataType::Float64,
             PrimitiveStorage::from(vec![val]),
         ))
     }
+
+    fn for_partition(&self, partition: usize) -> Option<Box<dyn ScalarFunctionImpl>> {
+        // Mix in the partition index so partitions don't share a sequence.
+        let seed = self.seed ^ (partition as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        Some(Box::new(RandomImpl::new(seed)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_values() {
+        let table_list = TableList::empty();
+
+        let a = Random.plan_with_seed(&table_list, Vec::new(), 42).unwrap();
+        let b = Random.plan_with_seed(&table_list, Vec::new(), 42).unwrap();
+        let c = Random.plan_with_seed(&table_list, Vec::new(), 43).unwrap();
+
+        let a_vals: Vec<_> = (0..3)
+            .map(|_| a.function_impl.execute(&[]).unwrap())
+            .collect();
+        let b_vals: Vec<_> = (0..3)
+            .map(|_| b.function_impl.execute(&[]).unwrap())
+            .collect();
+        let c_vals: Vec<_> = (0..3)
+            .map(|_| c.function_impl.execute(&[]).unwrap())
+            .collect();
+
+        assert_eq!(a_vals, b_vals);
+        assert_ne!(a_vals, c_vals);
+        // Each call advances the generator.
+        assert_ne!(a_vals[0], a_vals[1]);
+    }
+
+    #[test]
+    fn partition_seeds_independent_of_shared_generator() {
+        let table_list = TableList::empty();
+        let planned = Random.plan_with_seed(&table_list, Vec::new(), 42).unwrap();
+
+        let partition_val = |partition: usize| {
+            planned
+                .function_impl
+                .for_partition(partition)
+                .unwrap()
+                .execute(&[])
+                .unwrap()
+        };
+
+        let p1 = partition_val(1);
+        let p0 = partition_val(0);
+
+        // Advancing the shared generator doesn't change what a partition
+        // produces.
+        planned.function_impl.execute(&[]).unwrap();
+        assert_eq!(p1, partition_val(1));
+        assert_eq!(p0, partition_val(0));
+
+        assert_ne!(p0, p1);
+    }
 }
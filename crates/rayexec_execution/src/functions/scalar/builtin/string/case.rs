@@ -19,6 +19,10 @@ impl FunctionInfo for Lower {
         "lower"
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["lcase"]
+    }
+
     fn signatures(&self) -> &[Signature] {
         &[Signature {
             positional_args: &[DataTypeId::Utf8],
@@ -74,6 +78,10 @@ impl FunctionInfo for Upper {
         "upper"
     }
 
+    fn aliases(&self) -> &'static [&'static str] {
+        &["ucase"]
+    }
+
     fn signatures(&self) -> &[Signature] {
         &[Signature {
             positional_args: &[DataTypeId::Utf8],
@@ -84,7 +92,7 @@ impl FunctionInfo for Upper {
                 description: "Convert the string to uppercase.",
                 arguments: &["string"],
                 example: Some(Example {
-                    example: "lower('abc')",
+                    example: "upper('abc')",
                     output: "ABC",
                 }),
             }),
@@ -140,3 +148,21 @@ where
         buf.put(&case_fn(v))
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn case_convert_unicode() {
+        let input = Array::from_iter([Some("HéLLo WÖRLD"), None, Some("ΣΊΣΥΦΟΣ"), Some("")]);
+
+        let out = LowerImpl.execute(&[&input]).unwrap();
+        let expected = Array::from_iter([Some("héllo wörld"), None, Some("σίσυφος"), Some("")]);
+        assert_eq!(expected, out);
+
+        let out = UpperImpl.execute(&[&input]).unwrap();
+        let expected = Array::from_iter([Some("HÉLLO WÖRLD"), None, Some("ΣΊΣΥΦΟΣ"), Some("")]);
+        assert_eq!(expected, out);
+    }
+}
@@ -2,18 +2,36 @@ use rayexec_error::Result;
 
 use crate::arrays::array::Array;
 use crate::arrays::datatype::{DataType, DataTypeId};
-use crate::arrays::executor::builder::{ArrayBuilder, GermanVarlenBuffer};
+use crate::arrays::executor::builder::{ArrayBuilder, ArrayDataBuffer, GermanVarlenBuffer};
 use crate::arrays::executor::physical_type::PhysicalUtf8;
-use crate::arrays::executor::scalar::{BinaryExecutor, UniformExecutor};
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
 use crate::expr::Expression;
 use crate::functions::documentation::{Category, Documentation, Example};
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
-use crate::functions::{invalid_input_types_error, FunctionInfo, Signature};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
 
-// TODO: Currently '||' aliases to this, however there should be two separate
-// concat functions. One that should return null on any null arguments (||), and
-// one that should omit null arguments when concatenating (the normal concat).
+/// Check that all inputs are strings.
+fn plan_check_utf8_inputs(
+    func: &impl FunctionInfo,
+    table_list: &TableList,
+    inputs: &[Expression],
+) -> Result<()> {
+    let datatypes = inputs
+        .iter()
+        .map(|input| input.datatype(table_list))
+        .collect::<Result<Vec<_>>>()?;
+
+    if !datatypes.iter().all(|dt| dt == &DataType::Utf8) {
+        return Err(invalid_input_types_error(func, &datatypes));
+    }
+
+    Ok(())
+}
+
+/// Variadic string concatenation, skipping NULL arguments.
+///
+/// `concat('a', NULL, 'b')` returns 'ab', matching Postgres.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Concat;
 
@@ -29,11 +47,12 @@ impl FunctionInfo for Concat {
             return_type: DataTypeId::Utf8,
             doc: Some(&Documentation {
                 category: Category::String,
-                description: "Concatenate many strings into a single string.",
+                description:
+                    "Concatenate many strings into a single string. NULL arguments are ignored.",
                 arguments: &["var_args"],
                 example: Some(Example {
-                    example: "concat('cat', 'dog', 'mouse')",
-                    output: "catdogmouse",
+                    example: "concat('cat', NULL, 'dog')",
+                    output: "catdog",
                 }),
             }),
         }]
@@ -46,14 +65,7 @@ impl ScalarFunction for Concat {
         table_list: &TableList,
         inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction> {
-        let datatypes = inputs
-            .iter()
-            .map(|input| input.datatype(table_list))
-            .collect::<Result<Vec<_>>>()?;
-
-        if !datatypes.iter().all(|dt| dt == &DataType::Utf8) {
-            return Err(invalid_input_types_error(self, &datatypes));
-        }
+        plan_check_utf8_inputs(self, table_list, &inputs)?;
 
         Ok(PlannedScalarFunction {
             function: Box::new(*self),
@@ -62,61 +74,193 @@ impl ScalarFunction for Concat {
             function_impl: Box::new(StringConcatImpl),
         })
     }
+
+    fn output_nullable(&self, inputs: &[bool]) -> bool {
+        // NULLs are skipped, only produce NULL when there's nothing to
+        // concatenate.
+        inputs.is_empty()
+    }
+}
+
+/// The `||` operator, producing NULL if either side is NULL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcatOperator;
+
+impl FunctionInfo for ConcatOperator {
+    fn name(&self) -> &'static str {
+        "||"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8],
+            variadic_arg: None,
+            return_type: DataTypeId::Utf8,
+            doc: Some(&Documentation {
+                category: Category::String,
+                description: "Concatenate two strings. Returns NULL if either string is NULL.",
+                arguments: &["left", "right"],
+                example: Some(Example {
+                    example: "'cat' || 'dog'",
+                    output: "catdog",
+                }),
+            }),
+        }]
+    }
 }
 
+impl ScalarFunction for ConcatOperator {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+        plan_check_utf8_inputs(self, table_list, &inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Utf8,
+            inputs,
+            function_impl: Box::new(StringConcatStrictImpl),
+        })
+    }
+}
+
+/// Concatenates strings, treating NULLs as empty strings.
 #[derive(Debug, Clone)]
 pub struct StringConcatImpl;
 
 impl ScalarFunctionImpl for StringConcatImpl {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        match inputs.len() {
-            0 => {
+        let len = match inputs.first() {
+            Some(first) => first.logical_len(),
+            None => {
                 let mut array = Array::from_iter([""]);
                 array.set_physical_validity(0, false);
-                Ok(array)
-            }
-            1 => Ok(inputs[0].clone()),
-            2 => {
-                let a = inputs[0];
-                let b = inputs[1];
-
-                let mut string_buf = String::new();
-
-                // TODO: Compute data capacity.
-
-                BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _, _>(
-                    a,
-                    b,
-                    ArrayBuilder {
-                        datatype: DataType::Utf8,
-                        buffer: GermanVarlenBuffer::with_len(a.logical_len()),
-                    },
-                    |a, b, buf| {
-                        string_buf.clear();
-                        string_buf.push_str(a);
-                        string_buf.push_str(b);
-                        buf.put(string_buf.as_str());
-                    },
-                )
-            }
-            _ => {
-                let mut string_buf = String::new();
-
-                UniformExecutor::execute::<PhysicalUtf8, _, _>(
-                    inputs,
-                    ArrayBuilder {
-                        datatype: DataType::Utf8,
-                        buffer: GermanVarlenBuffer::with_len(inputs[0].logical_len()),
-                    },
-                    |strings, buf| {
-                        string_buf.clear();
-                        for s in strings {
-                            string_buf.push_str(s);
-                        }
-                        buf.put(string_buf.as_str());
-                    },
-                )
+                return Ok(array);
             }
+        };
+
+        let mut strings = vec![String::new(); len];
+        for input in inputs {
+            UnaryExecutor::for_each::<PhysicalUtf8, _>(input, |idx, val| {
+                if let Some(val) = val {
+                    strings[idx].push_str(val);
+                }
+            })?;
+        }
+
+        let mut buffer = GermanVarlenBuffer::<str>::with_len(len);
+        for (idx, s) in strings.iter().enumerate() {
+            buffer.put(idx, s.as_str());
         }
+
+        Ok(Array::new_with_array_data(
+            DataType::Utf8,
+            buffer.into_data(),
+        ))
+    }
+}
+
+/// Concatenates two strings, producing NULL if either input is NULL.
+#[derive(Debug, Clone)]
+pub struct StringConcatStrictImpl;
+
+impl ScalarFunctionImpl for StringConcatStrictImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let a = inputs[0];
+        let b = inputs[1];
+
+        let mut string_buf = String::new();
+
+        // TODO: Compute data capacity.
+
+        BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _, _>(
+            a,
+            b,
+            ArrayBuilder {
+                datatype: DataType::Utf8,
+                buffer: GermanVarlenBuffer::with_len(a.logical_len()),
+            },
+            |a, b, buf| {
+                string_buf.clear();
+                string_buf.push_str(a);
+                string_buf.push_str(b);
+                buf.put(string_buf.as_str());
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    fn plan(func: &impl ScalarFunction, num_inputs: usize) -> PlannedScalarFunction {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Utf8; num_inputs],
+                (0..num_inputs).map(|idx| format!("c{idx}")).collect(),
+            )
+            .unwrap();
+
+        func.plan(
+            &table_list,
+            (0..num_inputs)
+                .map(|idx| expr::col_ref(table_ref, idx))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn concat_operator_propagates_nulls() {
+        let out = plan(&ConcatOperator, 2)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([Some("a"), None, Some("c"), Some("")]),
+                &Array::from_iter([Some("b"), Some("b"), None, Some("")]),
+            ])
+            .unwrap();
+        let expected = Array::from_iter([Some("ab"), None, None, Some("")]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn concat_skips_nulls() {
+        let out = plan(&Concat, 3)
+            .function_impl
+            .execute(&[
+                &Array::from_iter([Some("a"), None, None]),
+                &Array::from_iter([None, Some("b"), None]),
+                &Array::from_iter([Some("c"), Some(""), None]),
+            ])
+            .unwrap();
+        // All NULL inputs produces an empty string, not NULL.
+        let expected = Array::from_iter(["ac", "b", ""]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn concat_empty_strings() {
+        let out = plan(&Concat, 2)
+            .function_impl
+            .execute(&[&Array::from_iter(["", "a"]), &Array::from_iter(["", ""])])
+            .unwrap();
+
+        assert_eq!(Array::from_iter(["", "a"]), out);
+    }
+
+    #[test]
+    fn concat_nullability() {
+        assert!(!Concat.output_nullable(&[true, true]));
+        assert!(ConcatOperator.output_nullable(&[false, true]));
+        assert!(!ConcatOperator.output_nullable(&[false, false]));
     }
 }
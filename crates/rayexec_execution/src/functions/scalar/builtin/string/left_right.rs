@@ -0,0 +1,210 @@
+use rayexec_error::Result;
+
+use super::substring::char_byte_offset;
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, GermanVarlenBuffer};
+use crate::arrays::executor::physical_type::{PhysicalI64, PhysicalUtf8};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Left;
+
+impl FunctionInfo for Left {
+    fn name(&self) -> &'static str {
+        "left"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Int64],
+            variadic_arg: None,
+            return_type: DataTypeId::Utf8,
+            doc: Some(&Documentation {
+                category: Category::String,
+                description: "Get the first n characters of a string. A negative n returns all but the last |n| characters.",
+                arguments: &["string", "n"],
+                example: Some(Example {
+                    example: "left('alphabet', 3)",
+                    output: "alp",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for Left {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Utf8, DataType::Int64) => Ok(PlannedScalarFunction {
+                function: Box::new(*self),
+                return_type: DataType::Utf8,
+                inputs,
+                function_impl: Box::new(LeftImpl),
+            }),
+            (a, b) => Err(invalid_input_types_error(self, &[a, b])),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeftImpl;
+
+impl ScalarFunctionImpl for LeftImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let len = inputs[0].logical_len();
+        BinaryExecutor::execute::<PhysicalUtf8, PhysicalI64, _, _>(
+            inputs[0],
+            inputs[1],
+            ArrayBuilder {
+                datatype: DataType::Utf8,
+                buffer: GermanVarlenBuffer::with_len(len),
+            },
+            |s, n, buf| buf.put(left(s, n)),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Right;
+
+impl FunctionInfo for Right {
+    fn name(&self) -> &'static str {
+        "right"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Int64],
+            variadic_arg: None,
+            return_type: DataTypeId::Utf8,
+            doc: Some(&Documentation {
+                category: Category::String,
+                description: "Get the last n characters of a string. A negative n returns all but the first |n| characters.",
+                arguments: &["string", "n"],
+                example: Some(Example {
+                    example: "right('alphabet', 3)",
+                    output: "bet",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for Right {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Utf8, DataType::Int64) => Ok(PlannedScalarFunction {
+                function: Box::new(*self),
+                return_type: DataType::Utf8,
+                inputs,
+                function_impl: Box::new(RightImpl),
+            }),
+            (a, b) => Err(invalid_input_types_error(self, &[a, b])),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RightImpl;
+
+impl ScalarFunctionImpl for RightImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let len = inputs[0].logical_len();
+        BinaryExecutor::execute::<PhysicalUtf8, PhysicalI64, _, _>(
+            inputs[0],
+            inputs[1],
+            ArrayBuilder {
+                datatype: DataType::Utf8,
+                buffer: GermanVarlenBuffer::with_len(len),
+            },
+            |s, n, buf| buf.put(right(s, n)),
+        )
+    }
+}
+
+fn left(s: &str, n: i64) -> &str {
+    let count = if n >= 0 {
+        n as usize
+    } else {
+        s.chars().count().saturating_sub(n.unsigned_abs() as usize)
+    };
+
+    &s[..char_byte_offset(s, count)]
+}
+
+fn right(s: &str, n: i64) -> &str {
+    let skip = if n >= 0 {
+        s.chars().count().saturating_sub(n as usize)
+    } else {
+        n.unsigned_abs() as usize
+    };
+
+    &s[char_byte_offset(s, skip)..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn left_cases() {
+        // ((string, n), expected)
+        let test_cases = [
+            (("hello", 2), "he"),
+            (("hello", 0), ""),
+            (("hello", 10), "hello"),
+            (("hello", -2), "hel"),
+            (("hello", -10), ""),
+            (("héllo", 2), "hé"),
+            (("héllo", -3), "hé"),
+        ];
+
+        for case in test_cases {
+            let out = left(case.0 .0, case.0 .1);
+            assert_eq!(case.1, out);
+        }
+    }
+
+    #[test]
+    fn right_cases() {
+        // ((string, n), expected)
+        let test_cases = [
+            (("hello", 2), "lo"),
+            (("hello", 0), ""),
+            (("hello", 10), "hello"),
+            (("hello", -2), "llo"),
+            (("hello", -10), ""),
+            (("héllo", 4), "éllo"),
+            (("héllo", -1), "éllo"),
+        ];
+
+        for case in test_cases {
+            let out = right(case.0 .0, case.0 .1);
+            assert_eq!(case.1, out);
+        }
+    }
+}
@@ -18,6 +18,9 @@ pub use repeat::*;
 mod substring;
 pub use substring::*;
 
+mod left_right;
+pub use left_right::*;
+
 mod starts_with;
 pub use starts_with::*;
 
@@ -33,6 +36,12 @@ pub use length::*;
 mod regexp_replace;
 pub use regexp_replace::*;
 
+mod regexp_like;
+pub use regexp_like::*;
+
+mod regexp_extract;
+pub use regexp_extract::*;
+
 mod concat;
 pub use concat::*;
 
@@ -0,0 +1,241 @@
+use rayexec_error::{RayexecError, Result};
+use regex::Regex;
+
+use super::regexp_replace::{plan_constant_regex, RowRegexCache};
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, GermanVarlenBuffer};
+use crate::arrays::executor::physical_type::PhysicalUtf8;
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{
+    invalid_input_types_error,
+    plan_check_num_args_one_of,
+    FunctionInfo,
+    Signature,
+};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegexpExtract;
+
+impl FunctionInfo for RegexpExtract {
+    fn name(&self) -> &'static str {
+        "regexp_extract"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8],
+                variadic_arg: None,
+                return_type: DataTypeId::Utf8,
+                doc: Some(&Documentation {
+                    category: Category::Regexp,
+                    description: "Extract the first match of a regular expression from a string. Returns an empty string if there's no match.",
+                    arguments: &["string", "regexp"],
+                    example: Some(Example {
+                        example: "regexp_extract('abc123', '[0-9]+')",
+                        output: "123",
+                    }),
+                }),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Utf8,
+                doc: Some(&Documentation {
+                    category: Category::Regexp,
+                    description: "Extract a capture group from the first match of a regular expression. Group 0 is the entire match. Returns an empty string if there's no match.",
+                    arguments: &["string", "regexp", "group"],
+                    example: Some(Example {
+                        example: "regexp_extract('key=value', '(\\w+)=(\\w+)', 2)",
+                        output: "value",
+                    }),
+                }),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for RegexpExtract {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args_one_of(self, &inputs, [2, 3])?;
+
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        match datatypes.as_slice() {
+            [DataType::Utf8, DataType::Utf8]
+            | [DataType::Utf8, DataType::Utf8, DataType::Int64] => (),
+            _ => return Err(invalid_input_types_error(self, &datatypes)),
+        }
+
+        let group = match inputs.get(2) {
+            Some(group) => {
+                if !group.is_const_foldable() {
+                    return Err(RayexecError::new(
+                        "Capture group for regexp_extract must be a constant",
+                    ));
+                }
+                let group = ConstFold::rewrite(table_list, group.clone())?
+                    .try_into_scalar()?
+                    .try_as_i64()?;
+                usize::try_from(group).map_err(|_| {
+                    RayexecError::new(format!("Capture group cannot be negative: {group}"))
+                })?
+            }
+            None => 0,
+        };
+
+        let pattern = plan_constant_regex(table_list, &inputs[1])?;
+        if let Some(pattern) = &pattern {
+            check_group(pattern, group)?;
+        }
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Utf8,
+            inputs,
+            function_impl: Box::new(RegexpExtractImpl { pattern, group }),
+        })
+    }
+}
+
+fn check_group(pattern: &Regex, group: usize) -> Result<()> {
+    if group >= pattern.captures_len() {
+        return Err(RayexecError::new(format!(
+            "Capture group {group} out of range for pattern '{pattern}'"
+        )));
+    }
+    Ok(())
+}
+
+fn extract<'a>(pattern: &Regex, s: &'a str, group: usize) -> &'a str {
+    pattern
+        .captures(s)
+        .and_then(|captures| captures.get(group))
+        .map(|m| m.as_str())
+        .unwrap_or("")
+}
+
+#[derive(Debug, Clone)]
+pub struct RegexpExtractImpl {
+    /// Pattern if it was constant.
+    pub pattern: Option<Regex>,
+    /// Capture group to extract.
+    pub group: usize,
+}
+
+impl ScalarFunctionImpl for RegexpExtractImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: DataType::Utf8,
+            buffer: GermanVarlenBuffer::<str>::with_len(inputs[0].logical_len()),
+        };
+
+        match self.pattern.as_ref() {
+            Some(pattern) => {
+                UnaryExecutor::execute::<PhysicalUtf8, _, _>(inputs[0], builder, |s, buf| {
+                    buf.put(extract(pattern, s, self.group))
+                })
+            }
+            None => {
+                let mut cache = RowRegexCache::default();
+                let mut group_error = None;
+                let out = BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _, _>(
+                    inputs[0],
+                    inputs[1],
+                    builder,
+                    |s, pattern, buf| {
+                        if group_error.is_some() {
+                            return;
+                        }
+                        if let Some(pattern) = cache.get(pattern) {
+                            match check_group(pattern, self.group) {
+                                Ok(_) => buf.put(extract(pattern, s, self.group)),
+                                Err(e) => group_error = Some(e),
+                            }
+                        }
+                    },
+                )?;
+
+                if let Some(e) = group_error {
+                    return Err(e);
+                }
+                cache.finish(out)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn extract_capture_group() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Utf8], vec!["s".to_string()])
+            .unwrap();
+
+        let planned = RegexpExtract
+            .plan(
+                &table_list,
+                vec![
+                    expr::col_ref(table_ref, 0),
+                    expr::lit("(\\w+)=(\\w+)"),
+                    expr::lit(2_i64),
+                ],
+            )
+            .unwrap();
+
+        let out = planned
+            .function_impl
+            .execute(&[
+                &Array::from_iter([Some("key=value"), Some("no match"), None]),
+                &Array::from_iter(["(\\w+)=(\\w+)"; 3]),
+                &Array::from_iter([2_i64; 3]),
+            ])
+            .unwrap();
+        let expected = Array::from_iter([Some("value"), Some(""), None]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn extract_group_out_of_range() {
+        let table_list = TableList::empty();
+        RegexpExtract
+            .plan(
+                &table_list,
+                vec![expr::lit("abc"), expr::lit("(a)"), expr::lit(2_i64)],
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    fn extract_invalid_row_pattern() {
+        RegexpExtractImpl {
+            pattern: None,
+            group: 0,
+        }
+        .execute(&[
+            &Array::from_iter(["abc", "abc"]),
+            &Array::from_iter(["a", "(a"]),
+        ])
+        .unwrap_err();
+    }
+}
@@ -0,0 +1,136 @@
+use rayexec_error::Result;
+use regex::Regex;
+
+use super::regexp_replace::{plan_constant_regex, RowRegexCache};
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::builder::{ArrayBuilder, BooleanBuffer};
+use crate::arrays::executor::physical_type::PhysicalUtf8;
+use crate::arrays::executor::scalar::{BinaryExecutor, UnaryExecutor};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegexpLike;
+
+impl FunctionInfo for RegexpLike {
+    fn name(&self) -> &'static str {
+        "regexp_like"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["regexp_matches"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8],
+            variadic_arg: None,
+            return_type: DataTypeId::Boolean,
+            doc: Some(&Documentation {
+                category: Category::Regexp,
+                description: "Check if a string contains a match for a regular expression.",
+                arguments: &["string", "regexp"],
+                example: Some(Example {
+                    example: "regexp_like('alphabet', 'ph.b')",
+                    output: "true",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for RegexpLike {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+        match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Utf8, DataType::Utf8) => (),
+            (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
+        }
+
+        let pattern = plan_constant_regex(table_list, &inputs[1])?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Boolean,
+            inputs,
+            function_impl: Box::new(RegexpLikeImpl { pattern }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RegexpLikeImpl {
+    /// Pattern if it was constant.
+    pub pattern: Option<Regex>,
+}
+
+impl ScalarFunctionImpl for RegexpLikeImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let builder = ArrayBuilder {
+            datatype: DataType::Boolean,
+            buffer: BooleanBuffer::with_len(inputs[0].logical_len()),
+        };
+
+        match self.pattern.as_ref() {
+            Some(pattern) => {
+                UnaryExecutor::execute::<PhysicalUtf8, _, _>(inputs[0], builder, |s, buf| {
+                    buf.put(&pattern.is_match(s))
+                })
+            }
+            None => {
+                let mut cache = RowRegexCache::default();
+                let out = BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _, _>(
+                    inputs[0],
+                    inputs[1],
+                    builder,
+                    |s, pattern, buf| {
+                        if let Some(pattern) = cache.get(pattern) {
+                            buf.put(&pattern.is_match(s))
+                        }
+                    },
+                )?;
+                cache.finish(out)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regexp_like_row_patterns() {
+        let out = RegexpLikeImpl { pattern: None }
+            .execute(&[
+                &Array::from_iter([Some("alphabet"), Some("alphabet"), None]),
+                &Array::from_iter([Some("^alp"), Some("^bet"), Some("a")]),
+            ])
+            .unwrap();
+        let expected = Array::from_iter([Some(true), Some(false), None]);
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn regexp_like_constant_pattern() {
+        let out = RegexpLikeImpl {
+            pattern: Some(Regex::new("[0-9]+").unwrap()),
+        }
+        .execute(&[&Array::from_iter(["abc123", "abc"])])
+        .unwrap();
+
+        assert_eq!(Array::from_iter([true, false]), out);
+    }
+}
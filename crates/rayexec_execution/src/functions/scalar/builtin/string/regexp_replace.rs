@@ -1,4 +1,4 @@
-use rayexec_error::{Result, ResultExt};
+use rayexec_error::{RayexecError, Result, ResultExt};
 use regex::Regex;
 
 use crate::arrays::array::Array;
@@ -6,6 +6,7 @@ use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::executor::builder::{ArrayBuilder, GermanVarlenBuffer};
 use crate::arrays::executor::physical_type::PhysicalUtf8;
 use crate::arrays::executor::scalar::{BinaryExecutor, TernaryExecutor, UnaryExecutor};
+use crate::arrays::scalar::ScalarValue;
 use crate::expr::Expression;
 use crate::functions::documentation::{Category, Documentation, Example};
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
@@ -58,16 +59,7 @@ impl ScalarFunction for RegexpReplace {
             }
         }
 
-        let pattern = if inputs[1].is_const_foldable() {
-            let pattern = ConstFold::rewrite(table_list, inputs[1].clone())?
-                .try_into_scalar()?
-                .try_into_string()?;
-            let pattern = Regex::new(&pattern).context("Failed to build regexp pattern")?;
-
-            Some(pattern)
-        } else {
-            None
-        };
+        let pattern = plan_constant_regex(table_list, &inputs[1])?;
 
         let replacement = if inputs[2].is_const_foldable() {
             let replacement = ConstFold::rewrite(table_list, inputs[2].clone())?
@@ -122,44 +114,168 @@ impl ScalarFunctionImpl for RegexpReplaceImpl {
                 },
             ),
             (None, Some(replacement)) => {
-                BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _, _>(
+                let mut cache = RowRegexCache::default();
+                let out = BinaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, _, _>(
                     inputs[0],
                     inputs[1],
                     builder,
                     |s, pattern, buf| {
-                        let pattern = match Regex::new(pattern) {
-                            Ok(pattern) => pattern,
-                            Err(_) => {
-                                // TODO: Do something.
-                                return;
-                            }
-                        };
-
-                        let out = pattern.replace(s, replacement);
-                        buf.put(out.as_ref());
+                        if let Some(pattern) = cache.get(pattern) {
+                            let out = pattern.replace(s, replacement);
+                            buf.put(out.as_ref());
+                        }
                     },
-                )
+                )?;
+                cache.finish(out)
             }
             (None, None) => {
-                TernaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, PhysicalUtf8, _, _>(
+                let mut cache = RowRegexCache::default();
+                let out = TernaryExecutor::execute::<PhysicalUtf8, PhysicalUtf8, PhysicalUtf8, _, _>(
                     inputs[0],
                     inputs[1],
                     inputs[2],
                     builder,
                     |s, pattern, replacement, buf| {
-                        let pattern = match Regex::new(pattern) {
-                            Ok(pattern) => pattern,
-                            Err(_) => {
-                                // TODO: Do something.
-                                return;
-                            }
-                        };
-
-                        let out = pattern.replace(s, replacement);
-                        buf.put(out.as_ref());
+                        if let Some(pattern) = cache.get(pattern) {
+                            let out = pattern.replace(s, replacement);
+                            buf.put(out.as_ref());
+                        }
                     },
-                )
+                )?;
+                cache.finish(out)
+            }
+        }
+    }
+}
+
+/// Compile the pattern argument for a regexp function if it's constant.
+///
+/// Returns None if the pattern isn't constant, or is a constant NULL, in which
+/// case patterns need to be compiled per row. Errors if a constant pattern
+/// isn't a valid regular expression.
+pub(super) fn plan_constant_regex(
+    table_list: &TableList,
+    pattern: &Expression,
+) -> Result<Option<Regex>> {
+    if !pattern.is_const_foldable() {
+        return Ok(None);
+    }
+
+    let pattern = ConstFold::rewrite(table_list, pattern.clone())?.try_into_scalar()?;
+    if matches!(pattern, ScalarValue::Null) {
+        return Ok(None);
+    }
+
+    let pattern =
+        Regex::new(&pattern.try_into_string()?).context("Failed to build regexp pattern")?;
+
+    Ok(Some(pattern))
+}
+
+/// Compiles per-row patterns for regexp functions with non-constant patterns.
+///
+/// The most recently compiled regex is kept around since the same pattern is
+/// commonly repeated across rows. The first invalid pattern is stored and
+/// returned as an error when calling `finish`.
+#[derive(Debug, Default)]
+pub(super) struct RowRegexCache {
+    last: Option<(String, Regex)>,
+    error: Option<RayexecError>,
+}
+
+impl RowRegexCache {
+    /// Get the compiled regex for a pattern.
+    ///
+    /// Returns None if the pattern is invalid, or if a previous pattern was
+    /// invalid.
+    pub(super) fn get(&mut self, pattern: &str) -> Option<&Regex> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        let is_cached = matches!(&self.last, Some((last, _)) if last == pattern);
+        if !is_cached {
+            match Regex::new(pattern).context("Failed to build regexp pattern") {
+                Ok(regex) => self.last = Some((pattern.to_string(), regex)),
+                Err(e) => {
+                    self.error = Some(e);
+                    return None;
+                }
             }
         }
+
+        self.last.as_ref().map(|(_, regex)| regex)
+    }
+
+    /// Return the output array, or an error if we encountered an invalid
+    /// pattern.
+    pub(super) fn finish(self, out: Array) -> Result<Array> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(out),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn constant_pattern_compiled_at_plan() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Utf8], vec!["s".to_string()])
+            .unwrap();
+
+        let planned = RegexpReplace
+            .plan(
+                &table_list,
+                vec![
+                    expr::col_ref(table_ref, 0),
+                    expr::lit("[ae]"),
+                    expr::lit("DOG"),
+                ],
+            )
+            .unwrap();
+
+        let out = planned
+            .function_impl
+            .execute(&[
+                &Array::from_iter(["alphabet", "xyz"]),
+                &Array::from_iter(["[ae]", "[ae]"]),
+                &Array::from_iter(["DOG", "DOG"]),
+            ])
+            .unwrap();
+        assert_eq!(Array::from_iter(["DOGlphabet", "xyz"]), out);
+    }
+
+    #[test]
+    fn invalid_constant_pattern_errors_at_plan() {
+        let table_list = TableList::empty();
+        RegexpReplace
+            .plan(
+                &table_list,
+                vec![expr::lit("alphabet"), expr::lit("[ae"), expr::lit("DOG")],
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    fn invalid_row_pattern_errors() {
+        let function_impl = RegexpReplaceImpl {
+            pattern: None,
+            replacement: None,
+        };
+
+        let out = function_impl
+            .execute(&[
+                &Array::from_iter(["alphabet", "alphabet"]),
+                &Array::from_iter(["[ae]", "[ae"]),
+                &Array::from_iter(["DOG", "DOG"]),
+            ])
+            .unwrap_err();
+        assert!(out.to_string().contains("Failed to build regexp pattern"));
     }
 }
@@ -138,40 +138,37 @@ impl ScalarFunctionImpl for SubstringFromToImpl {
     }
 }
 
-fn substring_from(s: &str, from: i64) -> &str {
-    let start = (from - 1) as usize;
-    let mut chars = s.chars();
-    for _ in 0..start {
-        let _ = chars.next();
-    }
+/// Get the byte offset of the nth character in the string, or the length of
+/// the string if there's fewer than `n` characters.
+pub(super) fn char_byte_offset(s: &str, n: usize) -> usize {
+    s.char_indices()
+        .nth(n)
+        .map(|(pos, _)| pos)
+        .unwrap_or(s.len())
+}
 
-    chars.as_str()
+fn substring_from(s: &str, from: i64) -> &str {
+    // Positions before the start of the string just return the full string.
+    let start = from.saturating_sub(1).max(0) as usize;
+    &s[char_byte_offset(s, start)..]
 }
 
 fn substring_from_count(s: &str, from: i64, count: i64) -> &str {
-    let start = (from - 1) as usize;
-    let count = count as usize;
-
-    let mut chars = s.chars();
-    for _ in 0..start {
-        let _ = chars.next();
+    if count < 0 {
+        return "";
     }
 
-    // Determine byte position for count if we're dealing with utf8.
-    let byte_pos = chars
-        .as_str()
-        .char_indices()
-        .skip(count)
-        .map(|(pos, _)| pos)
-        .next();
-
-    match byte_pos {
-        Some(pos) => &chars.as_str()[..pos],
-        None => {
-            // End is beyond length of string, return full string.
-            chars.as_str()
-        }
+    // Postgres semantics, characters in the range [from, from+count) are
+    // returned. A `from` less than 1 still counts towards the range, e.g.
+    // `substring('hello', 0, 2)` returns 'h'.
+    let end = from.saturating_add(count);
+    let start = from.max(1);
+    if end <= start {
+        return "";
     }
+
+    let s = &s[char_byte_offset(s, (start - 1) as usize)..];
+    &s[..char_byte_offset(s, (end - start) as usize)]
 }
 
 #[cfg(test)]
@@ -186,6 +183,9 @@ mod tests {
             (("hello", 2), "ello"),
             (("hello", 3), "llo"),
             (("hello", 8), ""),
+            (("hello", 0), "hello"),
+            (("hello", -2), "hello"),
+            (("héllo", 2), "éllo"),
         ];
 
         for case in test_cases {
@@ -208,6 +208,13 @@ mod tests {
             (("hello", 2, 2), "el"),
             (("hello", 2, 4), "ello"),
             (("hello", 2, 5), "ello"),
+            (("hello", 0, 2), "h"),
+            (("hello", -3, 5), "h"),
+            (("hello", -3, 2), ""),
+            (("hello", 2, -1), ""),
+            (("hello", 8, 2), ""),
+            (("héllo", 2, 2), "él"),
+            (("héllo", 3, 10), "llo"),
         ];
 
         for case in test_cases {
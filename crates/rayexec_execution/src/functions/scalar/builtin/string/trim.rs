@@ -30,7 +30,8 @@ pub trait StringTrimOp: Sync + Send + Debug + Clone + Copy + PartialEq + Eq + 's
     const DOC_ONE_ARG: &'static Documentation;
     const DOC_TWO_ARGS: &'static Documentation;
 
-    fn trim_func<'a>(input: &'a str, pattern: &str) -> &'a str;
+    /// Trim characters matching the predicate from the input.
+    fn trim_func(input: &str, pred: impl Fn(char) -> bool) -> &str;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -60,8 +61,8 @@ impl StringTrimOp for BothTrimOp {
         }),
     };
 
-    fn trim_func<'a>(input: &'a str, pattern: &str) -> &'a str {
-        input.trim_matches(|c| pattern.contains(c))
+    fn trim_func(input: &str, pred: impl Fn(char) -> bool) -> &str {
+        input.trim_matches(pred)
     }
 }
 
@@ -92,8 +93,8 @@ impl StringTrimOp for LeftTrimOp {
         }),
     };
 
-    fn trim_func<'a>(input: &'a str, pattern: &str) -> &'a str {
-        input.trim_start_matches(|c| pattern.contains(c))
+    fn trim_func(input: &str, pred: impl Fn(char) -> bool) -> &str {
+        input.trim_start_matches(pred)
     }
 }
 
@@ -124,8 +125,8 @@ impl StringTrimOp for RightTrimOp {
         }),
     };
 
-    fn trim_func<'a>(input: &'a str, pattern: &str) -> &'a str {
-        input.trim_end_matches(|c| pattern.contains(c))
+    fn trim_func(input: &str, pred: impl Fn(char) -> bool) -> &str {
+        input.trim_end_matches(pred)
     }
 }
 
@@ -229,7 +230,7 @@ impl<F: StringTrimOp> ScalarFunctionImpl for TrimWhitespaceImpl<F> {
         };
 
         UnaryExecutor::execute::<PhysicalUtf8, _, _>(inputs[0], builder, |s, buf| {
-            let trimmed = F::trim_func(s, " ");
+            let trimmed = F::trim_func(s, char::is_whitespace);
             buf.put(trimmed)
         })
     }
@@ -258,9 +259,55 @@ impl<F: StringTrimOp> ScalarFunctionImpl for TrimPatternImpl<F> {
             inputs[1],
             builder,
             |s, pattern, buf| {
-                let trimmed = F::trim_func(s, pattern);
+                let trimmed = F::trim_func(s, |c| pattern.contains(c));
                 buf.put(trimmed)
             },
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trim_whitespace() {
+        let input = Array::from_iter(["  hello\t", "\n \t ", "", " a b "]);
+
+        let out = TrimWhitespaceImpl::<BothTrimOp>::new()
+            .execute(&[&input])
+            .unwrap();
+        assert_eq!(Array::from_iter(["hello", "", "", "a b"]), out);
+
+        let out = TrimWhitespaceImpl::<LeftTrimOp>::new()
+            .execute(&[&input])
+            .unwrap();
+        assert_eq!(Array::from_iter(["hello\t", "", "", "a b "]), out);
+
+        let out = TrimWhitespaceImpl::<RightTrimOp>::new()
+            .execute(&[&input])
+            .unwrap();
+        assert_eq!(Array::from_iter(["  hello", "", "", " a b"]), out);
+    }
+
+    #[test]
+    fn trim_custom_characters() {
+        let input = Array::from_iter(["xyxtrimyyx", "éétrimé", "xyx"]);
+        let pattern = Array::from_iter(["xy", "é", "xy"]);
+
+        let out = TrimPatternImpl::<BothTrimOp>::new()
+            .execute(&[&input, &pattern])
+            .unwrap();
+        assert_eq!(Array::from_iter(["trim", "trim", ""]), out);
+
+        let out = TrimPatternImpl::<LeftTrimOp>::new()
+            .execute(&[&input, &pattern])
+            .unwrap();
+        assert_eq!(Array::from_iter(["trimyyx", "trimé", ""]), out);
+
+        let out = TrimPatternImpl::<RightTrimOp>::new()
+            .execute(&[&input, &pattern])
+            .unwrap();
+        assert_eq!(Array::from_iter(["xyxtrim", "éétrim", ""]), out);
+    }
+}
@@ -1,12 +1,21 @@
+use std::collections::HashSet;
 use std::fmt::Debug;
 
-use rayexec_error::{not_implemented, Result};
+use rayexec_error::{RayexecError, Result};
+use serde::{Deserialize, Serialize};
 
-use crate::arrays::datatype::DataTypeId;
+use crate::arrays::array::{Array, ArrayData};
+use crate::arrays::datatype::{DataType, DataTypeId, StructTypeMeta};
+use crate::arrays::executor::scalar::interleave;
+use crate::arrays::field::Field;
+use crate::arrays::storage::StructStorage;
 use crate::expr::Expression;
-use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction};
-use crate::functions::{FunctionInfo, Signature};
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct StructPack;
@@ -18,10 +27,18 @@ impl FunctionInfo for StructPack {
 
     fn signatures(&self) -> &[Signature] {
         &[Signature {
-            positional_args: &[DataTypeId::Struct],
-            variadic_arg: None,
+            positional_args: &[],
+            variadic_arg: Some(DataTypeId::Any),
             return_type: DataTypeId::Struct,
-            doc: None,
+            doc: Some(&Documentation {
+                category: Category::Struct,
+                description: "Create a struct from alternating field names and values.",
+                arguments: &["var_arg"],
+                example: Some(Example {
+                    example: "struct_pack('a', 1, 'b', 'cat')",
+                    output: "{1, cat}",
+                }),
+            }),
         }]
     }
 }
@@ -29,10 +46,135 @@ impl FunctionInfo for StructPack {
 impl ScalarFunction for StructPack {
     fn plan(
         &self,
-        _table_list: &TableList,
-        _inputs: Vec<Expression>,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction> {
-        not_implemented!("struct pack")
+        if inputs.is_empty() || !inputs.len().is_multiple_of(2) {
+            return Err(RayexecError::new(
+                "struct_pack expects alternating field names and values",
+            ));
+        }
+
+        let mut names = Vec::with_capacity(inputs.len() / 2);
+        let mut values = Vec::with_capacity(inputs.len() / 2);
+
+        let mut inputs = inputs.into_iter();
+        while let (Some(name), Some(value)) = (inputs.next(), inputs.next()) {
+            let name = ConstFold::rewrite(table_list, name)?
+                .try_into_scalar()?
+                .try_into_string()?;
+            names.push(name);
+            values.push(value);
+        }
+
+        plan_struct(self, table_list, names, values)
+    }
+
+    fn cast_variadic_args(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructRow;
+
+impl FunctionInfo for StructRow {
+    fn name(&self) -> &'static str {
+        "row"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: Some(DataTypeId::Any),
+            return_type: DataTypeId::Struct,
+            doc: Some(&Documentation {
+                category: Category::Struct,
+                description:
+                    "Create a struct from the given values. Fields are named 'f1', 'f2', etc.",
+                arguments: &["var_arg"],
+                example: Some(Example {
+                    example: "row(1, 'cat')",
+                    output: "{1, cat}",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for StructRow {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        if inputs.is_empty() {
+            return Err(RayexecError::new("row expects at least one value"));
+        }
+
+        let names = (1..=inputs.len()).map(|idx| format!("f{idx}")).collect();
+
+        plan_struct(self, table_list, names, inputs)
+    }
+
+    fn cast_variadic_args(&self) -> bool {
+        false
+    }
+}
+
+/// Plan a function producing a struct with the given field names and values.
+fn plan_struct(
+    function: &(impl ScalarFunction + Clone + 'static),
+    table_list: &TableList,
+    names: Vec<String>,
+    values: Vec<Expression>,
+) -> Result<PlannedScalarFunction> {
+    let mut seen = HashSet::with_capacity(names.len());
+    for name in &names {
+        if !seen.insert(name.as_str()) {
+            return Err(RayexecError::new(format!(
+                "Duplicate struct field name: {name}"
+            )));
+        }
+    }
+
+    let fields = names
+        .into_iter()
+        .zip(&values)
+        .map(|(name, value)| Ok(Field::new(name, value.datatype(table_list)?, true)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let return_type = DataType::Struct(StructTypeMeta { fields });
+
+    Ok(PlannedScalarFunction {
+        function: Box::new(function.clone()),
+        return_type: return_type.clone(),
+        inputs: values,
+        function_impl: Box::new(StructPackImpl {
+            struct_datatype: return_type,
+        }),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructPackImpl {
+    struct_datatype: DataType,
+}
+
+impl ScalarFunctionImpl for StructPackImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let len = match inputs.first() {
+            Some(input) => input.logical_len(),
+            None => return Err(RayexecError::new("Struct requires at least one field")),
+        };
+
+        let arrays = inputs.iter().map(|&input| input.clone()).collect();
+        let data = StructStorage::try_new(len, arrays)?;
+
+        Ok(Array::new_with_array_data(
+            self.struct_datatype.clone(),
+            data,
+        ))
     }
 }
 
@@ -46,10 +188,18 @@ impl FunctionInfo for StructExtract {
 
     fn signatures(&self) -> &[Signature] {
         &[Signature {
-            positional_args: &[DataTypeId::Struct],
+            positional_args: &[DataTypeId::Struct, DataTypeId::Utf8],
             variadic_arg: None,
             return_type: DataTypeId::Any,
-            doc: None,
+            doc: Some(&Documentation {
+                category: Category::Struct,
+                description: "Extract a field from a struct by name.",
+                arguments: &["struct", "field"],
+                example: Some(Example {
+                    example: "struct_extract({a: 1, b: 'cat'}, 'b')",
+                    output: "cat",
+                }),
+            }),
         }]
     }
 }
@@ -57,9 +207,104 @@ impl FunctionInfo for StructExtract {
 impl ScalarFunction for StructExtract {
     fn plan(
         &self,
-        _table_list: &TableList,
-        _inputs: Vec<Expression>,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction> {
-        not_implemented!("struct extract")
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        plan_check_num_args(self, &datatypes, 2)?;
+
+        let fields = match &datatypes[0] {
+            DataType::Struct(meta) => &meta.fields,
+            _ => return Err(invalid_input_types_error(self, &datatypes)),
+        };
+
+        let name = ConstFold::rewrite(table_list, inputs[1].clone())?
+            .try_into_scalar()?
+            .try_into_string()?;
+
+        let field_idx = fields
+            .iter()
+            .position(|field| field.name == name)
+            .ok_or_else(|| {
+                RayexecError::new(format!(
+                    "Missing field '{name}' in struct, available fields: {}",
+                    fields
+                        .iter()
+                        .map(|field| field.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })?;
+
+        let field_datatype = fields[field_idx].datatype.clone();
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: field_datatype.clone(),
+            inputs,
+            function_impl: Box::new(StructExtractImpl {
+                field_idx,
+                field_datatype,
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructExtractImpl {
+    field_idx: usize,
+    field_datatype: DataType,
+}
+
+impl ScalarFunctionImpl for StructExtractImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+        let storage = match input.array_data() {
+            ArrayData::Struct(storage) => storage,
+            _other => return Err(RayexecError::new("Unexpected storage type")),
+        };
+
+        let mut child = match storage.field_array(self.field_idx) {
+            Some(child) => child.clone(),
+            None => {
+                // All-null struct, no child arrays.
+                return Array::new_typed_null_array(
+                    self.field_datatype.clone(),
+                    input.logical_len(),
+                );
+            }
+        };
+
+        // Physical rows in the struct map to logical rows in the child.
+        if let Some(selection) = input.selection_vector() {
+            child.select_mut(selection.clone());
+        }
+
+        // Null structs produce null fields.
+        let has_nulls = input
+            .validity()
+            .map(|validity| !validity.is_all_true())
+            .unwrap_or(false);
+
+        if !has_nulls {
+            return Ok(child);
+        }
+
+        let nulls = Array::new_typed_null_array(self.field_datatype.clone(), 1)?;
+        let indices: Vec<_> = (0..input.logical_len())
+            .map(|idx| {
+                if input.is_valid(idx).unwrap_or(false) {
+                    (0, idx)
+                } else {
+                    (1, 0)
+                }
+            })
+            .collect();
+
+        interleave(&[&child, &nulls], &indices)
     }
 }
@@ -8,8 +8,12 @@ use rayexec_error::Result;
 
 use super::FunctionInfo;
 use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::datatype::DataType;
+use crate::expr::physical::PhysicalScalarExpression;
 use crate::expr::Expression;
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
 use crate::logical::binder::table_list::TableList;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,6 +25,15 @@ pub enum FunctionVolatility {
     Consistent,
 }
 
+/// Session options that may affect how a scalar function is planned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScalarPlanOptions {
+    /// How to round values that can't be represented exactly.
+    pub rounding: RoundingMode,
+    /// Behavior when an arithmetic result doesn't fit in the output type.
+    pub overflow: OverflowBehavior,
+}
+
 /// A generic scalar function that can specialize into a more specific function
 /// depending on input types.
 ///
@@ -43,6 +56,54 @@ pub trait ScalarFunction: FunctionInfo + Debug + Sync + Send + DynClone {
         table_list: &TableList,
         inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction>;
+
+    /// Plan a volatile scalar function using a seed derived from the query
+    /// seed.
+    ///
+    /// Functions producing random values should seed themselves with `seed`
+    /// so that a query can be replayed exactly. Defaults to `plan`, ignoring
+    /// the seed.
+    fn plan_with_seed(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        _seed: u64,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan(table_list, inputs)
+    }
+
+    /// Plan a scalar function using options configured for the session.
+    ///
+    /// Functions that round values (e.g. `round`) or that may overflow (e.g.
+    /// `+`) should use the relevant option. Defaults to `plan`, ignoring the
+    /// options.
+    fn plan_with_options(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+        _options: ScalarPlanOptions,
+    ) -> Result<PlannedScalarFunction> {
+        self.plan(table_list, inputs)
+    }
+
+    /// If variadic `Any` arguments should be cast to a common data type before
+    /// planning.
+    ///
+    /// Functions that operate on heterogeneous inputs (e.g. constructing a
+    /// struct) should return false to receive the inputs as-is.
+    fn cast_variadic_args(&self) -> bool {
+        true
+    }
+
+    /// Determine if the output of this function may be NULL given the
+    /// nullability of its inputs.
+    ///
+    /// The default assumes NULLs in produce NULLs out, and that NULLs are only
+    /// produced from NULL inputs. Functions that can produce NULLs from
+    /// non-NULL inputs (or never produce NULLs at all) should override this.
+    fn output_nullable(&self, inputs: &[bool]) -> bool {
+        inputs.iter().any(|nullable| *nullable)
+    }
 }
 
 impl Clone for Box<dyn ScalarFunction> {
@@ -94,6 +155,19 @@ impl PartialEq for PlannedScalarFunction {
 
 impl Eq for PlannedScalarFunction {}
 
+impl PlannedScalarFunction {
+    /// Determine if the output of this planned function may be NULL given the
+    /// nullability of its inputs.
+    ///
+    /// Checks the function implementation first since nullability may depend
+    /// on how the function was planned.
+    pub fn output_nullable(&self, inputs: &[bool]) -> bool {
+        self.function_impl
+            .output_nullable(inputs)
+            .unwrap_or_else(|| self.function.output_nullable(inputs))
+    }
+}
+
 impl Hash for PlannedScalarFunction {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.function.name().hash(state);
@@ -104,6 +178,41 @@ impl Hash for PlannedScalarFunction {
 
 pub trait ScalarFunctionImpl: Debug + Sync + Send + DynClone {
     fn execute(&self, inputs: &[&Array]) -> Result<Array>;
+
+    /// Execute the function, evaluating the inputs against the batch.
+    ///
+    /// The default evaluates every input up front then calls `execute`.
+    /// Functions that don't need every input for every row (e.g. `coalesce`)
+    /// can override this to only evaluate inputs for the rows that need them.
+    fn execute_lazy(&self, batch: &Batch, inputs: &[PhysicalScalarExpression]) -> Result<Array> {
+        let inputs = inputs
+            .iter()
+            .map(|input| input.eval(batch))
+            .collect::<Result<Vec<_>>>()?;
+        let refs: Vec<_> = inputs.iter().map(|a| a.as_ref()).collect();
+
+        self.execute(&refs)
+    }
+
+    /// Create a copy of this function for executing within a single
+    /// partition.
+    ///
+    /// Functions holding state that's advanced during execution (e.g. a random
+    /// number generator) should return a copy with state local to the
+    /// partition so that results don't depend on the order partitions are
+    /// scheduled in. Returns None if the function can be shared across all
+    /// partitions.
+    fn for_partition(&self, _partition: usize) -> Option<Box<dyn ScalarFunctionImpl>> {
+        None
+    }
+
+    /// Determine if the output may be NULL given the nullability of the
+    /// inputs, taking into account options the function was planned with.
+    ///
+    /// Returns None to defer to `ScalarFunction::output_nullable`.
+    fn output_nullable(&self, _inputs: &[bool]) -> Option<bool> {
+        None
+    }
 }
 
 impl Clone for Box<dyn ScalarFunctionImpl> {
@@ -9,6 +9,7 @@ use dyn_clone::DynClone;
 use futures::future::BoxFuture;
 use inout::TableInOutFunction;
 use rayexec_error::{RayexecError, Result};
+use rayexec_io::compression::Compression;
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::s3::credentials::AwsCredentials;
 use rayexec_io::s3::S3Location;
@@ -175,6 +176,20 @@ pub fn try_location_and_access_config_from_args(
     Ok((loc, conf))
 }
 
+/// Try to get the compression to use when reading from `loc`.
+///
+/// Uses the `compression` named argument if provided, otherwise infers the
+/// compression from the file extension.
+pub fn try_compression_from_args(
+    loc: &FileLocation,
+    named: &HashMap<String, OwnedScalarValue>,
+) -> Result<Compression> {
+    match named.get("compression") {
+        Some(val) => Compression::try_from_name(val.try_as_str()?),
+        None => Ok(Compression::from_location(loc)),
+    }
+}
+
 pub fn try_get_named<'a>(
     func: &impl TableFunction,
     name: &str,
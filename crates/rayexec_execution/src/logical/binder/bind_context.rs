@@ -5,8 +5,10 @@ use rayexec_error::{RayexecError, Result};
 
 use super::bind_query::BoundQuery;
 use super::table_list::{Table, TableAlias, TableList, TableRef};
+use crate::arrays::compute::rounding::RoundingMode;
 use crate::arrays::datatype::DataType;
 use crate::expr::Expression;
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
 use crate::logical::operator::{LogicalNode, LogicalOperator};
 
 /// Reference to a child bind scope.
@@ -66,6 +68,18 @@ pub struct BindContext {
     ///
     /// Referenced via `MaterializationRef`.
     materializations: Vec<PlanMaterialization>,
+    /// Seed for nondeterministic functions in the query.
+    query_seed: u64,
+    /// Number of function seeds derived from the query seed so far.
+    function_seed_count: u64,
+    /// If GROUP BY output should be sorted on the group keys.
+    sort_group_by_output: bool,
+    /// If dividing two integers should produce an integer.
+    integer_division: bool,
+    /// Rounding mode for operations that need to round.
+    rounding_mode: RoundingMode,
+    /// Behavior for arithmetic that overflows its output type.
+    arithmetic_overflow: OverflowBehavior,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -81,8 +95,11 @@ pub struct CorrelatedColumn {
 pub struct BoundCte {
     /// Scope used for binding the CTE.
     pub bind_scope: BindScopeRef,
-    /// If this CTE should be materialized.
-    pub materialized: bool,
+    /// Explicit materialization hint for the CTE.
+    ///
+    /// If None, the CTE is materialized only if it's referenced more than
+    /// once.
+    pub materialized: Option<bool>,
     /// Normalized name fo the CTE.
     pub name: String,
     /// Column names, possibly aliased.
@@ -93,9 +110,19 @@ pub struct BoundCte {
     pub bound: Box<BoundQuery>,
     /// Materialization reference for the CTE.
     ///
-    /// If `materialized` is false and this is None, we need to plan the bound
-    /// query first.
+    /// If this is None and the CTE should be materialized, we need to plan the
+    /// bound query first.
     pub mat_ref: Option<MaterializationRef>,
+    /// Number of times this CTE is referenced in the query, not counting
+    /// references to the working table of a recursive CTE.
+    pub num_references: usize,
+    /// If we're currently binding the recursive term of this CTE.
+    ///
+    /// While set, references to this CTE bind to the working table of the
+    /// recursive CTE instead of its plan.
+    pub in_recursive_term: bool,
+    /// If the recursive term referenced the working table.
+    pub self_referenced: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -144,6 +171,12 @@ impl Default for BindContext {
     }
 }
 
+impl AsRef<TableList> for BindContext {
+    fn as_ref(&self) -> &TableList {
+        &self.tables
+    }
+}
+
 impl BindContext {
     pub fn new() -> Self {
         BindContext {
@@ -157,9 +190,82 @@ impl BindContext {
             tables: TableList::empty(),
             ctes: Vec::new(),
             materializations: Vec::new(),
+            query_seed: rand::random(),
+            function_seed_count: 0,
+            sort_group_by_output: false,
+            integer_division: true,
+            rounding_mode: RoundingMode::default(),
+            arithmetic_overflow: OverflowBehavior::default(),
         }
     }
 
+    /// Get the seed used for nondeterministic functions in this query.
+    pub fn query_seed(&self) -> u64 {
+        self.query_seed
+    }
+
+    /// Set the seed to use for nondeterministic functions in this query.
+    pub fn set_query_seed(&mut self, seed: u64) {
+        self.query_seed = seed;
+        self.function_seed_count = 0;
+    }
+
+    /// Derive a seed for the next nondeterministic function in the query.
+    ///
+    /// Functions are bound in a consistent order, so binding the same query
+    /// with the same query seed hands out the same function seeds.
+    pub fn next_function_seed(&mut self) -> u64 {
+        let seed = self
+            .query_seed
+            .wrapping_add(self.function_seed_count.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        self.function_seed_count += 1;
+        seed
+    }
+
+    /// If the output of a GROUP BY should be sorted on its group keys.
+    pub fn sort_group_by_output(&self) -> bool {
+        self.sort_group_by_output
+    }
+
+    /// Set if the output of a GROUP BY should be sorted on its group keys.
+    pub fn set_sort_group_by_output(&mut self, sort: bool) {
+        self.sort_group_by_output = sort;
+    }
+
+    /// If dividing two integers should produce an integer.
+    ///
+    /// If false, integer inputs are cast to floats before dividing.
+    pub fn integer_division(&self) -> bool {
+        self.integer_division
+    }
+
+    /// Set if dividing two integers should produce an integer.
+    pub fn set_integer_division(&mut self, integer_division: bool) {
+        self.integer_division = integer_division;
+    }
+
+    /// Rounding mode to use for functions and casts that need to round.
+    pub fn rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    /// Set the rounding mode to use for functions and casts that need to
+    /// round.
+    pub fn set_rounding_mode(&mut self, rounding_mode: RoundingMode) {
+        self.rounding_mode = rounding_mode;
+    }
+
+    /// Behavior for arithmetic operators that overflow their output type.
+    pub fn arithmetic_overflow(&self) -> OverflowBehavior {
+        self.arithmetic_overflow
+    }
+
+    /// Set the behavior for arithmetic operators that overflow their output
+    /// type.
+    pub fn set_arithmetic_overflow(&mut self, overflow: OverflowBehavior) {
+        self.arithmetic_overflow = overflow;
+    }
+
     pub fn root_scope_ref(&self) -> BindScopeRef {
         BindScopeRef { context_idx: 0 }
     }
@@ -437,6 +543,7 @@ impl BindContext {
         let scope = Table {
             reference,
             alias: None,
+            column_nullable: vec![true; column_types.len()],
             column_types,
             column_names,
         };
@@ -472,6 +579,7 @@ impl BindContext {
             alias: None,
             column_types: table.column_types.clone(),
             column_names: table.column_names.clone(),
+            column_nullable: table.column_nullable.clone(),
         });
 
         Ok(reference)
@@ -500,6 +608,7 @@ impl BindContext {
         let idx = table.column_types.len();
         table.column_names.push(name.into());
         table.column_types.push(datatype);
+        table.column_nullable.push(true);
         Ok(idx)
     }
 
@@ -7,7 +7,7 @@ use super::bind_query::bind_from::BoundFrom;
 use super::bind_query::BoundQuery;
 use crate::arrays::datatype::DataType;
 use crate::arrays::field::{Field, Schema};
-use crate::functions::copy::CopyToFunction;
+use crate::functions::copy::{CopyToArgs, CopyToFunction};
 use crate::logical::binder::bind_query::bind_from::FromBinder;
 use crate::logical::binder::bind_query::QueryBinder;
 use crate::logical::resolver::resolve_context::ResolveContext;
@@ -25,6 +25,9 @@ pub struct BoundCopyTo {
     pub source_schema: Schema,
     pub location: FileLocation,
     pub copy_to: Box<dyn CopyToFunction>,
+    pub args: CopyToArgs,
+    /// Indices of the source columns to partition the output by.
+    pub partition_by: Vec<usize>,
 }
 
 #[derive(Debug)]
@@ -90,11 +93,43 @@ impl<'a> CopyBinder<'a> {
             .ok_or_else(|| RayexecError::new("Missing COPY TO function"))?
             .clone();
 
+        let mut args = copy_to.options;
+        let mut partition_by = Vec::new();
+        for name in args.try_remove_partition_by()? {
+            let idx = source_schema
+                .fields
+                .iter()
+                .position(|field| field.name == name)
+                .ok_or_else(|| {
+                    RayexecError::new(format!("Missing column for PARTITION_BY: '{name}'"))
+                })?;
+            if partition_by.contains(&idx) {
+                return Err(RayexecError::new(format!(
+                    "Duplicate column in PARTITION_BY: '{name}'"
+                )));
+            }
+            partition_by.push(idx);
+        }
+
+        if !partition_by.is_empty() && partition_by.len() >= source_schema.fields.len() {
+            return Err(RayexecError::new(
+                "PARTITION_BY requires at least one column that isn't partitioned on",
+            ));
+        }
+
+        if !partition_by.is_empty() && args.clone().try_remove_append()? {
+            return Err(RayexecError::new("APPEND cannot be used with PARTITION_BY"));
+        }
+
+        resolved_copy_to.func.validate_args(&args)?;
+
         Ok(BoundCopyTo {
             source,
             source_schema,
             location: copy_to.target,
             copy_to: resolved_copy_to.func,
+            args,
+            partition_by,
         })
     }
 }
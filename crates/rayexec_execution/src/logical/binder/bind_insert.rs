@@ -119,10 +119,11 @@ impl<'a> InsertBinder<'a> {
             });
 
             if have.2 != want {
-                expr = Expression::Cast(CastExpr {
-                    to: want.clone(),
-                    expr: Box::new(expr),
-                });
+                expr = Expression::Cast(CastExpr::new_using_session(
+                    bind_context,
+                    expr,
+                    want.clone(),
+                ));
                 has_cast = true;
             }
 
@@ -8,7 +8,7 @@ use super::{BoundQuery, QueryBinder};
 use crate::arrays::datatype::DataType;
 use crate::database::catalog_entry::CatalogEntry;
 use crate::expr::column_expr::ColumnExpr;
-use crate::expr::comparison_expr::{ComparisonExpr, ComparisonOperator};
+use crate::expr::comparison_expr::ComparisonOperator;
 use crate::expr::Expression;
 use crate::functions::table::{PlannedTableFunction, TableFunctionPlanner};
 use crate::logical::binder::bind_context::{
@@ -43,6 +43,7 @@ pub enum BoundFromItem {
     TableFunction(BoundTableFunction),
     Subquery(BoundSubquery),
     MaterializedCte(BoundMaterializedCte),
+    WorkingTable(BoundWorkingTable),
     Empty,
 }
 
@@ -60,6 +61,10 @@ pub struct BoundTableFunction {
     pub table_ref: TableRef,
     pub location: LocationRequirement,
     pub function: PlannedTableFunction,
+    /// If an Int64 ordinality column is appended to the function output.
+    ///
+    /// The ordinality column is the last column in the table scope.
+    pub with_ordinality: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -68,6 +73,10 @@ pub struct BoundSubquery {
     pub subquery: Box<BoundQuery>,
 }
 
+/// Reference to a CTE that's planned once and scanned by every reference.
+///
+/// Whether or not the CTE actually gets materialized is decided during
+/// planning once all references to the CTE have been bound.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoundMaterializedCte {
     pub table_ref: TableRef,
@@ -75,6 +84,13 @@ pub struct BoundMaterializedCte {
     pub cte_name: String,
 }
 
+/// Reference to the working table of a recursive CTE from within its
+/// recursive term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundWorkingTable {
+    pub table_ref: TableRef,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoundJoin {
     /// Reference to binder for left side of join.
@@ -210,6 +226,13 @@ impl<'a> FromBinder<'a> {
                     .iter()
                     .map(|c| c.name.clone())
                     .collect();
+                let column_nullable = table
+                    .entry
+                    .try_as_table_entry()?
+                    .columns
+                    .iter()
+                    .map(|c| c.nullable)
+                    .collect();
 
                 let default_alias = TableAlias {
                     database: Some(table.catalog.clone()),
@@ -224,6 +247,9 @@ impl<'a> FromBinder<'a> {
                     column_types,
                     alias,
                 )?;
+                bind_context
+                    .get_table_mut(table_ref)?
+                    .set_column_nullability(column_nullable)?;
 
                 Ok(BoundFrom {
                     bind_ref: self.current,
@@ -261,11 +287,11 @@ impl<'a> FromBinder<'a> {
         let names = cte.column_names.clone();
         let types = cte.column_types.clone();
 
-        if cte.materialized {
-            let cte_name = cte.name.clone();
-            // Binds with the alias provided in the FROM.
-            //
-            // ... FROM mycte AS aliased_cte(c1, c2) ...
+        if cte.in_recursive_term {
+            // Reference from within the CTE's own recursive term, scan the
+            // rows produced by the previous iteration.
+            bind_context.get_cte_mut(cte_ref)?.self_referenced = true;
+
             let table_ref = self.push_table_scope_with_from_alias(
                 bind_context,
                 Some(table_alias),
@@ -276,15 +302,13 @@ impl<'a> FromBinder<'a> {
 
             Ok(BoundFrom {
                 bind_ref: self.current,
-                item: BoundFromItem::MaterializedCte(BoundMaterializedCte {
-                    table_ref,
-                    cte_ref,
-                    cte_name,
-                }),
+                item: BoundFromItem::WorkingTable(BoundWorkingTable { table_ref }),
             })
         } else {
-            // Not materialize, just copy the plan as a subquery.
-            let subquery = cte.bound.clone();
+            // CTEs are inlined or materialized during planning depending on the
+            // hint and number of references.
+            let cte_name = cte.name.clone();
+            bind_context.get_cte_mut(cte_ref)?.num_references += 1;
 
             // Binds with the alias provided in the FROM.
             //
@@ -299,9 +323,10 @@ impl<'a> FromBinder<'a> {
 
             Ok(BoundFrom {
                 bind_ref: self.current,
-                item: BoundFromItem::Subquery(BoundSubquery {
+                item: BoundFromItem::MaterializedCte(BoundMaterializedCte {
                     table_ref,
-                    subquery,
+                    cte_ref,
+                    cte_name,
                 }),
             })
         }
@@ -327,9 +352,11 @@ impl<'a> FromBinder<'a> {
 
         let mut names = Vec::new();
         let mut types = Vec::new();
+        let mut nullable = Vec::new();
         for table in bind_context.iter_tables_in_scope(nested_scope)? {
             types.extend(table.column_types.iter().cloned());
             names.extend(table.column_names.iter().cloned());
+            nullable.extend(table.column_nullable.iter().copied());
         }
 
         let table_ref = if let ResolvedSubqueryOptions::View {
@@ -367,6 +394,9 @@ impl<'a> FromBinder<'a> {
             // Nothing special, just a normal subquery so no table alias.
             self.push_table_scope_with_from_alias(bind_context, None, names, types, alias)?
         };
+        bind_context
+            .get_table_mut(table_ref)?
+            .set_column_nullability(nullable)?;
 
         // Move correlated columns into current scope.
         bind_context.append_correlated_columns(self.current, nested_scope)?;
@@ -399,6 +429,7 @@ impl<'a> FromBinder<'a> {
                 // Handle in/out function planning now. We have everything we
                 // need to plan its inputs.
                 let expr_binder = BaseExpressionBinder::new(self.current, self.resolve_context);
+                let num_correlated = bind_context.correlated_columns(self.current)?.len();
 
                 let mut positional = Vec::new();
                 let mut named = HashMap::new();
@@ -442,7 +473,14 @@ impl<'a> FromBinder<'a> {
                                     let val =
                                         ConstFold::rewrite(bind_context.get_table_list(), expr)?
                                             .try_into_scalar()?;
-                                    named.insert(name.as_normalized_string(), val);
+
+                                    let name = name.as_normalized_string();
+                                    if named.contains_key(&name) {
+                                        return Err(RayexecError::new(format!(
+                                            "Duplicate argument: {name}"
+                                        )));
+                                    }
+                                    named.insert(name, val);
                                 }
                                 ast::FunctionArgExpr::Wildcard => {
                                     return Err(RayexecError::new(
@@ -454,6 +492,14 @@ impl<'a> FromBinder<'a> {
                     }
                 }
 
+                // Ordinality is computed over the entire function output, which
+                // doesn't match per-row numbering for lateral inputs.
+                if function.with_ordinality
+                    && bind_context.correlated_columns(self.current)?.len() != num_correlated
+                {
+                    not_implemented!("WITH ORDINALITY for table functions with lateral inputs");
+                }
+
                 // Note only positional input casts for now. Signatures don't
                 // have a notion of named arguments yet.
                 let positional = expr_binder.apply_casts_for_table_function(
@@ -484,13 +530,18 @@ impl<'a> FromBinder<'a> {
             table: reference.base_table_alias(),
         };
 
-        let (names, types) = planned
+        let (mut names, mut types): (Vec<_>, Vec<_>) = planned
             .schema
             .fields
             .iter()
             .map(|f| (f.name.clone(), f.datatype.clone()))
             .unzip();
 
+        if function.with_ordinality {
+            names.push("ordinality".to_string());
+            types.push(DataType::Int64);
+        }
+
         let table_ref = self.push_table_scope_with_from_alias(
             bind_context,
             Some(default_alias),
@@ -505,6 +556,7 @@ impl<'a> FromBinder<'a> {
                 table_ref,
                 location,
                 function: planned,
+                with_ordinality: function.with_ordinality,
             }),
         })
     }
@@ -593,6 +645,7 @@ impl<'a> FromBinder<'a> {
             ast::JoinType::Inner => JoinType::Inner,
             ast::JoinType::Left => JoinType::Left,
             ast::JoinType::Right => JoinType::Right,
+            ast::JoinType::Outer => JoinType::Full,
             ast::JoinType::LeftSemi => JoinType::Semi,
             other => not_implemented!("plan join type: {other:?}"),
         };
@@ -658,27 +711,18 @@ impl<'a> FromBinder<'a> {
             }
 
             // Generate additional equality condition.
-            // TODO: Probably make this a method on the expr binder. Easy to miss the cast.
-            let [left, right] = condition_binder.apply_cast_for_operator(
+            conditions.push(condition_binder.bind_comparison(
                 bind_context,
                 ComparisonOperator::Eq,
-                [
-                    Expression::Column(ColumnExpr {
-                        table_scope: left_table,
-                        column: left_col_idx,
-                    }),
-                    Expression::Column(ColumnExpr {
-                        table_scope: right_table,
-                        column: right_col_idx,
-                    }),
-                ],
-            )?;
-
-            conditions.push(Expression::Comparison(ComparisonExpr {
-                left: Box::new(left),
-                right: Box::new(right),
-                op: ComparisonOperator::Eq,
-            }))
+                Expression::Column(ColumnExpr {
+                    table_scope: left_table,
+                    column: left_col_idx,
+                }),
+                Expression::Column(ColumnExpr {
+                    table_scope: right_table,
+                    column: right_col_idx,
+                }),
+            )?)
         }
 
         // Remove right columns from scope for semi joins.
@@ -690,6 +734,36 @@ impl<'a> FromBinder<'a> {
             bind_context.remove_tables(self.current, &right_tables)?;
         }
 
+        // Columns from the non-preserved side of an outer join may be NULL
+        // even if they weren't NULL in the input.
+        let (left_nullable, right_nullable) = match join_type {
+            JoinType::Left => (false, true),
+            JoinType::Right => (true, false),
+            JoinType::Full => (true, true),
+            _ => (false, false),
+        };
+        let left_tables: Vec<_> = bind_context
+            .iter_tables_in_scope(left_idx)?
+            .map(|t| t.reference)
+            .collect();
+        if left_nullable {
+            for &table in &left_tables {
+                bind_context.get_table_mut(table)?.mark_all_nullable();
+            }
+        }
+        if right_nullable {
+            // Skip tables from the left that are in scope on the right for
+            // lateral references.
+            let right_tables: Vec<_> = bind_context
+                .iter_tables_in_scope(right_idx)?
+                .map(|t| t.reference)
+                .filter(|table| !left_tables.contains(table))
+                .collect();
+            for table in right_tables {
+                bind_context.get_table_mut(table)?.mark_all_nullable();
+            }
+        }
+
         Ok(BoundFrom {
             bind_ref: self.current,
             item: BoundFromItem::Join(BoundJoin {
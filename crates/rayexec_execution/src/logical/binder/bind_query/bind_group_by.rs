@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use rayexec_error::{not_implemented, RayexecError, Result};
+use rayexec_error::{not_implemented, Result};
 use rayexec_parser::ast;
 
 use super::select_list::SelectList;
@@ -9,6 +9,11 @@ use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::column_binder::{DefaultColumnBinder, ExpressionColumnBinder};
 use crate::logical::binder::expr_binder::{BaseExpressionBinder, RecursionContext};
 use crate::logical::binder::table_list::TableRef;
+use crate::logical::logical_aggregate::{
+    grouping_sets_cross_product,
+    grouping_sets_from_cube,
+    grouping_sets_from_rollup,
+};
 use crate::logical::resolver::resolve_context::ResolveContext;
 use crate::logical::resolver::ResolvedMeta;
 
@@ -146,46 +151,42 @@ impl GroupByWithSets {
     fn try_from_ast(group_by: ast::GroupByNode<ResolvedMeta>) -> Result<Self> {
         match group_by {
             ast::GroupByNode::All => not_implemented!("GROUP BY ALL"),
-            ast::GroupByNode::Exprs { mut exprs } => {
-                let expr = match exprs.len() {
-                    1 => exprs.pop().unwrap(),
-                    _ => return Err(RayexecError::new("Invalid number of group by expressions")),
-                };
-
-                let (expressions, grouping_sets) = match expr {
-                    ast::GroupByExpr::Expr(exprs) => {
-                        let len = exprs.len();
-                        (exprs, vec![(0..len).collect()])
-                    }
-                    ast::GroupByExpr::Rollup(exprs) => {
-                        let len = exprs.len();
-                        let mut sets: Vec<_> = (0..len).map(|i| (0..(len - i)).collect()).collect();
-                        sets.push(BTreeSet::new()); // Empty set.
-                        (exprs, sets)
-                    }
-                    ast::GroupByExpr::Cube(exprs) => {
-                        let len = exprs.len();
-                        let mut sets = Vec::new();
-
-                        // Powerset
-                        for mask in 0..(1 << len) {
-                            let mut set = BTreeSet::new();
-                            let mut bitset = mask;
-                            while bitset > 0 {
-                                let right: u64 = bitset & { !(bitset - 1) };
-                                let idx = right.trailing_zeros() as usize;
-                                set.insert(idx);
-                                bitset &= bitset - 1;
-                            }
-                            sets.push(set);
+            ast::GroupByNode::Exprs { exprs } => {
+                let mut expressions = Vec::new();
+                // Start with a single empty set, each grouping element gets
+                // combined with the sets produced so far.
+                let mut grouping_sets = vec![BTreeSet::new()];
+
+                for expr in exprs {
+                    let (exprs, sets) = match expr {
+                        ast::GroupByExpr::Expr(exprs) => {
+                            let len = exprs.len();
+                            (exprs, vec![(0..len).collect()])
                         }
+                        ast::GroupByExpr::Rollup(exprs) => {
+                            let sets = grouping_sets_from_rollup(exprs.len());
+                            (exprs, sets)
+                        }
+                        ast::GroupByExpr::Cube(exprs) => {
+                            let sets = grouping_sets_from_cube(exprs.len());
+                            (exprs, sets)
+                        }
+                        ast::GroupByExpr::GroupingSets(_exprs) => {
+                            not_implemented!("GROUPING SETS")
+                        }
+                    };
 
-                        (exprs, sets)
-                    }
-                    ast::GroupByExpr::GroupingSets(_exprs) => {
-                        not_implemented!("GROUPING SETS")
-                    }
-                };
+                    // Shift indices to point to this element's expressions in
+                    // the full list of expressions.
+                    let offset = expressions.len();
+                    let sets: Vec<BTreeSet<usize>> = sets
+                        .into_iter()
+                        .map(|set| set.into_iter().map(|idx| idx + offset).collect())
+                        .collect();
+
+                    expressions.extend(exprs);
+                    grouping_sets = grouping_sets_cross_product(&grouping_sets, &sets);
+                }
 
                 Ok(GroupByWithSets {
                     expressions,
@@ -296,4 +297,52 @@ mod tests {
 
         assert_eq!(expected, sets)
     }
+
+    #[test]
+    fn group_by_with_sets_from_expr_and_rollup() {
+        // GROUP BY a, ROLLUP (b, c)
+        let node = ast::GroupByNode::Exprs {
+            exprs: vec![
+                ast::GroupByExpr::Expr(vec![ast::Expr::Ident(ast::Ident::new_unquoted("a"))]),
+                ast::GroupByExpr::Rollup(vec![
+                    ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                    ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+                ]),
+            ],
+        };
+
+        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let expected = GroupByWithSets {
+            expressions: vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+            ],
+            grouping_sets: vec![[0, 1, 2].into(), [0, 1].into(), [0].into()],
+        };
+
+        assert_eq!(expected, sets)
+    }
+
+    #[test]
+    fn group_by_with_sets_from_rollup_and_cube() {
+        // GROUP BY ROLLUP (a), CUBE (b)
+        let node = ast::GroupByNode::Exprs {
+            exprs: vec![
+                ast::GroupByExpr::Rollup(vec![ast::Expr::Ident(ast::Ident::new_unquoted("a"))]),
+                ast::GroupByExpr::Cube(vec![ast::Expr::Ident(ast::Ident::new_unquoted("b"))]),
+            ],
+        };
+
+        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let expected = GroupByWithSets {
+            expressions: vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+            ],
+            grouping_sets: vec![[0].into(), [0, 1].into(), [].into(), [1].into()],
+        };
+
+        assert_eq!(expected, sets)
+    }
 }
@@ -41,8 +41,12 @@ pub struct BoundOrderBy {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoundLimit {
-    pub limit: usize,
+    /// Number of rows to limit to, None if only an offset was provided.
+    pub limit: Option<usize>,
     pub offset: Option<usize>,
+    /// If rows tying with the last row according to the ORDER BY should be
+    /// included in the output.
+    pub with_ties: bool,
 }
 
 /// Binds ORDER BY, LIMIT, and DISTINCT.
@@ -100,8 +104,13 @@ impl<'a> ModifierBinder<'a> {
                 // If we bound to an existing item in the select list, use that
                 // expression. If we didn't, push the expression to the appended
                 // list, and bind to to that instead.
+                //
+                // Expressions identical to a projection reuse that projection
+                // instead of being appended.
                 let expr = if column_binder.did_bind_to_select {
                     expr
+                } else if let Some(col) = select_list.column_by_expression(&expr) {
+                    Expression::Column(col)
                 } else {
                     let col = select_list.append_projection(bind_context, expr)?;
                     Expression::Column(col)
@@ -138,6 +147,7 @@ impl<'a> ModifierBinder<'a> {
         &self,
         bind_context: &mut BindContext,
         limit_mod: ast::LimitModifier<ResolvedMeta>,
+        order_by: Option<&BoundOrderBy>,
     ) -> Result<Option<BoundLimit>> {
         // TODO: What do here?
         let current = match self.current.first() {
@@ -148,30 +158,36 @@ impl<'a> ModifierBinder<'a> {
         let expr_binder = BaseExpressionBinder::new(current, self.resolve_context);
 
         let limit = match limit_mod.limit {
-            Some(limit) => expr_binder.bind_expression(
-                bind_context,
-                &limit,
-                &mut DefaultColumnBinder,
-                RecursionContext {
-                    allow_windows: false,
-                    allow_aggregates: false,
-                    is_root: true,
-                },
-            )?,
+            Some(limit) => {
+                let limit = expr_binder.bind_expression(
+                    bind_context,
+                    &limit,
+                    &mut DefaultColumnBinder,
+                    RecursionContext {
+                        allow_windows: false,
+                        allow_aggregates: false,
+                        is_root: true,
+                    },
+                )?;
+                let limit = limit.try_into_scalar()?.try_as_i64()?;
+                if limit < 0 {
+                    return Err(RayexecError::new("LIMIT cannot be negative"));
+                } else {
+                    Some(limit as usize)
+                }
+            }
             None => {
-                if limit_mod.offset.is_some() {
-                    return Err(RayexecError::new("OFFSET without LIMIT not supported"));
+                // OFFSET without a LIMIT just skips rows.
+                if limit_mod.offset.is_none() {
+                    return Ok(None);
                 }
-                return Ok(None);
+                None
             }
         };
 
-        let limit = limit.try_into_scalar()?.try_as_i64()?;
-        let limit = if limit < 0 {
-            return Err(RayexecError::new("LIMIT cannot be negative"));
-        } else {
-            limit as usize
-        };
+        if limit_mod.with_ties && order_by.is_none() {
+            return Err(RayexecError::new("WITH TIES requires an ORDER BY clause"));
+        }
 
         let offset = match limit_mod.offset {
             Some(offset) => {
@@ -195,7 +211,11 @@ impl<'a> ModifierBinder<'a> {
             None => None,
         };
 
-        Ok(Some(BoundLimit { limit, offset }))
+        Ok(Some(BoundLimit {
+            limit,
+            offset,
+            with_ties: limit_mod.with_ties,
+        }))
     }
 }
 
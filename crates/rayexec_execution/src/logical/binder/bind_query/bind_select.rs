@@ -1,4 +1,4 @@
-use rayexec_error::{RayexecError, Result};
+use rayexec_error::{not_implemented, RayexecError, Result};
 use rayexec_parser::ast;
 
 use super::bind_from::{BoundFrom, FromBinder};
@@ -31,6 +31,8 @@ pub struct BoundSelect {
     pub order_by: Option<BoundOrderBy>,
     /// Bound LIMIT.
     pub limit: Option<BoundLimit>,
+    /// If this is a SELECT DISTINCT.
+    pub distinct: bool,
     pub groupings: Vec<Vec<usize>>,
 }
 
@@ -89,12 +91,28 @@ impl<'a> SelectBinder<'a> {
             })
             .transpose()?;
 
-        // Handle ORDER BY, LIMIT, DISTINCT (todo)
+        // Handle ORDER BY, LIMIT
         let modifier_binder = ModifierBinder::new(vec![from_bind_ref], self.resolve_context);
         let order_by = order_by
             .map(|order_by| modifier_binder.bind_order_by(bind_context, &mut select_list, order_by))
             .transpose()?;
-        let limit = modifier_binder.bind_limit(bind_context, limit)?;
+
+        // Handle DISTINCT
+        let distinct = match select.distinct {
+            Some(ast::DistinctModifier::All) => {
+                // DISTINCT applies to the output columns, ordering on anything
+                // else would make the dedup depend on hidden columns.
+                if !select_list.appended.is_empty() {
+                    return Err(RayexecError::new(
+                        "For SELECT DISTINCT, ORDER BY expressions must appear in select list",
+                    ));
+                }
+                true
+            }
+            Some(ast::DistinctModifier::On(_)) => not_implemented!("DISTINCT ON"),
+            None => false,
+        };
+        let limit = modifier_binder.bind_limit(bind_context, limit, order_by.as_ref())?;
 
         // Handle GROUP BY
         let mut group_by = select
@@ -148,6 +166,7 @@ impl<'a> SelectBinder<'a> {
             group_by,
             order_by,
             limit,
+            distinct,
             groupings: Vec::new(),
         })
     }
@@ -187,6 +206,7 @@ mod tests {
         let limit = ast::LimitModifier {
             limit: None,
             offset: None,
+            with_ties: false,
         };
 
         let _ = binder.bind(&mut bind_context, select, None, limit).unwrap();
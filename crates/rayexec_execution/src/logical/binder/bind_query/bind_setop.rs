@@ -4,7 +4,7 @@ use rayexec_parser::ast;
 use super::bind_modifier::{BoundLimit, BoundOrderBy};
 use super::bind_select_list::SelectListBinder;
 use super::BoundQuery;
-use crate::functions::implicit::implicit_cast_score;
+use crate::functions::implicit::implicit_common_type;
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::bind_query::bind_modifier::ModifierBinder;
 use crate::logical::binder::bind_query::QueryBinder;
@@ -46,6 +46,9 @@ pub struct BoundSetOp {
     /// Bound LIMIT.
     pub limit: Option<BoundLimit>,
     pub cast_req: SetOpCastRequirement,
+    /// If this is the body of a recursive CTE, with the right side referencing
+    /// the working table.
+    pub recursive: bool,
 }
 
 #[derive(Debug)]
@@ -69,6 +72,25 @@ impl<'a> SetOpBinder<'a> {
         order_by: Option<ast::OrderByModifier<ResolvedMeta>>,
         limit: ast::LimitModifier<ResolvedMeta>,
     ) -> Result<BoundSetOp> {
+        self.bind_with_left_callback(bind_context, setop, order_by, limit, |_, _, _| Ok(()))
+    }
+
+    /// Bind the set operation, calling `after_left` with the bound left side and
+    /// its scope before binding the right side.
+    ///
+    /// Used for recursive CTEs where the left side (the anchor) determines the
+    /// columns of the working table referenced by the right side.
+    pub fn bind_with_left_callback<F>(
+        &self,
+        bind_context: &mut BindContext,
+        setop: ast::SetOp<ResolvedMeta>,
+        order_by: Option<ast::OrderByModifier<ResolvedMeta>>,
+        limit: ast::LimitModifier<ResolvedMeta>,
+        after_left: F,
+    ) -> Result<BoundSetOp>
+    where
+        F: FnOnce(&mut BindContext, BindScopeRef, &BoundQuery) -> Result<()>,
+    {
         let left_scope = bind_context.new_child_scope(self.current);
         // TODO: Make limit modifier optional.
         let left = QueryBinder::new(left_scope, self.resolve_context).bind_body(
@@ -78,9 +100,12 @@ impl<'a> SetOpBinder<'a> {
             ast::LimitModifier {
                 limit: None,
                 offset: None,
+                with_ties: false,
             },
         )?;
 
+        after_left(bind_context, left_scope, &left)?;
+
         let right_scope = bind_context.new_child_scope(self.current);
         let right = QueryBinder::new(right_scope, self.resolve_context).bind_body(
             bind_context,
@@ -89,6 +114,7 @@ impl<'a> SetOpBinder<'a> {
             ast::LimitModifier {
                 limit: None,
                 offset: None,
+                with_ties: false,
             },
         )?;
 
@@ -104,6 +130,18 @@ impl<'a> SetOpBinder<'a> {
             .flat_map(|t| t.column_types.iter().cloned())
             .collect();
 
+        // Output column is nullable if either side is nullable.
+        let nullable: Vec<_> = bind_context
+            .iter_tables_in_scope(left_scope)?
+            .flat_map(|t| t.column_nullable.iter().copied())
+            .zip(
+                bind_context
+                    .iter_tables_in_scope(right_scope)?
+                    .flat_map(|t| t.column_nullable.iter().copied()),
+            )
+            .map(|(left, right)| left || right)
+            .collect();
+
         // Determine output types of this node by comparing both sides, and
         // marking which side neds casting.
         let mut output_types = Vec::with_capacity(left_types.len());
@@ -111,28 +149,15 @@ impl<'a> SetOpBinder<'a> {
         let mut right_needs_cast = false;
 
         for (left, right) in left_types.into_iter().zip(right_types) {
-            if left == right {
-                // Nothing to do.
-                output_types.push(left);
-                continue;
-            }
-
-            let left_score = implicit_cast_score(&right, left.datatype_id());
-            let right_score = implicit_cast_score(&left, right.datatype_id());
-
-            if left_score.is_none() && right_score.is_none() {
-                return Err(RayexecError::new(format!(
+            let output = implicit_common_type(&left, &right).ok_or_else(|| {
+                RayexecError::new(format!(
                     "Cannot find suitable cast type for {left} and {right}"
-                )));
-            }
-
-            if left_score >= right_score {
-                output_types.push(left);
-                right_needs_cast = true;
-            } else {
-                output_types.push(right);
-                left_needs_cast = true;
-            }
+                ))
+            })?;
+
+            left_needs_cast |= output != left;
+            right_needs_cast |= output != right;
+            output_types.push(output);
         }
 
         let cast_req = match (left_needs_cast, right_needs_cast) {
@@ -155,6 +180,9 @@ impl<'a> SetOpBinder<'a> {
 
         // Move output into scope.
         let table_ref = bind_context.push_table(self.current, None, output_types, left_names)?;
+        bind_context
+            .get_table_mut(table_ref)?
+            .set_column_nullability(nullable)?;
 
         // ORDER BY and LIMIT on output of the setop.
         let modifier_binder =
@@ -167,7 +195,7 @@ impl<'a> SetOpBinder<'a> {
                 modifier_binder.bind_order_by(bind_context, &mut empty_select_list, order_by)
             })
             .transpose()?;
-        let limit = modifier_binder.bind_limit(bind_context, limit)?;
+        let limit = modifier_binder.bind_limit(bind_context, limit, order_by.as_ref())?;
 
         if !empty_select_list.appended.is_empty() {
             // Only support ordering by columns, no expressions beyond that yet.
@@ -191,6 +219,7 @@ impl<'a> SetOpBinder<'a> {
             order_by,
             limit,
             cast_req,
+            recursive: false,
         })
     }
 }
@@ -90,10 +90,12 @@ impl<'a> ValuesBinder<'a> {
         for row in &mut rows {
             for (expr, datatype) in row.iter_mut().zip(&types) {
                 if &expr.datatype(bind_context.get_table_list())? != datatype {
-                    *expr = Expression::Cast(CastExpr {
-                        to: datatype.clone(),
-                        expr: Box::new(expr.clone()), // TODO: Could try to take instead of clone.
-                    })
+                    // TODO: Could try to take instead of clone.
+                    *expr = Expression::Cast(CastExpr::new_using_session(
+                        bind_context,
+                        expr.clone(),
+                        datatype.clone(),
+                    ))
                 }
             }
         }
@@ -104,7 +106,17 @@ impl<'a> ValuesBinder<'a> {
 
         // TODO: What should happen with limit/order by?
 
+        let mut nullable = vec![false; types.len()];
+        for row in &rows {
+            for (expr, nullable) in row.iter().zip(&mut nullable) {
+                *nullable |= expr.nullable(bind_context.get_table_list())?;
+            }
+        }
+
         let table_ref = bind_context.push_table(self.current, None, types, names)?;
+        bind_context
+            .get_table_mut(table_ref)?
+            .set_column_nullability(nullable)?;
 
         Ok(BoundValues {
             rows,
@@ -10,14 +10,16 @@ pub mod bind_values;
 pub mod select_expr_expander;
 pub mod select_list;
 
+use bind_from::BoundFromItem;
 use bind_select::{BoundSelect, SelectBinder};
-use bind_setop::{BoundSetOp, SetOpBinder};
+use bind_setop::{BoundSetOp, SetOpBinder, SetOpCastRequirement};
 use bind_values::{BoundValues, ValuesBinder};
-use rayexec_error::{not_implemented, RayexecError, Result};
+use rayexec_error::{not_implemented, OptionExt, RayexecError, Result};
 use rayexec_parser::ast;
 
 use super::bind_context::{BindContext, BindScopeRef};
 use super::table_list::TableRef;
+use crate::arrays::datatype::DataType;
 use crate::logical::binder::bind_context::BoundCte;
 use crate::logical::resolver::resolve_context::ResolveContext;
 use crate::logical::resolver::ResolvedMeta;
@@ -102,12 +104,12 @@ impl<'a> QueryBinder<'a> {
         bind_context: &mut BindContext,
         ctes: ast::CommonTableExprs<ResolvedMeta>,
     ) -> Result<()> {
-        if ctes.recursive {
-            not_implemented!("recursive CTEs");
-        }
-
         for cte in ctes.ctes {
-            self.bind_cte(bind_context, cte)?
+            if ctes.recursive {
+                self.bind_recursive_cte(bind_context, cte)?
+            } else {
+                self.bind_cte(bind_context, cte)?
+            }
         }
 
         Ok(())
@@ -122,29 +124,7 @@ impl<'a> QueryBinder<'a> {
         let binder = QueryBinder::new(nested, self.resolve_context);
         let bound = binder.bind(bind_context, *cte.body)?;
 
-        let mut names = Vec::new();
-        let mut types = Vec::new();
-        for table in bind_context.iter_tables_in_scope(nested)? {
-            types.extend(table.column_types.iter().cloned());
-            names.extend(table.column_names.iter().cloned());
-        }
-
-        // Sets alias where cte is defined
-        //
-        // WITH my_cte(alias1, alias2) AS ...
-        if let Some(col_aliases) = &cte.column_aliases {
-            if col_aliases.len() > names.len() {
-                return Err(RayexecError::new(format!(
-                    "Expected at most {} column aliases, received {}",
-                    names.len(),
-                    col_aliases.len()
-                )));
-            }
-
-            for (idx, col_alias) in col_aliases.iter().enumerate() {
-                names[idx] = col_alias.as_normalized_string();
-            }
-        }
+        let (names, types) = cte_columns(bind_context, nested, &cte.column_aliases)?;
 
         let cte = BoundCte {
             bind_scope: nested,
@@ -154,6 +134,9 @@ impl<'a> QueryBinder<'a> {
             column_types: types,
             bound: Box::new(bound),
             mat_ref: None,
+            num_references: 0,
+            in_recursive_term: false,
+            self_referenced: false,
         };
 
         // Note that we bind the CTE in a nested scope, but add it to the
@@ -166,4 +149,336 @@ impl<'a> QueryBinder<'a> {
 
         Ok(())
     }
+
+    /// Binds a CTE in a `WITH RECURSIVE` clause.
+    ///
+    /// A recursive CTE has the form `<anchor> UNION [ALL] <recursive term>`.
+    /// The CTE is added to the bind context after binding the anchor so that
+    /// references to it in the recursive term bind to the working table.
+    ///
+    /// CTEs not in that form, or that never reference themselves, are bound
+    /// like normal CTEs.
+    fn bind_recursive_cte(
+        &self,
+        bind_context: &mut BindContext,
+        cte: ast::CommonTableExpr<ResolvedMeta>,
+    ) -> Result<()> {
+        let query = *cte.body;
+        let setop = match query.body {
+            ast::QueryNodeBody::Set(setop) if setop.operation == ast::SetOperation::Union => setop,
+            body => {
+                return self.bind_cte(
+                    bind_context,
+                    ast::CommonTableExpr {
+                        body: Box::new(ast::QueryNode { body, ..query }),
+                        ..cte
+                    },
+                )
+            }
+        };
+
+        if query.order_by.is_some() || query.limit.limit.is_some() || query.limit.offset.is_some() {
+            not_implemented!("ORDER BY or LIMIT in a recursive CTE");
+        }
+
+        let nested = bind_context.new_child_scope(self.current);
+        let binder = QueryBinder::new(nested, self.resolve_context);
+        if let Some(ctes) = query.ctes {
+            binder.bind_ctes(bind_context, ctes)?;
+        }
+
+        let name = cte.alias.into_normalized_string();
+        let mut cte_ref = None;
+
+        let mut bound = SetOpBinder::new(nested, self.resolve_context).bind_with_left_callback(
+            bind_context,
+            setop,
+            None,
+            query.limit,
+            |bind_context, left_scope, left| {
+                let (names, types) = cte_columns(bind_context, left_scope, &cte.column_aliases)?;
+
+                cte_ref = Some(bind_context.add_cte(
+                    self.current,
+                    BoundCte {
+                        bind_scope: nested,
+                        materialized: cte.materialized,
+                        name: name.clone(),
+                        column_names: names,
+                        column_types: types,
+                        // Replaced with the full body once the recursive term
+                        // is bound.
+                        bound: Box::new(left.clone()),
+                        mat_ref: None,
+                        num_references: 0,
+                        in_recursive_term: true,
+                        self_referenced: false,
+                    },
+                )?);
+
+                Ok(())
+            },
+        )?;
+
+        let cte = bind_context.get_cte_mut(cte_ref.required("cte ref")?)?;
+        cte.in_recursive_term = false;
+
+        if cte.self_referenced {
+            // The working table has the types of the anchor, so the output of
+            // the CTE needs to match.
+            if matches!(
+                bound.cast_req,
+                SetOpCastRequirement::LeftNeedsCast(_) | SetOpCastRequirement::BothNeedsCast { .. }
+            ) {
+                return Err(RayexecError::new(format!(
+                    "Recursive term of CTE '{name}' produces types that don't match the anchor"
+                )));
+            }
+            check_recursive_term(&bound.right)?;
+            bound.recursive = true;
+        }
+
+        *cte.bound = BoundQuery::Setop(bound);
+
+        Ok(())
+    }
+}
+
+/// Checks that the recursive term of a recursive CTE can be executed.
+///
+/// The recursive term is executed by the recursive CTE operator itself, which
+/// only supports projecting and filtering rows from the working table. Anything
+/// needing additional pipelines (joins, aggregates, subqueries, etc) isn't
+/// supported yet.
+fn check_recursive_term(query: &BoundQuery) -> Result<()> {
+    let select = match query {
+        BoundQuery::Select(select) => select,
+        BoundQuery::Setop(_) => {
+            not_implemented!("Set operations in the recursive term of a recursive CTE")
+        }
+        BoundQuery::Values(_) => {
+            not_implemented!("VALUES in the recursive term of a recursive CTE")
+        }
+    };
+
+    match &select.from.item {
+        BoundFromItem::WorkingTable(_) => (),
+        BoundFromItem::Join(_) => {
+            not_implemented!("Joins in the recursive term of a recursive CTE")
+        }
+        _ => not_implemented!(
+            "Recursive term of a recursive CTE reading from anything other than the CTE itself"
+        ),
+    }
+
+    if select.group_by.is_some()
+        || !select.select_list.aggregates.is_empty()
+        || select.having.is_some()
+    {
+        not_implemented!("Aggregates in the recursive term of a recursive CTE");
+    }
+    if !select.select_list.windows.is_empty() {
+        not_implemented!("Window functions in the recursive term of a recursive CTE");
+    }
+    if select.distinct {
+        not_implemented!("DISTINCT in the recursive term of a recursive CTE");
+    }
+    if select.order_by.is_some() || select.limit.is_some() {
+        not_implemented!("ORDER BY or LIMIT in the recursive term of a recursive CTE");
+    }
+
+    let exprs = select
+        .select_list
+        .projections
+        .iter()
+        .chain(select.filter.iter());
+    for expr in exprs {
+        if expr.contains_subquery() {
+            not_implemented!("Subqueries in the recursive term of a recursive CTE");
+        }
+        if expr.contains_unnest() {
+            not_implemented!("UNNEST in the recursive term of a recursive CTE");
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the column names and types for a CTE from the tables in `scope`,
+/// applying column aliases if provided.
+fn cte_columns(
+    bind_context: &BindContext,
+    scope: BindScopeRef,
+    column_aliases: &Option<Vec<ast::Ident>>,
+) -> Result<(Vec<String>, Vec<DataType>)> {
+    let mut names = Vec::new();
+    let mut types = Vec::new();
+    for table in bind_context.iter_tables_in_scope(scope)? {
+        types.extend(table.column_types.iter().cloned());
+        names.extend(table.column_names.iter().cloned());
+    }
+
+    // Sets alias where cte is defined
+    //
+    // WITH my_cte(alias1, alias2) AS ...
+    if let Some(col_aliases) = column_aliases {
+        if col_aliases.len() > names.len() {
+            return Err(RayexecError::new(format!(
+                "Expected at most {} column aliases, received {}",
+                names.len(),
+                col_aliases.len()
+            )));
+        }
+
+        for (idx, col_alias) in col_aliases.iter().enumerate() {
+            names[idx] = col_alias.as_normalized_string();
+        }
+    }
+
+    Ok((names, types))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use rayexec_parser::statement::Statement;
+
+    use super::*;
+    use crate::database::catalog::CatalogTx;
+    use crate::database::system::new_system_catalog;
+    use crate::database::DatabaseContext;
+    use crate::datasource::{DataSourceRegistry, FileHandlers};
+    use crate::functions::scalar::builtin::arith::OverflowBehavior;
+    use crate::logical::resolver::search_path::SearchPath;
+    use crate::logical::resolver::{ResolveConfig, ResolveMode, Resolver};
+
+    /// Resolve and bind a query, returning the nullability of each output
+    /// column.
+    fn output_nullability(sql: &str) -> Vec<bool> {
+        output_nullability_with_context(sql, BindContext::new())
+    }
+
+    fn output_nullability_with_context(sql: &str, mut bind_context: BindContext) -> Vec<bool> {
+        let catalog = new_system_catalog(&DataSourceRegistry::default()).unwrap();
+        let context = DatabaseContext::new(Arc::new(catalog)).unwrap();
+        let tx = CatalogTx::new();
+        let file_handlers = FileHandlers::empty();
+
+        let stmt = rayexec_parser::parser::parse(sql).unwrap().pop().unwrap();
+        let resolver = Resolver::new(
+            ResolveMode::Normal,
+            &tx,
+            &context,
+            &file_handlers,
+            ResolveConfig {
+                enable_function_chaining: true,
+                search_path: SearchPath::default(),
+            },
+        );
+        let (resolved, resolve_context) =
+            futures::executor::block_on(resolver.resolve_statement(stmt)).unwrap();
+        let query = match resolved {
+            Statement::Query(query) => query,
+            other => panic!("unexpected statement: {other:?}"),
+        };
+
+        let bound = QueryBinder::new(bind_context.root_scope_ref(), &resolve_context)
+            .bind(&mut bind_context, query)
+            .unwrap();
+
+        bind_context
+            .get_table(bound.output_table_ref())
+            .unwrap()
+            .column_nullable
+            .clone()
+    }
+
+    #[test]
+    fn nullability_literals() {
+        let nullable = output_nullability("SELECT 1, NULL, 1 + 2, 'a' IS NULL");
+        assert_eq!(vec![false, true, false, false], nullable);
+    }
+
+    #[test]
+    fn nullability_arith_null_overflow() {
+        let sql = "SELECT a + b, -a, add(a, b) FROM (SELECT 1 AS a, 2 AS b)";
+
+        let nullable = output_nullability(sql);
+        assert_eq!(vec![false, false, false], nullable);
+
+        let mut bind_context = BindContext::new();
+        bind_context.set_arithmetic_overflow(OverflowBehavior::Null);
+        let nullable = output_nullability_with_context(sql, bind_context);
+        assert_eq!(vec![true, true, true], nullable);
+    }
+
+    #[test]
+    fn nullability_cast_null_overflow() {
+        let sql = "SELECT a::TINYINT FROM (SELECT 1000 AS a)";
+
+        let nullable = output_nullability(sql);
+        assert_eq!(vec![false], nullable);
+
+        let mut bind_context = BindContext::new();
+        bind_context.set_arithmetic_overflow(OverflowBehavior::Null);
+        let nullable = output_nullability_with_context(sql, bind_context);
+        assert_eq!(vec![true], nullable);
+    }
+
+    #[test]
+    fn nullability_left_join_right_side() {
+        let nullable = output_nullability(
+            "SELECT a.x, b.y
+               FROM (SELECT 1 AS x) a
+               LEFT JOIN (SELECT 2 AS y) b ON a.x = b.y",
+        );
+        assert_eq!(vec![false, true], nullable);
+    }
+
+    #[test]
+    fn nullability_inner_join_preserved() {
+        let nullable = output_nullability(
+            "SELECT a.x, b.y
+               FROM (SELECT 1 AS x) a
+               INNER JOIN (SELECT 2 AS y) b ON a.x = b.y",
+        );
+        assert_eq!(vec![false, false], nullable);
+    }
+
+    #[test]
+    fn nullability_count_star() {
+        let nullable = output_nullability(
+            "SELECT count(*), sum(column1) FROM (VALUES (1), (NULL)) GROUP BY column1",
+        );
+        assert_eq!(vec![false, true], nullable);
+    }
+
+    #[test]
+    fn nullability_rollup_group_column() {
+        let nullable =
+            output_nullability("SELECT x, count(*) FROM (SELECT 1 AS x) GROUP BY ROLLUP (x)");
+        assert_eq!(vec![true, false], nullable);
+
+        let nullable = output_nullability("SELECT x, count(*) FROM (SELECT 1 AS x) GROUP BY x");
+        assert_eq!(vec![false, false], nullable);
+    }
+
+    #[test]
+    fn nullability_values_and_union() {
+        let nullable = output_nullability("VALUES (1, 2), (3, NULL)");
+        assert_eq!(vec![false, true], nullable);
+
+        let nullable = output_nullability("SELECT 1, 2 UNION ALL SELECT NULL, 3");
+        assert_eq!(vec![true, false], nullable);
+    }
+
+    #[test]
+    fn nullability_case_without_else() {
+        let nullable = output_nullability("SELECT CASE WHEN true THEN 1 END");
+        assert_eq!(vec![true], nullable);
+
+        let nullable = output_nullability("SELECT CASE WHEN true THEN 1 ELSE 2 END");
+        assert_eq!(vec![false], nullable);
+    }
 }
@@ -107,9 +107,11 @@ impl SelectList {
             self.aggregates_table,
             self.grouping_functions_table,
             &self.aggregates,
-            group_by,
+            group_by.as_deref_mut(),
         )?;
 
+        self.update_nullability(bind_context, group_by.as_deref())?;
+
         // If we had appended column, ensure we have a pruned table that only
         // contains the original projections.
         let pruned_table = if !self.appended.is_empty() {
@@ -119,6 +121,12 @@ impl SelectList {
             self.projections.append(&mut self.appended);
 
             let projections_table = bind_context.get_table(self.projections_table)?;
+            let nullable = projections_table
+                .column_nullable
+                .iter()
+                .take(len)
+                .copied()
+                .collect();
             let output_table_ref = bind_context.new_ephemeral_table_with_columns(
                 projections_table
                     .column_types
@@ -133,6 +141,9 @@ impl SelectList {
                     .cloned()
                     .collect(),
             )?;
+            bind_context
+                .get_table_mut(output_table_ref)?
+                .set_column_nullability(nullable)?;
 
             // Project out only expressions in the original select list.
             let expressions = (0..len)
@@ -166,6 +177,58 @@ impl SelectList {
         })
     }
 
+    /// Updates column nullability for the projections table and all tables
+    /// that the projections may reference.
+    ///
+    /// Group columns that are not part of every grouping set will produce
+    /// NULLs for the sets that exclude them.
+    fn update_nullability(
+        &self,
+        bind_context: &mut BindContext,
+        group_by: Option<&BoundGroupBy>,
+    ) -> Result<()> {
+        if let Some(group_by) = group_by {
+            let nullable = group_by
+                .expressions
+                .iter()
+                .enumerate()
+                .map(|(idx, expr)| {
+                    let in_all_sets = group_by.grouping_sets.iter().all(|set| set.contains(&idx));
+                    Ok(!in_all_sets || expr.nullable(bind_context.get_table_list())?)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            bind_context
+                .get_table_mut(group_by.group_exprs_table)?
+                .set_column_nullability(nullable)?;
+        }
+
+        for (table, exprs) in [
+            (self.aggregates_table, &self.aggregates),
+            (self.windows_table, &self.windows),
+        ] {
+            let nullable = exprs
+                .iter()
+                .map(|expr| expr.nullable(bind_context.get_table_list()))
+                .collect::<Result<Vec<_>>>()?;
+            bind_context
+                .get_table_mut(table)?
+                .set_column_nullability(nullable)?;
+        }
+
+        // Projections table includes appended expressions.
+        let nullable = self
+            .projections
+            .iter()
+            .chain(&self.appended)
+            .map(|expr| expr.nullable(bind_context.get_table_list()))
+            .collect::<Result<Vec<_>>>()?;
+        bind_context
+            .get_table_mut(self.projections_table)?
+            .set_column_nullability(nullable)?;
+
+        Ok(())
+    }
+
     /// Verify that all column expressions in the project list point to either
     /// an aggregate or group by expression.
     fn verify_column_references(
@@ -264,6 +327,16 @@ impl SelectList {
         None
     }
 
+    /// Try to get a column for an expression that's identical to one already
+    /// in the projections.
+    pub fn column_by_expression(&self, expr: &Expression) -> Option<ColumnExpr> {
+        let idx = self.projections.iter().position(|proj| proj == expr)?;
+        Some(ColumnExpr {
+            table_scope: self.projections_table,
+            column: idx,
+        })
+    }
+
     /// Get a column reference by ordinal.
     pub fn column_by_ordinal(
         &self,
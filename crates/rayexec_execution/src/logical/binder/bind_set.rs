@@ -47,7 +47,11 @@ impl<'a> SetVarBinder<'a> {
         let _ = self.config.get_as_scalar(&name)?;
 
         Ok(Node {
-            node: LogicalSetVar { name, value },
+            node: LogicalSetVar {
+                name,
+                value,
+                local: set.local,
+            },
             location: LocationRequirement::ClientLocal,
             children: Vec::new(),
             estimated_cardinality: StatisticsValue::Unknown,
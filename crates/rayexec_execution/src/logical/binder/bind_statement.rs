@@ -1,5 +1,7 @@
 use rayexec_error::Result;
+use rayexec_parser::ast;
 use rayexec_parser::statement::Statement;
+use tracing::debug;
 
 use super::bind_attach::{AttachBinder, BoundAttach, BoundDetach};
 use super::bind_context::BindContext;
@@ -19,9 +21,11 @@ use crate::logical::logical_create::{LogicalCreateSchema, LogicalCreateView};
 use crate::logical::logical_describe::LogicalDescribe;
 use crate::logical::logical_drop::LogicalDrop;
 use crate::logical::logical_set::{LogicalResetVar, LogicalSetVar, LogicalShowVar};
-use crate::logical::operator::Node;
+use crate::logical::logical_transaction::LogicalTransaction;
+use crate::logical::operator::{LocationRequirement, Node};
 use crate::logical::resolver::resolve_context::ResolveContext;
 use crate::logical::resolver::ResolvedMeta;
+use crate::logical::statistics::StatisticsValue;
 
 /// "Bound" variants for SQL statements that we support.
 ///
@@ -36,6 +40,7 @@ pub enum BoundStatement {
     Query(BoundQuery),
     SetVar(Node<LogicalSetVar>),
     ResetVar(Node<LogicalResetVar>),
+    Transaction(Node<LogicalTransaction>),
     ShowVar(Node<LogicalShowVar>),
     Attach(BoundAttach),
     Detach(BoundDetach),
@@ -61,6 +66,15 @@ impl StatementBinder<'_> {
         statement: Statement<ResolvedMeta>,
     ) -> Result<(BoundStatement, BindContext)> {
         let mut context = BindContext::new();
+        if let Some(seed) = self.session_config.query_seed {
+            context.set_query_seed(seed);
+        }
+        context.set_sort_group_by_output(self.session_config.sort_group_by_output);
+        context.set_integer_division(self.session_config.integer_division);
+        context.set_rounding_mode(self.session_config.rounding_mode);
+        context.set_arithmetic_overflow(self.session_config.arithmetic_overflow);
+        debug!(query_seed = context.query_seed(), "binding statement");
+
         let root_scope = context.root_scope_ref();
 
         let statement = match statement {
@@ -80,6 +94,16 @@ impl StatementBinder<'_> {
             Statement::ResetVariable(set) => BoundStatement::ResetVar(
                 SetVarBinder::new(root_scope, self.session_config).bind_reset(&mut context, set)?,
             ),
+            Statement::Transaction(transaction) => BoundStatement::Transaction(Node {
+                node: match transaction {
+                    ast::TransactionStatement::Begin => LogicalTransaction::Begin,
+                    ast::TransactionStatement::Commit => LogicalTransaction::Commit,
+                    ast::TransactionStatement::Rollback => LogicalTransaction::Rollback,
+                },
+                location: LocationRequirement::ClientLocal,
+                children: Vec::new(),
+                estimated_cardinality: StatisticsValue::Unknown,
+            }),
             Statement::Attach(attach) => BoundStatement::Attach(
                 AttachBinder::new(root_scope).bind_attach(&mut context, attach)?,
             ),
@@ -58,8 +58,12 @@ impl<'a> ConstantBinder<'a> {
         for arg in args {
             match arg {
                 ast::FunctionArg::Named { name, arg } => {
+                    let name = name.as_normalized_string();
+                    if named.contains_key(&name) {
+                        return Err(RayexecError::new(format!("Duplicate argument: {name}")));
+                    }
                     let expr = self.bind_constant_function_arg_expr(arg)?;
-                    named.insert(name.as_normalized_string(), expr);
+                    named.insert(name, expr);
                 }
                 ast::FunctionArg::Unnamed { arg } => {
                     let expr = self.bind_constant_function_arg_expr(arg)?;
@@ -7,7 +7,7 @@ use super::column_binder::ExpressionColumnBinder;
 use crate::arrays::datatype::DataType;
 use crate::arrays::scalar::interval::Interval;
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
-use crate::expr::aggregate_expr::AggregateExpr;
+use crate::expr::aggregate_expr::{AggregateExpr, AggregateOrdering};
 use crate::expr::arith_expr::{ArithExpr, ArithOperator};
 use crate::expr::case_expr::{CaseExpr, WhenThen};
 use crate::expr::cast_expr::CastExpr;
@@ -22,13 +22,18 @@ use crate::expr::unnest_expr::UnnestExpr;
 use crate::expr::window_expr::{WindowExpr, WindowFrameBound, WindowFrameExclusion};
 use crate::expr::{AsScalarFunction, Expression};
 use crate::functions::aggregate::AggregateFunction;
+use crate::functions::implicit::implicit_common_type;
+use crate::functions::scalar::builtin::arith::{FloorDiv, OverflowBehavior};
 use crate::functions::scalar::builtin::datetime::DatePart;
 use crate::functions::scalar::builtin::is;
-use crate::functions::scalar::builtin::list::{ListExtract, ListValues};
-use crate::functions::scalar::builtin::string::{Concat, Like, StartsWith, Substring};
-use crate::functions::scalar::ScalarFunction;
+use crate::functions::scalar::builtin::list::{ListExtract, ListSlice, ListValues};
+use crate::functions::scalar::builtin::map::{MapExtract, MapValues};
+use crate::functions::scalar::builtin::numeric::Power;
+use crate::functions::scalar::builtin::string::{ConcatOperator, Like, StartsWith, Substring};
+use crate::functions::scalar::builtin::struct_funcs::{StructExtract, StructPack};
+use crate::functions::scalar::{FunctionVolatility, ScalarFunction, ScalarPlanOptions};
 use crate::functions::table::TableFunction;
-use crate::functions::CastType;
+use crate::functions::{plan_named_args, CastType};
 use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
 use crate::logical::binder::bind_query::QueryBinder;
 use crate::logical::resolver::resolve_context::ResolveContext;
@@ -101,10 +106,23 @@ impl<'a> BaseExpressionBinder<'a> {
                 }
             }
             ast::Expr::CompoundIdent(idents) => {
-                // Use the provided column binder, no fallback.
-                match column_binder.bind_from_idents(self.current, bind_context, idents, recur)? {
-                    Some(expr) => Ok(expr),
-                    None => {
+                // Use the provided column binder, falling back to struct field
+                // access if the idents don't reference a column.
+                let result =
+                    column_binder.bind_from_idents(self.current, bind_context, idents, recur);
+                if let Ok(Some(expr)) = result {
+                    return Ok(expr);
+                }
+
+                if let Some(expr) =
+                    self.bind_struct_field_access(bind_context, idents, column_binder, recur)?
+                {
+                    return Ok(expr);
+                }
+
+                match result {
+                    Err(e) => Err(e),
+                    _ => {
                         let ident_string = idents
                             .iter()
                             .map(|i| i.as_normalized_string())
@@ -119,6 +137,46 @@ impl<'a> BaseExpressionBinder<'a> {
             ast::Expr::QualifiedWildcard(_) => Err(RayexecError::new(
                 "Qualified wildcard not a valid expression to bind",
             )),
+            ast::Expr::Literal(ast::Literal::Struct { keys, values }) => {
+                let mut inputs = Vec::with_capacity(keys.len() * 2);
+                for (key, value) in keys.iter().zip(values) {
+                    let value =
+                        self.bind_expression(bind_context, value, column_binder, recur.not_root())?;
+                    inputs.push(Expression::Literal(LiteralExpr {
+                        literal: ScalarValue::Utf8(key.clone().into()),
+                    }));
+                    inputs.push(value);
+                }
+
+                let planned = StructPack.plan(bind_context.get_table_list(), inputs)?;
+
+                Ok(Expression::ScalarFunction(ScalarFunctionExpr {
+                    function: planned,
+                }))
+            }
+            ast::Expr::Literal(ast::Literal::Map { keys, values }) => {
+                let mut inputs = Vec::with_capacity(keys.len() * 2);
+                for (key, value) in keys.iter().zip(values) {
+                    inputs.push(self.bind_expression(
+                        bind_context,
+                        key,
+                        column_binder,
+                        recur.not_root(),
+                    )?);
+                    inputs.push(self.bind_expression(
+                        bind_context,
+                        value,
+                        column_binder,
+                        recur.not_root(),
+                    )?);
+                }
+
+                let planned = MapValues.plan(bind_context.get_table_list(), inputs)?;
+
+                Ok(Expression::ScalarFunction(ScalarFunctionExpr {
+                    function: planned,
+                }))
+            }
             ast::Expr::Literal(literal) => {
                 // Use the provided column binder only if this is the root of
                 // the expression.
@@ -181,6 +239,28 @@ impl<'a> BaseExpressionBinder<'a> {
                             },
                         )?;
 
+                        // Subscripting a struct extracts a field by name,
+                        // subscripting a map looks up a key.
+                        match expr.datatype(bind_context.get_table_list())? {
+                            DataType::Struct(_) => {
+                                let planned = StructExtract
+                                    .plan(bind_context.get_table_list(), vec![expr, index])?;
+
+                                return Ok(Expression::ScalarFunction(ScalarFunctionExpr {
+                                    function: planned,
+                                }));
+                            }
+                            DataType::Map(_) => {
+                                let planned = MapExtract
+                                    .plan(bind_context.get_table_list(), vec![expr, index])?;
+
+                                return Ok(Expression::ScalarFunction(ScalarFunctionExpr {
+                                    function: planned,
+                                }));
+                            }
+                            _ => (),
+                        }
+
                         let scalar = Box::new(ListExtract);
                         let mut exprs = self.apply_casts_for_scalar_function(
                             bind_context,
@@ -197,8 +277,49 @@ impl<'a> BaseExpressionBinder<'a> {
                             function: planned,
                         }))
                     }
-                    ast::ArraySubscript::Slice { .. } => {
-                        Err(RayexecError::new("Array slicing not yet implemented"))
+                    ast::ArraySubscript::Slice {
+                        lower,
+                        upper,
+                        stride,
+                    } => {
+                        if stride.is_some() {
+                            return Err(RayexecError::new(
+                                "Array slicing with a stride not yet supported",
+                            ));
+                        }
+
+                        // Bounds are clamped to the list, so omitted bounds
+                        // can just use the widest values possible.
+                        let mut bind_bound =
+                            |bound: &Option<ast::Expr<ResolvedMeta>>, default: i64| match bound {
+                                Some(bound) => self.bind_expression(
+                                    bind_context,
+                                    bound,
+                                    column_binder,
+                                    RecursionContext {
+                                        allow_windows: false,
+                                        allow_aggregates: false,
+                                        is_root: false,
+                                    },
+                                ),
+                                None => Ok(Expression::Literal(LiteralExpr {
+                                    literal: ScalarValue::Int64(default),
+                                })),
+                            };
+                        let lower = bind_bound(lower, 1)?;
+                        let upper = bind_bound(upper, i64::MAX)?;
+
+                        let scalar = Box::new(ListSlice);
+                        let exprs = self.apply_casts_for_scalar_function(
+                            bind_context,
+                            scalar.as_ref(),
+                            vec![expr, lower, upper],
+                        )?;
+                        let planned = scalar.plan(bind_context.get_table_list(), exprs)?;
+
+                        Ok(Expression::ScalarFunction(ScalarFunctionExpr {
+                            function: planned,
+                        }))
                     }
                 }
             }
@@ -224,6 +345,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         Expression::Negate(NegateExpr {
                             op: NegateOperator::Not,
                             expr: Box::new(expr),
+                            overflow: OverflowBehavior::default(),
                         })
                     }
                     ast::UnaryOperator::Minus => {
@@ -236,6 +358,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         Expression::Negate(NegateExpr {
                             op: NegateOperator::Negate,
                             expr: Box::new(expr),
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
                 })
@@ -262,113 +385,143 @@ impl<'a> BaseExpressionBinder<'a> {
 
                 Ok(match op {
                     ast::BinaryOperator::NotEq => {
-                        let op = ComparisonOperator::NotEq;
-                        let [left, right] =
-                            self.apply_cast_for_operator(bind_context, op, [left, right])?;
-                        Expression::Comparison(ComparisonExpr {
-                            left: Box::new(left),
-                            right: Box::new(right),
-                            op,
-                        })
+                        self.bind_comparison(bind_context, ComparisonOperator::NotEq, left, right)?
                     }
                     ast::BinaryOperator::Eq => {
-                        let op = ComparisonOperator::Eq;
+                        self.bind_comparison(bind_context, ComparisonOperator::Eq, left, right)?
+                    }
+                    ast::BinaryOperator::Lt => {
+                        self.bind_comparison(bind_context, ComparisonOperator::Lt, left, right)?
+                    }
+                    ast::BinaryOperator::LtEq => {
+                        self.bind_comparison(bind_context, ComparisonOperator::LtEq, left, right)?
+                    }
+                    ast::BinaryOperator::Gt => {
+                        self.bind_comparison(bind_context, ComparisonOperator::Gt, left, right)?
+                    }
+                    ast::BinaryOperator::GtEq => {
+                        self.bind_comparison(bind_context, ComparisonOperator::GtEq, left, right)?
+                    }
+                    ast::BinaryOperator::Plus => {
+                        let op = ArithOperator::Add;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
-                        Expression::Comparison(ComparisonExpr {
+                        Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::Lt => {
-                        let op = ComparisonOperator::Lt;
+                    ast::BinaryOperator::Minus => {
+                        let op = ArithOperator::Sub;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
-                        Expression::Comparison(ComparisonExpr {
+                        Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::LtEq => {
-                        let op = ComparisonOperator::LtEq;
+                    ast::BinaryOperator::Multiply => {
+                        let op = ArithOperator::Mul;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
-                        Expression::Comparison(ComparisonExpr {
+                        Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::Gt => {
-                        let op = ComparisonOperator::Gt;
-                        let [left, right] =
+                    ast::BinaryOperator::Divide => {
+                        let op = ArithOperator::Div;
+                        let [mut left, mut right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
-                        Expression::Comparison(ComparisonExpr {
+
+                        // Promote to float if we're not doing integer
+                        // division.
+                        let table_list = bind_context.get_table_list();
+                        if !bind_context.integer_division()
+                            && left.datatype(table_list)?.is_integer()
+                            && right.datatype(table_list)?.is_integer()
+                        {
+                            left = Expression::Cast(CastExpr::new(left, DataType::Float64));
+                            right = Expression::Cast(CastExpr::new(right, DataType::Float64));
+                        }
+
+                        Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::GtEq => {
-                        let op = ComparisonOperator::GtEq;
+                    ast::BinaryOperator::Modulo => {
+                        let op = ArithOperator::Mod;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
-                        Expression::Comparison(ComparisonExpr {
+                        Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::Plus => {
-                        let op = ArithOperator::Add;
+                    ast::BinaryOperator::BitwiseAnd => {
+                        let op = ArithOperator::BitAnd;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::Minus => {
-                        let op = ArithOperator::Sub;
+                    ast::BinaryOperator::BitwiseOr => {
+                        let op = ArithOperator::BitOr;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::Multiply => {
-                        let op = ArithOperator::Mul;
+                    ast::BinaryOperator::BitwiseXor => {
+                        let op = ArithOperator::BitXor;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::Divide => {
-                        let op = ArithOperator::Div;
+                    ast::BinaryOperator::BitwiseShiftLeft => {
+                        let op = ArithOperator::ShiftLeft;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
-                    ast::BinaryOperator::Modulo => {
-                        let op = ArithOperator::Mod;
+                    ast::BinaryOperator::BitwiseShiftRight => {
+                        let op = ArithOperator::ShiftRight;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
                             op,
+                            overflow: bind_context.arithmetic_overflow(),
                         })
                     }
                     ast::BinaryOperator::And => {
@@ -390,10 +543,27 @@ impl<'a> BaseExpressionBinder<'a> {
                         })
                     }
                     ast::BinaryOperator::StringConcat => {
+                        let [left, right] = self.apply_cast_for_operator(
+                            bind_context,
+                            ConcatOperator,
+                            [left, right],
+                        )?;
+                        let planned = ConcatOperator
+                            .plan(bind_context.get_table_list(), vec![left, right])?;
+                        Expression::ScalarFunction(ScalarFunctionExpr { function: planned })
+                    }
+                    ast::BinaryOperator::IntDiv => {
+                        let [left, right] =
+                            self.apply_cast_for_operator(bind_context, FloorDiv, [left, right])?;
+                        let planned =
+                            FloorDiv.plan(bind_context.get_table_list(), vec![left, right])?;
+                        Expression::ScalarFunction(ScalarFunctionExpr { function: planned })
+                    }
+                    ast::BinaryOperator::Exponent => {
                         let [left, right] =
-                            self.apply_cast_for_operator(bind_context, Concat, [left, right])?;
+                            self.apply_cast_for_operator(bind_context, Power, [left, right])?;
                         let planned =
-                            Concat.plan(bind_context.get_table_list(), vec![left, right])?;
+                            Power.plan(bind_context.get_table_list(), vec![left, right])?;
                         Expression::ScalarFunction(ScalarFunctionExpr { function: planned })
                     }
                     ast::BinaryOperator::StringStartsWith => {
@@ -479,6 +649,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         Ok(Expression::Negate(NegateExpr {
                             op: NegateOperator::Not,
                             expr: Box::new(subquery),
+                            overflow: OverflowBehavior::default(),
                         }))
                     }
                     _ => unreachable!(),
@@ -512,6 +683,7 @@ impl<'a> BaseExpressionBinder<'a> {
                     expr = Expression::Negate(NegateExpr {
                         op: NegateOperator::Not,
                         expr: Box::new(expr),
+                        overflow: OverflowBehavior::default(),
                     });
                 }
 
@@ -552,18 +724,7 @@ impl<'a> BaseExpressionBinder<'a> {
 
                 let cmp_exprs = list
                     .into_iter()
-                    .map(|expr| {
-                        let [needle, expr] = self.apply_cast_for_operator(
-                            bind_context,
-                            cmp_op,
-                            [needle.clone(), expr],
-                        )?;
-                        Ok(Expression::Comparison(ComparisonExpr {
-                            left: Box::new(needle),
-                            right: Box::new(expr),
-                            op: cmp_op,
-                        }))
-                    })
+                    .map(|expr| self.bind_comparison(bind_context, cmp_op, needle.clone(), expr))
                     .collect::<Result<Vec<_>>>()?;
 
                 // TODO: Error on no epxressions?
@@ -579,10 +740,10 @@ impl<'a> BaseExpressionBinder<'a> {
                 // update cast rules for arrays and scalars at the same time.
                 //
                 // let scalar = cast_scalar(scalar, &datatype)?;
-                Ok(Expression::Cast(CastExpr {
-                    to: datatype.clone(),
-                    expr: Box::new(Expression::Literal(LiteralExpr { literal: scalar })),
-                }))
+                Ok(Expression::Cast(CastExpr::new(
+                    Expression::Literal(LiteralExpr { literal: scalar }),
+                    datatype.clone(),
+                )))
             }
             ast::Expr::Cast { datatype, expr } => {
                 let expr = self.bind_expression(
@@ -594,10 +755,11 @@ impl<'a> BaseExpressionBinder<'a> {
                         ..recur
                     },
                 )?;
-                Ok(Expression::Cast(CastExpr {
-                    to: datatype.clone(),
-                    expr: Box::new(expr),
-                }))
+                Ok(Expression::Cast(CastExpr::new_using_session(
+                    bind_context,
+                    expr,
+                    datatype.clone(),
+                )))
             }
             ast::Expr::Like {
                 expr,
@@ -636,6 +798,7 @@ impl<'a> BaseExpressionBinder<'a> {
                     expr = Expression::Negate(NegateExpr {
                         op: NegateOperator::Not,
                         expr: Box::new(expr),
+                        overflow: OverflowBehavior::default(),
                     })
                 }
 
@@ -755,12 +918,10 @@ impl<'a> BaseExpressionBinder<'a> {
                             op,
                             left: Box::new(interval),
                             right: Box::new(expr),
+                            overflow: bind_context.arithmetic_overflow(),
                         }))
                     }
-                    None => Ok(Expression::Cast(CastExpr {
-                        to: DataType::Interval,
-                        expr: Box::new(expr),
-                    })),
+                    None => Ok(Expression::Cast(CastExpr::new(expr, DataType::Interval))),
                 }
             }
             ast::Expr::Between {
@@ -796,28 +957,14 @@ impl<'a> BaseExpressionBinder<'a> {
                 } else {
                     ComparisonOperator::Lt
                 };
-                let [low_left, low_right] =
-                    self.apply_cast_for_operator(bind_context, low_op, [expr.clone(), low])?;
-
-                let left = Expression::Comparison(ComparisonExpr {
-                    left: Box::new(low_left),
-                    right: Box::new(low_right),
-                    op: low_op,
-                });
+                let left = self.bind_comparison(bind_context, low_op, expr.clone(), low)?;
 
                 let high_op = if !negated {
                     ComparisonOperator::LtEq
                 } else {
                     ComparisonOperator::Gt
                 };
-                let [high_left, high_right] =
-                    self.apply_cast_for_operator(bind_context, high_op, [expr, high])?;
-
-                let right = Expression::Comparison(ComparisonExpr {
-                    left: Box::new(high_left),
-                    right: Box::new(high_right),
-                    op: high_op,
-                });
+                let right = self.bind_comparison(bind_context, high_op, expr, high)?;
 
                 let conj_op = if !negated {
                     ConjunctionOperator::And
@@ -855,7 +1002,7 @@ impl<'a> BaseExpressionBinder<'a> {
                     })
                     .transpose()?;
 
-                let mut else_expr = else_expr
+                let else_expr = else_expr
                     .as_ref()
                     .map(|expr| {
                         self.bind_expression(bind_context, expr, column_binder, recur.not_root())
@@ -874,50 +1021,50 @@ impl<'a> BaseExpressionBinder<'a> {
 
                 // When leading expr is provided, conditions are implicit equalities.
                 let build_condition = |cond_expr| match &expr {
-                    Some(expr) => {
-                        let [left, right] = self.apply_cast_for_operator(
-                            bind_context,
-                            ComparisonOperator::Eq,
-                            [expr.clone(), cond_expr],
-                        )?;
+                    Some(expr) => self.bind_comparison(
+                        bind_context,
+                        ComparisonOperator::Eq,
+                        expr.clone(),
+                        cond_expr,
+                    ),
+                    None => Ok(cond_expr),
+                };
 
-                        Ok::<_, RayexecError>(Expression::Comparison(ComparisonExpr {
-                            left: Box::new(left),
-                            right: Box::new(right),
-                            op: ComparisonOperator::Eq,
-                        }))
+                // Cast the results so they all produce the same type.
+                let table_list = bind_context.get_table_list();
+                let mut result_type = DataType::Null;
+                for expr in results.iter().chain(else_expr.as_ref()) {
+                    let datatype = expr.datatype(table_list)?;
+                    result_type =
+                        implicit_common_type(&result_type, &datatype).ok_or_else(|| {
+                            RayexecError::new(format!(
+                                "CASE result types {result_type} and {datatype} cannot be matched"
+                            ))
+                        })?;
+                }
+
+                let cast_result = |expr: Expression| -> Result<Expression> {
+                    if expr.datatype(table_list)? == result_type {
+                        Ok(expr)
+                    } else {
+                        Ok(Expression::Cast(CastExpr::new_using_session(
+                            bind_context,
+                            expr,
+                            result_type.clone(),
+                        )))
                     }
-                    None => Ok(cond_expr),
                 };
 
-                // TODO: Cast the results so they all produce the same type.
                 let mut cases = Vec::with_capacity(conditions.len());
-
                 for (condition, result) in conditions.into_iter().zip(results) {
                     let condition = build_condition(condition)?;
                     cases.push(WhenThen {
                         when: condition,
-                        then: result,
+                        then: cast_result(result)?,
                     });
                 }
 
-                // Apply cast to else if needed.
-                if let Some(expr) = else_expr {
-                    let first_case_dt = cases
-                        .first()
-                        .expect("at least one case")
-                        .then
-                        .datatype(bind_context.get_table_list())?;
-
-                    if expr.datatype(bind_context.get_table_list())? != first_case_dt {
-                        else_expr = Some(Expression::Cast(CastExpr {
-                            to: first_case_dt,
-                            expr: Box::new(expr),
-                        }));
-                    } else {
-                        else_expr = Some(expr);
-                    }
-                }
+                let else_expr = else_expr.map(cast_result).transpose()?;
 
                 Ok(Expression::Case(CaseExpr {
                     cases,
@@ -1025,10 +1172,11 @@ impl<'a> BaseExpressionBinder<'a> {
             SubqueryType::Any { expr, op } => {
                 if expr.datatype(bind_context.get_table_list())? != return_type {
                     SubqueryType::Any {
-                        expr: Box::new(Expression::Cast(CastExpr {
-                            to: query_return_type,
-                            expr,
-                        })),
+                        expr: Box::new(Expression::Cast(CastExpr::new_using_session(
+                            bind_context,
+                            *expr,
+                            query_return_type,
+                        ))),
                         op,
                     }
                 } else {
@@ -1056,6 +1204,54 @@ impl<'a> BaseExpressionBinder<'a> {
         }))
     }
 
+    /// Try to bind compound idents as a struct column followed by field
+    /// accesses, e.g. `t.s.a` where `s` is a struct column in table `t`.
+    ///
+    /// Prefers the longest prefix that binds to a struct column. Returns
+    /// `Ok(None)` if no prefix binds to a struct column.
+    fn bind_struct_field_access(
+        &self,
+        bind_context: &mut BindContext,
+        idents: &[ast::Ident],
+        column_binder: &mut impl ExpressionColumnBinder,
+        recur: RecursionContext,
+    ) -> Result<Option<Expression>> {
+        for split in (1..idents.len()).rev() {
+            let (column_idents, fields) = idents.split_at(split);
+
+            let column = if column_idents.len() == 1 {
+                column_binder.bind_from_ident(self.current, bind_context, &column_idents[0], recur)
+            } else {
+                column_binder.bind_from_idents(self.current, bind_context, column_idents, recur)
+            };
+
+            let mut expr = match column {
+                Ok(Some(expr)) => expr,
+                _ => continue,
+            };
+
+            if !matches!(
+                expr.datatype(bind_context.get_table_list())?,
+                DataType::Struct(_)
+            ) {
+                continue;
+            }
+
+            for field in fields {
+                let field = Expression::Literal(LiteralExpr {
+                    literal: ScalarValue::Utf8(field.as_normalized_string().into()),
+                });
+                let planned =
+                    StructExtract.plan(bind_context.get_table_list(), vec![expr, field])?;
+                expr = Expression::ScalarFunction(ScalarFunctionExpr { function: planned });
+            }
+
+            return Ok(Some(expr));
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn bind_literal(literal: &ast::Literal<ResolvedMeta>) -> Result<Expression> {
         Ok(match literal {
             ast::Literal::Number(n) => {
@@ -1120,33 +1316,38 @@ impl<'a> BaseExpressionBinder<'a> {
             recur
         };
 
-        let inputs = func
-            .args
-            .iter()
-            .map(|arg| match arg {
-                ast::FunctionArg::Unnamed { arg } => match arg {
-                    ast::FunctionArgExpr::Expr(expr) => Ok(self.bind_expression(
-                        bind_context,
-                        expr,
-                        column_binder,
-                        RecursionContext {
-                            is_root: false,
-                            ..recur
-                        },
-                    )?),
-                    ast::FunctionArgExpr::Wildcard => {
-                        // Resolver should have handled removing '*'
-                        // from function calls.
-                        Err(RayexecError::new(
-                            "Cannot plan a function with '*' as an argument",
-                        ))
-                    }
-                },
-                ast::FunctionArg::Named { .. } => Err(RayexecError::new(
-                    "Named arguments to scalar functions not supported",
-                )),
-            })
-            .collect::<Result<Vec<_>>>()?;
+        let mut positional = Vec::new();
+        let mut named = Vec::new();
+        for arg in &func.args {
+            let (name, arg) = match arg {
+                ast::FunctionArg::Unnamed { arg } => (None, arg),
+                ast::FunctionArg::Named { name, arg } => (Some(name), arg),
+            };
+
+            let expr = match arg {
+                ast::FunctionArgExpr::Expr(expr) => self.bind_expression(
+                    bind_context,
+                    expr,
+                    column_binder,
+                    RecursionContext {
+                        is_root: false,
+                        ..recur
+                    },
+                )?,
+                ast::FunctionArgExpr::Wildcard => {
+                    // Resolver should have handled removing '*'
+                    // from function calls.
+                    return Err(RayexecError::new(
+                        "Cannot plan a function with '*' as an argument",
+                    ));
+                }
+            };
+
+            match name {
+                Some(name) => named.push((name.as_normalized_string(), expr)),
+                None => positional.push(expr),
+            }
+        }
 
         // TODO: This should probably assert that location == any since
         // I don't think it makes sense to try to handle different sets
@@ -1155,9 +1356,13 @@ impl<'a> BaseExpressionBinder<'a> {
             (ResolvedFunction::Special(special), _) => {
                 match special {
                     SpecialBuiltinFunction::Unnest => {
-                        if func.distinct || func.filter.is_some() || func.over.is_some() {
+                        if func.distinct
+                            || !func.within_group.is_empty()
+                            || func.filter.is_some()
+                            || func.over.is_some()
+                        {
                             return Err(RayexecError::new(
-                                "UNNEST does not support DISTINCT, FILTER, or OVER",
+                                "UNNEST does not support DISTINCT, WITHIN GROUP, FILTER, or OVER",
                             ));
                         }
 
@@ -1199,9 +1404,13 @@ impl<'a> BaseExpressionBinder<'a> {
                         Ok(unnest_expr)
                     }
                     SpecialBuiltinFunction::Grouping => {
-                        if func.distinct || func.filter.is_some() || func.over.is_some() {
+                        if func.distinct
+                            || !func.within_group.is_empty()
+                            || func.filter.is_some()
+                            || func.over.is_some()
+                        {
                             return Err(RayexecError::new(
-                                "GROUPING does not support DISTINCT, FILTER, or OVER",
+                                "GROUPING does not support DISTINCT, WITHIN GROUP, FILTER, or OVER",
                             ));
                         }
 
@@ -1250,23 +1459,109 @@ impl<'a> BaseExpressionBinder<'a> {
                         "OVER only supported for aggregate functions",
                     ));
                 }
+                if func.filter.is_some() {
+                    return Err(RayexecError::new(
+                        "FILTER only supported for aggregate functions",
+                    ));
+                }
+                if !func.within_group.is_empty() {
+                    return Err(RayexecError::new(
+                        "WITHIN GROUP only supported for aggregate functions",
+                    ));
+                }
 
+                let inputs = plan_named_args(scalar.as_ref(), positional, named)?;
                 let inputs =
                     self.apply_casts_for_scalar_function(bind_context, scalar.as_ref(), inputs)?;
 
-                let function = scalar.plan(bind_context.get_table_list(), inputs)?;
+                let function = match scalar.volatility() {
+                    FunctionVolatility::Volatile => {
+                        let seed = bind_context.next_function_seed();
+                        scalar.plan_with_seed(bind_context.get_table_list(), inputs, seed)?
+                    }
+                    FunctionVolatility::Consistent => scalar.plan_with_options(
+                        bind_context.get_table_list(),
+                        inputs,
+                        ScalarPlanOptions {
+                            rounding: bind_context.rounding_mode(),
+                            overflow: bind_context.arithmetic_overflow(),
+                        },
+                    )?,
+                };
 
                 Ok(Expression::ScalarFunction(ScalarFunctionExpr { function }))
             }
             (ResolvedFunction::Aggregate(agg), _) => {
-                let inputs =
-                    self.apply_casts_for_aggregate_function(bind_context, agg.as_ref(), inputs)?;
+                // Ordered-set aggregate, the expression being ordered is
+                // planned as the first input to the function.
+                let ordering = match func.within_group.as_slice() {
+                    [] => None,
+                    [order_by] => {
+                        if func.distinct {
+                            return Err(RayexecError::new(
+                                "DISTINCT not supported with WITHIN GROUP",
+                            ));
+                        }
+                        if func.over.is_some() {
+                            return Err(RayexecError::new(
+                                "OVER not supported for ordered-set aggregates",
+                            ));
+                        }
+
+                        let expr = self.bind_expression(
+                            bind_context,
+                            &order_by.expr,
+                            column_binder,
+                            RecursionContext {
+                                is_root: false,
+                                ..recur
+                            },
+                        )?;
+                        positional.insert(0, expr);
+
+                        let desc = matches!(
+                            order_by.typ.unwrap_or(ast::OrderByType::Asc),
+                            ast::OrderByType::Desc
+                        );
+                        // Matches postgres, NULLs are considered larger than
+                        // any other value.
+                        let nulls_first = match order_by.nulls {
+                            Some(nulls) => nulls == ast::OrderByNulls::First,
+                            None => desc,
+                        };
+
+                        Some(AggregateOrdering { desc, nulls_first })
+                    }
+                    _ => {
+                        return Err(RayexecError::new(
+                            "WITHIN GROUP only supports a single ORDER BY expression",
+                        ))
+                    }
+                };
 
-                let agg = agg.plan(bind_context.get_table_list(), inputs)?;
+                let inputs = plan_named_args(agg.as_ref(), positional, named)?;
+
+                // Ordered-set aggregates handle casting their own inputs.
+                let agg = match ordering {
+                    Some(ordering) => {
+                        agg.plan_ordered_set(bind_context.get_table_list(), inputs, ordering)?
+                    }
+                    None => {
+                        let inputs = self.apply_casts_for_aggregate_function(
+                            bind_context,
+                            agg.as_ref(),
+                            inputs,
+                        )?;
+                        agg.plan(bind_context.get_table_list(), inputs)?
+                    }
+                };
 
                 match &func.over {
                     Some(over) => {
                         // Window
+                        if func.filter.is_some() {
+                            not_implemented!("FILTER for window functions");
+                        }
 
                         match over {
                             ast::WindowSpec::Named(_) => {
@@ -1347,10 +1642,35 @@ impl<'a> BaseExpressionBinder<'a> {
                     }
                     None => {
                         // Normal aggregate.
+                        let filter = match &func.filter {
+                            Some(filter) => {
+                                let filter = self.bind_expression(
+                                    bind_context,
+                                    filter,
+                                    column_binder,
+                                    RecursionContext {
+                                        is_root: false,
+                                        ..recur
+                                    },
+                                )?;
+
+                                let datatype = filter.datatype(bind_context.get_table_list())?;
+                                if datatype != DataType::Boolean {
+                                    return Err(RayexecError::new(format!(
+                                        "FILTER expression must evaluate to a boolean, got {datatype}"
+                                    )));
+                                }
+
+                                Some(Box::new(filter))
+                            }
+                            None => None,
+                        };
+
                         Ok(Expression::Aggregate(AggregateExpr {
                             agg,
                             distinct: func.distinct,
-                            filter: None,
+                            filter,
+                            ordering,
                         }))
                     }
                 }
@@ -1412,6 +1732,28 @@ impl<'a> BaseExpressionBinder<'a> {
             .map_err(|_| RayexecError::new("Number of casted inputs incorrect"))
     }
 
+    /// Builds a comparison between two expressions.
+    ///
+    /// Mismatched inputs get implicit casts to a common type (e.g. `Int32 =
+    /// Int64` compares as Int64, and `Int32 = Utf8` parses the string as an
+    /// Int32). Errors if there's no common type the inputs can be compared
+    /// as.
+    pub(crate) fn bind_comparison(
+        &self,
+        bind_context: &BindContext,
+        op: ComparisonOperator,
+        left: Expression,
+        right: Expression,
+    ) -> Result<Expression> {
+        let [left, right] = self.apply_cast_for_operator(bind_context, op, [left, right])?;
+
+        Ok(Expression::Comparison(ComparisonExpr {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+        }))
+    }
+
     /// Applies casts to an input expression based on the signatures for a
     /// scalar function.
     fn apply_casts_for_scalar_function(
@@ -1425,8 +1767,8 @@ impl<'a> BaseExpressionBinder<'a> {
             .map(|expr| expr.datatype(bind_context.get_table_list()))
             .collect::<Result<Vec<_>>>()?;
 
-        if scalar.exact_signature(&input_datatypes).is_some() {
-            // Exact
+        if scalar.exact_signature(&input_datatypes).is_some() || !scalar.cast_variadic_args() {
+            // Exact, or function accepts inputs as-is.
             Ok(inputs)
         } else {
             // Try to find candidates that we can cast to.
@@ -1457,10 +1799,11 @@ impl<'a> BaseExpressionBinder<'a> {
                 .zip(candidate.casts)
                 .map(|(input, cast_to)| {
                     Ok(match cast_to {
-                        CastType::Cast { to, .. } => Expression::Cast(CastExpr {
-                            to: DataType::try_default_datatype(to)?,
-                            expr: Box::new(input),
-                        }),
+                        CastType::Cast { to, .. } => Expression::Cast(CastExpr::new_using_session(
+                            bind_context,
+                            input,
+                            DataType::try_default_datatype(to)?,
+                        )),
                         CastType::NoCastNeeded => input,
                     })
                 })
@@ -1506,10 +1849,11 @@ impl<'a> BaseExpressionBinder<'a> {
                 .zip(candidate.casts)
                 .map(|(input, cast_to)| {
                     Ok(match cast_to {
-                        CastType::Cast { to, .. } => Expression::Cast(CastExpr {
-                            to: DataType::try_default_datatype(to)?,
-                            expr: Box::new(input),
-                        }),
+                        CastType::Cast { to, .. } => Expression::Cast(CastExpr::new_using_session(
+                            bind_context,
+                            input,
+                            DataType::try_default_datatype(to)?,
+                        )),
                         CastType::NoCastNeeded => input,
                     })
                 })
@@ -1559,10 +1903,11 @@ impl<'a> BaseExpressionBinder<'a> {
                 .zip(candidate.casts)
                 .map(|(input, cast_to)| {
                     Ok(match cast_to {
-                        CastType::Cast { to, .. } => Expression::Cast(CastExpr {
-                            to: DataType::try_default_datatype(to)?,
-                            expr: Box::new(input),
-                        }),
+                        CastType::Cast { to, .. } => Expression::Cast(CastExpr::new_using_session(
+                            bind_context,
+                            input,
+                            DataType::try_default_datatype(to)?,
+                        )),
                         CastType::NoCastNeeded => input,
                     })
                 })
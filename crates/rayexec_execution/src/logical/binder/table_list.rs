@@ -1,6 +1,7 @@
 use std::fmt;
 
 use rayexec_error::{RayexecError, Result};
+use rayexec_proto::ProtoConv;
 use serde::{Deserialize, Serialize};
 
 use crate::arrays::datatype::DataType;
@@ -23,6 +24,22 @@ impl fmt::Display for TableRef {
     }
 }
 
+impl ProtoConv for TableRef {
+    type ProtoType = rayexec_proto::generated::logical::TableRef;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            table_idx: self.table_idx as u32,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(TableRef {
+            table_idx: proto.table_idx as usize,
+        })
+    }
+}
+
 /// Reference to a table inside a scope.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TableAlias {
@@ -73,12 +90,38 @@ pub struct Table {
     pub alias: Option<TableAlias>,
     pub column_types: Vec<DataType>,
     pub column_names: Vec<String>,
+    /// If each column may contain NULLs.
+    ///
+    /// Columns are assumed to be nullable unless we can prove otherwise.
+    pub column_nullable: Vec<bool>,
 }
 
 impl Table {
     pub fn num_columns(&self) -> usize {
         self.column_types.len()
     }
+
+    /// Set the nullability for all columns in the table.
+    pub fn set_column_nullability(&mut self, nullable: Vec<bool>) -> Result<()> {
+        if nullable.len() != self.column_types.len() {
+            return Err(
+                RayexecError::new("Column nullability and types have different lengths")
+                    .with_fields([
+                        ("types", self.column_types.len()),
+                        ("nullable", nullable.len()),
+                    ]),
+            );
+        }
+        self.column_nullable = nullable;
+        Ok(())
+    }
+
+    /// Mark all columns in the table as nullable.
+    ///
+    /// Used for the non-preserved side of an outer join.
+    pub fn mark_all_nullable(&mut self) {
+        self.column_nullable.iter_mut().for_each(|n| *n = true);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -89,6 +132,12 @@ pub struct TableList {
     pub(super) tables: Vec<Table>,
 }
 
+impl AsRef<TableList> for TableList {
+    fn as_ref(&self) -> &TableList {
+        self
+    }
+}
+
 impl TableList {
     pub const fn empty() -> Self {
         TableList { tables: Vec::new() }
@@ -126,6 +175,7 @@ impl TableList {
         let table = Table {
             reference,
             alias,
+            column_nullable: vec![true; column_types.len()],
             column_types,
             column_names,
         };
@@ -146,4 +196,12 @@ impl TableList {
         let datatype = &table.column_types[col_idx];
         Ok((name, datatype))
     }
+
+    /// Check if a column may contain NULLs.
+    pub fn column_nullable(&self, table_ref: TableRef, col_idx: usize) -> Result<bool> {
+        let table = self.get(table_ref)?;
+        table.column_nullable.get(col_idx).copied().ok_or_else(|| {
+            RayexecError::new(format!("Missing column {col_idx} in table {table_ref}"))
+        })
+    }
 }
@@ -111,3 +111,116 @@ impl LogicalNode for Node<LogicalAggregate> {
         Ok(())
     }
 }
+
+/// Produce the grouping sets for `ROLLUP` over `n` group expressions.
+///
+/// This produces every prefix of the expressions, starting with the full set
+/// and ending with the empty set.
+pub fn grouping_sets_from_rollup(n: usize) -> Vec<BTreeSet<usize>> {
+    (0..=n).rev().map(|len| (0..len).collect()).collect()
+}
+
+/// Produce the grouping sets for `CUBE` over `n` group expressions.
+///
+/// This produces the powerset of the expressions, ordered by the bitmask of
+/// included expressions (empty set first, full set last).
+pub fn grouping_sets_from_cube(n: usize) -> Vec<BTreeSet<usize>> {
+    (0..(1_u64 << n))
+        .map(|mask| (0..n).filter(|idx| mask & (1 << idx) != 0).collect())
+        .collect()
+}
+
+/// Combine the grouping sets of two grouping elements.
+///
+/// Used for group by clauses containing multiple grouping elements, e.g.
+/// `GROUP BY a, ROLLUP (b, c)`. Every set on the left is unioned with every set
+/// on the right. Indices for both sides are expected to already reference the
+/// combined list of group expressions.
+pub fn grouping_sets_cross_product(
+    left: &[BTreeSet<usize>],
+    right: &[BTreeSet<usize>],
+) -> Vec<BTreeSet<usize>> {
+    left.iter()
+        .flat_map(|l| right.iter().map(|r| l.union(r).copied().collect()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::explain::context_display::ContextDisplayMode;
+    use crate::explain::explainable::ExplainValue;
+    use crate::expr;
+    use crate::expr::aggregate_expr::AggregateExpr;
+    use crate::functions::aggregate::builtin::count::Count;
+    use crate::functions::aggregate::AggregateFunction;
+    use crate::logical::binder::table_list::TableList;
+
+    fn count_expr(distinct: bool) -> Expression {
+        let agg = Count.plan(&TableList::empty(), vec![expr::lit(1)]).unwrap();
+        Expression::Aggregate(AggregateExpr {
+            agg,
+            filter: None,
+            distinct,
+            ordering: None,
+        })
+    }
+
+    #[test]
+    fn explain_distinct_aggregate() {
+        let agg = LogicalAggregate {
+            aggregates_table: TableRef::from(0),
+            aggregates: vec![count_expr(false), count_expr(true)],
+            group_table: None,
+            group_exprs: Vec::new(),
+            grouping_sets: None,
+            grouping_functions_table: None,
+            grouping_functions: Vec::new(),
+        };
+
+        let ent = agg.explain_entry(ExplainConfig {
+            context_mode: ContextDisplayMode::Raw,
+            verbose: false,
+        });
+        assert_eq!(
+            Some(&ExplainValue::Values(vec![
+                "count(1)".to_string(),
+                "count(DISTINCT 1)".to_string()
+            ])),
+            ent.items.get("aggregates")
+        );
+    }
+
+    #[test]
+    fn rollup_sets() {
+        let sets = grouping_sets_from_rollup(3);
+        let expected: Vec<BTreeSet<usize>> =
+            vec![[0, 1, 2].into(), [0, 1].into(), [0].into(), [].into()];
+        assert_eq!(expected, sets);
+    }
+
+    #[test]
+    fn rollup_sets_empty() {
+        let sets = grouping_sets_from_rollup(0);
+        let expected: Vec<BTreeSet<usize>> = vec![[].into()];
+        assert_eq!(expected, sets);
+    }
+
+    #[test]
+    fn cube_sets() {
+        let sets = grouping_sets_from_cube(2);
+        let expected: Vec<BTreeSet<usize>> = vec![[].into(), [0].into(), [1].into(), [0, 1].into()];
+        assert_eq!(expected, sets);
+    }
+
+    #[test]
+    fn cross_product_sets() {
+        // GROUP BY a, ROLLUP (b, c)
+        let left: Vec<BTreeSet<usize>> = vec![[0].into()];
+        let right: Vec<BTreeSet<usize>> = vec![[1, 2].into(), [1].into(), [].into()];
+
+        let sets = grouping_sets_cross_product(&left, &right);
+        let expected: Vec<BTreeSet<usize>> = vec![[0, 1, 2].into(), [0, 1].into(), [0].into()];
+        assert_eq!(expected, sets);
+    }
+}
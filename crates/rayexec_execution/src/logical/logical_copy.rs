@@ -7,7 +7,7 @@ use super::operator::{LogicalNode, Node};
 use crate::arrays::field::Schema;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::Expression;
-use crate::functions::copy::CopyToFunction;
+use crate::functions::copy::{CopyToArgs, CopyToFunction};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalCopyTo {
@@ -18,11 +18,22 @@ pub struct LogicalCopyTo {
     pub source_schema: Schema,
     pub location: FileLocation,
     pub copy_to: Box<dyn CopyToFunction>,
+    /// Options provided in the COPY TO statement.
+    pub args: CopyToArgs,
+    /// Indices of the source columns to partition the output by.
+    ///
+    /// If non-empty, rows are written to a separate file for each distinct
+    /// partition key, and the partition columns are omitted from the data.
+    pub partition_by: Vec<usize>,
 }
 
 impl Explainable for LogicalCopyTo {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("CopyTo")
+        let mut ent = ExplainEntry::new("CopyTo");
+        if !self.partition_by.is_empty() {
+            ent = ent.with_values("partition_by", &self.partition_by);
+        }
+        ent
     }
 }
 
@@ -1,14 +1,18 @@
+use std::collections::HashSet;
 use std::fmt;
 
-use rayexec_error::{RayexecError, Result};
+use rayexec_error::{OptionExt, RayexecError, Result};
+use rayexec_proto::ProtoConv;
 
 use super::binder::bind_context::{BindContext, MaterializationRef};
 use super::binder::table_list::TableRef;
 use super::operator::{LogicalNode, Node};
+use crate::database::DatabaseContext;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::comparison_expr::{ComparisonExpr, ComparisonOperator};
 use crate::expr::Expression;
+use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum JoinType {
@@ -56,6 +60,45 @@ impl JoinType {
     }
 }
 
+impl ProtoConv for JoinType {
+    type ProtoType = rayexec_proto::generated::logical::JoinType;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        use rayexec_proto::generated::logical::join_type::Value;
+        use rayexec_proto::generated::logical::LeftMarkJoinType;
+
+        let value = match self {
+            Self::Left => Value::Left(Default::default()),
+            Self::Right => Value::Right(Default::default()),
+            Self::Inner => Value::Inner(Default::default()),
+            Self::Full => Value::Full(Default::default()),
+            Self::Semi => Value::Semi(Default::default()),
+            Self::Anti => Value::Anti(Default::default()),
+            Self::LeftMark { table_ref } => Value::LeftMark(LeftMarkJoinType {
+                table_ref: Some(table_ref.to_proto()?),
+            }),
+        };
+
+        Ok(Self::ProtoType { value: Some(value) })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        use rayexec_proto::generated::logical::join_type::Value;
+
+        Ok(match proto.value.required("value")? {
+            Value::Left(_) => Self::Left,
+            Value::Right(_) => Self::Right,
+            Value::Inner(_) => Self::Inner,
+            Value::Full(_) => Self::Full,
+            Value::Semi(_) => Self::Semi,
+            Value::Anti(_) => Self::Anti,
+            Value::LeftMark(mark) => Self::LeftMark {
+                table_ref: TableRef::from_proto(mark.table_ref.required("table_ref")?)?,
+            },
+        })
+    }
+}
+
 impl fmt::Display for JoinType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -133,6 +176,27 @@ impl ContextDisplay for ComparisonCondition {
     }
 }
 
+impl DatabaseProtoConv for ComparisonCondition {
+    type ProtoType = rayexec_proto::generated::logical::ComparisonCondition;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            left: Some(self.left.to_proto_ctx(context)?),
+            right: Some(self.right.to_proto_ctx(context)?),
+            op: self.op.to_proto()? as i32,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        let op = ComparisonOperator::from_proto(proto.op())?;
+        Ok(Self {
+            left: DatabaseProtoConv::from_proto_ctx(proto.left.required("left")?, context)?,
+            right: DatabaseProtoConv::from_proto_ctx(proto.right.required("right")?, context)?,
+            op,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LogicalComparisonJoin {
     pub join_type: JoinType,
@@ -147,6 +211,32 @@ impl Explainable for LogicalComparisonJoin {
     }
 }
 
+impl DatabaseProtoConv for LogicalComparisonJoin {
+    type ProtoType = rayexec_proto::generated::logical::LogicalComparisonJoin;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            join_type: Some(self.join_type.to_proto()?),
+            conditions: self
+                .conditions
+                .iter()
+                .map(|cond| cond.to_proto_ctx(context))
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            join_type: ProtoConv::from_proto(proto.join_type.required("join_type")?)?,
+            conditions: proto
+                .conditions
+                .into_iter()
+                .map(|cond| DatabaseProtoConv::from_proto_ctx(cond, context))
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+
 impl LogicalNode for Node<LogicalComparisonJoin> {
     fn get_output_table_refs(&self, bind_context: &BindContext) -> Vec<TableRef> {
         self.node.join_type.output_refs(self, bind_context)
@@ -243,6 +333,56 @@ pub struct LogicalArbitraryJoin {
     pub condition: Expression,
 }
 
+impl LogicalArbitraryJoin {
+    /// Flip the sides of the join.
+    ///
+    /// Comparisons in the condition that have one side referencing only
+    /// `left_refs` and the other referencing only `right_refs` (or vice versa)
+    /// get their operands swapped. The join type is flipped as well (LEFT
+    /// becomes RIGHT and vice versa).
+    ///
+    /// The caller is responsible for swapping the children of the node.
+    pub fn flip_sides(&mut self, left_refs: &[TableRef], right_refs: &[TableRef]) -> Result<()> {
+        self.join_type = match self.join_type {
+            JoinType::Left => JoinType::Right,
+            JoinType::Right => JoinType::Left,
+            JoinType::Inner => JoinType::Inner,
+            JoinType::Full => JoinType::Full,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Cannot flip sides for join type {other}"
+                )))
+            }
+        };
+
+        flip_comparison_sides(&mut self.condition, left_refs, right_refs)
+    }
+}
+
+fn flip_comparison_sides(
+    expr: &mut Expression,
+    left_refs: &[TableRef],
+    right_refs: &[TableRef],
+) -> Result<()> {
+    if let Expression::Comparison(cmp) = expr {
+        let refs_left = cmp.left.get_table_references();
+        let refs_right = cmp.right.get_table_references();
+
+        let only_refs = |refs: &HashSet<TableRef>, side: &[TableRef]| {
+            !refs.is_empty() && refs.iter().all(|r| side.contains(r))
+        };
+
+        if (only_refs(&refs_left, left_refs) && only_refs(&refs_right, right_refs))
+            || (only_refs(&refs_left, right_refs) && only_refs(&refs_right, left_refs))
+        {
+            std::mem::swap(&mut cmp.left, &mut cmp.right);
+            cmp.op = cmp.op.flip();
+        }
+    }
+
+    expr.for_each_child_mut(&mut |child| flip_comparison_sides(child, left_refs, right_refs))
+}
+
 impl Explainable for LogicalArbitraryJoin {
     fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
         ExplainEntry::new("ArbitraryJoin")
@@ -302,9 +442,22 @@ impl LogicalNode for Node<LogicalCrossJoin> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use rayexec_proto::testutil::assert_proto_roundtrip;
+
     use super::*;
+    use crate::arrays::datatype::DataType;
     use crate::arrays::scalar::ScalarValue;
+    use crate::database::system::new_system_catalog;
+    use crate::datasource::DataSourceRegistry;
+    use crate::explain::explainable::ExplainValue;
+    use crate::expr;
     use crate::expr::literal_expr::LiteralExpr;
+    use crate::expr::negate_expr::{NegateExpr, NegateOperator};
+    use crate::functions::scalar::builtin::arith::OverflowBehavior;
+    use crate::logical::operator::LocationRequirement;
+    use crate::logical::statistics::StatisticsValue;
 
     #[test]
     fn flip_comparison() {
@@ -348,4 +501,158 @@ mod tests {
             assert_eq!(flipped, original);
         }
     }
+
+    #[test]
+    fn comparison_join_explain_estimated_cardinality() {
+        let join = Node {
+            node: LogicalComparisonJoin {
+                join_type: JoinType::Inner,
+                conditions: vec![ComparisonCondition {
+                    left: expr::col_ref(0, 0),
+                    right: expr::col_ref(1, 0),
+                    op: ComparisonOperator::Eq,
+                }],
+            },
+            location: LocationRequirement::Any,
+            children: Vec::new(),
+            estimated_cardinality: StatisticsValue::Estimated(100),
+        };
+
+        let verbose = join.explain_entry(ExplainConfig {
+            context_mode: ContextDisplayMode::Raw,
+            verbose: true,
+        });
+        assert_eq!(
+            Some(&ExplainValue::Value("100 [estimated]".to_string())),
+            verbose.items.get("cardinality")
+        );
+
+        let not_verbose = join.explain_entry(ExplainConfig {
+            context_mode: ContextDisplayMode::Raw,
+            verbose: false,
+        });
+        assert_eq!(None, not_verbose.items.get("cardinality"));
+    }
+
+    #[test]
+    fn flip_arbitrary_join() {
+        // LEFT JOIN ON t0.c0 < t1.c0 AND (t1.c1 = t0.c1 + 1 OR t0.c2 > 5)
+        let mut join = LogicalArbitraryJoin {
+            join_type: JoinType::Left,
+            condition: Expression::and([
+                expr::lt(expr::col_ref(0, 0), expr::col_ref(1, 0)),
+                Expression::or([
+                    expr::eq(
+                        expr::col_ref(1, 1),
+                        expr::add(expr::col_ref(0, 1), expr::lit(1)),
+                    ),
+                    expr::gt(expr::col_ref(0, 2), expr::lit(5)),
+                ]),
+            ]),
+        };
+
+        join.flip_sides(&[0.into()], &[1.into()]).unwrap();
+
+        // RIGHT JOIN ON t1.c0 > t0.c0 AND (t0.c1 + 1 = t1.c1 OR t0.c2 > 5)
+        let expected = LogicalArbitraryJoin {
+            join_type: JoinType::Right,
+            condition: Expression::and([
+                expr::gt(expr::col_ref(1, 0), expr::col_ref(0, 0)),
+                Expression::or([
+                    expr::eq(
+                        expr::add(expr::col_ref(0, 1), expr::lit(1)),
+                        expr::col_ref(1, 1),
+                    ),
+                    expr::gt(expr::col_ref(0, 2), expr::lit(5)),
+                ]),
+            ]),
+        };
+        assert_eq!(expected, join);
+    }
+
+    #[test]
+    fn flip_arbitrary_join_unsupported() {
+        let mut join = LogicalArbitraryJoin {
+            join_type: JoinType::Semi,
+            condition: expr::lt(expr::col_ref(0, 0), expr::col_ref(1, 0)),
+        };
+
+        join.flip_sides(&[0.into()], &[1.into()]).unwrap_err();
+    }
+
+    #[test]
+    fn roundtrip_join_types() {
+        let join_types = [
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Inner,
+            JoinType::Full,
+            JoinType::Semi,
+            JoinType::Anti,
+            JoinType::LeftMark {
+                table_ref: TableRef::from(4),
+            },
+        ];
+
+        for join_type in join_types {
+            assert_proto_roundtrip(join_type);
+        }
+    }
+
+    fn assert_database_proto_roundtrip<P>(val: P)
+    where
+        P: DatabaseProtoConv + PartialEq + std::fmt::Debug,
+    {
+        let context = DatabaseContext::new(Arc::new(
+            new_system_catalog(&DataSourceRegistry::default()).unwrap(),
+        ))
+        .unwrap();
+
+        let proto = val.to_proto_ctx(&context).unwrap();
+        let got = P::from_proto_ctx(proto, &context).unwrap();
+
+        assert_eq!(val, got);
+    }
+
+    #[test]
+    fn roundtrip_comparison_condition() {
+        // #0.1 + 1 < CAST(-#1.0 AS Int64)
+        let condition = ComparisonCondition {
+            left: expr::add(expr::col_ref(0, 1), expr::lit(1)),
+            right: expr::cast(
+                Expression::Negate(NegateExpr {
+                    op: NegateOperator::Negate,
+                    expr: Box::new(expr::col_ref(1, 0)),
+                    overflow: OverflowBehavior::default(),
+                }),
+                DataType::Int64,
+            ),
+            op: ComparisonOperator::Lt,
+        };
+
+        assert_database_proto_roundtrip(condition);
+    }
+
+    #[test]
+    fn roundtrip_logical_comparison_join() {
+        let join = LogicalComparisonJoin {
+            join_type: JoinType::LeftMark {
+                table_ref: TableRef::from(3),
+            },
+            conditions: vec![
+                ComparisonCondition {
+                    left: expr::col_ref(0, 0),
+                    right: expr::add(expr::col_ref(1, 0), expr::lit(2)),
+                    op: ComparisonOperator::Eq,
+                },
+                ComparisonCondition {
+                    left: expr::or([expr::col_ref(0, 1), expr::lit(false)]).unwrap(),
+                    right: expr::col_ref(1, 1),
+                    op: ComparisonOperator::NotEq,
+                },
+            ],
+        };
+
+        assert_database_proto_roundtrip(join);
+    }
 }
@@ -9,15 +9,27 @@ use crate::expr::Expression;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LogicalLimit {
     pub offset: Option<usize>,
-    pub limit: usize,
+    /// Number of rows to limit to, None if only an offset was provided.
+    pub limit: Option<usize>,
+    /// Sort key expressions used to determine ties with the last row.
+    ///
+    /// Set when using `WITH TIES`, in which case rows after the limit that are
+    /// equal to the last row on these expressions are also included.
+    pub with_ties: Option<Vec<Expression>>,
 }
 
 impl Explainable for LogicalLimit {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        let mut ent = ExplainEntry::new("Limit").with_value("limit", self.limit);
+        let mut ent = ExplainEntry::new("Limit");
+        if let Some(limit) = self.limit {
+            ent = ent.with_value("limit", limit);
+        }
         if let Some(offset) = self.offset {
             ent = ent.with_value("offset", offset);
         }
+        if let Some(with_ties) = &self.with_ties {
+            ent = ent.with_values("with_ties", with_ties);
+        }
         ent
     }
 }
@@ -27,17 +39,23 @@ impl LogicalNode for Node<LogicalLimit> {
         self.get_children_table_refs(bind_context)
     }
 
-    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    fn for_each_expr<F>(&self, func: &mut F) -> Result<()>
     where
         F: FnMut(&Expression) -> Result<()>,
     {
+        for expr in self.node.with_ties.iter().flatten() {
+            func(expr)?;
+        }
         Ok(())
     }
 
-    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    fn for_each_expr_mut<F>(&mut self, func: &mut F) -> Result<()>
     where
         F: FnMut(&mut Expression) -> Result<()>,
     {
+        for expr in self.node.with_ties.iter_mut().flatten() {
+            func(expr)?;
+        }
         Ok(())
     }
 }
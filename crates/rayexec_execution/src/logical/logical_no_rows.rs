@@ -0,0 +1,46 @@
+use rayexec_error::Result;
+
+use super::binder::bind_context::BindContext;
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+/// A relation that's statically known to produce no rows.
+///
+/// This stands in for a pruned subtree, so it reports the same output table
+/// refs as the subtree it replaced. This keeps column references in parent
+/// operators valid.
+///
+/// This is different than `LogicalEmpty` which produces a single row with no
+/// columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogicalNoRows {
+    pub table_refs: Vec<TableRef>,
+}
+
+impl Explainable for LogicalNoRows {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("NoRows").with_values("table_refs", &self.table_refs)
+    }
+}
+
+impl LogicalNode for Node<LogicalNoRows> {
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        self.node.table_refs.clone()
+    }
+
+    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
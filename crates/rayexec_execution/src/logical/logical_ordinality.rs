@@ -0,0 +1,48 @@
+use rayexec_error::Result;
+
+use super::binder::bind_context::BindContext;
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+/// Appends a 1-based row number column to the output of its child.
+///
+/// Used for table functions invoked `WITH ORDINALITY`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogicalOrdinality {
+    /// Table ref for the single Int64 ordinality column.
+    pub ordinality_ref: TableRef,
+}
+
+impl Explainable for LogicalOrdinality {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        let mut ent = ExplainEntry::new("Ordinality");
+        if conf.verbose {
+            ent = ent.with_value("ordinality_table_ref", self.ordinality_ref);
+        }
+        ent
+    }
+}
+
+impl LogicalNode for Node<LogicalOrdinality> {
+    fn get_output_table_refs(&self, bind_context: &BindContext) -> Vec<TableRef> {
+        let mut refs = self.get_children_table_refs(bind_context);
+        refs.push(self.node.ordinality_ref);
+        refs
+    }
+
+    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
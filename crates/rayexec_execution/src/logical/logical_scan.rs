@@ -33,6 +33,10 @@ pub enum ScanSource {
         schema: String,
         source: Arc<CatalogEntry>,
     },
+    /// Rows produced by the previous iteration of a recursive CTE.
+    ///
+    /// Only valid within the recursive term of the CTE.
+    WorkingTable,
 }
 
 impl ScanSource {
@@ -42,6 +46,7 @@ impl ScanSource {
             Self::TableFunction { function } => function.cardinality,
             Self::ExpressionList { rows } => StatisticsValue::Exact(rows.len()),
             Self::View { .. } => StatisticsValue::Unknown,
+            Self::WorkingTable => StatisticsValue::Unknown,
         }
     }
 }
@@ -100,6 +105,7 @@ impl Explainable for LogicalScan {
             ScanSource::ExpressionList { rows } => {
                 ent = ent.with_value("num_rows", rows.len());
             }
+            ScanSource::WorkingTable => ent = ent.with_value("source", "working_table"),
         }
 
         if conf.verbose {
@@ -11,6 +11,9 @@ use crate::expr::Expression;
 pub struct LogicalSetVar {
     pub name: String,
     pub value: OwnedScalarValue,
+    /// If this was set with SET LOCAL, scoping the change to the current
+    /// transaction.
+    pub local: bool,
 }
 
 impl Explainable for LogicalSetVar {
@@ -1,6 +1,7 @@
 use std::fmt;
 
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
+use rayexec_proto::ProtoConv;
 
 use super::binder::bind_context::BindContext;
 use super::binder::table_list::TableRef;
@@ -25,11 +26,37 @@ impl fmt::Display for SetOpKind {
     }
 }
 
+impl ProtoConv for SetOpKind {
+    type ProtoType = rayexec_proto::generated::execution::SetOpKind;
+
+    fn to_proto(&self) -> Result<Self::ProtoType> {
+        Ok(match self {
+            Self::Union => Self::ProtoType::SetOpUnion,
+            Self::Except => Self::ProtoType::SetOpExcept,
+            Self::Intersect => Self::ProtoType::SetOpIntersect,
+        })
+    }
+
+    fn from_proto(proto: Self::ProtoType) -> Result<Self> {
+        Ok(match proto {
+            Self::ProtoType::InvalidSetOpKind => return Err(RayexecError::new("invalid")),
+            Self::ProtoType::SetOpUnion => Self::Union,
+            Self::ProtoType::SetOpExcept => Self::Except,
+            Self::ProtoType::SetOpIntersect => Self::Intersect,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalSetop {
     pub kind: SetOpKind,
     pub all: bool,
     pub table_ref: TableRef,
+    /// If this is a recursive CTE.
+    ///
+    /// The left child is the anchor, and the right child is the recursive term
+    /// which scans the working table.
+    pub recursive: bool,
 }
 
 impl Explainable for LogicalSetop {
@@ -37,6 +64,9 @@ impl Explainable for LogicalSetop {
         let kind = format!("{}{}", self.kind, if self.all { " ALL" } else { "" });
 
         let mut ent = ExplainEntry::new("Setop").with_value("kind", kind);
+        if self.recursive {
+            ent = ent.with_value("recursive", self.recursive);
+        }
         if conf.verbose {
             ent = ent.with_value("table_ref", self.table_ref);
         }
@@ -0,0 +1,56 @@
+use rayexec_error::Result;
+
+use super::binder::bind_context::BindContext;
+use super::binder::bind_query::bind_modifier::BoundOrderByExpr;
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+/// An ORDER BY directly followed by a LIMIT.
+///
+/// Produced by the optimizer, never by the planner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalTopN {
+    pub exprs: Vec<BoundOrderByExpr>,
+    pub limit: usize,
+    pub offset: Option<usize>,
+}
+
+impl Explainable for LogicalTopN {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        let mut ent = ExplainEntry::new("TopN")
+            .with_values("expressions", &self.exprs)
+            .with_value("limit", self.limit);
+        if let Some(offset) = self.offset {
+            ent = ent.with_value("offset", offset);
+        }
+        ent
+    }
+}
+
+impl LogicalNode for Node<LogicalTopN> {
+    fn get_output_table_refs(&self, bind_context: &BindContext) -> Vec<TableRef> {
+        self.get_children_table_refs(bind_context)
+    }
+
+    fn for_each_expr<F>(&self, func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        for order_expr in &self.node.exprs {
+            func(&order_expr.expr)?;
+        }
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        for order_expr in &mut self.node.exprs {
+            func(&mut order_expr.expr)?;
+        }
+        Ok(())
+    }
+}
@@ -59,10 +59,14 @@ pub mod logical_insert;
 pub mod logical_join;
 pub mod logical_limit;
 pub mod logical_materialization;
+pub mod logical_no_rows;
 pub mod logical_order;
+pub mod logical_ordinality;
 pub mod logical_project;
 pub mod logical_scan;
 pub mod logical_set;
 pub mod logical_setop;
+pub mod logical_top_n;
+pub mod logical_transaction;
 pub mod logical_unnest;
 pub mod logical_window;
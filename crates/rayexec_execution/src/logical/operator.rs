@@ -25,11 +25,15 @@ use super::logical_join::{
 };
 use super::logical_limit::LogicalLimit;
 use super::logical_materialization::{LogicalMagicMaterializationScan, LogicalMaterializationScan};
+use super::logical_no_rows::LogicalNoRows;
 use super::logical_order::LogicalOrder;
+use super::logical_ordinality::LogicalOrdinality;
 use super::logical_project::LogicalProject;
 use super::logical_scan::LogicalScan;
 use super::logical_set::{LogicalResetVar, LogicalSetVar, LogicalShowVar};
 use super::logical_setop::LogicalSetop;
+use super::logical_top_n::LogicalTopN;
+use super::logical_transaction::LogicalTransaction;
 use super::logical_unnest::LogicalUnnest;
 use super::logical_window::LogicalWindow;
 use super::statistics::StatisticsValue;
@@ -265,6 +269,7 @@ pub enum LogicalOperator {
     Project(Node<LogicalProject>),
     Filter(Node<LogicalFilter>),
     Limit(Node<LogicalLimit>),
+    TopN(Node<LogicalTopN>),
     Order(Node<LogicalOrder>),
     Distinct(Node<LogicalDistinct>),
     Aggregate(Node<LogicalAggregate>),
@@ -273,8 +278,10 @@ pub enum LogicalOperator {
     MaterializationScan(Node<LogicalMaterializationScan>),
     MagicMaterializationScan(Node<LogicalMagicMaterializationScan>),
     Empty(Node<LogicalEmpty>),
+    NoRows(Node<LogicalNoRows>),
     SetVar(Node<LogicalSetVar>),
     ResetVar(Node<LogicalResetVar>),
+    Transaction(Node<LogicalTransaction>),
     ShowVar(Node<LogicalShowVar>),
     AttachDatabase(Node<LogicalAttachDatabase>),
     DetachDatabase(Node<LogicalDetachDatabase>),
@@ -293,6 +300,7 @@ pub enum LogicalOperator {
     Unnest(Node<LogicalUnnest>),
     Window(Node<LogicalWindow>),
     InOut(Node<LogicalInOut>),
+    Ordinality(Node<LogicalOrdinality>),
 }
 
 impl LogicalOperator {
@@ -369,10 +377,13 @@ impl LogicalOperator {
             Self::Aggregate(n) => &n.children,
             Self::SetOp(n) => &n.children,
             Self::Empty(n) => &n.children,
+            Self::NoRows(n) => &n.children,
             Self::Limit(n) => &n.children,
+            Self::TopN(n) => &n.children,
             Self::Order(n) => &n.children,
             Self::SetVar(n) => &n.children,
             Self::ResetVar(n) => &n.children,
+            Self::Transaction(n) => &n.children,
             Self::ShowVar(n) => &n.children,
             Self::AttachDatabase(n) => &n.children,
             Self::DetachDatabase(n) => &n.children,
@@ -391,6 +402,7 @@ impl LogicalOperator {
             Self::Unnest(n) => &n.children,
             Self::Window(n) => &n.children,
             Self::InOut(n) => &n.children,
+            Self::Ordinality(n) => &n.children,
         }
     }
 
@@ -406,10 +418,13 @@ impl LogicalOperator {
             Self::Aggregate(n) => &mut n.children,
             Self::SetOp(n) => &mut n.children,
             Self::Empty(n) => &mut n.children,
+            Self::NoRows(n) => &mut n.children,
             Self::Limit(n) => &mut n.children,
+            Self::TopN(n) => &mut n.children,
             Self::Order(n) => &mut n.children,
             Self::SetVar(n) => &mut n.children,
             Self::ResetVar(n) => &mut n.children,
+            Self::Transaction(n) => &mut n.children,
             Self::ShowVar(n) => &mut n.children,
             Self::AttachDatabase(n) => &mut n.children,
             Self::DetachDatabase(n) => &mut n.children,
@@ -428,6 +443,7 @@ impl LogicalOperator {
             Self::Unnest(n) => &mut n.children,
             Self::Window(n) => &mut n.children,
             Self::InOut(n) => &mut n.children,
+            Self::Ordinality(n) => &mut n.children,
         }
     }
 
@@ -447,10 +463,13 @@ impl LogicalOperator {
             LogicalOperator::Aggregate(n) => n.estimated_cardinality,
             LogicalOperator::SetOp(n) => n.estimated_cardinality,
             LogicalOperator::Empty(n) => n.estimated_cardinality,
+            LogicalOperator::NoRows(n) => n.estimated_cardinality,
             LogicalOperator::Limit(n) => n.estimated_cardinality,
+            LogicalOperator::TopN(n) => n.estimated_cardinality,
             LogicalOperator::Order(n) => n.estimated_cardinality,
             LogicalOperator::SetVar(n) => n.estimated_cardinality,
             LogicalOperator::ResetVar(n) => n.estimated_cardinality,
+            LogicalOperator::Transaction(n) => n.estimated_cardinality,
             LogicalOperator::ShowVar(n) => n.estimated_cardinality,
             LogicalOperator::AttachDatabase(n) => n.estimated_cardinality,
             LogicalOperator::DetachDatabase(n) => n.estimated_cardinality,
@@ -469,6 +488,7 @@ impl LogicalOperator {
             LogicalOperator::Unnest(n) => n.estimated_cardinality,
             LogicalOperator::Window(n) => n.estimated_cardinality,
             LogicalOperator::InOut(n) => n.estimated_cardinality,
+            LogicalOperator::Ordinality(n) => n.estimated_cardinality,
         }
     }
 }
@@ -486,10 +506,13 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Aggregate(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::SetOp(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Empty(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::NoRows(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Limit(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::TopN(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Order(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::SetVar(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::ResetVar(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Transaction(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::ShowVar(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::AttachDatabase(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::DetachDatabase(n) => n.get_output_table_refs(bind_context),
@@ -508,6 +531,7 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Unnest(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Window(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::InOut(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Ordinality(n) => n.get_output_table_refs(bind_context),
         }
     }
 
@@ -526,10 +550,13 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Aggregate(n) => n.for_each_expr(func),
             LogicalOperator::SetOp(n) => n.for_each_expr(func),
             LogicalOperator::Empty(n) => n.for_each_expr(func),
+            LogicalOperator::NoRows(n) => n.for_each_expr(func),
             LogicalOperator::Limit(n) => n.for_each_expr(func),
+            LogicalOperator::TopN(n) => n.for_each_expr(func),
             LogicalOperator::Order(n) => n.for_each_expr(func),
             LogicalOperator::SetVar(n) => n.for_each_expr(func),
             LogicalOperator::ResetVar(n) => n.for_each_expr(func),
+            LogicalOperator::Transaction(n) => n.for_each_expr(func),
             LogicalOperator::ShowVar(n) => n.for_each_expr(func),
             LogicalOperator::AttachDatabase(n) => n.for_each_expr(func),
             LogicalOperator::DetachDatabase(n) => n.for_each_expr(func),
@@ -548,6 +575,7 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Unnest(n) => n.for_each_expr(func),
             LogicalOperator::Window(n) => n.for_each_expr(func),
             LogicalOperator::InOut(n) => n.for_each_expr(func),
+            LogicalOperator::Ordinality(n) => n.for_each_expr(func),
         }
     }
 
@@ -566,10 +594,13 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Aggregate(n) => n.for_each_expr_mut(func),
             LogicalOperator::SetOp(n) => n.for_each_expr_mut(func),
             LogicalOperator::Empty(n) => n.for_each_expr_mut(func),
+            LogicalOperator::NoRows(n) => n.for_each_expr_mut(func),
             LogicalOperator::Limit(n) => n.for_each_expr_mut(func),
+            LogicalOperator::TopN(n) => n.for_each_expr_mut(func),
             LogicalOperator::Order(n) => n.for_each_expr_mut(func),
             LogicalOperator::SetVar(n) => n.for_each_expr_mut(func),
             LogicalOperator::ResetVar(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Transaction(n) => n.for_each_expr_mut(func),
             LogicalOperator::ShowVar(n) => n.for_each_expr_mut(func),
             LogicalOperator::AttachDatabase(n) => n.for_each_expr_mut(func),
             LogicalOperator::DetachDatabase(n) => n.for_each_expr_mut(func),
@@ -588,6 +619,7 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Unnest(n) => n.for_each_expr_mut(func),
             LogicalOperator::Window(n) => n.for_each_expr_mut(func),
             LogicalOperator::InOut(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Ordinality(n) => n.for_each_expr_mut(func),
         }
     }
 }
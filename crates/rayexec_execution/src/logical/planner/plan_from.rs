@@ -2,14 +2,19 @@ use rayexec_error::{RayexecError, Result};
 
 use super::plan_query::QueryPlanner;
 use super::plan_subquery::SubqueryPlanner;
-use crate::arrays::scalar::ScalarValue;
+use crate::arrays::datatype::DataType;
 use crate::expr::column_expr::ColumnExpr;
 use crate::expr::comparison_expr::ComparisonExpr;
-use crate::expr::literal_expr::LiteralExpr;
-use crate::expr::{self, Expression};
+use crate::expr::Expression;
+use crate::functions::scalar::FunctionVolatility;
 use crate::functions::table::TableFunctionImpl;
 use crate::logical::binder::bind_context::BindContext;
-use crate::logical::binder::bind_query::bind_from::{BoundFrom, BoundFromItem, BoundJoin};
+use crate::logical::binder::bind_query::bind_from::{
+    BoundFrom,
+    BoundFromItem,
+    BoundJoin,
+    BoundSubquery,
+};
 use crate::logical::logical_empty::LogicalEmpty;
 use crate::logical::logical_filter::LogicalFilter;
 use crate::logical::logical_inout::LogicalInOut;
@@ -21,6 +26,7 @@ use crate::logical::logical_join::{
     LogicalCrossJoin,
 };
 use crate::logical::logical_materialization::LogicalMaterializationScan;
+use crate::logical::logical_ordinality::LogicalOrdinality;
 use crate::logical::logical_project::LogicalProject;
 use crate::logical::logical_scan::{LogicalScan, ScanSource};
 use crate::logical::operator::{LocationRequirement, LogicalNode, LogicalOperator, Node};
@@ -74,7 +80,19 @@ impl FromPlanner {
                     names.extend(table.column_names.iter().cloned());
                 }
 
-                match &func.function.function_impl {
+                // With ordinality, the function itself outputs all but the
+                // last column in the scope. The function output and ordinality
+                // column are then projected into the scope's table.
+                let function_table_ref = if func.with_ordinality {
+                    types.pop();
+                    names.pop();
+                    bind_context.new_ephemeral_table_with_columns(types.clone(), names.clone())?
+                } else {
+                    func.table_ref
+                };
+                let num_function_columns = types.len();
+
+                let plan = match &func.function.function_impl {
                     TableFunctionImpl::Scan(_) => {
                         let projection = (0..types.len()).collect();
 
@@ -83,9 +101,9 @@ impl FromPlanner {
                         };
                         let estimated_cardinality = source.cardinality();
 
-                        Ok(LogicalOperator::Scan(Node {
+                        LogicalOperator::Scan(Node {
                             node: LogicalScan {
-                                table_ref: func.table_ref,
+                                table_ref: function_table_ref,
                                 types,
                                 names,
                                 projection,
@@ -96,7 +114,7 @@ impl FromPlanner {
                             location: func.location,
                             children: Vec::new(),
                             estimated_cardinality,
-                        }))
+                        })
                     }
                     TableFunctionImpl::InOut(_) => {
                         let cardinality = func.function.cardinality;
@@ -105,9 +123,9 @@ impl FromPlanner {
                         // input with an empty operator. Subquery planning will
                         // take care of the lateral binding and changing its
                         // child as needed.
-                        Ok(LogicalOperator::InOut(Node {
+                        LogicalOperator::InOut(Node {
                             node: LogicalInOut {
-                                function_table_ref: func.table_ref,
+                                function_table_ref,
                                 function: func.function,
                                 projected_table_ref: None,
                                 projected_outputs: Vec::new(),
@@ -115,9 +133,48 @@ impl FromPlanner {
                             location: func.location,
                             children: vec![LogicalOperator::EMPTY],
                             estimated_cardinality: cardinality,
-                        }))
+                        })
                     }
+                };
+
+                if !func.with_ordinality {
+                    return Ok(plan);
                 }
+
+                let ordinality_ref = bind_context.new_ephemeral_table_with_columns(
+                    vec![DataType::Int64],
+                    vec!["ordinality".to_string()],
+                )?;
+
+                let mut projections: Vec<_> = (0..num_function_columns)
+                    .map(|column| {
+                        Expression::Column(ColumnExpr {
+                            table_scope: function_table_ref,
+                            column,
+                        })
+                    })
+                    .collect();
+                projections.push(Expression::Column(ColumnExpr {
+                    table_scope: ordinality_ref,
+                    column: 0,
+                }));
+
+                let estimated_cardinality = plan.estimated_cardinality();
+
+                Ok(LogicalOperator::Project(Node {
+                    node: LogicalProject {
+                        projections,
+                        projection_table: func.table_ref,
+                    },
+                    location: LocationRequirement::Any,
+                    children: vec![LogicalOperator::Ordinality(Node {
+                        node: LogicalOrdinality { ordinality_ref },
+                        location: LocationRequirement::Any,
+                        children: vec![plan],
+                        estimated_cardinality,
+                    })],
+                    estimated_cardinality,
+                }))
             }
             BoundFromItem::Subquery(subquery) => {
                 let plan = QueryPlanner.plan(bind_context, *subquery.subquery)?;
@@ -151,6 +208,30 @@ impl FromPlanner {
             BoundFromItem::MaterializedCte(mat_cte) => {
                 let cte = bind_context.get_cte(mat_cte.cte_ref)?;
 
+                // Without an explicit hint, only materialize CTEs that are
+                // referenced more than once.
+                let should_materialize = cte.materialized.unwrap_or(cte.num_references > 1);
+
+                if !should_materialize {
+                    let mut plan = self.plan(
+                        bind_context,
+                        BoundFrom {
+                            bind_ref: from.bind_ref,
+                            item: BoundFromItem::Subquery(BoundSubquery {
+                                table_ref: mat_cte.table_ref,
+                                subquery: cte.bound.clone(),
+                            }),
+                        },
+                    )?;
+
+                    // Every inlined reference is planned from the same bound
+                    // query. Give volatile functions in this copy their own
+                    // seeds so that references don't produce the same values.
+                    Self::reseed_volatile_functions(bind_context, &mut plan)?;
+
+                    return Ok(plan);
+                }
+
                 let mat_ref = match cte.mat_ref {
                     Some(mat_ref) => {
                         // Already have materialization, increment the scan
@@ -204,6 +285,27 @@ impl FromPlanner {
                     estimated_cardinality: StatisticsValue::Unknown,
                 }))
             }
+            BoundFromItem::WorkingTable(working) => {
+                let table = bind_context.get_table(working.table_ref)?;
+                let types = table.column_types.clone();
+                let names = table.column_names.clone();
+                let projection = (0..types.len()).collect();
+
+                Ok(LogicalOperator::Scan(Node {
+                    node: LogicalScan {
+                        table_ref: working.table_ref,
+                        types,
+                        names,
+                        projection,
+                        did_prune_columns: false,
+                        scan_filters: Vec::new(),
+                        source: ScanSource::WorkingTable,
+                    },
+                    location: LocationRequirement::Any,
+                    children: Vec::new(),
+                    estimated_cardinality: StatisticsValue::Unknown,
+                }))
+            }
             BoundFromItem::Empty => Ok(LogicalOperator::Empty(Node {
                 node: LogicalEmpty,
                 location: LocationRequirement::Any,
@@ -248,7 +350,7 @@ impl FromPlanner {
         if !extracted.left_filter.is_empty() {
             left = LogicalOperator::Filter(Node {
                 node: LogicalFilter {
-                    filter: expr::and(extracted.left_filter).expect("at least one expression"),
+                    filter: Expression::and(extracted.left_filter),
                 },
                 location: LocationRequirement::Any,
                 children: vec![left],
@@ -259,7 +361,7 @@ impl FromPlanner {
         if !extracted.right_filter.is_empty() {
             right = LogicalOperator::Filter(Node {
                 node: LogicalFilter {
-                    filter: expr::and(extracted.right_filter).expect("at least one expression"),
+                    filter: Expression::and(extracted.right_filter),
                 },
                 location: LocationRequirement::Any,
                 children: vec![right],
@@ -323,18 +425,13 @@ impl FromPlanner {
                 }));
             }
 
-            // Possible if we were able to push filters to left/right
-            // completely.
-            if expressions.is_empty() {
-                expressions.push(Expression::Literal(LiteralExpr {
-                    literal: ScalarValue::Boolean(true),
-                }));
-            }
-
+            // Expressions may be empty if we were able to push filters to
+            // left/right completely, in which case the condition is just
+            // `true`.
             return Ok(LogicalOperator::ArbitraryJoin(Node {
                 node: LogicalArbitraryJoin {
                     join_type,
-                    condition: expr::and(expressions).expect("at least one expression"),
+                    condition: Expression::and(expressions),
                 },
                 location: LocationRequirement::Any,
                 children: vec![left, right],
@@ -357,7 +454,7 @@ impl FromPlanner {
         if !arbitrary.is_empty() {
             plan = LogicalOperator::Filter(Node {
                 node: LogicalFilter {
-                    filter: expr::and(arbitrary).expect("at least one expression"),
+                    filter: Expression::and(arbitrary),
                 },
                 location: LocationRequirement::Any,
                 children: vec![plan],
@@ -367,4 +464,33 @@ impl FromPlanner {
 
         Ok(plan)
     }
+
+    /// Replan all volatile functions in the plan using new seeds from the bind
+    /// context.
+    fn reseed_volatile_functions(
+        bind_context: &mut BindContext,
+        plan: &mut LogicalOperator,
+    ) -> Result<()> {
+        fn reseed_expr(bind_context: &mut BindContext, expr: &mut Expression) -> Result<()> {
+            if let Expression::ScalarFunction(func) = expr {
+                if func.function.function.volatility() == FunctionVolatility::Volatile {
+                    let seed = bind_context.next_function_seed();
+                    let inputs = std::mem::take(&mut func.function.inputs);
+                    func.function = func.function.function.plan_with_seed(
+                        bind_context.get_table_list(),
+                        inputs,
+                        seed,
+                    )?;
+                }
+            }
+            expr.for_each_child_mut(&mut |child| reseed_expr(bind_context, child))
+        }
+
+        plan.for_each_expr_mut(&mut |expr| reseed_expr(bind_context, expr))?;
+        for child in plan.children_mut() {
+            Self::reseed_volatile_functions(bind_context, child)?;
+        }
+
+        Ok(())
+    }
 }
@@ -1,9 +1,15 @@
 use rayexec_error::Result;
 
 use super::plan_unnest::UnnestPlanner;
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::Expression;
 use crate::logical::binder::bind_context::BindContext;
+use crate::logical::binder::bind_query::bind_modifier::{BoundOrderBy, BoundOrderByExpr};
 use crate::logical::binder::bind_query::bind_select::BoundSelect;
+use crate::logical::binder::bind_query::select_list::BoundSelectList;
+use crate::logical::binder::table_list::TableRef;
 use crate::logical::logical_aggregate::LogicalAggregate;
+use crate::logical::logical_distinct::LogicalDistinct;
 use crate::logical::logical_filter::LogicalFilter;
 use crate::logical::logical_limit::LogicalLimit;
 use crate::logical::logical_order::LogicalOrder;
@@ -37,6 +43,13 @@ impl SelectPlanner {
             });
         }
 
+        // Group keys to sort the aggregate output on, only used when GROUP BY
+        // output should be sorted.
+        let mut group_sort_keys = None;
+        // If the sort on the group keys already produces the order requested
+        // by ORDER BY, letting us skip planning a separate sort for it.
+        let mut order_by_satisfied = false;
+
         // Handle GROUP BY/aggregates
         if !select.select_list.aggregates.is_empty() || select.group_by.is_some() {
             let (mut group_exprs, group_table, grouping_sets) = match select.group_by {
@@ -48,6 +61,33 @@ impl SelectPlanner {
                 None => (Vec::new(), None, None),
             };
 
+            // An ORDER BY in the query decides the final output order. If it
+            // only orders on the group keys, the sort on the group keys
+            // satisfies it. Otherwise there's no need for a separate sort on
+            // the group keys.
+            if bind_context.sort_group_by_output() {
+                if let Some(group_table) = group_table {
+                    let num_keys = group_exprs.len();
+                    match &select.order_by {
+                        None => group_sort_keys = Some((group_table, num_keys)),
+                        Some(order_by)
+                            if !select.distinct
+                                && select.select_list.windows.is_empty()
+                                && order_by_matches_group_keys(
+                                    order_by,
+                                    &select.select_list,
+                                    group_table,
+                                    num_keys,
+                                ) =>
+                        {
+                            group_sort_keys = Some((group_table, num_keys));
+                            order_by_satisfied = true;
+                        }
+                        Some(_) => (),
+                    }
+                }
+            }
+
             for expr in &mut group_exprs {
                 plan = SubqueryPlanner.plan_expression(bind_context, expr, plan)?;
             }
@@ -96,6 +136,25 @@ impl SelectPlanner {
             })
         }
 
+        // Sort on the group keys if requested. Projections above this keep the
+        // order.
+        if let Some((group_table, num_keys)) = group_sort_keys {
+            plan = LogicalOperator::Order(Node {
+                node: LogicalOrder {
+                    exprs: (0..num_keys)
+                        .map(|col| BoundOrderByExpr {
+                            expr: Expression::Column(ColumnExpr::new(group_table, col)),
+                            desc: false,
+                            nulls_first: false,
+                        })
+                        .collect(),
+                },
+                location: LocationRequirement::Any,
+                children: vec![plan],
+                estimated_cardinality: StatisticsValue::Unknown,
+            });
+        }
+
         // Handle windows
         if !select.select_list.windows.is_empty() {
             for expr in &mut select.select_list.windows {
@@ -117,6 +176,7 @@ impl SelectPlanner {
             plan = SubqueryPlanner.plan_expression(bind_context, expr, plan)?;
         }
 
+        let num_projections = select.select_list.projections.len();
         plan = LogicalOperator::Project(Node {
             node: LogicalProject {
                 projections: select.select_list.projections,
@@ -129,8 +189,39 @@ impl SelectPlanner {
         // Handle possible UNNESTing.
         plan = UnnestPlanner.plan_unnests(bind_context, plan)?;
 
+        // Handle DISTINCT
+        if select.distinct {
+            plan = LogicalOperator::Distinct(Node {
+                node: LogicalDistinct {
+                    on: (0..num_projections)
+                        .map(|col| {
+                            Expression::Column(ColumnExpr::new(
+                                select.select_list.projections_table,
+                                col,
+                            ))
+                        })
+                        .collect(),
+                },
+                location: LocationRequirement::Any,
+                children: vec![plan],
+                estimated_cardinality: StatisticsValue::Unknown,
+            });
+        }
+
+        // Sort keys for determining ties if using WITH TIES.
+        let with_ties = match (&select.limit, &select.order_by) {
+            (Some(limit), Some(order_by)) if limit.with_ties => Some(
+                order_by
+                    .exprs
+                    .iter()
+                    .map(|expr| expr.expr.clone())
+                    .collect(),
+            ),
+            _ => None,
+        };
+
         // Handle ORDER BY
-        if let Some(order_by) = select.order_by {
+        if let Some(order_by) = select.order_by.filter(|_| !order_by_satisfied) {
             plan = LogicalOperator::Order(Node {
                 node: LogicalOrder {
                     exprs: order_by.exprs,
@@ -147,6 +238,7 @@ impl SelectPlanner {
                 node: LogicalLimit {
                     offset: limit.offset,
                     limit: limit.limit,
+                    with_ties,
                 },
                 location: LocationRequirement::Any,
                 children: vec![plan],
@@ -170,3 +262,39 @@ impl SelectPlanner {
         Ok(plan)
     }
 }
+
+/// Check if sorting on the group keys produces the order requested by ORDER
+/// BY.
+///
+/// Each ORDER BY expression needs to be the group key in the same position,
+/// sorted ascending with NULLs last to match the group key sort. Ordering on a
+/// prefix of the group keys is fine.
+fn order_by_matches_group_keys(
+    order_by: &BoundOrderBy,
+    select_list: &BoundSelectList,
+    group_table: TableRef,
+    num_keys: usize,
+) -> bool {
+    if order_by.exprs.len() > num_keys {
+        return false;
+    }
+
+    order_by.exprs.iter().enumerate().all(|(idx, order_expr)| {
+        if order_expr.desc || order_expr.nulls_first {
+            return false;
+        }
+
+        // ORDER BY expressions reference columns in the select list.
+        let expr = match &order_expr.expr {
+            Expression::Column(col) if col.table_scope == select_list.projections_table => {
+                match select_list.projections.get(col.column) {
+                    Some(expr) => expr,
+                    None => return false,
+                }
+            }
+            other => other,
+        };
+
+        matches!(expr, Expression::Column(col) if col.table_scope == group_table && col.column == idx)
+    })
+}
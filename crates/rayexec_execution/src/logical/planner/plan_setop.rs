@@ -48,12 +48,25 @@ impl SetOpPlanner {
                 kind: setop.kind,
                 all: setop.all,
                 table_ref: setop.setop_table,
+                recursive: setop.recursive,
             },
             location: LocationRequirement::Any,
             children: vec![left, right],
             estimated_cardinality: StatisticsValue::Unknown,
         });
 
+        // Sort keys for determining ties if using WITH TIES.
+        let with_ties = match (&setop.limit, &setop.order_by) {
+            (Some(limit), Some(order_by)) if limit.with_ties => Some(
+                order_by
+                    .exprs
+                    .iter()
+                    .map(|expr| expr.expr.clone())
+                    .collect(),
+            ),
+            _ => None,
+        };
+
         // Handle ORDER BY
         if let Some(order_by) = setop.order_by {
             plan = LogicalOperator::Order(Node {
@@ -72,6 +85,7 @@ impl SetOpPlanner {
                 node: LogicalLimit {
                     offset: limit.offset,
                     limit: limit.limit,
+                    with_ties,
                 },
                 location: LocationRequirement::Any,
                 children: vec![plan],
@@ -152,10 +166,11 @@ impl SetOpPlanner {
                 continue;
             }
 
-            cast_exprs.push(Expression::Cast(CastExpr {
-                to: need_type.clone(),
-                expr: Box::new(col_expr),
-            }));
+            cast_exprs.push(Expression::Cast(CastExpr::new_using_session(
+                bind_context,
+                col_expr,
+                need_type.clone(),
+            )));
         }
 
         Ok(cast_exprs)
@@ -24,6 +24,7 @@ impl StatementPlanner {
             BoundStatement::SetVar(plan) => Ok(LogicalOperator::SetVar(plan)),
             BoundStatement::ShowVar(plan) => Ok(LogicalOperator::ShowVar(plan)),
             BoundStatement::ResetVar(plan) => Ok(LogicalOperator::ResetVar(plan)),
+            BoundStatement::Transaction(plan) => Ok(LogicalOperator::Transaction(plan)),
             BoundStatement::Attach(BoundAttach::Database(plan)) => {
                 Ok(LogicalOperator::AttachDatabase(plan))
             }
@@ -13,6 +13,7 @@ use crate::expr::subquery_expr::{SubqueryExpr, SubqueryType};
 use crate::expr::Expression;
 use crate::functions::aggregate::builtin::count::Count;
 use crate::functions::aggregate::AggregateFunction;
+use crate::functions::scalar::builtin::arith::OverflowBehavior;
 use crate::logical::binder::bind_context::{BindContext, CorrelatedColumn, MaterializationRef};
 use crate::logical::logical_aggregate::LogicalAggregate;
 use crate::logical::logical_join::{
@@ -205,6 +206,7 @@ impl SubqueryPlanner {
                     visited_expr = Expression::Negate(NegateExpr {
                         op: NegateOperator::Not,
                         expr: Box::new(visited_expr),
+                        overflow: OverflowBehavior::default(),
                     })
                 }
 
@@ -362,7 +364,8 @@ impl SubqueryPlanner {
                 let subquery_plan = LogicalOperator::Limit(Node {
                     node: LogicalLimit {
                         offset: None,
-                        limit: 1,
+                        limit: Some(1),
+                        with_ties: None,
                     },
                     location: LocationRequirement::Any,
                     children: vec![subquery_plan],
@@ -438,6 +441,7 @@ impl SubqueryPlanner {
                                 )?,
                                 distinct: false,
                                 filter: None,
+                                ordering: None,
                             })],
                             group_table: None,
                             group_exprs: Vec::new(),
@@ -449,7 +453,8 @@ impl SubqueryPlanner {
                         children: vec![LogicalOperator::Limit(Node {
                             node: LogicalLimit {
                                 offset: None,
-                                limit: 1,
+                                limit: Some(1),
+                                with_ties: None,
                             },
                             location: LocationRequirement::Any,
                             children: vec![subquery_plan],
@@ -921,6 +926,7 @@ impl DependentJoinPushdown {
                     ScanSource::Table { .. }
                         | ScanSource::View { .. }
                         | ScanSource::TableFunction { .. }
+                        | ScanSource::WorkingTable
                 ) {
                     return Err(RayexecError::new(
                         "Unexpectedly reached scan node when pushing down dependent join",
@@ -224,6 +224,12 @@ impl<'a> ExpressionResolver<'a> {
                         values: resolved,
                     }
                 }
+                ast::Literal::Map { keys, values } => {
+                    let keys = Box::pin(self.resolve_expressions(keys, resolve_context)).await?;
+                    let values =
+                        Box::pin(self.resolve_expressions(values, resolve_context)).await?;
+                    ast::Literal::Map { keys, values }
+                }
             })),
             ast::Expr::Array(arr) => self.resolve_array(arr, resolve_context).await,
             ast::Expr::ArraySubscript { expr, subscript } => {
@@ -646,6 +652,15 @@ impl<'a> ExpressionResolver<'a> {
             Some(over) => Some(self.resolve_window_spec(over, resolve_context).await?),
             None => None,
         };
+
+        let mut within_group = Vec::with_capacity(func.within_group.len());
+        for order in func.within_group {
+            within_group.push(ast::OrderByNode {
+                typ: order.typ,
+                nulls: order.nulls,
+                expr: Box::pin(self.resolve_expression(order.expr, resolve_context)).await?,
+            });
+        }
         let args = Box::pin(self.resolve_function_args(func.args, resolve_context)).await?;
 
         let schema_ent = context
@@ -664,6 +679,7 @@ impl<'a> ExpressionResolver<'a> {
                 reference: resolve_idx,
                 distinct: func.distinct,
                 args,
+                within_group,
                 filter,
                 over,
             })));
@@ -682,6 +698,7 @@ impl<'a> ExpressionResolver<'a> {
                 reference: resolve_idx,
                 distinct: func.distinct,
                 args,
+                within_group,
                 filter,
                 over,
             })));
@@ -703,6 +720,7 @@ impl<'a> ExpressionResolver<'a> {
                 reference: resolve_idx,
                 distinct: func.distinct,
                 args,
+                within_group,
                 filter,
                 over,
             })));
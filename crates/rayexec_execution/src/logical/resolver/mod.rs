@@ -7,6 +7,7 @@ pub mod resolved_cte;
 pub mod resolved_function;
 pub mod resolved_table;
 pub mod resolved_table_function;
+pub mod search_path;
 
 use std::collections::HashMap;
 
@@ -23,6 +24,7 @@ use resolved_copy_to::ResolvedCopyTo;
 use resolved_cte::ResolvedCte;
 use resolved_table::ResolvedTableOrCteReference;
 use resolved_table_function::{ResolvedTableFunctionReference, UnresolvedTableFunctionReference};
+use search_path::SearchPath;
 use serde::{Deserialize, Serialize};
 
 use super::binder::constant_binder::ConstantBinder;
@@ -115,6 +117,8 @@ impl ResolveMode {
 #[derive(Debug)]
 pub struct ResolveConfig {
     pub enable_function_chaining: bool,
+    /// Schemas to search for unqualified table references.
+    pub search_path: SearchPath,
 }
 
 /// Resolves references in a raw SQL AST with entries in the catalog.
@@ -197,6 +201,7 @@ impl<'a> Resolver<'a> {
                 value: ExpressionResolver::new(&self)
                     .resolve_expression(set.value, &mut resolve_context)
                     .await?,
+                local: set.local,
             }),
             Statement::Show(show) => self.resolve_show(show, &mut resolve_context).await?,
             Statement::ResetVariable(reset) => Statement::ResetVariable(ast::ResetVariable {
@@ -211,6 +216,7 @@ impl<'a> Resolver<'a> {
                 Statement::Attach(self.resolve_attach(attach, &mut resolve_context).await?)
             }
             Statement::Detach(detach) => Statement::Detach(self.resolve_detach(detach).await?),
+            Statement::Transaction(transaction) => Statement::Transaction(transaction),
         };
 
         Ok((bound, resolve_context))
@@ -308,15 +314,17 @@ impl<'a> Resolver<'a> {
             ast::CopyToSource::Table(reference) => {
                 let table = match self.resolve_mode {
                     ResolveMode::Normal => {
-                        let table = NormalResolver::new(self.tx, self.context)
-                            .require_resolve_table_or_cte(&reference, resolve_context)
-                            .await?;
+                        let table =
+                            NormalResolver::new(self.tx, self.context, &self.config.search_path)
+                                .require_resolve_table_or_cte(&reference, resolve_context)
+                                .await?;
                         MaybeResolved::Resolved(table, LocationRequirement::ClientLocal)
                     }
                     ResolveMode::Hybrid => {
-                        let table = NormalResolver::new(self.tx, self.context)
-                            .resolve_table_or_cte(&reference, resolve_context)
-                            .await?;
+                        let table =
+                            NormalResolver::new(self.tx, self.context, &self.config.search_path)
+                                .resolve_table_or_cte(&reference, resolve_context)
+                                .await?;
 
                         match table {
                             MaybeResolvedTable::Resolved(table) => {
@@ -343,22 +351,23 @@ impl<'a> Resolver<'a> {
         let mut options = HashMap::with_capacity(copy_to.options.len());
         for opt in copy_to.options {
             let key = opt.key.into_normalized_string();
-            let expr = ExpressionResolver::new(self)
-                .resolve_expression(opt.val, resolve_context)
-                .await?;
-
-            let val = match expr {
-                ast::Expr::Literal(lit) => {
-                    BaseExpressionBinder::bind_literal(&lit)?.try_into_scalar()?
-                }
-                // Ident allows for example `(FORMAT parquet)`, the user doesn't need to quote parquet.
-                ast::Expr::Ident(ident) => {
-                    OwnedScalarValue::Utf8(ident.into_normalized_string().into())
+            let val = match opt.val {
+                // Tuples allow for lists of values, e.g. `(PARTITION_BY (year, month))`.
+                ast::Expr::Tuple(exprs) => {
+                    let mut vals = Vec::with_capacity(exprs.len());
+                    for expr in exprs {
+                        let expr = ExpressionResolver::new(self)
+                            .resolve_expression(expr, resolve_context)
+                            .await?;
+                        vals.push(Self::copy_to_option_value(expr)?);
+                    }
+                    OwnedScalarValue::List(vals)
                 }
-                other => {
-                    return Err(RayexecError::new(format!(
-                        "COPY TO options must be constant, got: {other:?}"
-                    )))
+                expr => {
+                    let expr = ExpressionResolver::new(self)
+                        .resolve_expression(expr, resolve_context)
+                        .await?;
+                    Self::copy_to_option_value(expr)?
                 }
             };
 
@@ -422,6 +431,21 @@ impl<'a> Resolver<'a> {
         })
     }
 
+    /// Convert a resolved COPY TO option expression into a constant value.
+    fn copy_to_option_value(expr: ast::Expr<ResolvedMeta>) -> Result<OwnedScalarValue> {
+        Ok(match expr {
+            ast::Expr::Literal(lit) => BaseExpressionBinder::bind_literal(&lit)?.try_into_scalar()?,
+            // Ident allows for example `(FORMAT parquet)`, the user doesn't need to quote parquet.
+            ast::Expr::Ident(ident) => OwnedScalarValue::Utf8(ident.into_normalized_string().into()),
+            ast::Expr::Nested(expr) => Self::copy_to_option_value(*expr)?,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "COPY TO options must be constant, got: {other:?}"
+                )))
+            }
+        })
+    }
+
     async fn resolve_drop(
         &self,
         drop: ast::DropStatement<Raw>,
@@ -558,13 +582,13 @@ impl<'a> Resolver<'a> {
     ) -> Result<ast::Insert<ResolvedMeta>> {
         let table = match self.resolve_mode {
             ResolveMode::Normal => {
-                let table = NormalResolver::new(self.tx, self.context)
+                let table = NormalResolver::new(self.tx, self.context, &self.config.search_path)
                     .require_resolve_table_or_cte(&insert.table, resolve_context)
                     .await?;
                 MaybeResolved::Resolved(table, LocationRequirement::ClientLocal)
             }
             ResolveMode::Hybrid => {
-                let table = NormalResolver::new(self.tx, self.context)
+                let table = NormalResolver::new(self.tx, self.context, &self.config.search_path)
                     .resolve_table_or_cte(&insert.table, resolve_context)
                     .await?;
 
@@ -655,7 +679,11 @@ impl<'a> Resolver<'a> {
                 ctes,
                 body,
                 order_by,
-                limit: ast::LimitModifier { limit, offset },
+                limit: ast::LimitModifier {
+                    limit,
+                    offset,
+                    with_ties: query.limit.with_ties,
+                },
             })
         }
 
@@ -709,13 +737,21 @@ impl<'a> Resolver<'a> {
         for cte in ctes.ctes.into_iter() {
             let depth = resolve_context.current_depth;
 
-            let resolved_body = Box::pin(self.resolve_query(*cte.body, resolve_context)).await?;
             let resolved_cte = ResolvedCte {
                 name: cte.alias.as_normalized_string(),
                 depth,
             };
 
-            resolve_context.push_cte(resolved_cte);
+            // A recursive CTE may reference itself, so it needs to be visible
+            // when resolving its own body.
+            let resolved_body = if ctes.recursive {
+                resolve_context.push_cte(resolved_cte);
+                Box::pin(self.resolve_query(*cte.body, resolve_context)).await?
+            } else {
+                let body = Box::pin(self.resolve_query(*cte.body, resolve_context)).await?;
+                resolve_context.push_cte(resolved_cte);
+                body
+            };
 
             resolved_ctes.push(ast::CommonTableExpr {
                 alias: cte.alias,
@@ -854,15 +890,17 @@ impl<'a> Resolver<'a> {
             ast::FromNodeBody::BaseTable(ast::FromBaseTable { reference }) => {
                 let table = match self.resolve_mode {
                     ResolveMode::Normal => {
-                        let table = NormalResolver::new(self.tx, self.context)
-                            .require_resolve_table_or_cte(&reference, resolve_context)
-                            .await?;
+                        let table =
+                            NormalResolver::new(self.tx, self.context, &self.config.search_path)
+                                .require_resolve_table_or_cte(&reference, resolve_context)
+                                .await?;
                         MaybeResolved::Resolved(table, LocationRequirement::ClientLocal)
                     }
                     ResolveMode::Hybrid => {
-                        let table = NormalResolver::new(self.tx, self.context)
-                            .resolve_table_or_cte(&reference, resolve_context)
-                            .await?;
+                        let table =
+                            NormalResolver::new(self.tx, self.context, &self.config.search_path)
+                                .resolve_table_or_cte(&reference, resolve_context)
+                                .await?;
 
                         match table {
                             MaybeResolvedTable::Resolved(table) => {
@@ -981,6 +1019,7 @@ impl<'a> Resolver<'a> {
                             lateral: false,
                             reference: resolve_idx,
                             args,
+                            with_ordinality: false,
                         })
                     }
                     None => {
@@ -994,6 +1033,7 @@ impl<'a> Resolver<'a> {
                 lateral,
                 reference,
                 args,
+                with_ordinality,
             }) => {
                 let args = Box::pin(
                     ExpressionResolver::new(self).resolve_function_args(args, resolve_context),
@@ -1002,8 +1042,9 @@ impl<'a> Resolver<'a> {
 
                 let function = match self.resolve_mode {
                     ResolveMode::Normal => {
-                        let function = NormalResolver::new(self.tx, self.context)
-                            .require_resolve_table_function(&reference)?;
+                        let function =
+                            NormalResolver::new(self.tx, self.context, &self.config.search_path)
+                                .require_resolve_table_function(&reference)?;
 
                         let resolved = match function.planner() {
                             TableFunctionPlanner::InOut(_) => {
@@ -1029,7 +1070,7 @@ impl<'a> Resolver<'a> {
                         MaybeResolved::Resolved(resolved, LocationRequirement::ClientLocal)
                     }
                     ResolveMode::Hybrid => {
-                        match NormalResolver::new(self.tx, self.context)
+                        match NormalResolver::new(self.tx, self.context, &self.config.search_path)
                             .resolve_table_function(&reference)?
                         {
                             Some(function) => {
@@ -1077,6 +1118,7 @@ impl<'a> Resolver<'a> {
                     lateral,
                     reference: resolve_idx,
                     args,
+                    with_ordinality,
                 })
             }
             ast::FromNodeBody::Join(ast::FromJoin {
@@ -1123,7 +1165,7 @@ impl<'a> Resolver<'a> {
             .collect()
     }
 
-    fn ast_datatype_to_exec_datatype(datatype: ast::DataType) -> Result<DataType> {
+    pub(crate) fn ast_datatype_to_exec_datatype(datatype: ast::DataType) -> Result<DataType> {
         Ok(match datatype {
             ast::DataType::Varchar(_) => DataType::Utf8,
             ast::DataType::TinyInt => DataType::Int8,
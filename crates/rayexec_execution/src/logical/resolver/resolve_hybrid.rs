@@ -13,6 +13,7 @@ use crate::database::{Database, DatabaseContext};
 use crate::datasource::{DataSourceRegistry, FileHandlers};
 use crate::functions::table::TableFunctionPlanner;
 use crate::logical::operator::LocationRequirement;
+use crate::logical::resolver::search_path::SearchPath;
 use crate::logical::resolver::{ResolveConfig, ResolveMode};
 
 /// Extends a context by attaching additional databases using information
@@ -146,6 +147,7 @@ impl<'a> HybridResolver<'a> {
                 EMPTY_FILE_HANDLER_REF,
                 ResolveConfig {
                     enable_function_chaining: true, // TODO: We'll need to get this from the client.
+                    search_path: SearchPath::default(), // TODO: Same as above.
                 },
             ),
         }
@@ -176,9 +178,13 @@ impl<'a> HybridResolver<'a> {
                 // CTE lookup, which shouldn't be possible here.
                 let empty = ResolveContext::default();
 
-                let table = NormalResolver::new(self.resolver.tx, self.resolver.context)
-                    .require_resolve_table_or_cte(&unresolved.reference, &empty)
-                    .await?;
+                let table = NormalResolver::new(
+                    self.resolver.tx,
+                    self.resolver.context,
+                    &self.resolver.config.search_path,
+                )
+                .require_resolve_table_or_cte(&unresolved.reference, &empty)
+                .await?;
 
                 debug!(%unresolved.reference, "(hybrid) resolved unbound table");
 
@@ -195,8 +201,12 @@ impl<'a> HybridResolver<'a> {
     ) -> Result<()> {
         for item in resolve_context.table_functions.inner.iter_mut() {
             if let MaybeResolved::Unresolved(unresolved) = item {
-                let function = NormalResolver::new(self.resolver.tx, self.resolver.context)
-                    .require_resolve_table_function(&unresolved.reference)?;
+                let function = NormalResolver::new(
+                    self.resolver.tx,
+                    self.resolver.context,
+                    &self.resolver.config.search_path,
+                )
+                .require_resolve_table_function(&unresolved.reference)?;
 
                 let resolved = match function.planner() {
                     TableFunctionPlanner::InOut(_) => {
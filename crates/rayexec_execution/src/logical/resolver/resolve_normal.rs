@@ -9,6 +9,7 @@ use super::resolved_table::{
     ResolvedTableReference,
     UnresolvedTableReference,
 };
+use super::search_path::SearchPath;
 use super::ResolveContext;
 use crate::database::catalog::CatalogTx;
 use crate::database::catalog_entry::{CatalogEntry, CatalogEntryType};
@@ -67,16 +68,24 @@ pub enum MaybeResolvedTable {
     Unresolved,
 }
 
-// TODO: Search path
 #[derive(Debug)]
 pub struct NormalResolver<'a> {
     pub tx: &'a CatalogTx,
     pub context: &'a DatabaseContext,
+    pub search_path: &'a SearchPath,
 }
 
 impl<'a> NormalResolver<'a> {
-    pub fn new(tx: &'a CatalogTx, context: &'a DatabaseContext) -> Self {
-        NormalResolver { tx, context }
+    pub fn new(
+        tx: &'a CatalogTx,
+        context: &'a DatabaseContext,
+        search_path: &'a SearchPath,
+    ) -> Self {
+        NormalResolver {
+            tx,
+            context,
+            search_path,
+        }
     }
 
     /// Resolve a table function.
@@ -144,7 +153,6 @@ impl<'a> NormalResolver<'a> {
         reference: &ast::ObjectReference,
         resolve_context: &ResolveContext,
     ) -> Result<MaybeResolvedTable> {
-        // TODO: Seach path.
         let [catalog, schema, table] = match reference.0.len() {
             1 => {
                 let name = reference.0[0].as_normalized_string();
@@ -156,8 +164,9 @@ impl<'a> NormalResolver<'a> {
                     ));
                 }
 
-                // Otherwise continue with trying to resolve from the catalogs.
-                ["temp".to_string(), "temp".to_string(), name]
+                // Otherwise continue with trying to resolve from the catalogs
+                // in the search path.
+                return self.resolve_table_from_search_path(reference, name).await;
             }
             2 => {
                 let table = reference.0[1].as_normalized_string();
@@ -177,6 +186,56 @@ impl<'a> NormalResolver<'a> {
             }
         };
 
+        self.resolve_qualified_table(reference, catalog, schema, table)
+            .await
+    }
+
+    /// Resolve an unqualified table by trying each schema in the search path
+    /// in order.
+    ///
+    /// Catalogs in the search path that don't exist are skipped.
+    async fn resolve_table_from_search_path(
+        &self,
+        reference: &ast::ObjectReference,
+        table: String,
+    ) -> Result<MaybeResolvedTable> {
+        let mut unresolved = MaybeResolvedTable::Unresolved;
+
+        for entry in &self.search_path.entries {
+            if !self.context.database_exists(&entry.catalog) {
+                continue;
+            }
+
+            let resolved = self
+                .resolve_qualified_table(
+                    reference,
+                    entry.catalog.clone(),
+                    entry.schema.clone(),
+                    table.clone(),
+                )
+                .await?;
+
+            match resolved {
+                MaybeResolvedTable::Resolved(_) => return Ok(resolved),
+                // Keep the first candidate catalog for hybrid resolution.
+                other => {
+                    if matches!(unresolved, MaybeResolvedTable::Unresolved) {
+                        unresolved = other;
+                    }
+                }
+            }
+        }
+
+        Ok(unresolved)
+    }
+
+    async fn resolve_qualified_table(
+        &self,
+        reference: &ast::ObjectReference,
+        catalog: String,
+        schema: String,
+        table: String,
+    ) -> Result<MaybeResolvedTable> {
         let database = self.context.get_database(&catalog)?;
 
         // Try reading from in-memory catalog first.
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use rayexec_error::{RayexecError, Result};
+
+/// Catalog used for search path entries that only specify a schema.
+const DEFAULT_CATALOG: &str = "temp";
+
+/// A single schema in the search path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPathEntry {
+    pub catalog: String,
+    pub schema: String,
+}
+
+/// Ordered list of schemas to search when resolving unqualified table
+/// references.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchPath {
+    pub entries: Vec<SearchPathEntry>,
+}
+
+impl Default for SearchPath {
+    fn default() -> Self {
+        SearchPath {
+            entries: vec![SearchPathEntry {
+                catalog: DEFAULT_CATALOG.to_string(),
+                schema: "temp".to_string(),
+            }],
+        }
+    }
+}
+
+impl SearchPath {
+    /// Parse a comma separated list of schemas, e.g. 'my_schema, other.public'.
+    ///
+    /// Each entry is either a schema in the default catalog, or a
+    /// `catalog.schema` pair. Identifiers follow the normal rules, being
+    /// lowercased unless double quoted.
+    pub fn parse(s: &str) -> Result<Self> {
+        let entries = s
+            .split(',')
+            .map(|entry| {
+                let idents = entry
+                    .split('.')
+                    .map(normalize_ident)
+                    .collect::<Result<Vec<_>>>()?;
+
+                match idents.as_slice() {
+                    [schema] => Ok(SearchPathEntry {
+                        catalog: DEFAULT_CATALOG.to_string(),
+                        schema: schema.clone(),
+                    }),
+                    [catalog, schema] => Ok(SearchPathEntry {
+                        catalog: catalog.clone(),
+                        schema: schema.clone(),
+                    }),
+                    _ => Err(RayexecError::new(format!(
+                        "Invalid search path entry: '{}'",
+                        entry.trim()
+                    ))),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(SearchPath { entries })
+    }
+}
+
+impl fmt::Display for SearchPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, entry) in self.entries.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}.{}", entry.catalog, entry.schema)?;
+        }
+        Ok(())
+    }
+}
+
+fn normalize_ident(ident: &str) -> Result<String> {
+    let ident = ident.trim();
+    if ident.is_empty() {
+        return Err(RayexecError::new("Empty identifier in search path"));
+    }
+
+    match ident
+        .strip_prefix('"')
+        .and_then(|ident| ident.strip_suffix('"'))
+    {
+        Some(quoted) => Ok(quoted.to_string()),
+        None => Ok(ident.to_lowercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(catalog: &str, schema: &str) -> SearchPathEntry {
+        SearchPathEntry {
+            catalog: catalog.to_string(),
+            schema: schema.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_search_path() {
+        let path = SearchPath::parse("a, MyDb.B ,\"Quoted\"").unwrap();
+        let expected = vec![
+            entry("temp", "a"),
+            entry("mydb", "b"),
+            entry("temp", "Quoted"),
+        ];
+        assert_eq!(expected, path.entries);
+        assert_eq!("temp.a, mydb.b, temp.Quoted", path.to_string());
+    }
+
+    #[test]
+    fn parse_search_path_invalid() {
+        SearchPath::parse("").unwrap_err();
+        SearchPath::parse("a,,b").unwrap_err();
+        SearchPath::parse("a.b.c").unwrap_err();
+    }
+}
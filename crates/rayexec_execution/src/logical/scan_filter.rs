@@ -1,5 +1,7 @@
-use crate::arrays::scalar::OwnedScalarValue;
+use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::expr::comparison_expr::ComparisonOperator;
+use crate::expr::Expression;
+use crate::logical::binder::table_list::TableRef;
 
 /// A simplified filter that can be pushed into a scan.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -12,6 +14,45 @@ pub struct ScanFilter {
     pub filter: ScanFilterType,
 }
 
+impl ScanFilter {
+    /// Try to create a scan filter from a `column <op> constant` (or `constant
+    /// <op> column`) comparison referencing the output of a scan.
+    ///
+    /// `projection` maps the scan's output columns to columns in the source.
+    ///
+    /// Returns None if the expression can't be represented as a scan filter.
+    pub fn try_from_expr(
+        table_ref: TableRef,
+        projection: &[usize],
+        expr: &Expression,
+    ) -> Option<Self> {
+        let cmp = match expr {
+            Expression::Comparison(cmp) => cmp,
+            _ => return None,
+        };
+
+        let (col, constant, op) = match (cmp.left.as_ref(), cmp.right.as_ref()) {
+            (Expression::Column(col), Expression::Literal(lit)) => (col, &lit.literal, cmp.op),
+            (Expression::Literal(lit), Expression::Column(col)) => {
+                (col, &lit.literal, cmp.op.flip())
+            }
+            _ => return None,
+        };
+
+        if col.table_scope != table_ref || constant == &ScalarValue::Null {
+            return None;
+        }
+
+        Some(ScanFilter {
+            column: *projection.get(col.column)?,
+            filter: ScanFilterType::ConstComparison {
+                op,
+                constant: constant.clone(),
+            },
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScanFilterType {
     ConstComparison {
@@ -24,3 +65,70 @@ pub enum ScanFilterType {
         constant: OwnedScalarValue,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+
+    #[test]
+    fn from_column_comparison() {
+        let filter = ScanFilter::try_from_expr(
+            0.into(),
+            &[0, 2, 3],
+            &expr::gt(expr::col_ref(0, 1), expr::lit(5_i64)),
+        )
+        .unwrap();
+
+        let expected = ScanFilter {
+            column: 2,
+            filter: ScanFilterType::ConstComparison {
+                op: ComparisonOperator::Gt,
+                constant: ScalarValue::Int64(5),
+            },
+        };
+        assert_eq!(expected, filter);
+    }
+
+    #[test]
+    fn from_flipped_comparison() {
+        let filter = ScanFilter::try_from_expr(
+            0.into(),
+            &[0, 1],
+            &expr::lt(expr::lit(5_i64), expr::col_ref(0, 0)),
+        )
+        .unwrap();
+
+        let expected = ScanFilter {
+            column: 0,
+            filter: ScanFilterType::ConstComparison {
+                op: ComparisonOperator::Gt,
+                constant: ScalarValue::Int64(5),
+            },
+        };
+        assert_eq!(expected, filter);
+    }
+
+    #[test]
+    fn from_unsupported_exprs() {
+        // Different table.
+        let filter = ScanFilter::try_from_expr(
+            1.into(),
+            &[0, 1],
+            &expr::gt(expr::col_ref(0, 0), expr::lit(5_i64)),
+        );
+        assert_eq!(None, filter);
+
+        // Column to column.
+        let filter = ScanFilter::try_from_expr(
+            0.into(),
+            &[0, 1],
+            &expr::gt(expr::col_ref(0, 0), expr::col_ref(0, 1)),
+        );
+        assert_eq!(None, filter);
+
+        // Not a comparison.
+        let filter = ScanFilter::try_from_expr(0.into(), &[0, 1], &expr::col_ref(0, 0));
+        assert_eq!(None, filter);
+    }
+}
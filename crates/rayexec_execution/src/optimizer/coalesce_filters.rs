@@ -0,0 +1,106 @@
+use rayexec_error::Result;
+
+use super::OptimizeRule;
+use crate::expr::Expression;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::logical_filter::LogicalFilter;
+use crate::logical::operator::{LogicalOperator, Node};
+
+/// Combine a filter directly above another filter into a single filter with
+/// the predicates ANDed together.
+#[derive(Debug)]
+pub struct CoalesceFilters;
+
+impl OptimizeRule for CoalesceFilters {
+    fn optimize(
+        &mut self,
+        _bind_context: &mut BindContext,
+        mut plan: LogicalOperator,
+    ) -> Result<LogicalOperator> {
+        // Children first, a chain of filters below us will already be
+        // coalesced into a single filter.
+        plan.modify_replace_children(&mut |child| self.optimize(_bind_context, child))?;
+
+        match plan {
+            LogicalOperator::Filter(mut filter)
+                if matches!(filter.children.as_slice(), [LogicalOperator::Filter(_)]) =>
+            {
+                let child = match filter.children.pop() {
+                    Some(LogicalOperator::Filter(child)) => child,
+                    _ => unreachable!(),
+                };
+
+                // Child predicate first to keep the original evaluation order.
+                Ok(LogicalOperator::Filter(Node {
+                    node: LogicalFilter {
+                        filter: Expression::and([child.node.filter, filter.node.filter]),
+                    },
+                    location: filter.location,
+                    children: child.children,
+                    estimated_cardinality: filter.estimated_cardinality,
+                }))
+            }
+            other => Ok(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::logical::operator::LocationRequirement;
+    use crate::logical::statistics::StatisticsValue;
+
+    fn filter(predicate: Expression, child: LogicalOperator) -> LogicalOperator {
+        LogicalOperator::Filter(Node {
+            node: LogicalFilter { filter: predicate },
+            location: LocationRequirement::Any,
+            children: vec![child],
+            estimated_cardinality: StatisticsValue::Unknown,
+        })
+    }
+
+    #[test]
+    fn coalesce_two_filters() {
+        let a = expr::eq(expr::col_ref(0, 0), expr::lit(1));
+        let b = expr::gt(expr::col_ref(0, 1), expr::lit(2));
+
+        let plan = filter(b.clone(), filter(a.clone(), LogicalOperator::EMPTY));
+        let mut bind_context = BindContext::new();
+        let optimized = CoalesceFilters.optimize(&mut bind_context, plan).unwrap();
+
+        let expected = filter(Expression::and([a, b]), LogicalOperator::EMPTY);
+        assert_eq!(expected, optimized);
+    }
+
+    #[test]
+    fn coalesce_filter_chain() {
+        let a = expr::eq(expr::col_ref(0, 0), expr::lit(1));
+        let b = expr::gt(expr::col_ref(0, 1), expr::lit(2));
+        let c = expr::lt(expr::col_ref(0, 2), expr::lit(3));
+
+        let plan = filter(
+            c.clone(),
+            filter(b.clone(), filter(a.clone(), LogicalOperator::EMPTY)),
+        );
+        let mut bind_context = BindContext::new();
+        let optimized = CoalesceFilters.optimize(&mut bind_context, plan).unwrap();
+
+        // Conjunctions are flattened.
+        let expected = filter(Expression::and([a, b, c]), LogicalOperator::EMPTY);
+        assert_eq!(expected, optimized);
+    }
+
+    #[test]
+    fn single_filter_unchanged() {
+        let a = expr::eq(expr::col_ref(0, 0), expr::lit(1));
+        let plan = filter(a, LogicalOperator::EMPTY);
+
+        let mut bind_context = BindContext::new();
+        let optimized = CoalesceFilters
+            .optimize(&mut bind_context, plan.clone())
+            .unwrap();
+        assert_eq!(plan, optimized);
+    }
+}
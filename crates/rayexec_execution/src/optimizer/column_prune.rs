@@ -2,6 +2,7 @@ use std::collections::{BTreeSet, HashMap, HashSet};
 
 use rayexec_error::{RayexecError, Result};
 
+use super::merge_projections::try_merge_projections;
 use super::OptimizeRule;
 use crate::expr::column_expr::ColumnExpr;
 use crate::expr::Expression;
@@ -79,7 +80,7 @@ impl MagicScanColumnReplacer<'_> {
             LogicalOperator::MagicMaterializationScan(scan) if scan.node.mat == self.mat => {
                 // Magic scan matches, replace columns as necessary.
                 for proj in &mut scan.node.projections {
-                    replace_column_reference(proj, self.updated);
+                    proj.replace_column_references(self.updated);
                 }
             }
             other => {
@@ -142,7 +143,7 @@ impl PruneState {
     /// Replaces and outdated column refs in the plan at this node.
     fn apply_updated_expressions(&self, plan: &mut impl LogicalNode) -> Result<()> {
         plan.for_each_expr_mut(&mut |expr| {
-            replace_column_reference(expr, &self.updated_expressions);
+            expr.replace_column_references(&self.updated_expressions);
             Ok(())
         })
     }
@@ -586,11 +587,15 @@ fn projection_is_passthrough(
     proj: &Node<LogicalProject>,
     bind_context: &BindContext,
 ) -> Result<bool> {
-    let child_ref = match proj
-        .get_one_child_exact()?
-        .get_output_table_refs(bind_context)
-        .first()
-    {
+    let child = proj.get_one_child_exact()?;
+
+    // Every scan of a materialization outputs the same table refs, the project
+    // on top of it is what lets multiple scans be distinguished.
+    if matches!(child, LogicalOperator::MaterializationScan(_)) {
+        return Ok(false);
+    }
+
+    let child_ref = match child.get_output_table_refs(bind_context).first() {
         Some(table_ref) => *table_ref,
         None => return Ok(false),
     };
@@ -615,73 +620,24 @@ fn projection_is_passthrough(
 
 /// Recursively try to flatten this projection into a child projection.
 ///
-/// If the projection's child is not a projection, nothing it done.
+/// If the projection's child is not a projection, nothing it done. See
+/// `try_merge_projections` for when projections are able to be merged.
 ///
 /// This does not change the table ref of this projection, and all column
 /// references that reference this projection remain valid.
 fn try_flatten_projection(current: &mut Node<LogicalProject>) -> Result<()> {
     assert_eq!(1, current.children.len());
 
-    if !current.children[0].is_project() {
-        // Not a project, nothing to do.
-        return Ok(());
-    }
-
-    let mut child_projection = match current.take_one_child_exact()? {
-        LogicalOperator::Project(project) => project,
-        _ => unreachable!("operator has to be a project"),
-    };
-
     // Try flattening child project first.
-    try_flatten_projection(&mut child_projection)?;
-
-    // Generate old -> new expression map from the child. We'll walk the parent
-    // expression and just replace the old references.
-    let expr_map: HashMap<ColumnExpr, Expression> = child_projection
-        .node
-        .projections
-        .into_iter()
-        .enumerate()
-        .map(|(col_idx, expr)| {
-            (
-                ColumnExpr {
-                    table_scope: child_projection.node.projection_table,
-                    column: col_idx,
-                },
-                expr,
-            )
-        })
-        .collect();
-
-    current.for_each_expr_mut(&mut |expr| {
-        replace_column_reference(expr, &expr_map);
-        Ok(())
-    })?;
+    if let LogicalOperator::Project(child_projection) = &mut current.children[0] {
+        try_flatten_projection(child_projection)?;
+    }
 
-    // Set this projection's children the child projection's children.
-    current.children = child_projection.children;
+    try_merge_projections(current)?;
 
     Ok(())
 }
 
-/// Replace all column references in the expression map with the associated
-/// expression.
-fn replace_column_reference(expr: &mut Expression, mapping: &HashMap<ColumnExpr, Expression>) {
-    match expr {
-        Expression::Column(col) => {
-            if let Some(replace) = mapping.get(col) {
-                *expr = replace.clone()
-            }
-        }
-        other => other
-            .for_each_child_mut(&mut |child| {
-                replace_column_reference(child, mapping);
-                Ok(())
-            })
-            .expect("replace to not fail"),
-    }
-}
-
 fn extract_column_exprs(expr: &Expression, refs: &mut HashSet<ColumnExpr>) {
     match expr {
         Expression::Column(col) => {
@@ -0,0 +1,259 @@
+use rayexec_error::Result;
+
+use super::OptimizeRule;
+use crate::arrays::scalar::ScalarValue;
+use crate::expr::Expression;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::logical_join::JoinType;
+use crate::logical::logical_no_rows::LogicalNoRows;
+use crate::logical::operator::{LocationRequirement, LogicalNode, LogicalOperator, Node};
+use crate::logical::statistics::StatisticsValue;
+
+/// Replace operators that are statically known to produce no rows with a
+/// `NoRows` operator, pruning the subtree below it.
+///
+/// An operator produces no rows if it's a filter with a constant false (or
+/// NULL) predicate, a `LIMIT 0`, or if it has an input that produces no rows
+/// and doesn't emit rows on its own in that case. Outer joins only collapse if
+/// their preserved side is empty.
+///
+/// Subtrees containing materialization scans are left alone since the
+/// materialization expects a fixed number of scans.
+#[derive(Debug)]
+pub struct EmptyPropagation;
+
+impl OptimizeRule for EmptyPropagation {
+    fn optimize(
+        &mut self,
+        bind_context: &mut BindContext,
+        mut plan: LogicalOperator,
+    ) -> Result<LogicalOperator> {
+        // Children first so that empty inputs propagate up through the plan.
+        plan.modify_replace_children(&mut |child| self.optimize(bind_context, child))?;
+
+        if !produces_no_rows(&plan) || contains_materialization_scan(&plan) {
+            return Ok(plan);
+        }
+
+        Ok(LogicalOperator::NoRows(Node {
+            node: LogicalNoRows {
+                table_refs: plan.get_output_table_refs(bind_context),
+            },
+            location: LocationRequirement::Any,
+            children: Vec::new(),
+            estimated_cardinality: StatisticsValue::Exact(0),
+        }))
+    }
+}
+
+/// Check if this operator is known to produce no rows, assuming its children
+/// have already been optimized.
+fn produces_no_rows(plan: &LogicalOperator) -> bool {
+    match plan {
+        LogicalOperator::Filter(filter) => {
+            is_false_or_null(&filter.node.filter) || any_child_no_rows(plan)
+        }
+        LogicalOperator::Limit(limit) => limit.node.limit == Some(0) || any_child_no_rows(plan),
+        LogicalOperator::TopN(top_n) => top_n.node.limit == 0 || any_child_no_rows(plan),
+        LogicalOperator::Project(_)
+        | LogicalOperator::Order(_)
+        | LogicalOperator::Distinct(_)
+        | LogicalOperator::Unnest(_)
+        | LogicalOperator::Window(_)
+        | LogicalOperator::CrossJoin(_) => any_child_no_rows(plan),
+        LogicalOperator::Aggregate(agg) => {
+            // An aggregate without groups (or with an empty grouping set)
+            // produces a row even with no input.
+            let all_grouped = match &agg.node.grouping_sets {
+                Some(sets) => !sets.is_empty() && sets.iter().all(|set| !set.is_empty()),
+                None => false,
+            };
+            all_grouped && any_child_no_rows(plan)
+        }
+        LogicalOperator::ComparisonJoin(join) => join_produces_no_rows(join.node.join_type, plan),
+        LogicalOperator::ArbitraryJoin(join) => join_produces_no_rows(join.node.join_type, plan),
+        _ => false,
+    }
+}
+
+fn join_produces_no_rows(join_type: JoinType, plan: &LogicalOperator) -> bool {
+    let (left, right) = match plan.children() {
+        [left, right] => (is_no_rows(left), is_no_rows(right)),
+        _ => return false,
+    };
+
+    match join_type {
+        JoinType::Inner | JoinType::Semi => left || right,
+        JoinType::Left | JoinType::Anti | JoinType::LeftMark { .. } => left,
+        JoinType::Right => right,
+        JoinType::Full => left && right,
+    }
+}
+
+fn any_child_no_rows(plan: &LogicalOperator) -> bool {
+    plan.children().iter().any(is_no_rows)
+}
+
+fn is_no_rows(plan: &LogicalOperator) -> bool {
+    matches!(plan, LogicalOperator::NoRows(_))
+}
+
+fn is_false_or_null(expr: &Expression) -> bool {
+    matches!(
+        expr,
+        Expression::Literal(lit)
+            if matches!(lit.literal, ScalarValue::Boolean(false) | ScalarValue::Null)
+    )
+}
+
+fn contains_materialization_scan(plan: &LogicalOperator) -> bool {
+    match plan {
+        LogicalOperator::MaterializationScan(_) | LogicalOperator::MagicMaterializationScan(_) => {
+            true
+        }
+        other => other.children().iter().any(contains_materialization_scan),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::expr::comparison_expr::ComparisonOperator;
+    use crate::logical::binder::table_list::TableRef;
+    use crate::logical::logical_filter::LogicalFilter;
+    use crate::logical::logical_join::{ComparisonCondition, LogicalComparisonJoin};
+    use crate::logical::logical_limit::LogicalLimit;
+    use crate::logical::logical_project::LogicalProject;
+
+    fn node<N>(node: N, children: Vec<LogicalOperator>) -> Node<N> {
+        Node {
+            node,
+            location: LocationRequirement::Any,
+            children,
+            estimated_cardinality: StatisticsValue::Unknown,
+        }
+    }
+
+    fn project(table: impl Into<TableRef>, child: LogicalOperator) -> LogicalOperator {
+        LogicalOperator::Project(node(
+            LogicalProject {
+                projections: vec![expr::lit(1)],
+                projection_table: table.into(),
+            },
+            vec![child],
+        ))
+    }
+
+    fn filter(predicate: Expression, child: LogicalOperator) -> LogicalOperator {
+        LogicalOperator::Filter(node(LogicalFilter { filter: predicate }, vec![child]))
+    }
+
+    fn join(join_type: JoinType, left: LogicalOperator, right: LogicalOperator) -> LogicalOperator {
+        LogicalOperator::ComparisonJoin(node(
+            LogicalComparisonJoin {
+                join_type,
+                conditions: vec![ComparisonCondition {
+                    left: expr::col_ref(0, 0),
+                    right: expr::col_ref(1, 0),
+                    op: ComparisonOperator::Eq,
+                }],
+            },
+            vec![left, right],
+        ))
+    }
+
+    fn no_rows(table_refs: impl IntoIterator<Item = usize>) -> LogicalOperator {
+        LogicalOperator::NoRows(Node {
+            node: LogicalNoRows {
+                table_refs: table_refs.into_iter().map(TableRef::from).collect(),
+            },
+            location: LocationRequirement::Any,
+            children: Vec::new(),
+            estimated_cardinality: StatisticsValue::Exact(0),
+        })
+    }
+
+    #[test]
+    fn where_false_collapses() {
+        // project(filter(false, project))
+        let plan = project(
+            1,
+            filter(expr::lit(false), project(0, LogicalOperator::EMPTY)),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = EmptyPropagation.optimize(&mut bind_context, plan).unwrap();
+
+        assert_eq!(no_rows([1]), optimized);
+    }
+
+    #[test]
+    fn limit_zero_collapses() {
+        // project(limit 0 (project))
+        let plan = project(
+            1,
+            LogicalOperator::Limit(node(
+                LogicalLimit {
+                    offset: None,
+                    limit: Some(0),
+                    with_ties: None,
+                },
+                vec![project(0, LogicalOperator::EMPTY)],
+            )),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = EmptyPropagation.optimize(&mut bind_context, plan).unwrap();
+
+        assert_eq!(no_rows([1]), optimized);
+    }
+
+    #[test]
+    fn inner_join_with_empty_side_collapses() {
+        let plan = join(
+            JoinType::Inner,
+            project(0, LogicalOperator::EMPTY),
+            filter(expr::lit(false), project(1, LogicalOperator::EMPTY)),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = EmptyPropagation.optimize(&mut bind_context, plan).unwrap();
+
+        assert_eq!(no_rows([0, 1]), optimized);
+    }
+
+    #[test]
+    fn left_join_preserves_left_side() {
+        let plan = join(
+            JoinType::Left,
+            project(0, LogicalOperator::EMPTY),
+            filter(expr::lit(false), project(1, LogicalOperator::EMPTY)),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = EmptyPropagation.optimize(&mut bind_context, plan).unwrap();
+
+        let expected = join(
+            JoinType::Left,
+            project(0, LogicalOperator::EMPTY),
+            no_rows([1]),
+        );
+        assert_eq!(expected, optimized);
+    }
+
+    #[test]
+    fn filter_non_constant_unchanged() {
+        let plan = filter(
+            expr::eq(expr::col_ref(0, 0), expr::lit(1)),
+            project(0, LogicalOperator::EMPTY),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = EmptyPropagation
+            .optimize(&mut bind_context, plan.clone())
+            .unwrap();
+
+        assert_eq!(plan, optimized);
+    }
+}
@@ -2,6 +2,7 @@ use rayexec_error::{RayexecError, Result};
 
 use super::ExpressionRewriteRule;
 use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
 use crate::expr::literal_expr::LiteralExpr;
 use crate::expr::physical::planner::PhysicalExpressionPlanner;
 use crate::expr::Expression;
@@ -18,12 +19,23 @@ impl ExpressionRewriteRule for ConstFold {
     }
 }
 
+/// Struct and map scalars don't retain their full types (field names, key and
+/// value types), so folding would lose type information. Children may still be
+/// folded.
+fn is_foldable_datatype(datatype: &DataType) -> bool {
+    match datatype {
+        DataType::Struct(_) | DataType::Map(_) => false,
+        DataType::List(list) => is_foldable_datatype(&list.datatype),
+        _ => true,
+    }
+}
+
 fn maybe_fold(table_list: &TableList, expr: &mut Expression) -> Result<()> {
     if matches!(expr, Expression::Literal(_)) {
         return Ok(());
     }
 
-    if expr.is_const_foldable() {
+    if expr.is_const_foldable() && is_foldable_datatype(&expr.datatype(table_list)?) {
         let planner = PhysicalExpressionPlanner::new(table_list);
         let phys_expr = planner.plan_scalar(&[], expr)?;
         let dummy = Batch::empty_with_num_rows(1);
@@ -59,7 +71,6 @@ fn maybe_fold(table_list: &TableList, expr: &mut Expression) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::arrays::datatype::DataType;
     use crate::expr::{add, and, cast, col_ref, lit};
 
     #[test]
@@ -224,7 +224,12 @@ where
                                 continue;
                             }
 
-                            // TODO: Do we need to push to arbitrary here?
+                            // One side of the comparison references both sides
+                            // of the join, needs to be evaluated as an
+                            // arbitrary expression.
+                            extracted
+                                .arbitrary
+                                .push(Expression::Comparison(ComparisonExpr { left, right, op }));
                         }
                         other => {
                             extracted.arbitrary.push(other);
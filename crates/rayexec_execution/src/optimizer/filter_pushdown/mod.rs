@@ -26,8 +26,10 @@ use crate::logical::logical_join::{
 use crate::logical::logical_materialization::LogicalMaterializationScan;
 use crate::logical::logical_order::LogicalOrder;
 use crate::logical::logical_project::LogicalProject;
+use crate::logical::logical_scan::{LogicalScan, ScanSource};
 use crate::logical::operator::{LocationRequirement, LogicalNode, LogicalOperator, Node};
 use crate::logical::planner::plan_from::FromPlanner;
+use crate::logical::scan_filter::ScanFilter;
 use crate::logical::statistics::StatisticsValue;
 
 // TODO: ExtractedFilter seems to not be entirely worth it here. There's
@@ -61,6 +63,7 @@ impl OptimizeRule for FilterPushdown {
             LogicalOperator::MaterializationScan(mat) => {
                 self.pushdown_materialized_scan(bind_context, mat)
             }
+            LogicalOperator::Scan(scan) => self.pushdown_scan(bind_context, scan),
             other => self.stop_pushdown(bind_context, other),
         }
     }
@@ -118,6 +121,32 @@ impl FilterPushdown {
         }))
     }
 
+    /// Pushes simple `column <op> constant` filters into a table function
+    /// scan.
+    ///
+    /// Scans only use these filters to skip reading data that can't match, so
+    /// all filters are kept in a filter node above the scan.
+    fn pushdown_scan(
+        &mut self,
+        bind_context: &mut BindContext,
+        mut plan: Node<LogicalScan>,
+    ) -> Result<LogicalOperator> {
+        if matches!(plan.node.source, ScanSource::TableFunction { .. }) {
+            let filters = self.filter_gen.clone().into_expressions();
+            for filter in &filters {
+                if let Some(scan_filter) =
+                    ScanFilter::try_from_expr(plan.node.table_ref, &plan.node.projection, filter)
+                {
+                    if !plan.node.scan_filters.contains(&scan_filter) {
+                        plan.node.scan_filters.push(scan_filter);
+                    }
+                }
+            }
+        }
+
+        self.stop_pushdown(bind_context, LogicalOperator::Scan(plan))
+    }
+
     fn pushdown_materialized_scan(
         &mut self,
         bind_context: &mut BindContext,
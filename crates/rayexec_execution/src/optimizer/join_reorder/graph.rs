@@ -854,7 +854,9 @@ impl Graph {
                 node: LogicalCrossJoin,
                 location: LocationRequirement::Any,
                 children: vec![left, right],
-                estimated_cardinality: StatisticsValue::Unknown,
+                estimated_cardinality: StatisticsValue::Estimated(
+                    node.subgraph.estimated_cardinality() as usize,
+                ),
             }))
         } else {
             let join_type = if any_semi {
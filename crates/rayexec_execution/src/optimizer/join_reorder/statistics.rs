@@ -1,10 +1,16 @@
 use rayexec_error::Result;
 
+use crate::expr::comparison_expr::ComparisonOperator;
 use crate::logical::logical_aggregate::LogicalAggregate;
 use crate::logical::logical_filter::LogicalFilter;
+use crate::logical::logical_join::JoinType;
 use crate::logical::logical_project::LogicalProject;
 use crate::logical::operator::{LogicalOperator, Node};
-use crate::logical::statistics::assumptions::DEFAULT_SELECTIVITY;
+use crate::logical::statistics::assumptions::{
+    DEFAULT_SELECTIVITY,
+    EQUALITY_SELECTIVITY,
+    INEQUALITY_SELECTIVITY,
+};
 use crate::logical::statistics::StatisticsValue;
 
 /// Propagates estimated cardinalities from the bottom up.
@@ -13,6 +19,24 @@ pub fn propagate_estimated_cardinality(op: &mut LogicalOperator) -> Result<()> {
         LogicalOperator::Project(op) => propagate_project(op)?,
         LogicalOperator::Filter(op) => propagate_filter(op)?,
         LogicalOperator::Aggregate(op) => propagate_aggregate(op)?,
+        LogicalOperator::ComparisonJoin(op) => {
+            let selectivity = op
+                .node
+                .conditions
+                .iter()
+                .map(|cond| match cond.op {
+                    ComparisonOperator::Eq => EQUALITY_SELECTIVITY,
+                    _ => INEQUALITY_SELECTIVITY,
+                })
+                .product();
+            let join_type = op.node.join_type;
+            propagate_join(op, join_type, selectivity)?
+        }
+        LogicalOperator::ArbitraryJoin(op) => {
+            let join_type = op.node.join_type;
+            propagate_join(op, join_type, DEFAULT_SELECTIVITY)?
+        }
+        LogicalOperator::CrossJoin(op) => propagate_join(op, JoinType::Inner, 1.0)?,
         _ => (),
     }
 
@@ -70,3 +94,112 @@ fn propagate_aggregate(op: &mut Node<LogicalAggregate>) -> Result<()> {
 
     Ok(())
 }
+
+/// Propagate estimated cardinalities through a join with the given
+/// selectivity.
+///
+/// Joins produced during join reordering already have an estimate from the
+/// join graph, so that estimate is kept.
+fn propagate_join<N>(op: &mut Node<N>, join_type: JoinType, selectivity: f64) -> Result<()> {
+    let left = op.get_nth_child_mut(0)?;
+    propagate_estimated_cardinality(left)?;
+    let left_card = left.estimated_cardinality();
+
+    let right = op.get_nth_child_mut(1)?;
+    propagate_estimated_cardinality(right)?;
+    let right_card = right.estimated_cardinality();
+
+    if op.estimated_cardinality.value().is_some() {
+        return Ok(());
+    }
+
+    let (left, right) = match (left_card.value(), right_card.value()) {
+        (Some(&left), Some(&right)) => (left, right),
+        _ => {
+            op.estimated_cardinality = StatisticsValue::Unknown;
+            return Ok(());
+        }
+    };
+
+    let inner = ((left as f64) * (right as f64) * selectivity) as usize;
+
+    let estimated = match join_type {
+        JoinType::Inner => inner,
+        // Outer joins produce at least every row from the preserved side(s).
+        JoinType::Left => inner.max(left),
+        JoinType::Right => inner.max(right),
+        JoinType::Full => inner.max(left).max(right),
+        // Semi and anti joins produce at most the rows from the left.
+        JoinType::Semi | JoinType::Anti => ((left as f64) * DEFAULT_SELECTIVITY) as usize,
+        JoinType::LeftMark { .. } => left,
+    };
+
+    op.estimated_cardinality = StatisticsValue::Estimated(estimated);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logical::logical_empty::LogicalEmpty;
+    use crate::logical::logical_join::LogicalCrossJoin;
+    use crate::logical::operator::LocationRequirement;
+
+    fn empty_with_cardinality(cardinality: StatisticsValue<usize>) -> LogicalOperator {
+        LogicalOperator::Empty(Node {
+            node: LogicalEmpty,
+            location: LocationRequirement::Any,
+            children: Vec::new(),
+            estimated_cardinality: cardinality,
+        })
+    }
+
+    #[test]
+    fn propagate_cross_join() {
+        let mut op = LogicalOperator::CrossJoin(Node {
+            node: LogicalCrossJoin,
+            location: LocationRequirement::Any,
+            children: vec![
+                empty_with_cardinality(StatisticsValue::Exact(10)),
+                empty_with_cardinality(StatisticsValue::Exact(20)),
+            ],
+            estimated_cardinality: StatisticsValue::Unknown,
+        });
+
+        propagate_estimated_cardinality(&mut op).unwrap();
+        assert_eq!(StatisticsValue::Estimated(200), op.estimated_cardinality());
+    }
+
+    #[test]
+    fn propagate_cross_join_unknown_child() {
+        let mut op = LogicalOperator::CrossJoin(Node {
+            node: LogicalCrossJoin,
+            location: LocationRequirement::Any,
+            children: vec![
+                empty_with_cardinality(StatisticsValue::Exact(10)),
+                empty_with_cardinality(StatisticsValue::Unknown),
+            ],
+            estimated_cardinality: StatisticsValue::Unknown,
+        });
+
+        propagate_estimated_cardinality(&mut op).unwrap();
+        assert_eq!(StatisticsValue::Unknown, op.estimated_cardinality());
+    }
+
+    #[test]
+    fn propagate_keeps_existing_estimate() {
+        let mut op = LogicalOperator::CrossJoin(Node {
+            node: LogicalCrossJoin,
+            location: LocationRequirement::Any,
+            children: vec![
+                empty_with_cardinality(StatisticsValue::Exact(10)),
+                empty_with_cardinality(StatisticsValue::Exact(20)),
+            ],
+            estimated_cardinality: StatisticsValue::Estimated(5),
+        });
+
+        propagate_estimated_cardinality(&mut op).unwrap();
+        assert_eq!(StatisticsValue::Estimated(5), op.estimated_cardinality());
+    }
+}
@@ -15,7 +15,10 @@ impl OptimizeRule for LimitPushdown {
         mut plan: LogicalOperator,
     ) -> Result<LogicalOperator> {
         if let LogicalOperator::Limit(mut limit) = plan {
+            // Limits using WITH TIES reference the columns produced by the
+            // project, so can't be pushed below it.
             if limit.children.len() == 1
+                && limit.node.with_ties.is_none()
                 && matches!(&limit.children[0], LogicalOperator::Project(_))
             {
                 let mut project = limit.children.pop().unwrap();
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use rayexec_error::Result;
+
+use super::OptimizeRule;
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::Expression;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::logical_project::LogicalProject;
+use crate::logical::operator::{LogicalOperator, Node};
+
+/// Merge a projection directly above another projection into a single
+/// projection by substituting the child's expressions into the parent.
+#[derive(Debug)]
+pub struct MergeProjections;
+
+impl OptimizeRule for MergeProjections {
+    fn optimize(
+        &mut self,
+        _bind_context: &mut BindContext,
+        mut plan: LogicalOperator,
+    ) -> Result<LogicalOperator> {
+        // Children first, a chain of projections below us will already be
+        // merged as far as possible.
+        plan.modify_replace_children(&mut |child| self.optimize(_bind_context, child))?;
+
+        if let LogicalOperator::Project(project) = &mut plan {
+            try_merge_projections(project)?;
+        }
+
+        Ok(plan)
+    }
+}
+
+/// Try to merge this projection with its child projection.
+///
+/// This does not change the table ref of this projection, and all column
+/// references that reference this projection remain valid.
+///
+/// Child expressions other than plain columns and literals are only
+/// substituted if they're referenced at most once. This avoids evaluating
+/// volatile functions multiple times (which would change the result), as well
+/// as repeatedly computing expensive expressions.
+///
+/// Returns true if the projections were merged.
+pub(crate) fn try_merge_projections(current: &mut Node<LogicalProject>) -> Result<bool> {
+    let child = match current.get_one_child_exact()? {
+        LogicalOperator::Project(child) => child,
+        _ => return Ok(false),
+    };
+
+    let child_table = child.node.projection_table;
+    let mut reference_counts = vec![0; child.node.projections.len()];
+    for expr in &current.node.projections {
+        for col in expr.get_column_references() {
+            if col.table_scope != child_table || col.column >= reference_counts.len() {
+                // References something other than the child's output.
+                return Ok(false);
+            }
+            reference_counts[col.column] += 1;
+        }
+    }
+
+    let can_merge = child
+        .node
+        .projections
+        .iter()
+        .zip(&reference_counts)
+        .all(|(expr, &count)| match expr {
+            Expression::Column(_) | Expression::Literal(_) => true,
+            _ => count <= 1,
+        });
+
+    if !can_merge {
+        return Ok(false);
+    }
+
+    let mut child = match current.take_one_child_exact()? {
+        LogicalOperator::Project(child) => child,
+        _ => unreachable!("operator has to be a project"),
+    };
+
+    let mapping: HashMap<ColumnExpr, Expression> = child
+        .node
+        .projections
+        .into_iter()
+        .enumerate()
+        .map(|(column, expr)| {
+            (
+                ColumnExpr {
+                    table_scope: child_table,
+                    column,
+                },
+                expr,
+            )
+        })
+        .collect();
+
+    for expr in &mut current.node.projections {
+        expr.replace_column_references(&mapping);
+    }
+
+    current.children = std::mem::take(&mut child.children);
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::functions::scalar::builtin::random::Random;
+    use crate::functions::scalar::ScalarFunction;
+    use crate::logical::binder::table_list::{TableList, TableRef};
+    use crate::logical::operator::LocationRequirement;
+    use crate::logical::statistics::StatisticsValue;
+
+    fn project(
+        projections: Vec<Expression>,
+        table: impl Into<TableRef>,
+        child: LogicalOperator,
+    ) -> LogicalOperator {
+        LogicalOperator::Project(Node {
+            node: LogicalProject {
+                projections,
+                projection_table: table.into(),
+            },
+            location: LocationRequirement::Any,
+            children: vec![child],
+            estimated_cardinality: StatisticsValue::Unknown,
+        })
+    }
+
+    #[test]
+    fn merge_arith_projections() {
+        // project(a + 1) over project(x * 2 as a)
+        let plan = project(
+            vec![expr::add(expr::col_ref(1, 0), expr::lit(1))],
+            2,
+            project(
+                vec![expr::mul(expr::col_ref(0, 0), expr::lit(2))],
+                1,
+                LogicalOperator::EMPTY,
+            ),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = MergeProjections.optimize(&mut bind_context, plan).unwrap();
+
+        let expected = project(
+            vec![expr::add(
+                expr::mul(expr::col_ref(0, 0), expr::lit(2)),
+                expr::lit(1),
+            )],
+            2,
+            LogicalOperator::EMPTY,
+        );
+        assert_eq!(expected, optimized);
+    }
+
+    #[test]
+    fn merge_duplicated_column_references() {
+        // project(a, a) over project(x as a, 4)
+        let plan = project(
+            vec![expr::col_ref(1, 0), expr::col_ref(1, 0)],
+            2,
+            project(
+                vec![expr::col_ref(0, 0), expr::lit(4)],
+                1,
+                LogicalOperator::EMPTY,
+            ),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = MergeProjections.optimize(&mut bind_context, plan).unwrap();
+
+        let expected = project(
+            vec![expr::col_ref(0, 0), expr::col_ref(0, 0)],
+            2,
+            LogicalOperator::EMPTY,
+        );
+        assert_eq!(expected, optimized);
+    }
+
+    #[test]
+    fn no_merge_reused_expression() {
+        // project(a, a + 1) over project(x * 2 as a)
+        let plan = project(
+            vec![
+                expr::col_ref(1, 0),
+                expr::add(expr::col_ref(1, 0), expr::lit(1)),
+            ],
+            2,
+            project(
+                vec![expr::mul(expr::col_ref(0, 0), expr::lit(2))],
+                1,
+                LogicalOperator::EMPTY,
+            ),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = MergeProjections
+            .optimize(&mut bind_context, plan.clone())
+            .unwrap();
+        assert_eq!(plan, optimized);
+    }
+
+    #[test]
+    fn no_merge_duplicated_volatile() {
+        // project(r, r) over project(random() as r)
+        let random = Random.plan(&TableList::empty(), Vec::new()).unwrap();
+        let plan = project(
+            vec![expr::col_ref(1, 0), expr::col_ref(1, 0)],
+            2,
+            project(
+                vec![Expression::ScalarFunction(random.into())],
+                1,
+                LogicalOperator::EMPTY,
+            ),
+        );
+
+        let mut bind_context = BindContext::new();
+        let optimized = MergeProjections
+            .optimize(&mut bind_context, plan.clone())
+            .unwrap();
+        assert_eq!(plan, optimized);
+    }
+}
@@ -1,21 +1,29 @@
+pub mod coalesce_filters;
 pub mod column_prune;
+pub mod empty_propagation;
 pub mod expr_rewrite;
 pub mod filter_pushdown;
 pub mod join_reorder;
 pub mod limit_pushdown;
 pub mod location;
+pub mod merge_projections;
+pub mod top_n;
 
 #[allow(dead_code)] // Until it's more robust
 pub mod redundant_groups;
 
 use std::time::Duration;
 
+use coalesce_filters::CoalesceFilters;
 use column_prune::ColumnPrune;
+use empty_propagation::EmptyPropagation;
 use expr_rewrite::ExpressionRewriter;
 use filter_pushdown::FilterPushdown;
 use join_reorder::JoinReorder;
 use limit_pushdown::LimitPushdown;
+use merge_projections::MergeProjections;
 use rayexec_error::Result;
+use top_n::TopN;
 use tracing::debug;
 
 use crate::logical::binder::bind_context::BindContext;
@@ -66,6 +74,15 @@ impl Optimizer {
             .timings
             .push(("expression_rewrite", timer.stop()));
 
+        // Prune subtrees that can't produce rows. Happens after expression
+        // rewriting so that constant predicates are folded.
+        let timer = Timer::<I>::start();
+        let mut rule = EmptyPropagation;
+        let plan = rule.optimize(bind_context, plan)?;
+        self.profile_data
+            .timings
+            .push(("empty_propagation", timer.stop()));
+
         // First filter pushdown.
         let timer = Timer::<I>::start();
         let mut rule = FilterPushdown::default();
@@ -107,6 +124,30 @@ impl Optimizer {
             .timings
             .push(("join_reorder", timer.stop()));
 
+        // Merge any stacked filters left over from pushdown and join
+        // reordering.
+        let timer = Timer::<I>::start();
+        let mut rule = CoalesceFilters;
+        let plan = rule.optimize(bind_context, plan)?;
+        self.profile_data
+            .timings
+            .push(("coalesce_filters", timer.stop()));
+
+        // Merge any remaining stacked projections.
+        let timer = Timer::<I>::start();
+        let mut rule = MergeProjections;
+        let plan = rule.optimize(bind_context, plan)?;
+        self.profile_data
+            .timings
+            .push(("merge_projections", timer.stop()));
+
+        // Combine ORDER BY + LIMIT. Happens after the other rules since they
+        // only know about the separate operators.
+        let timer = Timer::<I>::start();
+        let mut rule = TopN;
+        let plan = rule.optimize(bind_context, plan)?;
+        self.profile_data.timings.push(("top_n", timer.stop()));
+
         // DO THE OTHER RULES
 
         // Second filter pushdown.
@@ -0,0 +1,117 @@
+use rayexec_error::Result;
+
+use super::OptimizeRule;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::logical_top_n::LogicalTopN;
+use crate::logical::operator::{LogicalOperator, Node};
+
+/// Combine an ORDER BY directly below a LIMIT into a single top N operator.
+#[derive(Debug)]
+pub struct TopN;
+
+impl OptimizeRule for TopN {
+    fn optimize(
+        &mut self,
+        _bind_context: &mut BindContext,
+        mut plan: LogicalOperator,
+    ) -> Result<LogicalOperator> {
+        if let LogicalOperator::Limit(mut limit) = plan {
+            // Limits without a count, or using WITH TIES need to see every
+            // sorted row.
+            match (
+                limit.node.limit,
+                &limit.node.with_ties,
+                limit.children.as_slice(),
+            ) {
+                (Some(count), None, [LogicalOperator::Order(_)]) => {
+                    let order = match limit.children.pop() {
+                        Some(LogicalOperator::Order(order)) => order,
+                        _ => unreachable!(),
+                    };
+
+                    plan = LogicalOperator::TopN(Node {
+                        node: LogicalTopN {
+                            exprs: order.node.exprs,
+                            limit: count,
+                            offset: limit.node.offset,
+                        },
+                        location: limit.location,
+                        children: order.children,
+                        estimated_cardinality: limit.estimated_cardinality,
+                    });
+                }
+                _ => plan = LogicalOperator::Limit(limit),
+            }
+        }
+
+        plan.modify_replace_children(&mut |child| self.optimize(_bind_context, child))?;
+
+        Ok(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
+    use crate::logical::logical_limit::LogicalLimit;
+    use crate::logical::logical_order::LogicalOrder;
+    use crate::logical::operator::LocationRequirement;
+    use crate::logical::statistics::StatisticsValue;
+
+    fn limit_over_order(limit: Option<usize>, with_ties: bool) -> LogicalOperator {
+        let order_expr = expr::col_ref(0, 0);
+        LogicalOperator::Limit(Node {
+            node: LogicalLimit {
+                offset: Some(2),
+                limit,
+                with_ties: with_ties.then(|| vec![order_expr.clone()]),
+            },
+            location: LocationRequirement::Any,
+            children: vec![LogicalOperator::Order(Node {
+                node: LogicalOrder {
+                    exprs: vec![BoundOrderByExpr {
+                        expr: order_expr,
+                        desc: true,
+                        nulls_first: false,
+                    }],
+                },
+                location: LocationRequirement::Any,
+                children: vec![LogicalOperator::EMPTY],
+                estimated_cardinality: StatisticsValue::Unknown,
+            })],
+            estimated_cardinality: StatisticsValue::Unknown,
+        })
+    }
+
+    #[test]
+    fn rewrite_limit_over_order() {
+        let mut bind_context = BindContext::new();
+        let plan = TopN
+            .optimize(&mut bind_context, limit_over_order(Some(10), false))
+            .unwrap();
+
+        let top_n = match plan {
+            LogicalOperator::TopN(top_n) => top_n,
+            other => panic!("unexpected plan: {other:?}"),
+        };
+        assert_eq!(10, top_n.node.limit);
+        assert_eq!(Some(2), top_n.node.offset);
+        assert_eq!(1, top_n.node.exprs.len());
+        assert_eq!(vec![LogicalOperator::EMPTY], top_n.children);
+    }
+
+    #[test]
+    fn no_rewrite_without_count_or_with_ties() {
+        let mut bind_context = BindContext::new();
+
+        let plan = limit_over_order(None, false);
+        let optimized = TopN.optimize(&mut bind_context, plan.clone()).unwrap();
+        assert_eq!(plan, optimized);
+
+        let plan = limit_over_order(Some(10), true);
+        let optimized = TopN.optimize(&mut bind_context, plan.clone()).unwrap();
+        assert_eq!(plan, optimized);
+    }
+}
@@ -7,6 +7,7 @@ use rayexec_proto::ProtoConv;
 use crate::arrays::batch::Batch;
 use crate::database::catalog_entry::CatalogEntry;
 use crate::execution::operators::sink::PartitionSink;
+use crate::logical::scan_filter::ScanFilter;
 
 /// Scan projections.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -82,6 +83,20 @@ pub trait DataTable: Debug + Sync + Send {
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>>;
 
+    /// Return table scanners for the table, providing filters the table may
+    /// use to skip reading data.
+    ///
+    /// Filters are only a hint, scans may still return rows that don't pass
+    /// them. By default filters are ignored.
+    fn scan_with_filters(
+        &self,
+        projections: Projections,
+        _filters: &[ScanFilter],
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        self.scan(projections, num_partitions)
+    }
+
     fn insert(&self, _input_partitions: usize) -> Result<Vec<Box<dyn PartitionSink>>> {
         Err(RayexecError::new("Data table does not support inserts"))
     }
@@ -0,0 +1,434 @@
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use flate2::write::{GzEncoder, MultiGzDecoder};
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream};
+use futures::{FutureExt, Stream, StreamExt};
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result, ResultExt};
+
+use crate::location::FileLocation;
+use crate::{FileSink, FileSource};
+
+/// Compression to use when reading from or writing to a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Infer the compression from the extension of a file location.
+    ///
+    /// Files ending in `.gz` are gzip compressed, and files ending in `.zst`
+    /// are zstd compressed. Everything else is assumed to be uncompressed.
+    pub fn from_location(location: &FileLocation) -> Self {
+        let extension = match location {
+            FileLocation::Url(url) => Path::new(url.path())
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_string()),
+            FileLocation::Path(path) => path
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_string()),
+        };
+
+        match extension {
+            Some(ext) if ext.eq_ignore_ascii_case("gz") => Compression::Gzip,
+            Some(ext) if ext.eq_ignore_ascii_case("zst") => Compression::Zstd,
+            _ => Compression::None,
+        }
+    }
+
+    /// Parse a compression from a user provided name, e.g. from a COPY TO
+    /// option.
+    pub fn try_from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "none" | "uncompressed" => Ok(Compression::None),
+            "gzip" | "gz" => Ok(Compression::Gzip),
+            "zstd" | "zst" => Ok(Compression::Zstd),
+            _ => Err(RayexecError::new(format!(
+                "Unsupported compression: '{name}'"
+            ))),
+        }
+    }
+
+    /// Wrap a file sink, compressing all bytes written to it.
+    ///
+    /// Returns the sink unchanged if there's no compression.
+    pub fn wrap_sink(&self, sink: Box<dyn FileSink>) -> Result<Box<dyn FileSink>> {
+        match self {
+            Compression::None => Ok(sink),
+            _ => Ok(Box::new(CompressedFileSink {
+                sink,
+                encoder: Mutex::new(Some(Encoder::try_new(*self)?)),
+            })),
+        }
+    }
+
+    /// Wrap a file source, decompressing the bytes read from it.
+    ///
+    /// Returns the source unchanged if there's no compression.
+    pub fn wrap_source(&self, source: Box<dyn FileSource>) -> Box<dyn FileSource> {
+        match self {
+            Compression::None => source,
+            _ => Box::new(DecompressedFileSource {
+                source,
+                compression: *self,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Gzip => write!(f, "gzip"),
+            Self::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+/// Streaming encoder writing compressed bytes to an in-memory buffer.
+enum Encoder {
+    Gzip(GzEncoder<Vec<u8>>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::write::Encoder<'static, Vec<u8>>),
+}
+
+impl Encoder {
+    fn try_new(compression: Compression) -> Result<Self> {
+        match compression {
+            Compression::None => Err(RayexecError::new("Cannot create encoder for 'none'")),
+            Compression::Gzip => Ok(Encoder::Gzip(GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            ))),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Ok(Encoder::Zstd(
+                zstd::stream::write::Encoder::new(Vec::new(), 0)
+                    .context("failed to create zstd encoder")?,
+            )),
+            #[cfg(not(feature = "zstd"))]
+            Compression::Zstd => Err(zstd_not_enabled_error()),
+        }
+    }
+
+    /// Compress `buf`, returning any compressed bytes that are ready.
+    fn encode(&mut self, buf: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Encoder::Gzip(enc) => {
+                enc.write_all(buf).context("failed to compress")?;
+                Ok(std::mem::take(enc.get_mut()))
+            }
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(enc) => {
+                enc.write_all(buf).context("failed to compress")?;
+                Ok(std::mem::take(enc.get_mut()))
+            }
+        }
+    }
+
+    /// Finish the stream, returning the remaining compressed bytes.
+    fn finish(self) -> Result<Vec<u8>> {
+        match self {
+            Encoder::Gzip(enc) => enc.finish().context("failed to finish compression"),
+            #[cfg(feature = "zstd")]
+            Encoder::Zstd(enc) => enc.finish().context("failed to finish compression"),
+        }
+    }
+}
+
+/// Streaming decoder writing decompressed bytes to an in-memory buffer.
+enum Decoder {
+    Gzip(MultiGzDecoder<Vec<u8>>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::write::Decoder<'static, Vec<u8>>),
+}
+
+impl Decoder {
+    fn try_new(compression: Compression) -> Result<Self> {
+        match compression {
+            Compression::None => Err(RayexecError::new("Cannot create decoder for 'none'")),
+            Compression::Gzip => Ok(Decoder::Gzip(MultiGzDecoder::new(Vec::new()))),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Ok(Decoder::Zstd(
+                zstd::stream::write::Decoder::new(Vec::new())
+                    .context("failed to create zstd decoder")?,
+            )),
+            #[cfg(not(feature = "zstd"))]
+            Compression::Zstd => Err(zstd_not_enabled_error()),
+        }
+    }
+
+    /// Decompress `buf`, returning any decompressed bytes that are ready.
+    fn decode(&mut self, buf: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Decoder::Gzip(dec) => {
+                dec.write_all(buf).context("failed to decompress")?;
+                Ok(std::mem::take(dec.get_mut()))
+            }
+            #[cfg(feature = "zstd")]
+            Decoder::Zstd(dec) => {
+                dec.write_all(buf).context("failed to decompress")?;
+                dec.flush().context("failed to decompress")?;
+                Ok(std::mem::take(dec.get_mut()))
+            }
+        }
+    }
+
+    /// Finish the stream, returning the remaining decompressed bytes.
+    fn finish(self) -> Result<Vec<u8>> {
+        match self {
+            Decoder::Gzip(dec) => dec.finish().context("failed to finish decompression"),
+            #[cfg(feature = "zstd")]
+            Decoder::Zstd(mut dec) => {
+                dec.flush().context("failed to finish decompression")?;
+                Ok(dec.into_inner())
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_not_enabled_error() -> RayexecError {
+    RayexecError::new("zstd compression not enabled")
+}
+
+/// File sink compressing bytes before writing them to the underlying sink.
+pub struct CompressedFileSink {
+    sink: Box<dyn FileSink>,
+    /// Encoder for the stream, taken on finish.
+    ///
+    /// Wrapped in a mutex only to make the sink `Sync`. It's only ever
+    /// accessed through `get_mut`.
+    encoder: Mutex<Option<Encoder>>,
+}
+
+impl FileSink for CompressedFileSink {
+    fn write_all(&mut self, buf: Bytes) -> BoxFuture<'static, Result<()>> {
+        let encoded = match self.encoder.get_mut() {
+            Some(encoder) => encoder.encode(&buf),
+            None => Err(RayexecError::new("Attempted to write to finished sink")),
+        };
+
+        match encoded {
+            Ok(encoded) if encoded.is_empty() => async { Ok(()) }.boxed(),
+            Ok(encoded) => self.sink.write_all(encoded.into()),
+            Err(e) => async move { Err(e) }.boxed(),
+        }
+    }
+
+    fn finish(&mut self) -> BoxFuture<'static, Result<()>> {
+        let remaining = match self.encoder.get_mut().take() {
+            Some(encoder) => encoder.finish(),
+            None => Err(RayexecError::new("Attempted to finish sink twice")),
+        };
+        let remaining = match remaining {
+            Ok(remaining) => remaining,
+            Err(e) => return async move { Err(e) }.boxed(),
+        };
+
+        let write = self.sink.write_all(remaining.into());
+        let finish = self.sink.finish();
+
+        async move {
+            write.await?;
+            finish.await
+        }
+        .boxed()
+    }
+}
+
+impl fmt::Debug for CompressedFileSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressedFileSink")
+            .field("sink", &self.sink)
+            .finish_non_exhaustive()
+    }
+}
+
+/// File source decompressing bytes read from the underlying source.
+///
+/// Only streaming reads are supported.
+#[derive(Debug)]
+pub struct DecompressedFileSource {
+    source: Box<dyn FileSource>,
+    compression: Compression,
+}
+
+impl FileSource for DecompressedFileSource {
+    fn read_range(&mut self, _start: usize, _len: usize) -> BoxFuture<'_, Result<Bytes>> {
+        let compression = self.compression;
+        async move {
+            Err(RayexecError::new(format!(
+                "Range reads not supported for {compression} compressed files"
+            )))
+        }
+        .boxed()
+    }
+
+    fn read_stream(&mut self) -> BoxStream<'static, Result<Bytes>> {
+        let decoder = match Decoder::try_new(self.compression) {
+            Ok(decoder) => decoder,
+            Err(e) => return stream::once(async move { Err(e) }).boxed(),
+        };
+
+        DecompressStream {
+            stream: self.source.read_stream(),
+            decoder: Some(decoder),
+        }
+        .boxed()
+    }
+
+    fn size(&mut self) -> BoxFuture<'_, Result<usize>> {
+        let compression = self.compression;
+        async move {
+            Err(RayexecError::new(format!(
+                "Cannot get the size of {compression} compressed files"
+            )))
+        }
+        .boxed()
+    }
+}
+
+struct DecompressStream {
+    stream: BoxStream<'static, Result<Bytes>>,
+    /// Decoder for the stream, taken once the inner stream is exhausted.
+    decoder: Option<Decoder>,
+}
+
+impl Stream for DecompressStream {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            let decoder = match this.decoder.as_mut() {
+                Some(decoder) => decoder,
+                None => return Poll::Ready(None),
+            };
+
+            let decoded = match this.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(bytes))) => decoder.decode(&bytes),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => this.decoder.take().expect("decoder to exist").finish(),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match decoded {
+                // Need more input before we can produce anything.
+                Ok(decoded) if decoded.is_empty() => continue,
+                Ok(decoded) => return Poll::Ready(Some(Ok(decoded.into()))),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use url::Url;
+
+    use super::*;
+    use crate::memory::MemoryFileSystem;
+    use crate::FileSourceExt;
+
+    fn round_trip(compression: Compression) {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("out");
+
+        // Enough to span multiple reads from the stream.
+        let content: Vec<u8> = (0..2000)
+            .flat_map(|i| format!("{i},row\n").into_bytes())
+            .collect();
+
+        let mut sink = compression.wrap_sink(fs.file_sink(path).unwrap()).unwrap();
+        let out = futures::executor::block_on(async {
+            for chunk in content.chunks(1000) {
+                sink.write_all(Bytes::copy_from_slice(chunk)).await.unwrap();
+            }
+            sink.finish().await.unwrap();
+
+            let mut source = compression.wrap_source(fs.file_source(path).unwrap());
+            source.read_stream_all().await.unwrap()
+        });
+
+        assert_eq!(content, out.as_ref());
+    }
+
+    #[test]
+    fn gzip_round_trip() {
+        round_trip(Compression::Gzip);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_round_trip() {
+        round_trip(Compression::Zstd);
+    }
+
+    #[test]
+    fn gzip_invalid_input() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("out");
+
+        futures::executor::block_on(async {
+            let mut sink = fs.file_sink(path).unwrap();
+            sink.write_all(Bytes::from_static(b"not gzip"))
+                .await
+                .unwrap();
+            sink.finish().await.unwrap();
+
+            let mut source = Compression::Gzip.wrap_source(fs.file_source(path).unwrap());
+            source.read_stream_all().await.unwrap_err();
+        });
+    }
+
+    #[test]
+    fn infer_from_location() {
+        let cases = [
+            (
+                FileLocation::Path(PathBuf::from("a/b.csv")),
+                Compression::None,
+            ),
+            (
+                FileLocation::Path(PathBuf::from("a/b.csv.gz")),
+                Compression::Gzip,
+            ),
+            (
+                FileLocation::Path(PathBuf::from("a/b.csv.GZ")),
+                Compression::Gzip,
+            ),
+            (
+                FileLocation::Path(PathBuf::from("b.json.zst")),
+                Compression::Zstd,
+            ),
+            (
+                FileLocation::Url(Url::parse("s3://bucket/b.csv.gz").unwrap()),
+                Compression::Gzip,
+            ),
+            (
+                FileLocation::Url(Url::parse("https://example.com/b.csv").unwrap()),
+                Compression::None,
+            ),
+        ];
+
+        for (location, expected) in cases {
+            assert_eq!(
+                expected,
+                Compression::from_location(&location),
+                "{location}"
+            );
+        }
+    }
+}
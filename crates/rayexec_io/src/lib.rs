@@ -1,3 +1,4 @@
+pub mod compression;
 pub mod http;
 pub mod location;
 pub mod memory;
@@ -33,6 +34,17 @@ pub trait FileProvider: Sync + Send + Debug {
     fn file_sink(&self, location: FileLocation, config: &AccessConfig)
         -> Result<Box<dyn FileSink>>;
 
+    /// Gets a file sink that appends to the file at some location, creating
+    /// the file if it doesn't exist.
+    ///
+    /// Also returns the size in bytes of the file before anything was
+    /// appended, which will be zero if the file was just created.
+    fn file_sink_append(
+        &self,
+        location: FileLocation,
+        config: &AccessConfig,
+    ) -> Result<(Box<dyn FileSink>, usize)>;
+
     /// Return a stream of paths relative to `prefix`.
     ///
     /// This is stream of vecs to allow for easily adapting to object store
@@ -114,6 +126,12 @@ impl FileSource for Box<dyn FileSource + '_> {
 ///
 /// The semantics for this is overwrite any existing data. If appending is
 /// needed, a separate trait should be created.
+///
+/// Written bytes should only become visible at the sink's location once
+/// `finish` completes. A sink that's dropped before finishing should discard
+/// everything written to it, e.g. by writing to a temp file that's renamed on
+/// finish, or by only completing a multipart upload on finish for object
+/// stores.
 pub trait FileSink: Sync + Send + Debug {
     /// Write all bytes.
     fn write_all(&mut self, buf: Bytes) -> BoxFuture<'static, Result<()>>;
@@ -60,6 +60,25 @@ impl MemoryFileSystem {
             files: self.files.clone(),
         }))
     }
+
+    /// Create a sink that appends to an existing file, returning the sink and
+    /// the size of the file before appending.
+    pub fn file_sink_append(&self, path: &Path) -> Result<(Box<dyn FileSink>, usize)> {
+        let name = get_normalized_file_name(path)?;
+        let buf = match self.files.lock().get(name) {
+            Some(existing) => existing.to_vec(),
+            None => Vec::new(),
+        };
+        let existing_len = buf.len();
+
+        let sink = MemoryFileSink {
+            name: name.to_string(),
+            buf,
+            files: self.files.clone(),
+        };
+
+        Ok((Box::new(sink), existing_len))
+    }
 }
 
 #[derive(Debug)]
@@ -123,7 +142,7 @@ impl MemoryFileStream {
         const STREAM_BUF_SIZE: usize = 4 * 1024;
 
         let buf = if self.content.len() - self.curr < STREAM_BUF_SIZE {
-            self.content.clone()
+            self.content.slice(self.curr..)
         } else {
             self.content.slice(self.curr..(self.curr + STREAM_BUF_SIZE))
         };
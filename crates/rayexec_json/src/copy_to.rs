@@ -0,0 +1,154 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use rayexec_error::{RayexecError, Result};
+use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::execution::operators::sink::PartitionSink;
+use rayexec_execution::functions::copy::{CopyToArgs, CopyToFunction};
+use rayexec_execution::runtime::Runtime;
+use rayexec_io::location::{AccessConfig, FileLocation};
+use rayexec_io::{FileProvider, FileSink};
+
+use crate::writer::NdjsonEncoder;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdjsonCopyToFunction<R: Runtime> {
+    pub(crate) runtime: R,
+}
+
+impl<R: Runtime> CopyToFunction for NdjsonCopyToFunction<R> {
+    fn name(&self) -> &'static str {
+        "ndjson_copy_to"
+    }
+
+    fn file_extension(&self) -> Option<&'static str> {
+        Some("ndjson")
+    }
+
+    fn create_sinks(
+        &self,
+        schema: Schema,
+        location: FileLocation,
+        mut args: CopyToArgs,
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn PartitionSink>>> {
+        let compression = args.try_remove_compression(&location)?;
+        if let Some(key) = args.named.keys().next() {
+            return Err(RayexecError::new(format!(
+                "Unsupported COPY TO option for ndjson: '{key}'"
+            )));
+        }
+
+        let provider = self.runtime.file_provider();
+
+        let mut sinks = Vec::with_capacity(num_partitions);
+        for _ in 0..num_partitions {
+            let sink = provider.file_sink(location.clone(), &AccessConfig::None)?;
+            let sink = compression.wrap_sink(sink)?;
+            sinks.push(Box::new(NdjsonCopyToSink::new(sink, schema.clone())) as _)
+        }
+
+        Ok(sinks)
+    }
+}
+
+/// Sink writing out each batch as it's pushed.
+#[derive(Debug)]
+pub struct NdjsonCopyToSink {
+    encoder: NdjsonEncoder,
+    sink: Box<dyn FileSink>,
+}
+
+impl NdjsonCopyToSink {
+    pub fn new(sink: Box<dyn FileSink>, schema: Schema) -> Self {
+        NdjsonCopyToSink {
+            encoder: NdjsonEncoder::new(schema),
+            sink,
+        }
+    }
+
+    async fn push_inner(&mut self, batch: Batch) -> Result<()> {
+        let mut buf = Vec::with_capacity(1024);
+        self.encoder.encode(&batch, &mut buf)?;
+        self.sink.write_all(buf.into()).await?;
+
+        Ok(())
+    }
+
+    async fn finalize_inner(&mut self) -> Result<()> {
+        self.sink.finish().await?;
+        Ok(())
+    }
+}
+
+impl PartitionSink for NdjsonCopyToSink {
+    fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+        self.push_inner(batch).boxed()
+    }
+
+    fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+        self.finalize_inner().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use rayexec_execution::arrays::array::Array;
+    use rayexec_execution::arrays::datatype::DataType;
+    use rayexec_execution::arrays::field::Field;
+    use rayexec_io::memory::MemoryFileSystem;
+    use rayexec_io::FileSource;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn copy_two_columns() {
+        let schema = Schema::new([
+            Field::new("id", DataType::Int64, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let batches = [
+            Batch::try_new([
+                Array::from_iter([Some(1_i64), Some(2)]),
+                Array::from_iter([Some("a"), None]),
+            ])
+            .unwrap(),
+            Batch::try_new([
+                Array::from_iter([None::<i64>]),
+                Array::from_iter([Some("quote\"d")]),
+            ])
+            .unwrap(),
+        ];
+
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("out.ndjson");
+        let mut sink = NdjsonCopyToSink::new(fs.file_sink(path).unwrap(), schema);
+
+        let out = futures::executor::block_on(async {
+            for batch in batches {
+                sink.push(batch).await.unwrap();
+            }
+            sink.finalize().await.unwrap();
+
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            source.read_range(0, size).await.unwrap()
+        });
+
+        let lines: Vec<serde_json::Value> = std::str::from_utf8(&out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        let expected = vec![
+            json!({"id": 1, "name": "a"}),
+            json!({"id": 2, "name": null}),
+            json!({"id": null, "name": "quote\"d"}),
+        ];
+        assert_eq!(expected, lines);
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt::{self, Debug};
+
+use futures::future::BoxFuture;
+use rayexec_error::Result;
+use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::runtime::Runtime;
+use rayexec_execution::storage::table_storage::{
+    DataTable,
+    DataTableScan,
+    EmptyTableScan,
+    ProjectedScan,
+    Projections,
+};
+use rayexec_io::compression::Compression;
+use rayexec_io::location::{AccessConfig, FileLocation};
+use rayexec_io::FileProvider;
+
+use crate::reader::AsyncNdjsonReader;
+
+/// Data table implementation that reads from a single newline-delimited JSON
+/// file.
+///
+/// This will produce a single scan that reads the actual file, with the
+/// remaining scans being empty.
+#[derive(Debug)]
+pub struct SingleFileNdjsonDataTable<R: Runtime> {
+    pub schema: Schema,
+    pub location: FileLocation,
+    pub compression: Compression,
+    pub conf: AccessConfig,
+    pub runtime: R,
+}
+
+impl<R: Runtime> DataTable for SingleFileNdjsonDataTable<R> {
+    fn scan(
+        &self,
+        projections: Projections,
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        let reader = self
+            .runtime
+            .file_provider()
+            .file_source(self.location.clone(), &self.conf)?;
+        let reader = self.compression.wrap_source(reader);
+        let json_reader = AsyncNdjsonReader::new(reader, self.schema.clone());
+
+        let mut scans: Vec<Box<dyn DataTableScan>> = vec![Box::new(ProjectedScan::new(
+            NdjsonFileScan {
+                reader: json_reader,
+            },
+            projections,
+        ))];
+        // Reset are empty (for now)
+        scans.extend((1..num_partitions).map(|_| Box::new(EmptyTableScan) as _));
+
+        Ok(scans)
+    }
+}
+
+pub struct NdjsonFileScan {
+    reader: AsyncNdjsonReader,
+}
+
+impl DataTableScan for NdjsonFileScan {
+    fn pull(&mut self) -> BoxFuture<'_, Result<Option<Batch>>> {
+        Box::pin(async { self.reader.read_next().await })
+    }
+}
+
+impl fmt::Debug for NdjsonFileScan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NdjsonFileScan").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,61 @@
+pub mod copy_to;
+pub mod datatable;
+pub mod reader;
+pub mod schema;
+pub mod value;
+pub mod writer;
+
+mod read_ndjson;
+
+use copy_to::NdjsonCopyToFunction;
+use rayexec_execution::datasource::{DataSource, DataSourceBuilder, DataSourceCopyTo, FileHandler};
+use rayexec_execution::functions::table::TableFunction;
+use rayexec_execution::runtime::Runtime;
+use read_ndjson::ReadNdjson;
+use regex::RegexBuilder;
+
+/// Data source for reading and writing newline-delimited JSON files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonDataSource<R: Runtime> {
+    runtime: R,
+}
+
+impl<R: Runtime> DataSourceBuilder<R> for JsonDataSource<R> {
+    fn initialize(runtime: R) -> Box<dyn DataSource> {
+        Box::new(JsonDataSource { runtime })
+    }
+}
+
+impl<R: Runtime> DataSource for JsonDataSource<R> {
+    fn initialize_table_functions(&self) -> Vec<Box<dyn TableFunction>> {
+        vec![Box::new(ReadNdjson {
+            runtime: self.runtime.clone(),
+        })]
+    }
+
+    fn initialize_copy_to_functions(&self) -> Vec<DataSourceCopyTo> {
+        vec![DataSourceCopyTo {
+            format: "ndjson".to_string(),
+            copy_to: Box::new(NdjsonCopyToFunction {
+                runtime: self.runtime.clone(),
+            }),
+        }]
+    }
+
+    fn file_handlers(&self) -> Vec<FileHandler> {
+        let regex = RegexBuilder::new(r"^.*\.(ndjson|jsonl)(\.(gz|zst))?$")
+            .case_insensitive(true)
+            .build()
+            .expect("regex to build");
+
+        vec![FileHandler {
+            regex,
+            table_func: Box::new(ReadNdjson {
+                runtime: self.runtime.clone(),
+            }),
+            copy_to: Some(Box::new(NdjsonCopyToFunction {
+                runtime: self.runtime.clone(),
+            })),
+        }]
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::{FutureExt, StreamExt};
+use rayexec_error::{RayexecError, Result};
+use rayexec_execution::arrays::datatype::DataTypeId;
+use rayexec_execution::arrays::scalar::OwnedScalarValue;
+use rayexec_execution::database::DatabaseContext;
+use rayexec_execution::expr;
+use rayexec_execution::functions::table::{
+    try_compression_from_args,
+    try_location_and_access_config_from_args,
+    PlannedTableFunction,
+    ScanPlanner,
+    TableFunction,
+    TableFunctionImpl,
+    TableFunctionPlanner,
+};
+use rayexec_execution::functions::{FunctionInfo, Signature};
+use rayexec_execution::logical::statistics::StatisticsValue;
+use rayexec_execution::runtime::Runtime;
+use rayexec_io::{FileProvider, FileSource};
+
+use crate::datatable::SingleFileNdjsonDataTable;
+use crate::reader::LineDecoder;
+use crate::schema::infer_schema_from_records;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadNdjson<R: Runtime> {
+    pub(crate) runtime: R,
+}
+
+impl<R: Runtime> FunctionInfo for ReadNdjson<R> {
+    fn name(&self) -> &'static str {
+        "read_ndjson"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["ndjson_scan"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8],
+            variadic_arg: None,
+            return_type: DataTypeId::Any,
+            doc: None,
+        }]
+    }
+}
+
+impl<R: Runtime> TableFunction for ReadNdjson<R> {
+    fn planner(&self) -> TableFunctionPlanner<'_> {
+        TableFunctionPlanner::Scan(self)
+    }
+}
+
+impl<R: Runtime> ScanPlanner for ReadNdjson<R> {
+    fn plan<'a>(
+        &self,
+        context: &'a DatabaseContext,
+        positional_inputs: Vec<OwnedScalarValue>,
+        named_inputs: HashMap<String, OwnedScalarValue>,
+    ) -> BoxFuture<'a, Result<PlannedTableFunction>> {
+        Self::plan_inner(self.clone(), context, positional_inputs, named_inputs).boxed()
+    }
+}
+
+impl<R: Runtime> ReadNdjson<R> {
+    async fn plan_inner(
+        self,
+        _context: &DatabaseContext,
+        positional_inputs: Vec<OwnedScalarValue>,
+        named_inputs: HashMap<String, OwnedScalarValue>,
+    ) -> Result<PlannedTableFunction> {
+        let (location, conf) =
+            try_location_and_access_config_from_args(&self, &positional_inputs, &named_inputs)?;
+        let compression = try_compression_from_args(&location, &named_inputs)?;
+
+        let source = self
+            .runtime
+            .file_provider()
+            .file_source(location.clone(), &conf)?;
+        let mut source = compression.wrap_source(source);
+
+        // Decode records until we have a large enough sample to infer the
+        // schema from.
+        //
+        // TODO: This throws away the records after inferring.
+        const INFER_SIZE: usize = 16 * 1024;
+        let mut stream = source.read_stream();
+        let mut decoder = LineDecoder::default();
+        let mut records = Vec::new();
+        let mut bytes_read = 0;
+        while bytes_read < INFER_SIZE || records.is_empty() {
+            match stream.next().await {
+                Some(result) => {
+                    let buf = result?;
+                    bytes_read += buf.len();
+                    decoder.decode(&buf, &mut records)?;
+                }
+                None => {
+                    decoder.finish(&mut records)?;
+                    break;
+                }
+            }
+        }
+
+        if records.is_empty() {
+            return Err(RayexecError::new("Stream returned no records"));
+        }
+
+        let schema = infer_schema_from_records(&records)?;
+
+        let datatable = SingleFileNdjsonDataTable {
+            schema: schema.clone(),
+            location,
+            compression,
+            conf,
+            runtime: self.runtime.clone(),
+        };
+
+        Ok(PlannedTableFunction {
+            function: Box::new(self),
+            positional_inputs: positional_inputs.into_iter().map(expr::lit).collect(),
+            named_inputs,
+            function_impl: TableFunctionImpl::Scan(Arc::new(datatable)),
+            cardinality: StatisticsValue::Unknown,
+            schema,
+        })
+    }
+}
@@ -0,0 +1,359 @@
+use std::fmt;
+
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use rayexec_error::{RayexecError, Result, ResultExt};
+use rayexec_execution::arrays::array::Array;
+use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::arrays::bitmap::Bitmap;
+use rayexec_execution::arrays::datatype::{DataType, StructTypeMeta};
+use rayexec_execution::arrays::executor::builder::{ArrayDataBuffer, GermanVarlenBuffer};
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::arrays::storage::{BooleanStorage, PrimitiveStorage, StructStorage};
+use rayexec_io::FileSource;
+
+use crate::value::JsonValue;
+
+/// Max number of records to include in a single batch.
+const BATCH_SIZE: usize = 4096;
+
+/// Splits a byte stream into lines and parses each line into a JSON value.
+#[derive(Debug, Default)]
+pub struct LineDecoder {
+    /// Bytes for a line we haven't seen the end of yet.
+    partial: Vec<u8>,
+
+    /// Current line number, used in error messages.
+    line: usize,
+}
+
+impl LineDecoder {
+    /// Decode all complete lines in `buf`, appending the parsed records to
+    /// `out`.
+    ///
+    /// Bytes following the last newline are held onto until either more
+    /// input arrives, or `finish` is called.
+    pub fn decode(&mut self, buf: &[u8], out: &mut Vec<JsonValue>) -> Result<()> {
+        let last_newline = match buf.iter().rposition(|&b| b == b'\n') {
+            Some(idx) => idx,
+            None => {
+                self.partial.extend_from_slice(buf);
+                return Ok(());
+            }
+        };
+
+        let mut lines = buf[..last_newline].split(|&b| b == b'\n');
+        if let Some(first) = lines.next() {
+            if self.partial.is_empty() {
+                self.decode_line(first, out)?;
+            } else {
+                let mut line = std::mem::take(&mut self.partial);
+                line.extend_from_slice(first);
+                self.decode_line(&line, out)?;
+            }
+        }
+        for line in lines {
+            self.decode_line(line, out)?;
+        }
+
+        self.partial.extend_from_slice(&buf[last_newline + 1..]);
+
+        Ok(())
+    }
+
+    /// Decode any remaining bytes as the final line.
+    pub fn finish(&mut self, out: &mut Vec<JsonValue>) -> Result<()> {
+        let line = std::mem::take(&mut self.partial);
+        self.decode_line(&line, out)
+    }
+
+    fn decode_line(&mut self, line: &[u8], out: &mut Vec<JsonValue>) -> Result<()> {
+        self.line += 1;
+
+        let line = line.trim_ascii();
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let value = serde_json::from_slice(line)
+            .context_fn(|| format!("Failed to parse JSON on line {}", self.line))?;
+        out.push(value);
+
+        Ok(())
+    }
+}
+
+pub struct AsyncNdjsonReader {
+    /// Schema we've inferred.
+    schema: Schema,
+
+    /// Inner stream for getting bytes.
+    stream: BoxStream<'static, Result<Bytes>>,
+
+    /// Decoder for turning bytes into records.
+    decoder: LineDecoder,
+
+    /// Records that have been decoded but not yet emitted in a batch.
+    records: Vec<JsonValue>,
+
+    /// If we've reached the end of the stream.
+    finished: bool,
+}
+
+impl AsyncNdjsonReader {
+    pub fn new(mut reader: impl FileSource, schema: Schema) -> Self {
+        AsyncNdjsonReader {
+            schema,
+            stream: reader.read_stream(),
+            decoder: LineDecoder::default(),
+            records: Vec::new(),
+            finished: false,
+        }
+    }
+
+    pub async fn read_next(&mut self) -> Result<Option<Batch>> {
+        while !self.finished && self.records.len() < BATCH_SIZE {
+            match self.stream.next().await {
+                Some(buf) => self.decoder.decode(&buf?, &mut self.records)?,
+                None => {
+                    self.decoder.finish(&mut self.records)?;
+                    self.finished = true;
+                }
+            }
+        }
+
+        if self.records.is_empty() {
+            return Ok(None);
+        }
+
+        let count = usize::min(BATCH_SIZE, self.records.len());
+        let batch = build_batch(&self.schema, &self.records[..count])?;
+        self.records.drain(..count);
+
+        Ok(Some(batch))
+    }
+}
+
+impl fmt::Debug for AsyncNdjsonReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncNdjsonReader").finish_non_exhaustive()
+    }
+}
+
+/// Build a batch from records.
+///
+/// Keys missing from a record produce NULLs.
+pub fn build_batch(schema: &Schema, records: &[JsonValue]) -> Result<Batch> {
+    let arrays = schema
+        .fields
+        .iter()
+        .map(|field| {
+            let values: Vec<_> = records.iter().map(|r| r.get(&field.name)).collect();
+            build_array(&field.datatype, &values)
+                .context_fn(|| format!("Failed to read values for field '{}'", field.name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Batch::try_new(arrays)
+}
+
+/// Build an array of the given type from values, with missing or null values
+/// producing NULLs.
+fn build_array(datatype: &DataType, values: &[Option<&JsonValue>]) -> Result<Array> {
+    let mut validity = Bitmap::with_capacity(values.len());
+    let values: Vec<_> = values
+        .iter()
+        .map(|v| {
+            let v = v.filter(|v| !v.is_null());
+            validity.push(v.is_some());
+            v
+        })
+        .collect();
+
+    let arr = match datatype {
+        DataType::Boolean => {
+            let mut bools = Bitmap::with_capacity(values.len());
+            for value in values {
+                bools.push(match value {
+                    Some(JsonValue::Bool(b)) => *b,
+                    Some(other) => return Err(unexpected_value_err(datatype, other)),
+                    None => false,
+                });
+            }
+            Array::new_with_validity_and_array_data(
+                datatype.clone(),
+                validity,
+                BooleanStorage::from(bools),
+            )
+        }
+        DataType::Int64 => {
+            let ints = values
+                .into_iter()
+                .map(|value| match value {
+                    Some(v @ JsonValue::Number(n)) => {
+                        n.as_i64().ok_or_else(|| unexpected_value_err(datatype, v))
+                    }
+                    Some(other) => Err(unexpected_value_err(datatype, other)),
+                    None => Ok(0),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Array::new_with_validity_and_array_data(
+                datatype.clone(),
+                validity,
+                PrimitiveStorage::from(ints),
+            )
+        }
+        DataType::Float64 => {
+            let floats = values
+                .into_iter()
+                .map(|value| match value {
+                    Some(v @ JsonValue::Number(n)) => {
+                        n.as_f64().ok_or_else(|| unexpected_value_err(datatype, v))
+                    }
+                    Some(other) => Err(unexpected_value_err(datatype, other)),
+                    None => Ok(0.0),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Array::new_with_validity_and_array_data(
+                datatype.clone(),
+                validity,
+                PrimitiveStorage::from(floats),
+            )
+        }
+        DataType::Utf8 => {
+            let mut strings = GermanVarlenBuffer::<str>::with_len(values.len());
+            for (idx, value) in values.into_iter().enumerate() {
+                match value {
+                    Some(JsonValue::String(s)) => strings.put(idx, s.as_str()),
+                    // Anything else gets kept as JSON text.
+                    Some(other) => strings.put(idx, other.to_string().as_str()),
+                    None => (),
+                }
+            }
+            Array::new_with_validity_and_array_data(datatype.clone(), validity, strings.into_data())
+        }
+        DataType::Struct(StructTypeMeta { fields }) => {
+            if let Some(other) = values
+                .iter()
+                .flatten()
+                .find(|v| !matches!(v, JsonValue::Object(_)))
+            {
+                return Err(unexpected_value_err(datatype, other));
+            }
+
+            let children = fields
+                .iter()
+                .map(|field| {
+                    let child_values: Vec<_> = values
+                        .iter()
+                        .map(|v| v.and_then(|v| v.get(&field.name)))
+                        .collect();
+                    build_array(&field.datatype, &child_values)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Array::new_with_validity_and_array_data(
+                datatype.clone(),
+                validity,
+                StructStorage::try_new(values.len(), children)?,
+            )
+        }
+        other => return Err(RayexecError::new(format!("Unhandled data type: {other}"))),
+    };
+
+    Ok(arr)
+}
+
+fn unexpected_value_err(datatype: &DataType, value: &JsonValue) -> RayexecError {
+    RayexecError::new(format!("Unable to read '{value}' as {datatype}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use rayexec_execution::arrays::field::Field;
+    use rayexec_execution::arrays::scalar::ScalarValue;
+
+    use super::*;
+
+    fn decode_all(chunks: &[&str]) -> Vec<JsonValue> {
+        let mut decoder = LineDecoder::default();
+        let mut out = Vec::new();
+        for chunk in chunks {
+            decoder.decode(chunk.as_bytes(), &mut out).unwrap();
+        }
+        decoder.finish(&mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn decode_lines_across_chunks() {
+        let records = decode_all(&["{\"a\": 1}\n{\"a\"", ": 2}\n\n", "{\"a\": 3}"]);
+
+        let expected: Vec<JsonValue> = ["{\"a\": 1}", "{\"a\": 2}", "{\"a\": 3}"]
+            .iter()
+            .map(|s| serde_json::from_str(s).unwrap())
+            .collect();
+        assert_eq!(expected, records);
+    }
+
+    #[test]
+    fn decode_invalid_line() {
+        let mut decoder = LineDecoder::default();
+        let mut out = Vec::new();
+        decoder
+            .decode(b"{\"a\": 1}\n{\"a\": \n", &mut out)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn build_batch_ragged_records() {
+        let schema = Schema::new([
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new(
+                "c",
+                DataType::Struct(StructTypeMeta {
+                    fields: vec![Field::new("x", DataType::Float64, true)],
+                }),
+                true,
+            ),
+        ]);
+        let records = decode_all(&[
+            "{\"a\": 1, \"b\": \"one\", \"c\": {\"x\": 1.5}}\n",
+            "{\"b\": 2}\n",
+            "{\"a\": 3, \"c\": {}}\n",
+        ]);
+
+        let batch = build_batch(&schema, &records).unwrap();
+        let a = batch.column(0).unwrap();
+        let b = batch.column(1).unwrap();
+        let c = batch.column(2).unwrap();
+
+        assert_eq!(ScalarValue::Int64(1), a.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::Null, a.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::Int64(3), a.logical_value(2).unwrap());
+
+        assert_eq!(ScalarValue::from("one"), b.logical_value(0).unwrap());
+        assert_eq!(ScalarValue::from("2"), b.logical_value(1).unwrap());
+        assert_eq!(ScalarValue::Null, b.logical_value(2).unwrap());
+
+        assert_eq!(
+            ScalarValue::Struct(vec![ScalarValue::Float64(1.5)]),
+            c.logical_value(0).unwrap()
+        );
+        assert_eq!(ScalarValue::Null, c.logical_value(1).unwrap());
+        assert_eq!(
+            ScalarValue::Struct(vec![ScalarValue::Null]),
+            c.logical_value(2).unwrap()
+        );
+    }
+
+    #[test]
+    fn build_batch_mismatched_type() {
+        let schema = Schema::new([Field::new("a", DataType::Int64, true)]);
+        let records = decode_all(&["{\"a\": \"x\"}\n"]);
+
+        build_batch(&schema, &records).unwrap_err();
+    }
+}
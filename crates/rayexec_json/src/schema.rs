@@ -0,0 +1,212 @@
+//! # Inference
+//!
+//! The schema is inferred from a sample of records. Every record must be a
+//! JSON object, and the columns are the union of all keys found across the
+//! sample, in the order they're first seen.
+//!
+//! Each column starts out as a "null" candidate and gets widened as values
+//! are seen:
+//!
+//! - Integers produce Int64, and any floating point value widens to Float64.
+//! - Objects produce structs, with their fields inferred the same way.
+//! - Strings and arrays produce Utf8 (arrays are kept as JSON text).
+//! - Any other combination of types (e.g. a number and a string) widens to
+//!   Utf8.
+//!
+//! Columns containing only nulls in the sample are read as Utf8.
+use rayexec_error::{RayexecError, Result};
+use rayexec_execution::arrays::datatype::{DataType, StructTypeMeta};
+use rayexec_execution::arrays::field::{Field, Schema};
+
+use crate::value::JsonValue;
+
+#[derive(Debug, Clone, PartialEq)]
+enum CandidateType {
+    /// Only nulls seen so far.
+    Null,
+    Boolean,
+    Int64,
+    Float64,
+    Utf8,
+    /// Object with the candidate types for each field.
+    Struct(Vec<(String, CandidateType)>),
+}
+
+impl CandidateType {
+    fn from_value(value: &JsonValue) -> Self {
+        match value {
+            JsonValue::Null => Self::Null,
+            JsonValue::Bool(_) => Self::Boolean,
+            JsonValue::Number(n) => {
+                if n.is_i64() {
+                    Self::Int64
+                } else {
+                    Self::Float64
+                }
+            }
+            JsonValue::String(_) | JsonValue::Array(_) => Self::Utf8,
+            JsonValue::Object(fields) => Self::Struct(
+                fields
+                    .iter()
+                    .map(|(key, val)| (key.clone(), Self::from_value(val)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Merge two candidate types into a type that can hold both.
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Null, other) | (other, Self::Null) => other,
+            (Self::Int64, Self::Float64) | (Self::Float64, Self::Int64) => Self::Float64,
+            (Self::Struct(mut fields), Self::Struct(others)) => {
+                merge_fields(&mut fields, others);
+                Self::Struct(fields)
+            }
+            (a, b) if a == b => a,
+            _ => Self::Utf8,
+        }
+    }
+
+    fn into_datatype(self) -> DataType {
+        match self {
+            Self::Null | Self::Utf8 => DataType::Utf8,
+            Self::Boolean => DataType::Boolean,
+            Self::Int64 => DataType::Int64,
+            Self::Float64 => DataType::Float64,
+            // Can't have a struct without fields, just keep the JSON text.
+            Self::Struct(fields) if fields.is_empty() => DataType::Utf8,
+            Self::Struct(fields) => DataType::Struct(StructTypeMeta {
+                fields: into_fields(fields),
+            }),
+        }
+    }
+}
+
+/// Merge `others` into `fields`, appending any fields not yet seen.
+fn merge_fields(fields: &mut Vec<(String, CandidateType)>, others: Vec<(String, CandidateType)>) {
+    for (key, other) in others {
+        match fields.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => {
+                let curr = std::mem::replace(existing, CandidateType::Null);
+                *existing = curr.merge(other);
+            }
+            None => fields.push((key, other)),
+        }
+    }
+}
+
+fn into_fields(fields: Vec<(String, CandidateType)>) -> Vec<Field> {
+    fields
+        .into_iter()
+        .map(|(name, candidate)| Field::new(name, candidate.into_datatype(), true))
+        .collect()
+}
+
+/// Infer a schema from a sample of records.
+pub fn infer_schema_from_records<'a>(
+    records: impl IntoIterator<Item = &'a JsonValue>,
+) -> Result<Schema> {
+    let mut fields = Vec::new();
+
+    for (idx, record) in records.into_iter().enumerate() {
+        match CandidateType::from_value(record) {
+            CandidateType::Struct(others) => merge_fields(&mut fields, others),
+            _ => {
+                return Err(RayexecError::new(format!(
+                    "Expected JSON object for record {}, got '{record}'",
+                    idx + 1
+                )))
+            }
+        }
+    }
+
+    if fields.is_empty() {
+        return Err(RayexecError::new(
+            "Unable to infer JSON schema, no fields found in records",
+        ));
+    }
+
+    Ok(Schema::new(into_fields(fields)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn infer(lines: &[&str]) -> Schema {
+        let records: Vec<JsonValue> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        infer_schema_from_records(&records).unwrap()
+    }
+
+    fn fields(schema: &Schema) -> Vec<(&str, DataType)> {
+        schema
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.datatype.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn union_of_keys() {
+        let schema = infer(&[r#"{"a": 1, "b": "x"}"#, r#"{"c": true, "a": 2}"#]);
+
+        let expected = vec![
+            ("a", DataType::Int64),
+            ("b", DataType::Utf8),
+            ("c", DataType::Boolean),
+        ];
+        assert_eq!(expected, fields(&schema));
+    }
+
+    #[test]
+    fn widen_types() {
+        let schema = infer(&[
+            r#"{"i": 1, "f": 1, "s": 1, "n": null}"#,
+            r#"{"i": null, "f": 2.5, "s": "two", "n": null}"#,
+        ]);
+
+        let expected = vec![
+            ("i", DataType::Int64),
+            ("f", DataType::Float64),
+            ("s", DataType::Utf8),
+            ("n", DataType::Utf8),
+        ];
+        assert_eq!(expected, fields(&schema));
+    }
+
+    #[test]
+    fn nested_struct() {
+        let schema = infer(&[
+            r#"{"o": {"x": 1}}"#,
+            r#"{"o": {"y": "a", "x": 2.5}}"#,
+            r#"{"o": null}"#,
+        ]);
+
+        let expected = vec![(
+            "o",
+            DataType::Struct(StructTypeMeta {
+                fields: vec![
+                    Field::new("x", DataType::Float64, true),
+                    Field::new("y", DataType::Utf8, true),
+                ],
+            }),
+        )];
+        assert_eq!(expected, fields(&schema));
+    }
+
+    #[test]
+    fn struct_and_scalar_widen_to_utf8() {
+        let schema = infer(&[r#"{"o": {"x": 1}}"#, r#"{"o": 3}"#]);
+        assert_eq!(vec![("o", DataType::Utf8)], fields(&schema));
+    }
+
+    #[test]
+    fn non_object_record() {
+        let records: Vec<JsonValue> = vec![serde_json::from_str("[1, 2]").unwrap()];
+        infer_schema_from_records(&records).unwrap_err();
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use serde_json::Number;
+
+/// A parsed JSON value.
+///
+/// Similar to `serde_json::Value`, except that objects retain the order in
+/// which keys appear in the input. Key order determines column order during
+/// schema inference.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Get the value for a key if this value is an object.
+    ///
+    /// Returns None if the key doesn't exist, or if this isn't an object.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            Self::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&s)
+    }
+}
+
+impl Serialize for JsonValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Null => serializer.serialize_unit(),
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::Number(v) => v.serialize(serializer),
+            Self::String(v) => serializer.serialize_str(v),
+            Self::Array(vals) => {
+                let mut seq = serializer.serialize_seq(Some(vals.len()))?;
+                for val in vals {
+                    seq.serialize_element(val)?;
+                }
+                seq.end()
+            }
+            Self::Object(fields) => {
+                let mut map = serializer.serialize_map(Some(fields.len()))?;
+                for (key, val) in fields {
+                    map.serialize_entry(key, val)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(JsonValueVisitor)
+    }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(JsonValue::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(JsonValue::Number(v.into()))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(JsonValue::Number(v.into()))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        // Non-finite floats can't be represented in JSON.
+        Ok(Number::from_f64(v).map_or(JsonValue::Null, JsonValue::Number))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(JsonValue::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+        Ok(JsonValue::String(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut vals = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(val) = seq.next_element()? {
+            vals.push(val);
+        }
+        Ok(JsonValue::Array(vals))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields: Vec<(String, JsonValue)> = Vec::new();
+        while let Some((key, val)) = map.next_entry::<String, JsonValue>()? {
+            // Later duplicate keys overwrite earlier ones, matching
+            // `serde_json::Value`.
+            match fields.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, existing)) => *existing = val,
+                None => fields.push((key, val)),
+            }
+        }
+        Ok(JsonValue::Object(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn object_key_order_preserved() {
+        let v: JsonValue = serde_json::from_str(r#"{"b": 1, "a": "x", "c": null}"#).unwrap();
+
+        let keys: Vec<_> = match &v {
+            JsonValue::Object(fields) => fields.iter().map(|(k, _)| k.as_str()).collect(),
+            other => panic!("unexpected value: {other:?}"),
+        };
+        assert_eq!(vec!["b", "a", "c"], keys);
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        let input = r#"{"b":[1,2.5,"s"],"a":{"n":null,"t":true}}"#;
+        let v: JsonValue = serde_json::from_str(input).unwrap();
+
+        assert_eq!(input, v.to_string());
+    }
+}
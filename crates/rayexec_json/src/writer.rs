@@ -0,0 +1,193 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use rayexec_error::{RayexecError, Result, ResultExt};
+use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::arrays::datatype::{DataType, TimeUnit};
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::arrays::scalar::ScalarValue;
+use serde_json::Number;
+
+use crate::value::JsonValue;
+
+/// Encodes batches as newline-delimited JSON, writing one object per row keyed
+/// by the field names in the schema.
+#[derive(Debug)]
+pub struct NdjsonEncoder {
+    /// Schema of the batches we're writing.
+    schema: Schema,
+}
+
+impl NdjsonEncoder {
+    pub fn new(schema: Schema) -> Self {
+        NdjsonEncoder { schema }
+    }
+
+    pub fn encode(&self, batch: &Batch, output_buf: &mut Vec<u8>) -> Result<()> {
+        if batch.columns().len() != self.schema.fields.len() {
+            return Err(RayexecError::new(format!(
+                "Batch has {} columns, expected {}",
+                batch.columns().len(),
+                self.schema.fields.len()
+            )));
+        }
+
+        for row in 0..batch.num_rows() {
+            let fields = self
+                .schema
+                .fields
+                .iter()
+                .zip(batch.columns())
+                .map(|(field, col)| {
+                    let scalar = col.logical_value(row)?;
+                    Ok((field.name.clone(), scalar_to_json(&field.datatype, scalar)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            serde_json::to_writer(&mut *output_buf, &JsonValue::Object(fields))
+                .context("failed to write record")?;
+            output_buf.push(b'\n');
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert a scalar to a JSON value.
+///
+/// Numbers are written as JSON numbers, with non-finite floats written as
+/// null. Dates and timestamps are written as ISO-8601 strings. Types without
+/// a JSON equivalent are written using their string representation.
+fn scalar_to_json(datatype: &DataType, scalar: ScalarValue) -> Result<JsonValue> {
+    let value = match scalar {
+        ScalarValue::Null => JsonValue::Null,
+        ScalarValue::Boolean(v) => JsonValue::Bool(v),
+        ScalarValue::Int8(v) => JsonValue::Number(v.into()),
+        ScalarValue::Int16(v) => JsonValue::Number(v.into()),
+        ScalarValue::Int32(v) => JsonValue::Number(v.into()),
+        ScalarValue::Int64(v) => JsonValue::Number(v.into()),
+        ScalarValue::UInt8(v) => JsonValue::Number(v.into()),
+        ScalarValue::UInt16(v) => JsonValue::Number(v.into()),
+        ScalarValue::UInt32(v) => JsonValue::Number(v.into()),
+        ScalarValue::UInt64(v) => JsonValue::Number(v.into()),
+        ScalarValue::Int128(v) => match i64::try_from(v) {
+            Ok(v) => JsonValue::Number(v.into()),
+            Err(_) => JsonValue::String(v.to_string()),
+        },
+        ScalarValue::UInt128(v) => match u64::try_from(v) {
+            Ok(v) => JsonValue::Number(v.into()),
+            Err(_) => JsonValue::String(v.to_string()),
+        },
+        ScalarValue::Float16(v) => float_to_json(v.to_f64()),
+        ScalarValue::Float32(v) => float_to_json(v as f64),
+        ScalarValue::Float64(v) => float_to_json(v),
+        v @ (ScalarValue::Decimal64(_) | ScalarValue::Decimal128(_)) => {
+            let s = v.to_string();
+            let f = s
+                .parse::<f64>()
+                .context_fn(|| format!("failed to convert decimal '{s}' to a JSON number"))?;
+            float_to_json(f)
+        }
+        ScalarValue::Date32(v) => {
+            let datetime = DateTime::from_timestamp(v as i64 * 86400, 0)
+                .ok_or_else(|| RayexecError::new(format!("Date out of range: {v}")))?;
+            JsonValue::String(datetime.format("%Y-%m-%d").to_string())
+        }
+        ScalarValue::Date64(v) => {
+            let datetime = DateTime::from_timestamp_millis(v)
+                .ok_or_else(|| RayexecError::new(format!("Date out of range: {v}")))?;
+            JsonValue::String(datetime.format("%Y-%m-%d").to_string())
+        }
+        ScalarValue::Timestamp(v) => {
+            let datetime = match v.unit {
+                TimeUnit::Second => DateTime::from_timestamp(v.value, 0),
+                TimeUnit::Millisecond => DateTime::from_timestamp_millis(v.value),
+                TimeUnit::Microsecond => DateTime::from_timestamp_micros(v.value),
+                TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(v.value)),
+            }
+            .ok_or_else(|| RayexecError::new(format!("Timestamp out of range: {}", v.value)))?;
+            JsonValue::String(format_timestamp(datetime))
+        }
+        ScalarValue::Utf8(v) => JsonValue::String(v.into_owned()),
+        ScalarValue::Struct(values) => {
+            let fields = match datatype {
+                DataType::Struct(m) => &m.fields,
+                other => return Err(unexpected_type_err(other, "struct")),
+            };
+            let fields = fields
+                .iter()
+                .zip(values)
+                .map(|(field, value)| {
+                    Ok((field.name.clone(), scalar_to_json(&field.datatype, value)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            JsonValue::Object(fields)
+        }
+        ScalarValue::List(values) => {
+            let element_type = match datatype {
+                DataType::List(m) => m.datatype.as_ref(),
+                other => return Err(unexpected_type_err(other, "list")),
+            };
+            let values = values
+                .into_iter()
+                .map(|value| scalar_to_json(element_type, value))
+                .collect::<Result<Vec<_>>>()?;
+            JsonValue::Array(values)
+        }
+        ScalarValue::Map(entries) => {
+            let value_type = match datatype {
+                DataType::Map(m) => m.value.as_ref(),
+                other => return Err(unexpected_type_err(other, "map")),
+            };
+            // JSON object keys must be strings.
+            let fields = entries
+                .into_iter()
+                .map(|(key, value)| Ok((key.to_string(), scalar_to_json(value_type, value)?)))
+                .collect::<Result<Vec<_>>>()?;
+            JsonValue::Object(fields)
+        }
+        other @ (ScalarValue::Interval(_) | ScalarValue::Binary(_)) => {
+            JsonValue::String(other.to_string())
+        }
+    };
+
+    Ok(value)
+}
+
+fn float_to_json(v: f64) -> JsonValue {
+    Number::from_f64(v).map_or(JsonValue::Null, JsonValue::Number)
+}
+
+/// Format a timestamp as ISO-8601, only including fractional seconds when
+/// needed.
+fn format_timestamp(datetime: DateTime<Utc>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+fn unexpected_type_err(datatype: &DataType, expected: &str) -> RayexecError {
+    RayexecError::new(format!("Expected {expected} type, got {datatype}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use rayexec_execution::arrays::datatype::TimestampTypeMeta;
+    use rayexec_execution::arrays::scalar::timestamp::TimestampScalar;
+
+    use super::*;
+
+    #[test]
+    fn timestamp_as_iso8601() {
+        let datatype = DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond));
+        let value = scalar_to_json(
+            &datatype,
+            ScalarValue::Timestamp(TimestampScalar {
+                unit: TimeUnit::Microsecond,
+                value: 1_577_934_245_123_000,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            JsonValue::String("2020-01-02T03:04:05.123Z".to_string()),
+            value
+        );
+    }
+}
@@ -2,16 +2,17 @@ use std::fmt;
 
 use futures::future::BoxFuture;
 use futures::FutureExt;
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Schema;
 use rayexec_execution::execution::operators::sink::PartitionSink;
-use rayexec_execution::functions::copy::CopyToFunction;
+use rayexec_execution::functions::copy::{unique_file_name, CopyToArgs, CopyToFunction};
 use rayexec_execution::runtime::Runtime;
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::FileProvider;
 
-use crate::writer::AsyncBatchWriter;
+use crate::has_extension;
+use crate::writer::{AsyncBatchWriter, ParquetWriteOptions};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ParquetCopyToFunction<R: Runtime> {
@@ -23,18 +24,45 @@ impl<R: Runtime> CopyToFunction for ParquetCopyToFunction<R> {
         "parquet_copy_to"
     }
 
+    fn file_extension(&self) -> Option<&'static str> {
+        Some("parquet")
+    }
+
+    fn validate_args(&self, args: &CopyToArgs) -> Result<()> {
+        let mut args = args.clone();
+        args.try_remove_append()?;
+        ParquetWriteOptions::try_from_args(&args)?;
+        Ok(())
+    }
+
     fn create_sinks(
         &self,
         schema: Schema,
         location: FileLocation,
+        mut args: CopyToArgs,
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>> {
         let provider = self.runtime.file_provider();
+        let append = args.try_remove_append()?;
+        let options = ParquetWriteOptions::try_from_args(&args)?;
+
+        if append && has_extension(&location) {
+            return Err(RayexecError::new(format!(
+                "Cannot APPEND to the parquet file '{location}', parquet files can't be modified once written. Use a directory as the COPY TO target to append new files to it."
+            )));
+        }
 
         let mut sinks = Vec::with_capacity(num_partitions);
         for _ in 0..num_partitions {
-            let sink = provider.file_sink(location.clone(), &AccessConfig::None)?;
-            let writer = AsyncBatchWriter::try_new(sink, schema.clone())?;
+            // When appending, each partition writes a new file into the
+            // target directory.
+            let location = if append {
+                location.join([unique_file_name("parquet")])?
+            } else {
+                location.clone()
+            };
+            let sink = provider.file_sink(location, &AccessConfig::None)?;
+            let writer = AsyncBatchWriter::try_new_with_options(sink, schema.clone(), &options)?;
             sinks.push(Box::new(ParquetCopyToSink { writer }) as _)
         }
 
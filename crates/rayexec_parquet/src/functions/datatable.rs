@@ -6,13 +6,15 @@ use futures::future::BoxFuture;
 use rayexec_error::Result;
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::logical::scan_filter::ScanFilter;
 use rayexec_execution::runtime::Runtime;
 use rayexec_execution::storage::table_storage::{DataTable, DataTableScan, Projections};
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::{FileProvider, FileSource};
 
 use crate::metadata::Metadata;
-use crate::reader::AsyncBatchReader;
+use crate::prune::prune_row_groups;
+use crate::reader::{AsyncBatchReader, RowFilter};
 
 /// Data table implementation which parallelizes on row groups. During scanning,
 /// each returned scan object is responsible for distinct row groups to read.
@@ -25,10 +27,12 @@ pub struct RowGroupPartitionedDataTable<R: Runtime> {
     pub runtime: R,
 }
 
-impl<R: Runtime> DataTable for RowGroupPartitionedDataTable<R> {
-    fn scan(
+impl<R: Runtime> RowGroupPartitionedDataTable<R> {
+    fn scan_row_groups(
         &self,
         projections: Projections,
+        row_groups: impl IntoIterator<Item = usize>,
+        row_filter: Option<RowFilter>,
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
         let file_provider = self.runtime.file_provider();
@@ -36,8 +40,8 @@ impl<R: Runtime> DataTable for RowGroupPartitionedDataTable<R> {
         let mut partitioned_row_groups = vec![VecDeque::new(); num_partitions];
 
         // Split row groups into individual partitions.
-        for row_group in 0..self.metadata.decoded_metadata.row_groups().len() {
-            let partition = row_group % num_partitions;
+        for (idx, row_group) in row_groups.into_iter().enumerate() {
+            let partition = idx % num_partitions;
             partitioned_row_groups[partition].push_back(row_group);
         }
 
@@ -46,13 +50,14 @@ impl<R: Runtime> DataTable for RowGroupPartitionedDataTable<R> {
             .map(|row_groups| {
                 let reader = file_provider.file_source(self.location.clone(), &self.conf)?;
                 const BATCH_SIZE: usize = 4096; // TODO
-                AsyncBatchReader::try_new(
+                AsyncBatchReader::try_new_with_row_filter(
                     reader,
                     row_groups,
                     self.metadata.clone(),
                     &self.schema,
                     BATCH_SIZE,
                     projections.clone(),
+                    row_filter.clone(),
                 )
             })
             .collect::<Result<Vec<_>>>()?;
@@ -66,6 +71,28 @@ impl<R: Runtime> DataTable for RowGroupPartitionedDataTable<R> {
     }
 }
 
+impl<R: Runtime> DataTable for RowGroupPartitionedDataTable<R> {
+    fn scan(
+        &self,
+        projections: Projections,
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        let num_row_groups = self.metadata.decoded_metadata.row_groups().len();
+        self.scan_row_groups(projections, 0..num_row_groups, None, num_partitions)
+    }
+
+    fn scan_with_filters(
+        &self,
+        projections: Projections,
+        filters: &[ScanFilter],
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        let row_groups = prune_row_groups(&self.metadata.decoded_metadata, &self.schema, filters);
+        let row_filter = RowFilter::try_from_scan_filters(&self.schema, filters)?;
+        self.scan_row_groups(projections, row_groups, row_filter, num_partitions)
+    }
+}
+
 struct RowGroupsScan {
     reader: AsyncBatchReader<Box<dyn FileSource>>,
 }
@@ -1,3 +1,4 @@
 pub mod read_parquet;
 
 mod datatable; // Should be moved up, other data sources (iceberg, delta) would find this useful.
+mod multi_file;
@@ -0,0 +1,396 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use rayexec_error::{RayexecError, Result};
+use rayexec_execution::arrays::array::Array;
+use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::arrays::field::{Field, Schema};
+use rayexec_execution::logical::scan_filter::ScanFilter;
+use rayexec_execution::runtime::Runtime;
+use rayexec_execution::storage::table_storage::{DataTable, DataTableScan, Projections};
+use rayexec_io::location::{AccessConfig, FileLocation};
+use rayexec_io::{FileProvider, FileSource};
+
+use crate::metadata::Metadata;
+use crate::prune::prune_row_groups;
+use crate::reader::{AsyncBatchReader, RowFilter};
+
+/// A single parquet file that's part of a multi-file table.
+#[derive(Debug)]
+pub struct ParquetFile {
+    pub location: FileLocation,
+    pub metadata: Arc<Metadata>,
+    /// Schema of the file itself.
+    pub schema: Schema,
+    /// Index of the column in this file for each column in the unified schema.
+    ///
+    /// None if the file doesn't contain the column.
+    pub column_map: Vec<Option<usize>>,
+}
+
+impl ParquetFile {
+    fn num_rows(&self) -> usize {
+        self.metadata
+            .decoded_metadata
+            .row_groups()
+            .iter()
+            .map(|g| g.num_rows())
+            .sum::<i64>() as usize
+    }
+
+    /// Map filters on the unified schema to filters on this file's columns.
+    ///
+    /// Filters on columns missing from the file are dropped.
+    fn map_filters(&self, filters: &[ScanFilter]) -> Vec<ScanFilter> {
+        filters
+            .iter()
+            .filter_map(|filter| {
+                let column = (*self.column_map.get(filter.column)?)?;
+                Some(ScanFilter {
+                    column,
+                    filter: filter.filter.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Compute a schema containing the union of all columns in the provided file
+/// schemas.
+///
+/// Columns are matched by name, and are ordered by when they're first seen.
+/// Columns with the same name must have the same type in every file containing
+/// them.
+///
+/// Returns the unified schema alongside the column mappings for each file
+/// (see `ParquetFile::column_map`).
+pub fn unify_schemas<'a>(
+    files: impl IntoIterator<Item = (&'a FileLocation, &'a Schema)>,
+) -> Result<(Schema, Vec<Vec<Option<usize>>>)> {
+    let mut fields: Vec<Field> = Vec::new();
+    // Where each unified column was first seen, used for error messages.
+    let mut origins: Vec<&FileLocation> = Vec::new();
+    // Unified column index -> file column index for each file. Extended to the
+    // full schema width once all files are processed.
+    let mut column_maps: Vec<Vec<Option<usize>>> = Vec::new();
+
+    for (location, schema) in files {
+        let mut column_map = vec![None; fields.len()];
+
+        for (file_idx, file_field) in schema.fields.iter().enumerate() {
+            match fields.iter().position(|f| f.name == file_field.name) {
+                Some(idx) => {
+                    let existing = &fields[idx];
+                    if existing.datatype != file_field.datatype {
+                        return Err(RayexecError::new(format!(
+                            "Column '{}' has conflicting types across parquet files: {} in '{}', {} in '{}'",
+                            file_field.name,
+                            existing.datatype,
+                            origins[idx],
+                            file_field.datatype,
+                            location,
+                        )));
+                    }
+                    column_map[idx] = Some(file_idx);
+                }
+                None => {
+                    // Columns may be missing from other files, so always
+                    // nullable.
+                    fields.push(Field::new(
+                        file_field.name.clone(),
+                        file_field.datatype.clone(),
+                        true,
+                    ));
+                    origins.push(location);
+                    column_map.push(Some(file_idx));
+                }
+            }
+        }
+
+        column_maps.push(column_map);
+    }
+
+    for column_map in &mut column_maps {
+        column_map.resize(fields.len(), None);
+    }
+
+    Ok((Schema::new(fields), column_maps))
+}
+
+/// Data table reading from multiple parquet files with possibly differing
+/// schemas.
+///
+/// Columns missing from a file are filled with NULLs.
+#[derive(Debug)]
+pub struct MultiFileDataTable<R: Runtime> {
+    pub files: Arc<[ParquetFile]>,
+    pub schema: Schema,
+    pub conf: AccessConfig,
+    pub runtime: R,
+}
+
+impl<R: Runtime> MultiFileDataTable<R> {
+    pub fn num_rows(&self) -> usize {
+        self.files.iter().map(|f| f.num_rows()).sum()
+    }
+
+    fn scan_files(
+        &self,
+        projections: Projections,
+        filters: &[ScanFilter],
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        let projected: Vec<usize> = match projections.column_indices {
+            Some(indices) => indices,
+            None => (0..self.schema.fields.len()).collect(),
+        };
+
+        // Split (file, row group) pairs across partitions, grouping row groups
+        // from the same file so each partition only opens a file once.
+        let mut partitioned: Vec<VecDeque<(usize, VecDeque<usize>)>> =
+            vec![VecDeque::new(); num_partitions];
+        let mut row_filters = Vec::with_capacity(self.files.len());
+        let mut idx = 0;
+        for (file_idx, file) in self.files.iter().enumerate() {
+            let file_filters = file.map_filters(filters);
+            row_filters.push(RowFilter::try_from_scan_filters(
+                &file.schema,
+                &file_filters,
+            )?);
+
+            let row_groups =
+                prune_row_groups(&file.metadata.decoded_metadata, &file.schema, &file_filters);
+            for row_group in row_groups {
+                let partition = &mut partitioned[idx % num_partitions];
+                match partition.back_mut() {
+                    Some((last, row_groups)) if *last == file_idx => {
+                        row_groups.push_back(row_group)
+                    }
+                    _ => partition.push_back((file_idx, VecDeque::from([row_group]))),
+                }
+                idx += 1;
+            }
+        }
+
+        let row_filters: Arc<[Option<RowFilter>]> = row_filters.into();
+
+        let scans = partitioned
+            .into_iter()
+            .map(|pending| {
+                Box::new(MultiFileScan {
+                    files: self.files.clone(),
+                    schema: self.schema.clone(),
+                    projected: projected.clone(),
+                    row_filters: row_filters.clone(),
+                    file_provider: self.runtime.file_provider(),
+                    conf: self.conf.clone(),
+                    pending,
+                    current: None,
+                }) as _
+            })
+            .collect();
+
+        Ok(scans)
+    }
+}
+
+impl<R: Runtime> DataTable for MultiFileDataTable<R> {
+    fn scan(
+        &self,
+        projections: Projections,
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        self.scan_files(projections, &[], num_partitions)
+    }
+
+    fn scan_with_filters(
+        &self,
+        projections: Projections,
+        filters: &[ScanFilter],
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        self.scan_files(projections, filters, num_partitions)
+    }
+}
+
+/// Reader for a single file, along with how to map its output into the
+/// unified schema.
+struct FileReader {
+    reader: AsyncBatchReader<Box<dyn FileSource>>,
+    /// For each projected column, the index of the column in the reader's
+    /// output batch. None if the column needs to be filled with NULLs.
+    output_columns: Vec<Option<usize>>,
+}
+
+struct MultiFileScan<P: FileProvider> {
+    files: Arc<[ParquetFile]>,
+    schema: Schema,
+    /// Projected columns from the unified schema.
+    projected: Vec<usize>,
+    /// Row filter to use when reading each file.
+    row_filters: Arc<[Option<RowFilter>]>,
+    file_provider: Arc<P>,
+    conf: AccessConfig,
+    /// Files and their row groups remaining to be read.
+    pending: VecDeque<(usize, VecDeque<usize>)>,
+    /// Reader for the file we're currently reading.
+    current: Option<FileReader>,
+}
+
+impl<P: FileProvider> MultiFileScan<P> {
+    async fn pull_inner(&mut self) -> Result<Option<Batch>> {
+        loop {
+            let current = match &mut self.current {
+                Some(current) => current,
+                None => match self.pending.pop_front() {
+                    Some((file_idx, row_groups)) => {
+                        self.current = Some(self.open_file(file_idx, row_groups)?);
+                        continue;
+                    }
+                    None => return Ok(None),
+                },
+            };
+
+            let batch = match current.reader.read_next().await? {
+                Some(batch) => batch,
+                None => {
+                    self.current = None;
+                    continue;
+                }
+            };
+
+            if self.projected.is_empty() {
+                return Ok(Some(Batch::empty_with_num_rows(batch.num_rows())));
+            }
+
+            let arrays = self
+                .projected
+                .iter()
+                .zip(&current.output_columns)
+                .map(|(&col, output)| match output {
+                    Some(idx) => Ok(batch.column(*idx).expect("column to exist").clone()),
+                    None => Array::new_typed_null_array(
+                        self.schema.fields[col].datatype.clone(),
+                        batch.num_rows(),
+                    ),
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            return Ok(Some(Batch::try_new(arrays)?));
+        }
+    }
+
+    fn open_file(&self, file_idx: usize, row_groups: VecDeque<usize>) -> Result<FileReader> {
+        let file = &self.files[file_idx];
+
+        // File columns we need to read, in the order the reader will output
+        // them.
+        let mut file_columns: Vec<usize> = self
+            .projected
+            .iter()
+            .filter_map(|&col| file.column_map[col])
+            .collect();
+        file_columns.sort_unstable();
+        file_columns.dedup();
+
+        let output_columns = self
+            .projected
+            .iter()
+            .map(|&col| {
+                file.column_map[col]
+                    .map(|file_col| file_columns.binary_search(&file_col).expect("column to exist"))
+            })
+            .collect();
+
+        // We still need to read something from the file to know how many
+        // rows to produce even if none of the projected columns are in it.
+        if file_columns.is_empty() {
+            file_columns.push(0);
+        }
+
+        let source = self
+            .file_provider
+            .file_source(file.location.clone(), &self.conf)?;
+        const BATCH_SIZE: usize = 4096; // TODO
+        let reader = AsyncBatchReader::try_new_with_row_filter(
+            source,
+            row_groups,
+            file.metadata.clone(),
+            &file.schema,
+            BATCH_SIZE,
+            Projections {
+                column_indices: Some(file_columns),
+            },
+            self.row_filters[file_idx].clone(),
+        )?;
+
+        Ok(FileReader {
+            reader,
+            output_columns,
+        })
+    }
+}
+
+impl<P: FileProvider> DataTableScan for MultiFileScan<P> {
+    fn pull(&mut self) -> BoxFuture<'_, Result<Option<Batch>>> {
+        Box::pin(async { self.pull_inner().await })
+    }
+}
+
+impl<P: FileProvider> fmt::Debug for MultiFileScan<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiFileScan")
+            .field("projected", &self.projected)
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayexec_execution::arrays::datatype::DataType;
+
+    use super::*;
+
+    fn location(s: &str) -> FileLocation {
+        FileLocation::parse(s)
+    }
+
+    #[test]
+    fn unify_disjoint_and_reordered_columns() {
+        let f1 = Schema::new([
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let f2 = Schema::new([
+            Field::new("c", DataType::Float64, false),
+            Field::new("a", DataType::Int64, false),
+        ]);
+        let (l1, l2) = (location("f1.parquet"), location("f2.parquet"));
+
+        let (schema, column_maps) = unify_schemas([(&l1, &f1), (&l2, &f2)]).unwrap();
+
+        let expected = Schema::new([
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Float64, true),
+        ]);
+        assert_eq!(expected, schema);
+        assert_eq!(
+            vec![vec![Some(0), Some(1), None], vec![Some(1), None, Some(0)]],
+            column_maps
+        );
+    }
+
+    #[test]
+    fn unify_type_conflict() {
+        let f1 = Schema::new([Field::new("a", DataType::Int64, false)]);
+        let f2 = Schema::new([Field::new("a", DataType::Utf8, false)]);
+        let (l1, l2) = (location("f1.parquet"), location("f2.parquet"));
+
+        let err = unify_schemas([(&l1, &f1), (&l2, &f2)]).unwrap_err();
+        assert!(err.to_string().contains("conflicting types"), "{err}");
+    }
+}
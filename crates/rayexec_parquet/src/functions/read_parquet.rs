@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::future::BoxFuture;
-use futures::FutureExt;
+use futures::{FutureExt, TryStreamExt};
 use rayexec_error::Result;
 use rayexec_execution::arrays::datatype::DataTypeId;
 use rayexec_execution::arrays::scalar::OwnedScalarValue;
@@ -19,9 +19,12 @@ use rayexec_execution::functions::table::{
 use rayexec_execution::functions::{FunctionInfo, Signature};
 use rayexec_execution::logical::statistics::StatisticsValue;
 use rayexec_execution::runtime::Runtime;
+use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::FileProvider;
 
 use super::datatable::RowGroupPartitionedDataTable;
+use super::multi_file::{unify_schemas, MultiFileDataTable, ParquetFile};
+use crate::has_extension;
 use crate::metadata::Metadata;
 use crate::schema::from_parquet_schema;
 
@@ -76,6 +79,26 @@ impl<R: Runtime> ReadParquet<R> {
         let (location, conf) =
             try_location_and_access_config_from_args(&self, &positional_inputs, &named_inputs)?;
 
+        // Locations without an extension are treated as directories, reading
+        // every parquet file in them. Falls back to reading a single file if
+        // there's nothing in the directory.
+        if is_directory_candidate(&location) {
+            let files = self.list_parquet_files(&location, &conf).await?;
+            if !files.is_empty() {
+                let datatable = self.plan_multi_file(files, conf).await?;
+                let schema = datatable.schema.clone();
+
+                return Ok(PlannedTableFunction {
+                    function: Box::new(self),
+                    positional_inputs: positional_inputs.into_iter().map(expr::lit).collect(),
+                    named_inputs,
+                    cardinality: StatisticsValue::Exact(datatable.num_rows()),
+                    function_impl: TableFunctionImpl::Scan(Arc::new(datatable)),
+                    schema,
+                });
+            }
+        }
+
         let mut source = self
             .runtime
             .file_provider()
@@ -110,4 +133,86 @@ impl<R: Runtime> ReadParquet<R> {
             schema,
         })
     }
+
+    /// List all parquet files under a directory, in lexicographic order.
+    async fn list_parquet_files(
+        &self,
+        location: &FileLocation,
+        conf: &AccessConfig,
+    ) -> Result<Vec<FileLocation>> {
+        let paths: Vec<Vec<String>> = self
+            .runtime
+            .file_provider()
+            .list_prefix(location.clone(), conf)
+            .try_collect()
+            .await?;
+
+        let mut paths: Vec<_> = paths
+            .into_iter()
+            .flatten()
+            .filter(|path| path.to_lowercase().ends_with(".parquet"))
+            .collect();
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| location.join(path.split('/')))
+            .collect()
+    }
+
+    /// Read metadata for all files, unifying their schemas.
+    async fn plan_multi_file(
+        &self,
+        locations: Vec<FileLocation>,
+        conf: AccessConfig,
+    ) -> Result<MultiFileDataTable<R>> {
+        let provider = self.runtime.file_provider();
+
+        let mut files = Vec::with_capacity(locations.len());
+        for location in locations {
+            let mut source = provider.file_source(location.clone(), &conf)?;
+            let size = source.size().await?;
+            let metadata = Metadata::new_from_source(source.as_mut(), size).await?;
+            let schema =
+                from_parquet_schema(metadata.decoded_metadata.file_metadata().schema_descr())?;
+            files.push((location, Arc::new(metadata), schema));
+        }
+
+        let (schema, column_maps) = unify_schemas(
+            files
+                .iter()
+                .map(|(location, _, schema)| (location, schema)),
+        )?;
+
+        let files: Arc<[ParquetFile]> = files
+            .into_iter()
+            .zip(column_maps)
+            .map(|((location, metadata, schema), column_map)| ParquetFile {
+                location,
+                metadata,
+                schema,
+                column_map,
+            })
+            .collect();
+
+        Ok(MultiFileDataTable {
+            files,
+            schema,
+            conf,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
+
+/// Check if a location may point to a directory of parquet files.
+///
+/// Http locations can't be listed, so are always treated as a single file.
+fn is_directory_candidate(location: &FileLocation) -> bool {
+    if has_extension(location) {
+        return false;
+    }
+    match location {
+        FileLocation::Url(url) => !matches!(url.scheme(), "http" | "https"),
+        FileLocation::Path(_) => true,
+    }
 }
@@ -1,16 +1,20 @@
 pub mod copy_to;
 pub mod functions;
 pub mod metadata;
+pub mod prune;
 pub mod reader;
 pub mod writer;
 
 mod schema;
 
+use std::path::Path;
+
 use copy_to::ParquetCopyToFunction;
 use functions::read_parquet::ReadParquet;
 use rayexec_execution::datasource::{DataSource, DataSourceBuilder, DataSourceCopyTo, FileHandler};
 use rayexec_execution::functions::table::TableFunction;
 use rayexec_execution::runtime::Runtime;
+use rayexec_io::location::FileLocation;
 use regex::{Regex, RegexBuilder};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -62,6 +66,14 @@ impl<R: Runtime> DataSource for ParquetDataSource<R> {
     }
 }
 
+/// Check if the final segment of the location has a file extension.
+pub(crate) fn has_extension(location: &FileLocation) -> bool {
+    match location {
+        FileLocation::Url(url) => Path::new(url.path()).extension().is_some(),
+        FileLocation::Path(path) => path.extension().is_some(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
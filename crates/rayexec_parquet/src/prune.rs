@@ -0,0 +1,270 @@
+use parquet::file::metadata::{ParquetMetaData, RowGroupMetaData};
+use parquet::file::statistics::Statistics;
+use rayexec_execution::arrays::datatype::DataType;
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::arrays::scalar::ScalarValue;
+use rayexec_execution::expr::comparison_expr::ComparisonOperator;
+use rayexec_execution::logical::scan_filter::{ScanFilter, ScanFilterType};
+
+/// Get the indices of row groups that may contain rows passing all `filters`.
+///
+/// Row groups are skipped only if they're empty, or the min/max statistics for
+/// a column guarantee that no row in the group can pass a filter. Filters on
+/// columns that are missing statistics, or have types we don't compare
+/// statistics for, never skip row groups.
+pub fn prune_row_groups(
+    metadata: &ParquetMetaData,
+    schema: &Schema,
+    filters: &[ScanFilter],
+) -> Vec<usize> {
+    metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .filter(|(_, row_group)| {
+            row_group.num_rows() > 0
+                && filters
+                    .iter()
+                    .all(|filter| row_group_may_match(row_group, schema, filter))
+        })
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn row_group_may_match(row_group: &RowGroupMetaData, schema: &Schema, filter: &ScanFilter) -> bool {
+    let ScanFilterType::ConstComparison { op, constant } = &filter.filter;
+
+    let field = match schema.fields.get(filter.column) {
+        Some(field) => field,
+        None => return true,
+    };
+
+    // Only top-level, non-nested columns are considered.
+    let column = row_group
+        .columns()
+        .iter()
+        .find(|col| matches!(col.column_path().parts(), [name] if name == &field.name));
+    let stats = match column.and_then(|col| col.statistics()) {
+        Some(stats) if stats.has_min_max_set() => stats,
+        _ => return true,
+    };
+
+    match (&field.datatype, stats) {
+        (
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Date32,
+            Statistics::Int32(s),
+        ) => match constant_as_i64(constant) {
+            Some(c) => may_match(*op, &(*s.min() as i64), &(*s.max() as i64), &c),
+            None => true,
+        },
+        (DataType::Int64, Statistics::Int64(s)) => match constant_as_i64(constant) {
+            Some(c) => may_match(*op, s.min(), s.max(), &c),
+            None => true,
+        },
+        // Deprecated min/max fields for byte arrays may have been written using
+        // a signed comparison.
+        (DataType::Utf8, Statistics::ByteArray(s)) if !stats.is_min_max_deprecated() => {
+            match constant {
+                ScalarValue::Utf8(c) => {
+                    may_match(*op, s.min().data(), s.max().data(), c.as_bytes())
+                }
+                _ => true,
+            }
+        }
+        _ => true,
+    }
+}
+
+fn constant_as_i64(constant: &ScalarValue) -> Option<i64> {
+    match constant {
+        ScalarValue::Date32(v) => Some(*v as i64),
+        other => other.try_as_i64().ok(),
+    }
+}
+
+/// Check if any value in the range `[min, max]` may satisfy `value <op> c`.
+fn may_match<T: PartialOrd + ?Sized>(op: ComparisonOperator, min: &T, max: &T, c: &T) -> bool {
+    match op {
+        ComparisonOperator::Eq => min <= c && c <= max,
+        ComparisonOperator::NotEq => !(min == c && max == c),
+        ComparisonOperator::Lt => min < c,
+        ComparisonOperator::LtEq => min <= c,
+        ComparisonOperator::Gt => max > c,
+        ComparisonOperator::GtEq => max >= c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::sync::Arc;
+
+    use parquet::file::properties::WriterProperties;
+    use rayexec_execution::arrays::array::Array;
+    use rayexec_execution::arrays::batch::Batch;
+    use rayexec_execution::arrays::field::Field;
+    use rayexec_execution::storage::table_storage::Projections;
+    use rayexec_io::memory::MemoryFileSystem;
+    use rayexec_io::FileSource;
+
+    use super::*;
+    use crate::metadata::Metadata;
+    use crate::reader::AsyncBatchReader;
+    use crate::writer::AsyncBatchWriter;
+
+    const ROWS_PER_GROUP: usize = 100;
+    const NUM_ROW_GROUPS: usize = 4;
+
+    fn schema() -> Schema {
+        Schema::new([
+            Field::new("x", DataType::Int64, false),
+            Field::new("s", DataType::Utf8, false),
+        ])
+    }
+
+    /// Writes a file with multiple row groups, where 'x' is the row number,
+    /// and 's' is the row group index prefixed with 'group_'.
+    fn write_file(fs: &MemoryFileSystem, path: &Path) -> Arc<Metadata> {
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(ROWS_PER_GROUP)
+            .build();
+        let mut writer =
+            AsyncBatchWriter::try_new_with_properties(fs.file_sink(path).unwrap(), schema(), props)
+                .unwrap();
+
+        futures::executor::block_on(async {
+            for group in 0..NUM_ROW_GROUPS {
+                let start = group * ROWS_PER_GROUP;
+                let batch = Batch::try_new([
+                    Array::from_iter((start..start + ROWS_PER_GROUP).map(|v| v as i64)),
+                    Array::from_iter((0..ROWS_PER_GROUP).map(|_| format!("group_{group}"))),
+                ])
+                .unwrap();
+                writer.write(&batch).await.unwrap();
+            }
+            writer.finish().await.unwrap();
+
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            Arc::new(
+                Metadata::new_from_source(source.as_mut(), size)
+                    .await
+                    .unwrap(),
+            )
+        })
+    }
+
+    fn cmp_filter(
+        column: usize,
+        op: ComparisonOperator,
+        constant: ScalarValue<'static>,
+    ) -> ScanFilter {
+        ScanFilter {
+            column,
+            filter: ScanFilterType::ConstComparison { op, constant },
+        }
+    }
+
+    #[test]
+    fn prune_int_filter_single_group() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("groups.parquet");
+        let metadata = write_file(&fs, path);
+        assert!(metadata.decoded_metadata.num_row_groups() >= NUM_ROW_GROUPS);
+
+        // x > 300 only matches the last row group.
+        let filters = [cmp_filter(
+            0,
+            ComparisonOperator::Gt,
+            ScalarValue::Int64(300),
+        )];
+        let row_groups = prune_row_groups(&metadata.decoded_metadata, &schema(), &filters);
+        assert_eq!(vec![3], row_groups);
+
+        // Read back only the remaining row group.
+        let values = futures::executor::block_on(async {
+            let mut reader = AsyncBatchReader::try_new(
+                fs.file_source(path).unwrap(),
+                row_groups.into_iter().collect(),
+                metadata.clone(),
+                &schema(),
+                1024,
+                Projections {
+                    column_indices: Some(vec![0]),
+                },
+            )
+            .unwrap();
+
+            let mut values = Vec::new();
+            while let Some(batch) = reader.read_next().await.unwrap() {
+                for idx in 0..batch.num_rows() {
+                    values.push(batch.row(idx).unwrap().columns[0].try_as_i64().unwrap());
+                }
+            }
+            values
+        });
+        let expected: Vec<_> = (300..400).collect();
+        assert_eq!(expected, values);
+    }
+
+    #[test]
+    fn prune_multiple_filters() {
+        let fs = MemoryFileSystem::default();
+        let metadata = write_file(&fs, Path::new("groups.parquet"));
+
+        // 150 <= x < 250 spans two row groups.
+        let filters = [
+            cmp_filter(0, ComparisonOperator::GtEq, ScalarValue::Int64(150)),
+            cmp_filter(0, ComparisonOperator::Lt, ScalarValue::Int64(250)),
+        ];
+        let row_groups = prune_row_groups(&metadata.decoded_metadata, &schema(), &filters);
+        assert_eq!(vec![1, 2], row_groups);
+
+        // Adding a string filter narrows it down to one.
+        let filters = [
+            filters[0].clone(),
+            filters[1].clone(),
+            cmp_filter(
+                1,
+                ComparisonOperator::Eq,
+                ScalarValue::Utf8("group_2".into()),
+            ),
+        ];
+        let row_groups = prune_row_groups(&metadata.decoded_metadata, &schema(), &filters);
+        assert_eq!(vec![2], row_groups);
+
+        // Nothing matches.
+        let filters = [cmp_filter(0, ComparisonOperator::Lt, ScalarValue::Int64(0))];
+        let row_groups = prune_row_groups(&metadata.decoded_metadata, &schema(), &filters);
+        assert!(row_groups.is_empty());
+    }
+
+    #[test]
+    fn may_match_ranges() {
+        use ComparisonOperator::*;
+
+        // Range [10, 20]
+        let cases = [
+            (Eq, 5, false),
+            (Eq, 15, true),
+            (Eq, 25, false),
+            (NotEq, 15, true),
+            (Lt, 10, false),
+            (Lt, 11, true),
+            (LtEq, 10, true),
+            (LtEq, 9, false),
+            (Gt, 20, false),
+            (Gt, 19, true),
+            (GtEq, 20, true),
+            (GtEq, 21, false),
+        ];
+
+        for (op, c, expected) in cases {
+            assert_eq!(expected, may_match(op, &10, &20, &c), "op: {op:?}, c: {c}");
+        }
+
+        // Single value range.
+        assert!(!may_match(NotEq, &10, &10, &10));
+        assert!(may_match(NotEq, &10, &10, &11));
+    }
+}
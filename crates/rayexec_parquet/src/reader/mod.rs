@@ -18,12 +18,19 @@ use parquet::data_type::Int96;
 use parquet::file::reader::{ChunkReader, Length, SerializedPageReader};
 use parquet::schema::types::ColumnDescPtr;
 use primitive::PrimitiveArrayReader;
-use rayexec_error::{RayexecError, Result, ResultExt};
+use rayexec_error::{OptionExt, RayexecError, Result, ResultExt};
 use rayexec_execution::arrays::array::{Array, ArrayData};
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::bitmap::Bitmap;
 use rayexec_execution::arrays::datatype::DataType;
 use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::arrays::selection::SelectionVector;
+use rayexec_execution::expr::comparison_expr::ComparisonExpr;
+use rayexec_execution::expr::physical::planner::PhysicalExpressionPlanner;
+use rayexec_execution::expr::physical::PhysicalScalarExpression;
+use rayexec_execution::expr::{self, Expression};
+use rayexec_execution::logical::binder::table_list::TableList;
+use rayexec_execution::logical::scan_filter::{ScanFilter, ScanFilterType};
 use rayexec_execution::storage::table_storage::Projections;
 use rayexec_io::FileSource;
 use varlen::VarlenArrayReader;
@@ -40,6 +47,10 @@ pub trait ArrayBuilder<P: PageReader>: Send {
     /// Read `n` number of rows from the page reader, returning the actual
     /// number of rows read.
     fn read_rows(&mut self, n: usize) -> Result<usize>;
+
+    /// Skip `n` number of rows in the page reader without decoding them into
+    /// the buffer, returning the actual number of rows skipped.
+    fn skip_rows(&mut self, n: usize) -> Result<usize>;
 }
 
 /// Create a new array builder based on the provided type.
@@ -144,6 +155,87 @@ where
     }
 }
 
+/// Predicate evaluated during a scan before the remaining projected columns are
+/// decoded.
+///
+/// Only the columns referenced by the filter are decoded up front. The rest of
+/// the projected columns are then decoded for just the rows that pass the
+/// predicate, with non-matching rows being skipped in the column readers.
+#[derive(Debug, Clone)]
+pub struct RowFilter {
+    /// Indices of the columns in the file needed to evaluate the predicate.
+    pub column_indices: Vec<usize>,
+    /// Predicate to evaluate.
+    ///
+    /// Column references in the predicate index into a batch containing only
+    /// the filter columns, in the order provided by `column_indices`.
+    pub predicate: PhysicalScalarExpression,
+}
+
+impl RowFilter {
+    /// Try to create a row filter from filters pushed down into a scan.
+    ///
+    /// Filter columns index into `schema`. Filters on columns outside of the
+    /// schema, or comparing against a constant of a different type than the
+    /// column, are ignored since scan filters are only a hint.
+    ///
+    /// Returns None if none of the filters can be used.
+    pub fn try_from_scan_filters(schema: &Schema, filters: &[ScanFilter]) -> Result<Option<Self>> {
+        let filters: Vec<_> = filters
+            .iter()
+            .filter(|filter| {
+                let ScanFilterType::ConstComparison { constant, .. } = &filter.filter;
+                schema
+                    .fields
+                    .get(filter.column)
+                    .is_some_and(|field| field.datatype == constant.datatype())
+            })
+            .collect();
+
+        if filters.is_empty() {
+            return Ok(None);
+        }
+
+        let mut column_indices: Vec<_> = filters.iter().map(|filter| filter.column).collect();
+        column_indices.sort_unstable();
+        column_indices.dedup();
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list.push_table(
+            None,
+            column_indices
+                .iter()
+                .map(|&idx| schema.fields[idx].datatype.clone())
+                .collect(),
+            column_indices
+                .iter()
+                .map(|&idx| schema.fields[idx].name.clone())
+                .collect(),
+        )?;
+
+        let exprs = filters.iter().map(|filter| {
+            let ScanFilterType::ConstComparison { op, constant } = &filter.filter;
+            let col = column_indices
+                .binary_search(&filter.column)
+                .expect("filter column to exist");
+            Expression::Comparison(ComparisonExpr {
+                left: Box::new(expr::col_ref(table_ref, col)),
+                right: Box::new(expr::lit(constant.clone())),
+                op: *op,
+            })
+        });
+        let expr = expr::and(exprs).required("at least one filter")?;
+
+        let predicate =
+            PhysicalExpressionPlanner::new(&table_list).plan_scalar(&[table_ref], &expr)?;
+
+        Ok(Some(RowFilter {
+            column_indices,
+            predicate,
+        }))
+    }
+}
+
 pub struct AsyncBatchReader<R: FileSource> {
     /// Reader we're reading from.
     reader: R,
@@ -159,15 +251,79 @@ pub struct AsyncBatchReader<R: FileSource> {
     batch_size: usize,
     /// All column states for columns we're reading.
     column_states: Vec<ColumnState>,
+    /// Optional filter to apply before decoding non-filter columns.
+    row_filter: Option<RowFilter>,
+    /// Indices into `column_states` for each of the filter columns.
+    filter_states: Vec<usize>,
 }
 
 struct ColumnState {
     /// Index of the column in the parquet file.
     column_idx: usize,
+    /// If this column is part of the output batch.
+    ///
+    /// Columns only needed for evaluating a row filter will not be projected.
+    projected: bool,
     /// Builder for this column.
     builder: Box<dyn ArrayBuilder<SerializedPageReader<InMemoryColumnChunk>>>,
     /// In-memory buffer for reading this column.
     column_chunk: Option<InMemoryColumnChunk>,
+    /// Number of rows decoded by the builder for this column.
+    rows_decoded: usize,
+}
+
+impl ColumnState {
+    fn read_rows(&mut self, n: usize) -> Result<usize> {
+        let num_read = self.builder.read_rows(n)?;
+        self.rows_decoded += num_read;
+        Ok(num_read)
+    }
+
+    fn skip_rows(&mut self, n: usize) -> Result<()> {
+        let num_skipped = self.builder.skip_rows(n)?;
+        if num_skipped != n {
+            return Err(RayexecError::new(format!(
+                "Expected to skip {n} rows, skipped {num_skipped}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Read only the rows in `selection`, skipping everything else up to
+    /// `num_rows`.
+    ///
+    /// Selection locations are expected to be in ascending order.
+    fn read_selected_rows(&mut self, selection: &SelectionVector, num_rows: usize) -> Result<()> {
+        // Next row we haven't read or skipped yet.
+        let mut curr = 0;
+        // Number of contiguous selected rows starting at `curr` that still
+        // need to be read.
+        let mut pending = 0;
+
+        for loc in selection.iter_locations() {
+            if loc != curr + pending {
+                if pending > 0 {
+                    self.read_rows(pending)?;
+                    curr += pending;
+                    pending = 0;
+                }
+                self.skip_rows(loc - curr)?;
+                curr = loc;
+            }
+            pending += 1;
+        }
+
+        if pending > 0 {
+            self.read_rows(pending)?;
+            curr += pending;
+        }
+
+        if curr < num_rows {
+            self.skip_rows(num_rows - curr)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<R: FileSource + 'static> AsyncBatchReader<R> {
@@ -178,6 +334,31 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
         schema: &Schema,
         batch_size: usize,
         projections: Projections,
+    ) -> Result<Self> {
+        Self::try_new_with_row_filter(
+            reader,
+            row_groups,
+            metadata,
+            schema,
+            batch_size,
+            projections,
+            None,
+        )
+    }
+
+    /// Create a new reader that evaluates `row_filter` before decoding the
+    /// remaining projected columns.
+    ///
+    /// Filter columns do not need to be part of the projection. Output batches
+    /// only contain the projected columns.
+    pub fn try_new_with_row_filter(
+        reader: R,
+        row_groups: VecDeque<usize>,
+        metadata: Arc<Metadata>,
+        schema: &Schema,
+        batch_size: usize,
+        projections: Projections,
+        row_filter: Option<RowFilter>,
     ) -> Result<Self> {
         // Create projection bitmap.
         //
@@ -194,6 +375,19 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
             None => Bitmap::new_with_all_true(schema.fields.len()),
         };
 
+        let mut filter_bitmap = Bitmap::new_with_all_false(schema.fields.len());
+        if let Some(filter) = &row_filter {
+            for &idx in &filter.column_indices {
+                if idx >= schema.fields.len() {
+                    return Err(RayexecError::new(format!(
+                        "Row filter column index {idx} out of bounds for schema with {} columns",
+                        schema.fields.len()
+                    )));
+                }
+                filter_bitmap.set_unchecked(idx, true);
+            }
+        }
+
         let mut states = Vec::with_capacity(schema.fields.len());
 
         for (col_idx, ((datatype, column_chunk_meta), projected)) in schema
@@ -203,7 +397,7 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
             .zip(bitmap.iter())
             .enumerate()
         {
-            if projected {
+            if projected || filter_bitmap.value(col_idx) {
                 let physical = column_chunk_meta.column_type();
                 let builder = builder_for_type(
                     batch_size,
@@ -214,14 +408,30 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
 
                 let state = ColumnState {
                     column_idx: col_idx,
+                    projected,
                     builder,
                     column_chunk: None,
+                    rows_decoded: 0,
                 };
 
                 states.push(state)
             }
         }
 
+        let filter_states = match &row_filter {
+            Some(filter) => filter
+                .column_indices
+                .iter()
+                .map(|&idx| {
+                    states
+                        .iter()
+                        .position(|state| state.column_idx == idx)
+                        .required("state for filter column")
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+
         Ok(AsyncBatchReader {
             reader,
             row_groups,
@@ -229,9 +439,22 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
             metadata,
             batch_size,
             column_states: states,
+            row_filter,
+            filter_states,
         })
     }
 
+    /// Get the number of rows that have been decoded so far for a column in
+    /// the file.
+    ///
+    /// Returns None if the column isn't being read.
+    pub fn rows_decoded(&self, column_idx: usize) -> Option<usize> {
+        self.column_states
+            .iter()
+            .find(|state| state.column_idx == column_idx)
+            .map(|state| state.rows_decoded)
+    }
+
     pub async fn read_next(&mut self) -> Result<Option<Batch>> {
         if self.current_row_group.is_none() {
             match self.row_groups.pop_front() {
@@ -267,8 +490,12 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
     ///
     /// Returns Ok(None) when there's nothing left to read.
     fn maybe_read_batch(&mut self) -> Result<Option<Batch>> {
+        if self.row_filter.is_some() {
+            return self.maybe_read_filtered_batch();
+        }
+
         for state in self.column_states.iter_mut() {
-            state.builder.read_rows(self.batch_size)?;
+            state.read_rows(self.batch_size)?;
         }
         let arrays = self
             .column_states
@@ -285,6 +512,66 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
         }
     }
 
+    /// Read the next batch by first decoding the filter columns, then decoding
+    /// the remaining projected columns for only the rows that pass the filter.
+    ///
+    /// Batches where no rows pass the filter are not returned, and we'll
+    /// continue reading until we either have a non-empty batch or there's
+    /// nothing left in the row group.
+    fn maybe_read_filtered_batch(&mut self) -> Result<Option<Batch>> {
+        let filter = self.row_filter.as_ref().required("row filter")?;
+
+        loop {
+            let mut filter_arrays = Vec::with_capacity(self.filter_states.len());
+            for &state_idx in &self.filter_states {
+                let state = &mut self.column_states[state_idx];
+                state.read_rows(self.batch_size)?;
+                filter_arrays.push(state.builder.build()?);
+            }
+
+            let filter_batch = Batch::try_new(filter_arrays)?;
+            let num_rows = filter_batch.num_rows();
+            if num_rows == 0 {
+                return Ok(None);
+            }
+
+            let selection = Arc::new(filter.predicate.select(&filter_batch)?);
+            let filter_batch = filter_batch.select(selection.clone());
+
+            let mut arrays = Vec::new();
+            for (state_idx, state) in self.column_states.iter_mut().enumerate() {
+                match self.filter_states.iter().position(|&idx| idx == state_idx) {
+                    Some(filter_col) => {
+                        // Already decoded, just need to include it in the
+                        // output if it's projected.
+                        if state.projected {
+                            let array = filter_batch
+                                .column(filter_col)
+                                .required("filter column")?
+                                .clone();
+                            arrays.push(array);
+                        }
+                    }
+                    None => {
+                        state.read_selected_rows(&selection, num_rows)?;
+                        arrays.push(state.builder.build()?);
+                    }
+                }
+            }
+
+            if selection.is_empty() {
+                // Nothing passed the filter, move on to the next set of rows.
+                continue;
+            }
+
+            if arrays.is_empty() {
+                return Ok(Some(Batch::empty_with_num_rows(selection.num_rows())));
+            }
+
+            return Ok(Some(Batch::try_new(arrays)?));
+        }
+    }
+
     fn set_page_readers(&mut self) -> Result<()> {
         for state in self.column_states.iter_mut() {
             let row_group = self.current_row_group.expect("current row group to be set");
@@ -476,6 +763,15 @@ where
         Ok(num_read)
     }
 
+    pub fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let reader = match &mut self.reader {
+            Some(reader) => reader,
+            None => return Err(RayexecError::new("Expected reader to be Some")),
+        };
+
+        reader.skip_records(num_records).context("skip records")
+    }
+
     pub fn take_def_levels(&mut self) -> Option<Vec<i16>> {
         // We want to take the inner array and replace it with an empty array.
         // Calling `take` on an option would replace Some with None.
@@ -487,3 +783,342 @@ where
         self.rep_levels.as_mut().map(std::mem::take)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use rayexec_execution::arrays::field::Field;
+    use rayexec_execution::arrays::scalar::ScalarValue;
+    use rayexec_execution::expr::comparison_expr::ComparisonOperator;
+    use rayexec_execution::expr::physical::column_expr::PhysicalColumnExpr;
+    use rayexec_execution::expr::physical::literal_expr::PhysicalLiteralExpr;
+    use rayexec_execution::expr::physical::scalar_function_expr::PhysicalScalarFunctionExpr;
+    use rayexec_execution::functions::scalar::builtin::comparison::Eq;
+    use rayexec_execution::functions::scalar::ScalarFunction;
+    use rayexec_io::memory::MemoryFileSystem;
+
+    use super::*;
+    use crate::writer::AsyncBatchWriter;
+
+    const NUM_ROWS: usize = 10_000;
+    const NUM_PAYLOAD_COLS: usize = 16;
+
+    /// Schema with a single 'key' column followed by a bunch of payload
+    /// columns.
+    fn wide_schema() -> Schema {
+        let mut fields = vec![Field::new("key", DataType::Int32, false)];
+        fields.extend(
+            (0..NUM_PAYLOAD_COLS).map(|idx| Field::new(format!("c{idx}"), DataType::Int64, false)),
+        );
+        Schema::new(fields)
+    }
+
+    /// Writes a wide parquet file where 'key' is `row % 100` and every payload
+    /// column contains the row number.
+    fn write_wide_file(fs: &MemoryFileSystem, path: &Path) {
+        let schema = wide_schema();
+        let mut cols = vec![Array::from_iter(
+            (0..NUM_ROWS).map(|row| (row % 100) as i32),
+        )];
+        cols.extend(
+            (0..NUM_PAYLOAD_COLS).map(|_| Array::from_iter((0..NUM_ROWS).map(|row| row as i64))),
+        );
+        let batch = Batch::try_new(cols).unwrap();
+
+        let sink = fs.file_sink(path).unwrap();
+        let mut writer = AsyncBatchWriter::try_new(sink, schema).unwrap();
+        futures::executor::block_on(async {
+            writer.write(&batch).await.unwrap();
+            writer.finish().await.unwrap();
+        });
+    }
+
+    /// Predicate for 'key = 0' over a batch containing only the key column.
+    fn key_eq_zero_predicate() -> PhysicalScalarExpression {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Int32], vec!["key".to_string()])
+            .unwrap();
+        let function = Eq
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::lit(0_i32)],
+            )
+            .unwrap();
+
+        PhysicalScalarExpression::ScalarFunction(PhysicalScalarFunctionExpr {
+            function,
+            inputs: vec![
+                PhysicalScalarExpression::Column(PhysicalColumnExpr { idx: 0 }),
+                PhysicalScalarExpression::Literal(PhysicalLiteralExpr {
+                    literal: ScalarValue::Int32(0),
+                }),
+            ],
+        })
+    }
+
+    #[test]
+    fn row_filter_skips_decoding_non_filter_columns() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("wide.parquet");
+        write_wide_file(&fs, path);
+
+        let batches = futures::executor::block_on(async {
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            let metadata = Arc::new(
+                Metadata::new_from_source(source.as_mut(), size)
+                    .await
+                    .unwrap(),
+            );
+            let row_groups = (0..metadata.decoded_metadata.num_row_groups()).collect();
+
+            let mut reader = AsyncBatchReader::try_new_with_row_filter(
+                fs.file_source(path).unwrap(),
+                row_groups,
+                metadata,
+                &wide_schema(),
+                1024,
+                Projections::all(),
+                Some(RowFilter {
+                    column_indices: vec![0],
+                    predicate: key_eq_zero_predicate(),
+                }),
+            )
+            .unwrap();
+
+            let mut batches = Vec::new();
+            while let Some(batch) = reader.read_next().await.unwrap() {
+                batches.push(batch);
+            }
+
+            // Filter column needs to be fully decoded, payload columns should
+            // only decode the 1% of rows that passed.
+            assert_eq!(Some(NUM_ROWS), reader.rows_decoded(0));
+            for col_idx in 1..=NUM_PAYLOAD_COLS {
+                assert_eq!(Some(NUM_ROWS / 100), reader.rows_decoded(col_idx));
+            }
+
+            batches
+        });
+
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(NUM_ROWS / 100, num_rows);
+
+        let mut expected_row = 0;
+        for batch in &batches {
+            assert_eq!(NUM_PAYLOAD_COLS + 1, batch.columns().len());
+            for row_idx in 0..batch.num_rows() {
+                let row = batch.row(row_idx).unwrap();
+                assert_eq!(ScalarValue::Int32(0), row.columns[0]);
+                for col in &row.columns[1..] {
+                    assert_eq!(&ScalarValue::Int64(expected_row), col);
+                }
+                expected_row += 100;
+            }
+        }
+    }
+
+    #[test]
+    fn row_filter_column_not_projected() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("wide.parquet");
+        write_wide_file(&fs, path);
+
+        let batches = futures::executor::block_on(async {
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            let metadata = Arc::new(
+                Metadata::new_from_source(source.as_mut(), size)
+                    .await
+                    .unwrap(),
+            );
+            let row_groups = (0..metadata.decoded_metadata.num_row_groups()).collect();
+
+            let mut reader = AsyncBatchReader::try_new_with_row_filter(
+                fs.file_source(path).unwrap(),
+                row_groups,
+                metadata,
+                &wide_schema(),
+                1024,
+                Projections {
+                    column_indices: Some(vec![3]),
+                },
+                Some(RowFilter {
+                    column_indices: vec![0],
+                    predicate: key_eq_zero_predicate(),
+                }),
+            )
+            .unwrap();
+
+            let mut batches = Vec::new();
+            while let Some(batch) = reader.read_next().await.unwrap() {
+                batches.push(batch);
+            }
+
+            // Columns outside of the projection and filter aren't read.
+            assert_eq!(None, reader.rows_decoded(1));
+
+            batches
+        });
+
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| (0..b.num_rows()).map(|idx| b.row(idx).unwrap().columns))
+            .collect();
+        let expected: Vec<_> = (0..NUM_ROWS)
+            .step_by(100)
+            .map(|row| vec![ScalarValue::Int64(row as i64)])
+            .collect();
+        assert_eq!(expected, values);
+    }
+
+    #[test]
+    fn projection_skips_unselected_columns() {
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("wide.parquet");
+        write_wide_file(&fs, path);
+
+        let batches = futures::executor::block_on(async {
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            let metadata = Arc::new(
+                Metadata::new_from_source(source.as_mut(), size)
+                    .await
+                    .unwrap(),
+            );
+            let row_groups = (0..metadata.decoded_metadata.num_row_groups()).collect();
+
+            let mut reader = AsyncBatchReader::try_new(
+                fs.file_source(path).unwrap(),
+                row_groups,
+                metadata,
+                &wide_schema(),
+                1024,
+                Projections {
+                    column_indices: Some(vec![2, 9]),
+                },
+            )
+            .unwrap();
+
+            let mut batches = Vec::new();
+            while let Some(batch) = reader.read_next().await.unwrap() {
+                batches.push(batch);
+            }
+
+            // Only projected columns are decoded.
+            for col in 0..=NUM_PAYLOAD_COLS {
+                let expected = match col {
+                    2 | 9 => Some(NUM_ROWS),
+                    _ => None,
+                };
+                assert_eq!(expected, reader.rows_decoded(col), "column {col}");
+            }
+
+            batches
+        });
+
+        assert!(batches.iter().all(|b| b.columns().len() == 2));
+
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| (0..b.num_rows()).map(|idx| b.row(idx).unwrap().columns))
+            .collect();
+        let expected: Vec<_> = (0..NUM_ROWS)
+            .map(|row| {
+                vec![
+                    ScalarValue::Int64(row as i64),
+                    ScalarValue::Int64(row as i64),
+                ]
+            })
+            .collect();
+        assert_eq!(expected, values);
+    }
+
+    #[test]
+    fn row_filter_from_scan_filters() {
+        let filters = [
+            ScanFilter {
+                column: 3,
+                filter: ScanFilterType::ConstComparison {
+                    op: ComparisonOperator::GtEq,
+                    constant: ScalarValue::Int64(5000),
+                },
+            },
+            ScanFilter {
+                column: 0,
+                filter: ScanFilterType::ConstComparison {
+                    op: ComparisonOperator::Eq,
+                    constant: ScalarValue::Int32(0),
+                },
+            },
+            // Mismatched type, ignored.
+            ScanFilter {
+                column: 1,
+                filter: ScanFilterType::ConstComparison {
+                    op: ComparisonOperator::Eq,
+                    constant: ScalarValue::Utf8("a".into()),
+                },
+            },
+        ];
+        let row_filter = RowFilter::try_from_scan_filters(&wide_schema(), &filters)
+            .unwrap()
+            .unwrap();
+        assert_eq!(vec![0, 3], row_filter.column_indices);
+
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("wide.parquet");
+        write_wide_file(&fs, path);
+
+        let batches = futures::executor::block_on(async {
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            let metadata = Arc::new(
+                Metadata::new_from_source(source.as_mut(), size)
+                    .await
+                    .unwrap(),
+            );
+            let row_groups = (0..metadata.decoded_metadata.num_row_groups()).collect();
+
+            let mut reader = AsyncBatchReader::try_new_with_row_filter(
+                fs.file_source(path).unwrap(),
+                row_groups,
+                metadata,
+                &wide_schema(),
+                1024,
+                Projections {
+                    column_indices: Some(vec![5]),
+                },
+                Some(row_filter),
+            )
+            .unwrap();
+
+            let mut batches = Vec::new();
+            while let Some(batch) = reader.read_next().await.unwrap() {
+                batches.push(batch);
+            }
+
+            assert_eq!(Some(NUM_ROWS), reader.rows_decoded(0));
+            assert_eq!(Some(NUM_ROWS), reader.rows_decoded(3));
+            assert_eq!(Some((NUM_ROWS - 5000) / 100), reader.rows_decoded(5));
+            assert_eq!(None, reader.rows_decoded(1));
+
+            batches
+        });
+
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| (0..b.num_rows()).map(|idx| b.row(idx).unwrap().columns))
+            .collect();
+        let expected: Vec<_> = (5000..NUM_ROWS)
+            .step_by(100)
+            .map(|row| vec![ScalarValue::Int64(row as i64)])
+            .collect();
+        assert_eq!(expected, values);
+
+        // No usable filters.
+        let row_filter = RowFilter::try_from_scan_filters(&wide_schema(), &filters[2..]).unwrap();
+        assert!(row_filter.is_none());
+    }
+}
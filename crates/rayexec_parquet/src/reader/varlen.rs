@@ -85,4 +85,8 @@ where
     fn read_rows(&mut self, n: usize) -> Result<usize> {
         self.values_reader.read_records(n, &mut self.values_buffer)
     }
+
+    fn skip_rows(&mut self, n: usize) -> Result<usize> {
+        self.values_reader.skip_records(n)
+    }
 }
@@ -2,12 +2,20 @@ use std::fmt;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use parquet::basic::{Compression, GzipLevel, ZstdLevel};
 use parquet::column::page::{CompressedPage, PageWriteSpec, PageWriter};
 use parquet::column::writer::{get_column_writer, ColumnCloseResult, ColumnWriter};
 use parquet::data_type::ByteArray;
 use parquet::errors::ParquetError;
 use parquet::file::metadata::ColumnChunkMetaData;
-use parquet::file::properties::{WriterProperties, WriterPropertiesPtr};
+use parquet::file::properties::{
+    EnabledStatistics,
+    WriterProperties,
+    WriterPropertiesPtr,
+    DEFAULT_COMPRESSION,
+    DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT,
+    DEFAULT_MAX_ROW_GROUP_SIZE,
+};
 use parquet::file::writer::{write_page, SerializedFileWriter};
 use parquet::format::FileMetaData;
 use parquet::schema::types::SchemaDescriptor;
@@ -18,10 +26,118 @@ use rayexec_execution::arrays::datatype::DataType;
 use rayexec_execution::arrays::executor::physical_type::{PhysicalBinary, PhysicalStorage};
 use rayexec_execution::arrays::field::Schema;
 use rayexec_execution::arrays::storage::AddressableStorage;
+use rayexec_execution::functions::copy::CopyToArgs;
 use rayexec_io::FileSink;
 
 use crate::schema::to_parquet_schema;
 
+/// Options for writing out parquet files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParquetWriteOptions {
+    /// Codec to use for compressing pages.
+    pub compression: Compression,
+
+    /// Target number of rows in each row group.
+    pub row_group_size: usize,
+
+    /// If min/max statistics should be written for each column chunk.
+    pub write_statistics: bool,
+
+    /// If columns should be dictionary encoded.
+    pub dictionary: bool,
+
+    /// Max size in bytes of a column's dictionary page. Once exceeded, the
+    /// column falls back to plain encoding for the rest of the row group.
+    pub dictionary_size_limit: usize,
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        ParquetWriteOptions {
+            compression: DEFAULT_COMPRESSION,
+            row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            write_statistics: true,
+            dictionary: true,
+            dictionary_size_limit: DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT,
+        }
+    }
+}
+
+impl ParquetWriteOptions {
+    /// Create write options from the arguments provided to COPY TO.
+    ///
+    /// Accepts `compression`, `row_group_size`, `write_statistics`,
+    /// `dictionary`, and `dictionary_size_limit`. Errors on any other
+    /// argument.
+    pub fn try_from_args(args: &CopyToArgs) -> Result<Self> {
+        let mut opts = ParquetWriteOptions::default();
+
+        for (key, val) in &args.named {
+            match key.as_str() {
+                "compression" => opts.compression = try_compression_from_name(val.try_as_str()?)?,
+                "row_group_size" => {
+                    let size = val.try_as_usize()?;
+                    if size == 0 {
+                        return Err(RayexecError::new("ROW_GROUP_SIZE must be greater than 0"));
+                    }
+                    opts.row_group_size = size;
+                }
+                "write_statistics" => opts.write_statistics = val.try_as_bool()?,
+                "dictionary" => opts.dictionary = val.try_as_bool()?,
+                "dictionary_size_limit" => {
+                    let size = val.try_as_usize()?;
+                    if size == 0 {
+                        return Err(RayexecError::new(
+                            "DICTIONARY_SIZE_LIMIT must be greater than 0",
+                        ));
+                    }
+                    opts.dictionary_size_limit = size;
+                }
+                other => {
+                    return Err(RayexecError::new(format!(
+                        "Unsupported COPY TO option for parquet: '{other}'"
+                    )))
+                }
+            }
+        }
+
+        Ok(opts)
+    }
+
+    pub fn writer_properties(&self) -> WriterProperties {
+        WriterProperties::builder()
+            .set_compression(self.compression)
+            .set_max_row_group_size(self.row_group_size)
+            .set_statistics_enabled(if self.write_statistics {
+                EnabledStatistics::Page
+            } else {
+                EnabledStatistics::None
+            })
+            .set_dictionary_enabled(self.dictionary)
+            .set_dictionary_page_size_limit(self.dictionary_size_limit)
+            .build()
+    }
+}
+
+fn try_compression_from_name(name: &str) -> Result<Compression> {
+    Ok(match name.to_lowercase().as_str() {
+        "none" | "uncompressed" => Compression::UNCOMPRESSED,
+        "snappy" => Compression::SNAPPY,
+        "gzip" => Compression::GZIP(GzipLevel::default()),
+        "zstd" if cfg!(feature = "zstd") => Compression::ZSTD(ZstdLevel::default()),
+        "zstd" => {
+            return Err(RayexecError::new(
+                "Parquet zstd compression not enabled for this build",
+            ))
+        }
+        other => {
+            return Err(RayexecError::new(format!(
+                "Unknown parquet compression codec: '{other}'"
+            )))
+        }
+    })
+}
+
 /// Writes batches out to a parquet file.
 ///
 /// During writes, a complete row group is buffered in memory. Once that row
@@ -41,7 +157,25 @@ pub struct AsyncBatchWriter {
 
 impl AsyncBatchWriter {
     pub fn try_new(sink: Box<dyn FileSink>, schema: Schema) -> Result<Self> {
-        let props = Arc::new(WriterProperties::new());
+        Self::try_new_with_properties(sink, schema, WriterProperties::new())
+    }
+
+    /// Create a new writer using the given write options.
+    pub fn try_new_with_options(
+        sink: Box<dyn FileSink>,
+        schema: Schema,
+        options: &ParquetWriteOptions,
+    ) -> Result<Self> {
+        Self::try_new_with_properties(sink, schema, options.writer_properties())
+    }
+
+    /// Create a new writer using the given write properties.
+    pub fn try_new_with_properties(
+        sink: Box<dyn FileSink>,
+        schema: Schema,
+        props: WriterProperties,
+    ) -> Result<Self> {
+        let props = Arc::new(props);
         let parquet_schema = to_parquet_schema(&schema)?;
         let writer =
             SerializedFileWriter::new(Vec::new(), parquet_schema.root_schema_ptr(), props.clone())
@@ -64,13 +198,25 @@ impl AsyncBatchWriter {
             return Ok(());
         }
 
-        self.current_row_group.write(batch)?;
+        // Split the batch across row groups so that every row group (except
+        // the last) has exactly the max number of rows.
+        let max_rows = self.props.max_row_group_size();
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let remaining = max_rows - self.current_row_group.num_rows;
+            let count = remaining.min(batch.num_rows() - offset);
 
-        // TODO: Slice buffer before right to make sure number of rows in row
-        // groups are exact.
-        if self.current_row_group.num_rows >= self.props.max_row_group_size() {
-            self.flush_row_group()?;
-            self.flush_writer_buffer().await?;
+            if count == batch.num_rows() {
+                self.current_row_group.write(batch)?;
+            } else {
+                self.current_row_group.write(&batch.slice(offset, count))?;
+            }
+            offset += count;
+
+            if self.current_row_group.num_rows >= max_rows {
+                self.flush_row_group()?;
+                self.flush_writer_buffer().await?;
+            }
         }
 
         Ok(())
@@ -335,3 +481,229 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    use parquet::basic::Encoding;
+    use parquet::file::statistics::Statistics;
+    use rayexec_execution::arrays::field::Field;
+    use rayexec_execution::arrays::scalar::{OwnedScalarValue, ScalarValue};
+    use rayexec_execution::storage::table_storage::Projections;
+    use rayexec_io::memory::MemoryFileSystem;
+
+    use super::*;
+    use crate::metadata::Metadata;
+    use crate::reader::AsyncBatchReader;
+
+    const NUM_ROWS: usize = 2500;
+
+    /// Writes `NUM_ROWS` rows using the given COPY TO args, then reads them
+    /// back, returning the file's metadata and the rows.
+    fn round_trip(named: HashMap<String, OwnedScalarValue>) -> (Metadata, Vec<Batch>) {
+        let schema = Schema::new([
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let options = ParquetWriteOptions::try_from_args(&CopyToArgs { named }).unwrap();
+
+        let fs = MemoryFileSystem::default();
+        let path = Path::new("out.parquet");
+
+        let mut writer = AsyncBatchWriter::try_new_with_options(
+            fs.file_sink(path).unwrap(),
+            schema.clone(),
+            &options,
+        )
+        .unwrap();
+
+        futures::executor::block_on(async {
+            // Batches don't line up with row groups.
+            for start in (0..NUM_ROWS).step_by(300) {
+                let end = (start + 300).min(NUM_ROWS);
+                let batch = Batch::try_new([
+                    Array::from_iter((start..end).map(|v| v as i64)),
+                    Array::from_iter((start..end).map(|v| format!("row {v}"))),
+                ])
+                .unwrap();
+                writer.write(&batch).await.unwrap();
+            }
+            writer.finish().await.unwrap();
+
+            let mut source = fs.file_source(path).unwrap();
+            let size = source.size().await.unwrap();
+            let metadata = Metadata::new_from_source(source.as_mut(), size)
+                .await
+                .unwrap();
+
+            let row_groups = (0..metadata.decoded_metadata.num_row_groups()).collect();
+            let mut reader = AsyncBatchReader::try_new(
+                fs.file_source(path).unwrap(),
+                row_groups,
+                Arc::new(metadata.clone()),
+                &schema,
+                1024,
+                Projections::all(),
+            )
+            .unwrap();
+
+            let mut batches = Vec::new();
+            while let Some(batch) = reader.read_next().await.unwrap() {
+                batches.push(batch);
+            }
+
+            (metadata, batches)
+        })
+    }
+
+    fn assert_rows(batches: &[Batch]) {
+        let mut expected = 0;
+        for batch in batches {
+            for row_idx in 0..batch.num_rows() {
+                let row = batch.row(row_idx).unwrap();
+                assert_eq!(ScalarValue::Int64(expected as i64), row.columns[0]);
+                assert_eq!(ScalarValue::from(format!("row {expected}")), row.columns[1]);
+                expected += 1;
+            }
+        }
+        assert_eq!(NUM_ROWS, expected);
+    }
+
+    fn row_group_sizes(metadata: &Metadata) -> Vec<i64> {
+        metadata
+            .decoded_metadata
+            .row_groups()
+            .iter()
+            .map(|rg| rg.num_rows())
+            .collect()
+    }
+
+    #[test]
+    fn write_snappy_with_row_group_size() {
+        let (metadata, batches) = round_trip(HashMap::from([
+            ("compression".to_string(), OwnedScalarValue::from("snappy")),
+            ("row_group_size".to_string(), OwnedScalarValue::Int64(1000)),
+        ]));
+
+        assert_eq!(vec![1000, 1000, 500], row_group_sizes(&metadata));
+        for rg in metadata.decoded_metadata.row_groups() {
+            for col in rg.columns() {
+                assert_eq!(Compression::SNAPPY, col.compression());
+            }
+        }
+        assert_rows(&batches);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn write_zstd_with_row_group_size() {
+        let (metadata, batches) = round_trip(HashMap::from([
+            ("compression".to_string(), OwnedScalarValue::from("zstd")),
+            ("row_group_size".to_string(), OwnedScalarValue::Int64(1024)),
+        ]));
+
+        assert_eq!(vec![1024, 1024, 452], row_group_sizes(&metadata));
+        for rg in metadata.decoded_metadata.row_groups() {
+            for col in rg.columns() {
+                assert_eq!(Compression::ZSTD(ZstdLevel::default()), col.compression());
+            }
+        }
+        assert_rows(&batches);
+    }
+
+    #[test]
+    fn write_statistics() {
+        let (metadata, batches) = round_trip(HashMap::from([(
+            "row_group_size".to_string(),
+            OwnedScalarValue::Int64(1000),
+        )]));
+
+        let mins_maxes: Vec<_> = metadata
+            .decoded_metadata
+            .row_groups()
+            .iter()
+            .map(|rg| match rg.column(0).statistics() {
+                Some(Statistics::Int64(s)) => (*s.min(), *s.max()),
+                other => panic!("unexpected stats: {other:?}"),
+            })
+            .collect();
+        assert_eq!(vec![(0, 999), (1000, 1999), (2000, 2499)], mins_maxes);
+
+        for rg in metadata.decoded_metadata.row_groups() {
+            let stats = rg.column(1).statistics().unwrap();
+            assert!(stats.has_min_max_set());
+        }
+        assert_rows(&batches);
+    }
+
+    #[test]
+    fn write_without_statistics() {
+        let (metadata, batches) = round_trip(HashMap::from([(
+            "write_statistics".to_string(),
+            OwnedScalarValue::Boolean(false),
+        )]));
+
+        for rg in metadata.decoded_metadata.row_groups() {
+            for col in rg.columns() {
+                assert!(col.statistics().is_none());
+            }
+        }
+        assert_rows(&batches);
+    }
+
+    #[test]
+    fn write_dictionary_toggle() {
+        let is_dict_encoded = |metadata: &Metadata| {
+            metadata
+                .decoded_metadata
+                .row_groups()
+                .iter()
+                .flat_map(|rg| rg.columns())
+                .all(|col| col.dictionary_page_offset().is_some())
+        };
+
+        let (metadata, batches) = round_trip(HashMap::new());
+        assert!(is_dict_encoded(&metadata));
+        assert_rows(&batches);
+
+        let (metadata, batches) = round_trip(HashMap::from([(
+            "dictionary".to_string(),
+            OwnedScalarValue::Boolean(false),
+        )]));
+        for rg in metadata.decoded_metadata.row_groups() {
+            for col in rg.columns() {
+                assert!(col.dictionary_page_offset().is_none());
+                assert!(!col.encodings().contains(&Encoding::RLE_DICTIONARY));
+            }
+        }
+        assert_rows(&batches);
+    }
+
+    #[test]
+    fn invalid_options() {
+        let args = CopyToArgs {
+            named: HashMap::from([("compression".to_string(), OwnedScalarValue::from("lzo"))]),
+        };
+        ParquetWriteOptions::try_from_args(&args).unwrap_err();
+
+        let args = CopyToArgs {
+            named: HashMap::from([("row_group_size".to_string(), OwnedScalarValue::Int64(0))]),
+        };
+        ParquetWriteOptions::try_from_args(&args).unwrap_err();
+
+        let args = CopyToArgs {
+            named: HashMap::from([("header".to_string(), OwnedScalarValue::Boolean(true))]),
+        };
+        ParquetWriteOptions::try_from_args(&args).unwrap_err();
+
+        let args = CopyToArgs {
+            named: HashMap::from([(
+                "dictionary_size_limit".to_string(),
+                OwnedScalarValue::Int64(0),
+            )]),
+        };
+        ParquetWriteOptions::try_from_args(&args).unwrap_err();
+    }
+}
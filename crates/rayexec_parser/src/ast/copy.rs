@@ -127,6 +127,7 @@ mod tests {
                 limit: LimitModifier {
                     limit: None,
                     offset: None,
+                    with_ties: false,
                 },
             }),
             target: CopyToTarget::File("myfile.csv".to_string()),
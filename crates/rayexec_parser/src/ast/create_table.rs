@@ -113,6 +113,7 @@ mod tests {
             limit: LimitModifier {
                 limit: None,
                 offset: None,
+                with_ties: false,
             },
         }
     }
@@ -27,7 +27,11 @@ impl AstParseable for CommonTableExprs<Raw> {
 pub struct CommonTableExpr<T: AstMeta> {
     pub alias: Ident,
     pub column_aliases: Option<Vec<Ident>>,
-    pub materialized: bool,
+    /// Explicit materialization hint.
+    ///
+    /// `Some(true)` for `AS MATERIALIZED`, `Some(false)` for `AS NOT
+    /// MATERIALIZED`, and None if left up to the planner.
+    pub materialized: Option<bool>,
     pub body: Box<QueryNode<T>>,
 }
 
@@ -49,7 +53,14 @@ impl AstParseable for CommonTableExpr<Raw> {
             Some(column_aliases)
         };
 
-        let materialized = parser.parse_keyword(Keyword::MATERIALIZED);
+        let materialized = if parser.parse_keyword_sequence(&[Keyword::NOT, Keyword::MATERIALIZED])
+        {
+            Some(false)
+        } else if parser.parse_keyword(Keyword::MATERIALIZED) {
+            Some(true)
+        } else {
+            None
+        };
 
         // Parse the subquery.
         parser.expect_token(&Token::LeftParen)?;
@@ -38,16 +38,20 @@ pub enum DataType {
 
 impl AstParseable for DataType {
     fn parse(parser: &mut Parser) -> Result<Self> {
-        let kw = match parser.next() {
-            Some(tok) => match tok.keyword() {
-                Some(kw) => kw,
-                None => return Err(RayexecError::new(format!("Expected keyword, got: {tok:?}"))),
+        let (kw, name) = match parser.next() {
+            Some(tok) => match &tok.token {
+                Token::Word(word) => (word.keyword, word.value.clone()),
+                _ => return Err(RayexecError::new(format!("Expected keyword, got: {tok:?}"))),
             },
             None => return Err(RayexecError::new("Unexpected end of query")),
         };
+        let kw = match kw {
+            Some(kw) => kw,
+            None => return Err(RayexecError::new(format!("Unknown data type: '{name}'"))),
+        };
 
         Ok(match kw {
-            Keyword::VARCHAR => DataType::Varchar(None), // TODO: With length.
+            Keyword::VARCHAR => DataType::Varchar(Self::parse_length(parser)?),
             Keyword::TEXT | Keyword::STRING => DataType::Varchar(None),
             Keyword::TINYINT | Keyword::INT1 => DataType::TinyInt,
             Keyword::SMALLINT | Keyword::INT2 => DataType::SmallInt,
@@ -64,16 +68,24 @@ impl AstParseable for DataType {
             Keyword::DATE => DataType::Date,
             Keyword::TIMESTAMP => DataType::Timestamp,
             Keyword::INTERVAL => DataType::Interval,
-            other => {
-                return Err(RayexecError::new(format!(
-                    "Unexpected keyword for data type: {other:?}",
-                )))
-            }
+            _ => return Err(RayexecError::new(format!("Unknown data type: '{name}'"))),
         })
     }
 }
 
 impl DataType {
+    /// Parse an optional length, e.g. the `(10)` in `VARCHAR(10)`.
+    fn parse_length(parser: &mut Parser) -> Result<Option<u64>> {
+        if !parser.consume_token(&Token::LeftParen) {
+            return Ok(None);
+        }
+        let len = Expr::parse_i64_literal(parser)?;
+        let len = u64::try_from(len)
+            .map_err(|_| RayexecError::new(format!("Length cannot be negative: {len}")))?;
+        parser.expect_token(&Token::RightParen)?;
+        Ok(Some(len))
+    }
+
     fn parse_precision_scale(parser: &mut Parser) -> Result<(Option<i64>, Option<i64>)> {
         let (mut prec, mut scale) = (None, None);
         if parser.consume_token(&Token::LeftParen) {
@@ -135,6 +147,21 @@ mod tests {
         assert_ast_eq(DataType::Interval, "INTERVAL");
     }
 
+    #[test]
+    fn varchar_length() {
+        assert_ast_eq(DataType::Varchar(Some(255)), "VARCHAR(255)");
+        parse_ast::<DataType>("varchar(-1)").unwrap_err();
+    }
+
+    #[test]
+    fn unknown_type() {
+        let err = parse_ast::<DataType>("foo").unwrap_err();
+        assert_eq!("Unknown data type: 'foo'", err.get_msg());
+
+        let err = parse_ast::<DataType>("select").unwrap_err();
+        assert_eq!("Unknown data type: 'select'", err.get_msg());
+    }
+
     #[test]
     fn decimal() {
         assert_ast_eq(DataType::Decimal(None, None), "decimal");
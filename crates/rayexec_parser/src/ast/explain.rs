@@ -31,24 +31,45 @@ impl AstParseable for ExplainNode<Raw> {
     fn parse(parser: &mut Parser) -> Result<Self> {
         parser.expect_keyword(Keyword::EXPLAIN)?;
 
-        let analyze = parser.parse_keyword(Keyword::ANALYZE);
-        let verbose = parser.parse_keyword(Keyword::VERBOSE);
-
-        let output = if parser.consume_token(&Token::LeftParen) {
-            // Just FORMAT for now.
-            parser.expect_keyword(Keyword::FORMAT)?;
-            let format = if parser.parse_keyword(Keyword::JSON) {
-                ExplainOutput::Json
-            } else if parser.parse_keyword(Keyword::TEXT) {
-                ExplainOutput::Text
-            } else {
-                return Err(RayexecError::new("Expect JSON or TEXT for explain format"));
-            };
+        let mut analyze = parser.parse_keyword(Keyword::ANALYZE);
+        let mut verbose = parser.parse_keyword(Keyword::VERBOSE);
+        let mut output = None;
+
+        // Options list, e.g. `(ANALYZE, VERBOSE, FORMAT JSON)`
+        if parser.consume_token(&Token::LeftParen) {
+            loop {
+                match parser.parse_one_of_keywords(&[
+                    Keyword::ANALYZE,
+                    Keyword::VERBOSE,
+                    Keyword::FORMAT,
+                ]) {
+                    Some(Keyword::ANALYZE) => analyze = true,
+                    Some(Keyword::VERBOSE) => verbose = true,
+                    Some(Keyword::FORMAT) => {
+                        let format = if parser.parse_keyword(Keyword::JSON) {
+                            ExplainOutput::Json
+                        } else if parser.parse_keyword(Keyword::TEXT) {
+                            ExplainOutput::Text
+                        } else {
+                            return Err(RayexecError::new(
+                                "Expect JSON or TEXT for explain format",
+                            ));
+                        };
+                        output = Some(format);
+                    }
+                    _ => {
+                        return Err(RayexecError::new(
+                            "Expected ANALYZE, VERBOSE, or FORMAT for explain option",
+                        ))
+                    }
+                }
+
+                if !parser.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
             parser.expect_token(&Token::RightParen)?;
-            Some(format)
-        } else {
-            None
-        };
+        }
 
         let body = match parser.parse_statement()? {
             Statement::Query(query) => ExplainBody::Query(query),
@@ -90,6 +111,7 @@ mod tests {
             limit: LimitModifier {
                 limit: None,
                 offset: None,
+                with_ties: false,
             },
         }
     }
@@ -130,6 +152,35 @@ mod tests {
         assert_eq!(expected, explain)
     }
 
+    #[test]
+    fn options_list() {
+        let explain: ExplainNode<_> = parse_ast("explain (verbose, format json) select 1").unwrap();
+        let expected = ExplainNode {
+            analyze: false,
+            verbose: true,
+            body: ExplainBody::Query(query_node_select_1()),
+            output: Some(ExplainOutput::Json),
+        };
+        assert_eq!(expected, explain)
+    }
+
+    #[test]
+    fn options_list_analyze() {
+        let explain: ExplainNode<_> = parse_ast("explain (format text, analyze) select 1").unwrap();
+        let expected = ExplainNode {
+            analyze: true,
+            verbose: false,
+            body: ExplainBody::Query(query_node_select_1()),
+            output: Some(ExplainOutput::Text),
+        };
+        assert_eq!(expected, explain)
+    }
+
+    #[test]
+    fn options_list_unknown() {
+        let _ = parse_ast::<ExplainNode<_>>("explain (costs) select 1").unwrap_err();
+    }
+
     #[test]
     fn format_unknown() {
         let _ = parse_ast::<ExplainNode<_>>("explain (format exemel) select 1").unwrap_err();
@@ -9,6 +9,7 @@ use super::{
     DataType,
     Ident,
     ObjectReference,
+    OrderByNode,
     QueryNode,
     WindowDefinition,
     WindowSpec,
@@ -42,6 +43,8 @@ pub enum BinaryOperator {
     IntDiv,
     /// Modulo, e.g. `a % b`
     Modulo,
+    /// Exponentiation, e.g. `a ^ b`
+    Exponent,
     /// String/Array Concat operator, e.g. `a || b`
     StringConcat,
     /// String starts with operator, e.g. `a ^@ b`
@@ -70,8 +73,12 @@ pub enum BinaryOperator {
     BitwiseOr,
     /// Bitwise and, e.g. `a & b`
     BitwiseAnd,
-    /// Bitwise XOR, e.g. `a ^ b`
+    /// Bitwise XOR, e.g. `a # b`
     BitwiseXor,
+    /// Bitwise shift left, e.g. `a << b`
+    BitwiseShiftLeft,
+    /// Bitwise shift right, e.g. `a >> b`
+    BitwiseShiftRight,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -91,6 +98,13 @@ pub enum Literal<T: AstMeta> {
         keys: Vec<String>,
         values: Vec<Expr<T>>,
     },
+    /// Map literal.
+    ///
+    /// Lengths of keys and values must be the same.
+    Map {
+        keys: Vec<Expr<T>>,
+        values: Vec<Expr<T>>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -102,6 +116,12 @@ pub struct Function<T: AstMeta> {
     pub distinct: bool,
     /// Arguments to the function.
     pub args: Vec<FunctionArg<T>>,
+    /// Ordering for ordered-set aggregates.
+    ///
+    /// E.g. `SELECT percentile_cont(0.5) WITHIN GROUP (ORDER BY col) FROM ...`
+    ///
+    /// Empty if there's no WITHIN GROUP.
+    pub within_group: Vec<OrderByNode<T>>,
     /// Filter part of `COUNT(col) FILTER (WHERE col > 5)`
     pub filter: Option<Box<Expr<T>>>,
     /// Option OVER clause indicating this is a window function.
@@ -144,6 +164,28 @@ impl AstParseable for FunctionArg<Raw> {
     }
 }
 
+impl<T: AstMeta> FunctionArg<T> {
+    /// Check that all positional arguments come before any named arguments.
+    pub fn check_arg_order(args: &[Self]) -> Result<()> {
+        let first_named = args
+            .iter()
+            .position(|arg| matches!(arg, FunctionArg::Named { .. }));
+
+        if let Some(first_named) = first_named {
+            if args[first_named..]
+                .iter()
+                .any(|arg| matches!(arg, FunctionArg::Unnamed { .. }))
+            {
+                return Err(RayexecError::new(
+                    "Positional arguments cannot follow named arguments",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum FunctionArgExpr<T: AstMeta> {
     Wildcard,
@@ -333,7 +375,7 @@ impl Expr<Raw> {
     const PREC_EVERYTHING_ELSE: u8 = 70; // Anything without a specific precedence.
     const PREC_ADD_SUB: u8 = 80;
     const PREC_MUL_DIV_MOD: u8 = 90;
-    const _PREC_EXPONENTIATION: u8 = 100;
+    const PREC_EXPONENTIATION: u8 = 100;
     const PREC_UNARY_MINUS: u8 = 105;
     const _PREC_AT: u8 = 110; // AT TIME ZONE
     const _PREC_COLLATE: u8 = 120;
@@ -503,6 +545,29 @@ impl Expr<Raw> {
                             expr: Box::new(expr),
                         }
                     }
+                    Keyword::MAP => {
+                        let w = w.clone();
+                        if parser.consume_token(&Token::LeftBrace) {
+                            // Map literal, e.g. `MAP {'a': 1, 'b': 2}`
+                            let entries = if parser.consume_token(&Token::RightBrace) {
+                                Vec::new()
+                            } else {
+                                let entries = parser.parse_comma_separated(|parser| {
+                                    let key = Expr::parse(parser)?;
+                                    parser.expect_token(&Token::Colon)?;
+                                    let value = Expr::parse(parser)?;
+                                    Ok((key, value))
+                                })?;
+                                parser.expect_token(&Token::RightBrace)?;
+                                entries
+                            };
+
+                            let (keys, values) = entries.into_iter().unzip();
+                            Expr::Literal(Literal::Map { keys, values })
+                        } else {
+                            Self::parse_ident_expr(w, parser)?
+                        }
+                    }
                     Keyword::COLUMNS => {
                         // TODO: Should we just special case on left paren? And
                         // assume ident otherwise?
@@ -530,6 +595,27 @@ impl Expr<Raw> {
                     expr
                 }
             }
+            Token::LeftBrace => {
+                // Struct literal, e.g. `{a: 1, 'b': 'cat'}`
+                let fields = parser.parse_comma_separated(|parser| {
+                    let key = match parser.next().map(|t| &t.token) {
+                        Some(Token::Word(w)) => Ident::from(w.clone()).into_normalized_string(),
+                        Some(Token::SingleQuotedString(s)) => s.clone(),
+                        other => {
+                            return Err(RayexecError::new(format!(
+                                "Expected struct field name, got {other:?}"
+                            )))
+                        }
+                    };
+                    parser.expect_token(&Token::Colon)?;
+                    let value = Expr::parse(parser)?;
+                    Ok((key, value))
+                })?;
+                parser.expect_token(&Token::RightBrace)?;
+
+                let (keys, values) = fields.into_iter().unzip();
+                Expr::Literal(Literal::Struct { keys, values })
+            }
             Token::SingleQuotedString(s) => Expr::Literal(Literal::SingleQuotedString(s.clone())),
             Token::Number(s) => Expr::Literal(Literal::Number(s.clone())),
             Token::LeftParen => {
@@ -589,8 +675,14 @@ impl Expr<Raw> {
             Token::Div => Some(BinaryOperator::Divide),
             Token::IntDiv => Some(BinaryOperator::IntDiv),
             Token::Mod => Some(BinaryOperator::Modulo),
+            Token::Caret => Some(BinaryOperator::Exponent),
             Token::Concat => Some(BinaryOperator::StringConcat),
             Token::CaretAt => Some(BinaryOperator::StringStartsWith),
+            Token::Ampersand => Some(BinaryOperator::BitwiseAnd),
+            Token::Pipe => Some(BinaryOperator::BitwiseOr),
+            Token::Hash => Some(BinaryOperator::BitwiseXor),
+            Token::ShiftLeft => Some(BinaryOperator::BitwiseShiftLeft),
+            Token::ShiftRight => Some(BinaryOperator::BitwiseShiftRight),
             Token::Word(w) => match w.keyword {
                 Some(Keyword::AND) => Some(BinaryOperator::And),
                 Some(Keyword::OR) => Some(BinaryOperator::Or),
@@ -855,6 +947,7 @@ impl Expr<Raw> {
             // Numeric operators
             Token::Plus | Token::Minus => Ok(Self::PREC_ADD_SUB),
             Token::Mul | Token::Div | Token::IntDiv | Token::Mod => Ok(Self::PREC_MUL_DIV_MOD),
+            Token::Caret => Ok(Self::PREC_EXPONENTIATION),
 
             // Cast
             Token::DoubleColon => Ok(Self::PREC_CAST),
@@ -865,6 +958,11 @@ impl Expr<Raw> {
             // Starts with
             Token::CaretAt => Ok(Self::PREC_EVERYTHING_ELSE),
 
+            // Bitwise
+            Token::Ampersand | Token::Pipe | Token::Hash | Token::ShiftLeft | Token::ShiftRight => {
+                Ok(Self::PREC_EVERYTHING_ELSE)
+            }
+
             // Array, struct literals
             Token::LeftBrace | Token::LeftBracket => Ok(Self::PREC_ARRAY_ELEM),
 
@@ -908,10 +1006,24 @@ impl Expr<Raw> {
                 Vec::new()
             } else {
                 let args = parser.parse_comma_separated(FunctionArg::parse)?;
+                FunctionArg::check_arg_order(&args)?;
                 parser.expect_token(&Token::RightParen)?;
                 args
             };
 
+            // WITHIN GROUP (ORDER BY <expr>, ...)
+            let within_group = if parser.parse_keyword_sequence(&[Keyword::WITHIN, Keyword::GROUP])
+            {
+                parser.expect_token(&Token::LeftParen)?;
+                parser.expect_keyword(Keyword::ORDER)?;
+                parser.expect_keyword(Keyword::BY)?;
+                let order_by = parser.parse_comma_separated(OrderByNode::parse)?;
+                parser.expect_token(&Token::RightParen)?;
+                order_by
+            } else {
+                Vec::new()
+            };
+
             // FILTER (WHERE <expr>)
             let filter = if parser.parse_keyword(Keyword::FILTER) {
                 parser.expect_token(&Token::LeftParen)?;
@@ -945,6 +1057,7 @@ impl Expr<Raw> {
                 reference: ObjectReference(idents),
                 distinct,
                 args,
+                within_group,
                 filter,
                 over,
             })))
@@ -1281,7 +1394,7 @@ mod tests {
 
     use super::*;
     use crate::ast::testutil::parse_ast;
-    use crate::ast::{OrderByNode, OrderByType};
+    use crate::ast::OrderByType;
 
     #[test]
     fn literal() {
@@ -1290,6 +1403,42 @@ mod tests {
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn struct_literal() {
+        let expr: Expr<_> = parse_ast("{a: 1, 'B': 'cat'}").unwrap();
+        let expected = Expr::Literal(Literal::Struct {
+            keys: vec!["a".to_string(), "B".to_string()],
+            values: vec![
+                Expr::Literal(Literal::Number("1".to_string())),
+                Expr::Literal(Literal::SingleQuotedString("cat".to_string())),
+            ],
+        });
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn map_literal() {
+        let expr: Expr<_> = parse_ast("MAP {'a': 1, 2: b}").unwrap();
+        let expected = Expr::Literal(Literal::Map {
+            keys: vec![
+                Expr::Literal(Literal::SingleQuotedString("a".to_string())),
+                Expr::Literal(Literal::Number("2".to_string())),
+            ],
+            values: vec![
+                Expr::Literal(Literal::Number("1".to_string())),
+                Expr::Ident(Ident::new_unquoted("b")),
+            ],
+        });
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn map_function_call() {
+        // `map` without a brace is a regular identifier.
+        let expr: Expr<_> = parse_ast("map(1, 2)").unwrap();
+        assert!(matches!(expr, Expr::Function(_)));
+    }
+
     #[test]
     fn compound() {
         let expr: Expr<_> = parse_ast("my_schema.t1").unwrap();
@@ -1328,6 +1477,59 @@ mod tests {
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn exponent_binds_tighter_than_mul() {
+        // 2 * (3 ^ 2)
+        let expr: Expr<_> = parse_ast("2 * 3 ^ 2").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::Literal(Literal::Number("2".to_string()))),
+            op: BinaryOperator::Multiply,
+            right: Box::new(Expr::BinaryExpr {
+                left: Box::new(Expr::Literal(Literal::Number("3".to_string()))),
+                op: BinaryOperator::Exponent,
+                right: Box::new(Expr::Literal(Literal::Number("2".to_string()))),
+            }),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        let cases = [
+            ("5 & 8", BinaryOperator::BitwiseAnd),
+            ("5 | 8", BinaryOperator::BitwiseOr),
+            ("5 # 8", BinaryOperator::BitwiseXor),
+            ("5 << 8", BinaryOperator::BitwiseShiftLeft),
+            ("5 >> 8", BinaryOperator::BitwiseShiftRight),
+        ];
+
+        for (sql, op) in cases {
+            let expr: Expr<_> = parse_ast(sql).unwrap();
+            let expected = Expr::BinaryExpr {
+                left: Box::new(Expr::Literal(Literal::Number("5".to_string()))),
+                op,
+                right: Box::new(Expr::Literal(Literal::Number("8".to_string()))),
+            };
+            assert_eq!(expected, expr, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn bitwise_binds_looser_than_arith() {
+        // 1 << (2 + 3)
+        let expr: Expr<_> = parse_ast("1 << 2 + 3").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::Literal(Literal::Number("1".to_string()))),
+            op: BinaryOperator::BitwiseShiftLeft,
+            right: Box::new(Expr::BinaryExpr {
+                left: Box::new(Expr::Literal(Literal::Number("2".to_string()))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Literal(Literal::Number("3".to_string()))),
+            }),
+        };
+        assert_eq!(expected, expr);
+    }
+
     #[test]
     fn function_call_simple() {
         let expr: Expr<_> = parse_ast("sum(my_col)").unwrap();
@@ -1337,6 +1539,7 @@ mod tests {
             args: vec![FunctionArg::Unnamed {
                 arg: FunctionArgExpr::Expr(Expr::Ident(Ident::new_unquoted("my_col"))),
             }],
+            within_group: Vec::new(),
             filter: None,
             over: None,
         }));
@@ -1350,12 +1553,42 @@ mod tests {
             reference: ObjectReference(vec![Ident::new_unquoted("random")]),
             distinct: false,
             args: Vec::new(),
+            within_group: Vec::new(),
             filter: None,
             over: None,
         }));
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn function_call_named_args() {
+        let expr: Expr<_> = parse_ast("lpad('a', count => 3)").unwrap();
+        let expected = Expr::Function(Box::new(Function {
+            reference: ObjectReference(vec![Ident::new_unquoted("lpad")]),
+            distinct: false,
+            args: vec![
+                FunctionArg::Unnamed {
+                    arg: FunctionArgExpr::Expr(Expr::Literal(Literal::SingleQuotedString(
+                        "a".to_string(),
+                    ))),
+                },
+                FunctionArg::Named {
+                    name: Ident::new_unquoted("count"),
+                    arg: FunctionArgExpr::Expr(Expr::Literal(Literal::Number("3".to_string()))),
+                },
+            ],
+            within_group: Vec::new(),
+            filter: None,
+            over: None,
+        }));
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn function_call_positional_after_named() {
+        parse_ast::<Expr<_>>("lpad(string => 'a', 3)").unwrap_err();
+    }
+
     #[test]
     fn function_call_with_over() {
         let expr: Expr<_> = parse_ast("count(x) filter (where x > 5)").unwrap();
@@ -1365,6 +1598,7 @@ mod tests {
             args: vec![FunctionArg::Unnamed {
                 arg: FunctionArgExpr::Expr(Expr::Ident(Ident::new_unquoted("x"))),
             }],
+            within_group: Vec::new(),
             filter: Some(Box::new(Expr::BinaryExpr {
                 left: Box::new(Expr::Ident(Ident::new_unquoted("x"))),
                 op: BinaryOperator::Gt,
@@ -1375,6 +1609,27 @@ mod tests {
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn function_call_within_group() {
+        let expr: Expr<_> =
+            parse_ast("percentile_cont(0.5) within group (order by x desc)").unwrap();
+        let expected = Expr::Function(Box::new(Function {
+            reference: ObjectReference(vec![Ident::new_unquoted("percentile_cont")]),
+            distinct: false,
+            args: vec![FunctionArg::Unnamed {
+                arg: FunctionArgExpr::Expr(Expr::Literal(Literal::Number("0.5".to_string()))),
+            }],
+            within_group: vec![OrderByNode {
+                typ: Some(OrderByType::Desc),
+                nulls: None,
+                expr: Expr::Ident(Ident::new_unquoted("x")),
+            }],
+            filter: None,
+            over: None,
+        }));
+        assert_eq!(expected, expr);
+    }
+
     #[test]
     fn function_call_with_distinct() {
         let expr: Expr<_> = parse_ast("count(distinct x)").unwrap();
@@ -1384,6 +1639,7 @@ mod tests {
             args: vec![FunctionArg::Unnamed {
                 arg: FunctionArgExpr::Expr(Expr::Ident(Ident::new_unquoted("x"))),
             }],
+            within_group: Vec::new(),
             filter: None,
             over: None,
         }));
@@ -1398,6 +1654,7 @@ mod tests {
             reference: ObjectReference(vec![Ident::new_unquoted("rank")]),
             distinct: false,
             args: Vec::new(),
+            within_group: Vec::new(),
             filter: None,
             over: Some(WindowSpec::Definition(WindowDefinition {
                 existing: None,
@@ -1427,6 +1684,7 @@ mod tests {
             reference: ObjectReference(vec![Ident::new_unquoted("rank")]),
             distinct: false,
             args: Vec::new(),
+            within_group: Vec::new(),
             filter: None,
             // Note that this should be Some but everything empty. We need to
             // differentiate between and empty OVER and missing OVER.
@@ -1460,6 +1718,7 @@ mod tests {
             args: vec![FunctionArg::Unnamed {
                 arg: FunctionArgExpr::Wildcard,
             }],
+            within_group: Vec::new(),
             filter: None,
             over: None,
         }));
@@ -1478,6 +1737,7 @@ mod tests {
                 args: vec![FunctionArg::Unnamed {
                     arg: FunctionArgExpr::Wildcard,
                 }],
+                within_group: Vec::new(),
                 filter: None,
                 over: None,
             }))),
@@ -1495,6 +1755,7 @@ mod tests {
                 args: vec![FunctionArg::Unnamed {
                     arg: FunctionArgExpr::Wildcard,
                 }],
+                within_group: Vec::new(),
                 filter: None,
                 over: None,
             }))),
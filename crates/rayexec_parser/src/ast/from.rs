@@ -231,6 +231,7 @@ impl FromNode<Raw> {
                         limit: LimitModifier {
                             limit: None,
                             offset: None,
+                            with_ties: false,
                         },
                     },
                 }),
@@ -257,10 +258,14 @@ impl FromNode<Raw> {
             let body = match parser.peek() {
                 Some(TokenWithLocation { token, .. }) if token == &Token::LeftParen => {
                     let args = parser.parse_parenthesized_comma_separated(FunctionArg::parse)?;
+                    FunctionArg::check_arg_order(&args)?;
+                    let with_ordinality =
+                        parser.parse_keyword_sequence(&[Keyword::WITH, Keyword::ORDINALITY]);
                     FromNodeBody::TableFunction(FromTableFunction {
                         lateral,
                         reference,
                         args,
+                        with_ordinality,
                     })
                 }
                 _ => {
@@ -331,6 +336,9 @@ pub struct FromTableFunction<T: AstMeta> {
     pub lateral: bool,
     pub reference: T::TableFunctionReference,
     pub args: Vec<FunctionArg<T>>,
+    /// If `WITH ORDINALITY` was specified, appending a row number column to
+    /// the function output.
+    pub with_ordinality: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -504,6 +512,27 @@ mod tests {
                     quoted: false,
                 }]),
                 args: Vec::new(),
+                with_ordinality: false,
+            }),
+        };
+        assert_eq!(expected, node)
+    }
+
+    #[test]
+    fn table_func_with_ordinality() {
+        let node: FromNode<_> = parse_ast("my_table_func(1) WITH ORDINALITY AS t(a, n)").unwrap();
+        let expected = FromNode {
+            alias: Some(FromAlias {
+                alias: Ident::new_unquoted("t"),
+                columns: Some(vec![Ident::new_unquoted("a"), Ident::new_unquoted("n")]),
+            }),
+            body: FromNodeBody::TableFunction(FromTableFunction {
+                lateral: false,
+                reference: ObjectReference::from_strings(["my_table_func"]),
+                args: vec![FunctionArg::Unnamed {
+                    arg: FunctionArgExpr::Expr(Expr::Literal(Literal::Number("1".to_string()))),
+                }],
+                with_ordinality: true,
             }),
         };
         assert_eq!(expected, node)
@@ -536,11 +565,17 @@ mod tests {
                         ))),
                     },
                 ],
+                with_ordinality: false,
             }),
         };
         assert_eq!(expected, node)
     }
 
+    #[test]
+    fn table_func_positional_after_named() {
+        parse_ast::<FromNode<_>>("my_table_func(kw = 'arg2', 'arg1')").unwrap_err();
+    }
+
     #[test]
     fn inner_join_on() {
         let node: FromNode<_> = parse_ast("table1 INNER JOIN table2 ON (c1 = c2)").unwrap();
@@ -689,6 +724,7 @@ mod tests {
                                 Ident::new_unquoted("a"),
                             ])),
                         }],
+                        with_ordinality: false,
                     }),
                 }),
                 join_type: JoinType::Left,
@@ -56,6 +56,7 @@ mod tests {
             limit: LimitModifier {
                 limit: None,
                 offset: None,
+                with_ties: false,
             },
         }
     }
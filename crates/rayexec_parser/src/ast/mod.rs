@@ -34,6 +34,7 @@ pub use cte::*;
 pub mod drop;
 pub use drop::*;
 pub mod attach;
+pub mod transaction;
 pub mod window;
 use std::fmt;
 use std::hash::Hash;
@@ -41,6 +42,7 @@ use std::hash::Hash;
 pub use attach::*;
 use rayexec_error::{RayexecError, Result};
 use serde::{Deserialize, Serialize};
+pub use transaction::*;
 pub use window::*;
 
 use crate::parser::Parser;
@@ -1,7 +1,7 @@
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 use serde::{Deserialize, Serialize};
 
-use super::{AstParseable, Expr};
+use super::{AstParseable, Expr, Literal};
 use crate::keywords::Keyword;
 use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
@@ -59,19 +59,28 @@ pub enum OrderByNulls {
 pub struct LimitModifier<T: AstMeta> {
     pub limit: Option<Expr<T>>,
     pub offset: Option<Expr<T>>,
+    /// If rows tying with the last row (according to ORDER BY) should also be
+    /// included.
+    ///
+    /// Only set when using `FETCH FIRST n ROWS WITH TIES`.
+    pub with_ties: bool,
 }
 
 impl AstParseable for LimitModifier<Raw> {
     fn parse(parser: &mut Parser) -> Result<Self> {
         let mut limit = None;
         let mut offset = None;
+        let mut with_ties = false;
 
         if parser.parse_keyword(Keyword::LIMIT) {
             limit = Some(Expr::parse(parser)?)
         }
 
         if parser.parse_keyword(Keyword::OFFSET) {
-            offset = Some(Expr::parse(parser)?)
+            offset = Some(Expr::parse(parser)?);
+            // SQL standard allows for an optional ROW/ROWS following the
+            // offset.
+            let _ = parser.parse_one_of_keywords(&[Keyword::ROW, Keyword::ROWS]);
         }
 
         // Try limit again since LIMIT and OFFSET can be specified in any order.
@@ -79,7 +88,47 @@ impl AstParseable for LimitModifier<Raw> {
             limit = Some(Expr::parse(parser)?)
         }
 
-        Ok(LimitModifier { limit, offset })
+        // FETCH {FIRST | NEXT} [count] {ROW | ROWS} {ONLY | WITH TIES}
+        if parser.parse_keyword(Keyword::FETCH) {
+            if limit.is_some() {
+                return Err(RayexecError::new("Cannot specify both LIMIT and FETCH"));
+            }
+
+            if parser
+                .parse_one_of_keywords(&[Keyword::FIRST, Keyword::NEXT])
+                .is_none()
+            {
+                return Err(RayexecError::new("Expected FIRST or NEXT after FETCH"));
+            }
+
+            // Count is optional, defaulting to 1.
+            let count = match parser.parse_one_of_keywords(&[Keyword::ROW, Keyword::ROWS]) {
+                Some(_) => Expr::Literal(Literal::Number("1".to_string())),
+                None => {
+                    let count = Expr::parse(parser)?;
+                    if parser
+                        .parse_one_of_keywords(&[Keyword::ROW, Keyword::ROWS])
+                        .is_none()
+                    {
+                        return Err(RayexecError::new("Expected ROW or ROWS in FETCH clause"));
+                    }
+                    count
+                }
+            };
+            limit = Some(count);
+
+            if parser.parse_keyword_sequence(&[Keyword::WITH, Keyword::TIES]) {
+                with_ties = true;
+            } else {
+                parser.expect_keyword(Keyword::ONLY)?;
+            }
+        }
+
+        Ok(LimitModifier {
+            limit,
+            offset,
+            with_ties,
+        })
     }
 }
 
@@ -88,3 +137,60 @@ pub enum DistinctModifier<T: AstMeta> {
     On(Vec<Expr<T>>),
     All,
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+
+    #[test]
+    fn limit_offset() {
+        let limit: LimitModifier<_> = parse_ast("LIMIT 5 OFFSET 2").unwrap();
+        let expected = LimitModifier {
+            limit: Some(Expr::Literal(Literal::Number("5".to_string()))),
+            offset: Some(Expr::Literal(Literal::Number("2".to_string()))),
+            with_ties: false,
+        };
+        assert_eq!(expected, limit);
+    }
+
+    #[test]
+    fn fetch_first_only() {
+        let limit: LimitModifier<_> = parse_ast("OFFSET 2 ROWS FETCH NEXT 5 ROWS ONLY").unwrap();
+        let expected = LimitModifier {
+            limit: Some(Expr::Literal(Literal::Number("5".to_string()))),
+            offset: Some(Expr::Literal(Literal::Number("2".to_string()))),
+            with_ties: false,
+        };
+        assert_eq!(expected, limit);
+    }
+
+    #[test]
+    fn fetch_first_with_ties() {
+        let limit: LimitModifier<_> = parse_ast("FETCH FIRST 3 ROWS WITH TIES").unwrap();
+        let expected = LimitModifier {
+            limit: Some(Expr::Literal(Literal::Number("3".to_string()))),
+            offset: None,
+            with_ties: true,
+        };
+        assert_eq!(expected, limit);
+    }
+
+    #[test]
+    fn fetch_first_implicit_count() {
+        let limit: LimitModifier<_> = parse_ast("FETCH FIRST ROW ONLY").unwrap();
+        let expected = LimitModifier {
+            limit: Some(Expr::Literal(Literal::Number("1".to_string()))),
+            offset: None,
+            with_ties: false,
+        };
+        assert_eq!(expected, limit);
+    }
+
+    #[test]
+    fn fetch_with_limit_errors() {
+        parse_ast::<LimitModifier<_>>("LIMIT 3 FETCH FIRST 3 ROWS ONLY").unwrap_err();
+    }
+}
@@ -25,7 +25,19 @@ pub struct SelectNode<T: AstMeta> {
 
 impl AstParseable for SelectNode<Raw> {
     fn parse(parser: &mut Parser) -> Result<Self> {
-        // TODO: distinct
+        // DISTINCT [ON (...)]
+        let distinct = if parser.parse_keyword(Keyword::DISTINCT) {
+            if parser.parse_keyword(Keyword::ON) {
+                let exprs = parser.parse_parenthesized_comma_separated(Expr::parse)?;
+                Some(DistinctModifier::On(exprs))
+            } else {
+                Some(DistinctModifier::All)
+            }
+        } else {
+            // ALL is the default.
+            let _ = parser.parse_keyword(Keyword::ALL);
+            None
+        };
 
         // Select list
         let projections = parser.parse_comma_separated(SelectExpr::parse)?;
@@ -59,7 +71,7 @@ impl AstParseable for SelectNode<Raw> {
         };
 
         Ok(SelectNode {
-            distinct: None,
+            distinct,
             projections,
             from,
             where_expr,
@@ -261,7 +273,15 @@ impl AstParseable for GroupByNode<Raw> {
         if parser.parse_keyword(Keyword::ALL) {
             Ok(GroupByNode::All)
         } else {
-            let exprs = parser.parse_comma_separated(GroupByExpr::parse)?;
+            // Each plain expression is parsed on its own, merge consecutive
+            // expressions into a single grouping element.
+            let mut exprs: Vec<GroupByExpr<Raw>> = Vec::new();
+            for expr in parser.parse_comma_separated(GroupByExpr::parse)? {
+                match (exprs.last_mut(), expr) {
+                    (Some(GroupByExpr::Expr(prev)), GroupByExpr::Expr(next)) => prev.extend(next),
+                    (_, expr) => exprs.push(expr),
+                }
+            }
             Ok(GroupByNode::Exprs { exprs })
         }
     }
@@ -312,7 +332,49 @@ impl AstParseable for GroupByExpr<Raw> {
             }
         }
 
-        let exprs = parser.parse_comma_separated(Expr::parse)?;
-        Ok(GroupByExpr::Expr(exprs))
+        let expr = Expr::parse(parser)?;
+        Ok(GroupByExpr::Expr(vec![expr]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+
+    fn ident_expr(name: &str) -> Expr<Raw> {
+        Expr::Ident(Ident::new_unquoted(name))
+    }
+
+    #[test]
+    fn select_distinct() {
+        let node: SelectNode<_> = parse_ast("DISTINCT a, b").unwrap();
+        assert_eq!(Some(DistinctModifier::All), node.distinct);
+        assert_eq!(
+            vec![
+                SelectExpr::Expr(ident_expr("a")),
+                SelectExpr::Expr(ident_expr("b"))
+            ],
+            node.projections
+        );
+    }
+
+    #[test]
+    fn select_distinct_on() {
+        let node: SelectNode<_> = parse_ast("DISTINCT ON (a) a, b").unwrap();
+        assert_eq!(
+            Some(DistinctModifier::On(vec![ident_expr("a")])),
+            node.distinct
+        );
+        assert_eq!(2, node.projections.len());
+    }
+
+    #[test]
+    fn select_all() {
+        let node: SelectNode<_> = parse_ast("ALL a").unwrap();
+        assert_eq!(None, node.distinct);
+        assert_eq!(vec![SelectExpr::Expr(ident_expr("a"))], node.projections);
     }
 }
@@ -0,0 +1,75 @@
+use rayexec_error::{RayexecError, Result};
+use serde::{Deserialize, Serialize};
+
+use super::AstParseable;
+use crate::keywords::Keyword;
+use crate::parser::Parser;
+
+/// Statements for controlling transaction blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TransactionStatement {
+    /// BEGIN [TRANSACTION | WORK]
+    /// START TRANSACTION
+    Begin,
+    /// COMMIT [TRANSACTION | WORK]
+    /// END [TRANSACTION | WORK]
+    Commit,
+    /// ROLLBACK [TRANSACTION | WORK]
+    Rollback,
+}
+
+impl AstParseable for TransactionStatement {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let stmt = match parser.next_keyword()? {
+            Keyword::BEGIN => TransactionStatement::Begin,
+            Keyword::START => {
+                parser.expect_keyword(Keyword::TRANSACTION)?;
+                return Ok(TransactionStatement::Begin);
+            }
+            Keyword::COMMIT | Keyword::END => TransactionStatement::Commit,
+            Keyword::ROLLBACK => TransactionStatement::Rollback,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Expected BEGIN, START, COMMIT, END, or ROLLBACK, got '{other}'"
+                )))
+            }
+        };
+
+        // Optional noise words.
+        let _ = parser.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
+
+        Ok(stmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+
+    #[test]
+    fn transaction_statements() {
+        let cases = [
+            ("BEGIN", TransactionStatement::Begin),
+            ("BEGIN TRANSACTION", TransactionStatement::Begin),
+            ("START TRANSACTION", TransactionStatement::Begin),
+            ("COMMIT", TransactionStatement::Commit),
+            ("COMMIT WORK", TransactionStatement::Commit),
+            ("END", TransactionStatement::Commit),
+            ("ROLLBACK", TransactionStatement::Rollback),
+            ("ROLLBACK TRANSACTION", TransactionStatement::Rollback),
+        ];
+
+        for (sql, expected) in cases {
+            let got = parse_ast::<TransactionStatement>(sql).unwrap();
+            assert_eq!(expected, got, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn start_requires_transaction() {
+        parse_ast::<TransactionStatement>("START").unwrap_err();
+    }
+}
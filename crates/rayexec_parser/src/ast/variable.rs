@@ -11,18 +11,28 @@ use crate::tokens::Token;
 pub struct SetVariable<T: AstMeta> {
     pub reference: T::ItemReference,
     pub value: Expr<T>,
+    /// If this is `SET LOCAL`, only applying the change for the current
+    /// transaction.
+    pub local: bool,
 }
 
 impl AstParseable for SetVariable<Raw> {
     fn parse(parser: &mut Parser) -> Result<Self> {
         parser.expect_keyword(Keyword::SET)?;
 
+        // SESSION is the default.
+        let local = matches!(
+            parser.parse_one_of_keywords(&[Keyword::LOCAL, Keyword::SESSION]),
+            Some(Keyword::LOCAL)
+        );
+
         let name = ObjectReference::parse(parser)?;
         if parser.parse_keyword(Keyword::TO) || parser.consume_token(&Token::Eq) {
             let expr = Expr::parse(parser)?;
             return Ok(SetVariable {
                 reference: name,
                 value: expr,
+                local,
             });
         }
 
@@ -54,3 +64,36 @@ impl AstParseable for ResetVariable<Raw> {
         Ok(ResetVariable { var })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::Literal;
+
+    #[test]
+    fn set_session() {
+        for sql in ["SET my_var TO 1", "SET SESSION my_var = 1"] {
+            let got = parse_ast::<SetVariable<_>>(sql).unwrap();
+            let expected = SetVariable {
+                reference: ObjectReference::from_strings(["my_var"]),
+                value: Expr::Literal(Literal::Number("1".to_string())),
+                local: false,
+            };
+            assert_eq!(expected, got);
+        }
+    }
+
+    #[test]
+    fn set_local() {
+        let got = parse_ast::<SetVariable<_>>("SET LOCAL my_var = 1").unwrap();
+        let expected = SetVariable {
+            reference: ObjectReference::from_strings(["my_var"]),
+            value: Expr::Literal(Literal::Number("1".to_string())),
+            local: true,
+        };
+        assert_eq!(expected, got);
+    }
+}
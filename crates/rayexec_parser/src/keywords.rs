@@ -70,6 +70,7 @@ define_keywords!(
     CENTURY,
     CLUSTER,
     COLUMNS,
+    COMMIT,
     COPY,
     CREATE,
     CROSS,
@@ -148,6 +149,8 @@ define_keywords!(
     LEFT,
     LIKE,
     LIMIT,
+    LOCAL,
+    MAP,
     MATERIALIZED,
     MICROSECOND,
     MICROSECONDS,
@@ -162,6 +165,7 @@ define_keywords!(
     NANOSECOND,
     NANOSECONDS,
     NATURAL,
+    NEXT,
     NO,
     NOT,
     NULL,
@@ -169,8 +173,10 @@ define_keywords!(
     NUMERIC,
     OFFSET,
     ON,
+    ONLY,
     OR,
     ORDER,
+    ORDINALITY,
     OTHERS,
     OUTER,
     OVER,
@@ -199,6 +205,7 @@ define_keywords!(
     SECONDS,
     SELECT,
     SEMI,
+    SESSION,
     SET,
     SETS,
     SHOW,
@@ -206,6 +213,7 @@ define_keywords!(
     SMALLINT,
     SOME,
     SORT,
+    START,
     STRING,
     SUBSTRING,
     TABLE,
@@ -223,6 +231,7 @@ define_keywords!(
     TINYINT,
     TO,
     TOP,
+    TRANSACTION,
     TRUE,
     UNBOUNDED,
     UNION,
@@ -238,6 +247,8 @@ define_keywords!(
     WHERE,
     WINDOW,
     WITH,
+    WITHIN,
+    WORK,
     YEAR,
     YEARS,
 );
@@ -3,5 +3,6 @@ pub mod ast;
 pub mod keywords;
 pub mod meta;
 pub mod parser;
+pub mod split;
 pub mod statement;
 pub mod tokens;
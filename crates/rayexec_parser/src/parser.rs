@@ -8,6 +8,7 @@ use crate::ast::{
     CreateSchema,
     CreateTable,
     CreateView,
+    DataType,
     Describe,
     Detach,
     DropStatement,
@@ -18,6 +19,7 @@ use crate::ast::{
     ResetVariable,
     SetVariable,
     Show,
+    TransactionStatement,
 };
 use crate::keywords::{Keyword, RESERVED_FOR_COLUMN_ALIAS};
 use crate::meta::Raw;
@@ -31,6 +33,21 @@ pub fn parse(sql: &str) -> Result<Vec<Statement<Raw>>> {
     Parser::with_tokens(toks, sql).parse_statements()
 }
 
+/// Parse a sql data type, e.g. `DECIMAL(10, 2)`.
+///
+/// Errors if there's anything remaining in the string after the data type.
+pub fn parse_datatype(sql: &str) -> Result<DataType> {
+    let toks = Tokenizer::new(sql).tokenize()?;
+    let mut parser = Parser::with_tokens(toks, sql);
+    let datatype = DataType::parse(&mut parser)?;
+    if parser.peek().is_some() {
+        return Err(RayexecError::new(format!(
+            "Unexpected tokens after data type: '{sql}'"
+        )));
+    }
+    Ok(datatype)
+}
+
 #[derive(Debug)]
 pub struct Parser<'a> {
     toks: Vec<TokenWithLocation>,
@@ -117,6 +134,13 @@ impl<'a> Parser<'a> {
                     }
                     Keyword::INSERT => Ok(RawStatement::Insert(Insert::parse(self)?)),
                     Keyword::EXPLAIN => Ok(RawStatement::Explain(ExplainNode::parse(self)?)),
+                    Keyword::BEGIN
+                    | Keyword::START
+                    | Keyword::COMMIT
+                    | Keyword::END
+                    | Keyword::ROLLBACK => Ok(RawStatement::Transaction(
+                        TransactionStatement::parse(self)?,
+                    )),
                     other => Err(RayexecError::new(format!("Unexpected keyword: {other:?}",))),
                 }
             }
@@ -0,0 +1,150 @@
+//! Splitting sql scripts into individual statements without fully parsing
+//! them.
+
+/// Split a sql script on top-level semicolons.
+///
+/// Semicolons inside string literals, quoted identifiers, dollar-quoted strings
+/// and comments don't end a statement. The returned statements are trimmed
+/// and don't include the trailing semicolon. Empty statements are skipped.
+///
+/// This lets each statement be parsed and executed independently, so that a
+/// syntax error in one statement doesn't prevent reporting results for the
+/// others.
+pub fn split_statements(sql: &str) -> Vec<&str> {
+    let bytes = sql.as_bytes();
+    let mut statements = Vec::new();
+
+    let mut start = 0;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b';' => {
+                push_statement(&mut statements, &sql[start..idx]);
+                idx += 1;
+                start = idx;
+            }
+            quote @ (b'\'' | b'"') => idx = skip_quoted(bytes, idx, quote),
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                idx = match bytes[idx..].iter().position(|&b| b == b'\n') {
+                    Some(pos) => idx + pos + 1,
+                    None => bytes.len(),
+                };
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = match sql[idx + 2..].find("*/") {
+                    Some(pos) => idx + 2 + pos + 2,
+                    None => bytes.len(),
+                };
+            }
+            b'$' => match dollar_quote_tag(&sql[idx..]) {
+                Some(tag) => {
+                    let body_start = idx + tag.len();
+                    idx = match sql[body_start..].find(tag) {
+                        Some(pos) => body_start + pos + tag.len(),
+                        None => bytes.len(),
+                    };
+                }
+                None => idx += 1,
+            },
+            _ => idx += 1,
+        }
+    }
+
+    push_statement(&mut statements, &sql[start..]);
+
+    statements
+}
+
+fn push_statement<'a>(statements: &mut Vec<&'a str>, statement: &'a str) {
+    let statement = statement.trim();
+    if !statement.is_empty() {
+        statements.push(statement);
+    }
+}
+
+/// Skip past a quoted string or identifier starting at `idx`, returning the
+/// index after the closing quote.
+///
+/// Doubled quotes are treated as an escaped quote.
+fn skip_quoted(bytes: &[u8], idx: usize, quote: u8) -> usize {
+    let mut idx = idx + 1;
+    while idx < bytes.len() {
+        if bytes[idx] == quote {
+            if bytes.get(idx + 1) == Some(&quote) {
+                idx += 2;
+                continue;
+            }
+            return idx + 1;
+        }
+        idx += 1;
+    }
+    bytes.len()
+}
+
+/// Get the opening dollar quote tag (e.g. `$$` or `$body$`) if the string
+/// starts with one.
+fn dollar_quote_tag(s: &str) -> Option<&str> {
+    let rest = &s[1..];
+    let end = rest.find('$')?;
+    let tag = &rest[..end];
+
+    let mut chars = tag.chars();
+    let valid = match chars.next() {
+        None => true,
+        Some(first) => {
+            (first.is_alphabetic() || first == '_')
+                && chars.all(|c| c.is_alphanumeric() || c == '_')
+        }
+    };
+
+    if valid {
+        Some(&s[..end + 2])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_simple() {
+        let out = split_statements("select 1; select 2 ;\n select 3");
+        assert_eq!(vec!["select 1", "select 2", "select 3"], out);
+    }
+
+    #[test]
+    fn split_skips_empty() {
+        let out = split_statements(";; select 1;\n;  ");
+        assert_eq!(vec!["select 1"], out);
+    }
+
+    #[test]
+    fn split_respects_quotes() {
+        let out = split_statements("select 'a;b', 'it''s;'; select \"c;\" from t");
+        assert_eq!(vec!["select 'a;b', 'it''s;'", "select \"c;\" from t"], out);
+    }
+
+    #[test]
+    fn split_respects_comments() {
+        let out = split_statements("select 1 -- a; comment\n; /* b; */ select 2");
+        assert_eq!(vec!["select 1 -- a; comment", "/* b; */ select 2"], out);
+    }
+
+    #[test]
+    fn split_respects_dollar_quotes() {
+        let out = split_statements("select $$a;b$$; select $tag$c;$$;$tag$; select $1");
+        assert_eq!(
+            vec!["select $$a;b$$", "select $tag$c;$$;$tag$", "select $1"],
+            out
+        );
+    }
+
+    #[test]
+    fn split_unterminated_quote() {
+        let out = split_statements("select 1; select 'abc;");
+        assert_eq!(vec!["select 1", "select 'abc;"], out);
+    }
+}
@@ -15,6 +15,7 @@ use crate::ast::{
     ResetVariable,
     SetVariable,
     Show,
+    TransactionStatement,
 };
 use crate::meta::{AstMeta, Raw};
 
@@ -62,4 +63,7 @@ pub enum Statement<T: AstMeta> {
 
     /// RESET <variable>
     ResetVariable(ResetVariable<T>),
+
+    /// BEGIN/COMMIT/ROLLBACK
+    Transaction(TransactionStatement),
 }
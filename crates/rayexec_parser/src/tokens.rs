@@ -38,6 +38,14 @@ pub enum Token {
     Mod,
     /// '|'
     Pipe,
+    /// '&'
+    Ampersand,
+    /// '#'
+    Hash,
+    /// '<<'
+    ShiftLeft,
+    /// '>>'
+    ShiftRight,
     /// '||'
     Concat,
     /// ','
@@ -275,6 +283,14 @@ impl<'a> Tokenizer<'a> {
                 self.state.next();
                 Token::RightBracket
             }
+            '{' => {
+                self.state.next();
+                Token::LeftBrace
+            }
+            '}' => {
+                self.state.next();
+                Token::RightBrace
+            }
             ',' => {
                 self.state.next();
                 Token::Comma
@@ -322,6 +338,10 @@ impl<'a> Tokenizer<'a> {
                         self.state.next();
                         Token::GtEq
                     }
+                    Some('>') => {
+                        self.state.next();
+                        Token::ShiftRight
+                    }
                     _ => Token::Gt,
                 }
             }
@@ -336,6 +356,10 @@ impl<'a> Tokenizer<'a> {
                         self.state.next();
                         Token::Neq
                     }
+                    Some('<') => {
+                        self.state.next();
+                        Token::ShiftLeft
+                    }
                     _ => Token::Lt,
                 }
             }
@@ -359,6 +383,14 @@ impl<'a> Tokenizer<'a> {
                     _ => Token::Pipe,
                 }
             }
+            '&' => {
+                self.state.next();
+                Token::Ampersand
+            }
+            '#' => {
+                self.state.next();
+                Token::Hash
+            }
             ':' => {
                 self.state.next();
                 match self.state.peek() {
@@ -375,6 +407,10 @@ impl<'a> Tokenizer<'a> {
                 let s = self.take_quoted_string('\'');
                 Token::SingleQuotedString(s.to_string())
             }
+            '$' => {
+                let s = self.take_dollar_quoted_string()?;
+                Token::SingleQuotedString(s.to_string())
+            }
             // Numbers
             '0'..='9' | '.' => {
                 let mut period_found = false;
@@ -436,6 +472,43 @@ impl<'a> Tokenizer<'a> {
         s
     }
 
+    /// Take a dollar-quoted string (`$$...$$` or `$tag$...$tag$`).
+    ///
+    /// Everything between the opening and closing tags is taken as is, with no
+    /// special handling of quotes or escapes.
+    fn take_dollar_quoted_string(&mut self) -> Result<&'a str> {
+        // Reported positions are 1-based.
+        let (line, col) = (self.state.line + 1, self.state.col + 1);
+
+        let _ = self.state.next(); // Take start '$'
+        let tag = self.state.take_while(|c| c.is_alphanumeric() || c == '_');
+        let tag_valid = !tag.starts_with(|c: char| c.is_ascii_digit());
+        if !tag_valid || self.state.peek() != Some('$') {
+            return Err(RayexecError::new(format!(
+                "Invalid dollar-quoted string tag at line {line}, column {col}"
+            )));
+        }
+        let _ = self.state.next(); // Take '$' ending the tag
+
+        let close = format!("${tag}$");
+        let start = self.state.idx;
+        let end = match self.state.query[start..].find(&close) {
+            Some(offset) => start + offset,
+            None => {
+                return Err(RayexecError::new(format!(
+                    "Unterminated dollar-quoted string at line {line}, column {col}"
+                )))
+            }
+        };
+
+        // Step through so line and column stay accurate.
+        while self.state.idx < end + close.len() {
+            let _ = self.state.next();
+        }
+
+        Ok(&self.state.query[start..end])
+    }
+
     fn take_quoted_identifier(&mut self) -> Word {
         let _ = self.state.next(); // Take start quote
         let quoted = self.take_quoted_string('"');
@@ -505,4 +578,63 @@ mod tests {
         // hi
         assert_eq!(toks[6].start_idx, 14);
     }
+
+    fn tokenize_string_literals(query: &str) -> Vec<String> {
+        Tokenizer::new(query)
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .filter_map(|tok| match tok.token {
+                Token::SingleQuotedString(s) => Some(s),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dollar_quoted_string() {
+        let strings = tokenize_string_literals("SELECT $$it's fine$$");
+        assert_eq!(vec!["it's fine".to_string()], strings);
+    }
+
+    #[test]
+    fn dollar_quoted_string_empty() {
+        let strings = tokenize_string_literals("SELECT $$$$, 'a'");
+        assert_eq!(vec!["".to_string(), "a".to_string()], strings);
+    }
+
+    #[test]
+    fn dollar_quoted_string_tagged() {
+        let strings = tokenize_string_literals("SELECT $x$ a $$ 'b' $y$ c $x$, 1");
+        assert_eq!(vec![" a $$ 'b' $y$ c ".to_string()], strings);
+    }
+
+    #[test]
+    fn dollar_quoted_string_tracks_lines() {
+        let toks = Tokenizer::new("SELECT $$a\nb$$\n, 1").tokenize().unwrap();
+
+        assert_eq!(Token::SingleQuotedString("a\nb".to_string()), toks[2].token);
+        // Following tokens start after the closing tag.
+        assert_eq!(Token::Whitespace, toks[3].token);
+        assert_eq!(14, toks[3].start_idx);
+        assert_eq!(2, toks[3].line);
+    }
+
+    #[test]
+    fn dollar_quoted_string_unterminated() {
+        let err = Tokenizer::new("SELECT\n  $x$ abc $x")
+            .tokenize()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unterminated dollar-quoted string at line 2, column 3"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn dollar_quoted_string_invalid_tag() {
+        Tokenizer::new("SELECT $1").tokenize().unwrap_err();
+        Tokenizer::new("SELECT $a b$").tokenize().unwrap_err();
+    }
 }
@@ -3,6 +3,7 @@ use rayexec_csv::CsvDataSource;
 use rayexec_delta::DeltaDataSource;
 use rayexec_error::RayexecError;
 use rayexec_execution::datasource::{DataSourceBuilder, DataSourceRegistry, MemoryDataSource};
+use rayexec_json::JsonDataSource;
 use rayexec_parquet::ParquetDataSource;
 use rayexec_rt_native::runtime::{NativeRuntime, ThreadedNativeExecutor};
 use rayexec_shell::session::SingleUserEngine;
@@ -19,6 +20,7 @@ pub fn connect() -> Result<PythonSession> {
         .with_datasource("memory", Box::new(MemoryDataSource))?
         .with_datasource("parquet", ParquetDataSource::initialize(runtime.clone()))?
         .with_datasource("csv", CsvDataSource::initialize(runtime.clone()))?
+        .with_datasource("json", JsonDataSource::initialize(runtime.clone()))?
         .with_datasource("delta", DeltaDataSource::initialize(runtime.clone()))?;
 
     let executor = ThreadedNativeExecutor::try_new()?;
@@ -1,7 +1,8 @@
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::task::{Context, Poll};
 
 use bytes::Bytes;
@@ -32,12 +33,31 @@ impl LocalFileSystemProvider {
         Ok(Box::new(LocalFile { len, file }))
     }
 
+    /// Open a file for writing, creating any missing parent directories.
+    ///
+    /// Bytes are written to a temporary file next to the target, which is
+    /// renamed to the target path once the sink is finished. A sink dropped
+    /// before finishing removes the temporary file, leaving anything previously
+    /// at the target path untouched.
     pub fn file_sink(&self, path: &Path) -> Result<Box<dyn FileSink>> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| {
+                RayexecError::with_source(
+                    format!(
+                        "Failed to create directory for writing at location: {}",
+                        parent.to_string_lossy()
+                    ),
+                    Box::new(e),
+                )
+            })?;
+        }
+
+        let tmp_path = temp_sibling_path(path)?;
         let file = OpenOptions::new()
             .write(true)
             .create(true)
             .truncate(true)
-            .open(path)
+            .open(&tmp_path)
             .map_err(|e| {
                 RayexecError::with_source(
                     format!(
@@ -50,9 +70,55 @@ impl LocalFileSystemProvider {
 
         Ok(Box::new(LocalFileSink {
             file: BufWriter::new(file),
+            path: path.to_path_buf(),
+            tmp_path,
+            finished: false,
         }))
     }
 
+    /// Open a file for appending, creating the file and any missing parent
+    /// directories if needed.
+    ///
+    /// Returns the sink along with the size of the file before appending. A
+    /// sink dropped before finishing truncates the file back to that size.
+    pub fn file_sink_append(&self, path: &Path) -> Result<(Box<dyn FileSink>, usize)> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| {
+                RayexecError::with_source(
+                    format!(
+                        "Failed to create directory for writing at location: {}",
+                        parent.to_string_lossy()
+                    ),
+                    Box::new(e),
+                )
+            })?;
+        }
+
+        let file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)
+            .map_err(|e| {
+                RayexecError::with_source(
+                    format!(
+                        "Failed to open file for appending at location: {}",
+                        path.to_string_lossy()
+                    ),
+                    Box::new(e),
+                )
+            })?;
+
+        let existing_len = file.metadata()?.len();
+
+        let sink = LocalAppendFileSink {
+            file: Some(BufWriter::new(file)),
+            existing_len,
+            finished: false,
+        };
+
+        Ok((Box::new(sink), existing_len as usize))
+    }
+
     pub fn list_prefix(&self, dir: &Path) -> Result<Vec<String>> {
         fn inner(dir: &Path, paths: &mut Vec<String>) -> Result<()> {
             if dir.is_dir() {
@@ -123,9 +189,59 @@ impl FileSource for LocalFile {
     }
 }
 
+/// Get a path for a hidden temporary file in the same directory as `path`.
+///
+/// Keeping the file in the same directory ensures the final rename doesn't
+/// cross filesystems.
+fn temp_sibling_path(path: &Path) -> Result<PathBuf> {
+    static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let file_name = path.file_name().ok_or_else(|| {
+        RayexecError::new(format!(
+            "Missing file name for location: {}",
+            path.to_string_lossy()
+        ))
+    })?;
+
+    let tmp_name = format!(
+        ".{}.{}-{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed),
+    );
+
+    Ok(path.with_file_name(tmp_name))
+}
+
 #[derive(Debug)]
 pub struct LocalFileSink {
     file: BufWriter<File>,
+    /// Final location of the file.
+    path: PathBuf,
+    /// Location of the file we're currently writing to.
+    tmp_path: PathBuf,
+    /// If the temp file was successfully moved to the final location.
+    finished: bool,
+}
+
+impl LocalFileSink {
+    fn finish_inner(&mut self) -> Result<()> {
+        self.file.flush().context("failed to flush")?;
+        self.file.get_ref().sync_all().context("failed to sync")?;
+
+        fs::rename(&self.tmp_path, &self.path).map_err(|e| {
+            RayexecError::with_source(
+                format!(
+                    "Failed to move file to location: {}",
+                    self.path.to_string_lossy()
+                ),
+                Box::new(e),
+            )
+        })?;
+        self.finished = true;
+
+        Ok(())
+    }
 }
 
 impl FileSink for LocalFileSink {
@@ -138,11 +254,76 @@ impl FileSink for LocalFileSink {
     }
 
     fn finish(&mut self) -> BoxFuture<'static, Result<()>> {
-        let result = self.file.flush().context("failed to flush");
+        let result = self.finish_inner();
+        async move { result }.boxed()
+    }
+}
+
+impl Drop for LocalFileSink {
+    fn drop(&mut self) {
+        if !self.finished {
+            // Sink was never finished (or finishing failed), don't leave a
+            // partially written file behind.
+            let _ = fs::remove_file(&self.tmp_path);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LocalAppendFileSink {
+    /// File being appended to, only None once dropped.
+    file: Option<BufWriter<File>>,
+    /// Size of the file before we started appending.
+    existing_len: u64,
+    /// If all appended bytes were successfully flushed.
+    finished: bool,
+}
+
+impl LocalAppendFileSink {
+    fn file_mut(&mut self) -> Result<&mut BufWriter<File>> {
+        self.file
+            .as_mut()
+            .ok_or_else(|| RayexecError::new("Append file sink already closed"))
+    }
+
+    fn finish_inner(&mut self) -> Result<()> {
+        let file = self.file_mut()?;
+        file.flush().context("failed to flush")?;
+        file.get_ref().sync_all().context("failed to sync")?;
+        self.finished = true;
+
+        Ok(())
+    }
+}
+
+impl FileSink for LocalAppendFileSink {
+    fn write_all(&mut self, buf: Bytes) -> BoxFuture<'static, Result<()>> {
+        let result = self.file_mut().and_then(|file| {
+            file.write_all(buf.as_ref())
+                .context("failed to write buffer")
+        });
+        async move { result }.boxed()
+    }
+
+    fn finish(&mut self) -> BoxFuture<'static, Result<()>> {
+        let result = self.finish_inner();
         async move { result }.boxed()
     }
 }
 
+impl Drop for LocalAppendFileSink {
+    fn drop(&mut self) {
+        if let Some(file) = self.file.take() {
+            if !self.finished {
+                // Discard anything still buffered, and remove anything that
+                // was already appended.
+                let (file, _) = file.into_parts();
+                let _ = file.set_len(self.existing_len);
+            }
+        }
+    }
+}
+
 struct FileStream {
     file: File,
     curr: usize,
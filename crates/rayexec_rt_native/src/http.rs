@@ -38,7 +38,15 @@ impl HttpClient for TokioWrappedHttpClient {
                 println!("ERROR: {result:?}");
             }
 
-            let resp = result.context("Failed to send request")?;
+            let resp = result.map_err(|e| {
+                let retriable = e.is_timeout() || e.is_connect();
+                let err = RayexecError::with_source("Failed to send request", Box::new(e));
+                if retriable {
+                    err.with_retriable(true)
+                } else {
+                    err
+                }
+            })?;
 
             Ok(BoxingResponse(resp))
         });
@@ -179,6 +179,17 @@ impl FileProvider for NativeFileProvider {
         }
     }
 
+    fn file_sink_append(
+        &self,
+        location: FileLocation,
+        _config: &AccessConfig,
+    ) -> Result<(Box<dyn FileSink>, usize)> {
+        match location {
+            FileLocation::Url(_url) => not_implemented!("http append sink native"),
+            FileLocation::Path(path) => LocalFileSystemProvider.file_sink_append(&path),
+        }
+    }
+
     fn list_prefix(
         &self,
         prefix: FileLocation,
@@ -5,6 +5,7 @@ use rayexec_error::Result;
 use rayexec_execution::datasource::{DataSourceBuilder, DataSourceRegistry, MemoryDataSource};
 use rayexec_execution::engine::Engine;
 use rayexec_execution::runtime::{Runtime, TokioHandlerProvider};
+use rayexec_json::JsonDataSource;
 use rayexec_parquet::ParquetDataSource;
 use rayexec_postgres::PostgresDataSource;
 use rayexec_rt_native::runtime::{NativeRuntime, ThreadedNativeExecutor};
@@ -56,7 +57,8 @@ fn main() -> Result<()> {
             UnityCatalogDataSource::initialize(runtime.clone()),
         )?
         .with_datasource("parquet", ParquetDataSource::initialize(runtime.clone()))?
-        .with_datasource("csv", CsvDataSource::initialize(runtime.clone()))?;
+        .with_datasource("csv", CsvDataSource::initialize(runtime.clone()))?
+        .with_datasource("json", JsonDataSource::initialize(runtime.clone()))?;
     let engine = Engine::new_with_registry(sched.clone(), runtime.clone(), registry)?;
 
     tokio_handle.block_on(async move { serve_with_engine(engine, args.port).await })
@@ -8,10 +8,11 @@ use rayexec_execution::engine::Engine;
 use rayexec_execution::hybrid::client::{HybridClient, HybridConnectConfig};
 use rayexec_execution::runtime::{PipelineExecutor, Runtime};
 use rayexec_parser::parser;
+use rayexec_parser::split::split_statements;
 use rayexec_parser::statement::RawStatement;
 use tokio::sync::Mutex;
 
-use crate::result_table::StreamingTable;
+use crate::result_table::{MaterializedResultTable, StreamingTable};
 
 /// A wrapper around a session and an engine for when running the database in a
 /// local, single user mode (e.g. in the CLI or through wasm).
@@ -115,6 +116,65 @@ where
             })
             .collect())
     }
+
+    /// Execute a script containing any number of statements, returning a
+    /// result for each statement.
+    ///
+    /// Statements are split on top-level semicolons and parsed individually,
+    /// so a syntax error only fails the statement it's in. Each statement is
+    /// executed to completion before moving to the next, and all statements
+    /// share session state.
+    pub async fn execute_script(
+        &self,
+        sql: &str,
+        opts: ScriptOptions,
+    ) -> Vec<Result<MaterializedResultTable>> {
+        let mut results = Vec::new();
+
+        for sql in split_statements(sql) {
+            let result = self.execute_script_statement(sql).await;
+            let failed = result.is_err();
+            results.push(result);
+
+            if failed && !opts.continue_on_error {
+                break;
+            }
+        }
+
+        results
+    }
+
+    async fn execute_script_statement(&self, sql: &str) -> Result<MaterializedResultTable> {
+        let mut statements = parser::parse(sql)?;
+        let statement = match statements.len() {
+            1 => statements.pop().unwrap(),
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Expected 1 statement, got {}",
+                    other
+                )))
+            }
+        };
+
+        let table = PendingQuery {
+            session: self.session.clone(),
+            statement,
+        }
+        .execute()
+        .await?;
+
+        table.collect().await
+    }
+}
+
+/// Options for executing a multi-statement script.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScriptOptions {
+    /// Keep executing statements after one fails.
+    ///
+    /// If false, no results are returned for statements after the first
+    /// failure.
+    pub continue_on_error: bool,
 }
 
 #[derive(Debug)]
@@ -141,3 +201,295 @@ where
         Ok(StreamingTable { result })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::future::BoxFuture;
+    use futures::{StreamExt, TryStreamExt};
+    use rayexec_execution::arrays::array::Array;
+    use rayexec_execution::arrays::batch::Batch;
+    use rayexec_execution::arrays::datatype::DataType;
+    use rayexec_execution::arrays::field::{Field, Schema};
+    use rayexec_execution::arrays::scalar::OwnedScalarValue;
+    use rayexec_execution::database::DatabaseContext;
+    use rayexec_execution::datasource::{DataSource, MemoryDataSource};
+    use rayexec_execution::functions::table::{
+        PlannedTableFunction,
+        ScanPlanner,
+        TableFunction,
+        TableFunctionImpl,
+        TableFunctionPlanner,
+    };
+    use rayexec_execution::functions::{FunctionInfo, Signature};
+    use rayexec_execution::logical::statistics::StatisticsValue;
+    use rayexec_execution::runtime::{Runtime, TokioHandlerProvider};
+    use rayexec_execution::storage::table_storage::{DataTable, DataTableScan, Projections};
+    use rayexec_rt_native::runtime::{NativeRuntime, ThreadedNativeExecutor};
+
+    use super::*;
+
+    /// Data source providing a `counted_scan()` table function that tracks how
+    /// many times it's been scanned.
+    #[derive(Debug, Clone, Default)]
+    struct CountingDataSource {
+        scans: Arc<AtomicUsize>,
+    }
+
+    impl DataSource for CountingDataSource {
+        fn initialize_table_functions(&self) -> Vec<Box<dyn TableFunction>> {
+            vec![Box::new(CountedScan {
+                scans: self.scans.clone(),
+            })]
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct CountedScan {
+        scans: Arc<AtomicUsize>,
+    }
+
+    impl FunctionInfo for CountedScan {
+        fn name(&self) -> &'static str {
+            "counted_scan"
+        }
+
+        fn signatures(&self) -> &[Signature] {
+            &[]
+        }
+    }
+
+    impl TableFunction for CountedScan {
+        fn planner(&self) -> TableFunctionPlanner<'_> {
+            TableFunctionPlanner::Scan(self)
+        }
+    }
+
+    impl ScanPlanner for CountedScan {
+        fn plan<'a>(
+            &self,
+            _context: &'a DatabaseContext,
+            _positional_inputs: Vec<OwnedScalarValue>,
+            named_inputs: HashMap<String, OwnedScalarValue>,
+        ) -> BoxFuture<'a, Result<PlannedTableFunction>> {
+            let planned = PlannedTableFunction {
+                function: Box::new(self.clone()),
+                positional_inputs: Vec::new(),
+                named_inputs,
+                function_impl: TableFunctionImpl::Scan(Arc::new(CountedTable {
+                    scans: self.scans.clone(),
+                })),
+                cardinality: StatisticsValue::Unknown,
+                schema: Schema::new([Field::new("a", DataType::Int64, false)]),
+            };
+
+            Box::pin(async move { Ok(planned) })
+        }
+    }
+
+    #[derive(Debug)]
+    struct CountedTable {
+        scans: Arc<AtomicUsize>,
+    }
+
+    impl DataTable for CountedTable {
+        fn scan(
+            &self,
+            _projections: Projections,
+            num_partitions: usize,
+        ) -> Result<Vec<Box<dyn DataTableScan>>> {
+            self.scans.fetch_add(1, Ordering::SeqCst);
+
+            // Only the first partition produces rows.
+            Ok((0..num_partitions)
+                .map(|idx| {
+                    let batch = (idx == 0)
+                        .then(|| Batch::try_new([Array::from_iter([1_i64, 2, 3, 4])]).unwrap());
+                    Box::new(CountedTableScan { batch }) as _
+                })
+                .collect())
+        }
+    }
+
+    #[derive(Debug)]
+    struct CountedTableScan {
+        batch: Option<Batch>,
+    }
+
+    impl DataTableScan for CountedTableScan {
+        fn pull(&mut self) -> BoxFuture<'_, Result<Option<Batch>>> {
+            let batch = self.batch.take();
+            Box::pin(async move { Ok(batch) })
+        }
+    }
+
+    fn run_script(sql: &str, opts: ScriptOptions) -> Vec<Result<MaterializedResultTable>> {
+        let executor = ThreadedNativeExecutor::try_new().unwrap();
+        let runtime = NativeRuntime::with_default_tokio().unwrap();
+        let registry = DataSourceRegistry::default()
+            .with_datasource("memory", Box::new(MemoryDataSource))
+            .unwrap();
+        let engine = SingleUserEngine::try_new(executor, runtime.clone(), registry).unwrap();
+
+        let handle = runtime.tokio_handle().handle().unwrap();
+        handle.block_on(engine.session().execute_script(sql, opts))
+    }
+
+    const SCRIPT: &str = "
+        CREATE TEMP TABLE t (a INT);
+        SELECT * FROM missing_table;
+        INSERT INTO t VALUES (1), (2);
+    ";
+
+    #[test]
+    fn script_stops_on_error() {
+        let results = run_script(SCRIPT, ScriptOptions::default());
+
+        assert_eq!(2, results.len());
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn script_continue_on_error() {
+        let results = run_script(
+            SCRIPT,
+            ScriptOptions {
+                continue_on_error: true,
+            },
+        );
+
+        assert_eq!(3, results.len());
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+
+        // Insert ran against the table created by the first statement.
+        let inserted = results[2].as_ref().unwrap();
+        assert_eq!(1, inserted.num_rows());
+    }
+
+    #[test]
+    fn explain_analyze_filter_rows() {
+        let results = run_script(
+            "
+            CREATE TEMP TABLE t (a INT);
+            INSERT INTO t SELECT * FROM generate_series(1, 100);
+            EXPLAIN ANALYZE SELECT a FROM t WHERE a > 90;
+            ",
+            ScriptOptions::default(),
+        );
+
+        assert_eq!(3, results.len());
+        let explain = results[2].as_ref().unwrap();
+
+        let plan = explain
+            .iter_rows()
+            .find(|row| row.columns[0].try_as_str().unwrap() == "physical_analyzed")
+            .map(|row| row.columns[1].try_as_str().unwrap().to_string())
+            .unwrap();
+
+        // Items are sorted by name, with actual rows being the first item for
+        // each operator.
+        let first_item = |operator: &str| {
+            let mut lines = plan.lines().map(|line| line.trim());
+            lines.find(|line| *line == operator).unwrap();
+            lines.next().unwrap().to_string()
+        };
+
+        assert_eq!("├ actual_rows: 100", first_item("Scan"));
+        assert_eq!("├ actual_rows: 10", first_item("Filter"));
+    }
+
+    #[test]
+    fn execute_stream_yields_batches_incrementally() {
+        let executor = ThreadedNativeExecutor::try_new().unwrap();
+        let runtime = NativeRuntime::with_default_tokio().unwrap();
+        let engine = Engine::new(executor, runtime.clone()).unwrap();
+
+        let handle = runtime.tokio_handle().handle().unwrap();
+        handle.block_on(async {
+            let mut stream = engine
+                .execute_stream("SELECT a FROM generate_series(1, 100000) g(a) WHERE a % 2 = 0")
+                .await
+                .unwrap()
+                .stream;
+
+            // First batch is available before the full output has been
+            // produced.
+            let first = stream.next().await.unwrap().unwrap();
+            assert!(first.num_rows() > 0);
+            assert!(first.num_rows() < 50000);
+
+            let rest: Vec<_> = stream.try_collect().await.unwrap();
+            assert!(!rest.is_empty());
+
+            let total: usize = first.num_rows() + rest.iter().map(|b| b.num_rows()).sum::<usize>();
+            assert_eq!(50000, total);
+        });
+    }
+
+    #[test]
+    fn execute_stream_cancel() {
+        let executor = ThreadedNativeExecutor::try_new().unwrap();
+        let runtime = NativeRuntime::with_default_tokio().unwrap();
+        let engine = Engine::new(executor, runtime.clone()).unwrap();
+
+        let handle = runtime.tokio_handle().handle().unwrap();
+        handle.block_on(async {
+            let mut result = engine
+                .execute_stream("SELECT a FROM generate_series(1, 100000000) g(a)")
+                .await
+                .unwrap();
+
+            // Query can't complete without the output being read.
+            result.stream.next().await.unwrap().unwrap();
+            result.handle.cancel();
+
+            let err = result.stream.try_collect::<Vec<_>>().await.unwrap_err();
+            assert!(err.to_string().contains("Query canceled"), "{err}");
+        });
+    }
+
+    #[test]
+    fn cte_referenced_twice_executes_once() {
+        let executor = ThreadedNativeExecutor::try_new().unwrap();
+        let runtime = NativeRuntime::with_default_tokio().unwrap();
+        let datasource = CountingDataSource::default();
+        let scans = datasource.scans.clone();
+        let registry = DataSourceRegistry::default()
+            .with_datasource("counting", Box::new(datasource))
+            .unwrap();
+        let engine = SingleUserEngine::try_new(executor, runtime.clone(), registry).unwrap();
+
+        let handle = runtime.tokio_handle().handle().unwrap();
+        let run = |sql: &str| {
+            scans.store(0, Ordering::SeqCst);
+            let mut results = handle.block_on(
+                engine
+                    .session()
+                    .execute_script(sql, ScriptOptions::default()),
+            );
+            assert_eq!(1, results.len());
+            let table = results.pop().unwrap().unwrap();
+            (table.num_rows(), scans.load(Ordering::SeqCst))
+        };
+
+        // Materialized, body only executed once.
+        let (num_rows, num_scans) = run("
+            WITH cte AS (SELECT * FROM counted_scan())
+            SELECT * FROM cte t1 JOIN cte t2 ON t1.a = t2.a + 1
+        ");
+        assert_eq!(3, num_rows);
+        assert_eq!(1, num_scans);
+
+        // Inlined, body executed per reference.
+        let (num_rows, num_scans) = run("
+            WITH cte AS NOT MATERIALIZED (SELECT * FROM counted_scan())
+            SELECT * FROM cte t1, cte t2
+        ");
+        assert_eq!(16, num_rows);
+        assert_eq!(2, num_scans);
+    }
+}
@@ -154,6 +154,17 @@ impl FileProvider for WasmFileProvider {
         }
     }
 
+    fn file_sink_append(
+        &self,
+        location: FileLocation,
+        _config: &AccessConfig,
+    ) -> Result<(Box<dyn FileSink>, usize)> {
+        match location {
+            FileLocation::Url(_url) => not_implemented!("http append sink wasm"),
+            FileLocation::Path(path) => self.fs.file_sink_append(&path),
+        }
+    }
+
     fn list_prefix(
         &self,
         prefix: FileLocation,
@@ -6,6 +6,7 @@ use rayexec_delta::DeltaDataSource;
 use rayexec_execution::arrays::format::{FormatOptions, Formatter};
 use rayexec_execution::datasource::{DataSourceBuilder, DataSourceRegistry, MemoryDataSource};
 use rayexec_iceberg::IcebergDataSource;
+use rayexec_json::JsonDataSource;
 use rayexec_parquet::ParquetDataSource;
 use rayexec_shell::result_table::{MaterializedColumn, MaterializedResultTable};
 use rayexec_shell::session::SingleUserEngine;
@@ -31,6 +32,7 @@ impl WasmSession {
             .with_datasource("memory", Box::new(MemoryDataSource))?
             .with_datasource("parquet", ParquetDataSource::initialize(runtime.clone()))?
             .with_datasource("csv", CsvDataSource::initialize(runtime.clone()))?
+            .with_datasource("json", JsonDataSource::initialize(runtime.clone()))?
             .with_datasource("delta", DeltaDataSource::initialize(runtime.clone()))?
             .with_datasource("unity", UnityCatalogDataSource::initialize(runtime.clone()))?
             .with_datasource("iceberg", IcebergDataSource::initialize(runtime.clone()))?;
@@ -4,6 +4,7 @@ use rayexec_error::{RayexecError, Result};
 use rayexec_execution::datasource::{DataSourceBuilder, DataSourceRegistry, MemoryDataSource};
 use rayexec_execution::engine::Engine;
 use rayexec_execution::runtime::{Runtime, TokioHandlerProvider};
+use rayexec_json::JsonDataSource;
 use rayexec_parquet::ParquetDataSource;
 use rayexec_postgres::PostgresDataSource;
 use rayexec_rt_native::runtime::{NativeRuntime, ThreadedNativeExecutor};
@@ -20,6 +21,7 @@ fn main() -> Result<()> {
         .with_datasource("memory", Box::new(MemoryDataSource))?
         .with_datasource("postgres", PostgresDataSource::initialize(runtime.clone()))?
         .with_datasource("csv", CsvDataSource::initialize(runtime.clone()))?
+        .with_datasource("json", JsonDataSource::initialize(runtime.clone()))?
         .with_datasource("delta", DeltaDataSource::initialize(runtime.clone()))?
         .with_datasource("parquet", ParquetDataSource::initialize(runtime.clone()))?;
 